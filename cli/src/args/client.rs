@@ -17,7 +17,8 @@
  */
 
 use crate::args::common::ListMode;
-use clap::{Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand};
+use iggy::identifier::Identifier;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ClientAction {
@@ -39,6 +40,18 @@ pub(crate) enum ClientAction {
     ///  iggy client list -l table
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(ClientListArgs),
+    /// Forcibly disconnect client with given ID or all clients of given user
+    ///
+    /// TCP and QUIC connections are closed by the server, and for the user
+    /// all previously issued HTTP access tokens are revoked as well.
+    /// The user ID can be specified as either a username or an ID.
+    ///
+    /// Examples:
+    ///  iggy client disconnect 42
+    ///  iggy client disconnect --user testuser
+    ///  iggy client disconnect -u 2
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Disconnect(ClientDisconnectArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -53,3 +66,17 @@ pub(crate) struct ClientListArgs {
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
 }
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("target").required(true))]
+pub(crate) struct ClientDisconnectArgs {
+    /// Client ID to disconnect
+    #[clap(group = "target")]
+    pub(crate) client_id: Option<u32>,
+    /// Disconnect all clients of the user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, group = "target", value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user: Option<Identifier>,
+}
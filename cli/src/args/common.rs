@@ -21,6 +21,7 @@ use iggy::cli::client::get_clients::GetClientsOutput;
 use iggy::cli::consumer_group::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy::cli::context::get_contexts::GetContextsOutput;
 use iggy::cli::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
+use iggy::cli::schemas::get_schemas::GetSchemasOutput;
 use iggy::cli::streams::get_streams::GetStreamsOutput;
 use iggy::cli::system::stats::GetStatsOutput;
 use iggy::cli::topics::get_topics::GetTopicsOutput;
@@ -86,6 +87,15 @@ impl From<ListMode> for GetConsumerGroupsOutput {
     }
 }
 
+impl From<ListMode> for GetSchemasOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetSchemasOutput::Table,
+            ListMode::List => GetSchemasOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetContextsOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
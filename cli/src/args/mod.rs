@@ -36,6 +36,7 @@ use crate::args::{
     message::MessageAction,
     partition::PartitionAction,
     personal_access_token::PersonalAccessTokenAction,
+    schema::SchemaAction,
     stream::StreamAction,
     system::{PingArgs, StatsArgs},
     topic::TopicAction,
@@ -55,6 +56,7 @@ pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
+pub(crate) mod schema;
 pub(crate) mod segment;
 pub(crate) mod stream;
 pub(crate) mod system;
@@ -164,6 +166,13 @@ pub(crate) enum Command {
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
+    /// reload iggy server configuration
+    ///
+    /// Command makes Iggy server re-read and validate its configuration.
+    /// Settings which can be changed at runtime (log level, background task intervals,
+    /// TLS certificates) are applied immediately, remaining changes require a restart.
+    #[clap(verbatim_doc_comment)]
+    ReloadConfig,
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
@@ -179,6 +188,9 @@ pub(crate) enum Command {
     /// consumer offset operations
     #[command(subcommand, visible_alias = "o")]
     ConsumerOffset(ConsumerOffsetAction),
+    /// schema registry operations
+    #[command(subcommand)]
+    Schema(SchemaAction),
     /// message operations
     #[command(subcommand, visible_alias = "m")]
     Message(MessageAction),
@@ -0,0 +1,184 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand, ValueEnum};
+use iggy::identifier::Identifier;
+use iggy::models::schema::{SchemaCompatibility, SchemaFormat, SchemaValidation};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum SchemaAction {
+    /// Register new version of the schema for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Schema definition is read from the given file. If the latest version
+    /// has the same format and definition, it's returned instead of
+    /// registering a new one.
+    ///
+    /// Examples:
+    ///  iggy schema register 1 1 json_schema order.json
+    ///  iggy schema register prod orders avro order.avsc
+    ///  iggy schema register prod orders protobuf order.proto
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Register(SchemaRegisterArgs),
+    /// Get details of a single schema with given ID
+    ///
+    /// Examples:
+    ///  iggy schema get 1
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(SchemaGetArgs),
+    /// List all versions of the schema for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy schema list 1 1
+    ///  iggy schema list prod orders --list-mode table
+    ///  iggy schema list prod 2 -l list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(SchemaListArgs),
+    /// Update compatibility rule of the schema for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Compatibility can be one of: none, backward, forward, full
+    ///
+    /// Examples:
+    ///  iggy schema compatibility 1 1 full
+    ///  iggy schema compatibility prod orders none
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Compatibility(SchemaCompatibilityArgs),
+    /// Update validation of the message payloads for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Mode can be one of: none, reject, route
+    /// With the route mode, the invalid messages are appended to the rejection
+    /// topic, which must be specified by the numeric stream and topic IDs.
+    ///
+    /// Examples:
+    ///  iggy schema validation 1 1 reject
+    ///  iggy schema validation prod orders route --rejection-stream-id 2 --rejection-topic-id 1
+    ///  iggy schema validation prod orders none
+    #[clap(verbatim_doc_comment, visible_alias = "v")]
+    Validation(SchemaValidationArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaRegisterArgs {
+    /// Stream ID to register schema
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to register schema
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Schema format (avro, json_schema or protobuf)
+    #[arg(value_parser = clap::value_parser!(SchemaFormat))]
+    pub(crate) format: SchemaFormat,
+    /// Path to the file with schema definition
+    pub(crate) definition_file: PathBuf,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaGetArgs {
+    /// Schema ID to get
+    pub(crate) schema_id: u32,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaListArgs {
+    /// Stream ID to list schemas
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to list schemas
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaCompatibilityArgs {
+    /// Stream ID to update schema compatibility
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to update schema compatibility
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Compatibility rule (none, backward, forward or full)
+    #[arg(value_parser = clap::value_parser!(SchemaCompatibility))]
+    pub(crate) compatibility: SchemaCompatibility,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum SchemaValidationMode {
+    None,
+    Reject,
+    Route,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaValidationArgs {
+    /// Stream ID to update schema validation
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to update schema validation
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Validation mode
+    #[clap(value_enum)]
+    pub(crate) mode: SchemaValidationMode,
+    /// Stream ID of the rejection topic, required for the route mode
+    #[clap(short = 's', long, required_if_eq("mode", "route"))]
+    pub(crate) rejection_stream_id: Option<u32>,
+    /// Topic ID of the rejection topic, required for the route mode
+    #[clap(short = 't', long, required_if_eq("mode", "route"))]
+    pub(crate) rejection_topic_id: Option<u32>,
+}
+
+impl SchemaValidationArgs {
+    pub(crate) fn validation(&self) -> SchemaValidation {
+        match self.mode {
+            SchemaValidationMode::None => SchemaValidation::None,
+            SchemaValidationMode::Reject => SchemaValidation::Reject,
+            SchemaValidationMode::Route => SchemaValidation::Route {
+                stream_id: self.rejection_stream_id.unwrap_or_default(),
+                topic_id: self.rejection_topic_id.unwrap_or_default(),
+            },
+        }
+    }
+}
@@ -24,8 +24,8 @@ mod logging;
 use crate::args::{
     client::ClientAction, consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction, permissions::PermissionsArgs,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
-    Command, IggyConsoleArgs,
+    personal_access_token::PersonalAccessTokenAction, schema::SchemaAction, stream::StreamAction,
+    topic::TopicAction, Command, IggyConsoleArgs,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
@@ -43,7 +43,10 @@ use iggy::cli::context::use_context::UseContextCmd;
 use iggy::cli::segments::delete_segments::DeleteSegmentsCmd;
 use iggy::cli::system::snapshot::GetSnapshotCmd;
 use iggy::cli::{
-    client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
+    client::{
+        disconnect_client::DisconnectClientCmd, get_client::GetClientCmd,
+        get_clients::GetClientsCmd,
+    },
     consumer_group::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
@@ -63,11 +66,16 @@ use iggy::cli::{
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    schemas::{
+        get_schema::GetSchemaCmd, get_schemas::GetSchemasCmd, register_schema::RegisterSchemaCmd,
+        update_schema_compatibility::UpdateSchemaCompatibilityCmd,
+        update_schema_validation::UpdateSchemaValidationCmd,
+    },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{me::GetMeCmd, ping::PingCmd, reload_config::ReloadConfigCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
         get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
@@ -85,6 +93,7 @@ use iggy::cli::{
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::IggyClient;
+use iggy::system::disconnect_client::DisconnectTarget;
 use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use std::sync::Arc;
@@ -184,6 +193,7 @@ fn get_command(
             args.snapshot_types,
             args.out_dir,
         )),
+        Command::ReloadConfig => Box::new(ReloadConfigCmd::new()),
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -247,6 +257,12 @@ fn get_command(
             ClientAction::List(list_args) => {
                 Box::new(GetClientsCmd::new(list_args.list_mode.into()))
             }
+            ClientAction::Disconnect(disconnect_args) => {
+                Box::new(DisconnectClientCmd::new(match &disconnect_args.user {
+                    Some(user_id) => DisconnectTarget::User(user_id.clone()),
+                    None => DisconnectTarget::Client(disconnect_args.client_id.unwrap()),
+                }))
+            }
         },
         Command::ConsumerGroup(command) => match command {
             ConsumerGroupAction::Create(create_args) => Box::new(CreateConsumerGroupCmd::new(
@@ -271,6 +287,32 @@ fn get_command(
                 list_args.list_mode.into(),
             )),
         },
+        Command::Schema(command) => match command {
+            SchemaAction::Register(register_args) => Box::new(RegisterSchemaCmd::new(
+                register_args.stream_id.clone(),
+                register_args.topic_id.clone(),
+                register_args.format,
+                register_args.definition_file.clone(),
+            )),
+            SchemaAction::Get(get_args) => Box::new(GetSchemaCmd::new(get_args.schema_id)),
+            SchemaAction::List(list_args) => Box::new(GetSchemasCmd::new(
+                list_args.stream_id.clone(),
+                list_args.topic_id.clone(),
+                list_args.list_mode.into(),
+            )),
+            SchemaAction::Compatibility(compatibility_args) => {
+                Box::new(UpdateSchemaCompatibilityCmd::new(
+                    compatibility_args.stream_id.clone(),
+                    compatibility_args.topic_id.clone(),
+                    compatibility_args.compatibility,
+                ))
+            }
+            SchemaAction::Validation(validation_args) => Box::new(UpdateSchemaValidationCmd::new(
+                validation_args.stream_id.clone(),
+                validation_args.topic_id.clone(),
+                validation_args.validation(),
+            )),
+        },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
                 send_args.stream_id.clone(),
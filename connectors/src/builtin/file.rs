@@ -0,0 +1,226 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::builtin::OutputFormat;
+use crate::error::ConnectorError;
+use crate::sink::{Sink, SinkRecord};
+use crate::source::{Source, SourceRecord};
+use async_trait::async_trait;
+use iggy::utils::duration::IggyDuration;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+use std::path::PathBuf;
+use std::str::FromStr;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileSourceConfig {
+    pub path: PathBuf,
+    /// Whether to keep reading the lines appended to the file, instead of finishing at its end.
+    #[serde(default)]
+    pub follow: bool,
+    /// How often to check for the new lines when following the file.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval: IggyDuration,
+    /// The maximum number of the lines returned by the single poll.
+    #[serde(default = "default_max_lines")]
+    pub max_lines: usize,
+}
+
+/// Reads the file line by line, each line being the payload of the record.
+#[derive(Debug)]
+pub struct FileSource {
+    config: FileSourceConfig,
+    reader: Option<BufReader<File>>,
+    /// The incomplete last line of the followed file.
+    pending: String,
+}
+
+impl FileSource {
+    pub fn new(config: FileSourceConfig) -> Self {
+        Self {
+            config,
+            reader: None,
+            pending: String::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Source for FileSource {
+    async fn open(&mut self) -> Result<(), ConnectorError> {
+        let file = File::open(&self.config.path).await?;
+        self.reader = Some(BufReader::new(file));
+        Ok(())
+    }
+
+    async fn poll(&mut self) -> Result<Option<Vec<SourceRecord>>, ConnectorError> {
+        let reader = self.reader.as_mut().ok_or_else(|| {
+            ConnectorError::Connector("file source hasn't been opened".to_owned())
+        })?;
+        let mut records = Vec::new();
+        while records.len() < self.config.max_lines {
+            if reader.read_line(&mut self.pending).await? == 0 {
+                break;
+            }
+            if self.pending.ends_with('\n') {
+                let line = std::mem::take(&mut self.pending);
+                let line = line.trim_end_matches(['\n', '\r']);
+                records.push(SourceRecord::new(line.to_owned()));
+            }
+        }
+
+        if !records.is_empty() {
+            return Ok(Some(records));
+        }
+        if self.config.follow {
+            tokio::time::sleep(self.config.poll_interval.get_duration()).await;
+            return Ok(Some(records));
+        }
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        let line = std::mem::take(&mut self.pending);
+        Ok(Some(vec![SourceRecord::new(line)]))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileSinkConfig {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub format: OutputFormat,
+}
+
+/// Appends the records to the file, one per line.
+#[derive(Debug)]
+pub struct FileSink {
+    config: FileSinkConfig,
+    file: Option<File>,
+}
+
+impl FileSink {
+    pub fn new(config: FileSinkConfig) -> Self {
+        Self { config, file: None }
+    }
+}
+
+#[async_trait]
+impl Sink for FileSink {
+    async fn open(&mut self) -> Result<(), ConnectorError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.config.path)
+            .await?;
+        self.file = Some(file);
+        Ok(())
+    }
+
+    async fn consume(&mut self, records: &[SinkRecord]) -> Result<(), ConnectorError> {
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| ConnectorError::Connector("file sink hasn't been opened".to_owned()))?;
+        let content = records
+            .iter()
+            .flat_map(|record| self.config.format.format(record))
+            .collect::<Vec<_>>();
+        file.write_all(&content).await?;
+        file.sync_data().await?;
+        Ok(())
+    }
+}
+
+fn default_poll_interval() -> IggyDuration {
+    IggyDuration::from_str("100ms").unwrap()
+}
+
+fn default_max_lines() -> usize {
+    1000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::header::{HeaderKey, HeaderValue};
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn file_source_should_read_lines_until_end_of_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("input.txt");
+        tokio::fs::write(&path, "first\r\nsecond\nthird")
+            .await
+            .unwrap();
+        let mut source = FileSource::new(FileSourceConfig {
+            path,
+            follow: false,
+            poll_interval: default_poll_interval(),
+            max_lines: 2,
+        });
+        source.open().await.unwrap();
+
+        let mut payloads = Vec::new();
+        while let Some(records) = source.poll().await.unwrap() {
+            assert!(records.len() <= 2);
+            payloads.extend(records.into_iter().map(|record| record.payload));
+        }
+
+        assert_eq!(payloads, ["first", "second", "third"]);
+    }
+
+    #[tokio::test]
+    async fn file_sink_should_append_records_in_selected_format() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("output.txt");
+        let record = SinkRecord {
+            stream: "connectors".to_owned(),
+            topic: "lines".to_owned(),
+            partition_id: 1,
+            offset: 7,
+            timestamp: 100,
+            id: 1,
+            headers: HashMap::from([(
+                HeaderKey::new("source").unwrap(),
+                HeaderValue::from_str("file").unwrap(),
+            )]),
+            payload: Bytes::from_static(b"hello"),
+        };
+        for format in [OutputFormat::Payload, OutputFormat::Json] {
+            let mut sink = FileSink::new(FileSinkConfig {
+                path: path.clone(),
+                format,
+            });
+            sink.open().await.unwrap();
+            sink.consume(std::slice::from_ref(&record)).await.unwrap();
+        }
+
+        let content = tokio::fs::read_to_string(&path).await.unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "hello");
+        let json = serde_json::from_str::<serde_json::Value>(lines[1]).unwrap();
+        assert_eq!(json["offset"], 7);
+        assert_eq!(json["headers"]["source"], "file");
+        assert_eq!(json["payload"], "hello");
+    }
+}
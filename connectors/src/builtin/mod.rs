@@ -0,0 +1,120 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! The built-in connectors, registered by [`ConnectorRegistry::with_builtin`](crate::ConnectorRegistry::with_builtin):
+//!
+//! - `file` source and sink, reading and appending the lines of the file,
+//! - `stdin` source and `stdout` sink, reading and writing the lines of the standard streams,
+//! - `http_webhook` source and sink, receiving and posting the HTTP requests.
+
+mod file;
+mod stdio;
+mod webhook;
+
+pub use file::{FileSink, FileSinkConfig, FileSource, FileSourceConfig};
+pub use stdio::{StdinSource, StdoutSink, StdoutSinkConfig};
+pub use webhook::{WebhookSink, WebhookSinkConfig, WebhookSource, WebhookSourceConfig};
+
+use crate::config::parse_config;
+use crate::registry::ConnectorRegistry;
+use crate::sink::SinkRecord;
+use iggy::utils::text::as_base64;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How the records are written by the sinks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// The payload of the record followed by the new line.
+    #[default]
+    Payload,
+    /// The record with its metadata and headers as the JSON object followed by the new line.
+    Json,
+}
+
+/// The JSON representation of the record, with the UTF-8 payload written as is, and the binary one encoded in Base64.
+#[derive(Debug, Serialize)]
+pub(crate) struct JsonRecord<'a> {
+    stream: &'a str,
+    topic: &'a str,
+    partition_id: u32,
+    offset: u64,
+    timestamp: u64,
+    id: String,
+    headers: BTreeMap<&'a str, String>,
+    payload: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payload_encoding: Option<&'static str>,
+}
+
+impl<'a> From<&'a SinkRecord> for JsonRecord<'a> {
+    fn from(record: &'a SinkRecord) -> Self {
+        let (payload, payload_encoding) = match std::str::from_utf8(&record.payload) {
+            Ok(payload) => (payload.to_owned(), None),
+            Err(_) => (as_base64(&record.payload), Some("base64")),
+        };
+        Self {
+            stream: &record.stream,
+            topic: &record.topic,
+            partition_id: record.partition_id,
+            offset: record.offset,
+            timestamp: record.timestamp,
+            id: record.id.to_string(),
+            headers: record
+                .headers
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.value_only_to_string()))
+                .collect(),
+            payload,
+            payload_encoding,
+        }
+    }
+}
+
+impl OutputFormat {
+    pub(crate) fn format(&self, record: &SinkRecord) -> Vec<u8> {
+        let mut line = match self {
+            OutputFormat::Payload => record.payload.to_vec(),
+            OutputFormat::Json => serde_json::to_vec(&JsonRecord::from(record))
+                .expect("JSON record should be serializable"),
+        };
+        line.push(b'\n');
+        line
+    }
+}
+
+pub(crate) fn register(registry: &mut ConnectorRegistry) {
+    registry
+        .register_source("file", |config| {
+            Ok(Box::new(FileSource::new(parse_config(config)?)))
+        })
+        .register_source("stdin", |_| Ok(Box::new(StdinSource::new())))
+        .register_source("http_webhook", |config| {
+            Ok(Box::new(WebhookSource::new(parse_config(config)?)))
+        })
+        .register_sink("file", |config| {
+            Ok(Box::new(FileSink::new(parse_config(config)?)))
+        })
+        .register_sink("stdout", |config| {
+            Ok(Box::new(StdoutSink::new(parse_config(config)?)))
+        })
+        .register_sink("http_webhook", |config| {
+            Ok(Box::new(WebhookSink::new(parse_config(config)?)))
+        });
+}
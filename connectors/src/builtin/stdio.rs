@@ -0,0 +1,85 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::builtin::OutputFormat;
+use crate::error::ConnectorError;
+use crate::sink::{Sink, SinkRecord};
+use crate::source::{Source, SourceRecord};
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines, Stdin};
+
+/// Reads the standard input line by line, each line being the payload of the record.
+#[derive(Debug)]
+pub struct StdinSource {
+    lines: Lines<BufReader<Stdin>>,
+}
+
+impl StdinSource {
+    pub fn new() -> Self {
+        Self {
+            lines: BufReader::new(tokio::io::stdin()).lines(),
+        }
+    }
+}
+
+impl Default for StdinSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Source for StdinSource {
+    async fn poll(&mut self) -> Result<Option<Vec<SourceRecord>>, ConnectorError> {
+        let line = self.lines.next_line().await?;
+        Ok(line.map(|line| vec![SourceRecord::new(line)]))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StdoutSinkConfig {
+    #[serde(default)]
+    pub format: OutputFormat,
+}
+
+/// Writes the records to the standard output, one per line.
+#[derive(Debug)]
+pub struct StdoutSink {
+    config: StdoutSinkConfig,
+}
+
+impl StdoutSink {
+    pub fn new(config: StdoutSinkConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl Sink for StdoutSink {
+    async fn consume(&mut self, records: &[SinkRecord]) -> Result<(), ConnectorError> {
+        let content = records
+            .iter()
+            .flat_map(|record| self.config.format.format(record))
+            .collect::<Vec<_>>();
+        let mut stdout = tokio::io::stdout();
+        stdout.write_all(&content).await?;
+        stdout.flush().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::builtin::JsonRecord;
+use crate::error::ConnectorError;
+use crate::sink::{Sink, SinkRecord};
+use crate::source::{Source, SourceRecord};
+use async_trait::async_trait;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::duration::IggyDuration;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+const CONTENT_TYPE_HEADER: &str = "content-type";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookSourceConfig {
+    /// The address the HTTP listener is bound to.
+    pub address: String,
+    /// The path accepting the `POST` requests.
+    #[serde(default = "default_path")]
+    pub path: String,
+    /// The HTTP header, which value is used as the partitioning key of the record.
+    pub key_header: Option<String>,
+    /// The maximum number of the accepted requests waiting to be appended to the topic.
+    #[serde(default = "default_buffer_size")]
+    pub buffer_size: usize,
+    /// The maximum number of the records returned by the single poll.
+    #[serde(default = "default_max_records")]
+    pub max_records: usize,
+}
+
+/// Accepts the HTTP `POST` requests, each request body being the payload of the record.
+///
+/// The request is answered with `202 Accepted` once it's buffered, before it's appended to the topic,
+/// so the buffered requests are lost if the runtime stops abruptly.
+#[derive(Debug)]
+pub struct WebhookSource {
+    config: WebhookSourceConfig,
+    receiver: Option<mpsc::Receiver<SourceRecord>>,
+    local_addr: Option<SocketAddr>,
+    shutdown: CancellationToken,
+}
+
+#[derive(Debug, Clone)]
+struct WebhookState {
+    sender: mpsc::Sender<SourceRecord>,
+    key_header: Option<String>,
+}
+
+impl WebhookSource {
+    pub fn new(config: WebhookSourceConfig) -> Self {
+        Self {
+            config,
+            receiver: None,
+            local_addr: None,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    /// Returns the address the HTTP listener is bound to, once the source is opened.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+}
+
+#[async_trait]
+impl Source for WebhookSource {
+    async fn open(&mut self) -> Result<(), ConnectorError> {
+        let listener = TcpListener::bind(&self.config.address).await?;
+        let local_addr = listener.local_addr()?;
+        let (sender, receiver) = mpsc::channel(self.config.buffer_size.max(1));
+        let state = WebhookState {
+            sender,
+            key_header: self.config.key_header.clone(),
+        };
+        let router = Router::new()
+            .route(&self.config.path, post(accept))
+            .with_state(state);
+        let shutdown = self.shutdown.clone();
+        tokio::spawn(async move {
+            if let Err(error) = axum::serve(listener, router)
+                .with_graceful_shutdown(shutdown.cancelled_owned())
+                .await
+            {
+                error!("Webhook source listener on: {local_addr} has failed. {error}");
+            }
+        });
+
+        info!("Webhook source is listening on: {local_addr}");
+        self.receiver = Some(receiver);
+        self.local_addr = Some(local_addr);
+        Ok(())
+    }
+
+    async fn poll(&mut self) -> Result<Option<Vec<SourceRecord>>, ConnectorError> {
+        let receiver = self.receiver.as_mut().ok_or_else(|| {
+            ConnectorError::Connector("webhook source hasn't been opened".to_owned())
+        })?;
+        let Some(record) = receiver.recv().await else {
+            return Ok(None);
+        };
+
+        let mut records = vec![record];
+        while records.len() < self.config.max_records {
+            match receiver.try_recv() {
+                Ok(record) => records.push(record),
+                Err(_) => break,
+            }
+        }
+        Ok(Some(records))
+    }
+
+    async fn close(&mut self) -> Result<(), ConnectorError> {
+        self.shutdown.cancel();
+        Ok(())
+    }
+}
+
+async fn accept(State(state): State<WebhookState>, headers: HeaderMap, body: Bytes) -> StatusCode {
+    if body.is_empty() {
+        return StatusCode::BAD_REQUEST;
+    }
+
+    let mut record = SourceRecord::new(body);
+    if let Some(key_header) = &state.key_header {
+        if let Some(key) = headers.get(key_header) {
+            record = record.with_key(Bytes::copy_from_slice(key.as_bytes()));
+        }
+    }
+    if let Some(content_type) = headers
+        .get(CONTENT_TYPE_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        if let (Ok(key), Ok(value)) = (
+            HeaderKey::new(CONTENT_TYPE_HEADER),
+            HeaderValue::from_str(content_type),
+        ) {
+            record = record.with_header(key, value);
+        }
+    }
+
+    match state.sender.send(record).await {
+        Ok(()) => StatusCode::ACCEPTED,
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookSinkConfig {
+    /// The URL the records are posted to.
+    pub url: String,
+    /// The additional headers of the requests, e.g. the authorization ones.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default = "default_timeout")]
+    pub timeout: IggyDuration,
+}
+
+/// Posts each batch of the records as the JSON array, failing on the non-successful responses.
+#[derive(Debug)]
+pub struct WebhookSink {
+    config: WebhookSinkConfig,
+    client: Option<reqwest::Client>,
+}
+
+impl WebhookSink {
+    pub fn new(config: WebhookSinkConfig) -> Self {
+        Self {
+            config,
+            client: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Sink for WebhookSink {
+    async fn open(&mut self) -> Result<(), ConnectorError> {
+        let client = reqwest::Client::builder()
+            .timeout(self.config.timeout.get_duration())
+            .build()
+            .map_err(|error| ConnectorError::Http(error.to_string()))?;
+        self.client = Some(client);
+        Ok(())
+    }
+
+    async fn consume(&mut self, records: &[SinkRecord]) -> Result<(), ConnectorError> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            ConnectorError::Connector("webhook sink hasn't been opened".to_owned())
+        })?;
+        let records = records.iter().map(JsonRecord::from).collect::<Vec<_>>();
+        let mut request = client.post(&self.config.url).json(&records);
+        for (name, value) in &self.config.headers {
+            request = request.header(name, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|error| ConnectorError::Http(error.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ConnectorError::Http(format!(
+                "webhook: {} has responded with status: {}",
+                self.config.url,
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn default_path() -> String {
+    "/".to_owned()
+}
+
+fn default_buffer_size() -> usize {
+    1000
+}
+
+fn default_max_records() -> usize {
+    100
+}
+
+fn default_timeout() -> IggyDuration {
+    IggyDuration::from_str("10s").unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn webhook_sink_should_post_records_to_webhook_source() {
+        let mut source = WebhookSource::new(WebhookSourceConfig {
+            address: "127.0.0.1:0".to_owned(),
+            path: "/records".to_owned(),
+            key_header: Some("x-key".to_owned()),
+            buffer_size: default_buffer_size(),
+            max_records: default_max_records(),
+        });
+        source.open().await.unwrap();
+        let url = format!("http://{}/records", source.local_addr().unwrap());
+        let mut sink = WebhookSink::new(WebhookSinkConfig {
+            url,
+            headers: HashMap::from([("x-key".to_owned(), "device-1".to_owned())]),
+            timeout: default_timeout(),
+        });
+        sink.open().await.unwrap();
+
+        let record = SinkRecord {
+            stream: "connectors".to_owned(),
+            topic: "events".to_owned(),
+            partition_id: 1,
+            offset: 0,
+            timestamp: 100,
+            id: 1,
+            headers: HashMap::new(),
+            payload: Bytes::from_static(&[0xff, 0x00]),
+        };
+        sink.consume(&[record]).await.unwrap();
+
+        let records = source.poll().await.unwrap().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].key.as_deref(), Some(b"device-1".as_slice()));
+        let content_type = records[0]
+            .headers
+            .get(&HeaderKey::new(CONTENT_TYPE_HEADER).unwrap())
+            .unwrap();
+        assert_eq!(content_type.as_str().unwrap(), "application/json");
+        let json = serde_json::from_slice::<serde_json::Value>(&records[0].payload).unwrap();
+        assert_eq!(json[0]["topic"], "events");
+        assert_eq!(json[0]["payload"], "/wA=");
+        assert_eq!(json[0]["payload_encoding"], "base64");
+        source.close().await.unwrap();
+    }
+}
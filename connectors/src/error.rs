@@ -0,0 +1,38 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConnectorError {
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("Unknown source type: {0}")]
+    UnknownSourceType(String),
+    #[error("Unknown sink type: {0}")]
+    UnknownSinkType(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Iggy error: {0}")]
+    Iggy(#[from] IggyError),
+    #[error("HTTP error: {0}")]
+    Http(String),
+    #[error("Connector error: {0}")]
+    Connector(String),
+}
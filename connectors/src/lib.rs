@@ -0,0 +1,86 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! The connectors runtime, moving the data between Iggy and the external systems.
+//!
+//! The [`Source`] connectors produce the records appended to the Iggy topics, while the [`Sink`] connectors consume
+//! the messages polled from the Iggy topics by the consumer groups, with the offsets committed once the batch
+//! has been written. The connectors are created by the factories registered in the [`ConnectorRegistry`]
+//! under their type names and configured in the `[[sources]]` and `[[sinks]]` sections of the TOML file.
+//!
+//! The custom connectors are implemented against the same traits and registered next to the built-in ones:
+//!
+//! ```no_run
+//! use async_trait::async_trait;
+//! use iggy_connectors::{parse_config, ConnectorError, ConnectorRegistry, Runtime, RuntimeConfig, Sink, SinkRecord};
+//! use serde::Deserialize;
+//! use tokio_util::sync::CancellationToken;
+//!
+//! #[derive(Deserialize)]
+//! struct CountingSinkConfig {
+//!     prefix: String,
+//! }
+//!
+//! struct CountingSink {
+//!     config: CountingSinkConfig,
+//!     count: usize,
+//! }
+//!
+//! #[async_trait]
+//! impl Sink for CountingSink {
+//!     async fn consume(&mut self, records: &[SinkRecord]) -> Result<(), ConnectorError> {
+//!         self.count += records.len();
+//!         println!("{}: {}", self.config.prefix, self.count);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # async fn run() -> Result<(), ConnectorError> {
+//! let mut registry = ConnectorRegistry::with_builtin();
+//! registry.register_sink("counting", |config| {
+//!     Ok(Box::new(CountingSink {
+//!         config: parse_config(config)?,
+//!         count: 0,
+//!     }))
+//! });
+//! let config = RuntimeConfig::load("connectors.toml")?;
+//! Runtime::new(config, registry).run(CancellationToken::new()).await
+//! # }
+//! ```
+
+pub mod builtin;
+mod config;
+mod error;
+mod registry;
+mod runtime;
+mod sink;
+mod source;
+
+pub use config::{
+    parse_config, DeadLetterConfig, IggyConfig, RetryConfig, RuntimeConfig, SinkConfig,
+    SourceConfig,
+};
+pub use error::ConnectorError;
+pub use registry::{ConnectorRegistry, SinkFactory, SourceFactory};
+pub use runtime::Runtime;
+pub use sink::{
+    Sink, SinkRecord, DEAD_LETTER_CONNECTOR_HEADER, DEAD_LETTER_ERROR_HEADER,
+    DEAD_LETTER_OFFSET_HEADER, DEAD_LETTER_PARTITION_ID_HEADER, DEAD_LETTER_STREAM_HEADER,
+    DEAD_LETTER_TOPIC_HEADER,
+};
+pub use source::{Source, SourceRecord};
@@ -0,0 +1,70 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use anyhow::Result;
+use clap::Parser;
+use iggy_connectors::{ConnectorRegistry, Runtime, RuntimeConfig};
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct ConnectorsArgs {
+    /// The path to the TOML configuration of the connectors.
+    #[arg(
+        long,
+        env = "IGGY_CONNECTORS_CONFIG_PATH",
+        default_value = "configs/connectors.toml"
+    )]
+    config: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = ConnectorsArgs::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+
+    let config = RuntimeConfig::load(&args.config)?;
+    info!(
+        "Loaded {} sources and {} sinks from: {}",
+        config.sources.len(),
+        config.sinks.len(),
+        args.config.display()
+    );
+    let shutdown = CancellationToken::new();
+    let ctrl_c_shutdown = shutdown.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            info!("Stopping the connectors...");
+            ctrl_c_shutdown.cancel();
+        }
+    });
+
+    Runtime::new(config, ConnectorRegistry::with_builtin())
+        .run(shutdown)
+        .await?;
+    info!("Connectors runtime has stopped.");
+    Ok(())
+}
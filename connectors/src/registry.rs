@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::builtin;
+use crate::error::ConnectorError;
+use crate::sink::Sink;
+use crate::source::Source;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+/// Creates the source from the `config` table of its configuration.
+pub type SourceFactory =
+    Box<dyn Fn(&toml::Table) -> Result<Box<dyn Source>, ConnectorError> + Send + Sync>;
+/// Creates the sink from the `config` table of its configuration.
+pub type SinkFactory =
+    Box<dyn Fn(&toml::Table) -> Result<Box<dyn Sink>, ConnectorError> + Send + Sync>;
+
+/// The factories of the connectors by their type names.
+#[derive(Default)]
+pub struct ConnectorRegistry {
+    sources: HashMap<String, SourceFactory>,
+    sinks: HashMap<String, SinkFactory>,
+}
+
+impl ConnectorRegistry {
+    /// Creates the empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the registry with the built-in connectors.
+    pub fn with_builtin() -> Self {
+        let mut registry = Self::new();
+        builtin::register(&mut registry);
+        registry
+    }
+
+    /// Registers the source factory, replacing the existing one with the same type name.
+    pub fn register_source<F>(&mut self, kind: &str, factory: F) -> &mut Self
+    where
+        F: Fn(&toml::Table) -> Result<Box<dyn Source>, ConnectorError> + Send + Sync + 'static,
+    {
+        self.sources.insert(kind.to_owned(), Box::new(factory));
+        self
+    }
+
+    /// Registers the sink factory, replacing the existing one with the same type name.
+    pub fn register_sink<F>(&mut self, kind: &str, factory: F) -> &mut Self
+    where
+        F: Fn(&toml::Table) -> Result<Box<dyn Sink>, ConnectorError> + Send + Sync + 'static,
+    {
+        self.sinks.insert(kind.to_owned(), Box::new(factory));
+        self
+    }
+
+    pub(crate) fn create_source(
+        &self,
+        kind: &str,
+        config: &toml::Table,
+    ) -> Result<Box<dyn Source>, ConnectorError> {
+        let factory = self
+            .sources
+            .get(kind)
+            .ok_or_else(|| ConnectorError::UnknownSourceType(kind.to_owned()))?;
+        factory(config)
+    }
+
+    pub(crate) fn create_sink(
+        &self,
+        kind: &str,
+        config: &toml::Table,
+    ) -> Result<Box<dyn Sink>, ConnectorError> {
+        let factory = self
+            .sinks
+            .get(kind)
+            .ok_or_else(|| ConnectorError::UnknownSinkType(kind.to_owned()))?;
+        factory(config)
+    }
+}
+
+impl Debug for ConnectorRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectorRegistry")
+            .field("sources", &self.sources.keys())
+            .field("sinks", &self.sinks.keys())
+            .finish()
+    }
+}
@@ -0,0 +1,160 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod sink;
+mod source;
+
+use crate::config::{RetryConfig, RuntimeConfig};
+use crate::error::ConnectorError;
+use crate::registry::ConnectorRegistry;
+use iggy::client::Client;
+use iggy::clients::client::IggyClient;
+use std::future::Future;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+/// Runs the configured connectors, each one with its own Iggy client.
+#[derive(Debug)]
+pub struct Runtime {
+    config: RuntimeConfig,
+    registry: ConnectorRegistry,
+}
+
+impl Runtime {
+    pub fn new(config: RuntimeConfig, registry: ConnectorRegistry) -> Self {
+        Self { config, registry }
+    }
+
+    /// Runs the enabled connectors until all of them are finished, or until the shutdown is requested.
+    /// The failed connector is stopped without affecting the other ones, and the first error is returned at the end.
+    pub async fn run(self, shutdown: CancellationToken) -> Result<(), ConnectorError> {
+        // All the connectors are created before starting any of them, so that the invalid configuration is reported upfront.
+        let sources = self
+            .config
+            .sources
+            .into_iter()
+            .filter(|source| source.enabled)
+            .map(|config| {
+                let source = self.registry.create_source(&config.kind, &config.config)?;
+                Ok((config, source))
+            })
+            .collect::<Result<Vec<_>, ConnectorError>>()?;
+        let sinks = self
+            .config
+            .sinks
+            .into_iter()
+            .filter(|sink| sink.enabled)
+            .map(|config| {
+                let sink = self.registry.create_sink(&config.kind, &config.config)?;
+                Ok((config, sink))
+            })
+            .collect::<Result<Vec<_>, ConnectorError>>()?;
+
+        let mut connectors = JoinSet::new();
+        for (config, source) in sources {
+            let client = connect(&self.config.iggy.connection_string).await?;
+            info!(
+                "Starting source: {} of type: {}...",
+                config.name, config.kind
+            );
+            let name = config.name.clone();
+            let shutdown = shutdown.clone();
+            connectors.spawn(async move {
+                let result = source::run(config, source, client, shutdown).await;
+                (name, result)
+            });
+        }
+        for (config, sink) in sinks {
+            let client = connect(&self.config.iggy.connection_string).await?;
+            info!("Starting sink: {} of type: {}...", config.name, config.kind);
+            let name = config.name.clone();
+            let shutdown = shutdown.clone();
+            connectors.spawn(async move {
+                let result = sink::run(config, sink, client, shutdown).await;
+                (name, result)
+            });
+        }
+
+        let mut first_error = None;
+        while let Some(result) = connectors.join_next().await {
+            let (name, result) = result.map_err(|error| {
+                ConnectorError::Connector(format!("connector task has panicked: {error}"))
+            })?;
+            match result {
+                Ok(()) => info!("Connector: {name} has stopped."),
+                Err(error) => {
+                    error!("Connector: {name} has failed. {error}");
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+async fn connect(connection_string: &str) -> Result<IggyClient, ConnectorError> {
+    let client = IggyClient::from_connection_string(connection_string)?;
+    client.connect().await?;
+    Ok(client)
+}
+
+/// Retries the operation with the configured interval, until it succeeds, the retries are exhausted
+/// or the shutdown is requested, in which case `None` is returned.
+async fn retry<T, F, Fut>(
+    description: &str,
+    config: &RetryConfig,
+    shutdown: &CancellationToken,
+    mut operation: F,
+) -> Option<Result<T, ConnectorError>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ConnectorError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Some(Ok(value)),
+            Err(error) if attempt >= config.max_retries => return Some(Err(error)),
+            Err(error) => {
+                attempt += 1;
+                if !wait_before_retry(description, config, attempt, &error, shutdown).await {
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Waits for the configured interval before the next attempt, returning `false` if the shutdown is requested in the meantime.
+async fn wait_before_retry(
+    description: &str,
+    config: &RetryConfig,
+    attempt: u32,
+    error: &ConnectorError,
+    shutdown: &CancellationToken,
+) -> bool {
+    warn!(
+        "Failed to {description}, retrying in {} ({attempt}/{})... {error}",
+        config.interval, config.max_retries
+    );
+    tokio::select! {
+        _ = shutdown.cancelled() => false,
+        _ = tokio::time::sleep(config.interval.get_duration()) => true,
+    }
+}
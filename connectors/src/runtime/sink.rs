@@ -0,0 +1,280 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::config::SinkConfig;
+use crate::error::ConnectorError;
+use crate::runtime::{retry, wait_before_retry};
+use crate::sink::{
+    Sink, SinkRecord, DEAD_LETTER_CONNECTOR_HEADER, DEAD_LETTER_ERROR_HEADER,
+    DEAD_LETTER_OFFSET_HEADER, DEAD_LETTER_PARTITION_ID_HEADER, DEAD_LETTER_STREAM_HEADER,
+    DEAD_LETTER_TOPIC_HEADER,
+};
+use iggy::client::{ConsumerGroupClient, ConsumerOffsetClient, MessageClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::PolledMessages;
+use std::str::FromStr;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+const MAX_HEADER_VALUE_LENGTH: usize = 255;
+
+/// Consumes the messages of the topic by the consumer group and commits their offsets once they're consumed by the sink,
+/// or sent to the dead letter topic, until the shutdown is requested.
+pub(crate) async fn run(
+    config: SinkConfig,
+    mut sink: Box<dyn Sink>,
+    client: IggyClient,
+    shutdown: CancellationToken,
+) -> Result<(), ConnectorError> {
+    let stream_id = Identifier::from_str_value(&config.stream)?;
+    let topic_id = Identifier::from_str_value(&config.topic)?;
+    let group_name = config.consumer_group.as_deref().unwrap_or(&config.name);
+    let group_id = Identifier::named(group_name)?;
+    join_group(&client, &stream_id, &topic_id, &group_id, group_name).await?;
+    info!(
+        "Sink: {} has joined consumer group: {group_name}, stream: {}, topic: {}.",
+        config.name, config.stream, config.topic
+    );
+
+    let result = match sink.open().await {
+        Ok(()) => {
+            let result = consume(&config, sink.as_mut(), &client, &group_id, &shutdown).await;
+            if let Err(error) = sink.close().await {
+                warn!("Failed to close sink: {}. {error}", config.name);
+            }
+            result
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = client
+        .leave_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+    {
+        warn!(
+            "Failed to leave consumer group: {group_name} by sink: {}. {error}",
+            config.name
+        );
+    }
+    result
+}
+
+async fn join_group(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    group_id: &Identifier,
+    group_name: &str,
+) -> Result<(), ConnectorError> {
+    match client
+        .join_consumer_group(stream_id, topic_id, group_id)
+        .await
+    {
+        Err(IggyError::ConsumerGroupNameNotFound(_, _)) => {
+            match client
+                .create_consumer_group(stream_id, topic_id, group_name, None)
+                .await
+            {
+                Ok(_) | Err(IggyError::ConsumerGroupNameAlreadyExists(_, _)) => {}
+                Err(error) => return Err(error.into()),
+            }
+            client
+                .join_consumer_group(stream_id, topic_id, group_id)
+                .await?;
+            Ok(())
+        }
+        result => Ok(result?),
+    }
+}
+
+async fn consume(
+    config: &SinkConfig,
+    sink: &mut dyn Sink,
+    client: &IggyClient,
+    group_id: &Identifier,
+    shutdown: &CancellationToken,
+) -> Result<(), ConnectorError> {
+    let stream_id = Identifier::from_str_value(&config.stream)?;
+    let topic_id = Identifier::from_str_value(&config.topic)?;
+    let consumer = Consumer::group(group_id.clone());
+    // The polling isn't cancelled on shutdown, as it's bounded by the max wait anyway,
+    // and the cancelled request could leave its response pending on the connection.
+    while !shutdown.is_cancelled() {
+        let description = format!("poll messages of sink: {}", config.name);
+        let polled_messages = retry(&description, &config.retry, shutdown, || async {
+            client
+                .poll_messages_with_wait(
+                    &stream_id,
+                    &topic_id,
+                    None,
+                    &consumer,
+                    &PollingStrategy::next(),
+                    config.batch_size,
+                    false,
+                    config.max_wait,
+                )
+                .await
+                .map_err(ConnectorError::from)
+        })
+        .await;
+        let polled_messages = match polled_messages {
+            Some(result) => result?,
+            None => return Ok(()),
+        };
+        let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        else {
+            continue;
+        };
+
+        let partition_id = polled_messages.partition_id;
+        let records = to_records(config, polled_messages);
+        let description = format!("consume messages by sink: {}", config.name);
+        let mut attempt = 0;
+        let consumed = loop {
+            match sink.consume(&records).await {
+                Ok(()) => break Ok(()),
+                Err(error) if attempt >= config.retry.max_retries => break Err(error),
+                Err(error) => {
+                    attempt += 1;
+                    // The offset isn't committed, so the messages are consumed again once the sink is restarted.
+                    if !wait_before_retry(&description, &config.retry, attempt, &error, shutdown)
+                        .await
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+        };
+        if let Err(error) = consumed {
+            send_to_dead_letter(config, client, &records, error).await?;
+        }
+
+        debug!(
+            "Committing offset: {last_offset} of partition: {partition_id} by sink: {}.",
+            config.name
+        );
+        client
+            .store_consumer_offset(
+                &consumer,
+                &stream_id,
+                &topic_id,
+                Some(partition_id),
+                last_offset,
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+fn to_records(config: &SinkConfig, polled_messages: PolledMessages) -> Vec<SinkRecord> {
+    polled_messages
+        .messages
+        .into_iter()
+        .map(|message| SinkRecord {
+            stream: config.stream.clone(),
+            topic: config.topic.clone(),
+            partition_id: polled_messages.partition_id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            id: message.id,
+            headers: message.headers.unwrap_or_default(),
+            payload: message.payload,
+        })
+        .collect()
+}
+
+/// Sends the records which couldn't be consumed to the dead letter topic, if it's configured,
+/// otherwise returns the error stopping the sink.
+async fn send_to_dead_letter(
+    config: &SinkConfig,
+    client: &IggyClient,
+    records: &[SinkRecord],
+    error: ConnectorError,
+) -> Result<(), ConnectorError> {
+    let Some(dead_letter) = &config.dead_letter else {
+        return Err(error);
+    };
+
+    warn!(
+        "Sending {} messages which couldn't be consumed by sink: {} to dead letter topic: {}, stream: {}. {error}",
+        records.len(),
+        config.name,
+        dead_letter.topic,
+        dead_letter.stream
+    );
+    let error = to_error_header(&error);
+    let mut messages = records
+        .iter()
+        .map(|record| {
+            let mut headers = record.headers.clone();
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_CONNECTOR_HEADER)?,
+                HeaderValue::from_str(&config.name)?,
+            );
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_ERROR_HEADER)?,
+                HeaderValue::from_str(&error)?,
+            );
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_STREAM_HEADER)?,
+                HeaderValue::from_str(&record.stream)?,
+            );
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_TOPIC_HEADER)?,
+                HeaderValue::from_str(&record.topic)?,
+            );
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_PARTITION_ID_HEADER)?,
+                HeaderValue::from_uint32(record.partition_id)?,
+            );
+            headers.insert(
+                HeaderKey::new(DEAD_LETTER_OFFSET_HEADER)?,
+                HeaderValue::from_uint64(record.offset)?,
+            );
+            Ok(Message::new(None, record.payload.clone(), Some(headers)))
+        })
+        .collect::<Result<Vec<_>, IggyError>>()?;
+    client
+        .send_messages(
+            &Identifier::from_str_value(&dead_letter.stream)?,
+            &Identifier::from_str_value(&dead_letter.topic)?,
+            &Partitioning::balanced(),
+            &mut messages,
+        )
+        .await?;
+    Ok(())
+}
+
+/// The header values are limited in length, so the longer error messages are truncated.
+fn to_error_header(error: &ConnectorError) -> String {
+    let mut error = error.to_string();
+    let mut length = error.len().min(MAX_HEADER_VALUE_LENGTH);
+    while !error.is_char_boundary(length) {
+        length -= 1;
+    }
+    error.truncate(length);
+    error
+}
@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::config::SourceConfig;
+use crate::error::ConnectorError;
+use crate::runtime::retry;
+use crate::source::{Source, SourceRecord};
+use iggy::client::MessageClient;
+use iggy::clients::client::IggyClient;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+/// Appends the records produced by the source to the topic until the source is exhausted or the shutdown is requested.
+pub(crate) async fn run(
+    config: SourceConfig,
+    mut source: Box<dyn Source>,
+    client: IggyClient,
+    shutdown: CancellationToken,
+) -> Result<(), ConnectorError> {
+    source.open().await?;
+    let result = produce(&config, source.as_mut(), &client, &shutdown).await;
+    if let Err(error) = source.close().await {
+        warn!("Failed to close source: {}. {error}", config.name);
+    }
+    result
+}
+
+async fn produce(
+    config: &SourceConfig,
+    source: &mut dyn Source,
+    client: &IggyClient,
+    shutdown: &CancellationToken,
+) -> Result<(), ConnectorError> {
+    let stream_id = Identifier::from_str_value(&config.stream)?;
+    let topic_id = Identifier::from_str_value(&config.topic)?;
+    loop {
+        let records = tokio::select! {
+            _ = shutdown.cancelled() => return Ok(()),
+            records = source.poll() => records?,
+        };
+        let Some(records) = records else {
+            debug!("Source: {} has been exhausted.", config.name);
+            return Ok(());
+        };
+
+        for batch in records.chunks(config.batch_size as usize) {
+            // The records with the different keys are appended separately, preserving their order.
+            for records in batch.chunk_by(|first, second| first.key == second.key) {
+                let partitioning = match &records[0].key {
+                    Some(key) => Partitioning::messages_key(key)?,
+                    None => Partitioning::balanced(),
+                };
+                let description = format!("send messages of source: {}", config.name);
+                let sent = retry(&description, &config.retry, shutdown, || async {
+                    let mut messages = records.iter().map(to_message).collect::<Vec<_>>();
+                    client
+                        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+                        .await
+                        .map_err(ConnectorError::from)
+                })
+                .await;
+                match sent {
+                    Some(result) => result?,
+                    None => return Ok(()),
+                }
+            }
+        }
+        source.commit().await?;
+    }
+}
+
+fn to_message(record: &SourceRecord) -> Message {
+    let headers = (!record.headers.is_empty()).then(|| record.headers.clone());
+    Message::new(None, record.payload.clone(), headers)
+}
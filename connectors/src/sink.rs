@@ -0,0 +1,65 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::ConnectorError;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+/// The name of the sink which failed to consume the message sent to the dead letter topic.
+pub const DEAD_LETTER_CONNECTOR_HEADER: &str = "iggy-connector";
+/// The error returned by the sink for the message sent to the dead letter topic.
+pub const DEAD_LETTER_ERROR_HEADER: &str = "iggy-connector-error";
+/// The stream, the topic, the partition and the offset the message sent to the dead letter topic has been polled from.
+pub const DEAD_LETTER_STREAM_HEADER: &str = "iggy-source-stream";
+pub const DEAD_LETTER_TOPIC_HEADER: &str = "iggy-source-topic";
+pub const DEAD_LETTER_PARTITION_ID_HEADER: &str = "iggy-source-partition-id";
+pub const DEAD_LETTER_OFFSET_HEADER: &str = "iggy-source-offset";
+
+/// The message polled from the Iggy topic and passed to the sink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SinkRecord {
+    /// The stream and the topic as configured for the sink.
+    pub stream: String,
+    pub topic: String,
+    pub partition_id: u32,
+    pub offset: u64,
+    pub timestamp: u64,
+    pub id: u128,
+    pub headers: HashMap<HeaderKey, HeaderValue>,
+    pub payload: Bytes,
+}
+
+/// The connector writing the messages to the external system.
+///
+/// The runtime calls `open` once, then keeps calling `consume` with the batches of the polled messages
+/// until the runtime is stopped, and finally calls `close`. The offsets are committed only once the batch
+/// has been consumed, and the failed batches are retried, so the same records might be consumed more than once.
+#[async_trait]
+pub trait Sink: Send {
+    async fn open(&mut self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn consume(&mut self, records: &[SinkRecord]) -> Result<(), ConnectorError>;
+
+    async fn close(&mut self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::ConnectorError;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+/// The record produced by the source and appended to the Iggy topic as the message.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SourceRecord {
+    /// The optional partitioning key, the records with the same key are appended to the same partition.
+    /// The records without the key are balanced between the partitions.
+    pub key: Option<Bytes>,
+    pub headers: HashMap<HeaderKey, HeaderValue>,
+    pub payload: Bytes,
+}
+
+impl SourceRecord {
+    pub fn new(payload: impl Into<Bytes>) -> Self {
+        Self {
+            payload: payload.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_key(mut self, key: impl Into<Bytes>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn with_header(mut self, key: HeaderKey, value: HeaderValue) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+}
+
+/// The connector producing the records from the external system.
+///
+/// The runtime calls `open` once, then keeps calling `poll` and appending the returned records to the topic,
+/// followed by `commit`, until the source is exhausted or the runtime is stopped, and finally calls `close`.
+#[async_trait]
+pub trait Source: Send {
+    async fn open(&mut self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    /// Returns the next records, waiting for them if there are none available yet, or `None` if the source is exhausted.
+    /// The pending `poll` is cancelled when the runtime is stopped.
+    async fn poll(&mut self) -> Result<Option<Vec<SourceRecord>>, ConnectorError>;
+
+    /// Called once the records returned by the last `poll` have been appended to the topic,
+    /// e.g. to acknowledge them in the external system.
+    async fn commit(&mut self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+}
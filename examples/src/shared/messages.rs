@@ -53,6 +53,15 @@ impl Envelope {
     }
 }
 
+// With the typed producer and consumer, the enum tagged by the message type can replace the envelope.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "message_type", rename_all = "snake_case")]
+pub enum OrderEvent {
+    OrderCreated(OrderCreated),
+    OrderConfirmed(OrderConfirmed),
+    OrderRejected(OrderRejected),
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct OrderCreated {
     pub order_id: u64,
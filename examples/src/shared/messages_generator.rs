@@ -16,7 +16,9 @@
  * under the License.
  */
 
-use crate::shared::messages::{OrderConfirmed, OrderCreated, OrderRejected, SerializableMessage};
+use crate::shared::messages::{
+    OrderConfirmed, OrderCreated, OrderEvent, OrderRejected, SerializableMessage,
+};
 use iggy::utils::timestamp::IggyTimestamp;
 use rand::rngs::ThreadRng;
 use rand::Rng;
@@ -38,17 +40,25 @@ impl MessagesGenerator {
     }
 
     pub fn generate(&mut self) -> Box<dyn SerializableMessage> {
+        match self.generate_event() {
+            OrderEvent::OrderCreated(order_created) => Box::new(order_created),
+            OrderEvent::OrderConfirmed(order_confirmed) => Box::new(order_confirmed),
+            OrderEvent::OrderRejected(order_rejected) => Box::new(order_rejected),
+        }
+    }
+
+    pub fn generate_event(&mut self) -> OrderEvent {
         match self.rng.random_range(0..=2) {
-            0 => self.generate_order_created(),
-            1 => self.generate_order_confirmed(),
-            2 => self.generate_order_rejected(),
+            0 => OrderEvent::OrderCreated(self.generate_order_created()),
+            1 => OrderEvent::OrderConfirmed(self.generate_order_confirmed()),
+            2 => OrderEvent::OrderRejected(self.generate_order_rejected()),
             _ => panic!("Unexpected message type"),
         }
     }
 
-    fn generate_order_created(&mut self) -> Box<dyn SerializableMessage> {
+    fn generate_order_created(&mut self) -> OrderCreated {
         self.order_id += 1;
-        Box::new(OrderCreated {
+        OrderCreated {
             order_id: self.order_id,
             timestamp: IggyTimestamp::now(),
             currency_pair: CURRENCY_PAIRS[self.rng.random_range(0..CURRENCY_PAIRS.len())]
@@ -60,19 +70,19 @@ impl MessagesGenerator {
                 _ => "sell",
             }
             .to_string(),
-        })
+        }
     }
 
-    fn generate_order_confirmed(&mut self) -> Box<dyn SerializableMessage> {
-        Box::new(OrderConfirmed {
+    fn generate_order_confirmed(&mut self) -> OrderConfirmed {
+        OrderConfirmed {
             order_id: self.order_id,
             timestamp: IggyTimestamp::now(),
             price: self.rng.random_range(10.0..=1000.0),
-        })
+        }
     }
 
-    fn generate_order_rejected(&mut self) -> Box<dyn SerializableMessage> {
-        Box::new(OrderRejected {
+    fn generate_order_rejected(&mut self) -> OrderRejected {
+        OrderRejected {
             order_id: self.order_id,
             timestamp: IggyTimestamp::now(),
             reason: match self.rng.random_range(0..=1) {
@@ -80,6 +90,6 @@ impl MessagesGenerator {
                 _ => "other",
             }
             .to_string(),
-        })
+        }
     }
 }
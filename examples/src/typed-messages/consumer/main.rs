@@ -0,0 +1,123 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use clap::Parser;
+use futures_util::StreamExt;
+use iggy::client::Client;
+use iggy::client_provider;
+use iggy::client_provider::ClientProviderConfig;
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{AutoCommit, AutoCommitWhen};
+use iggy::clients::typed_consumer::{DecodeErrorPolicy, IggyTypedConsumer, TypedReceivedMessage};
+use iggy::codecs::json::JsonCodec;
+use iggy::consumer::ConsumerKind;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::utils::duration::IggyDuration;
+use iggy_examples::shared::args::Args;
+use iggy_examples::shared::messages::OrderEvent;
+use std::error::Error;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, info};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+    info!(
+        "Typed messages consumer has started, selected transport: {}",
+        args.transport
+    );
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.to_sdk_args())?);
+    let client = client_provider::get_raw_client(client_provider_config, false).await?;
+    let client = IggyClient::new(client);
+    client.connect().await?;
+
+    let name = "typed-messages-consumer";
+    // The messages which cannot be decoded (e.g. sent by the other producers) are logged and skipped.
+    let mut consumer = match ConsumerKind::from_code(args.consumer_kind)? {
+        ConsumerKind::Consumer => {
+            client.consumer(name, &args.stream_id, &args.topic_id, args.partition_id)?
+        }
+        ConsumerKind::ConsumerGroup => {
+            client.consumer_group(name, &args.stream_id, &args.topic_id)?
+        }
+    }
+    .auto_commit(AutoCommit::When(AutoCommitWhen::PollingMessages))
+    .create_consumer_group_if_not_exists()
+    .auto_join_consumer_group()
+    .polling_strategy(PollingStrategy::next())
+    .poll_interval(IggyDuration::from_str(&args.interval)?)
+    .batch_size(args.messages_per_batch)
+    .build()
+    .typed(JsonCodec)
+    .decode_error_policy(DecodeErrorPolicy::Skip);
+
+    consumer.init().await?;
+    consume_messages(&args, &mut consumer).await?;
+
+    Ok(())
+}
+
+async fn consume_messages(
+    args: &Args,
+    consumer: &mut IggyTypedConsumer<OrderEvent, JsonCodec>,
+) -> Result<(), Box<dyn Error>> {
+    let interval = args.get_interval();
+    let mut consumed_batches = 0;
+
+    info!("Messages will be polled by consumer: {} from stream: {}, topic: {}, partition: {} with interval {}.",
+        args.consumer_id, args.stream_id, args.topic_id, args.partition_id, interval.map_or("none".to_string(), |i| i.as_human_time_string()));
+
+    while let Some(message) = consumer.next().await {
+        if args.message_batches_limit > 0 && consumed_batches == args.message_batches_limit {
+            info!("Consumed {consumed_batches} batches of messages, exiting.");
+            return Ok(());
+        }
+
+        match message {
+            Ok(message) => {
+                handle_message(&message);
+                consumed_batches += 1;
+            }
+            Err(error) => {
+                error!("Error while handling message: {error}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn handle_message(message: &TypedReceivedMessage<OrderEvent>) {
+    // The payload has been already decoded, so there's no need for the envelope.
+    info!(
+        "Handling message at offset: {} in partition ID: {} with current offset: {}",
+        message.message.offset, message.partition_id, message.current_offset,
+    );
+    match &message.value {
+        OrderEvent::OrderCreated(order_created) => info!("{:#?}", order_created),
+        OrderEvent::OrderConfirmed(order_confirmed) => info!("{:#?}", order_confirmed),
+        OrderEvent::OrderRejected(order_rejected) => info!("{:#?}", order_rejected),
+    }
+}
@@ -0,0 +1,46 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::env;
+use std::error;
+use std::fs;
+use std::path::PathBuf;
+
+const UPDATE_HEADER_ENV: &str = "IGGY_FFI_UPDATE_HEADER";
+
+/// Generates the C header from the exported functions and types into `OUT_DIR`. The header is also kept
+/// in the repository, so that it can be used without building the crate, and its changes are visible when reviewing
+/// the bindings. It's updated only when building with `IGGY_FFI_UPDATE_HEADER=1`, and the test of the crate fails
+/// when it's outdated.
+fn main() -> Result<(), Box<dyn error::Error>> {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed={UPDATE_HEADER_ENV}");
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?);
+    let header = PathBuf::from(env::var("OUT_DIR")?).join("iggy.h");
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))?;
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()?
+        .write_to_file(&header);
+    if env::var(UPDATE_HEADER_ENV).is_ok_and(|value| value == "1") {
+        fs::copy(&header, crate_dir.join("include").join("iggy.h"))?;
+    }
+    Ok(())
+}
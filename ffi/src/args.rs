@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use std::ffi::{c_char, CStr};
+use std::{ptr, slice};
+
+/// Reads the required NUL-terminated UTF-8 string.
+///
+/// # Safety
+/// The pointer must be either `NULL` or point to the NUL-terminated string valid for the lifetime `'a`.
+pub(crate) unsafe fn str_arg<'a>(value: *const c_char) -> Result<&'a str, IggyError> {
+    if value.is_null() {
+        return Err(IggyError::InvalidCommand);
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| IggyError::InvalidUtf8)
+}
+
+/// Reads the identifier given either as the numeric ID or the name, e.g. `"1"` or `"orders"`.
+///
+/// # Safety
+/// Same as for `str_arg()`.
+pub(crate) unsafe fn identifier_arg(value: *const c_char) -> Result<Identifier, IggyError> {
+    str_arg(value)?.parse()
+}
+
+/// Reads the required array of `count` items, which might be `NULL` only when it's empty.
+///
+/// # Safety
+/// The pointer must be either `NULL` or point to `count` initialized items valid for the lifetime `'a`.
+pub(crate) unsafe fn slice_arg<'a, T>(items: *const T, count: usize) -> Result<&'a [T], IggyError> {
+    if count == 0 {
+        return Ok(&[]);
+    }
+    if items.is_null() {
+        return Err(IggyError::InvalidCommand);
+    }
+    Ok(slice::from_raw_parts(items, count))
+}
+
+/// Reads the partition ID, which must be given explicitly, as 0 doesn't select any partition.
+pub(crate) fn partition_id_arg(partition_id: u32) -> Result<u32, IggyError> {
+    if partition_id == 0 {
+        return Err(IggyError::InvalidCommand);
+    }
+    Ok(partition_id)
+}
+
+/// Returns the handle shared by the concurrent calls, e.g. the client or the producer.
+///
+/// # Safety
+/// The pointer must be either `NULL` or point to the live handle for the lifetime `'a`.
+pub(crate) unsafe fn handle_arg<'a, T>(handle: *const T) -> Result<&'a T, IggyError> {
+    handle.as_ref().ok_or(IggyError::InvalidCommand)
+}
+
+/// Returns the handle used exclusively by the call, e.g. the consumer.
+///
+/// # Safety
+/// The pointer must be either `NULL` or point to the live handle, not used by any other call for the lifetime `'a`.
+pub(crate) unsafe fn handle_mut_arg<'a, T>(handle: *mut T) -> Result<&'a mut T, IggyError> {
+    handle.as_mut().ok_or(IggyError::InvalidCommand)
+}
+
+/// Returns the required output argument, reset to `NULL` until the call succeeds.
+///
+/// # Safety
+/// The pointer must be either `NULL` or point to the writable location for the lifetime `'a`.
+pub(crate) unsafe fn out_arg<'a, T>(out: *mut *mut T) -> Result<&'a mut *mut T, IggyError> {
+    let out = out.as_mut().ok_or(IggyError::InvalidCommand)?;
+    *out = ptr::null_mut();
+    Ok(out)
+}
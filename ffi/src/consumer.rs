@@ -0,0 +1,121 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::{handle_arg, handle_mut_arg, out_arg, str_arg};
+use crate::client::IggyClient;
+use crate::error::call;
+use crate::messages::{IggyPolledMessage, OwnedPolledMessage};
+use iggy::blocking::IggyConsumer as BlockingIggyConsumer;
+use iggy::clients::consumer::AutoCommit;
+use iggy::utils::duration::IggyDuration;
+use std::ffi::c_char;
+use std::time::Duration;
+
+/// The consumer receiving the messages from the topic one by one, which must not be used by the threads at the same time.
+/// Created with `iggy_consumer_new()` and released with `iggy_consumer_free()`.
+pub struct IggyConsumer {
+    consumer: BlockingIggyConsumer,
+}
+
+/// Creates the consumer named either by the numeric ID or the name, receiving the messages following its stored offset.
+/// It's the standalone consumer of the partition, or the member of the consumer group (created if it doesn't exist)
+/// if `partition_id` is 0. With `auto_commit`, the offsets are stored while polling the messages,
+/// otherwise they must be stored with `iggy_consumer_store_offset()`.
+///
+/// # Safety
+/// The client must be live, the name, the stream and the topic must be the NUL-terminated strings,
+/// and `consumer` must point to the writable location.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_consumer_new(
+    client: *const IggyClient,
+    name: *const c_char,
+    stream: *const c_char,
+    topic: *const c_char,
+    partition_id: u32,
+    auto_commit: bool,
+    consumer: *mut *mut IggyConsumer,
+) -> u32 {
+    call(|| {
+        let out = out_arg(consumer)?;
+        let client = &handle_arg(client)?.client;
+        let (name, stream, topic) = (str_arg(name)?, str_arg(stream)?, str_arg(topic)?);
+        let mut builder = match partition_id {
+            0 => client.consumer_group(name, stream, topic)?,
+            partition_id => client.consumer(name, stream, topic, partition_id)?,
+        };
+        if !auto_commit {
+            builder = builder.auto_commit(AutoCommit::Disabled);
+        }
+        let consumer = builder.build()?;
+        *out = Box::into_raw(Box::new(IggyConsumer { consumer }));
+        Ok(())
+    })
+}
+
+/// Receives the next message, waiting for it up to `timeout_ms` milliseconds.
+/// If no message has been received in time, `IGGY_OK` is returned and `message` is set to `NULL`.
+/// The message must be released with `iggy_polled_message_free()`.
+///
+/// # Safety
+/// The consumer must be live and not used by any other thread, and `message` must point to the writable location.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_consumer_poll(
+    consumer: *mut IggyConsumer,
+    timeout_ms: u64,
+    message: *mut *mut IggyPolledMessage,
+) -> u32 {
+    call(|| {
+        let out = out_arg(message)?;
+        let consumer = handle_mut_arg(consumer)?;
+        let timeout = IggyDuration::new(Duration::from_millis(timeout_ms));
+        if let Some(received) = consumer.consumer.next_timeout(timeout) {
+            let received = received?;
+            let message = OwnedPolledMessage::new(received.partition_id, received.message);
+            *out = Box::into_raw(Box::new(message)).cast();
+        }
+        Ok(())
+    })
+}
+
+/// Stores the offset of the consumer for the partition, or for the partition of the last received message if `partition_id` is 0.
+///
+/// # Safety
+/// The consumer must be live and not used by any other thread.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_consumer_store_offset(
+    consumer: *mut IggyConsumer,
+    offset: u64,
+    partition_id: u32,
+) -> u32 {
+    call(|| {
+        let consumer = handle_mut_arg(consumer)?;
+        let partition_id = (partition_id != 0).then_some(partition_id);
+        consumer.consumer.store_offset(offset, partition_id)
+    })
+}
+
+/// Releases the consumer. Passing `NULL` is a no-op.
+///
+/// # Safety
+/// The consumer must have been created by `iggy_consumer_new()` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_consumer_free(consumer: *mut IggyConsumer) {
+    if !consumer.is_null() {
+        drop(Box::from_raw(consumer));
+    }
+}
@@ -0,0 +1,57 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// The status returned by the functions on success. Any other status is the code of `IggyError`,
+/// the same as returned by the server in the binary protocol.
+pub const IGGY_OK: u32 = 0;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Runs the body of the exported function, returning its status and keeping the message of the error
+/// for `iggy_last_error_message()`. The panics are caught, as they must not unwind across the C ABI.
+pub(crate) fn call(f: impl FnOnce() -> Result<(), IggyError>) -> u32 {
+    let result = panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(Err(IggyError::Error));
+    match result {
+        Ok(()) => IGGY_OK,
+        Err(error) => {
+            let message = CString::new(error.to_string()).unwrap_or_default();
+            LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+            error.as_code()
+        }
+    }
+}
+
+/// Returns the message of the last error on the calling thread, or `NULL` if there was none.
+/// The string is owned by the library and remains valid until the next error on the same thread.
+#[no_mangle]
+pub extern "C" fn iggy_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
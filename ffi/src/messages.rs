@@ -0,0 +1,159 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::slice_arg;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::models::messages::{PolledMessage, PolledMessages};
+
+/// The message to send, with the payload borrowed from the caller only for the duration of the call.
+#[repr(C)]
+pub struct IggyMessage {
+    /// The 16 bytes of the big-endian message ID, or all zeros to have it generated by the server.
+    pub id: [u8; 16],
+    pub payload: *const u8,
+    pub payload_length: usize,
+}
+
+/// The polled message, owned by the library. The payload is valid until the message
+/// (or the batch containing it) is released.
+#[repr(C)]
+pub struct IggyPolledMessage {
+    pub partition_id: u32,
+    pub offset: u64,
+    /// The append time as the Unix timestamp in microseconds.
+    pub timestamp: u64,
+    /// The 16 bytes of the big-endian message ID.
+    pub id: [u8; 16],
+    pub payload: *const u8,
+    pub payload_length: usize,
+}
+
+/// The batch of the polled messages, owned by the library until released with `iggy_polled_messages_free()`.
+#[repr(C)]
+pub struct IggyPolledMessages {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub messages: *const IggyPolledMessage,
+    pub count: usize,
+}
+
+/// The single polled message along with its payload. The message is the first field,
+/// so the pointer to it returned to the caller is the pointer to the whole allocation.
+#[repr(C)]
+pub(crate) struct OwnedPolledMessage {
+    message: IggyPolledMessage,
+    _payload: Bytes,
+}
+
+impl OwnedPolledMessage {
+    pub(crate) fn new(partition_id: u32, message: PolledMessage) -> Self {
+        Self {
+            message: IggyPolledMessage::new(partition_id, &message),
+            _payload: message.payload,
+        }
+    }
+}
+
+/// The batch of the polled messages along with their payloads, laid out like `OwnedPolledMessage`.
+#[repr(C)]
+pub(crate) struct OwnedPolledMessages {
+    messages: IggyPolledMessages,
+    _items: Vec<IggyPolledMessage>,
+    _payloads: Vec<Bytes>,
+}
+
+impl OwnedPolledMessages {
+    pub(crate) fn new(polled_messages: PolledMessages) -> Self {
+        let partition_id = polled_messages.partition_id;
+        let items = polled_messages
+            .messages
+            .iter()
+            .map(|message| IggyPolledMessage::new(partition_id, message))
+            .collect::<Vec<_>>();
+        let payloads = polled_messages
+            .messages
+            .into_iter()
+            .map(|message| message.payload)
+            .collect();
+        Self {
+            messages: IggyPolledMessages {
+                partition_id,
+                current_offset: polled_messages.current_offset,
+                messages: items.as_ptr(),
+                count: items.len(),
+            },
+            _items: items,
+            _payloads: payloads,
+        }
+    }
+}
+
+impl IggyPolledMessage {
+    fn new(partition_id: u32, message: &PolledMessage) -> Self {
+        Self {
+            partition_id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            id: message.id.to_be_bytes(),
+            payload: message.payload.as_ptr(),
+            payload_length: message.payload.len(),
+        }
+    }
+}
+
+/// Copies the messages to send, as the payloads are borrowed from the caller.
+///
+/// # Safety
+/// Each payload must point to `payload_length` readable bytes.
+pub(crate) unsafe fn to_messages(messages: &[IggyMessage]) -> Result<Vec<Message>, IggyError> {
+    messages
+        .iter()
+        .map(|message| {
+            let payload = slice_arg(message.payload, message.payload_length)?;
+            let id = match u128::from_be_bytes(message.id) {
+                0 => None,
+                id => Some(id),
+            };
+            Ok(Message::new(id, Bytes::copy_from_slice(payload), None))
+        })
+        .collect()
+}
+
+/// Releases the message returned by `iggy_consumer_poll()`. Passing `NULL` is a no-op.
+///
+/// # Safety
+/// The message must have been returned by the library and not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_polled_message_free(message: *mut IggyPolledMessage) {
+    if !message.is_null() {
+        drop(Box::from_raw(message.cast::<OwnedPolledMessage>()));
+    }
+}
+
+/// Releases the messages returned by `iggy_client_poll_messages()`. Passing `NULL` is a no-op.
+///
+/// # Safety
+/// The messages must have been returned by the library and not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_polled_messages_free(messages: *mut IggyPolledMessages) {
+    if !messages.is_null() {
+        drop(Box::from_raw(messages.cast::<OwnedPolledMessages>()));
+    }
+}
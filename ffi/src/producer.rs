@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::{handle_arg, out_arg, slice_arg, str_arg};
+use crate::client::IggyClient;
+use crate::error::call;
+use crate::messages::{to_messages, IggyMessage};
+use iggy::blocking::IggyProducer as BlockingIggyProducer;
+use iggy::messages::send_messages::Partitioning;
+use std::ffi::c_char;
+
+/// The producer sending the messages to the topic, which can be shared by the threads.
+/// Created with `iggy_producer_new()` and released with `iggy_producer_free()`.
+pub struct IggyProducer {
+    producer: BlockingIggyProducer,
+}
+
+/// Creates the producer sending the messages to the partition, or balanced across the partitions if `partition_id` is 0.
+/// The stream and the topic are given either as the numeric IDs or the names, and are created if they don't exist.
+/// The messages are sent right away, rather than buffered in the background, so that the errors are returned by `iggy_producer_send()`.
+///
+/// # Safety
+/// The client must be live, the stream and the topic must be the NUL-terminated strings,
+/// and `producer` must point to the writable location.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_producer_new(
+    client: *const IggyClient,
+    stream: *const c_char,
+    topic: *const c_char,
+    partition_id: u32,
+    producer: *mut *mut IggyProducer,
+) -> u32 {
+    call(|| {
+        let out = out_arg(producer)?;
+        let client = handle_arg(client)?;
+        let partitioning = match partition_id {
+            0 => Partitioning::balanced(),
+            partition_id => Partitioning::partition_id(partition_id),
+        };
+        let producer = client
+            .client
+            .producer(str_arg(stream)?, str_arg(topic)?)?
+            .partitioning(partitioning)
+            .without_send_interval()
+            .build()?;
+        *out = Box::into_raw(Box::new(IggyProducer { producer }));
+        Ok(())
+    })
+}
+
+/// Sends the messages, copying their payloads, so they can be released once the function returns.
+///
+/// # Safety
+/// The producer must be live, and `messages` must point to `count` messages with the readable payloads.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_producer_send(
+    producer: *const IggyProducer,
+    messages: *const IggyMessage,
+    count: usize,
+) -> u32 {
+    call(|| {
+        let producer = handle_arg(producer)?;
+        let messages = to_messages(slice_arg(messages, count)?)?;
+        producer.producer.send(messages)
+    })
+}
+
+/// Releases the producer. Passing `NULL` is a no-op.
+///
+/// # Safety
+/// The producer must have been created by `iggy_producer_new()` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_producer_free(producer: *mut IggyProducer) {
+    if !producer.is_null() {
+        drop(Box::from_raw(producer));
+    }
+}
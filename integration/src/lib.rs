@@ -25,3 +25,5 @@ pub mod quic_client;
 pub mod tcp_client;
 #[allow(deprecated)]
 pub mod test_server;
+#[allow(deprecated)]
+pub mod websocket_client;
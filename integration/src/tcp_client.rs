@@ -27,6 +27,7 @@ use std::sync::Arc;
 pub struct TcpClientFactory {
     pub server_addr: String,
     pub nodelay: bool,
+    pub pipelining: bool,
 }
 
 #[async_trait]
@@ -35,6 +36,7 @@ impl ClientFactory for TcpClientFactory {
         let config = TcpClientConfig {
             server_address: self.server_addr.clone(),
             nodelay: self.nodelay,
+            pipelining: self.pipelining,
             ..TcpClientConfig::default()
         };
         let client = TcpClient::create(Arc::new(config)).unwrap_or_else(|e| {
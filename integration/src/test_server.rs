@@ -83,6 +83,18 @@ enum ServerProtocolAddr {
 
     #[display("QUIC_UDP:{_0}")]
     QuicUdp(SocketAddr),
+
+    #[display("WEBSOCKET_TCP:{_0}")]
+    WebSocketTcp(SocketAddr),
+
+    #[display("KAFKA_TCP:{_0}")]
+    KafkaTcp(SocketAddr),
+
+    #[display("MQTT_TCP:{_0}")]
+    MqttTcp(SocketAddr),
+
+    #[display("GRPC_TCP:{_0}")]
+    GrpcTcp(SocketAddr),
 }
 
 #[derive(Debug)]
@@ -159,6 +171,25 @@ impl TestServer {
             }
         }
 
+        // The WebSocket listener always binds to a random port, unless specified explicitly,
+        // so that the servers started with the fixed addresses of the other transports don't collide.
+        if let Some(websocket_addr) = envs.get("IGGY_WEBSOCKET_ADDRESS") {
+            server_addrs.push(ServerProtocolAddr::WebSocketTcp(
+                websocket_addr.parse().unwrap(),
+            ));
+        } else if !server_addrs
+            .iter()
+            .any(|addr| matches!(addr, ServerProtocolAddr::WebSocketTcp(_)))
+        {
+            let ip_addr = match ip_kind {
+                IpAddrKind::V6 => Ipv6Addr::LOCALHOST.into(),
+                _ => Ipv4Addr::LOCALHOST.into(),
+            };
+            server_addrs.push(ServerProtocolAddr::WebSocketTcp(SocketAddr::new(
+                ip_addr, 0,
+            )));
+        }
+
         Self {
             local_data_path,
             envs,
@@ -253,6 +284,15 @@ impl TestServer {
         self.cleanup();
     }
 
+    /// Kills the server process without letting it shut down gracefully, e.g. to simulate a crash.
+    pub fn kill(&mut self) {
+        if let Some(mut child_handle) = self.child_handle.take() {
+            child_handle.kill().unwrap();
+            child_handle.wait().unwrap();
+        }
+        self.cleanup();
+    }
+
     pub fn is_started(&self) -> bool {
         self.child_handle.is_some()
     }
@@ -277,6 +317,7 @@ impl TestServer {
             ServerProtocolAddr::QuicUdp(addr),
             ServerProtocolAddr::RawTcp(addr),
             ServerProtocolAddr::HttpTcp(addr),
+            ServerProtocolAddr::WebSocketTcp(addr),
         ]
     }
 
@@ -286,6 +327,7 @@ impl TestServer {
             ServerProtocolAddr::QuicUdp(addr),
             ServerProtocolAddr::RawTcp(addr),
             ServerProtocolAddr::HttpTcp(addr),
+            ServerProtocolAddr::WebSocketTcp(addr),
         ]
     }
 
@@ -301,6 +343,18 @@ impl TestServer {
                 ServerProtocolAddr::QuicUdp(addr) => {
                     ("IGGY_QUIC_ADDRESS".to_string(), addr.to_string())
                 }
+                ServerProtocolAddr::WebSocketTcp(addr) => {
+                    ("IGGY_WEBSOCKET_ADDRESS".to_string(), addr.to_string())
+                }
+                ServerProtocolAddr::KafkaTcp(addr) => {
+                    ("IGGY_KAFKA_ADDRESS".to_string(), addr.to_string())
+                }
+                ServerProtocolAddr::MqttTcp(addr) => {
+                    ("IGGY_MQTT_ADDRESS".to_string(), addr.to_string())
+                }
+                ServerProtocolAddr::GrpcTcp(addr) => {
+                    ("IGGY_GRPC_ADDRESS".to_string(), addr.to_string())
+                }
             };
 
             self.envs.entry(key.0).or_insert(key.1);
@@ -350,6 +404,28 @@ impl TestServer {
             self.server_addrs.push(ServerProtocolAddr::HttpTcp(
                 config.http.address.parse().unwrap(),
             ));
+
+            self.server_addrs.push(ServerProtocolAddr::WebSocketTcp(
+                config.websocket.address.parse().unwrap(),
+            ));
+
+            if config.kafka.enabled {
+                self.server_addrs.push(ServerProtocolAddr::KafkaTcp(
+                    config.kafka.address.parse().unwrap(),
+                ));
+            }
+
+            if config.mqtt.enabled {
+                self.server_addrs.push(ServerProtocolAddr::MqttTcp(
+                    config.mqtt.address.parse().unwrap(),
+                ));
+            }
+
+            if config.grpc.enabled {
+                self.server_addrs.push(ServerProtocolAddr::GrpcTcp(
+                    config.grpc.address.parse().unwrap(),
+                ));
+            }
         } else {
             panic!(
                 "Failed to load config from file {} in {} s!",
@@ -413,6 +489,42 @@ impl TestServer {
         None
     }
 
+    pub fn get_websocket_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::WebSocketTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
+    pub fn get_kafka_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::KafkaTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
+    pub fn get_mqtt_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::MqttTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
+    pub fn get_grpc_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::GrpcTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
     pub fn get_server_ip_addr(&self) -> Option<String> {
         if let Some(server_address) = self
             .get_raw_tcp_addr()
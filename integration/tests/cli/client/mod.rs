@@ -16,6 +16,7 @@
  * under the License.
  */
 
+mod test_client_disconnect_command;
 mod test_client_get_command;
 mod test_client_help_command;
 mod test_client_list_command;
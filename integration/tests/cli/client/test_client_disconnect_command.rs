@@ -0,0 +1,152 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestClientDisconnectCmd {
+    username: String,
+}
+
+impl TestClientDisconnectCmd {
+    fn new(username: String) -> Self {
+        Self { username }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestClientDisconnectCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let user = client
+            .create_user(&self.username, "secret", UserStatus::Active, None)
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("client")
+            .arg("disconnect")
+            .arg("--user")
+            .arg(self.username.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing disconnect all clients of user with ID: {}\nDisconnected all clients of user with ID: {}\n",
+            self.username, self.username
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user_id = Identifier::named(&self.username).unwrap();
+        let clients = client.get_clients().await;
+        assert!(clients.is_ok());
+        let user = client.get_user(&user_id).await.unwrap().unwrap();
+        assert!(clients
+            .unwrap()
+            .iter()
+            .all(|client| client.user_id != Some(user.id)));
+
+        let deleted = client.delete_user(&user_id).await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestClientDisconnectCmd::new(String::from("testuser")))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["client", "disconnect", "--help"],
+            format!(
+                r#"Forcibly disconnect client with given ID or all clients of given user
+
+TCP and QUIC connections are closed by the server, and for the user
+all previously issued HTTP access tokens are revoked as well.
+The user ID can be specified as either a username or an ID.
+
+Examples:
+ iggy client disconnect 42
+ iggy client disconnect --user testuser
+ iggy client disconnect -u 2
+
+{USAGE_PREFIX} client disconnect <CLIENT_ID|--user <USER>>
+
+Arguments:
+  [CLIENT_ID]
+          Client ID to disconnect
+
+Options:
+  -u, --user <USER>
+          Disconnect all clients of the user with given ID
+          
+          The user ID can be specified as either a username or an ID
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["client", "disconnect", "-h"],
+            format!(
+                r#"Forcibly disconnect client with given ID or all clients of given user
+
+{USAGE_PREFIX} client disconnect <CLIENT_ID|--user <USER>>
+
+Arguments:
+  [CLIENT_ID]  Client ID to disconnect
+
+Options:
+  -u, --user <USER>  Disconnect all clients of the user with given ID
+  -h, --help         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -33,9 +33,10 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} client <COMMAND>
 
 Commands:
-  get   Get details of a single client with given ID [aliases: g]
-  list  List all currently connected clients to iggy server [aliases: l]
-  help  Print this message or the help of the given subcommand(s)
+  get         Get details of a single client with given ID [aliases: g]
+  list        List all currently connected clients to iggy server [aliases: l]
+  disconnect  Forcibly disconnect client with given ID or all clients of given user [aliases: d]
+  help        Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
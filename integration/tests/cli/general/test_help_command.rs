@@ -41,11 +41,13 @@ Commands:
   me               get current client info
   stats            get iggy server statistics
   snapshot         collect iggy server troubleshooting data
+  reload-config    reload iggy server configuration
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
+  schema           schema registry operations
   message          message operations [aliases: m]
   context          context operations [aliases: ctx]
   login            login to Iggy server [aliases: li]
@@ -0,0 +1,271 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::models::schema::SchemaFormat;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+const DEFINITION: &str = r#"{"type":"object","properties":{"id":{"type":"integer"}}}"#;
+
+struct TestSchemaRegisterCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    definition_file: NamedTempFile,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestSchemaRegisterCmd {
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        let mut definition_file = NamedTempFile::new().unwrap();
+        definition_file.write_all(DEFINITION.as_bytes()).unwrap();
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            definition_file,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn stream_arg(&self) -> String {
+        match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        }
+    }
+
+    fn topic_arg(&self) -> String {
+        match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            self.stream_arg(),
+            self.topic_arg(),
+            "json_schema".into(),
+            self.definition_file.path().to_str().unwrap().into(),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestSchemaRegisterCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("schema")
+            .arg("register")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!(
+            "Executing register json_schema schema from file: {} for topic with ID: {} and stream with ID: {}",
+            self.definition_file.path().display(),
+            self.topic_arg(),
+            self.stream_arg()
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!(
+                "version: 1 registered for topic with ID: {} and stream with ID: {}",
+                self.topic_arg(),
+                self.stream_arg()
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic_schemas = client
+            .get_schemas(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await
+            .expect("Failed to get schemas");
+        assert_eq!(topic_schemas.schemas.len(), 1);
+        let schema = &topic_schemas.schemas[0];
+        assert_eq!(schema.version, 1);
+        assert_eq!(schema.format, SchemaFormat::JsonSchema);
+        assert_eq!(schema.definition, DEFINITION);
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters = vec![
+        (TestStreamId::Numeric, TestTopicId::Numeric),
+        (TestStreamId::Named, TestTopicId::Numeric),
+        (TestStreamId::Numeric, TestTopicId::Named),
+        (TestStreamId::Named, TestTopicId::Named),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestSchemaRegisterCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                using_stream_id,
+                using_topic_id,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["schema", "register", "--help"],
+            format!(
+                r#"Register new version of the schema for given stream ID and topic ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Schema definition is read from the given file. If the latest version
+has the same format and definition, it's returned instead of
+registering a new one.
+
+Examples:
+ iggy schema register 1 1 json_schema order.json
+ iggy schema register prod orders avro order.avsc
+ iggy schema register prod orders protobuf order.proto
+
+{USAGE_PREFIX} schema register <STREAM_ID> <TOPIC_ID> <FORMAT> <DEFINITION_FILE>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to register schema
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to register schema
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <FORMAT>
+          Schema format (avro, json_schema or protobuf)
+
+  <DEFINITION_FILE>
+          Path to the file with schema definition
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["schema", "register", "-h"],
+            format!(
+                r#"Register new version of the schema for given stream ID and topic ID
+
+{USAGE_PREFIX} schema register <STREAM_ID> <TOPIC_ID> <FORMAT> <DEFINITION_FILE>
+
+Arguments:
+  <STREAM_ID>        Stream ID to register schema
+  <TOPIC_ID>         Topic ID to register schema
+  <FORMAT>           Schema format (avro, json_schema or protobuf)
+  <DEFINITION_FILE>  Path to the file with schema definition
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
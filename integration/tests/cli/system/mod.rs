@@ -29,5 +29,6 @@ mod test_logout_command;
 #[cfg(not(any(target_os = "macos", target_env = "musl")))]
 mod test_me_command;
 mod test_ping_command;
+mod test_reload_config_command;
 mod test_snapshot_cmd;
 mod test_stats_command;
@@ -0,0 +1,264 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::PolledMessage;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy_connectors::{
+    ConnectorError, ConnectorRegistry, Runtime, RuntimeConfig, Sink, SinkRecord,
+    DEAD_LETTER_CONNECTOR_HEADER, DEAD_LETTER_ERROR_HEADER, DEAD_LETTER_OFFSET_HEADER,
+};
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory, TestServer};
+use serial_test::parallel;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+use tokio_util::sync::CancellationToken;
+
+const STREAM_NAME: &str = "connectors";
+const TOPIC_NAME: &str = "lines";
+const DEAD_LETTER_TOPIC_NAME: &str = "dead_letters";
+const PARTITIONS_COUNT: u32 = 2;
+const LINES_COUNT: usize = 50;
+const FILE_SINK_NAME: &str = "file_sink";
+const FAILING_SINK_NAME: &str = "failing_sink";
+const FAILURE: &str = "external system is unavailable";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+struct FailingSink;
+
+#[async_trait]
+impl Sink for FailingSink {
+    async fn consume(&mut self, _records: &[SinkRecord]) -> Result<(), ConnectorError> {
+        Err(ConnectorError::Connector(FAILURE.to_owned()))
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn connectors_runtime_should_move_data_between_file_and_topics() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.clone(),
+        ..Default::default()
+    };
+    let client = IggyClient::create(client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let directory = tempfile::tempdir().unwrap();
+    let input_path = directory.path().join("input.txt");
+    let output_path = directory.path().join("output.txt");
+    let lines = (0..LINES_COUNT)
+        .map(|index| format!("line-{index:03}"))
+        .collect::<Vec<_>>();
+    std::fs::write(&input_path, lines.join("\n")).unwrap();
+
+    let config = format!(
+        r#"
+        [iggy]
+        connection_string = "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}"
+
+        [[sources]]
+        name = "file_source"
+        type = "file"
+        stream = "{STREAM_NAME}"
+        topic = "{TOPIC_NAME}"
+        batch_size = 20
+
+        [sources.config]
+        path = "{input_path}"
+
+        [[sinks]]
+        name = "{FILE_SINK_NAME}"
+        type = "file"
+        stream = "{STREAM_NAME}"
+        topic = "{TOPIC_NAME}"
+        batch_size = 10
+        max_wait = "100 ms"
+
+        [sinks.config]
+        path = "{output_path}"
+
+        [[sinks]]
+        name = "{FAILING_SINK_NAME}"
+        type = "failing"
+        stream = "{STREAM_NAME}"
+        topic = "{TOPIC_NAME}"
+        max_wait = "100 ms"
+
+        [sinks.retry]
+        max_retries = 1
+        interval = "10 ms"
+
+        [sinks.dead_letter]
+        stream = "{STREAM_NAME}"
+        topic = "{DEAD_LETTER_TOPIC_NAME}"
+        "#,
+        input_path = input_path.display(),
+        output_path = output_path.display(),
+    )
+    .parse::<RuntimeConfig>()
+    .unwrap();
+    let mut registry = ConnectorRegistry::with_builtin();
+    registry.register_sink("failing", |_| Ok(Box::new(FailingSink)));
+    let shutdown = CancellationToken::new();
+    let runtime = tokio::spawn(Runtime::new(config, registry).run(shutdown.clone()));
+
+    // 1. The lines read by the file source are written by the file sink
+    let output_lines = wait_for_lines(&output_path).await;
+    assert_eq!(output_lines, lines.iter().cloned().collect::<BTreeSet<_>>());
+
+    // 2. The messages which the failing sink couldn't consume are sent to the dead letter topic
+    let dead_letters = wait_for_dead_letters(&client).await;
+    for message in &dead_letters {
+        let headers = message.headers.as_ref().unwrap();
+        let header = |key: &str| headers.get(&HeaderKey::new(key).unwrap()).unwrap();
+        assert_eq!(
+            header(DEAD_LETTER_CONNECTOR_HEADER).as_str().unwrap(),
+            FAILING_SINK_NAME
+        );
+        assert!(header(DEAD_LETTER_ERROR_HEADER)
+            .as_str()
+            .unwrap()
+            .contains(FAILURE));
+        assert!(header(DEAD_LETTER_OFFSET_HEADER).as_uint64().unwrap() < LINES_COUNT as u64);
+    }
+    let dead_letter_payloads = dead_letters
+        .iter()
+        .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(dead_letter_payloads, output_lines);
+
+    // 3. The offsets of both sinks are committed by their consumer groups
+    for group in [FILE_SINK_NAME, FAILING_SINK_NAME] {
+        assert_offsets_committed(&client, group).await;
+    }
+
+    shutdown.cancel();
+    runtime.await.unwrap().unwrap();
+
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    for (topic, partitions_count) in [(TOPIC_NAME, PARTITIONS_COUNT), (DEAD_LETTER_TOPIC_NAME, 1)] {
+        client
+            .create_topic(
+                &Identifier::named(STREAM_NAME).unwrap(),
+                topic,
+                partitions_count,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn wait_for_lines(path: &Path) -> BTreeSet<String> {
+    timeout(TIMEOUT, async {
+        loop {
+            let content = tokio::fs::read_to_string(path).await.unwrap_or_default();
+            let lines = content.lines().map(str::to_owned).collect::<Vec<_>>();
+            if lines.len() >= LINES_COUNT {
+                assert_eq!(lines.len(), LINES_COUNT);
+                return lines.into_iter().collect();
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Lines not written by the file sink")
+}
+
+async fn wait_for_dead_letters(client: &IggyClient) -> Vec<PolledMessage> {
+    timeout(TIMEOUT, async {
+        loop {
+            let polled_messages = client
+                .poll_messages(
+                    &Identifier::named(STREAM_NAME).unwrap(),
+                    &Identifier::named(DEAD_LETTER_TOPIC_NAME).unwrap(),
+                    Some(1),
+                    &Consumer::default(),
+                    &PollingStrategy::offset(0),
+                    LINES_COUNT as u32 * 2,
+                    false,
+                )
+                .await
+                .unwrap();
+            if polled_messages.messages.len() >= LINES_COUNT {
+                assert_eq!(polled_messages.messages.len(), LINES_COUNT);
+                return polled_messages.messages;
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Messages not sent to the dead letter topic")
+}
+
+async fn assert_offsets_committed(client: &IggyClient, group: &str) {
+    let consumer = Consumer::group(Identifier::named(group).unwrap());
+    timeout(TIMEOUT, async {
+        loop {
+            let mut committed = true;
+            for partition_id in 1..=PARTITIONS_COUNT {
+                let offset = client
+                    .get_consumer_offset(
+                        &consumer,
+                        &Identifier::named(STREAM_NAME).unwrap(),
+                        &Identifier::named(TOPIC_NAME).unwrap(),
+                        Some(partition_id),
+                    )
+                    .await
+                    .unwrap();
+                if offset.is_none_or(|offset| offset.stored_offset != offset.current_offset) {
+                    committed = false;
+                }
+            }
+            if committed {
+                return;
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Consumer offsets not committed");
+}
@@ -16,4 +16,7 @@
  * under the License.
  */
 
+mod verify_after_graceful_shutdown;
+mod verify_after_server_crash;
 mod verify_after_server_restart;
+mod verify_revoked_sessions_after_server_restart;
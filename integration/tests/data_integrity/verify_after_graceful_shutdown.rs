@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{login_root, ClientFactory, IpAddrKind, TestServer, SYSTEM_PATH_ENV_VAR},
+};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const STORED_OFFSET: u64 = 5;
+
+#[tokio::test]
+#[parallel]
+async fn should_flush_buffered_messages_and_consumer_offsets_on_graceful_shutdown() {
+    // 1. Start server with the messages buffered in memory until the shutdown
+    let envs = HashMap::from([("IGGY_MESSAGE_SAVER_ENABLED".to_owned(), "false".to_owned())]);
+    let mut test_server = TestServer::new(Some(envs.clone()), false, None, IpAddrKind::V4);
+    test_server.start();
+    let local_data_path = test_server.get_local_data_path().to_owned();
+    let client = create_client(test_server.get_raw_tcp_addr().unwrap()).await;
+
+    // 2. Send the messages which are not persisted on disk yet and store the consumer offset
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+    client
+        .create_stream("test-stream", Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            "test-topic",
+            1,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message {id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    client
+        .store_consumer_offset(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            STORED_OFFSET,
+        )
+        .await
+        .unwrap();
+
+    // 3. Stop the server gracefully and restart it using the same data path
+    test_server.stop();
+    drop(test_server);
+    std::fs::remove_file(local_data_path.clone() + "/runtime/current_config.toml").unwrap();
+    let mut envs = envs;
+    envs.insert(SYSTEM_PATH_ENV_VAR.to_owned(), local_data_path.clone());
+    let mut test_server = TestServer::new(Some(envs), false, None, IpAddrKind::V4);
+    test_server.start();
+    let client = create_client(test_server.get_raw_tcp_addr().unwrap()).await;
+
+    // 4. Ensure that all the messages and the consumer offset have been persisted
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    let consumer_offset = client
+        .get_consumer_offset(&consumer, &stream_id, &topic_id, Some(PARTITION_ID))
+        .await
+        .unwrap()
+        .expect("Consumer offset should exist");
+    assert_eq!(consumer_offset.stored_offset, STORED_OFFSET);
+
+    // 5. Manual cleanup
+    test_server.stop();
+    drop(test_server);
+    std::fs::remove_dir_all(local_data_path).unwrap();
+}
+
+async fn create_client(server_addr: String) -> IggyClient {
+    let client = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    }
+    .create_client()
+    .await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    client
+}
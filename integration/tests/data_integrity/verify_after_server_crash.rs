@@ -0,0 +1,156 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{login_root, ClientFactory, IpAddrKind, TestServer, SYSTEM_PATH_ENV_VAR},
+};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const PRODUCERS_COUNT: u32 = 5;
+const BATCHES_PER_PRODUCER: u32 = 10;
+const MESSAGES_PER_BATCH: u32 = 10;
+
+#[tokio::test]
+#[parallel]
+async fn should_keep_acknowledged_messages_after_server_crash_with_group_commit() {
+    // 1. Start server with the group commit durability and no background message saver
+    let envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_SEGMENT_DURABILITY".to_owned(),
+            "group_commit".to_owned(),
+        ),
+        ("IGGY_MESSAGE_SAVER_ENABLED".to_owned(), "false".to_owned()),
+    ]);
+    let mut test_server = TestServer::new(Some(envs.clone()), false, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let local_data_path = test_server.get_local_data_path().to_owned();
+    let client = create_client(server_addr.clone()).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream("test-stream", Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            "test-topic",
+            1,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 2. Send the messages concurrently from multiple producers to the same partition
+    let mut producers = Vec::new();
+    for producer_id in 0..PRODUCERS_COUNT {
+        let server_addr = server_addr.clone();
+        producers.push(tokio::spawn(async move {
+            let client = create_client(server_addr).await;
+            let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+            let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+            for batch_id in 0..BATCHES_PER_PRODUCER {
+                let mut messages = (0..MESSAGES_PER_BATCH)
+                    .map(|id| {
+                        let payload =
+                            format!("producer {producer_id}, batch {batch_id}, message {id}");
+                        Message::new(None, Bytes::from(payload), None)
+                    })
+                    .collect::<Vec<_>>();
+                client
+                    .send_messages(
+                        &stream_id,
+                        &topic_id,
+                        &Partitioning::partition_id(PARTITION_ID),
+                        &mut messages,
+                    )
+                    .await
+                    .unwrap();
+            }
+        }));
+    }
+    for producer in producers {
+        producer.await.unwrap();
+    }
+
+    // 3. Kill the server without the graceful shutdown and restart it using the same data path
+    test_server.kill();
+    drop(test_server);
+    std::fs::remove_file(local_data_path.clone() + "/runtime/current_config.toml").unwrap();
+    let mut envs = envs;
+    envs.insert(SYSTEM_PATH_ENV_VAR.to_owned(), local_data_path.clone());
+    let mut test_server = TestServer::new(Some(envs), false, None, IpAddrKind::V4);
+    test_server.start();
+    let client = create_client(test_server.get_raw_tcp_addr().unwrap()).await;
+
+    // 4. Ensure that all the acknowledged messages have been persisted
+    let expected_messages_count = PRODUCERS_COUNT * BATCHES_PER_PRODUCER * MESSAGES_PER_BATCH;
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            expected_messages_count,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        expected_messages_count
+    );
+
+    // 5. Manual cleanup
+    test_server.stop();
+    drop(test_server);
+    std::fs::remove_dir_all(local_data_path).unwrap();
+}
+
+async fn create_client(server_addr: String) -> IggyClient {
+    let client = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    }
+    .create_client()
+    .await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    client
+}
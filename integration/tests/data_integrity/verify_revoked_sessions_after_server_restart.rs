@@ -0,0 +1,123 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::client::{SystemClient, UserClient};
+use iggy::error::IggyError;
+use iggy::http::client::HttpClient;
+use iggy::http::HttpTransport;
+use iggy::identifier::Identifier;
+use iggy::models::identity_info::IdentityInfo;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::user_status::UserStatus;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use integration::test_server::{IpAddrKind, TestServer, SYSTEM_PATH_ENV_VAR};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+const USERNAME: &str = "revoked-user";
+const PASSWORD: &str = "secret";
+
+#[tokio::test]
+#[parallel]
+async fn should_reject_revoked_user_sessions_after_refresh_and_restart() {
+    // 1. Start server and create the user, which can read its own details
+    let mut test_server = TestServer::new(None, false, None, IpAddrKind::V4);
+    test_server.start();
+    let local_data_path = test_server.get_local_data_path().to_owned();
+    let root_client = create_client(&test_server);
+    root_client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    let permissions = Permissions {
+        global: GlobalPermissions {
+            read_users: true,
+            ..Default::default()
+        },
+        streams: None,
+    };
+    root_client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, Some(permissions))
+        .await
+        .unwrap();
+
+    // 2. Login the user and disconnect all of its sessions
+    let revoked_client = create_client(&test_server);
+    let revoked_identity = revoked_client.login_user(USERNAME, PASSWORD).await.unwrap();
+    root_client
+        .disconnect_user_clients(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+
+    // 3. The revoked access token can be neither used nor refreshed
+    assert_unauthenticated(get_user(&revoked_client).await);
+    assert_unauthenticated(revoked_client.refresh_access_token().await);
+
+    // 4. The access token issued right after the revocation (even within the same second) is valid
+    let client = create_client(&test_server);
+    let identity = client.login_user(USERNAME, PASSWORD).await.unwrap();
+    get_user(&client).await.unwrap();
+
+    // 5. Stop the server and restart it using the same data path
+    test_server.stop();
+    drop(test_server);
+    std::fs::remove_file(local_data_path.clone() + "/runtime/current_config.toml").unwrap();
+    let extra_envs = HashMap::from([(SYSTEM_PATH_ENV_VAR.to_owned(), local_data_path.clone())]);
+    let mut test_server = TestServer::new(Some(extra_envs), false, None, IpAddrKind::V4);
+    test_server.start();
+
+    // 6. The sessions revoked before the restart remain revoked
+    let revoked_client = create_authenticated_client(&test_server, &revoked_identity).await;
+    assert_unauthenticated(get_user(&revoked_client).await);
+    assert_unauthenticated(revoked_client.refresh_access_token().await);
+    let client = create_authenticated_client(&test_server, &identity).await;
+    get_user(&client).await.unwrap();
+
+    // 7. Manual cleanup
+    test_server.stop();
+    drop(test_server);
+    std::fs::remove_dir_all(local_data_path).unwrap();
+}
+
+fn create_client(test_server: &TestServer) -> HttpClient {
+    let api_url = format!("http://{}", test_server.get_http_api_addr().unwrap());
+    HttpClient::new(&api_url).unwrap()
+}
+
+async fn create_authenticated_client(
+    test_server: &TestServer,
+    identity: &IdentityInfo,
+) -> HttpClient {
+    let client = create_client(test_server);
+    client.set_token_from_identity(identity).await.unwrap();
+    client
+}
+
+async fn get_user(client: &HttpClient) -> Result<(), IggyError> {
+    client
+        .get_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .map(|_| ())
+}
+
+fn assert_unauthenticated(result: Result<(), IggyError>) {
+    assert!(
+        matches!(result, Err(IggyError::Unauthenticated)),
+        "Expected unauthenticated error, got: {result:?}"
+    );
+}
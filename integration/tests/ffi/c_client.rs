@@ -0,0 +1,125 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::client::StreamClient;
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory, TestServer};
+use serial_test::parallel;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const STREAM_NAME: &str = "ffi-stream";
+
+#[tokio::test]
+#[parallel]
+async fn c_client_should_send_and_poll_messages() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let build_dir = tempfile::tempdir().unwrap();
+    let executable = compile_c_client(build_dir.path());
+
+    let connection_string =
+        format!("iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}");
+    let output = tokio::task::spawn_blocking(move || {
+        Command::new(executable)
+            .arg(connection_string)
+            .output()
+            .expect("Failed to run the C client")
+    })
+    .await
+    .unwrap();
+    assert!(
+        output.status.success(),
+        "The C client has failed, stdout:\n{}\nstderr:\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+/// Compiles the C client against the header and the shared library of the `iggy-ffi` crate,
+/// which is built along with the rest of the workspace into the same target directory as the tests.
+fn compile_c_client(build_dir: &Path) -> PathBuf {
+    let target_dir = env::current_exe()
+        .unwrap()
+        .parent()
+        .and_then(Path::parent)
+        .unwrap()
+        .to_path_buf();
+    let library = target_dir.join(format!(
+        "{}iggy_ffi{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ));
+    assert!(
+        library.exists(),
+        "The library {} is missing, build it with `cargo build -p iggy-ffi`",
+        library.display()
+    );
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let executable = build_dir.join("c_client");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let output = Command::new(compiler)
+        .arg(manifest_dir.join("tests/ffi/client_test.c"))
+        .arg("-std=c11")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg(format!(
+            "-DIGGY_ERROR_INVALID_COMMAND={}",
+            IggyError::InvalidCommand.as_code()
+        ))
+        .arg(format!(
+            "-DIGGY_ERROR_STREAM_NAME_NOT_FOUND={}",
+            IggyError::StreamNameNotFound(String::new()).as_code()
+        ))
+        .arg("-I")
+        .arg(manifest_dir.join("../ffi/include"))
+        .arg("-L")
+        .arg(&target_dir)
+        .arg("-liggy_ffi")
+        .arg(format!("-Wl,-rpath,{}", target_dir.display()))
+        .arg("-o")
+        .arg(&executable)
+        .output()
+        .expect("Failed to run the C compiler");
+    assert!(
+        output.status.success(),
+        "Failed to compile the C client:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    executable
+}
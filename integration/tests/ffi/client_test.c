@@ -0,0 +1,192 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+/* Exercises the C ABI of the Iggy client against the running server, whose connection string
+ * is given as the only argument. The error codes checked below are defined by the test runner. */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "iggy.h"
+
+#define STREAM "ffi-stream"
+#define TOPIC "ffi-topic"
+#define PARTITION_ID 1
+#define MESSAGES_COUNT 10
+#define POLL_TIMEOUT_MS 5000
+#define EMPTY_POLL_TIMEOUT_MS 200
+
+#define CHECK(condition)                                                      \
+    do {                                                                      \
+        if (!(condition)) {                                                   \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #condition);                                              \
+            exit(1);                                                          \
+        }                                                                     \
+    } while (0)
+
+#define CHECK_OK(call)                                                        \
+    do {                                                                      \
+        uint32_t status = (call);                                             \
+        if (status != IGGY_OK) {                                              \
+            fprintf(stderr, "%s:%d: %s failed with %u: %s\n", __FILE__,       \
+                    __LINE__, #call, status, iggy_last_error_message());      \
+            exit(1);                                                          \
+        }                                                                     \
+    } while (0)
+
+static void check_payload(const IggyPolledMessage *message, uint64_t offset) {
+    char expected[32];
+    int length = snprintf(expected, sizeof(expected), "message-%llu",
+                          (unsigned long long)offset);
+    CHECK(message->offset == offset);
+    CHECK(message->partition_id == PARTITION_ID);
+    CHECK(message->timestamp > 0);
+    CHECK(message->payload_length == (size_t)length);
+    CHECK(memcmp(message->payload, expected, (size_t)length) == 0);
+}
+
+static void test_errors(const IggyClient *client) {
+    IggyClient *invalid_client = (IggyClient *)1;
+    CHECK(iggy_client_new("localhost:8090", &invalid_client) != IGGY_OK);
+    CHECK(invalid_client == NULL);
+    CHECK(iggy_last_error_message() != NULL);
+
+    CHECK(iggy_client_connect(NULL) == IGGY_ERROR_INVALID_COMMAND);
+    CHECK(iggy_client_send_messages(client, NULL, TOPIC, PARTITION_ID, NULL, 0) ==
+          IGGY_ERROR_INVALID_COMMAND);
+
+    IggyPolledMessages *messages = NULL;
+    CHECK(iggy_client_poll_messages(client, "missing-stream", TOPIC, PARTITION_ID,
+                                    "ffi-client", IGGY_POLLING_KIND_OFFSET, 0, 10,
+                                    false, &messages) ==
+          IGGY_ERROR_STREAM_NAME_NOT_FOUND);
+    CHECK(messages == NULL);
+    CHECK(strlen(iggy_last_error_message()) > 0);
+    CHECK(iggy_client_poll_messages(client, STREAM, TOPIC, 0, "ffi-client",
+                                    IGGY_POLLING_KIND_OFFSET, 0, 10, false, &messages) ==
+          IGGY_ERROR_INVALID_COMMAND);
+    CHECK(messages == NULL);
+
+    /* The free functions accept NULL. */
+    iggy_polled_messages_free(NULL);
+    iggy_polled_message_free(NULL);
+    iggy_consumer_free(NULL);
+    iggy_producer_free(NULL);
+    iggy_client_free(NULL);
+}
+
+static void test_producer(const IggyClient *client) {
+    IggyProducer *producer = NULL;
+    CHECK_OK(iggy_producer_new(client, STREAM, TOPIC, PARTITION_ID, &producer));
+    CHECK(producer != NULL);
+
+    char payloads[MESSAGES_COUNT][32];
+    IggyMessage messages[MESSAGES_COUNT];
+    memset(messages, 0, sizeof(messages));
+    for (int i = 0; i < MESSAGES_COUNT; i++) {
+        int length = snprintf(payloads[i], sizeof(payloads[i]), "message-%d", i);
+        messages[i].payload = (const uint8_t *)payloads[i];
+        messages[i].payload_length = (size_t)length;
+    }
+    /* The last message has the explicit ID, the others are generated by the server. */
+    messages[MESSAGES_COUNT - 1].id[15] = 42;
+    CHECK_OK(iggy_producer_send(producer, messages, MESSAGES_COUNT));
+    iggy_producer_free(producer);
+}
+
+static void test_client_polling(const IggyClient *client) {
+    IggyPolledMessages *messages = NULL;
+    CHECK_OK(iggy_client_poll_messages(client, STREAM, TOPIC, PARTITION_ID,
+                                       "ffi-client", IGGY_POLLING_KIND_OFFSET, 0,
+                                       100, false, &messages));
+    CHECK(messages != NULL);
+    CHECK(messages->partition_id == PARTITION_ID);
+    CHECK(messages->current_offset == MESSAGES_COUNT - 1);
+    CHECK(messages->count == MESSAGES_COUNT);
+    for (size_t i = 0; i < messages->count; i++) {
+        check_payload(&messages->messages[i], i);
+    }
+    const uint8_t *last_id = messages->messages[MESSAGES_COUNT - 1].id;
+    CHECK(last_id[15] == 42);
+    iggy_polled_messages_free(messages);
+
+    /* The next messages follow the stored offset of the consumer. */
+    CHECK_OK(iggy_client_store_consumer_offset(client, "ffi-client", STREAM, TOPIC,
+                                               PARTITION_ID, 6));
+    CHECK_OK(iggy_client_poll_messages(client, STREAM, TOPIC, PARTITION_ID,
+                                       "ffi-client", IGGY_POLLING_KIND_NEXT, 0, 100,
+                                       true, &messages));
+    CHECK(messages->count == MESSAGES_COUNT - 7);
+    for (size_t i = 0; i < messages->count; i++) {
+        check_payload(&messages->messages[i], i + 7);
+    }
+    iggy_polled_messages_free(messages);
+}
+
+static void test_consumer(const IggyClient *client) {
+    IggyConsumer *consumer = NULL;
+    CHECK_OK(iggy_consumer_new(client, "ffi-consumer", STREAM, TOPIC, PARTITION_ID,
+                               false, &consumer));
+    for (uint64_t offset = 0; offset < 5; offset++) {
+        IggyPolledMessage *message = NULL;
+        CHECK_OK(iggy_consumer_poll(consumer, POLL_TIMEOUT_MS, &message));
+        CHECK(message != NULL);
+        check_payload(message, offset);
+        iggy_polled_message_free(message);
+    }
+    CHECK_OK(iggy_consumer_store_offset(consumer, 4, 0));
+    iggy_consumer_free(consumer);
+
+    /* The new consumer with the same name continues after the stored offset,
+     * and receives no message once all of them have been consumed. */
+    CHECK_OK(iggy_consumer_new(client, "ffi-consumer", STREAM, TOPIC, PARTITION_ID,
+                               true, &consumer));
+    for (uint64_t offset = 5; offset < MESSAGES_COUNT; offset++) {
+        IggyPolledMessage *message = NULL;
+        CHECK_OK(iggy_consumer_poll(consumer, POLL_TIMEOUT_MS, &message));
+        CHECK(message != NULL);
+        check_payload(message, offset);
+        iggy_polled_message_free(message);
+    }
+    IggyPolledMessage *message = (IggyPolledMessage *)1;
+    CHECK_OK(iggy_consumer_poll(consumer, EMPTY_POLL_TIMEOUT_MS, &message));
+    CHECK(message == NULL);
+    iggy_consumer_free(consumer);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "Usage: %s <connection_string>\n", argv[0]);
+        return 2;
+    }
+
+    IggyClient *client = NULL;
+    CHECK_OK(iggy_client_new(argv[1], &client));
+    CHECK_OK(iggy_client_connect(client));
+
+    test_errors(client);
+    test_producer(client);
+    test_client_polling(client);
+    test_consumer(client);
+
+    iggy_client_free(client);
+    printf("All checks passed.\n");
+    return 0;
+}
@@ -29,11 +29,14 @@ mod archiver;
 mod bench;
 mod cli;
 mod config_provider;
+mod connectors;
 mod data_integrity;
 mod examples;
+mod ffi;
 mod server;
 mod state;
 mod streaming;
+mod streams;
 
 lazy_static! {
     static ref TESTS_FAILED: AtomicBool = AtomicBool::new(false);
@@ -17,10 +17,17 @@
  */
 
 use crate::server::scenarios::{
-    create_message_payload, stream_size_validation_scenario, system_scenario, user_scenario,
+    config_reload_scenario, create_message_payload, message_long_polling_scenario,
+    message_offsets_scenario, openapi_scenario, schema_registry_scenario,
+    sse_subscription_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+};
+use integration::{
+    http_client::HttpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
+use tempfile::NamedTempFile;
 
 #[tokio::test]
 #[parallel]
@@ -52,6 +59,16 @@ async fn stream_size_validation_scenario_should_be_valid() {
     stream_size_validation_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn system_scenario_should_be_valid() {
@@ -71,3 +88,64 @@ async fn user_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     user_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn config_reload_scenario_should_be_valid() {
+    let config_file = NamedTempFile::new().unwrap();
+    let envs = HashMap::from([(
+        "IGGY_CONFIG_PATH".to_string(),
+        config_file.path().display().to_string(),
+    )]);
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr: server_addr.clone(),
+    };
+    config_reload_scenario::run(&client_factory, config_file.path(), &server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn sse_subscription_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr: server_addr.clone(),
+    };
+    sse_subscription_scenario::run(&client_factory, &server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn openapi_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr: server_addr.clone(),
+    };
+    openapi_scenario::run(&client_factory, &server_addr).await;
+}
@@ -16,7 +16,11 @@
  * under the License.
  */
 
+mod grpc_server;
 mod http_server;
+mod kafka_server;
+mod mqtt_server;
 mod quic_server;
 mod scenarios;
 mod tcp_server;
+mod websocket_server;
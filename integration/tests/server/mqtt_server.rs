@@ -0,0 +1,41 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::mqtt_bridge_scenario;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{IpAddrKind, TestServer};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+#[tokio::test]
+#[parallel]
+async fn mqtt_bridge_scenario_should_be_valid() {
+    let envs = HashMap::from([
+        ("IGGY_MQTT_ENABLED".to_string(), "true".to_string()),
+        ("IGGY_MQTT_ADDRESS".to_string(), "127.0.0.1:0".to_string()),
+    ]);
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let mqtt_addr = test_server.get_mqtt_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    mqtt_bridge_scenario::run(&client_factory, &mqtt_addr).await;
+}
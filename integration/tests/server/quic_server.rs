@@ -17,9 +17,11 @@
  */
 
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
+    client_disconnect_scenario, consumer_group_join_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
     consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    message_headers_scenario, message_long_polling_scenario, message_offsets_scenario,
+    schema_validation_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
 };
 use integration::{quic_client::QuicClientFactory, test_server::TestServer};
 use serial_test::parallel;
@@ -44,6 +46,16 @@ async fn user_scenario_should_be_valid() {
     user_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn client_disconnect_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    client_disconnect_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -103,3 +115,33 @@ async fn stream_size_validation_scenario_should_be_valid() {
     let client_factory = QuicClientFactory { server_addr };
     stream_size_validation_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn schema_validation_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    schema_validation_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    message_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    message_long_polling_scenario::run(&client_factory).await;
+}
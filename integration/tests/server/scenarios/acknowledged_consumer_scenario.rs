@@ -0,0 +1,157 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use futures::StreamExt;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{AutoCommit, IggyConsumer, ReceivedMessage};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+const CONSUMER_NAME: &str = "acknowledged-consumer";
+const MESSAGES_COUNT: u32 = 10;
+const MAX_IN_FLIGHT: u32 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let mut messages = (1..=MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    let mut consumer = client
+        .consumer(CONSUMER_NAME, STREAM_NAME, TOPIC_NAME, PARTITION_ID)
+        .unwrap()
+        .auto_commit(AutoCommit::Acknowledged)
+        .max_in_flight(MAX_IN_FLIGHT)
+        .polling_strategy(PollingStrategy::offset(0))
+        .batch_size(MESSAGES_COUNT)
+        .build();
+    consumer.init().await.unwrap();
+
+    // 1. The consumer stops returning the messages once the max in-flight messages are not acknowledged
+    let mut received_messages = Vec::new();
+    for _ in 0..MAX_IN_FLIGHT {
+        received_messages.push(next_message(&mut consumer).await);
+    }
+    assert!(timeout(Duration::from_millis(300), consumer.next())
+        .await
+        .is_err());
+
+    // 2. The offset is not stored until all the earlier messages are acknowledged
+    received_messages[1].ack();
+    received_messages[2].ack();
+    received_messages.push(next_message(&mut consumer).await);
+    sleep(Duration::from_millis(300)).await;
+    assert_eq!(get_stored_offset(&client).await, None);
+
+    received_messages[0].ack();
+    wait_for_stored_offset(&client, 2).await;
+
+    // 3. The last offset is stored once the oldest pending message is acknowledged
+    for _ in received_messages.len()..MESSAGES_COUNT as usize {
+        next_message(&mut consumer).await.ack();
+    }
+    sleep(Duration::from_millis(300)).await;
+    assert_eq!(get_stored_offset(&client).await, Some(2));
+
+    received_messages[3].ack();
+    wait_for_stored_offset(&client, MESSAGES_COUNT as u64 - 1).await;
+
+    drop(consumer);
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn next_message(consumer: &mut IggyConsumer) -> ReceivedMessage {
+    let message = timeout(Duration::from_secs(5), consumer.next())
+        .await
+        .expect("Timed out waiting for the message")
+        .expect("Consumer stream has ended")
+        .unwrap();
+    assert!(message.ack_handle.is_some());
+    message
+}
+
+async fn get_stored_offset(client: &IggyClient) -> Option<u64> {
+    client
+        .get_consumer_offset(
+            &Consumer::new(Identifier::named(CONSUMER_NAME).unwrap()),
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap()
+        .map(|offset| offset.stored_offset)
+}
+
+async fn wait_for_stored_offset(client: &IggyClient, offset: u64) {
+    for _ in 0..50 {
+        if get_stored_offset(client).await == Some(offset) {
+            return;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Offset: {offset} was not stored");
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
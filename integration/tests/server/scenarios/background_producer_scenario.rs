@@ -0,0 +1,149 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::background_producer::{BackgroundConfig, IggyBackgroundProducer};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u32 = 25;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The enqueued messages are sent in batches, and each one gets its delivery report
+    let producer = create_producer(
+        &client,
+        BackgroundConfig {
+            max_batch_messages: 10,
+            linger: IggyDuration::from_str("100ms").unwrap(),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let mut deliveries = Vec::new();
+    for id in 1..=MESSAGES_COUNT {
+        let delivery = producer.send(create_message(id)).await.unwrap();
+        deliveries.push(delivery);
+    }
+
+    for (id, delivery) in (1..=MESSAGES_COUNT).zip(deliveries) {
+        let report = delivery.await.unwrap();
+        assert_eq!(report.message_id, id as u128);
+        assert_eq!(report.partition_id, Some(PARTITION_ID));
+        assert_eq!(report.offset, Some(id as u64 - 1));
+    }
+
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT * 2,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (id, message) in (1..=MESSAGES_COUNT).zip(&polled_messages.messages) {
+        assert_eq!(message.id, id as u128);
+    }
+    producer.shutdown().await.unwrap();
+
+    // 2. When the buffer is full, the message can't be enqueued until the buffered ones are delivered
+    let producer = create_producer(
+        &client,
+        BackgroundConfig {
+            max_buffer_size: IggyByteSize::from(40),
+            linger: IggyDuration::from_str("1h").unwrap(),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let delivery = producer.try_send(create_message(1)).unwrap();
+    let error = producer.try_send(create_message(2)).unwrap_err();
+    assert_eq!(error.as_code(), IggyError::ProducerBufferFull.as_code());
+
+    producer.flush().await.unwrap();
+    delivery.await.unwrap();
+    let delivery = producer.try_send(create_message(2)).unwrap();
+    producer.shutdown().await.unwrap();
+    delivery.await.unwrap();
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn create_producer(client: &IggyClient, config: BackgroundConfig) -> IggyBackgroundProducer {
+    let mut producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .do_not_create_stream_if_not_exists()
+        .do_not_create_topic_if_not_exists()
+        .build();
+    producer.init().await.unwrap();
+    producer.background(config)
+}
+
+fn create_message(id: u32) -> Message {
+    Message::new(Some(id as u128), Bytes::from(format!("message-{id}")), None)
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,122 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::blocking::client::{
+    Client, ConsumerOffsetClient, StreamClient, SystemClient, TopicClient,
+};
+use iggy::blocking::IggyClient;
+use iggy::clients::consumer::AutoCommit;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::str::FromStr;
+
+const CONSUMER_NAME: &str = "blocking-consumer";
+const MESSAGES_COUNT: u32 = 10;
+
+pub fn run(server_addr: &str) {
+    let client = IggyClient::from_connection_string(&format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}"
+    ))
+    .unwrap();
+    client.connect().unwrap();
+    client.ping().unwrap();
+
+    // 1. The resources are managed with the blocking methods
+    client.create_stream(STREAM_NAME, Some(STREAM_ID)).unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .unwrap();
+    let stream = client
+        .get_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .unwrap()
+        .expect("Stream should exist");
+    assert_eq!(stream.name, STREAM_NAME);
+    assert_eq!(stream.topics_count, 1);
+
+    // 2. The messages are sent by the blocking producer
+    let producer = client
+        .producer(STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .without_send_interval()
+        .do_not_create_stream_if_not_exists()
+        .do_not_create_topic_if_not_exists()
+        .build()
+        .unwrap();
+    let messages = (1..=MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect();
+    producer.send(messages).unwrap();
+
+    // 3. The messages are received by iterating over the blocking consumer
+    let mut consumer = client
+        .consumer(CONSUMER_NAME, STREAM_NAME, TOPIC_NAME, PARTITION_ID)
+        .unwrap()
+        .polling_strategy(PollingStrategy::offset(0))
+        .auto_commit(AutoCommit::Disabled)
+        .build()
+        .unwrap();
+    for (id, message) in (1..=MESSAGES_COUNT).zip(consumer.by_ref()) {
+        let message = message.unwrap();
+        assert_eq!(message.message.payload, format!("message-{id}"));
+    }
+    assert!(consumer
+        .next_timeout(IggyDuration::from_str("200ms").unwrap())
+        .is_none());
+
+    consumer
+        .store_offset(MESSAGES_COUNT as u64 - 1, None)
+        .unwrap();
+    let offset = client
+        .get_consumer_offset(
+            &Consumer::new(Identifier::named(CONSUMER_NAME).unwrap()),
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .unwrap()
+        .expect("Consumer offset should exist");
+    assert_eq!(offset.stored_offset, MESSAGES_COUNT as u64 - 1);
+
+    drop(consumer);
+    drop(producer);
+    client
+        .delete_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .unwrap();
+    assert!(client.get_streams().unwrap().is_empty());
+}
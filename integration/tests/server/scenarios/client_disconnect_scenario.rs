@@ -0,0 +1,113 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{create_client, USERNAME_1};
+use iggy::client::{SystemClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::identifier::Identifier;
+use iggy::models::client_info::ClientInfo;
+use iggy::models::user_status::UserStatus;
+use integration::test_server::{assert_clean_system, delete_user, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const PASSWORD: &str = "secret";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let system_client = create_client(client_factory).await;
+    login_root(&system_client).await;
+
+    // 1. Create the user without any permissions and login the clients
+    system_client
+        .create_user(USERNAME_1, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+    let client3 = create_client(client_factory).await;
+    let user_id = client1
+        .login_user(USERNAME_1, PASSWORD)
+        .await
+        .unwrap()
+        .user_id;
+    client2.login_user(USERNAME_1, PASSWORD).await.unwrap();
+    client3.login_user(USERNAME_1, PASSWORD).await.unwrap();
+    let system_client_id = system_client.get_me().await.unwrap().client_id;
+    let clients = get_user_clients(&system_client, user_id).await;
+    assert_eq!(clients.len(), 3);
+
+    // 2. Disconnecting the client without the permission to manage the servers should fail
+    let disconnect_client = client1.disconnect_client(system_client_id).await;
+    assert!(disconnect_client.is_err());
+
+    // 3. Disconnecting the non-existing client should fail
+    let disconnect_client = system_client.disconnect_client(u32::MAX).await;
+    assert!(disconnect_client.is_err());
+
+    // 4. Disconnect the single client and validate that it's no longer listed
+    let client_id = clients[0].client_id;
+    system_client.disconnect_client(client_id).await.unwrap();
+    let clients = wait_for_user_clients(&system_client, user_id, 2).await;
+    assert!(clients.iter().all(|client| client.client_id != client_id));
+
+    // 5. Disconnect all the remaining clients of the user
+    system_client
+        .disconnect_user_clients(&Identifier::named(USERNAME_1).unwrap())
+        .await
+        .unwrap();
+    wait_for_user_clients(&system_client, user_id, 0).await;
+
+    // 6. The client which issued the disconnection should remain connected
+    let me = system_client.get_me().await.unwrap();
+    assert_eq!(me.client_id, system_client_id);
+
+    // 7. Disconnecting the clients of the non-existing user should fail
+    let disconnect_user_clients = system_client
+        .disconnect_user_clients(&Identifier::named("unknown").unwrap())
+        .await;
+    assert!(disconnect_user_clients.is_err());
+
+    delete_user(&system_client, USERNAME_1).await;
+    assert_clean_system(&system_client).await;
+}
+
+async fn get_user_clients(client: &IggyClient, user_id: u32) -> Vec<ClientInfo> {
+    client
+        .get_clients()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|client| client.user_id == Some(user_id))
+        .collect()
+}
+
+// The connections are closed asynchronously, thus the clients might still be listed for a while.
+async fn wait_for_user_clients(
+    client: &IggyClient,
+    user_id: u32,
+    expected_count: usize,
+) -> Vec<ClientInfo> {
+    for _ in 0..50 {
+        let clients = get_user_clients(client, user_id).await;
+        if clients.len() == expected_count {
+            return clients;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Expected {expected_count} clients for user with ID: {user_id}");
+}
@@ -0,0 +1,150 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, USERNAME_1,
+};
+use iggy::client::{StreamClient, SystemClient, TopicClient, UserClient};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, delete_user, login_root, ClientFactory};
+use std::path::Path;
+use std::str::FromStr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const PASSWORD: &str = "secret";
+const ALLOWED_ORIGIN: &str = "http://localhost:3000";
+const OTHER_ORIGIN: &str = "http://localhost:4000";
+const RELOADED_CONFIG: &str = r#"
+[heartbeat]
+interval = "7s"
+
+[http]
+max_request_size = "3 MB"
+
+[http.cors]
+allowed_origins = ["http://localhost:3000"]
+
+[system.topic]
+max_size = "10 GB"
+"#;
+
+pub async fn run(client_factory: &dyn ClientFactory, config_path: &Path, http_api_addr: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+
+    // 1. Reloading the unchanged configuration should not report any changes
+    let info = client.reload_config().await.unwrap();
+    assert!(info.is_empty());
+
+    // 2. Reloading the configuration without the permission to manage the servers should fail
+    client
+        .create_user(USERNAME_1, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let user_client = create_client(client_factory).await;
+    user_client.login_user(USERNAME_1, PASSWORD).await.unwrap();
+    assert!(user_client.reload_config().await.is_err());
+
+    // 3. Reload the changed configuration, the heartbeat interval, CORS origins and topic size quota can be applied at runtime
+    assert_eq!(
+        get_allowed_origin(http_api_addr, OTHER_ORIGIN).await,
+        Some(OTHER_ORIGIN.to_string())
+    );
+    std::fs::write(config_path, RELOADED_CONFIG).unwrap();
+    let info = client.reload_config().await.unwrap();
+    assert_eq!(
+        info.applied,
+        vec![
+            "heartbeat.interval",
+            "http.cors.allowed_origins",
+            "system.topic.max_size"
+        ]
+    );
+    assert_eq!(info.requires_restart, vec!["http.max_request_size"]);
+    assert_eq!(
+        get_allowed_origin(http_api_addr, ALLOWED_ORIGIN).await,
+        Some(ALLOWED_ORIGIN.to_string())
+    );
+    assert_eq!(get_allowed_origin(http_api_addr, OTHER_ORIGIN).await, None);
+
+    // 4. The topics created with the server default size should get the reloaded quota
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    let topic = client
+        .get_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get topic");
+    assert_eq!(
+        topic.max_topic_size,
+        MaxTopicSize::Custom(IggyByteSize::from_str("10 GB").unwrap())
+    );
+
+    // 5. Once applied, the changes are no longer reported, unlike the one requiring a restart
+    let info = client.reload_config().await.unwrap();
+    assert!(info.applied.is_empty());
+    assert_eq!(info.requires_restart, vec!["http.max_request_size"]);
+
+    // 6. Reloading the invalid configuration should fail
+    std::fs::write(config_path, "[heartbeat").unwrap();
+    assert!(client.reload_config().await.is_err());
+
+    cleanup(&client, false).await;
+    delete_user(&client, USERNAME_1).await;
+    assert_clean_system(&client).await;
+}
+
+/// Sends the CORS preflight request and returns the allowed origin from the response, if any.
+async fn get_allowed_origin(http_api_addr: &str, origin: &str) -> Option<String> {
+    let mut stream = TcpStream::connect(http_api_addr).await.unwrap();
+    let request = format!(
+        "OPTIONS /ping HTTP/1.1\r\nHost: {http_api_addr}\r\nOrigin: {origin}\r\nAccess-Control-Request-Method: GET\r\nConnection: close\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("access-control-allow-origin")
+            .then(|| value.trim().to_string())
+    })
+}
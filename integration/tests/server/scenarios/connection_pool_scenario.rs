@@ -0,0 +1,136 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    get_consumer_group, join_consumer_group, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME,
+    PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use futures::future::try_join_all;
+use iggy::client::{
+    Client, ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root};
+
+const POOL_SIZE: usize = 3;
+const BATCHES_COUNT: u32 = 50;
+
+pub async fn run(server_addr: &str) {
+    let client = IggyClient::builder()
+        .with_tcp()
+        .with_server_address(server_addr.to_string())
+        .with_pipelining()
+        .with_connection_pool(POOL_SIZE)
+        .build()
+        .unwrap();
+    client.connect().await.unwrap();
+    login_root(&client).await;
+
+    // 1. Every pooled connection should be signed in
+    let clients = client.get_clients().await.unwrap();
+    assert_eq!(clients.len(), POOL_SIZE);
+    assert!(clients.iter().all(|client| client.user_id.is_some()));
+
+    init_system(&client).await;
+
+    // 2. Send the batches concurrently, so they're pipelined over and balanced between the connections
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(1);
+    try_join_all((0..BATCHES_COUNT).map(|batch| {
+        let (client, stream_id, topic_id, partitioning) =
+            (&client, &stream_id, &topic_id, &partitioning);
+        async move {
+            let mut messages = vec![Message::new(None, Bytes::from(batch.to_string()), None)];
+            client
+                .send_messages(stream_id, topic_id, partitioning, &mut messages)
+                .await
+        }
+    }))
+    .await
+    .unwrap();
+
+    let stats = client.get_stats().await.unwrap();
+    assert_eq!(stats.messages_count, BATCHES_COUNT as u64);
+    let clients = client.get_clients().await.unwrap();
+    assert!(clients.iter().all(|client| client.user_id.is_some()));
+
+    // 3. The consumer group membership and polling should stick to the same (primary) connection
+    join_consumer_group(&client).await;
+    let consumer_group = get_consumer_group(&client).await;
+    assert_eq!(consumer_group.members_count, 1);
+    let me = client.get_me().await.unwrap();
+    assert_eq!(consumer_group.members[0].id, me.client_id);
+    assert_eq!(me.consumer_groups_count, 1);
+
+    let consumer = Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap());
+    for _ in 0..PARTITIONS_COUNT {
+        client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                None,
+                &consumer,
+                &PollingStrategy::next(),
+                BATCHES_COUNT,
+                true,
+            )
+            .await
+            .unwrap();
+    }
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,623 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    create_client, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, CONSUMER_ID, PARTITIONS_COUNT,
+    PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, USERNAME_1,
+};
+use futures::StreamExt;
+use iggy::error::IggyError;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_USER_ID};
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use server::grpc::proto;
+use server::grpc::proto::iggy_client::IggyClient;
+use server::grpc::ERROR_CODE_METADATA;
+use std::time::Duration;
+use tokio::time::timeout;
+use tonic::transport::Channel;
+use tonic::{Code, Request, Status, Streaming};
+
+const MESSAGES_COUNT: u64 = 5;
+const PASSWORD: &str = "secret-password";
+const NEW_PASSWORD: &str = "new-secret-password";
+const TOKEN_NAME: &str = "grpc-token";
+const MESSAGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn run(client_factory: &dyn ClientFactory, grpc_addr: &str) {
+    let mut grpc = IggyClient::connect(format!("http://{grpc_addr}"))
+        .await
+        .unwrap();
+
+    // 1. The ping and the login do not require the authentication, unlike the rest of the calls
+    let pong = grpc.ping(proto::PingRequest {}).await.unwrap().into_inner();
+    assert_eq!(pong.message, "pong");
+    let status = grpc
+        .get_streams(proto::GetStreamsRequest {})
+        .await
+        .unwrap_err();
+    assert_error(
+        &status,
+        Code::Unauthenticated,
+        IggyError::AccessTokenMissing,
+    );
+    let status = grpc
+        .get_streams(authorized(proto::GetStreamsRequest {}, "invalid"))
+        .await
+        .unwrap_err();
+    assert_error(
+        &status,
+        Code::Unauthenticated,
+        IggyError::InvalidAccessToken,
+    );
+    let status = grpc
+        .login_user(proto::LoginUserRequest {
+            username: DEFAULT_ROOT_USERNAME.to_owned(),
+            password: NEW_PASSWORD.to_owned(),
+        })
+        .await
+        .unwrap_err();
+    assert_error(
+        &status,
+        Code::Unauthenticated,
+        IggyError::InvalidCredentials,
+    );
+    let token = login(&mut grpc, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD).await;
+
+    // 2. The streams, the topics and the partitions are managed
+    let stream = grpc
+        .create_stream(authorized(
+            proto::CreateStreamRequest {
+                stream_id: Some(STREAM_ID),
+                name: STREAM_NAME.to_owned(),
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(stream.id, STREAM_ID);
+    assert_eq!(stream.name, STREAM_NAME);
+    let status = grpc
+        .create_stream(authorized(
+            proto::CreateStreamRequest {
+                stream_id: Some(STREAM_ID),
+                name: STREAM_NAME.to_owned(),
+            },
+            &token,
+        ))
+        .await
+        .unwrap_err();
+    assert_error(
+        &status,
+        Code::AlreadyExists,
+        IggyError::StreamNameAlreadyExists(STREAM_NAME.to_owned()),
+    );
+    let streams = grpc
+        .get_streams(authorized(proto::GetStreamsRequest {}, &token))
+        .await
+        .unwrap()
+        .into_inner()
+        .streams;
+    assert_eq!(streams.len(), 1);
+
+    let topic = grpc
+        .create_topic(authorized(
+            proto::CreateTopicRequest {
+                stream_id: Some(numeric(STREAM_ID)),
+                topic_id: Some(TOPIC_ID),
+                name: TOPIC_NAME.to_owned(),
+                partitions_count: PARTITIONS_COUNT,
+                ..Default::default()
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(topic.id, TOPIC_ID);
+    assert_eq!(topic.name, TOPIC_NAME);
+    assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
+    assert_eq!(topic.replication_factor, 1);
+
+    grpc.create_partitions(authorized(
+        proto::CreatePartitionsRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+            partitions_count: 2,
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    assert_eq!(
+        get_topic(&mut grpc, &token).await.partitions_count,
+        PARTITIONS_COUNT + 2
+    );
+    grpc.delete_partitions(authorized(
+        proto::DeletePartitionsRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+            partitions_count: 2,
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    assert_eq!(
+        get_topic(&mut grpc, &token).await.partitions_count,
+        PARTITIONS_COUNT
+    );
+
+    // 3. The messages are sent and polled
+    let appended_messages = send_messages(&mut grpc, &token, 0)
+        .await
+        .appended_messages
+        .expect("The appended messages should be reported");
+    assert_eq!(appended_messages.partition_id, PARTITION_ID);
+    assert_eq!(appended_messages.base_offset, 0);
+    assert_eq!(appended_messages.last_offset, MESSAGES_COUNT - 1);
+
+    let chunks = grpc
+        .poll_messages(authorized(poll_request(0), &token))
+        .await
+        .unwrap()
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(chunks.len(), 1);
+    let polled_messages = chunks.into_iter().next().unwrap().unwrap();
+    assert_eq!(polled_messages.partition_id, PARTITION_ID);
+    assert_eq!(polled_messages.current_offset, MESSAGES_COUNT - 1);
+    assert_messages(&polled_messages.messages, 0);
+
+    // 4. The consumer offsets are stored, returned and deleted
+    grpc.store_consumer_offset(authorized(
+        proto::StoreConsumerOffsetRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+            consumer_id: Some(numeric(CONSUMER_ID)),
+            partition_id: Some(PARTITION_ID),
+            offset: 2,
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    let offset = grpc
+        .get_consumer_offset(authorized(get_consumer_offset_request(), &token))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(offset.partition_id, PARTITION_ID);
+    assert_eq!(offset.current_offset, MESSAGES_COUNT - 1);
+    assert_eq!(offset.stored_offset, 2);
+    grpc.delete_consumer_offset(authorized(
+        proto::DeleteConsumerOffsetRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+            consumer_id: Some(numeric(CONSUMER_ID)),
+            partition_id: Some(PARTITION_ID),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    let status = grpc
+        .get_consumer_offset(authorized(get_consumer_offset_request(), &token))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+
+    // 5. The invalid subscription is rejected with the error status instead of the stream
+    let status = grpc
+        .subscribe_messages(authorized(
+            proto::PollMessagesRequest {
+                topic_id: Some(numeric(TOPIC_ID + 1)),
+                ..poll_request(0)
+            },
+            &token,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+
+    // 6. The subscription streams the existing and the newly appended messages
+    let mut subscription = grpc
+        .subscribe_messages(authorized(poll_request(0), &token))
+        .await
+        .unwrap()
+        .into_inner();
+    let messages = next_messages(&mut subscription, MESSAGES_COUNT).await;
+    assert_messages(&messages, 0);
+    send_messages(&mut grpc, &token, MESSAGES_COUNT).await;
+    let messages = next_messages(&mut subscription, MESSAGES_COUNT).await;
+    assert_messages(&messages, MESSAGES_COUNT);
+    drop(subscription);
+
+    // 7. The consumer groups are managed
+    let consumer_group = grpc
+        .create_consumer_group(authorized(
+            proto::CreateConsumerGroupRequest {
+                stream_id: Some(numeric(STREAM_ID)),
+                topic_id: Some(numeric(TOPIC_ID)),
+                group_id: Some(CONSUMER_GROUP_ID),
+                name: CONSUMER_GROUP_NAME.to_owned(),
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(consumer_group.id, CONSUMER_GROUP_ID);
+    assert_eq!(consumer_group.name, CONSUMER_GROUP_NAME);
+    assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
+    assert_eq!(consumer_group.members_count, 0);
+    let consumer_group = grpc
+        .get_consumer_group(authorized(
+            proto::GetConsumerGroupRequest {
+                stream_id: Some(numeric(STREAM_ID)),
+                topic_id: Some(numeric(TOPIC_ID)),
+                group_id: Some(named(CONSUMER_GROUP_NAME)),
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(consumer_group.id, CONSUMER_GROUP_ID);
+    let consumer_groups = grpc
+        .get_consumer_groups(authorized(
+            proto::GetConsumerGroupsRequest {
+                stream_id: Some(numeric(STREAM_ID)),
+                topic_id: Some(numeric(TOPIC_ID)),
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .groups;
+    assert_eq!(consumer_groups.len(), 1);
+    grpc.delete_consumer_group(authorized(
+        proto::DeleteConsumerGroupRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+            group_id: Some(numeric(CONSUMER_GROUP_ID)),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+
+    // 8. The users are managed and the logged out user's token is revoked
+    let user = grpc
+        .create_user(authorized(
+            proto::CreateUserRequest {
+                username: USERNAME_1.to_owned(),
+                password: PASSWORD.to_owned(),
+                status: proto::UserStatus::Active as i32,
+                permissions: None,
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(user.username, USERNAME_1);
+    assert!(user.permissions.is_none());
+    let users = grpc
+        .get_users(authorized(proto::GetUsersRequest {}, &token))
+        .await
+        .unwrap()
+        .into_inner()
+        .users;
+    assert_eq!(users.len(), 2);
+    grpc.update_permissions(authorized(
+        proto::UpdatePermissionsRequest {
+            user_id: Some(numeric(user.id)),
+            permissions: Some(proto::Permissions {
+                global: Some(proto::GlobalPermissions {
+                    read_streams: true,
+                    ..Default::default()
+                }),
+                streams: Default::default(),
+            }),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    grpc.change_password(authorized(
+        proto::ChangePasswordRequest {
+            user_id: Some(named(USERNAME_1)),
+            current_password: PASSWORD.to_owned(),
+            new_password: NEW_PASSWORD.to_owned(),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+
+    let user_token = login(&mut grpc, USERNAME_1, NEW_PASSWORD).await;
+    let streams = grpc
+        .get_streams(authorized(proto::GetStreamsRequest {}, &user_token))
+        .await
+        .unwrap()
+        .into_inner()
+        .streams;
+    assert_eq!(streams.len(), 1);
+    let status = grpc
+        .delete_stream(authorized(
+            proto::DeleteStreamRequest {
+                stream_id: Some(numeric(STREAM_ID)),
+            },
+            &user_token,
+        ))
+        .await
+        .unwrap_err();
+    assert_error(&status, Code::PermissionDenied, IggyError::Unauthorized);
+    grpc.logout_user(authorized(proto::LogoutUserRequest {}, &user_token))
+        .await
+        .unwrap();
+    let status = grpc
+        .get_streams(authorized(proto::GetStreamsRequest {}, &user_token))
+        .await
+        .unwrap_err();
+    assert_error(
+        &status,
+        Code::Unauthenticated,
+        IggyError::InvalidAccessToken,
+    );
+
+    grpc.update_user(authorized(
+        proto::UpdateUserRequest {
+            user_id: Some(numeric(user.id)),
+            username: None,
+            status: Some(proto::UserStatus::Inactive as i32),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    let user = grpc
+        .get_user(authorized(
+            proto::GetUserRequest {
+                user_id: Some(numeric(user.id)),
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(user.status, proto::UserStatus::Inactive as i32);
+    assert!(user.permissions.unwrap().global.unwrap().read_streams);
+    grpc.delete_user(authorized(
+        proto::DeleteUserRequest {
+            user_id: Some(numeric(user.id)),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+
+    // 9. The personal access tokens are managed and used for the login
+    let raw_token = grpc
+        .create_personal_access_token(authorized(
+            proto::CreatePersonalAccessTokenRequest {
+                name: TOKEN_NAME.to_owned(),
+                expiry: 0,
+            },
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .token;
+    let identity = grpc
+        .login_with_personal_access_token(proto::LoginWithPersonalAccessTokenRequest {
+            token: raw_token,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(identity.user_id, DEFAULT_ROOT_USER_ID);
+    let tokens = grpc
+        .get_personal_access_tokens(authorized(
+            proto::GetPersonalAccessTokensRequest {},
+            &identity.access_token,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .tokens;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].name, TOKEN_NAME);
+    grpc.delete_personal_access_token(authorized(
+        proto::DeletePersonalAccessTokenRequest {
+            name: TOKEN_NAME.to_owned(),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+
+    // 10. The topic and the stream are purged and deleted
+    grpc.purge_topic(authorized(
+        proto::PurgeTopicRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    assert_eq!(get_topic(&mut grpc, &token).await.messages_count, 0);
+    grpc.delete_topic(authorized(
+        proto::DeleteTopicRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(named(TOPIC_NAME)),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+    grpc.delete_stream(authorized(
+        proto::DeleteStreamRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+        },
+        &token,
+    ))
+    .await
+    .unwrap();
+
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    assert_clean_system(&client).await;
+}
+
+fn authorized<T>(message: T, token: &str) -> Request<T> {
+    let mut request = Request::new(message);
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    request
+}
+
+fn numeric(id: u32) -> proto::Identifier {
+    proto::Identifier {
+        kind: Some(proto::identifier::Kind::Numeric(id)),
+    }
+}
+
+fn named(name: &str) -> proto::Identifier {
+    proto::Identifier {
+        kind: Some(proto::identifier::Kind::Name(name.to_owned())),
+    }
+}
+
+fn assert_error(status: &Status, code: Code, error: IggyError) {
+    assert_eq!(status.code(), code);
+    let error_code = status
+        .metadata()
+        .get(ERROR_CODE_METADATA)
+        .expect("The error code should be included in the metadata")
+        .to_str()
+        .unwrap();
+    assert_eq!(error_code, error.as_code().to_string());
+}
+
+async fn login(grpc: &mut IggyClient<Channel>, username: &str, password: &str) -> String {
+    grpc.login_user(proto::LoginUserRequest {
+        username: username.to_owned(),
+        password: password.to_owned(),
+    })
+    .await
+    .unwrap()
+    .into_inner()
+    .access_token
+}
+
+async fn get_topic(grpc: &mut IggyClient<Channel>, token: &str) -> proto::TopicDetails {
+    grpc.get_topic(authorized(
+        proto::GetTopicRequest {
+            stream_id: Some(named(STREAM_NAME)),
+            topic_id: Some(numeric(TOPIC_ID)),
+        },
+        token,
+    ))
+    .await
+    .unwrap()
+    .into_inner()
+}
+
+async fn send_messages(
+    grpc: &mut IggyClient<Channel>,
+    token: &str,
+    start_id: u64,
+) -> proto::SendMessagesResponse {
+    let messages = (start_id..start_id + MESSAGES_COUNT)
+        .map(|id| proto::Message {
+            id: Vec::new().into(),
+            payload: format!("message-{id}").into_bytes().into(),
+            headers: Default::default(),
+        })
+        .collect();
+    grpc.send_messages(authorized(
+        proto::SendMessagesRequest {
+            stream_id: Some(numeric(STREAM_ID)),
+            topic_id: Some(numeric(TOPIC_ID)),
+            partitioning: Some(proto::Partitioning {
+                kind: Some(proto::partitioning::Kind::PartitionId(PARTITION_ID)),
+            }),
+            messages,
+        },
+        token,
+    ))
+    .await
+    .unwrap()
+    .into_inner()
+}
+
+fn poll_request(offset: u64) -> proto::PollMessagesRequest {
+    proto::PollMessagesRequest {
+        stream_id: Some(numeric(STREAM_ID)),
+        topic_id: Some(numeric(TOPIC_ID)),
+        consumer_id: Some(numeric(CONSUMER_ID)),
+        partition_id: Some(PARTITION_ID),
+        kind: proto::PollingKind::Offset as i32,
+        value: offset,
+        count: 100,
+        auto_commit: false,
+        max_wait_ms: 0,
+    }
+}
+
+fn get_consumer_offset_request() -> proto::GetConsumerOffsetRequest {
+    proto::GetConsumerOffsetRequest {
+        stream_id: Some(numeric(STREAM_ID)),
+        topic_id: Some(numeric(TOPIC_ID)),
+        consumer_id: Some(numeric(CONSUMER_ID)),
+        partition_id: Some(PARTITION_ID),
+    }
+}
+
+async fn next_messages(
+    subscription: &mut Streaming<proto::PolledMessages>,
+    count: u64,
+) -> Vec<proto::PolledMessage> {
+    let mut messages = Vec::new();
+    while (messages.len() as u64) < count {
+        let polled_messages = timeout(MESSAGE_TIMEOUT, subscription.message())
+            .await
+            .expect("Timed out waiting for the messages")
+            .unwrap()
+            .expect("The subscription has ended");
+        messages.extend(polled_messages.messages);
+    }
+    messages
+}
+
+fn assert_messages(messages: &[proto::PolledMessage], start_offset: u64) {
+    assert_eq!(messages.len() as u64, MESSAGES_COUNT);
+    for (offset, message) in (start_offset..).zip(messages) {
+        assert_eq!(message.offset, offset);
+        assert_eq!(message.state, proto::MessageState::Available as i32);
+        assert_eq!(message.id.len(), 16);
+        assert_eq!(message.payload, format!("message-{offset}").into_bytes());
+    }
+}
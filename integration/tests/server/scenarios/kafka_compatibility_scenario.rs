@@ -0,0 +1,533 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::HeaderKey;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use kafka_protocol::messages::join_group_request::JoinGroupRequestProtocol;
+use kafka_protocol::messages::leave_group_request::MemberIdentity;
+use kafka_protocol::messages::offset_commit_request::{
+    OffsetCommitRequestPartition, OffsetCommitRequestTopic,
+};
+use kafka_protocol::messages::offset_fetch_request::OffsetFetchRequestTopic;
+use kafka_protocol::messages::sync_group_request::SyncGroupRequestAssignment;
+use kafka_protocol::messages::{
+    FindCoordinatorRequest, GroupId, HeartbeatRequest, JoinGroupRequest, JoinGroupResponse,
+    LeaveGroupRequest, OffsetCommitRequest, OffsetFetchRequest, RequestHeader, ResponseHeader,
+    SaslAuthenticateRequest, SaslHandshakeRequest, SyncGroupRequest, TopicName,
+};
+use kafka_protocol::protocol::{Decodable, Encodable, HeaderVersion, Request, StrBytes};
+use kafka_protocol::ResponseError;
+use rskafka::chrono::Utc;
+use rskafka::client::partition::{Compression, OffsetAt, UnknownTopicHandling};
+use rskafka::client::{Client, ClientBuilder, Credentials, SaslConfig};
+use rskafka::record::Record;
+use rskafka::BackoffConfig;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::sleep;
+
+const RECORDS_COUNT: usize = 3;
+const KEY_HEADER: &str = "kafka-key";
+const GROUP_ID: &str = "kafka-group";
+const PROTOCOL_TYPE: &str = "consumer";
+const PROTOCOL_NAME: &str = "range";
+
+pub async fn run(client_factory: &dyn ClientFactory, kafka_addr: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let kafka_client = create_kafka_client(kafka_addr, DEFAULT_ROOT_PASSWORD)
+        .await
+        .expect("Failed to connect the Kafka client");
+    let kafka_topic = format!("{STREAM_NAME}.{TOPIC_NAME}");
+
+    // 1. The topics of the default stream are listed under their own names
+    let topics = kafka_client.list_topics().await.unwrap();
+    let topic = topics
+        .iter()
+        .find(|topic| topic.name == TOPIC_NAME)
+        .expect("Topic not found in the Kafka metadata");
+    assert_eq!(
+        topic.partitions.iter().copied().collect::<Vec<_>>(),
+        (0..PARTITIONS_COUNT as i32).collect::<Vec<_>>()
+    );
+
+    // 2. The records produced to the Kafka partition are appended to the Iggy partition
+    let partition_client = kafka_client
+        .partition_client(kafka_topic.clone(), 0, UnknownTopicHandling::Error)
+        .await
+        .unwrap();
+    let records = (0..RECORDS_COUNT).map(create_record).collect::<Vec<_>>();
+    let offsets = partition_client
+        .produce(records.clone(), Compression::NoCompression)
+        .await
+        .unwrap();
+    assert_eq!(offsets, (0..RECORDS_COUNT as i64).collect::<Vec<_>>());
+
+    // 3. The records are fetched with their keys, values and headers
+    let (fetched_records, high_watermark) = partition_client
+        .fetch_records(0, 1..1_000_000, 1_000)
+        .await
+        .unwrap();
+    assert_eq!(high_watermark, RECORDS_COUNT as i64);
+    assert_eq!(fetched_records.len(), RECORDS_COUNT);
+    for (index, fetched_record) in fetched_records.iter().enumerate() {
+        assert_eq!(fetched_record.offset, index as i64);
+        assert_eq!(fetched_record.record.key, records[index].key);
+        assert_eq!(fetched_record.record.value, records[index].value);
+        assert_eq!(fetched_record.record.headers, records[index].headers);
+    }
+
+    // 4. The records are polled by the Iggy clients as the messages with the key stored in the header
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            RECORDS_COUNT as u32,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), RECORDS_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(
+            message.payload.to_vec(),
+            records[index].value.clone().unwrap()
+        );
+        let headers = message.headers.as_ref().unwrap();
+        let key = headers.get(&HeaderKey::new(KEY_HEADER).unwrap()).unwrap();
+        assert_eq!(
+            key.as_raw().unwrap(),
+            records[index].key.as_deref().unwrap()
+        );
+    }
+
+    // 5. The messages sent by the Iggy clients are fetched by the Kafka clients
+    let mut messages = vec![Message::new(None, Bytes::from("iggy-message"), None)];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID + 1),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    let second_partition_client = kafka_client
+        .partition_client(TOPIC_NAME, 1, UnknownTopicHandling::Error)
+        .await
+        .unwrap();
+    let (fetched_records, high_watermark) = second_partition_client
+        .fetch_records(0, 1..1_000_000, 1_000)
+        .await
+        .unwrap();
+    assert_eq!(high_watermark, 1);
+    assert_eq!(fetched_records.len(), 1);
+    assert_eq!(fetched_records[0].record.key, None);
+    assert_eq!(
+        fetched_records[0].record.value.as_deref(),
+        Some(b"iggy-message".as_slice())
+    );
+
+    // 6. The earliest and the latest offsets are listed
+    let earliest_offset = partition_client
+        .get_offset(OffsetAt::Earliest)
+        .await
+        .unwrap();
+    let latest_offset = partition_client.get_offset(OffsetAt::Latest).await.unwrap();
+    assert_eq!(earliest_offset, 0);
+    assert_eq!(latest_offset, RECORDS_COUNT as i64);
+
+    // 7. The unknown topics are reported as such
+    let result = kafka_client
+        .partition_client(
+            format!("{STREAM_NAME}.unknown"),
+            0,
+            UnknownTopicHandling::Error,
+        )
+        .await;
+    assert!(result.is_err());
+
+    // 8. The clients with the invalid credentials can't connect
+    assert!(create_kafka_client(kafka_addr, "invalid-password")
+        .await
+        .is_err());
+
+    // 9. The consumer groups are coordinated and their offsets are stored as the Iggy consumer offsets
+    run_consumer_group(&client, kafka_addr, &kafka_topic).await;
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn run_consumer_group(client: &IggyClient, kafka_addr: &str, kafka_topic: &str) {
+    let mut first_connection = KafkaConnection::connect(kafka_addr).await;
+    let mut second_connection = KafkaConnection::connect(kafka_addr).await;
+
+    let coordinator = first_connection
+        .send(
+            FindCoordinatorRequest::default().with_key(StrBytes::from_static_str(GROUP_ID)),
+            3,
+        )
+        .await;
+    assert_eq!(coordinator.error_code, 0);
+    assert_eq!(
+        coordinator.port,
+        kafka_addr.parse::<SocketAddr>().unwrap().port() as i32
+    );
+
+    // The first member is the leader of the first generation
+    let joined = first_connection.join_group("", b"first").await;
+    assert_eq!(joined.error_code, 0);
+    assert_eq!(joined.generation_id, 1);
+    assert_eq!(joined.leader, joined.member_id);
+    assert_eq!(joined.members.len(), 1);
+    let first_member_id = joined.member_id.to_string();
+
+    let assignment = first_connection
+        .sync_group(
+            1,
+            &first_member_id,
+            vec![(&first_member_id, b"first-assignment")],
+        )
+        .await;
+    assert_eq!(assignment.as_ref(), b"first-assignment");
+    assert_eq!(first_connection.heartbeat(1, &first_member_id).await, 0);
+
+    // The committed offsets are stored as the offsets of the Iggy consumer named after the group
+    let error_code = first_connection
+        .commit_offset(1, &first_member_id, kafka_topic, 2)
+        .await;
+    assert_eq!(error_code, 0);
+    assert_eq!(first_connection.fetch_offset(kafka_topic).await, 2);
+    let consumer_offset = client
+        .get_consumer_offset(
+            &Consumer::new(Identifier::named(GROUP_ID).unwrap()),
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap()
+        .expect("Consumer offset not found");
+    assert_eq!(consumer_offset.stored_offset, 1);
+    let error_code = first_connection
+        .commit_offset(5, &first_member_id, kafka_topic, 3)
+        .await;
+    assert_eq!(error_code, ResponseError::IllegalGeneration.code());
+
+    // The second member joining the group triggers the rebalance
+    let second_join = tokio::spawn(async move {
+        let joined = second_connection.join_group("", b"second").await;
+        (second_connection, joined)
+    });
+    let mut error_code = 0;
+    for _ in 0..50 {
+        error_code = first_connection.heartbeat(1, &first_member_id).await;
+        if error_code != 0 {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(error_code, ResponseError::RebalanceInProgress.code());
+
+    let joined = first_connection
+        .join_group(&first_member_id, b"first")
+        .await;
+    assert_eq!(joined.error_code, 0);
+    assert_eq!(joined.generation_id, 2);
+    assert_eq!(joined.leader.as_str(), first_member_id);
+    assert_eq!(joined.members.len(), 2);
+    let (mut second_connection, second_joined) = second_join.await.unwrap();
+    assert_eq!(second_joined.error_code, 0);
+    assert_eq!(second_joined.generation_id, 2);
+    assert!(second_joined.members.is_empty());
+    let second_member_id = second_joined.member_id.to_string();
+
+    // The followers receive the assignments distributed by the leader
+    let follower_member_id = second_member_id.clone();
+    let second_sync = tokio::spawn(async move {
+        let assignment = second_connection
+            .sync_group(2, &follower_member_id, Vec::new())
+            .await;
+        (second_connection, assignment)
+    });
+    sleep(Duration::from_millis(200)).await;
+    let assignment = first_connection
+        .sync_group(
+            2,
+            &first_member_id,
+            vec![
+                (&first_member_id, b"first-assignment"),
+                (&second_member_id, b"second-assignment"),
+            ],
+        )
+        .await;
+    assert_eq!(assignment.as_ref(), b"first-assignment");
+    let (mut second_connection, assignment) = second_sync.await.unwrap();
+    assert_eq!(assignment.as_ref(), b"second-assignment");
+    assert_eq!(second_connection.heartbeat(2, &second_member_id).await, 0);
+
+    // The member leaving the group triggers the rebalance as well
+    let left = second_connection
+        .send(
+            LeaveGroupRequest::default()
+                .with_group_id(GroupId(StrBytes::from_static_str(GROUP_ID)))
+                .with_members(vec![MemberIdentity::default()
+                    .with_member_id(StrBytes::from_string(second_member_id))]),
+            3,
+        )
+        .await;
+    assert_eq!(left.error_code, 0);
+    assert_eq!(left.members[0].error_code, 0);
+    assert_eq!(
+        first_connection.heartbeat(2, &first_member_id).await,
+        ResponseError::RebalanceInProgress.code()
+    );
+    let joined = first_connection
+        .join_group(&first_member_id, b"first")
+        .await;
+    assert_eq!(joined.generation_id, 3);
+    assert_eq!(joined.members.len(), 1);
+}
+
+struct KafkaConnection {
+    stream: TcpStream,
+    correlation_id: i32,
+}
+
+impl KafkaConnection {
+    async fn connect(kafka_addr: &str) -> Self {
+        let stream = TcpStream::connect(kafka_addr).await.unwrap();
+        let mut connection = Self {
+            stream,
+            correlation_id: 0,
+        };
+        let handshake = connection
+            .send(
+                SaslHandshakeRequest::default().with_mechanism(StrBytes::from_static_str("PLAIN")),
+                1,
+            )
+            .await;
+        assert_eq!(handshake.error_code, 0);
+        let authenticated = connection
+            .send(
+                SaslAuthenticateRequest::default().with_auth_bytes(Bytes::from(format!(
+                    "\0{DEFAULT_ROOT_USERNAME}\0{DEFAULT_ROOT_PASSWORD}"
+                ))),
+                1,
+            )
+            .await;
+        assert_eq!(authenticated.error_code, 0);
+        connection
+    }
+
+    async fn send<R: Request>(&mut self, request: R, version: i16) -> R::Response {
+        self.correlation_id += 1;
+        let mut buffer = BytesMut::new();
+        RequestHeader::default()
+            .with_request_api_key(R::KEY)
+            .with_request_api_version(version)
+            .with_correlation_id(self.correlation_id)
+            .with_client_id(Some(StrBytes::from_static_str("iggy-integration")))
+            .encode(&mut buffer, R::header_version(version))
+            .unwrap();
+        request.encode(&mut buffer, version).unwrap();
+        let mut frame = BytesMut::new();
+        frame.put_i32(buffer.len() as i32);
+        frame.put_slice(&buffer);
+        self.stream.write_all(&frame).await.unwrap();
+
+        let length = self.stream.read_i32().await.unwrap();
+        let mut response = vec![0; length as usize];
+        self.stream.read_exact(&mut response).await.unwrap();
+        let mut response = Bytes::from(response);
+        let header =
+            ResponseHeader::decode(&mut response, R::Response::header_version(version)).unwrap();
+        assert_eq!(header.correlation_id, self.correlation_id);
+        let decoded = R::Response::decode(&mut response, version).unwrap();
+        assert!(!response.has_remaining());
+        decoded
+    }
+
+    async fn join_group(&mut self, member_id: &str, metadata: &'static [u8]) -> JoinGroupResponse {
+        self.send(
+            JoinGroupRequest::default()
+                .with_group_id(GroupId(StrBytes::from_static_str(GROUP_ID)))
+                .with_session_timeout_ms(30_000)
+                .with_rebalance_timeout_ms(10_000)
+                .with_member_id(StrBytes::from_string(member_id.to_owned()))
+                .with_protocol_type(StrBytes::from_static_str(PROTOCOL_TYPE))
+                .with_protocols(vec![JoinGroupRequestProtocol::default()
+                    .with_name(StrBytes::from_static_str(PROTOCOL_NAME))
+                    .with_metadata(Bytes::from_static(metadata))]),
+            5,
+        )
+        .await
+    }
+
+    async fn sync_group(
+        &mut self,
+        generation_id: i32,
+        member_id: &str,
+        assignments: Vec<(&str, &'static [u8])>,
+    ) -> Bytes {
+        let response = self
+            .send(
+                SyncGroupRequest::default()
+                    .with_group_id(GroupId(StrBytes::from_static_str(GROUP_ID)))
+                    .with_generation_id(generation_id)
+                    .with_member_id(StrBytes::from_string(member_id.to_owned()))
+                    .with_assignments(
+                        assignments
+                            .into_iter()
+                            .map(|(member_id, assignment)| {
+                                SyncGroupRequestAssignment::default()
+                                    .with_member_id(StrBytes::from_string(member_id.to_owned()))
+                                    .with_assignment(Bytes::from_static(assignment))
+                            })
+                            .collect(),
+                    ),
+                3,
+            )
+            .await;
+        assert_eq!(response.error_code, 0);
+        response.assignment
+    }
+
+    async fn heartbeat(&mut self, generation_id: i32, member_id: &str) -> i16 {
+        self.send(
+            HeartbeatRequest::default()
+                .with_group_id(GroupId(StrBytes::from_static_str(GROUP_ID)))
+                .with_generation_id(generation_id)
+                .with_member_id(StrBytes::from_string(member_id.to_owned())),
+            3,
+        )
+        .await
+        .error_code
+    }
+
+    async fn commit_offset(
+        &mut self,
+        generation_id: i32,
+        member_id: &str,
+        topic: &str,
+        offset: i64,
+    ) -> i16 {
+        let response = self
+            .send(
+                OffsetCommitRequest::default()
+                    .with_group_id(GroupId(StrBytes::from_static_str(GROUP_ID)))
+                    .with_generation_id_or_member_epoch(generation_id)
+                    .with_member_id(StrBytes::from_string(member_id.to_owned()))
+                    .with_topics(vec![OffsetCommitRequestTopic::default()
+                        .with_name(TopicName(StrBytes::from_string(topic.to_owned())))
+                        .with_partitions(vec![OffsetCommitRequestPartition::default()
+                            .with_partition_index(0)
+                            .with_committed_offset(offset)])]),
+                7,
+            )
+            .await;
+        response.topics[0].partitions[0].error_code
+    }
+
+    async fn fetch_offset(&mut self, topic: &str) -> i64 {
+        let response = self
+            .send(
+                OffsetFetchRequest::default()
+                    .with_group_id(GroupId(StrBytes::from_static_str(GROUP_ID)))
+                    .with_topics(Some(vec![OffsetFetchRequestTopic::default()
+                        .with_name(TopicName(StrBytes::from_string(topic.to_owned())))
+                        .with_partition_indexes(vec![0])])),
+                6,
+            )
+            .await;
+        let partition = &response.topics[0].partitions[0];
+        assert_eq!(partition.error_code, 0);
+        partition.committed_offset
+    }
+}
+
+async fn create_kafka_client(
+    kafka_addr: &str,
+    password: &str,
+) -> Result<Client, rskafka::client::error::Error> {
+    ClientBuilder::new(vec![kafka_addr.to_owned()])
+        .sasl_config(SaslConfig::Plain(Credentials::new(
+            DEFAULT_ROOT_USERNAME.to_owned(),
+            password.to_owned(),
+        )))
+        .backoff_config(BackoffConfig {
+            deadline: Some(Duration::from_secs(3)),
+            ..Default::default()
+        })
+        .build()
+        .await
+}
+
+fn create_record(index: usize) -> Record {
+    Record {
+        key: Some(format!("key-{index}").into_bytes()),
+        value: Some(format!("value-{index}").into_bytes()),
+        headers: BTreeMap::from([("trace-id".to_owned(), format!("trace-{index}").into_bytes())]),
+        timestamp: Utc::now(),
+    }
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,145 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const MESSAGES_COUNT: u32 = 5;
+const SEND_DELAY: Duration = Duration::from_millis(500);
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let producer = create_client(client_factory).await;
+    login_root(&producer).await;
+
+    // 1. Without the max wait, the poll returns immediately when there are no messages
+    let polled_messages = poll_messages(&client, 0, IggyDuration::default()).await;
+    assert!(polled_messages.is_empty());
+
+    // 2. The poll returns no messages once the max wait elapses
+    let max_wait = IggyDuration::from_str("300ms").unwrap();
+    let now = Instant::now();
+    let polled_messages = poll_messages(&client, 0, max_wait).await;
+    assert!(polled_messages.is_empty());
+    assert!(now.elapsed() >= max_wait.get_duration());
+
+    // 3. The parked poll returns as soon as the messages are appended
+    let max_wait = IggyDuration::from_str("30s").unwrap();
+    let now = Instant::now();
+    let send_messages = tokio::spawn(async move {
+        sleep(SEND_DELAY).await;
+        send_messages(&producer).await;
+    });
+    let polled_messages = poll_messages(&client, 0, max_wait).await;
+    let elapsed = now.elapsed();
+    assert_eq!(
+        polled_messages,
+        (0..MESSAGES_COUNT as u64).collect::<Vec<_>>()
+    );
+    assert!(elapsed >= SEND_DELAY);
+    assert!(elapsed < max_wait.get_duration());
+    send_messages.await.unwrap();
+
+    // 4. The already available messages are returned without waiting
+    let now = Instant::now();
+    let polled_messages = poll_messages(&client, 1, max_wait).await;
+    assert_eq!(
+        polled_messages,
+        (1..MESSAGES_COUNT as u64).collect::<Vec<_>>()
+    );
+    assert!(now.elapsed() < max_wait.get_duration());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn poll_messages(client: &IggyClient, offset: u64, max_wait: IggyDuration) -> Vec<u64> {
+    client
+        .poll_messages_with_wait(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(offset),
+            MESSAGES_COUNT,
+            false,
+            max_wait,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.offset)
+        .collect()
+}
+
+async fn send_messages(client: &IggyClient) {
+    let mut messages = (1..=MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(PARTITION_ID);
+
+    // 1. The offsets of the first batch start from 0
+    let mut messages = create_messages(3);
+    let appended_messages = client
+        .send_messages_with_offsets(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap()
+        .expect("Missing appended messages");
+    assert_eq!(appended_messages.partition_id, PARTITION_ID);
+    assert_eq!(appended_messages.base_offset, 0);
+    assert_eq!(appended_messages.last_offset, 2);
+    assert_eq!(appended_messages.count(), 3);
+    assert!(appended_messages.base_timestamp > 0);
+    assert!(appended_messages.base_timestamp <= appended_messages.last_timestamp);
+
+    // 2. The messages sent without the offsets are still appended
+    let mut messages = create_messages(2);
+    client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap();
+
+    // 3. The offsets of the next batch follow the previously appended messages
+    let mut messages = create_messages(2);
+    let appended_messages = client
+        .send_messages_with_offsets(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap()
+        .expect("Missing appended messages");
+    assert_eq!(appended_messages.partition_id, PARTITION_ID);
+    assert_eq!(appended_messages.base_offset, 5);
+    assert_eq!(appended_messages.last_offset, 6);
+
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(appended_messages.base_offset),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 2);
+    assert_eq!(
+        polled_messages.messages[0].timestamp,
+        appended_messages.base_timestamp
+    );
+    assert_eq!(
+        polled_messages.messages[1].timestamp,
+        appended_messages.last_timestamp
+    );
+
+    // 4. The partition calculated by the server is returned for the balanced partitioning
+    let mut messages = create_messages(1);
+    let appended_messages = client
+        .send_messages_with_offsets(
+            &stream_id,
+            &topic_id,
+            &Partitioning::balanced(),
+            &mut messages,
+        )
+        .await
+        .unwrap()
+        .expect("Missing appended messages");
+    assert!((1..=PARTITIONS_COUNT).contains(&appended_messages.partition_id));
+    assert_eq!(appended_messages.count(), 1);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+fn create_messages(count: u32) -> Vec<Message> {
+    (1..=count)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect()
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,316 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use futures::StreamExt;
+use iggy::binary::subscriptions::MessageSubscription;
+use iggy::client::{AutoLogin, Client, MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::quic::client::QuicClient;
+use iggy::quic::config::QuicClientConfig;
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::TcpClientConfig;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy::websocket::client::WebSocketClient;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const MESSAGES_COUNT: u32 = 10;
+const BATCH_SIZE: u32 = 4;
+const PUSH_TIMEOUT: Duration = Duration::from_secs(5);
+const NO_PUSH_TIMEOUT: Duration = Duration::from_millis(500);
+
+pub async fn run(websocket_server_addr: &str, tcp_server_addr: &str, quic_server_addr: &str) {
+    let client = WebSocketClient::new(websocket_server_addr, AutoLogin::Disabled).unwrap();
+    client.connect().await.unwrap();
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    init_system(&client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::default();
+    send_messages(&client, 1).await;
+
+    // 1. The already appended messages are pushed in batches of at most the requested count
+    let mut subscription = client
+        .subscribe_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &PollingStrategy::offset(0),
+            BATCH_SIZE,
+            false,
+            0,
+        )
+        .await
+        .unwrap();
+    let offsets = receive_offsets(&mut subscription, MESSAGES_COUNT).await;
+    assert_eq!(offsets, (0..MESSAGES_COUNT as u64).collect::<Vec<_>>());
+
+    // 2. The messages appended after subscribing are pushed as well
+    send_messages(&client, MESSAGES_COUNT + 1).await;
+    let offsets = receive_offsets(&mut subscription, MESSAGES_COUNT).await;
+    assert_eq!(
+        offsets,
+        (MESSAGES_COUNT as u64..2 * MESSAGES_COUNT as u64).collect::<Vec<_>>()
+    );
+
+    // 3. The regular requests are still handled while the subscription is active
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(0),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+
+    // 4. The subscription stream ends once unsubscribed
+    client
+        .unsubscribe_messages(subscription.id())
+        .await
+        .unwrap();
+    let next = timeout(PUSH_TIMEOUT, subscription.next()).await.unwrap();
+    assert!(next.is_none());
+    let error = client
+        .unsubscribe_messages(subscription.id())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::MessageSubscriptionNotFound(0).as_code()
+    );
+
+    // 5. The TCP subscriptions require the pipelining, as the pushed messages are interleaved with the responses
+    let tcp_client = create_tcp_client(tcp_server_addr, false).await;
+    let error = tcp_client
+        .subscribe_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &PollingStrategy::offset(0),
+            BATCH_SIZE,
+            false,
+            0,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::MessageSubscriptionsNotSupported.as_code()
+    );
+
+    // 6. The pipelined TCP subscription pushes only as many batches as the granted credits
+    let tcp_client = create_tcp_client(tcp_server_addr, true).await;
+    let mut subscription = tcp_client
+        .subscribe_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &PollingStrategy::offset(0),
+            BATCH_SIZE,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    assert_credited_batches(&mut subscription, 0, 1).await;
+    tcp_client
+        .grant_message_credits(subscription.id(), 2)
+        .await
+        .unwrap();
+    assert_credited_batches(&mut subscription, BATCH_SIZE, 2).await;
+    tcp_client
+        .unsubscribe_messages(subscription.id())
+        .await
+        .unwrap();
+
+    // 7. The QUIC subscription pushes the batches over the server-opened streams
+    let quic_client = QuicClient::create(Arc::new(QuicClientConfig {
+        server_address: quic_server_addr.to_string(),
+        ..QuicClientConfig::default()
+    }))
+    .unwrap();
+    quic_client.connect().await.unwrap();
+    quic_client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    let mut subscription = quic_client
+        .subscribe_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &PollingStrategy::offset(0),
+            BATCH_SIZE,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    assert_credited_batches(&mut subscription, 0, 1).await;
+    quic_client
+        .grant_message_credits(subscription.id(), 1)
+        .await
+        .unwrap();
+    assert_credited_batches(&mut subscription, BATCH_SIZE, 1).await;
+    let error = quic_client
+        .grant_message_credits(subscription.id(), 0)
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_code(), IggyError::InvalidMessageCredits.as_code());
+    quic_client.disconnect().await.unwrap();
+    let next = timeout(PUSH_TIMEOUT, subscription.next()).await.unwrap();
+    assert!(next.is_none());
+
+    // 8. The subscription is ended by the server once its messages can't be polled anymore
+    let tcp_client = create_tcp_client(tcp_server_addr, true).await;
+    let mut subscription = tcp_client
+        .subscribe_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &PollingStrategy::offset(0),
+            BATCH_SIZE,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    assert_credited_batches(&mut subscription, 0, 1).await;
+    client.delete_stream(&stream_id).await.unwrap();
+    tcp_client
+        .grant_message_credits(subscription.id(), 1)
+        .await
+        .unwrap();
+    let next = timeout(PUSH_TIMEOUT, subscription.next()).await.unwrap();
+    assert!(next.is_none());
+    let error = tcp_client
+        .unsubscribe_messages(subscription.id())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::MessageSubscriptionNotFound(0).as_code()
+    );
+}
+
+async fn create_tcp_client(server_address: &str, pipelining: bool) -> TcpClient {
+    let client = TcpClient::create(Arc::new(TcpClientConfig {
+        server_address: server_address.to_string(),
+        pipelining,
+        ..TcpClientConfig::default()
+    }))
+    .unwrap();
+    client.connect().await.unwrap();
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    client
+}
+
+async fn assert_credited_batches(
+    subscription: &mut MessageSubscription,
+    first_offset: u32,
+    batches: u32,
+) {
+    let offsets = receive_offsets(subscription, batches * BATCH_SIZE).await;
+    assert_eq!(
+        offsets,
+        (first_offset as u64..(first_offset + batches * BATCH_SIZE) as u64).collect::<Vec<_>>()
+    );
+    assert!(timeout(NO_PUSH_TIMEOUT, subscription.next()).await.is_err());
+}
+
+async fn init_system(client: &WebSocketClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_messages(client: &WebSocketClient, first_id: u32) {
+    let mut messages = (first_id..first_id + MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn receive_offsets(subscription: &mut MessageSubscription, count: u32) -> Vec<u64> {
+    let mut offsets = Vec::new();
+    while offsets.len() < count as usize {
+        let polled_messages = timeout(PUSH_TIMEOUT, subscription.next())
+            .await
+            .expect("Pushed messages should be received")
+            .expect("Subscription should be active");
+        assert!(polled_messages.messages.len() <= BATCH_SIZE as usize);
+        offsets.extend(
+            polled_messages
+                .messages
+                .iter()
+                .map(|message| message.offset),
+        );
+    }
+    offsets
+}
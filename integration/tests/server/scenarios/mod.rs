@@ -23,12 +23,29 @@ use iggy::identifier::Identifier;
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use integration::test_server::{delete_user, ClientFactory};
 
+pub mod acknowledged_consumer_scenario;
+pub mod background_producer_scenario;
+pub mod blocking_client_scenario;
+pub mod client_disconnect_scenario;
+pub mod config_reload_scenario;
+pub mod connection_pool_scenario;
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
 pub mod create_message_payload;
+pub mod grpc_gateway_scenario;
+pub mod kafka_compatibility_scenario;
 pub mod message_headers_scenario;
+pub mod message_long_polling_scenario;
+pub mod message_offsets_scenario;
 pub mod message_size_scenario;
+pub mod message_subscription_scenario;
+pub mod mqtt_bridge_scenario;
+pub mod openapi_scenario;
+pub mod schema_registry_scenario;
+pub mod schema_validation_scenario;
+pub mod server_failover_scenario;
+pub mod sse_subscription_scenario;
 pub mod stream_size_validation_scenario;
 pub mod system_scenario;
 pub mod user_scenario;
@@ -36,7 +53,7 @@ pub mod user_scenario;
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
 const PARTITION_ID: u32 = 1;
-const STREAM_NAME: &str = "test-stream";
+pub(crate) const STREAM_NAME: &str = "test-stream";
 const TOPIC_NAME: &str = "test-topic";
 const PARTITIONS_COUNT: u32 = 3;
 const CONSUMER_GROUP_ID: u32 = 10;
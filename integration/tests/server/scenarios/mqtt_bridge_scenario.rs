@@ -0,0 +1,463 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::PolledMessage;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use rumqttc::v5::mqttbytes::v5::{Packet as PacketV5, PublishProperties};
+use rumqttc::v5::{
+    AsyncClient as AsyncClientV5, ConnectionError as ConnectionErrorV5, Event as EventV5,
+    MqttOptions as MqttOptionsV5,
+};
+use rumqttc::{
+    AsyncClient, ConnectReturnCode, ConnectionError, Event, MqttOptions, Packet, Publish, QoS,
+};
+use std::collections::BTreeSet;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{sleep, timeout};
+
+const DEVICE_TOPIC: &str = "devices/sensor-1";
+const FILTER: &str = "devices/#";
+const SHARED_GROUP: &str = "mqtt-shared";
+const SUBSCRIBER_ID: &str = "mqtt-subscriber";
+const MESSAGES_COUNT: usize = 3;
+const TOPIC_HEADER: &str = "mqtt-topic";
+const QOS_HEADER: &str = "mqtt-qos";
+const CONTENT_TYPE_HEADER: &str = "mqtt-content-type";
+const CONTENT_TYPE: &str = "application/json";
+const DEVICE_HEADER: &str = "device";
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn run(client_factory: &dyn ClientFactory, mqtt_addr: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The messages published by the MQTT 3.1.1 clients are appended to the mapped topic
+    let (publisher, mut publisher_packets) =
+        connect_v4(mqtt_addr, "mqtt-publisher", DEFAULT_ROOT_PASSWORD, true)
+            .await
+            .expect("Failed to connect the MQTT 3.1.1 client");
+    let device_topic = mqtt_topic(DEVICE_TOPIC);
+    for index in 0..MESSAGES_COUNT {
+        publisher
+            .publish(&device_topic, QoS::AtLeastOnce, false, payload(index))
+            .await
+            .unwrap();
+        receive_v4(&mut publisher_packets, |packet| {
+            matches!(packet, Packet::PubAck(_))
+        })
+        .await;
+    }
+
+    let messages = poll_all_messages(&client).await;
+    assert_eq!(messages.len(), MESSAGES_COUNT);
+    for (index, message) in messages.iter().enumerate() {
+        assert_eq!(message.payload, payload(index));
+        assert_eq!(get_string_header(message, TOPIC_HEADER), device_topic);
+        let qos = message
+            .headers
+            .as_ref()
+            .unwrap()
+            .get(&HeaderKey::new(QOS_HEADER).unwrap())
+            .unwrap();
+        assert_eq!(qos.as_uint8().unwrap(), 1);
+    }
+
+    // 2. The MQTT 5 properties are carried as the message headers
+    let (publisher_v5, mut publisher_v5_packets) =
+        connect_v5(mqtt_addr, "mqtt-publisher-v5", DEFAULT_ROOT_PASSWORD, true)
+            .await
+            .expect("Failed to connect the MQTT 5 client");
+    let properties = PublishProperties {
+        content_type: Some(CONTENT_TYPE.to_owned()),
+        user_properties: vec![(DEVICE_HEADER.to_owned(), "sensor-2".to_owned())],
+        ..Default::default()
+    };
+    let second_device_topic = mqtt_topic("devices/sensor-2");
+    publisher_v5
+        .publish_with_properties(
+            &second_device_topic,
+            rumqttc::v5::mqttbytes::QoS::AtLeastOnce,
+            false,
+            payload(MESSAGES_COUNT),
+            properties,
+        )
+        .await
+        .unwrap();
+    receive_v5(&mut publisher_v5_packets, |packet| {
+        matches!(packet, PacketV5::PubAck(_))
+    })
+    .await;
+
+    let messages = poll_all_messages(&client).await;
+    assert_eq!(messages.len(), MESSAGES_COUNT + 1);
+    let message = messages
+        .iter()
+        .find(|message| get_string_header(message, TOPIC_HEADER) == second_device_topic)
+        .expect("Message published by the MQTT 5 client not found");
+    assert_eq!(message.payload, payload(MESSAGES_COUNT));
+    assert_eq!(
+        get_string_header(message, CONTENT_TYPE_HEADER),
+        CONTENT_TYPE
+    );
+    assert_eq!(get_string_header(message, DEVICE_HEADER), "sensor-2");
+
+    // 3. The subscriptions receive the new messages and commit the offsets once they're acknowledged
+    let (subscriber, mut subscriber_packets) =
+        connect_v4(mqtt_addr, SUBSCRIBER_ID, DEFAULT_ROOT_PASSWORD, false)
+            .await
+            .unwrap();
+    subscribe_v4(&subscriber, &mut subscriber_packets).await;
+    let live_payload = payload(MESSAGES_COUNT + 1);
+    publisher
+        .publish(&device_topic, QoS::AtLeastOnce, false, live_payload.clone())
+        .await
+        .unwrap();
+    let publish = receive_publish_v4(&mut subscriber_packets).await;
+    assert_eq!(publish.topic, device_topic);
+    assert_eq!(publish.qos, QoS::AtLeastOnce);
+    assert_eq!(publish.payload, live_payload);
+    assert_offsets_committed(&client, &format!("mqtt-{SUBSCRIBER_ID}")).await;
+    subscriber.disconnect().await.unwrap();
+
+    // 4. The persistent sessions continue from the committed offsets, receiving the messages published while offline
+    let offline_payload = payload(MESSAGES_COUNT + 2);
+    publisher
+        .publish(
+            &second_device_topic,
+            QoS::AtLeastOnce,
+            false,
+            offline_payload.clone(),
+        )
+        .await
+        .unwrap();
+    receive_v4(&mut publisher_packets, |packet| {
+        matches!(packet, Packet::PubAck(_))
+    })
+    .await;
+    let (subscriber, mut subscriber_packets) =
+        connect_v4(mqtt_addr, SUBSCRIBER_ID, DEFAULT_ROOT_PASSWORD, false)
+            .await
+            .unwrap();
+    subscribe_v4(&subscriber, &mut subscriber_packets).await;
+    let publish = receive_publish_v4(&mut subscriber_packets).await;
+    assert_eq!(publish.topic, second_device_topic);
+    assert_eq!(publish.payload, offline_payload);
+    assert_offsets_committed(&client, &format!("mqtt-{SUBSCRIBER_ID}")).await;
+    subscriber.disconnect().await.unwrap();
+
+    // 5. The shared subscriptions split the messages between the members of the consumer group
+    let shared_filter = format!("$share/{SHARED_GROUP}/{}", mqtt_topic("#"));
+    let mut shared_packets = Vec::new();
+    let mut shared_subscribers = Vec::new();
+    for index in 0..2 {
+        let (subscriber, mut packets) = connect_v5(
+            mqtt_addr,
+            &format!("mqtt-shared-subscriber-{index}"),
+            DEFAULT_ROOT_PASSWORD,
+            true,
+        )
+        .await
+        .unwrap();
+        subscriber
+            .subscribe(&shared_filter, rumqttc::v5::mqttbytes::QoS::AtLeastOnce)
+            .await
+            .unwrap();
+        receive_v5(&mut packets, |packet| matches!(packet, PacketV5::SubAck(_))).await;
+        shared_packets.push(packets);
+        shared_subscribers.push(subscriber);
+    }
+
+    let iggy_payloads = (MESSAGES_COUNT + 3..2 * MESSAGES_COUNT + 3)
+        .map(payload)
+        .collect::<BTreeSet<_>>();
+    let mut iggy_messages = iggy_payloads
+        .iter()
+        .map(|payload| Message::new(None, payload.clone(), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Partitioning::balanced(),
+            &mut iggy_messages,
+        )
+        .await
+        .unwrap();
+
+    let mut received = BTreeSet::new();
+    timeout(RECEIVE_TIMEOUT, async {
+        while received != iggy_payloads {
+            for packets in shared_packets.iter_mut() {
+                while let Ok(packet) = packets.try_recv() {
+                    if let PacketV5::Publish(publish) = packet {
+                        // The messages appended by the Iggy clients are published on the mapped topic
+                        assert_eq!(publish.topic, format!("{STREAM_NAME}/{TOPIC_NAME}"));
+                        received.insert(publish.payload);
+                    }
+                }
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Messages not received by the shared subscription");
+    assert_offsets_committed(&client, SHARED_GROUP).await;
+
+    // 6. The clients with the invalid credentials are refused
+    let result = connect_v4(mqtt_addr, "mqtt-invalid", "invalid-password", true).await;
+    assert!(matches!(
+        result,
+        Err(ConnectionError::ConnectionRefused(
+            ConnectReturnCode::BadUserNamePassword
+        ))
+    ));
+    let result = connect_v5(mqtt_addr, "mqtt-invalid-v5", "invalid-password", true).await;
+    assert!(matches!(
+        result,
+        Err(ConnectionErrorV5::ConnectionRefused(_))
+    ));
+
+    publisher.disconnect().await.unwrap();
+    publisher_v5.disconnect().await.unwrap();
+    for subscriber in shared_subscribers {
+        subscriber.disconnect().await.unwrap();
+    }
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
+
+fn mqtt_topic(suffix: &str) -> String {
+    format!("{STREAM_NAME}/{TOPIC_NAME}/{suffix}")
+}
+
+fn payload(index: usize) -> Bytes {
+    Bytes::from(format!("mqtt-message-{index:02}"))
+}
+
+fn get_string_header(message: &PolledMessage, key: &str) -> String {
+    let headers = message.headers.as_ref().unwrap();
+    let value: &HeaderValue = headers.get(&HeaderKey::new(key).unwrap()).unwrap();
+    value.as_str().unwrap().to_owned()
+}
+
+async fn poll_all_messages(client: &IggyClient) -> Vec<PolledMessage> {
+    let mut messages = Vec::new();
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let polled_messages = client
+            .poll_messages(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                &Identifier::numeric(TOPIC_ID).unwrap(),
+                Some(partition_id),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                100,
+                false,
+            )
+            .await
+            .unwrap();
+        messages.extend(polled_messages.messages);
+    }
+    messages.sort_by(|first, second| first.payload.cmp(&second.payload));
+    messages
+}
+
+async fn assert_offsets_committed(client: &IggyClient, group: &str) {
+    let consumer = Consumer::group(Identifier::named(group).unwrap());
+    timeout(RECEIVE_TIMEOUT, async {
+        loop {
+            let mut offsets = Vec::new();
+            for partition_id in 1..=PARTITIONS_COUNT {
+                let offset = client
+                    .get_consumer_offset(
+                        &consumer,
+                        &Identifier::numeric(STREAM_ID).unwrap(),
+                        &Identifier::numeric(TOPIC_ID).unwrap(),
+                        Some(partition_id),
+                    )
+                    .await
+                    .unwrap();
+                offsets.extend(offset);
+            }
+            // The offsets aren't stored for the partitions without any messages delivered to the group.
+            if !offsets.is_empty()
+                && offsets
+                    .iter()
+                    .all(|offset| offset.stored_offset == offset.current_offset)
+            {
+                return;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Consumer offsets not committed");
+}
+
+async fn connect_v4(
+    mqtt_addr: &str,
+    client_id: &str,
+    password: &str,
+    clean_session: bool,
+) -> Result<(AsyncClient, mpsc::UnboundedReceiver<Packet>), ConnectionError> {
+    let (host, port) = split_address(mqtt_addr);
+    let mut options = MqttOptions::new(client_id, host, port);
+    options
+        .set_credentials(DEFAULT_ROOT_USERNAME, password)
+        .set_clean_session(clean_session);
+    let (client, mut event_loop) = AsyncClient::new(options, 100);
+    loop {
+        if let Event::Incoming(Packet::ConnAck(_)) = event_loop.poll().await? {
+            break;
+        }
+    }
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok(event) = event_loop.poll().await {
+            if let Event::Incoming(packet) = event {
+                if sender.send(packet).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    Ok((client, receiver))
+}
+
+async fn connect_v5(
+    mqtt_addr: &str,
+    client_id: &str,
+    password: &str,
+    clean_start: bool,
+) -> Result<(AsyncClientV5, mpsc::UnboundedReceiver<PacketV5>), ConnectionErrorV5> {
+    let (host, port) = split_address(mqtt_addr);
+    let mut options = MqttOptionsV5::new(client_id, host, port);
+    options
+        .set_credentials(DEFAULT_ROOT_USERNAME, password)
+        .set_clean_start(clean_start);
+    let (client, mut event_loop) = AsyncClientV5::new(options, 100);
+    loop {
+        if let EventV5::Incoming(PacketV5::ConnAck(_)) = event_loop.poll().await? {
+            break;
+        }
+    }
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok(event) = event_loop.poll().await {
+            if let EventV5::Incoming(packet) = event {
+                if sender.send(packet).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    Ok((client, receiver))
+}
+
+fn split_address(mqtt_addr: &str) -> (String, u16) {
+    let (host, port) = mqtt_addr.rsplit_once(':').unwrap();
+    (host.to_owned(), port.parse().unwrap())
+}
+
+async fn subscribe_v4(subscriber: &AsyncClient, packets: &mut mpsc::UnboundedReceiver<Packet>) {
+    subscriber
+        .subscribe(mqtt_topic(FILTER), QoS::AtLeastOnce)
+        .await
+        .unwrap();
+    receive_v4(packets, |packet| matches!(packet, Packet::SubAck(_))).await;
+}
+
+async fn receive_v4(packets: &mut mpsc::UnboundedReceiver<Packet>, predicate: fn(&Packet) -> bool) {
+    timeout(RECEIVE_TIMEOUT, async {
+        while let Some(packet) = packets.recv().await {
+            if predicate(&packet) {
+                return;
+            }
+        }
+        panic!("MQTT connection closed");
+    })
+    .await
+    .expect("MQTT packet not received");
+}
+
+async fn receive_publish_v4(packets: &mut mpsc::UnboundedReceiver<Packet>) -> Publish {
+    timeout(RECEIVE_TIMEOUT, async {
+        while let Some(packet) = packets.recv().await {
+            if let Packet::Publish(publish) = packet {
+                return publish;
+            }
+        }
+        panic!("MQTT connection closed");
+    })
+    .await
+    .expect("MQTT message not received")
+}
+
+async fn receive_v5(
+    packets: &mut mpsc::UnboundedReceiver<PacketV5>,
+    predicate: fn(&PacketV5) -> bool,
+) {
+    timeout(RECEIVE_TIMEOUT, async {
+        while let Some(packet) = packets.recv().await {
+            if predicate(&packet) {
+                return;
+            }
+        }
+        panic!("MQTT connection closed");
+    })
+    .await
+    .expect("MQTT packet not received");
+}
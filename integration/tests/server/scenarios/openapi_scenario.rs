@@ -0,0 +1,389 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, USERNAME_1,
+};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Value};
+
+const CONSUMER_GROUP_ID: u32 = 1;
+const MESSAGES_COUNT: u64 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory, http_addr: &str) {
+    let mut api = OpenApiClient::new(http_addr).await;
+    assert_eq!(api.spec["openapi"], "3.1.0");
+    let response = reqwest::get(format!("{}/docs/", api.api_url))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response
+        .headers()
+        .get(CONTENT_TYPE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("text/html"));
+
+    // 1. The unauthenticated request and the failed login
+    let (status, _) = api.call(Method::GET, "/streams", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = api
+        .call(
+            Method::POST,
+            "/users/login",
+            Some(json!({"username": DEFAULT_ROOT_USERNAME, "password": "invalid"})),
+        )
+        .await;
+    assert!(status.is_client_error());
+
+    // 2. The public endpoints and the login
+    api.call(Method::GET, "/ping", None).await;
+    api.call(Method::GET, "/stats", None).await;
+    let (status, identity) = api
+        .call(
+            Method::POST,
+            "/users/login",
+            Some(json!({"username": DEFAULT_ROOT_USERNAME, "password": DEFAULT_ROOT_PASSWORD})),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    api.token = identity.unwrap()["access_token"]["token"]
+        .as_str()
+        .map(ToOwned::to_owned);
+
+    // 3. The streams, topics, partitions and consumer groups
+    let stream = format!("/streams/{STREAM_ID}");
+    let topic = format!("{stream}/topics/{TOPIC_ID}");
+    let (status, _) = api.call(Method::GET, &stream, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    api.call(
+        Method::POST,
+        "/streams",
+        Some(json!({"stream_id": STREAM_ID, "name": STREAM_NAME})),
+    )
+    .await;
+    api.call(Method::GET, "/streams", None).await;
+    api.call(
+        Method::POST,
+        &format!("{stream}/topics"),
+        Some(json!({
+            "topic_id": TOPIC_ID,
+            "name": TOPIC_NAME,
+            "partitions_count": PARTITIONS_COUNT,
+            "compression_algorithm": "none",
+            "message_expiry": 0,
+            "max_topic_size": 0,
+            "replication_factor": 1
+        })),
+    )
+    .await;
+    api.call(
+        Method::POST,
+        &format!("{topic}/partitions"),
+        Some(json!({"partitions_count": 1})),
+    )
+    .await;
+    api.call(
+        Method::DELETE,
+        &format!("{topic}/partitions?partitions_count=1"),
+        None,
+    )
+    .await;
+    api.call(
+        Method::POST,
+        &format!("{topic}/consumer-groups"),
+        Some(json!({"group_id": CONSUMER_GROUP_ID, "name": "test-consumer-group"})),
+    )
+    .await;
+    api.call(Method::GET, &format!("{topic}/consumer-groups"), None)
+        .await;
+    api.call(
+        Method::GET,
+        &format!("{topic}/consumer-groups/{CONSUMER_GROUP_ID}"),
+        None,
+    )
+    .await;
+
+    // 4. The messages and the consumer offsets
+    let messages = (1..=MESSAGES_COUNT)
+        .map(|id| {
+            json!({
+                "id": id,
+                "payload": "bWVzc2FnZQ==",
+                "headers": {"content-type": {"kind": "string", "value": "anNvbg=="}}
+            })
+        })
+        .collect::<Vec<_>>();
+    let (status, appended_messages) = api
+        .call(
+            Method::POST,
+            &format!("{topic}/messages"),
+            Some(json!({
+                "partitioning": {"kind": "partition_id", "value": "AQAAAA=="},
+                "messages": messages,
+                "report_offsets": true
+            })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(
+        appended_messages.unwrap()["last_offset"],
+        MESSAGES_COUNT - 1
+    );
+    let (_, polled_messages) = api
+        .call(
+            Method::GET,
+            &format!("{topic}/messages?partition_id=1&kind=offset&value=0&count=10"),
+            None,
+        )
+        .await;
+    assert_eq!(
+        polled_messages.unwrap()["messages"]
+            .as_array()
+            .unwrap()
+            .len(),
+        MESSAGES_COUNT as usize
+    );
+    api.call(
+        Method::PUT,
+        &format!("{topic}/consumer-offsets"),
+        Some(json!({"id": "1", "partition_id": 1, "offset": 1})),
+    )
+    .await;
+    api.call(
+        Method::GET,
+        &format!("{topic}/consumer-offsets?id=1&partition_id=1"),
+        None,
+    )
+    .await;
+    api.call(
+        Method::DELETE,
+        &format!("{topic}/consumer-offsets/1?partition_id=1"),
+        None,
+    )
+    .await;
+
+    // 5. The schemas
+    let (status, schema) = api
+        .call(
+            Method::POST,
+            &format!("{topic}/schemas"),
+            Some(json!({"format": "json_schema", "definition": "{\"type\": \"object\"}"})),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let schema_id = schema.unwrap()["id"].as_u64().unwrap();
+    api.call(Method::GET, &format!("/schemas/{schema_id}"), None)
+        .await;
+    api.call(
+        Method::PUT,
+        &format!("{topic}/schemas/validation"),
+        Some(json!({"validation": {"mode": "reject"}})),
+    )
+    .await;
+    api.call(Method::GET, &format!("{topic}/schemas"), None)
+        .await;
+
+    // 6. The users, personal access tokens and clients
+    let (_, user) = api
+        .call(
+            Method::POST,
+            "/users",
+            Some(json!({
+                "username": USERNAME_1,
+                "password": "secret",
+                "status": "active",
+                "permissions": {
+                    "global": {
+                        "manage_servers": false,
+                        "read_servers": true,
+                        "manage_users": false,
+                        "read_users": true,
+                        "manage_streams": false,
+                        "read_streams": true,
+                        "manage_topics": false,
+                        "read_topics": true,
+                        "poll_messages": true,
+                        "send_messages": false
+                    },
+                    "streams": {
+                        STREAM_ID.to_string(): {
+                            "manage_stream": false,
+                            "read_stream": true,
+                            "manage_topics": false,
+                            "read_topics": true,
+                            "poll_messages": true,
+                            "send_messages": true,
+                            "topics": null
+                        }
+                    }
+                }
+            })),
+        )
+        .await;
+    let user_id = user.unwrap()["id"].as_u64().unwrap();
+    api.call(Method::GET, "/users", None).await;
+    api.call(Method::GET, &format!("/users/{user_id}"), None)
+        .await;
+    api.call(
+        Method::POST,
+        "/personal-access-tokens",
+        Some(json!({"name": "test-token", "expiry": 0})),
+    )
+    .await;
+    api.call(Method::GET, "/personal-access-tokens", None).await;
+    api.call(Method::DELETE, "/personal-access-tokens/test-token", None)
+        .await;
+    api.call(Method::GET, "/clients", None).await;
+    api.call(Method::DELETE, &format!("/users/{user_id}"), None)
+        .await;
+
+    // 7. The cleanup
+    api.call(Method::DELETE, &topic, None).await;
+    api.call(Method::DELETE, &stream, None).await;
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    assert_clean_system(&client).await;
+}
+
+/// Sends the requests to the HTTP API, and validates their bodies and the responses against the OpenAPI document served by the server.
+struct OpenApiClient {
+    http: reqwest::Client,
+    api_url: String,
+    spec: Value,
+    token: Option<String>,
+}
+
+impl OpenApiClient {
+    async fn new(http_addr: &str) -> Self {
+        let api_url = format!("http://{http_addr}");
+        let spec = reqwest::get(format!("{api_url}/openapi.json"))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        Self {
+            http: reqwest::Client::new(),
+            api_url,
+            spec,
+            token: None,
+        }
+    }
+
+    async fn call(
+        &self,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Option<Value>) {
+        let path = uri.split('?').next().unwrap();
+        let template = self.find_path(path);
+        let operation = &self.spec["paths"][template][method.as_str().to_lowercase()];
+        assert!(
+            operation.is_object(),
+            "{method} {template} is not documented"
+        );
+
+        let mut request = self
+            .http
+            .request(method.clone(), format!("{}{uri}", self.api_url));
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = &body {
+            let schema = &operation["requestBody"]["content"]["application/json"]["schema"];
+            self.validate(schema, body, &format!("{method} {uri} request"));
+            request = request.json(body);
+        }
+
+        let response = request.send().await.unwrap();
+        let status = response.status();
+        let responses = &operation["responses"];
+        let mut documented_response = &responses[status.as_str()];
+        if documented_response.is_null() && status.is_client_error() {
+            documented_response = &responses["4XX"];
+        }
+        let documented_response = self.resolve(documented_response);
+        assert!(
+            documented_response.is_object(),
+            "{method} {uri} response status {status} is not documented"
+        );
+
+        let context = format!("{method} {uri} response {status}");
+        let schema = &documented_response["content"]["application/json"]["schema"];
+        if schema.is_null() {
+            return (status, None);
+        }
+        let body = response.json().await.unwrap();
+        self.validate(schema, &body, &context);
+        (status, Some(body))
+    }
+
+    /// Returns the documented path template matching the path, preferring the one with the fewest parameters.
+    fn find_path(&self, path: &str) -> &str {
+        let segments = path.split('/').collect::<Vec<_>>();
+        self.spec["paths"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .filter(|template| {
+                let template_segments = template.split('/').collect::<Vec<_>>();
+                template_segments.len() == segments.len()
+                    && template_segments
+                        .iter()
+                        .zip(&segments)
+                        .all(|(template_segment, segment)| {
+                            template_segment.starts_with('{') || template_segment == segment
+                        })
+            })
+            .min_by_key(|template| template.matches('{').count())
+            .unwrap_or_else(|| panic!("{path} is not documented"))
+    }
+
+    fn resolve<'a>(&'a self, value: &'a Value) -> &'a Value {
+        match value["$ref"].as_str() {
+            Some(reference) => reference
+                .trim_start_matches("#/")
+                .split('/')
+                .fold(&self.spec, |value, segment| &value[segment]),
+            None => value,
+        }
+    }
+
+    fn validate(&self, schema: &Value, instance: &Value, context: &str) {
+        assert!(!schema.is_null(), "{context} schema is not documented");
+        let schema = json!({
+            "components": self.spec["components"],
+            "allOf": [schema]
+        });
+        let validator = jsonschema::draft202012::new(&schema).unwrap();
+        let errors = validator
+            .iter_errors(instance)
+            .map(|error| format!("{error} at {}", error.instance_path))
+            .collect::<Vec<_>>();
+        assert!(
+            errors.is_empty(),
+            "{context} doesn't match the schema: {errors:?}, body: {instance}"
+        );
+    }
+}
@@ -0,0 +1,242 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use iggy::client::{MessageClient, SchemaClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::codecs::json::JsonCodec;
+use iggy::codecs::registry::RegisteredCodec;
+use iggy::codecs::{schema_id, schema_id_header, Codec};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::schema::{SchemaCompatibility, SchemaFormat};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+
+const SCHEMA_V1: &str = r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"],"additionalProperties":false}"#;
+const SCHEMA_V2: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"quantity":{"type":"integer"}},"required":["id"]}"#;
+const INCOMPATIBLE_SCHEMA: &str =
+    r#"{"type":"object","properties":{"id":{"type":"string"}},"required":["id"]}"#;
+
+type Order = HashMap<String, u64>;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+
+    // 1. The topic has no schemas and the default backward compatibility rule
+    let topic_schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(topic_schemas.stream_id, STREAM_ID);
+    assert_eq!(topic_schemas.topic_id, TOPIC_ID);
+    assert_eq!(topic_schemas.compatibility, SchemaCompatibility::Backward);
+    assert!(topic_schemas.schemas.is_empty());
+
+    // 2. Register the first version of the schema
+    let schema_v1 = client
+        .register_schema(&stream_id, &topic_id, SchemaFormat::JsonSchema, SCHEMA_V1)
+        .await
+        .unwrap();
+    assert_eq!(schema_v1.version, 1);
+    assert_eq!(schema_v1.stream_id, STREAM_ID);
+    assert_eq!(schema_v1.topic_id, TOPIC_ID);
+    assert_eq!(schema_v1.format, SchemaFormat::JsonSchema);
+    assert_eq!(schema_v1.definition, SCHEMA_V1);
+
+    // 3. Registering the same definition again returns the existing version
+    let schema = client
+        .register_schema(&stream_id, &topic_id, SchemaFormat::JsonSchema, SCHEMA_V1)
+        .await
+        .unwrap();
+    assert_eq!(schema.id, schema_v1.id);
+    assert_eq!(schema.version, 1);
+
+    // 4. Register the backward compatible second version
+    let schema_v2 = client
+        .register_schema(&stream_id, &topic_id, SchemaFormat::JsonSchema, SCHEMA_V2)
+        .await
+        .unwrap();
+    assert_eq!(schema_v2.version, 2);
+    assert!(schema_v2.id > schema_v1.id);
+
+    // 5. The incompatible version and invalid definition are rejected
+    let register_incompatible_schema = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaFormat::JsonSchema,
+            INCOMPATIBLE_SCHEMA,
+        )
+        .await;
+    assert!(register_incompatible_schema.is_err());
+
+    let register_invalid_schema = client
+        .register_schema(&stream_id, &topic_id, SchemaFormat::JsonSchema, "{")
+        .await;
+    assert!(register_invalid_schema.is_err());
+
+    // 6. Get the schema by ID
+    let schema = client
+        .get_schema(schema_v1.id)
+        .await
+        .unwrap()
+        .expect("Failed to get schema");
+    assert_eq!(schema.version, 1);
+    assert_eq!(schema.definition, SCHEMA_V1);
+    assert!(client
+        .get_schema(schema_v2.id + 100)
+        .await
+        .unwrap()
+        .is_none());
+
+    // 7. Send and poll the messages encoded with the codec bound to the registered schema
+    let codec = RegisteredCodec::register(
+        &client,
+        &stream_id,
+        &topic_id,
+        SchemaFormat::JsonSchema,
+        SCHEMA_V2,
+        JsonCodec,
+    )
+    .await
+    .unwrap();
+    assert_eq!(codec.schema().id, schema_v2.id);
+    assert_eq!(codec.readable_schema_ids(), &[schema_v1.id, schema_v2.id]);
+
+    let order = Order::from([("id".to_owned(), 1), ("quantity".to_owned(), 10)]);
+    let payload = codec.encode(&order).unwrap();
+    let (key, value) = schema_id_header::<Order>(&codec).unwrap().unwrap();
+    let mut messages = vec![Message {
+        id: 1,
+        length: payload.len() as u32,
+        payload,
+        headers: Some(HashMap::from([(key, value)])),
+    }];
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    let message = &polled_messages.messages[0];
+    let message_schema_id = schema_id(&message.headers).expect("Missing schema ID header");
+    assert_eq!(message_schema_id, codec.schema().id);
+    let decoded: Order = codec
+        .decode_with_schema(message_schema_id, &message.payload)
+        .unwrap();
+    assert_eq!(decoded, order);
+
+    // 8. The codec bound to the first version doesn't read the payloads written with the second one
+    let error = Codec::<Order>::decode_with_schema(
+        &codec_for_version(&client, 1).await,
+        message_schema_id,
+        &message.payload,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::IncompatibleMessageSchema(0, 0).as_code()
+    );
+
+    // 9. With no compatibility checks, any valid version can be registered
+    client
+        .update_schema_compatibility(&stream_id, &topic_id, SchemaCompatibility::None)
+        .await
+        .unwrap();
+    let schema_v3 = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaFormat::JsonSchema,
+            INCOMPATIBLE_SCHEMA,
+        )
+        .await
+        .unwrap();
+    assert_eq!(schema_v3.version, 3);
+
+    let topic_schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(topic_schemas.compatibility, SchemaCompatibility::None);
+    assert_eq!(topic_schemas.schemas.len(), 3);
+    assert_eq!(topic_schemas.latest().unwrap().id, schema_v3.id);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn codec_for_version(client: &IggyClient, version: u32) -> RegisteredCodec<JsonCodec> {
+    let mut topic_schemas = client
+        .get_schemas(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+        )
+        .await
+        .unwrap();
+    topic_schemas
+        .schemas
+        .retain(|schema| schema.version <= version);
+    RegisteredCodec::from_schemas(&topic_schemas, JsonCodec).unwrap()
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
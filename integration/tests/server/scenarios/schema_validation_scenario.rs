@@ -0,0 +1,294 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::client::{MessageClient, SchemaClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::command::Command;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::PolledMessage;
+use iggy::models::schema::{SchemaFormat, SchemaValidation};
+use iggy::schemas::{
+    REJECTION_REASON_HEADER, REJECTION_SCHEMA_ID_HEADER, REJECTION_STREAM_ID_HEADER,
+    REJECTION_TOPIC_ID_HEADER,
+};
+use iggy::system::ping::Ping;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::users::login_user::LoginUser;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const REJECTION_TOPIC_ID: u32 = 2;
+const REJECTION_TOPIC_NAME: &str = "test-rejected-messages";
+const SCHEMA: &str =
+    r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#;
+const VALID_PAYLOAD: &str = r#"{"id":1}"#;
+const INVALID_PAYLOAD: &str = r#"{"id":"one"}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let schema = client
+        .register_schema(&stream_id, &topic_id, SchemaFormat::JsonSchema, SCHEMA)
+        .await
+        .unwrap();
+
+    // 1. By default, the payloads are not validated
+    let topic_schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(topic_schemas.validation, SchemaValidation::None);
+    send_messages(&client, 1, &[INVALID_PAYLOAD]).await.unwrap();
+    assert_eq!(poll_messages(&client, TOPIC_ID, 1).await.len(), 1);
+
+    // 2. In the reject mode, the whole batch fails on the first invalid payload
+    client
+        .update_schema_validation(&stream_id, &topic_id, SchemaValidation::Reject)
+        .await
+        .unwrap();
+    let error = send_messages(&client, 2, &[VALID_PAYLOAD, INVALID_PAYLOAD, VALID_PAYLOAD])
+        .await
+        .unwrap_err();
+    let IggyError::MessagePayloadSchemaViolation(index, schema_id, reason) = error else {
+        panic!("Unexpected error: {error}");
+    };
+    assert_eq!(index, 1);
+    assert_eq!(schema_id, schema.id);
+    assert!(reason.starts_with("$/id"));
+    assert!(poll_messages(&client, TOPIC_ID, 2).await.is_empty());
+
+    send_messages(&client, 2, &[VALID_PAYLOAD]).await.unwrap();
+    assert_eq!(poll_messages(&client, TOPIC_ID, 2).await.len(), 1);
+
+    // 3. The rejection topic must exist, and it can't be the validated topic
+    let route_to_same_topic = client
+        .update_schema_validation(
+            &stream_id,
+            &topic_id,
+            SchemaValidation::Route {
+                stream_id: STREAM_ID,
+                topic_id: TOPIC_ID,
+            },
+        )
+        .await;
+    assert!(route_to_same_topic.is_err());
+
+    let route_to_missing_topic = client
+        .update_schema_validation(
+            &stream_id,
+            &topic_id,
+            SchemaValidation::Route {
+                stream_id: STREAM_ID,
+                topic_id: REJECTION_TOPIC_ID + 1,
+            },
+        )
+        .await;
+    assert!(route_to_missing_topic.is_err());
+
+    // 4. In the route mode, the invalid payloads are appended to the rejection topic
+    let route = SchemaValidation::Route {
+        stream_id: STREAM_ID,
+        topic_id: REJECTION_TOPIC_ID,
+    };
+    client
+        .update_schema_validation(&stream_id, &topic_id, route)
+        .await
+        .unwrap();
+    let topic_schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(topic_schemas.validation, route);
+
+    send_messages(&client, 3, &[VALID_PAYLOAD, INVALID_PAYLOAD, VALID_PAYLOAD])
+        .await
+        .unwrap();
+    let accepted_messages = poll_messages(&client, TOPIC_ID, 3).await;
+    assert_eq!(accepted_messages.len(), 2);
+    assert!(accepted_messages
+        .iter()
+        .all(|message| message.payload == VALID_PAYLOAD));
+
+    let rejected_messages = poll_messages(&client, REJECTION_TOPIC_ID, 1).await;
+    assert_eq!(rejected_messages.len(), 1);
+    let rejected_message = &rejected_messages[0];
+    assert_eq!(rejected_message.payload, INVALID_PAYLOAD);
+    let headers = rejected_message.headers.as_ref().unwrap();
+    let header = |key: &str| headers.get(&HeaderKey::new(key).unwrap()).unwrap();
+    assert!(header(REJECTION_REASON_HEADER)
+        .as_str()
+        .unwrap()
+        .starts_with("$/id"));
+    assert_eq!(
+        header(REJECTION_SCHEMA_ID_HEADER).as_uint32().unwrap(),
+        schema.id
+    );
+    assert_eq!(
+        header(REJECTION_STREAM_ID_HEADER).as_uint32().unwrap(),
+        STREAM_ID
+    );
+    assert_eq!(
+        header(REJECTION_TOPIC_ID_HEADER).as_uint32().unwrap(),
+        TOPIC_ID
+    );
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+/// The client which doesn't request the error details (e.g. released before they were introduced)
+/// receives the bare error status, without any payload it wouldn't expect.
+pub async fn run_without_error_details(client_factory: &dyn ClientFactory, server_address: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .register_schema(&stream_id, &topic_id, SchemaFormat::JsonSchema, SCHEMA)
+        .await
+        .unwrap();
+    client
+        .update_schema_validation(&stream_id, &topic_id, SchemaValidation::Reject)
+        .await
+        .unwrap();
+
+    let mut stream = TcpStream::connect(server_address).await.unwrap();
+    let login_user = LoginUser {
+        username: DEFAULT_ROOT_USERNAME.to_string(),
+        password: DEFAULT_ROOT_PASSWORD.to_string(),
+        version: None,
+        context: None,
+    };
+    let (status, _) = send_raw_request(&mut stream, &login_user).await;
+    assert_eq!(status, 0);
+
+    let send_messages = SendMessages {
+        stream_id: stream_id.clone(),
+        topic_id: topic_id.clone(),
+        partitioning: Partitioning::partition_id(1),
+        messages: vec![Message::new(None, Bytes::from(INVALID_PAYLOAD), None)],
+        ..Default::default()
+    };
+    let (status, payload) = send_raw_request(&mut stream, &send_messages).await;
+    assert_eq!(
+        status,
+        IggyError::MessagePayloadSchemaViolation(0, 0, String::new()).as_code()
+    );
+    assert!(payload.is_empty());
+
+    // The connection is still in sync, so the next response is read correctly.
+    let (status, payload) = send_raw_request(&mut stream, &Ping {}).await;
+    assert_eq!(status, 0);
+    assert!(payload.is_empty());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+/// Sends the request with the plain command code, as the clients without the error details support do.
+async fn send_raw_request<T: Command>(stream: &mut TcpStream, command: &T) -> (u32, Vec<u8>) {
+    let payload = command.to_bytes();
+    let mut request = BytesMut::with_capacity(8 + payload.len());
+    request.put_u32_le(4 + payload.len() as u32);
+    request.put_u32_le(command.code());
+    request.put_slice(&payload);
+    stream.write_all(&request).await.unwrap();
+
+    let status = stream.read_u32_le().await.unwrap();
+    let length = stream.read_u32_le().await.unwrap();
+    let mut payload = vec![0; length as usize];
+    stream.read_exact(&mut payload).await.unwrap();
+    (status, payload)
+}
+
+async fn send_messages(
+    client: &IggyClient,
+    partition_id: u32,
+    payloads: &[&str],
+) -> Result<(), IggyError> {
+    let mut messages = payloads
+        .iter()
+        .map(|payload| Message::new(None, Bytes::from(payload.to_string()), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+}
+
+async fn poll_messages(
+    client: &IggyClient,
+    topic_id: u32,
+    partition_id: u32,
+) -> Vec<PolledMessage> {
+    client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(topic_id).unwrap(),
+            Some(partition_id),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    for (topic_id, topic_name, partitions_count) in [
+        (TOPIC_ID, TOPIC_NAME, PARTITIONS_COUNT),
+        (REJECTION_TOPIC_ID, REJECTION_TOPIC_NAME, 1),
+    ] {
+        client
+            .create_topic(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                topic_name,
+                partitions_count,
+                CompressionAlgorithm::default(),
+                None,
+                Some(topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+    }
+}
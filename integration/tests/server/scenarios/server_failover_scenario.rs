@@ -0,0 +1,116 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    create_client, get_consumer_group, join_consumer_group, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME,
+    PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{Client, ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::TcpClientConfigBuilder;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, TestServer};
+use std::sync::Arc;
+
+pub async fn run(primary_server: &mut TestServer, secondary_server: &TestServer) {
+    let primary_address = primary_server.get_raw_tcp_addr().unwrap();
+    let secondary_address = secondary_server.get_raw_tcp_addr().unwrap();
+
+    // 1. Create the same stream, topic and consumer group on both servers
+    let primary_system_client = create_system_client(&primary_address).await;
+    let secondary_system_client = create_system_client(&secondary_address).await;
+    init_system(&primary_system_client).await;
+    init_system(&secondary_system_client).await;
+
+    // 2. Connect to the bootstrap list, sign in manually and join the consumer group
+    let config = TcpClientConfigBuilder::new()
+        .with_server_addresses(vec![primary_address, secondary_address])
+        .with_reconnection_max_retries(Some(3))
+        .build();
+    let client = TcpClient::create(Arc::new(config)).unwrap();
+    let client = IggyClient::create(Box::new(client), None, None);
+    client.connect().await.unwrap();
+    login_root(&client).await;
+    join_consumer_group(&client).await;
+    assert_eq!(
+        get_consumer_group(&primary_system_client)
+            .await
+            .members_count,
+        1
+    );
+
+    // 3. Stop the primary server, the next command should fail over to the secondary one
+    primary_server.stop();
+    let client_info = client.get_me().await.unwrap();
+
+    // 4. The session should be restored on the secondary server, including the consumer group membership
+    assert_eq!(client_info.consumer_groups_count, 1);
+    assert_eq!(client_info.consumer_groups[0].group_id, CONSUMER_GROUP_ID);
+    let consumer_group = get_consumer_group(&secondary_system_client).await;
+    assert_eq!(consumer_group.members_count, 1);
+    assert_eq!(consumer_group.members[0].id, client_info.client_id);
+
+    secondary_system_client
+        .delete_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+}
+
+async fn create_system_client(server_addr: &str) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.to_string(),
+        ..Default::default()
+    };
+    let client = create_client(&client_factory).await;
+    login_root(&client).await;
+    client
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+        )
+        .await
+        .unwrap();
+}
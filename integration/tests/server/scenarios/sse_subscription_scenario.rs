@@ -0,0 +1,337 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, get_consumer_group, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME,
+    PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::identity_info::IdentityInfo;
+use iggy::models::messages::PolledMessages;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Response, StatusCode};
+use serde_json::json;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+const MESSAGES_COUNT: u64 = 5;
+const EVENT_TIMEOUT: Duration = Duration::from_secs(10);
+const LEAVE_GROUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub async fn run(client_factory: &dyn ClientFactory, http_addr: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let http = reqwest::Client::new();
+    let api_url = format!("http://{http_addr}");
+    let token = login(&http, &api_url).await;
+    let subscribe_url = |topic_id: u32| {
+        format!(
+            "{api_url}/streams/{STREAM_ID}/topics/{topic_id}/messages/subscribe?partition_id={PARTITION_ID}&kind=offset&value=0&count=100"
+        )
+    };
+
+    // 1. The subscription requires the authentication
+    let response = http.get(subscribe_url(TOPIC_ID)).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // 2. The invalid subscription is rejected with the error response instead of the event stream
+    let response = http
+        .get(subscribe_url(TOPIC_ID + 1))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert!(!response.status().is_success());
+
+    // 3. The messages appended after subscribing are streamed as the events
+    let response = http
+        .get(subscribe_url(TOPIC_ID))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+    let mut events = EventReader::new(response);
+    send_messages(&client, PARTITION_ID, 0).await;
+    assert_eq!(
+        events.read_offsets(MESSAGES_COUNT - 1).await,
+        (0..MESSAGES_COUNT).collect::<Vec<_>>()
+    );
+    send_messages(&client, PARTITION_ID, MESSAGES_COUNT).await;
+    assert_eq!(
+        events.read_offsets(2 * MESSAGES_COUNT - 1).await,
+        (MESSAGES_COUNT..2 * MESSAGES_COUNT).collect::<Vec<_>>()
+    );
+    drop(events);
+
+    // 4. The reconnecting client resumes after the Last-Event-ID, passing the token in the query like the EventSource
+    let resume_offset = MESSAGES_COUNT + 1;
+    let response = http
+        .get(format!("{}&access_token={token}", subscribe_url(TOPIC_ID)))
+        .header("Last-Event-ID", format!("{PARTITION_ID}:{resume_offset}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = EventReader::new(response);
+    assert_eq!(
+        events.read_offsets(2 * MESSAGES_COUNT - 1).await,
+        (resume_offset + 1..2 * MESSAGES_COUNT).collect::<Vec<_>>()
+    );
+    drop(events);
+
+    // 5. The consumer group subscription polls all the partitions, and is resumed from the stored offsets of the group
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+        )
+        .await
+        .unwrap();
+    let group_subscribe_url = format!(
+        "{api_url}/streams/{STREAM_ID}/topics/{TOPIC_ID}/messages/subscribe?consumer_kind=consumer_group&id={CONSUMER_GROUP_ID}&kind=next&count=3&auto_commit=true"
+    );
+    for partition_id in 1..=PARTITIONS_COUNT {
+        if partition_id != PARTITION_ID {
+            send_messages(&client, partition_id, 0).await;
+        }
+    }
+    let mut expected_messages = (1..=PARTITIONS_COUNT)
+        .flat_map(|partition_id| {
+            let count = if partition_id == PARTITION_ID {
+                2 * MESSAGES_COUNT
+            } else {
+                MESSAGES_COUNT
+            };
+            (0..count).map(move |offset| (partition_id, offset))
+        })
+        .collect::<Vec<_>>();
+    let response = http
+        .get(&group_subscribe_url)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = EventReader::new(response);
+    let mut messages = events.read_messages(expected_messages.len()).await;
+    messages.sort_unstable();
+    assert_eq!(messages, expected_messages);
+    drop(events);
+    wait_for_group_members(&client, 0).await;
+
+    let mut next_offsets = Vec::new();
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let start_offset = expected_messages
+            .iter()
+            .filter(|(partition, _)| *partition == partition_id)
+            .count() as u64;
+        send_messages(&client, partition_id, start_offset).await;
+        next_offsets.extend(
+            (start_offset..start_offset + MESSAGES_COUNT).map(|offset| (partition_id, offset)),
+        );
+    }
+    expected_messages = next_offsets;
+    let response = http
+        .get(&group_subscribe_url)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = EventReader::new(response);
+    let mut messages = events.read_messages(expected_messages.len()).await;
+    messages.sort_unstable();
+    assert_eq!(messages, expected_messages);
+    drop(events);
+    wait_for_group_members(&client, 0).await;
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+struct Event {
+    name: String,
+    id: String,
+    data: String,
+}
+
+struct EventReader {
+    response: Response,
+    buffer: String,
+}
+
+impl EventReader {
+    fn new(response: Response) -> Self {
+        Self {
+            response,
+            buffer: String::new(),
+        }
+    }
+
+    /// Reads the `messages` events until the one with the given last offset, returning the offsets of all the streamed messages.
+    async fn read_offsets(&mut self, last_offset: u64) -> Vec<u64> {
+        let mut offsets = Vec::new();
+        loop {
+            let messages = self.next_messages().await;
+            assert_eq!(messages.partition_id, PARTITION_ID);
+            offsets.extend(messages.messages.iter().map(|message| message.offset));
+            if *offsets.last().unwrap() >= last_offset {
+                return offsets;
+            }
+        }
+    }
+
+    /// Reads the `messages` events until the given number of messages, returning their partition IDs and offsets.
+    async fn read_messages(&mut self, count: usize) -> Vec<(u32, u64)> {
+        let mut messages = Vec::new();
+        while messages.len() < count {
+            let polled_messages = self.next_messages().await;
+            messages.extend(
+                polled_messages
+                    .messages
+                    .iter()
+                    .map(|message| (polled_messages.partition_id, message.offset)),
+            );
+        }
+        messages
+    }
+
+    async fn next_messages(&mut self) -> PolledMessages {
+        let event = self.next_event().await;
+        assert_eq!(event.name, "messages");
+        let messages: PolledMessages = serde_json::from_str(&event.data).unwrap();
+        let last_offset = messages.messages.last().unwrap().offset;
+        assert_eq!(event.id, format!("{}:{last_offset}", messages.partition_id));
+        messages
+    }
+
+    async fn next_event(&mut self) -> Event {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let block = self.buffer[..end].to_owned();
+                self.buffer.drain(..end + 2);
+                let mut event = Event {
+                    name: String::new(),
+                    id: String::new(),
+                    data: String::new(),
+                };
+                for line in block.lines() {
+                    match line.split_once(':') {
+                        Some(("event", value)) => event.name = value.trim_start().to_owned(),
+                        Some(("id", value)) => event.id = value.trim_start().to_owned(),
+                        Some(("data", value)) => event.data.push_str(value.trim_start()),
+                        _ => {}
+                    }
+                }
+                // The keep-alive comments carry no event
+                if !event.name.is_empty() {
+                    return event;
+                }
+                continue;
+            }
+
+            let chunk = timeout(EVENT_TIMEOUT, self.response.chunk())
+                .await
+                .expect("Timed out waiting for the event")
+                .unwrap()
+                .expect("The event stream has ended");
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+}
+
+async fn login(http: &reqwest::Client, api_url: &str) -> String {
+    let identity: IdentityInfo = http
+        .post(format!("{api_url}/users/login"))
+        .json(&json!({
+            "username": DEFAULT_ROOT_USERNAME,
+            "password": DEFAULT_ROOT_PASSWORD,
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    identity.access_token.unwrap().token
+}
+
+/// Waits for the closed subscriptions to leave the consumer group.
+async fn wait_for_group_members(client: &IggyClient, members_count: u32) {
+    timeout(LEAVE_GROUP_TIMEOUT, async {
+        while get_consumer_group(client).await.members_count != members_count {
+            sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("Timed out waiting for the subscription to leave the consumer group");
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32, start_id: u64) {
+    let mut messages = (start_id..start_id + MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message-{id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
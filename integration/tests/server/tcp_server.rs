@@ -17,13 +17,21 @@
  */
 
 use crate::server::scenarios::{
+    acknowledged_consumer_scenario, background_producer_scenario, blocking_client_scenario,
+    client_disconnect_scenario, config_reload_scenario, connection_pool_scenario,
     consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
     consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, message_size_scenario, stream_size_validation_scenario,
-    system_scenario, user_scenario,
+    message_headers_scenario, message_long_polling_scenario, message_offsets_scenario,
+    message_size_scenario, schema_registry_scenario, schema_validation_scenario,
+    server_failover_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+};
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
+use tempfile::NamedTempFile;
 
 #[tokio::test]
 #[parallel]
@@ -51,6 +59,38 @@ async fn user_scenario_should_be_valid() {
     user_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn client_disconnect_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    client_disconnect_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn config_reload_scenario_should_be_valid() {
+    let config_file = NamedTempFile::new().unwrap();
+    let envs = HashMap::from([(
+        "IGGY_CONFIG_PATH".to_string(),
+        config_file.path().display().to_string(),
+    )]);
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    config_reload_scenario::run(&client_factory, config_file.path(), &http_api_addr).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_headers_scenario_should_be_valid() {
@@ -129,6 +169,45 @@ async fn stream_size_validation_scenario_should_be_valid() {
     stream_size_validation_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_validation_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    schema_validation_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_validation_without_error_details_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.clone(),
+        ..Default::default()
+    };
+    schema_validation_scenario::run_without_error_details(&client_factory, &server_addr).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn message_size_scenario_should_be_valid() {
@@ -141,3 +220,112 @@ async fn message_size_scenario_should_be_valid() {
     };
     message_size_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn server_failover_scenario_should_be_valid() {
+    let mut primary_server = TestServer::default();
+    primary_server.start();
+    let mut secondary_server = TestServer::default();
+    secondary_server.start();
+    server_failover_scenario::run(&mut primary_server, &secondary_server).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_with_pipelining_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: true,
+        ..Default::default()
+    };
+    system_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_with_single_client_polling_messages_scenario_with_pipelining_should_be_valid(
+) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        pipelining: true,
+        ..Default::default()
+    };
+    consumer_group_with_single_client_polling_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn connection_pool_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    connection_pool_scenario::run(&server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn background_producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    background_producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    message_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    message_long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn acknowledged_consumer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    acknowledged_consumer_scenario::run(&client_factory).await;
+}
+
+#[test]
+#[parallel]
+fn blocking_client_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    blocking_client_scenario::run(&server_addr);
+}
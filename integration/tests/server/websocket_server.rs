@@ -0,0 +1,129 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    client_disconnect_scenario, consumer_group_join_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
+    message_headers_scenario, message_offsets_scenario, message_subscription_scenario,
+    system_scenario, user_scenario,
+};
+use integration::{test_server::TestServer, websocket_client::WebSocketClientFactory};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    system_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn user_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    user_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn client_disconnect_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    client_disconnect_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_headers_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    message_headers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn create_message_payload_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    create_message_payload::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_join_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    consumer_group_join_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_with_single_client_polling_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    consumer_group_with_single_client_polling_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_offsets_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_websocket_addr().unwrap();
+    let client_factory = WebSocketClientFactory { server_addr };
+    message_offsets_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_subscription_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let websocket_server_addr = test_server.get_websocket_addr().unwrap();
+    let tcp_server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let quic_server_addr = test_server.get_quic_udp_addr().unwrap();
+    message_subscription_scenario::run(&websocket_server_addr, &tcp_server_addr, &quic_server_addr)
+        .await;
+}
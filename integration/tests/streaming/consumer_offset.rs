@@ -17,12 +17,21 @@
  */
 
 use crate::streaming::common::test_setup::TestSetup;
+use crate::streaming::create_messages;
 use iggy::consumer::ConsumerKind;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
 use server::configs::system::SystemConfig;
-use server::streaming::partitions::partition::ConsumerOffset;
+use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+use server::streaming::partitions::partition::{ConsumerOffset, Partition};
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::storage::PartitionStorageKind;
+use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
 use tokio::fs;
+use tokio::sync::Notify;
 
 #[tokio::test]
 async fn should_persist_consumer_offsets_and_then_load_them_from_disk() {
@@ -32,6 +41,68 @@ async fn should_persist_consumer_offsets_and_then_load_them_from_disk() {
     assert_persisted_offsets(&setup.config, storage, ConsumerKind::ConsumerGroup).await;
 }
 
+#[tokio::test]
+async fn should_store_consumer_group_offset_separately_from_consumer_offset() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Arc::new(Notify::new()),
+        IggyTimestamp::now(),
+    )
+    .await;
+    partition.persist().await.unwrap();
+    let messages = create_messages();
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages
+            .iter()
+            .map(|msg| msg.get_size_bytes())
+            .sum::<IggyByteSize>(),
+        partition.partition_id,
+    );
+    partition
+        .append_messages(appendable_batch_info, messages, None)
+        .await
+        .unwrap();
+
+    let id = 1;
+    let consumer = PollingConsumer::Consumer(id, partition_id);
+    let consumer_group = PollingConsumer::ConsumerGroup(id, 1);
+    partition
+        .store_consumer_offset(consumer_group, 3)
+        .await
+        .unwrap();
+    assert_eq!(
+        partition.get_consumer_offset(consumer_group).await.unwrap(),
+        Some(3)
+    );
+    assert_eq!(partition.get_consumer_offset(consumer).await.unwrap(), None);
+
+    partition.store_consumer_offset(consumer, 1).await.unwrap();
+    assert_eq!(
+        partition.get_consumer_offset(consumer_group).await.unwrap(),
+        Some(3)
+    );
+    assert_eq!(
+        partition.get_consumer_offset(consumer).await.unwrap(),
+        Some(1)
+    );
+}
+
 async fn assert_persisted_offsets(
     config: &Arc<SystemConfig>,
     storage: &PartitionStorageKind,
@@ -34,6 +34,7 @@ use std::str::FromStr;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
 use test_case::test_matrix;
+use tokio::sync::Notify;
 
 /*
  * Below helper functions are here only to make test function name more readable.
@@ -132,6 +133,7 @@ async fn test_get_messages_by_timestamp(
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Arc::new(Notify::new()),
         IggyTimestamp::now(),
     )
     .await;
@@ -33,6 +33,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
+use tokio::sync::Notify;
 
 #[tokio::test]
 async fn should_persist_messages_and_then_load_them_by_timestamp() {
@@ -63,6 +64,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Arc::new(Notify::new()),
         IggyTimestamp::now(),
     )
     .await;
@@ -225,6 +227,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Arc::new(Notify::new()),
         IggyTimestamp::now(),
     )
     .await;
@@ -296,6 +299,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Arc::new(Notify::new()),
         now,
     )
     .await;
@@ -29,6 +29,7 @@ use server::streaming::segments::*;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
 use tokio::fs;
+use tokio::sync::Notify;
 
 #[tokio::test]
 async fn should_persist_partition_with_segment() {
@@ -52,6 +53,7 @@ async fn should_persist_partition_with_segment() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -84,6 +86,7 @@ async fn should_load_existing_partition_from_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -104,6 +107,7 @@ async fn should_load_existing_partition_from_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             now,
         )
         .await;
@@ -159,6 +163,7 @@ async fn should_delete_existing_partition_from_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -193,6 +198,7 @@ async fn should_purge_existing_partition_on_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -134,6 +134,9 @@ async fn should_load_existing_topic_from_disk() {
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
+            schemas: Default::default(),
+            schema_compatibility: Default::default(),
+            schema_validation: Default::default(),
             created_at: Default::default(),
         };
         loaded_topic.load(topic_state).await.unwrap();
@@ -0,0 +1,271 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::codecs::json::JsonCodec;
+use iggy::codecs::Codec;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy_streams::{
+    StreamsApp, StreamsBuilder, StreamsConfig, TimeWindows, KEY_HEADER, WINDOW_START_HEADER,
+};
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory, TestServer};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout};
+use tokio_util::sync::CancellationToken;
+
+const APPLICATION_ID: &str = "orders-app";
+const STREAM_NAME: &str = "shop";
+const CUSTOMERS_TOPIC: &str = "customers";
+const ORDERS_TOPIC: &str = "orders";
+const ENRICHED_ORDERS_TOPIC: &str = "enriched-orders";
+const TOTALS_TOPIC: &str = "totals";
+const HOURLY_COUNTS_TOPIC: &str = "hourly-counts";
+const PARTITIONS_COUNT: u32 = 2;
+const ORDERS_COUNT: u64 = 20;
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[tokio::test]
+#[parallel]
+async fn streams_app_should_join_aggregate_and_restore_state() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.clone(),
+        ..Default::default()
+    };
+    let client = IggyClient::create(client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+    let connection_string =
+        format!("iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}");
+
+    send(&client, CUSTOMERS_TOPIC, "alice", &"Alice".to_owned()).await;
+    send(&client, CUSTOMERS_TOPIC, "bob", &"Bob".to_owned()).await;
+    let (shutdown, app) = start_app(&connection_string).await;
+    let mut expected_totals = HashMap::new();
+    for amount in 1..=ORDERS_COUNT {
+        let customer = ["alice", "bob", "carol"][amount as usize % 3];
+        send(&client, ORDERS_TOPIC, customer, &amount).await;
+        *expected_totals.entry(customer.to_owned()).or_insert(0) += amount;
+    }
+
+    // 1. The orders of the known customers are enriched with their names, and the others are skipped
+    let enriched_orders = wait_for_messages::<String>(&client, ENRICHED_ORDERS_TOPIC, |orders| {
+        orders.len() as u64 == ORDERS_COUNT * 2 / 3
+    })
+    .await;
+    for (customer, order, _) in enriched_orders {
+        let expected_name = if customer == "alice" { "Alice" } else { "Bob" };
+        assert!(order.starts_with(&format!("{expected_name}:")));
+    }
+
+    // 2. The totals of the orders are aggregated by the customer
+    wait_for_totals(&client, &expected_totals).await;
+
+    // 3. The orders are counted within the windows, which sum up to all the orders of the customer
+    let hourly_counts = wait_for_messages::<u64>(&client, HOURLY_COUNTS_TOPIC, |counts| {
+        counts.len() as u64 == ORDERS_COUNT
+    })
+    .await;
+    let mut last_counts = HashMap::new();
+    for (customer, count, window_start) in hourly_counts {
+        last_counts.insert((customer, window_start.unwrap()), count);
+    }
+    let mut counts = HashMap::new();
+    for ((customer, _), count) in last_counts {
+        *counts.entry(customer).or_insert(0) += count;
+    }
+    assert_eq!(counts.values().sum::<u64>(), ORDERS_COUNT);
+
+    // 4. The restarted application restores the totals from the changelog and continues the aggregation
+    shutdown.cancel();
+    app.await.unwrap().unwrap();
+    let (shutdown, app) = start_app(&connection_string).await;
+    send(&client, ORDERS_TOPIC, "alice", &100).await;
+    *expected_totals.get_mut("alice").unwrap() += 100;
+    wait_for_totals(&client, &expected_totals).await;
+
+    shutdown.cancel();
+    app.await.unwrap().unwrap();
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+fn build_topology() -> StreamsBuilder {
+    let builder = StreamsBuilder::new();
+    let customers = builder.table::<String>(STREAM_NAME, CUSTOMERS_TOPIC, "customers", JsonCodec);
+    builder
+        .stream::<u64>(STREAM_NAME, ORDERS_TOPIC, JsonCodec)
+        .join(&customers, |amount, name| format!("{name}:{amount}"))
+        .to(STREAM_NAME, ENRICHED_ORDERS_TOPIC, JsonCodec);
+    builder
+        .stream::<u64>(STREAM_NAME, ORDERS_TOPIC, JsonCodec)
+        .group_by_key()
+        .aggregate("totals", JsonCodec, || 0, |_, amount, total| total + amount)
+        .to_stream()
+        .to(STREAM_NAME, TOTALS_TOPIC, JsonCodec);
+    builder
+        .stream::<u64>(STREAM_NAME, ORDERS_TOPIC, JsonCodec)
+        .group_by_key()
+        .windowed_by(TimeWindows::tumbling(IggyDuration::from_str("1h").unwrap()))
+        .count("hourly-counts")
+        .to_stream()
+        .map(|window| window.value)
+        .to(STREAM_NAME, HOURLY_COUNTS_TOPIC, JsonCodec);
+    builder
+}
+
+async fn start_app(
+    connection_string: &str,
+) -> (
+    CancellationToken,
+    JoinHandle<Result<(), iggy_streams::StreamsError>>,
+) {
+    let config = StreamsConfig::builder()
+        .application_id(APPLICATION_ID)
+        .changelog_stream(STREAM_NAME)
+        .batch_size(7)
+        .poll_interval(IggyDuration::from_str("10ms").unwrap())
+        .build();
+    let app = StreamsApp::from_connection_string(connection_string, config, build_topology())
+        .await
+        .unwrap();
+    let shutdown = CancellationToken::new();
+    (shutdown.clone(), tokio::spawn(app.run(shutdown)))
+}
+
+async fn init_system(client: &IggyClient) {
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    for (topic, partitions_count) in [
+        (CUSTOMERS_TOPIC, 1),
+        (ORDERS_TOPIC, PARTITIONS_COUNT),
+        (ENRICHED_ORDERS_TOPIC, 1),
+        (TOTALS_TOPIC, 1),
+        (HOURLY_COUNTS_TOPIC, 1),
+    ] {
+        client
+            .create_topic(
+                &Identifier::named(STREAM_NAME).unwrap(),
+                topic,
+                partitions_count,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn send<T>(client: &IggyClient, topic: &str, key: &str, value: &T)
+where
+    JsonCodec: Codec<T>,
+{
+    let headers = HashMap::from([(
+        HeaderKey::new(KEY_HEADER).unwrap(),
+        HeaderValue::from_str(key).unwrap(),
+    )]);
+    let payload = JsonCodec.encode(value).unwrap();
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(topic).unwrap(),
+            &Partitioning::messages_key_str(key).unwrap(),
+            &mut [Message::new(None, payload, Some(headers))],
+        )
+        .await
+        .unwrap();
+}
+
+/// Polls all the messages of the single partition topic until the condition is met,
+/// returning their keys, values and the window starts, if any.
+async fn wait_for_messages<T>(
+    client: &IggyClient,
+    topic: &str,
+    condition: impl Fn(&[(String, T, Option<u64>)]) -> bool,
+) -> Vec<(String, T, Option<u64>)>
+where
+    JsonCodec: Codec<T>,
+{
+    timeout(TIMEOUT, async {
+        loop {
+            let polled_messages = client
+                .poll_messages(
+                    &Identifier::named(STREAM_NAME).unwrap(),
+                    &Identifier::named(topic).unwrap(),
+                    Some(1),
+                    &Consumer::default(),
+                    &PollingStrategy::offset(0),
+                    1000,
+                    false,
+                )
+                .await
+                .unwrap();
+            let messages = polled_messages
+                .messages
+                .into_iter()
+                .map(|message| {
+                    let headers = message.headers.unwrap();
+                    let header = |key: &str| headers.get(&HeaderKey::new(key).unwrap());
+                    (
+                        header(KEY_HEADER).unwrap().as_str().unwrap().to_owned(),
+                        JsonCodec.decode(&message.payload).unwrap(),
+                        header(WINDOW_START_HEADER).map(|start| start.as_uint64().unwrap()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if condition(&messages) {
+                return messages;
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("Messages not sent to topic: {topic}"))
+}
+
+async fn wait_for_totals(client: &IggyClient, expected_totals: &HashMap<String, u64>) {
+    wait_for_messages::<u64>(client, TOTALS_TOPIC, |totals| {
+        let mut last_totals = HashMap::new();
+        for (customer, total, _) in totals {
+            last_totals.insert(customer.clone(), *total);
+        }
+        &last_totals == expected_totals
+    })
+    .await;
+}
@@ -99,6 +99,8 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
             group_id: group_id.clone(),
         })
         .await?;
+        self.session()
+            .left_consumer_group(stream_id, topic_id, group_id);
         Ok(())
     }
 
@@ -115,6 +117,8 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
             group_id: group_id.clone(),
         })
         .await?;
+        self.session()
+            .joined_consumer_group(stream_id, topic_id, group_id);
         Ok(())
     }
 
@@ -131,6 +135,8 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
             group_id: group_id.clone(),
         })
         .await?;
+        self.session()
+            .left_consumer_group(stream_id, topic_id, group_id);
         Ok(())
     }
 }
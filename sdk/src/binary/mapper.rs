@@ -19,7 +19,9 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use crate::models::config_reload_info::ConfigReloadInfo;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
@@ -27,6 +29,9 @@ use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
 use crate::models::partition::Partition;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
 use crate::models::stats::{CacheMetrics, CacheMetricsKey, Stats};
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
@@ -381,6 +386,48 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     })
 }
 
+pub fn map_config_reload_info(payload: Bytes) -> Result<ConfigReloadInfo, IggyError> {
+    let (applied, position) = map_to_config_keys(&payload, 0)?;
+    let (requires_restart, _) = map_to_config_keys(&payload, position)?;
+    Ok(ConfigReloadInfo {
+        applied,
+        requires_restart,
+    })
+}
+
+fn map_to_config_keys(payload: &Bytes, position: usize) -> Result<(Vec<String>, usize), IggyError> {
+    if position + 4 > payload.len() {
+        return Err(IggyError::InvalidNumberEncoding);
+    }
+    let count = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut current_position = position + 4;
+    let mut keys = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        if current_position + 4 > payload.len() {
+            return Err(IggyError::InvalidNumberEncoding);
+        }
+        let key_length = u32::from_le_bytes(
+            payload[current_position..current_position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        current_position += 4;
+        if current_position + key_length > payload.len() {
+            return Err(IggyError::InvalidNumberEncoding);
+        }
+        let key = from_utf8(&payload[current_position..current_position + key_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        current_position += key_length;
+        keys.push(key);
+    }
+    Ok((keys, current_position))
+}
+
 pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyError> {
     let partition_id = u32::from_le_bytes(
         payload[..4]
@@ -545,6 +592,45 @@ pub fn map_clients(payload: Bytes) -> Result<Vec<ClientInfo>, IggyError> {
     Ok(clients)
 }
 
+pub fn map_appended_messages(payload: Bytes) -> Result<Option<AppendedMessages>, IggyError> {
+    if payload.is_empty() {
+        return Ok(None);
+    }
+
+    let partition_id = u32::from_le_bytes(
+        payload[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let base_offset = u64::from_le_bytes(
+        payload[4..12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let last_offset = u64::from_le_bytes(
+        payload[12..20]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let base_timestamp = u64::from_le_bytes(
+        payload[20..28]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let last_timestamp = u64::from_le_bytes(
+        payload[28..36]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(Some(AppendedMessages {
+        partition_id,
+        base_offset,
+        last_offset,
+        base_timestamp,
+        last_timestamp,
+    }))
+}
+
 pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError> {
     if payload.is_empty() {
         return Ok(PolledMessages {
@@ -1019,6 +1105,10 @@ fn map_to_client_info(
     let transport = match transport {
         1 => "TCP",
         2 => "QUIC",
+        3 => "WebSocket",
+        4 => "Kafka",
+        5 => "MQTT",
+        6 => "HTTP",
         _ => "Unknown",
     }
     .to_string();
@@ -1103,3 +1193,102 @@ fn map_to_pat_info(
     let read_bytes = 1 + name_length as usize + 8;
     Ok((PersonalAccessTokenInfo { name, expiry_at }, read_bytes))
 }
+
+pub fn map_schema(payload: Bytes) -> Result<Schema, IggyError> {
+    let (schema, _) = map_to_schema(payload, 0)?;
+    Ok(schema)
+}
+
+pub fn map_topic_schemas(payload: Bytes) -> Result<TopicSchemas, IggyError> {
+    if payload.len() < 9 + SchemaValidation::SIZE {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let stream_id = u32::from_le_bytes(
+        payload[0..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let topic_id = u32::from_le_bytes(
+        payload[4..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let compatibility = SchemaCompatibility::from_code(payload[8])?;
+    let validation = SchemaValidation::from_bytes(payload.slice(9..9 + SchemaValidation::SIZE))?;
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = 9 + SchemaValidation::SIZE;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema(payload.clone(), position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    schemas.sort_by_key(|x| x.version);
+    Ok(TopicSchemas {
+        stream_id,
+        topic_id,
+        compatibility,
+        validation,
+        schemas,
+    })
+}
+
+fn map_to_schema(payload: Bytes, position: usize) -> Result<(Schema, usize), IggyError> {
+    if payload.len() < position + 29 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let id = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let stream_id = u32::from_le_bytes(
+        payload[position + 4..position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let topic_id = u32::from_le_bytes(
+        payload[position + 8..position + 12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let version = u32::from_le_bytes(
+        payload[position + 12..position + 16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let format = SchemaFormat::from_code(payload[position + 16])?;
+    let created_at = u64::from_le_bytes(
+        payload[position + 17..position + 25]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let definition_length = u32::from_le_bytes(
+        payload[position + 25..position + 29]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    if payload.len() < position + 29 + definition_length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let definition = from_utf8(&payload[position + 29..position + 29 + definition_length])
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    let read_bytes = 4 + 4 + 4 + 4 + 1 + 8 + 4 + definition_length;
+    Ok((
+        Schema {
+            id,
+            stream_id,
+            topic_id,
+            version,
+            format,
+            created_at,
+            definition,
+        },
+        read_bytes,
+    ))
+}
@@ -19,7 +19,7 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::MessageClient;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE, SEND_MESSAGES_WITH_OFFSETS_CODE};
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -27,7 +27,9 @@ use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
 use crate::messages::{poll_messages, send_messages};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::messages::PolledMessages;
+use crate::utils::duration::IggyDuration;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -53,6 +55,37 @@ impl<B: BinaryClient> MessageClient for B {
                     strategy,
                     count,
                     auto_commit,
+                    IggyDuration::default(),
+                ),
+            )
+            .await?;
+        mapper::map_polled_messages(response)
+    }
+
+    async fn poll_messages_with_wait(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_CODE,
+                poll_messages::as_bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    auto_commit,
+                    max_wait,
                 ),
             )
             .await?;
@@ -75,6 +108,23 @@ impl<B: BinaryClient> MessageClient for B {
         Ok(())
     }
 
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                SEND_MESSAGES_WITH_OFFSETS_CODE,
+                send_messages::as_bytes(stream_id, topic_id, partitioning, messages),
+            )
+            .await?;
+        mapper::map_appended_messages(response)
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::binary::session::ClientSession;
 use crate::command::Command;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
@@ -30,7 +31,7 @@ pub mod binary_client;
 pub mod consumer_groups;
 #[allow(deprecated)]
 pub mod consumer_offsets;
-mod mapper;
+pub(crate) mod mapper;
 #[allow(deprecated)]
 pub mod messages;
 #[allow(deprecated)]
@@ -38,9 +39,14 @@ pub mod partitions;
 #[allow(deprecated)]
 pub mod personal_access_tokens;
 #[allow(deprecated)]
+pub mod schemas;
+#[allow(deprecated)]
 pub mod segments;
+pub(crate) mod servers;
+pub mod session;
 #[allow(deprecated)]
 pub mod streams;
+pub mod subscriptions;
 #[allow(deprecated)]
 pub mod system;
 #[allow(deprecated)]
@@ -82,6 +88,8 @@ pub trait BinaryTransport {
     async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError>;
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError>;
     fn get_heartbeat_interval(&self) -> IggyDuration;
+    /// Gets the client-side session state, which is restored after reconnecting.
+    fn session(&self) -> &ClientSession;
 }
 
 async fn fail_if_not_authenticated<T: BinaryTransport>(transport: &T) -> Result<(), IggyError> {
@@ -18,7 +18,7 @@
 
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper, ClientState};
-use crate::client::PersonalAccessTokenClient;
+use crate::client::{Credentials, PersonalAccessTokenClient};
 use crate::error::IggyError;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
@@ -69,6 +69,8 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
                 token: token.to_string(),
             })
             .await?;
+        self.session()
+            .signed_in(Credentials::PersonalAccessToken(token.to_string()));
         self.set_state(ClientState::Authenticated).await;
         mapper::map_identity_info(response)
     }
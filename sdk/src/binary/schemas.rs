@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use crate::schemas::update_schema_validation::UpdateSchemaValidation;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetSchema { schema_id }).await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_schema(response).map(Some)
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetSchemas {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        mapper::map_topic_schemas(response)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RegisterSchema {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                format,
+                definition: definition.to_string(),
+            })
+            .await?;
+        mapper::map_schema(response)
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateSchemaCompatibility {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            compatibility,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_schema_validation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateSchemaValidation {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            validation,
+        })
+        .await?;
+        Ok(())
+    }
+}
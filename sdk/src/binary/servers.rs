@@ -0,0 +1,170 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::error;
+
+/// The separator used to provide multiple server addresses in a single string, e.g. `host1:8090,host2:8090`.
+pub const SERVER_ADDRESS_SEPARATOR: char = ',';
+
+/// Splits the provided server address(es) into the list of the trimmed, non-empty addresses.
+pub fn split_server_addresses(server_address: &str) -> Vec<&str> {
+    server_address
+        .split(SERVER_ADDRESS_SEPARATOR)
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .collect()
+}
+
+/// The bootstrap list of servers used by the binary clients.
+/// Each server keeps track of its health (consecutive failures and the latest connect latency),
+/// so that the healthiest server is tried first when (re)connecting, and the next ones are used for failover.
+#[derive(Debug)]
+pub(crate) struct ServerList<A> {
+    servers: Mutex<Vec<Server<A>>>,
+}
+
+#[derive(Debug)]
+struct Server<A> {
+    address: A,
+    failures: u32,
+    latency: Option<Duration>,
+}
+
+impl<A> ServerList<A>
+where
+    A: FromStr + Clone + PartialEq,
+    A::Err: Display,
+{
+    /// Parses the comma-separated list of server addresses.
+    pub fn parse(server_address: &str) -> Result<Self, IggyError> {
+        let addresses = split_server_addresses(server_address);
+        if addresses.is_empty() {
+            error!("No server address was provided.");
+            return Err(IggyError::InvalidServerAddress);
+        }
+
+        let mut servers: Vec<Server<A>> = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let address = address.parse::<A>().map_err(|error| {
+                error!("Invalid server address: {address}. {error}");
+                IggyError::InvalidServerAddress
+            })?;
+            if servers.iter().any(|server| server.address == address) {
+                continue;
+            }
+
+            servers.push(Server {
+                address,
+                failures: 0,
+                latency: None,
+            });
+        }
+
+        Ok(Self {
+            servers: Mutex::new(servers),
+        })
+    }
+
+    /// Returns the first configured server address.
+    pub fn primary(&self) -> A {
+        self.servers.lock().unwrap()[0].address.clone()
+    }
+
+    /// Returns the server addresses ordered by their health: the least failing servers go first,
+    /// and within the same number of failures, the ones with the lowest connect latency.
+    /// Servers that have not been connected yet keep their configured order.
+    pub fn candidates(&self) -> Vec<A> {
+        let servers = self.servers.lock().unwrap();
+        let mut indexes = (0..servers.len()).collect::<Vec<_>>();
+        indexes.sort_by_key(|index| {
+            let server = &servers[*index];
+            (server.failures, server.latency.unwrap_or(Duration::MAX))
+        });
+        indexes
+            .into_iter()
+            .map(|index| servers[index].address.clone())
+            .collect()
+    }
+
+    /// Resets the failures of the server and records its connect latency.
+    pub fn mark_connected(&self, address: &A, latency: Duration) {
+        if let Some(server) = self.find(address) {
+            let mut servers = self.servers.lock().unwrap();
+            servers[server].failures = 0;
+            servers[server].latency = Some(latency);
+        }
+    }
+
+    /// Increments the consecutive failures of the server.
+    pub fn mark_failed(&self, address: &A) {
+        if let Some(server) = self.find(address) {
+            let mut servers = self.servers.lock().unwrap();
+            servers[server].failures = servers[server].failures.saturating_add(1);
+        }
+    }
+
+    fn find(&self, address: &A) -> Option<usize> {
+        self.servers
+            .lock()
+            .unwrap()
+            .iter()
+            .position(|server| &server.address == address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::SocketAddr;
+
+    #[test]
+    fn server_list_should_be_parsed_from_comma_separated_addresses() {
+        let servers = ServerList::<String>::parse("host1:8090, host2:8090,,host1:8090").unwrap();
+        assert_eq!(servers.primary(), "host1:8090");
+        assert_eq!(servers.candidates(), vec!["host1:8090", "host2:8090"]);
+    }
+
+    #[test]
+    fn empty_server_list_should_fail() {
+        assert!(ServerList::<String>::parse(" , ").is_err());
+    }
+
+    #[test]
+    fn invalid_socket_address_should_fail() {
+        assert!(ServerList::<SocketAddr>::parse("127.0.0.1:8080,localhost").is_err());
+    }
+
+    #[test]
+    fn candidates_should_be_ordered_by_health() {
+        let servers = ServerList::<String>::parse("a:1,b:1,c:1").unwrap();
+        servers.mark_failed(&"a:1".to_string());
+        servers.mark_connected(&"c:1".to_string(), Duration::from_millis(5));
+        servers.mark_connected(&"b:1".to_string(), Duration::from_millis(10));
+        assert_eq!(servers.candidates(), vec!["c:1", "b:1", "a:1"]);
+
+        servers.mark_failed(&"c:1".to_string());
+        servers.mark_failed(&"c:1".to_string());
+        servers.mark_connected(&"a:1".to_string(), Duration::from_millis(20));
+        assert_eq!(servers.candidates(), vec!["b:1", "a:1", "c:1"]);
+    }
+}
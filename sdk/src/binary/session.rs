@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::client::Credentials;
+use crate::identifier::Identifier;
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+/// The client-side state of the session, which is restored after the client reconnects
+/// to the same or another server, e.g. when failing over to the next server from the bootstrap list.
+#[derive(Debug, Default)]
+pub struct ClientSession {
+    credentials: Mutex<Option<Credentials>>,
+    consumer_groups: Mutex<Vec<ConsumerGroupMembership>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ConsumerGroupMembership {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    group_id: Identifier,
+}
+
+impl ClientSession {
+    /// Returns the credentials used by the latest successful sign in.
+    pub(crate) fn credentials(&self) -> Option<Credentials> {
+        self.credentials.lock().unwrap().clone()
+    }
+
+    pub(crate) fn signed_in(&self, credentials: Credentials) {
+        self.credentials.lock().unwrap().replace(credentials);
+    }
+
+    pub(crate) fn signed_out(&self) {
+        self.credentials.lock().unwrap().take();
+        self.consumer_groups.lock().unwrap().clear();
+    }
+
+    pub(crate) fn joined_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) {
+        let membership = ConsumerGroupMembership {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+        };
+        let mut consumer_groups = self.consumer_groups.lock().unwrap();
+        if !consumer_groups.contains(&membership) {
+            consumer_groups.push(membership);
+        }
+    }
+
+    pub(crate) fn left_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) {
+        self.consumer_groups.lock().unwrap().retain(|membership| {
+            &membership.stream_id != stream_id
+                || &membership.topic_id != topic_id
+                || &membership.group_id != group_id
+        });
+    }
+
+    fn consumer_groups(&self) -> Vec<ConsumerGroupMembership> {
+        self.consumer_groups.lock().unwrap().clone()
+    }
+}
+
+/// Rejoins the consumer groups of the previous session after the client has signed in again.
+/// The failures are only logged, as the group might have been deleted in the meantime.
+pub(crate) async fn rejoin_consumer_groups<B: BinaryClient>(client: &B) {
+    for membership in client.session().consumer_groups() {
+        match client
+            .join_consumer_group(
+                &membership.stream_id,
+                &membership.topic_id,
+                &membership.group_id,
+            )
+            .await
+        {
+            Ok(()) => info!(
+                "Rejoined consumer group: {} for stream: {}, topic: {}.",
+                membership.group_id, membership.stream_id, membership.topic_id
+            ),
+            Err(error) => warn!(
+                "Failed to rejoin consumer group: {} for stream: {}, topic: {}. {error}",
+                membership.group_id, membership.stream_id, membership.topic_id
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consumer_group_memberships_should_be_tracked_until_left_or_signed_out() {
+        let session = ClientSession::default();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("topic").unwrap();
+        let group_1 = Identifier::numeric(1).unwrap();
+        let group_2 = Identifier::numeric(2).unwrap();
+
+        session.joined_consumer_group(&stream_id, &topic_id, &group_1);
+        session.joined_consumer_group(&stream_id, &topic_id, &group_1);
+        session.joined_consumer_group(&stream_id, &topic_id, &group_2);
+        assert_eq!(session.consumer_groups().len(), 2);
+
+        session.left_consumer_group(&stream_id, &topic_id, &group_1);
+        let consumer_groups = session.consumer_groups();
+        assert_eq!(consumer_groups.len(), 1);
+        assert_eq!(consumer_groups[0].group_id, group_2);
+
+        session.signed_in(Credentials::PersonalAccessToken("token".to_string()));
+        assert!(session.credentials().is_some());
+        session.signed_out();
+        assert!(session.credentials().is_none());
+        assert!(session.consumer_groups().is_empty());
+    }
+}
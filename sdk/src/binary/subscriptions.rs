@@ -0,0 +1,188 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::{mapper, BinaryTransport};
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::grant_message_credits::GrantMessageCredits;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::subscribe_messages::{SubscribeMessages, SUBSCRIPTION_ENDED_PAYLOAD_LENGTH};
+use crate::messages::unsubscribe_messages::UnsubscribeMessages;
+use crate::models::messages::PolledMessages;
+use bytes::Bytes;
+use dashmap::DashMap;
+use futures::Stream;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+use tracing::{error, trace, warn};
+
+/// The default number of the pushed batches buffered for a single subscription, before the connection stops being read.
+pub const DEFAULT_SUBSCRIPTION_BUFFER_SIZE: usize = 100;
+
+/// The stream of the message batches pushed by the server for a single subscription.
+///
+/// The stream ends once the subscription is cancelled, ended by the server or the connection is lost.
+#[derive(Debug)]
+pub struct MessageSubscription {
+    id: u32,
+    receiver: mpsc::Receiver<PolledMessages>,
+}
+
+impl MessageSubscription {
+    pub(crate) fn new(id: u32, receiver: mpsc::Receiver<PolledMessages>) -> Self {
+        Self { id, receiver }
+    }
+
+    /// Returns the identifier of the subscription, used to unsubscribe or grant more credits.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl Stream for MessageSubscription {
+    type Item = PolledMessages;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// The subscriptions of the binary client, to which the pushed messages are routed by the connection reader.
+#[derive(Debug)]
+pub(crate) struct MessageSubscriptions {
+    buffer_size: usize,
+    senders: DashMap<u32, mpsc::Sender<PolledMessages>>,
+    last_subscription_id: AtomicU32,
+}
+
+impl MessageSubscriptions {
+    pub fn new(buffer_size: usize) -> Self {
+        Self {
+            buffer_size: buffer_size.max(1),
+            senders: DashMap::new(),
+            last_subscription_id: AtomicU32::new(0),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe<T: BinaryTransport>(
+        &self,
+        transport: &T,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        credits: u32,
+    ) -> Result<MessageSubscription, IggyError> {
+        let subscription_id = self.last_subscription_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let (sender, receiver) = mpsc::channel(self.buffer_size);
+        // The channel is registered before sending the command, as the server might push the first batch
+        // before the response is read.
+        self.senders.insert(subscription_id, sender.clone());
+        let command = SubscribeMessages {
+            subscription_id,
+            credits,
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            strategy: *strategy,
+            count,
+            auto_commit,
+        };
+        if let Err(error) = transport.send_with_response(&command).await {
+            self.senders.remove(&subscription_id);
+            return Err(error);
+        }
+
+        // The subscriptions are dropped on reconnection, which might have happened while sending the command.
+        self.senders.entry(subscription_id).or_insert(sender);
+        Ok(MessageSubscription::new(subscription_id, receiver))
+    }
+
+    pub async fn unsubscribe<T: BinaryTransport>(
+        &self,
+        transport: &T,
+        subscription_id: u32,
+    ) -> Result<(), IggyError> {
+        self.senders.remove(&subscription_id);
+        transport
+            .send_with_response(&UnsubscribeMessages { subscription_id })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn grant_credits<T: BinaryTransport>(
+        &self,
+        transport: &T,
+        subscription_id: u32,
+        credits: u32,
+    ) -> Result<(), IggyError> {
+        transport
+            .send_with_response(&GrantMessageCredits {
+                subscription_id,
+                credits,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Delivers the messages pushed for the subscription, waiting if its buffer is full, or ends the subscription.
+    pub async fn route(&self, subscription_id: u32, payload: Bytes) {
+        if payload.len() == SUBSCRIPTION_ENDED_PAYLOAD_LENGTH {
+            let code = u32::from_le_bytes(payload[..].try_into().unwrap());
+            warn!(
+                "Subscription with ID: {subscription_id} has been ended by the server. {}",
+                IggyError::from_code_as_string(code)
+            );
+            self.senders.remove(&subscription_id);
+            return;
+        }
+
+        let messages = match mapper::map_polled_messages(payload) {
+            Ok(messages) => messages,
+            Err(error) => {
+                error!("Failed to map pushed messages for subscription with ID: {subscription_id}. {error}");
+                return;
+            }
+        };
+        let Some(sender) = self
+            .senders
+            .get(&subscription_id)
+            .map(|sender| sender.clone())
+        else {
+            trace!("Received messages for an unknown subscription with ID: {subscription_id}.");
+            return;
+        };
+        if sender.send(messages).await.is_err() {
+            trace!("Subscription with ID: {subscription_id} has been dropped.");
+            self.senders.remove(&subscription_id);
+        }
+    }
+
+    /// Ends all the subscriptions, as they're bound to the lost connection.
+    pub fn clear(&self) {
+        self.senders.clear();
+    }
+}
@@ -20,16 +20,20 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::SystemClient;
 use crate::error::IggyError;
+use crate::identifier::Identifier;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload_info::ConfigReloadInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::disconnect_client::DisconnectClient;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
 use crate::system::get_snapshot::GetSnapshot;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::reload_config::ReloadConfig;
 use crate::utils::duration::IggyDuration;
 
 #[async_trait::async_trait]
@@ -61,6 +65,26 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_clients(response)
     }
 
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DisconnectClient::client(client_id))
+            .await?;
+        Ok(())
+    }
+
+    async fn disconnect_user_clients(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DisconnectClient::user(user_id.clone()))
+            .await?;
+        Ok(())
+    }
+
+    async fn reload_config(&self) -> Result<ConfigReloadInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&ReloadConfig {}).await?;
+        mapper::map_config_reload_info(response)
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.send_with_response(&Ping {}).await?;
         Ok(())
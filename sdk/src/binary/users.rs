@@ -18,7 +18,7 @@
 
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper, ClientState};
-use crate::client::UserClient;
+use crate::client::{Credentials, UserClient};
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -141,6 +141,10 @@ impl<B: BinaryClient> UserClient for B {
                 context: Some("".to_string()),
             })
             .await?;
+        self.session().signed_in(Credentials::UsernamePassword(
+            username.to_string(),
+            password.to_string(),
+        ));
         self.set_state(ClientState::Authenticated).await;
         self.publish_event(DiagnosticEvent::SignedIn).await;
         mapper::map_identity_info(response)
@@ -149,6 +153,7 @@ impl<B: BinaryClient> UserClient for B {
     async fn logout_user(&self) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&LogoutUser {}).await?;
+        self.session().signed_out();
         self.set_state(ClientState::Connected).await;
         self.publish_event(DiagnosticEvent::SignedOut).await;
         Ok(())
@@ -0,0 +1,526 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::Consumer;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
+use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload_info::ConfigReloadInfo;
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::identity_info::IdentityInfo;
+use crate::models::messages::PolledMessages;
+use crate::models::permissions::Permissions;
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
+use crate::models::snapshot::Snapshot;
+use crate::models::stats::Stats;
+use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic::{Topic, TopicDetails};
+use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::user_status::UserStatus;
+use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use async_broadcast::Receiver;
+use std::fmt::Debug;
+
+/// The blocking counterpart of the `Client` trait, which is the main interface to the Iggy server.
+/// It consists of multiple modules, each of which is responsible for a specific set of commands.
+/// Except the ping, login and get me, all the other methods require authentication.
+pub trait Client:
+    SystemClient
+    + UserClient
+    + PersonalAccessTokenClient
+    + StreamClient
+    + TopicClient
+    + PartitionClient
+    + SegmentClient
+    + MessageClient
+    + ConsumerOffsetClient
+    + ConsumerGroupClient
+    + SchemaClient
+    + Sync
+    + Send
+    + Debug
+{
+    /// Connect to the server. Depending on the selected transport and provided configuration it might also perform authentication, retry logic etc.
+    /// If the client is already connected, it will do nothing.
+    fn connect(&self) -> Result<(), IggyError>;
+    /// Disconnect from the server. If the client is not connected, it will do nothing.
+    fn disconnect(&self) -> Result<(), IggyError>;
+    // Shutdown the client and release all the resources.
+    fn shutdown(&self) -> Result<(), IggyError>;
+    /// Subscribe to diagnostic events.
+    fn subscribe_events(&self) -> Receiver<DiagnosticEvent>;
+}
+
+/// This trait defines the blocking methods to interact with the system module.
+pub trait SystemClient {
+    /// Get the stats of the system such as PID, memory usage, streams count etc.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    fn get_stats(&self) -> Result<Stats, IggyError>;
+    /// Get the info about the currently connected client (not to be confused with the user).
+    ///
+    /// Authentication is required.
+    fn get_me(&self) -> Result<ClientInfoDetails, IggyError>;
+    /// Get the info about a specific client by unique ID (not to be confused with the user).
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    fn get_client(&self, client_id: u32) -> Result<Option<ClientInfoDetails>, IggyError>;
+    /// Get the info about all the currently connected clients (not to be confused with the users).
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError>;
+    /// Forcibly disconnect a specific client by unique ID (not to be confused with the user).
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError>;
+    /// Forcibly disconnect all the clients of the user by unique ID or username.
+    /// The TCP and QUIC connections are closed, and the HTTP access tokens issued so far are revoked.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    fn disconnect_user_clients(&self, user_id: &Identifier) -> Result<(), IggyError>;
+    /// Make the server re-read and validate its configuration.
+    /// The settings which can be changed at runtime (e.g. log level, background task intervals or TLS certificates) are applied immediately,
+    /// while the remaining changes are reported as requiring a restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    fn reload_config(&self) -> Result<ConfigReloadInfo, IggyError>;
+    /// Ping the server to check if it's alive.
+    fn ping(&self) -> Result<(), IggyError>;
+    fn heartbeat_interval(&self) -> IggyDuration;
+    /// Capture and package the current system state as a snapshot.
+    ///
+    /// Authentication is required.
+    fn snapshot(
+        &self,
+        compression: SnapshotCompression,
+        snapshot_types: Vec<SystemSnapshotType>,
+    ) -> Result<Snapshot, IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the user module.
+pub trait UserClient {
+    /// Get the info about a specific user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to read the users, unless the provided user ID is the same as the authenticated user.
+    fn get_user(&self, user_id: &Identifier) -> Result<Option<UserInfoDetails>, IggyError>;
+    /// Get the info about all the users.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    fn get_users(&self) -> Result<Vec<UserInfo>, IggyError>;
+    /// Create a new user.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError>;
+    /// Delete a user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    fn delete_user(&self, user_id: &Identifier) -> Result<(), IggyError>;
+    /// Update a user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    fn update_user(
+        &self,
+        user_id: &Identifier,
+        username: Option<&str>,
+        status: Option<UserStatus>,
+    ) -> Result<(), IggyError>;
+    /// Update the permissions of a user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    fn update_permissions(
+        &self,
+        user_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError>;
+    /// Change the password of a user by unique ID or username.
+    ///
+    /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
+    fn change_password(
+        &self,
+        user_id: &Identifier,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), IggyError>;
+    /// Login a user by username and password.
+    fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError>;
+    /// Logout the currently authenticated user.
+    fn logout_user(&self) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the personal access token module.
+pub trait PersonalAccessTokenClient {
+    /// Get the info about all the personal access tokens of the currently authenticated user.
+    fn get_personal_access_tokens(&self) -> Result<Vec<PersonalAccessTokenInfo>, IggyError>;
+    /// Create a new personal access token for the currently authenticated user.
+    fn create_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError>;
+    /// Delete a personal access token of the currently authenticated user by unique token name.
+    fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError>;
+    /// Login the user with the provided personal access token.
+    fn login_with_personal_access_token(&self, token: &str) -> Result<IdentityInfo, IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the stream module.
+pub trait StreamClient {
+    /// Get the info about a specific stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    fn get_stream(&self, stream_id: &Identifier) -> Result<Option<StreamDetails>, IggyError>;
+    /// Get the info about all the streams.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    fn get_streams(&self) -> Result<Vec<Stream>, IggyError>;
+    /// Create a new stream.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    fn create_stream(&self, name: &str, stream_id: Option<u32>)
+        -> Result<StreamDetails, IggyError>;
+    /// Update a stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError>;
+    /// Delete a stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError>;
+    /// Purge a stream by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError>;
+}
+
+pub trait TopicClient {
+    /// Get the info about a specific topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    fn get_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<TopicDetails>, IggyError>;
+    /// Get the info about all the topics.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError>;
+    /// Create a new topic.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
+    fn create_topic(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError>;
+    /// Update a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
+    fn update_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+    ) -> Result<(), IggyError>;
+    /// Delete a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    fn delete_topic(&self, stream_id: &Identifier, topic_id: &Identifier) -> Result<(), IggyError>;
+    /// Purge a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    fn purge_topic(&self, stream_id: &Identifier, topic_id: &Identifier) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the partition module.
+pub trait PartitionClient {
+    /// Create new N partitions for a topic by unique ID or name.
+    ///
+    /// For example, given a topic with 3 partitions, if you create 2 partitions, the topic will have 5 partitions (from 1 to 5).
+    ///
+    /// Authentication is required, and the permission to manage the partitions.
+    fn create_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError>;
+    /// Delete last N partitions for a topic by unique ID or name.
+    ///
+    /// For example, given a topic with 5 partitions, if you delete 2 partitions, the topic will have 3 partitions left (from 1 to 3).
+    ///
+    /// Authentication is required, and the permission to manage the partitions.
+    fn delete_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the partition module.
+pub trait SegmentClient {
+    /// Delete last N segments for a partition by unique ID or name.
+    ///
+    /// For example, given a partition with 5 segments, if you delete 2 segments, the topic will have 3 segments left (from 1 to 3).
+    ///
+    /// Authentication is required, and the permission to manage the segments.
+    fn delete_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        segments_count: u32,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the messaging module.
+pub trait MessageClient {
+    /// Poll given amount of messages using the specified consumer and strategy from the specified stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    fn poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages like `poll_messages`, but when there are no messages to poll,
+    /// the server waits for the new ones to be appended for up to `max_wait` (capped by the server configuration).
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    fn poll_messages_with_wait(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    fn send_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// returning the partition ID, offsets and timestamps of the appended messages.
+    /// Returns `None` if no message was appended to the topic, e.g. all of them were routed to the rejection topic.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError>;
+    /// Force flush of the `unsaved_messages` buffer to disk, optionally fsyncing the data.
+    #[allow(clippy::too_many_arguments)]
+    fn flush_unsaved_buffer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        fsync: bool,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the consumer offset module.
+pub trait ConsumerOffsetClient {
+    /// Store the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    fn store_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError>;
+    /// Get the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    fn get_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Option<ConsumerOffsetInfo>, IggyError>;
+    /// Delete the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    fn delete_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the consumer group module.
+pub trait ConsumerGroupClient {
+    /// Get the info about a specific consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    fn get_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupDetails>, IggyError>;
+    /// Get the info about all the consumer groups for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    fn get_consumer_groups(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<ConsumerGroup>, IggyError>;
+    /// Create a new consumer group for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    fn create_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError>;
+    /// Delete a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    fn delete_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError>;
+    /// Join a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    fn join_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError>;
+    /// Leave a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    fn leave_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the blocking methods to interact with the schema registry module.
+pub trait SchemaClient {
+    /// Get the schema by unique ID, regardless of the topic it was registered for.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError>;
+    /// Get all the versions of the schema registered for the given stream and topic by unique IDs or names, and its compatibility rule.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError>;
+    /// Register a new version of the schema for the given stream and topic by unique IDs or names.
+    /// The new version must be compatible with the latest one, according to the compatibility rule of the topic.
+    /// If the latest version has the same format and definition, it's returned instead of registering a new one.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<Schema, IggyError>;
+    /// Update the compatibility rule checked when registering a new version of the schema for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    fn update_schema_compatibility(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError>;
+    /// Update the validation of the message payloads appended to the given stream and topic by unique IDs or names, against its registered schema.
+    /// The invalid messages are either rejected, or routed to the rejection topic, depending on the validation mode.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    fn update_schema_validation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError>;
+}
@@ -0,0 +1,805 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::blocking::client::{
+    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
+    PersonalAccessTokenClient, SchemaClient, SegmentClient, StreamClient, SystemClient,
+    TopicClient, UserClient,
+};
+use crate::blocking::clients::consumer::IggyConsumerBuilder;
+use crate::blocking::clients::producer::IggyProducerBuilder;
+use crate::blocking::clients::BlockingRuntime;
+use crate::client as async_client;
+use crate::clients::client::IggyClient as AsyncIggyClient;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::Consumer;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
+use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload_info::ConfigReloadInfo;
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::identity_info::IdentityInfo;
+use crate::models::messages::PolledMessages;
+use crate::models::permissions::Permissions;
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
+use crate::models::snapshot::Snapshot;
+use crate::models::stats::Stats;
+use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic::{Topic, TopicDetails};
+use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::user_status::UserStatus;
+use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use async_broadcast::Receiver;
+use std::future::Future;
+
+/// The blocking wrapper of `IggyClient`, with the synchronous methods backed by an internal runtime.
+///
+/// It provides the builders for the blocking standalone consumer, consumer group, and producer.
+/// The blocking client must not be used from within the async runtime, as it would panic when blocking on the futures.
+#[derive(Debug)]
+pub struct IggyClient {
+    client: AsyncIggyClient,
+    runtime: BlockingRuntime,
+}
+
+impl IggyClient {
+    /// Creates a new blocking client wrapping the provided async client.
+    pub fn new(client: AsyncIggyClient) -> Result<Self, IggyError> {
+        Ok(Self {
+            client,
+            runtime: BlockingRuntime::new()?,
+        })
+    }
+
+    /// Creates a new blocking client using the TCP transport configured by the connection string.
+    pub fn from_connection_string(connection_string: &str) -> Result<Self, IggyError> {
+        let runtime = BlockingRuntime::new()?;
+        let client =
+            runtime.enter(|| AsyncIggyClient::from_connection_string(connection_string))?;
+        Ok(Self { client, runtime })
+    }
+
+    /// Returns the wrapped async client.
+    pub fn async_client(&self) -> &AsyncIggyClient {
+        &self.client
+    }
+
+    /// Returns the builder for the blocking standalone consumer.
+    pub fn consumer(
+        &self,
+        name: &str,
+        stream: &str,
+        topic: &str,
+        partition: u32,
+    ) -> Result<IggyConsumerBuilder, IggyError> {
+        let builder = self.client.consumer(name, stream, topic, partition)?;
+        Ok(IggyConsumerBuilder::new(builder, self.runtime.clone()))
+    }
+
+    /// Returns the builder for the blocking consumer group.
+    pub fn consumer_group(
+        &self,
+        name: &str,
+        stream: &str,
+        topic: &str,
+    ) -> Result<IggyConsumerBuilder, IggyError> {
+        let builder = self.client.consumer_group(name, stream, topic)?;
+        Ok(IggyConsumerBuilder::new(builder, self.runtime.clone()))
+    }
+
+    /// Returns the builder for the blocking producer.
+    pub fn producer(&self, stream: &str, topic: &str) -> Result<IggyProducerBuilder, IggyError> {
+        let builder = self.client.producer(stream, topic)?;
+        Ok(IggyProducerBuilder::new(builder, self.runtime.clone()))
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+impl Client for IggyClient {
+    fn connect(&self) -> Result<(), IggyError> {
+        self.block_on(async_client::Client::connect(&self.client))
+    }
+
+    fn disconnect(&self) -> Result<(), IggyError> {
+        self.block_on(async_client::Client::disconnect(&self.client))
+    }
+
+    fn shutdown(&self) -> Result<(), IggyError> {
+        self.block_on(async_client::Client::shutdown(&self.client))
+    }
+
+    fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.block_on(async_client::Client::subscribe_events(&self.client))
+    }
+}
+
+impl SystemClient for IggyClient {
+    fn get_stats(&self) -> Result<Stats, IggyError> {
+        self.block_on(async_client::SystemClient::get_stats(&self.client))
+    }
+
+    fn get_me(&self) -> Result<ClientInfoDetails, IggyError> {
+        self.block_on(async_client::SystemClient::get_me(&self.client))
+    }
+
+    fn get_client(&self, client_id: u32) -> Result<Option<ClientInfoDetails>, IggyError> {
+        self.block_on(async_client::SystemClient::get_client(
+            &self.client,
+            client_id,
+        ))
+    }
+
+    fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError> {
+        self.block_on(async_client::SystemClient::get_clients(&self.client))
+    }
+
+    fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        self.block_on(async_client::SystemClient::disconnect_client(
+            &self.client,
+            client_id,
+        ))
+    }
+
+    fn disconnect_user_clients(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.block_on(async_client::SystemClient::disconnect_user_clients(
+            &self.client,
+            user_id,
+        ))
+    }
+
+    fn reload_config(&self) -> Result<ConfigReloadInfo, IggyError> {
+        self.block_on(async_client::SystemClient::reload_config(&self.client))
+    }
+
+    fn ping(&self) -> Result<(), IggyError> {
+        self.block_on(async_client::SystemClient::ping(&self.client))
+    }
+
+    fn heartbeat_interval(&self) -> IggyDuration {
+        self.block_on(async_client::SystemClient::heartbeat_interval(&self.client))
+    }
+
+    fn snapshot(
+        &self,
+        compression: SnapshotCompression,
+        snapshot_types: Vec<SystemSnapshotType>,
+    ) -> Result<Snapshot, IggyError> {
+        self.block_on(async_client::SystemClient::snapshot(
+            &self.client,
+            compression,
+            snapshot_types,
+        ))
+    }
+}
+
+impl UserClient for IggyClient {
+    fn get_user(&self, user_id: &Identifier) -> Result<Option<UserInfoDetails>, IggyError> {
+        self.block_on(async_client::UserClient::get_user(&self.client, user_id))
+    }
+
+    fn get_users(&self) -> Result<Vec<UserInfo>, IggyError> {
+        self.block_on(async_client::UserClient::get_users(&self.client))
+    }
+
+    fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<UserInfoDetails, IggyError> {
+        self.block_on(async_client::UserClient::create_user(
+            &self.client,
+            username,
+            password,
+            status,
+            permissions,
+        ))
+    }
+
+    fn delete_user(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.block_on(async_client::UserClient::delete_user(&self.client, user_id))
+    }
+
+    fn update_user(
+        &self,
+        user_id: &Identifier,
+        username: Option<&str>,
+        status: Option<UserStatus>,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::UserClient::update_user(
+            &self.client,
+            user_id,
+            username,
+            status,
+        ))
+    }
+
+    fn update_permissions(
+        &self,
+        user_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::UserClient::update_permissions(
+            &self.client,
+            user_id,
+            permissions,
+        ))
+    }
+
+    fn change_password(
+        &self,
+        user_id: &Identifier,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::UserClient::change_password(
+            &self.client,
+            user_id,
+            current_password,
+            new_password,
+        ))
+    }
+
+    fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError> {
+        self.block_on(async_client::UserClient::login_user(
+            &self.client,
+            username,
+            password,
+        ))
+    }
+
+    fn logout_user(&self) -> Result<(), IggyError> {
+        self.block_on(async_client::UserClient::logout_user(&self.client))
+    }
+}
+
+impl PersonalAccessTokenClient for IggyClient {
+    fn get_personal_access_tokens(&self) -> Result<Vec<PersonalAccessTokenInfo>, IggyError> {
+        self.block_on(
+            async_client::PersonalAccessTokenClient::get_personal_access_tokens(&self.client),
+        )
+    }
+
+    fn create_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.block_on(
+            async_client::PersonalAccessTokenClient::create_personal_access_token(
+                &self.client,
+                name,
+                expiry,
+            ),
+        )
+    }
+
+    fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError> {
+        self.block_on(
+            async_client::PersonalAccessTokenClient::delete_personal_access_token(
+                &self.client,
+                name,
+            ),
+        )
+    }
+
+    fn login_with_personal_access_token(&self, token: &str) -> Result<IdentityInfo, IggyError> {
+        self.block_on(
+            async_client::PersonalAccessTokenClient::login_with_personal_access_token(
+                &self.client,
+                token,
+            ),
+        )
+    }
+}
+
+impl StreamClient for IggyClient {
+    fn get_stream(&self, stream_id: &Identifier) -> Result<Option<StreamDetails>, IggyError> {
+        self.block_on(async_client::StreamClient::get_stream(
+            &self.client,
+            stream_id,
+        ))
+    }
+
+    fn get_streams(&self) -> Result<Vec<Stream>, IggyError> {
+        self.block_on(async_client::StreamClient::get_streams(&self.client))
+    }
+
+    fn create_stream(
+        &self,
+        name: &str,
+        stream_id: Option<u32>,
+    ) -> Result<StreamDetails, IggyError> {
+        self.block_on(async_client::StreamClient::create_stream(
+            &self.client,
+            name,
+            stream_id,
+        ))
+    }
+
+    fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
+        self.block_on(async_client::StreamClient::update_stream(
+            &self.client,
+            stream_id,
+            name,
+        ))
+    }
+
+    fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        self.block_on(async_client::StreamClient::delete_stream(
+            &self.client,
+            stream_id,
+        ))
+    }
+
+    fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        self.block_on(async_client::StreamClient::purge_stream(
+            &self.client,
+            stream_id,
+        ))
+    }
+}
+
+impl TopicClient for IggyClient {
+    fn get_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Option<TopicDetails>, IggyError> {
+        self.block_on(async_client::TopicClient::get_topic(
+            &self.client,
+            stream_id,
+            topic_id,
+        ))
+    }
+
+    fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError> {
+        self.block_on(async_client::TopicClient::get_topics(
+            &self.client,
+            stream_id,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_topic(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError> {
+        self.block_on(async_client::TopicClient::create_topic(
+            &self.client,
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+        ))
+    }
+
+    fn update_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::TopicClient::update_topic(
+            &self.client,
+            stream_id,
+            topic_id,
+            name,
+            compression_algorithm,
+            replication_factor,
+            message_expiry,
+            max_topic_size,
+        ))
+    }
+
+    fn delete_topic(&self, stream_id: &Identifier, topic_id: &Identifier) -> Result<(), IggyError> {
+        self.block_on(async_client::TopicClient::delete_topic(
+            &self.client,
+            stream_id,
+            topic_id,
+        ))
+    }
+
+    fn purge_topic(&self, stream_id: &Identifier, topic_id: &Identifier) -> Result<(), IggyError> {
+        self.block_on(async_client::TopicClient::purge_topic(
+            &self.client,
+            stream_id,
+            topic_id,
+        ))
+    }
+}
+
+impl PartitionClient for IggyClient {
+    fn create_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::PartitionClient::create_partitions(
+            &self.client,
+            stream_id,
+            topic_id,
+            partitions_count,
+        ))
+    }
+
+    fn delete_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::PartitionClient::delete_partitions(
+            &self.client,
+            stream_id,
+            topic_id,
+            partitions_count,
+        ))
+    }
+}
+
+impl SegmentClient for IggyClient {
+    fn delete_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        segments_count: u32,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::SegmentClient::delete_segments(
+            &self.client,
+            stream_id,
+            topic_id,
+            partition_id,
+            segments_count,
+        ))
+    }
+}
+
+impl MessageClient for IggyClient {
+    #[allow(clippy::too_many_arguments)]
+    fn poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.block_on(async_client::MessageClient::poll_messages(
+            &self.client,
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+        ))
+    }
+
+    fn poll_messages_with_wait(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        self.block_on(async_client::MessageClient::poll_messages_with_wait(
+            &self.client,
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            max_wait,
+        ))
+    }
+
+    fn send_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::MessageClient::send_messages(
+            &self.client,
+            stream_id,
+            topic_id,
+            partitioning,
+            messages,
+        ))
+    }
+
+    fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        self.block_on(async_client::MessageClient::send_messages_with_offsets(
+            &self.client,
+            stream_id,
+            topic_id,
+            partitioning,
+            messages,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn flush_unsaved_buffer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        fsync: bool,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::MessageClient::flush_unsaved_buffer(
+            &self.client,
+            stream_id,
+            topic_id,
+            partition_id,
+            fsync,
+        ))
+    }
+}
+
+impl ConsumerOffsetClient for IggyClient {
+    fn store_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::ConsumerOffsetClient::store_consumer_offset(
+            &self.client,
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+        ))
+    }
+
+    fn get_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Option<ConsumerOffsetInfo>, IggyError> {
+        self.block_on(async_client::ConsumerOffsetClient::get_consumer_offset(
+            &self.client,
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+        ))
+    }
+
+    fn delete_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::ConsumerOffsetClient::delete_consumer_offset(
+            &self.client,
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+        ))
+    }
+}
+
+impl ConsumerGroupClient for IggyClient {
+    fn get_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<Option<ConsumerGroupDetails>, IggyError> {
+        self.block_on(async_client::ConsumerGroupClient::get_consumer_group(
+            &self.client,
+            stream_id,
+            topic_id,
+            group_id,
+        ))
+    }
+
+    fn get_consumer_groups(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<ConsumerGroup>, IggyError> {
+        self.block_on(async_client::ConsumerGroupClient::get_consumer_groups(
+            &self.client,
+            stream_id,
+            topic_id,
+        ))
+    }
+
+    fn create_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.block_on(async_client::ConsumerGroupClient::create_consumer_group(
+            &self.client,
+            stream_id,
+            topic_id,
+            name,
+            group_id,
+        ))
+    }
+
+    fn delete_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::ConsumerGroupClient::delete_consumer_group(
+            &self.client,
+            stream_id,
+            topic_id,
+            group_id,
+        ))
+    }
+
+    fn join_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::ConsumerGroupClient::join_consumer_group(
+            &self.client,
+            stream_id,
+            topic_id,
+            group_id,
+        ))
+    }
+
+    fn leave_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::ConsumerGroupClient::leave_consumer_group(
+            &self.client,
+            stream_id,
+            topic_id,
+            group_id,
+        ))
+    }
+}
+
+impl SchemaClient for IggyClient {
+    fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        self.block_on(async_client::SchemaClient::get_schema(
+            &self.client,
+            schema_id,
+        ))
+    }
+
+    fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        self.block_on(async_client::SchemaClient::get_schemas(
+            &self.client,
+            stream_id,
+            topic_id,
+        ))
+    }
+
+    fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        self.block_on(async_client::SchemaClient::register_schema(
+            &self.client,
+            stream_id,
+            topic_id,
+            format,
+            definition,
+        ))
+    }
+
+    fn update_schema_compatibility(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::SchemaClient::update_schema_compatibility(
+            &self.client,
+            stream_id,
+            topic_id,
+            compatibility,
+        ))
+    }
+
+    fn update_schema_validation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError> {
+        self.block_on(async_client::SchemaClient::update_schema_validation(
+            &self.client,
+            stream_id,
+            topic_id,
+            validation,
+        ))
+    }
+}
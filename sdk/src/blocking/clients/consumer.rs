@@ -0,0 +1,206 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::blocking::clients::{forward_builder_methods, BlockingRuntime};
+use crate::clients::consumer::{
+    AutoCommit, IggyConsumer as AsyncIggyConsumer, IggyConsumerBuilder as AsyncIggyConsumerBuilder,
+    ReceivedMessage,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::utils::crypto::EncryptorKind;
+use crate::utils::duration::IggyDuration;
+use futures_util::StreamExt;
+use std::future::poll_fn;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+/// The blocking wrapper of `IggyConsumer`, with the synchronous methods backed by the runtime of the blocking client.
+/// The messages are received by iterating over the consumer, which blocks until the next message is available.
+///
+/// The underlying consumer polls the messages in a background task, so that its requests are always completed
+/// and never hold the connection shared with the client, e.g. after `next_timeout()` has elapsed.
+/// As a result, at most one message is fetched ahead of the one being returned.
+pub struct IggyConsumer {
+    consumer: Arc<Mutex<AsyncIggyConsumer>>,
+    name: String,
+    stream: Identifier,
+    topic: Identifier,
+    receiver: mpsc::Receiver<Result<ReceivedMessage, IggyError>>,
+    runtime: BlockingRuntime,
+}
+
+impl IggyConsumer {
+    fn new(consumer: AsyncIggyConsumer, runtime: BlockingRuntime) -> Self {
+        let name = consumer.name().to_owned();
+        let stream = consumer.stream().clone();
+        let topic = consumer.topic().clone();
+        let consumer = Arc::new(Mutex::new(consumer));
+        let (sender, receiver) = mpsc::channel(1);
+        runtime.spawn(Self::forward_messages(consumer.clone(), sender));
+        Self {
+            consumer,
+            name,
+            stream,
+            topic,
+            receiver,
+            runtime,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn topic(&self) -> &Identifier {
+        &self.topic
+    }
+
+    pub fn stream(&self) -> &Identifier {
+        &self.stream
+    }
+
+    pub fn partition_id(&self) -> u32 {
+        self.consumer.lock().unwrap().partition_id()
+    }
+
+    /// Receives the next message, waiting for it up to the provided timeout.
+    /// Returns `None` if no message was received within the timeout or the consumer has stopped.
+    pub fn next_timeout(
+        &mut self,
+        timeout: IggyDuration,
+    ) -> Option<Result<ReceivedMessage, IggyError>> {
+        let receiver = &mut self.receiver;
+        self.runtime
+            .block_on(async { tokio::time::timeout(timeout.get_duration(), receiver.recv()).await })
+            .ok()
+            .flatten()
+    }
+
+    /// Stores the consumer offset on the server, either for the current partition or the provided partition ID.
+    pub fn store_offset(&self, offset: u64, partition_id: Option<u32>) -> Result<(), IggyError> {
+        let future = self
+            .consumer
+            .lock()
+            .unwrap()
+            .store_offset_future(offset, partition_id);
+        self.runtime.block_on(future)
+    }
+
+    /// Deletes the consumer offset on the server, either for the current partition or the provided partition ID.
+    pub fn delete_offset(&self, partition_id: Option<u32>) -> Result<(), IggyError> {
+        let future = self
+            .consumer
+            .lock()
+            .unwrap()
+            .delete_offset_future(partition_id);
+        self.runtime.block_on(future)
+    }
+
+    async fn forward_messages(
+        consumer: Arc<Mutex<AsyncIggyConsumer>>,
+        sender: mpsc::Sender<Result<ReceivedMessage, IggyError>>,
+    ) {
+        loop {
+            let next_message = poll_fn(|cx| consumer.lock().unwrap().poll_next_unpin(cx));
+            let message = tokio::select! {
+                _ = sender.closed() => return,
+                message = next_message => message,
+            };
+            let Some(message) = message else {
+                return;
+            };
+            if sender.send(message).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+impl Iterator for IggyConsumer {
+    type Item = Result<ReceivedMessage, IggyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.receiver.recv())
+    }
+}
+
+/// The builder of the blocking consumer, with the same options as `IggyConsumerBuilder`.
+#[derive(Debug)]
+pub struct IggyConsumerBuilder {
+    builder: AsyncIggyConsumerBuilder,
+    runtime: BlockingRuntime,
+}
+
+impl IggyConsumerBuilder {
+    pub(crate) fn new(builder: AsyncIggyConsumerBuilder, runtime: BlockingRuntime) -> Self {
+        Self { builder, runtime }
+    }
+
+    forward_builder_methods! {
+    /// Sets the stream identifier.
+    fn stream(self, stream: Identifier);
+    /// Sets the topic identifier.
+    fn topic(self, topic: Identifier);
+    /// Sets the partition identifier.
+    fn partition(self, partition: Option<u32>);
+    /// Sets the polling strategy.
+    fn polling_strategy(self, polling_strategy: PollingStrategy);
+    /// Sets the batch size for polling messages.
+    fn batch_size(self, batch_size: u32);
+    /// Sets the auto-commit configuration for storing the offset on the server.
+    fn auto_commit(self, auto_commit: AutoCommit);
+    fn commit_failed_messages(self);
+    /// Automatically joins the consumer group if the consumer is a part of a consumer group.
+    fn auto_join_consumer_group(self);
+    /// Does not automatically join the consumer group if the consumer is a part of a consumer group.
+    fn do_not_auto_join_consumer_group(self);
+    /// Automatically creates the consumer group if it does not exist.
+    fn create_consumer_group_if_not_exists(self);
+    /// Does not automatically create the consumer group if it does not exist.
+    fn do_not_create_consumer_group_if_not_exists(self);
+    /// Sets the polling interval for messages.
+    fn poll_interval(self, interval: IggyDuration);
+    /// Clears the polling interval for messages.
+    fn without_poll_interval(self);
+    /// Sets the encryptor for decrypting the messages' payloads.
+    fn encryptor(self, encryptor: Arc<EncryptorKind>);
+    /// Clears the encryptor for decrypting the messages' payloads.
+    fn without_encryptor(self);
+    /// Sets the polling retry interval in case of server disconnection.
+    fn polling_retry_interval(self, interval: IggyDuration);
+    /// Sets the number of retries and the interval when initializing the consumer if the stream or topic is not found.
+    /// Might be useful when the stream or topic is created dynamically by the producer.
+    /// By default, the consumer will not retry.
+    fn init_retries(self, retries: u32, interval: IggyDuration);
+    /// Allows replaying the messages, `false` by default.
+    fn allow_replay(self);
+    /// Sets the maximum number of the received messages which are not acknowledged yet.
+    /// Once reached, the consumer waits for the acknowledgements before returning the next message.
+    /// Applies only to `AutoCommit::Acknowledged`, by default there's no limit.
+    fn max_in_flight(self, max_in_flight: u32);
+    }
+
+    /// Builds and initializes the consumer.
+    pub fn build(self) -> Result<IggyConsumer, IggyError> {
+        let mut consumer = self.builder.build();
+        self.runtime.block_on(consumer.init())?;
+        Ok(IggyConsumer::new(consumer, self.runtime))
+    }
+}
@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod client;
+pub mod consumer;
+pub mod producer;
+
+use crate::error::IggyError;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+use tracing::error;
+
+/// Implements the methods of the blocking builder, each of which forwards to the same method of the wrapped async builder.
+macro_rules! forward_builder_methods {
+    ($($(#[$meta:meta])* fn $name:ident(self $(, $arg:ident: $arg_type:ty)*);)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(self $(, $arg: $arg_type)*) -> Self {
+                Self {
+                    builder: self.builder.$name($($arg),*),
+                    ..self
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use forward_builder_methods;
+
+/// The runtime shared by the blocking client and its producers and consumers.
+/// The futures are driven on the calling thread, while the background tasks (e.g. heartbeat,
+/// buffered sending or storing the offsets) run on the dedicated worker thread.
+#[derive(Debug, Clone)]
+pub(crate) struct BlockingRuntime {
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingRuntime {
+    pub(crate) fn new() -> Result<Self, IggyError> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("iggy-blocking")
+            .enable_all()
+            .build()
+            .map_err(|error| {
+                error!("Failed to create the runtime for the blocking client: {error}");
+                IggyError::CannotCreateRuntime
+            })?;
+        Ok(Self {
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Runs the future to completion on the calling thread.
+    ///
+    /// Panics if called from within the async runtime, as the blocking client must not be used in the async context.
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Spawns the background task on the runtime.
+    pub(crate) fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.runtime.spawn(future)
+    }
+
+    /// Runs the function within the context of the runtime, e.g. to create the objects spawning the tasks.
+    pub(crate) fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        let _guard = self.runtime.enter();
+        f()
+    }
+}
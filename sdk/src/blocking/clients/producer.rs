@@ -0,0 +1,141 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::blocking::clients::{forward_builder_methods, BlockingRuntime};
+use crate::clients::producer::{
+    IggyProducer as AsyncIggyProducer, IggyProducerBuilder as AsyncIggyProducerBuilder,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::partitioner::Partitioner;
+use crate::utils::crypto::EncryptorKind;
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use std::sync::Arc;
+
+/// The blocking wrapper of `IggyProducer`, with the synchronous methods backed by the runtime of the blocking client.
+pub struct IggyProducer {
+    producer: AsyncIggyProducer,
+    runtime: BlockingRuntime,
+}
+
+impl IggyProducer {
+    pub fn stream(&self) -> &Identifier {
+        self.producer.stream()
+    }
+
+    pub fn topic(&self) -> &Identifier {
+        self.producer.topic()
+    }
+
+    /// Sends the messages using the partitioning configured for the producer.
+    pub fn send(&self, messages: Vec<Message>) -> Result<(), IggyError> {
+        self.runtime.block_on(self.producer.send(messages))
+    }
+
+    /// Sends a single message using the partitioning configured for the producer.
+    pub fn send_one(&self, message: Message) -> Result<(), IggyError> {
+        self.runtime.block_on(self.producer.send_one(message))
+    }
+
+    /// Sends the messages using the provided partitioning, or the one configured for the producer.
+    pub fn send_with_partitioning(
+        &self,
+        messages: Vec<Message>,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        self.runtime
+            .block_on(self.producer.send_with_partitioning(messages, partitioning))
+    }
+
+    /// Sends the messages to the provided stream and topic.
+    pub fn send_to(
+        &self,
+        stream: Arc<Identifier>,
+        topic: Arc<Identifier>,
+        messages: Vec<Message>,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        self.runtime
+            .block_on(self.producer.send_to(stream, topic, messages, partitioning))
+    }
+}
+
+/// The builder of the blocking producer, with the same options as `IggyProducerBuilder`.
+#[derive(Debug)]
+pub struct IggyProducerBuilder {
+    builder: AsyncIggyProducerBuilder,
+    runtime: BlockingRuntime,
+}
+
+impl IggyProducerBuilder {
+    pub(crate) fn new(builder: AsyncIggyProducerBuilder, runtime: BlockingRuntime) -> Self {
+        Self { builder, runtime }
+    }
+
+    forward_builder_methods! {
+    /// Sets the stream identifier.
+    fn stream(self, stream: Identifier);
+    /// Sets the topic identifier.
+    fn topic(self, topic: Identifier);
+    /// Sets the number of messages to batch before sending them, can be combined with `interval`.
+    fn batch_size(self, batch_size: u32);
+    /// Clears the batch size.
+    fn without_batch_size(self);
+    /// Sets the interval between sending the messages, can be combined with `batch_size`.
+    fn send_interval(self, interval: IggyDuration);
+    /// Clears the interval.
+    fn without_send_interval(self);
+    /// Sets the encryptor for encrypting the messages' payloads.
+    fn encryptor(self, encryptor: Arc<EncryptorKind>);
+    /// Clears the encryptor for encrypting the messages' payloads.
+    fn without_encryptor(self);
+    /// Sets the partitioning strategy for messages.
+    fn partitioning(self, partitioning: Partitioning);
+    /// Clears the partitioning strategy.
+    fn without_partitioning(self);
+    /// Sets the partitioner for messages.
+    fn partitioner(self, partitioner: Arc<dyn Partitioner>);
+    /// Clears the partitioner.
+    fn without_partitioner(self);
+    /// Creates the stream if it does not exist - requires user to have the necessary permissions.
+    fn create_stream_if_not_exists(self);
+    /// Does not create the stream if it does not exist.
+    fn do_not_create_stream_if_not_exists(self);
+    /// Creates the topic if it does not exist - requires user to have the necessary permissions.
+    fn create_topic_if_not_exists(self, partitions_count: u32, replication_factor: Option<u8>, message_expiry: IggyExpiry, max_size: MaxTopicSize);
+    /// Does not create the topic if it does not exist.
+    fn do_not_create_topic_if_not_exists(self);
+    /// Sets the retry policy (maximum number of retries and interval between them) in case of messages sending failure.
+    /// The error can be related either to disconnecting from the server or to the server rejecting the messages.
+    /// Default is 3 retries with 1 second interval between them.
+    fn send_retries(self, retries: Option<u32>, interval: Option<IggyDuration>);
+    }
+
+    /// Builds and initializes the producer.
+    pub fn build(self) -> Result<IggyProducer, IggyError> {
+        let mut producer = self.builder.build();
+        self.runtime.block_on(producer.init())?;
+        Ok(IggyProducer {
+            producer,
+            runtime: self.runtime,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::disconnect_client::{DisconnectClient, DisconnectTarget};
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct DisconnectClientCmd {
+    disconnect_client: DisconnectClient,
+}
+
+impl DisconnectClientCmd {
+    pub fn new(target: DisconnectTarget) -> Self {
+        Self {
+            disconnect_client: DisconnectClient { target },
+        }
+    }
+
+    fn describe_target(&self) -> String {
+        match &self.disconnect_client.target {
+            DisconnectTarget::Client(client_id) => format!("client with ID: {client_id}"),
+            DisconnectTarget::User(user_id) => format!("all clients of user with ID: {user_id}"),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DisconnectClientCmd {
+    fn explain(&self) -> String {
+        format!("disconnect {}", self.describe_target())
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        match &self.disconnect_client.target {
+            DisconnectTarget::Client(client_id) => client.disconnect_client(*client_id).await,
+            DisconnectTarget::User(user_id) => client.disconnect_user_clients(user_id).await,
+        }
+        .with_context(|| format!("Problem disconnecting {}", self.describe_target()))?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Disconnected {}", self.describe_target());
+
+        Ok(())
+    }
+}
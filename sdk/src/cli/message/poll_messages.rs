@@ -74,6 +74,7 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                max_wait: IggyDuration::default(),
             },
             show_headers,
             output_file,
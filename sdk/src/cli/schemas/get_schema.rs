@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetSchemaCmd {
+    schema_id: u32,
+}
+
+impl GetSchemaCmd {
+    pub fn new(schema_id: u32) -> Self {
+        Self { schema_id }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemaCmd {
+    fn explain(&self) -> String {
+        format!("get schema with ID: {}", self.schema_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let schema = client
+            .get_schema(self.schema_id)
+            .await
+            .with_context(|| format!("Problem getting schema with ID: {}", self.schema_id))?;
+
+        let Some(schema) = schema else {
+            event!(target: PRINT_TARGET, Level::INFO, "Schema with ID: {} was not found", self.schema_id);
+            return Ok(());
+        };
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Schema ID", format!("{}", schema.id).as_str()]);
+        table.add_row(vec!["Stream ID", format!("{}", schema.stream_id).as_str()]);
+        table.add_row(vec!["Topic ID", format!("{}", schema.topic_id).as_str()]);
+        table.add_row(vec!["Version", format!("{}", schema.version).as_str()]);
+        table.add_row(vec!["Format", format!("{}", schema.format).as_str()]);
+        table.add_row(vec![
+            "Created",
+            schema
+                .created_at
+                .to_local_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec!["Definition", schema.definition.as_str()]);
+
+        event!(target: PRINT_TARGET, Level::INFO,"{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::schemas::get_schemas::GetSchemas;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{self, Display, Formatter};
+use tracing::{event, Level};
+
+pub enum GetSchemasOutput {
+    Table,
+    List,
+}
+
+impl Display for GetSchemasOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetSchemasOutput::Table => write!(f, "table"),
+            GetSchemasOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetSchemasCmd {
+    get_schemas: GetSchemas,
+    output: GetSchemasOutput,
+}
+
+impl GetSchemasCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, output: GetSchemasOutput) -> Self {
+        Self {
+            get_schemas: GetSchemas {
+                stream_id,
+                topic_id,
+            },
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemasCmd {
+    fn explain(&self) -> String {
+        format!(
+            "list schemas for stream with ID: {} and topic with ID: {} in {} mode",
+            self.get_schemas.stream_id, self.get_schemas.topic_id, self.output
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let topic_schemas = client
+            .get_schemas(&self.get_schemas.stream_id, &self.get_schemas.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting schemas for stream with ID: {} and topic with ID: {}",
+                    self.get_schemas.stream_id, self.get_schemas.topic_id
+                )
+            })?;
+
+        match self.output {
+            GetSchemasOutput::Table => {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Compatibility: {}, validation: {}",
+                    topic_schemas.compatibility,
+                    topic_schemas.validation
+                );
+                let mut table = Table::new();
+                table.set_header(vec!["ID", "Version", "Format", "Created"]);
+                topic_schemas.schemas.iter().for_each(|schema| {
+                    table.add_row(vec![
+                        format!("{}", schema.id),
+                        format!("{}", schema.version),
+                        format!("{}", schema.format),
+                        schema.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetSchemasOutput::List => {
+                topic_schemas.schemas.iter().for_each(|schema| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}",
+                        schema.id,
+                        schema.version,
+                        schema.format,
+                        schema.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaFormat;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct RegisterSchemaCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    format: SchemaFormat,
+    definition_file: PathBuf,
+}
+
+impl RegisterSchemaCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        format: SchemaFormat,
+        definition_file: PathBuf,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            format,
+            definition_file,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RegisterSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "register {} schema from file: {} for topic with ID: {} and stream with ID: {}",
+            self.format,
+            self.definition_file.display(),
+            self.topic_id,
+            self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let definition = tokio::fs::read_to_string(&self.definition_file)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem reading schema definition from file: {}",
+                    self.definition_file.display()
+                )
+            })?;
+
+        let schema = client
+            .register_schema(&self.stream_id, &self.topic_id, self.format, &definition)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem registering {} schema for topic with ID: {} and stream with ID: {}",
+                    self.format, self.topic_id, self.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Schema with ID: {}, version: {} registered for topic with ID: {} and stream with ID: {}",
+            schema.id,
+            schema.version,
+            self.topic_id,
+            self.stream_id
+        );
+
+        Ok(())
+    }
+}
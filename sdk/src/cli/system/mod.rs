@@ -20,6 +20,7 @@ pub mod login;
 pub mod logout;
 pub mod me;
 pub mod ping;
+pub mod reload_config;
 pub mod session;
 pub mod snapshot;
 pub mod stats;
@@ -0,0 +1,76 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::reload_config::ReloadConfig;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct ReloadConfigCmd {
+    _reload_config: ReloadConfig,
+}
+
+impl ReloadConfigCmd {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for ReloadConfigCmd {
+    fn default() -> Self {
+        Self {
+            _reload_config: ReloadConfig {},
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ReloadConfigCmd {
+    fn explain(&self) -> String {
+        "reload config command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let info = client
+            .reload_config()
+            .await
+            .with_context(|| "Problem sending reload_config command".to_owned())?;
+
+        if info.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "Server configuration has not changed");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Setting", "Status"]);
+        for key in &info.applied {
+            table.add_row(vec![key.as_str(), "applied"]);
+        }
+        for key in &info.requires_restart {
+            table.add_row(vec![key.as_str(), "requires restart"]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::binary::servers::{split_server_addresses, SERVER_ADDRESS_SEPARATOR};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::consumer::Consumer;
 use crate::diagnostic::DiagnosticEvent;
@@ -23,13 +24,18 @@ use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload_info::ConfigReloadInfo;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
@@ -83,6 +89,7 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + SchemaClient
     + Sync
     + Send
     + Debug
@@ -120,6 +127,21 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError>;
+    /// Forcibly disconnect a specific client by unique ID (not to be confused with the user).
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError>;
+    /// Forcibly disconnect all the clients of the user by unique ID or username.
+    /// The TCP and QUIC connections are closed, and the HTTP access tokens issued so far are revoked.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn disconnect_user_clients(&self, user_id: &Identifier) -> Result<(), IggyError>;
+    /// Make the server re-read and validate its configuration.
+    /// The settings which can be changed at runtime (e.g. log level, background task intervals or TLS certificates) are applied immediately,
+    /// while the remaining changes are reported as requiring a restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn reload_config(&self) -> Result<ConfigReloadInfo, IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self) -> Result<(), IggyError>;
     async fn heartbeat_interval(&self) -> IggyDuration;
@@ -366,6 +388,23 @@ pub trait MessageClient {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages like `poll_messages`, but when there are no messages to poll,
+    /// the server waits for the new ones to be appended for up to `max_wait` (capped by the server configuration),
+    /// instead of returning an empty batch right away.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_messages_with_wait(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError>;
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -376,6 +415,18 @@ pub trait MessageClient {
         partitioning: &Partitioning,
         messages: &mut [Message],
     ) -> Result<(), IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// returning the partition ID, offsets and timestamps of the appended messages.
+    /// Returns `None` if no message was appended to the topic, e.g. all of them were routed to the rejection topic.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError>;
     /// Force flush of the `unsaved_messages` buffer to disk, optionally fsyncing the data.
     #[allow(clippy::too_many_arguments)]
     async fn flush_unsaved_buffer(
@@ -482,6 +533,54 @@ pub trait ConsumerGroupClient {
     ) -> Result<(), IggyError>;
 }
 
+/// This trait defines the methods to interact with the schema registry module.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the schema by unique ID, regardless of the topic it was registered for.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError>;
+    /// Get all the versions of the schema registered for the given stream and topic by unique IDs or names, and its compatibility rule.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError>;
+    /// Register a new version of the schema for the given stream and topic by unique IDs or names.
+    /// The new version must be compatible with the latest one, according to the compatibility rule of the topic.
+    /// If the latest version has the same format and definition, it's returned instead of registering a new one.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<Schema, IggyError>;
+    /// Update the compatibility rule checked when registering a new version of the schema for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn update_schema_compatibility(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError>;
+    /// Update the validation of the message payloads appended to the given stream and topic by unique IDs or names, against its registered schema.
+    /// The invalid messages are either rejected, or routed to the rejection topic, depending on the validation mode.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn update_schema_validation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError>;
+}
+
 impl FromStr for ConnectionString {
     type Err = IggyError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -527,17 +626,24 @@ impl ConnectionString {
             return Err(IggyError::InvalidConnectionString);
         }
 
-        if !server_address.contains(':') {
+        let server_addresses = split_server_addresses(server_address);
+        if server_addresses.is_empty() {
             return Err(IggyError::InvalidConnectionString);
         }
 
-        let port = server_address.split(':').collect::<Vec<&str>>()[1];
-        if port.is_empty() {
-            return Err(IggyError::InvalidConnectionString);
-        }
+        for server_address in &server_addresses {
+            if !server_address.contains(':') {
+                return Err(IggyError::InvalidConnectionString);
+            }
 
-        if port.parse::<u16>().is_err() {
-            return Err(IggyError::InvalidConnectionString);
+            let port = server_address.split(':').collect::<Vec<&str>>()[1];
+            if port.is_empty() {
+                return Err(IggyError::InvalidConnectionString);
+            }
+
+            if port.parse::<u16>().is_err() {
+                return Err(IggyError::InvalidConnectionString);
+            }
         }
 
         let connection_string_options;
@@ -548,7 +654,7 @@ impl ConnectionString {
         }
 
         Ok(ConnectionString {
-            server_address: server_address.to_owned(),
+            server_address: server_addresses.join(&SERVER_ADDRESS_SEPARATOR.to_string()),
             auto_login: AutoLogin::Enabled(Credentials::UsernamePassword(
                 username.to_owned(),
                 password.to_owned(),
@@ -567,6 +673,7 @@ impl ConnectionString {
         let mut reestablish_after = "5s".to_owned();
         let mut heartbeat_interval = "5s".to_owned();
         let mut nodelay = false;
+        let mut pipelining = false;
 
         for option in options {
             let option_parts = option.split('=').collect::<Vec<&str>>();
@@ -598,6 +705,9 @@ impl ConnectionString {
                 "nodelay" => {
                     nodelay = option_parts[1] == "true";
                 }
+                "pipelining" => {
+                    pipelining = option_parts[1] == "true";
+                }
                 _ => {
                     return Err(IggyError::InvalidConnectionString);
                 }
@@ -625,6 +735,7 @@ impl ConnectionString {
                     .map_err(|_| IggyError::InvalidConnectionString)?,
             },
             nodelay,
+            pipelining,
         })
     }
 }
@@ -637,6 +748,7 @@ struct ConnectionStringOptions {
     reconnection: TcpClientReconnectionConfig,
     heartbeat_interval: IggyDuration,
     nodelay: bool,
+    pipelining: bool,
 }
 
 impl Default for ConnectionStringOptions {
@@ -648,6 +760,7 @@ impl Default for ConnectionStringOptions {
             reconnection: Default::default(),
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             nodelay: false,
+            pipelining: false,
         }
     }
 }
@@ -663,6 +776,7 @@ impl From<ConnectionString> for TcpClientConfig {
             reconnection: connection_string.options.reconnection,
             heartbeat_interval: connection_string.options.heartbeat_interval,
             nodelay: connection_string.options.nodelay,
+            pipelining: connection_string.options.pipelining,
         }
     }
 }
@@ -701,6 +815,28 @@ mod tests {
         assert!(connection_string.is_err());
     }
 
+    #[test]
+    fn connection_string_with_invalid_port_in_server_list_should_fail() {
+        let value = format!("{CONNECTION_STRING_PREFIX}user:secret@localhost:1234,localhost");
+        let connection_string = ConnectionString::new(&value);
+        assert!(connection_string.is_err());
+    }
+
+    #[test]
+    fn connection_string_with_multiple_servers_should_be_parsed_correctly() {
+        let value = format!(
+            "{CONNECTION_STRING_PREFIX}user:secret@host1:1234, host2:1234,host3:5678?reconnection_retries=3"
+        );
+        let connection_string = ConnectionString::new(&value);
+        assert!(connection_string.is_ok());
+        let connection_string = connection_string.unwrap();
+        assert_eq!(
+            connection_string.server_address,
+            "host1:1234,host2:1234,host3:5678"
+        );
+        assert_eq!(connection_string.options.reconnection.max_retries, Some(3));
+    }
+
     #[test]
     fn connection_string_without_options_should_be_parsed_correctly() {
         let username = "user1";
@@ -728,6 +864,7 @@ mod tests {
             IggyDuration::from_str("1s").unwrap()
         );
         assert!(!connection_string.options.nodelay);
+        assert!(!connection_string.options.pipelining);
     }
 
     #[test]
@@ -743,7 +880,8 @@ mod tests {
         let reestablish_after = "10s";
         let heartbeat_interval = "3s";
         let nodelay = true;
-        let value = format!("{CONNECTION_STRING_PREFIX}{username}:{password}@{server_address}?tls={tls}&tls_domain={tls_domain}&tls_ca_file={tls_ca_file}&reconnection_retries={reconnection_retries}&reconnection_interval={reconnection_interval}&reestablish_after={reestablish_after}&heartbeat_interval={heartbeat_interval}&nodelay={nodelay}");
+        let pipelining = true;
+        let value = format!("{CONNECTION_STRING_PREFIX}{username}:{password}@{server_address}?tls={tls}&tls_domain={tls_domain}&tls_ca_file={tls_ca_file}&reconnection_retries={reconnection_retries}&reconnection_interval={reconnection_interval}&reestablish_after={reestablish_after}&heartbeat_interval={heartbeat_interval}&nodelay={nodelay}&pipelining={pipelining}");
         let connection_string = ConnectionString::new(&value);
         assert!(connection_string.is_ok());
         let connection_string = connection_string.unwrap();
@@ -780,5 +918,6 @@ mod tests {
             IggyDuration::from_str(heartbeat_interval).unwrap()
         );
         assert_eq!(connection_string.options.nodelay, nodelay);
+        assert_eq!(connection_string.options.pipelining, pipelining);
     }
 }
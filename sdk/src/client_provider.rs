@@ -131,6 +131,7 @@ impl ClientProviderConfig {
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
                     nodelay: args.tcp_nodelay,
+                    pipelining: false,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
                     reconnection: TcpClientReconnectionConfig {
@@ -0,0 +1,496 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::clients::producer::IggyProducer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use crate::models::appended_messages::AppendedMessages;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::{mpsc, oneshot, OwnedSemaphorePermit, Semaphore, TryAcquireError};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep_until, Instant};
+use tracing::{error, info, trace};
+
+type DeliveryResult = Result<DeliveryReport, IggyError>;
+
+/// The configuration of the background producer, buffering the messages in memory and sending them in batches.
+#[derive(Debug, Clone)]
+pub struct BackgroundConfig {
+    /// The maximum size of the buffered messages which weren't acknowledged by the server yet.
+    /// When it's reached, `send` waits until there's enough space in the buffer, while `try_send` fails.
+    pub max_buffer_size: IggyByteSize,
+    /// The maximum number of messages in a single batch.
+    pub max_batch_messages: usize,
+    /// The maximum size of a single batch.
+    pub max_batch_size: IggyByteSize,
+    /// The maximum time the message waits in the buffer for its batch to fill up.
+    pub linger: IggyDuration,
+}
+
+impl Default for BackgroundConfig {
+    fn default() -> Self {
+        Self {
+            max_buffer_size: IggyByteSize::from(32 * 1024 * 1024),
+            max_batch_messages: 1000,
+            max_batch_size: IggyByteSize::from(1024 * 1024),
+            linger: IggyDuration::from(5000),
+        }
+    }
+}
+
+/// The result of the successful delivery of the message, which was acknowledged by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReport {
+    /// The ID of the message, or 0 if it was generated by the server.
+    pub message_id: u128,
+    /// The partitioning the message was sent with.
+    pub partitioning: Arc<Partitioning>,
+    /// The ID of the partition the message was appended to, if it's known.
+    pub partition_id: Option<u32>,
+    /// The offset assigned to the message, if it was reported by the server.
+    /// It's unknown if the server didn't append all the messages of the batch, e.g. the duplicated ones.
+    pub offset: Option<u64>,
+}
+
+/// The future resolved once the message is acknowledged by the server, or its delivery fails.
+#[derive(Debug)]
+pub struct DeliveryFuture {
+    receiver: oneshot::Receiver<DeliveryResult>,
+}
+
+impl Future for DeliveryFuture {
+    type Output = DeliveryResult;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(IggyError::ProducerClosed)))
+    }
+}
+
+/// The producer enqueuing the messages into the bounded in-memory buffer, from which the background task
+/// builds the batches per partitioning, sending each one when it's full or its linger time passes.
+/// Every enqueued message gets the `DeliveryFuture` resolved with its delivery report once the server acknowledges it.
+/// The batches are sent one by one, so the order of the messages with the same partitioning is preserved.
+pub struct IggyBackgroundProducer {
+    producer: Arc<IggyProducer>,
+    config: BackgroundConfig,
+    buffer: Arc<Semaphore>,
+    sender: mpsc::UnboundedSender<BackgroundCommand>,
+    task: JoinHandle<()>,
+}
+
+impl IggyProducer {
+    /// Turns the producer into the background one, sending the buffered messages in batches.
+    ///
+    /// Note: The producer should be initialized before.
+    pub fn background(self, config: BackgroundConfig) -> IggyBackgroundProducer {
+        IggyBackgroundProducer::new(self, config)
+    }
+}
+
+impl IggyBackgroundProducer {
+    pub fn new(producer: IggyProducer, config: BackgroundConfig) -> Self {
+        let producer = Arc::new(producer);
+        let permits = get_buffer_permits(&config) as usize;
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let worker = BatchingWorker {
+            producer: producer.clone(),
+            config: config.clone(),
+            batches: HashMap::new(),
+        };
+        let task = tokio::spawn(worker.run(receiver));
+        Self {
+            producer,
+            config,
+            buffer: Arc::new(Semaphore::new(permits)),
+            sender,
+            task,
+        }
+    }
+
+    pub fn stream(&self) -> &Identifier {
+        self.producer.stream()
+    }
+
+    pub fn topic(&self) -> &Identifier {
+        self.producer.topic()
+    }
+
+    pub fn config(&self) -> &BackgroundConfig {
+        &self.config
+    }
+
+    /// Returns the underlying producer, which can be used to send the messages directly.
+    pub fn producer(&self) -> &IggyProducer {
+        &self.producer
+    }
+
+    /// Enqueues the message, waiting until there's enough space in the buffer.
+    pub async fn send(&self, message: Message) -> Result<DeliveryFuture, IggyError> {
+        self.send_with_partitioning(message, None).await
+    }
+
+    /// Enqueues the message with the optional partitioning, waiting until there's enough space in the buffer.
+    pub async fn send_with_partitioning(
+        &self,
+        message: Message,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<DeliveryFuture, IggyError> {
+        let permits = self.get_permits(&message);
+        let permit = self
+            .buffer
+            .clone()
+            .acquire_many_owned(permits)
+            .await
+            .map_err(|_| IggyError::ProducerClosed)?;
+        self.enqueue(message, partitioning, permit)
+    }
+
+    /// Enqueues the message, or fails immediately if there's not enough space in the buffer.
+    pub fn try_send(&self, message: Message) -> Result<DeliveryFuture, IggyError> {
+        self.try_send_with_partitioning(message, None)
+    }
+
+    /// Enqueues the message with the optional partitioning, or fails immediately if there's not enough space in the buffer.
+    pub fn try_send_with_partitioning(
+        &self,
+        message: Message,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<DeliveryFuture, IggyError> {
+        let permits = self.get_permits(&message);
+        let permit = match self.buffer.clone().try_acquire_many_owned(permits) {
+            Ok(permit) => permit,
+            Err(TryAcquireError::NoPermits) => return Err(IggyError::ProducerBufferFull),
+            Err(TryAcquireError::Closed) => return Err(IggyError::ProducerClosed),
+        };
+        self.enqueue(message, partitioning, permit)
+    }
+
+    /// Sends all the buffered messages, waiting until they are acknowledged by the server.
+    pub async fn flush(&self) -> Result<(), IggyError> {
+        let (sender, receiver) = oneshot::channel();
+        self.sender
+            .send(BackgroundCommand::Flush(sender))
+            .map_err(|_| IggyError::ProducerClosed)?;
+        receiver.await.map_err(|_| IggyError::ProducerClosed)
+    }
+
+    /// Stops accepting the new messages, sends the buffered ones and waits for the background task to finish.
+    pub async fn shutdown(self) -> Result<(), IggyError> {
+        self.buffer.close();
+        drop(self.sender);
+        self.task.await.map_err(|error| {
+            error!("Background producer task has failed: {error}");
+            IggyError::ProducerClosed
+        })
+    }
+
+    /// Returns the number of the buffer permits for the message, which can't exceed the whole buffer.
+    fn get_permits(&self, message: &Message) -> u32 {
+        let buffer_permits = get_buffer_permits(&self.config);
+        message
+            .get_size_bytes()
+            .as_bytes_u64()
+            .clamp(1, buffer_permits as u64) as u32
+    }
+
+    fn enqueue(
+        &self,
+        message: Message,
+        partitioning: Option<Arc<Partitioning>>,
+        permit: OwnedSemaphorePermit,
+    ) -> Result<DeliveryFuture, IggyError> {
+        let (delivery, receiver) = oneshot::channel();
+        let size = message.get_size_bytes().as_bytes_u64();
+        self.sender
+            .send(BackgroundCommand::Send(BufferedMessage {
+                message,
+                size,
+                partitioning,
+                delivery,
+                permit,
+            }))
+            .map_err(|_| IggyError::ProducerClosed)?;
+        Ok(DeliveryFuture { receiver })
+    }
+}
+
+enum BackgroundCommand {
+    Send(BufferedMessage),
+    Flush(oneshot::Sender<()>),
+}
+
+struct BufferedMessage {
+    message: Message,
+    size: u64,
+    partitioning: Option<Arc<Partitioning>>,
+    delivery: oneshot::Sender<DeliveryResult>,
+    // Released once the message is delivered, to free the space in the buffer.
+    permit: OwnedSemaphorePermit,
+}
+
+struct Batch {
+    partitioning: Arc<Partitioning>,
+    messages: Vec<BufferedMessage>,
+    size: u64,
+    deadline: Instant,
+}
+
+struct BatchingWorker {
+    producer: Arc<IggyProducer>,
+    config: BackgroundConfig,
+    batches: HashMap<Arc<Partitioning>, Batch>,
+}
+
+impl BatchingWorker {
+    async fn run(mut self, mut receiver: mpsc::UnboundedReceiver<BackgroundCommand>) {
+        trace!(
+            "Started background producer for stream: {} and topic: {}.",
+            self.producer.stream(),
+            self.producer.topic()
+        );
+        loop {
+            let deadline = self.batches.values().map(|batch| batch.deadline).min();
+            tokio::select! {
+                command = receiver.recv() => match command {
+                    Some(BackgroundCommand::Send(message)) => self.buffer(message).await,
+                    Some(BackgroundCommand::Flush(done)) => {
+                        self.send_all().await;
+                        let _ = done.send(());
+                    }
+                    None => {
+                        self.send_all().await;
+                        break;
+                    }
+                },
+                _ = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                    self.send_expired().await;
+                }
+            }
+        }
+        info!(
+            "Stopped background producer for stream: {} and topic: {}.",
+            self.producer.stream(),
+            self.producer.topic()
+        );
+    }
+
+    async fn buffer(&mut self, message: BufferedMessage) {
+        let partitioning = match self.producer.get_partitioning(
+            self.producer.stream(),
+            self.producer.topic(),
+            std::slice::from_ref(&message.message),
+            message.partitioning.clone(),
+        ) {
+            Ok(partitioning) => partitioning,
+            Err(error) => {
+                let _ = message.delivery.send(Err(error));
+                return;
+            }
+        };
+
+        let max_batch_size = self.config.max_batch_size.as_bytes_u64();
+        let is_overflowing = self.batches.get(&partitioning).is_some_and(|batch| {
+            !batch.messages.is_empty() && batch.size + message.size > max_batch_size
+        });
+        if is_overflowing {
+            if let Some(batch) = self.batches.remove(&partitioning) {
+                self.send_batch(batch).await;
+            }
+        }
+
+        let linger = self.config.linger.get_duration();
+        let batch = self
+            .batches
+            .entry(partitioning.clone())
+            .or_insert_with(|| Batch {
+                partitioning,
+                messages: Vec::new(),
+                size: 0,
+                deadline: Instant::now() + linger,
+            });
+        batch.size += message.size;
+        batch.messages.push(message);
+        if batch.messages.len() < self.config.max_batch_messages && batch.size < max_batch_size {
+            return;
+        }
+
+        let partitioning = batch.partitioning.clone();
+        if let Some(batch) = self.batches.remove(&partitioning) {
+            self.send_batch(batch).await;
+        }
+    }
+
+    async fn send_expired(&mut self) {
+        let now = Instant::now();
+        let mut expired = self
+            .batches
+            .values()
+            .filter(|batch| batch.deadline <= now)
+            .map(|batch| (batch.deadline, batch.partitioning.clone()))
+            .collect::<Vec<_>>();
+        expired.sort_by_key(|(deadline, _)| *deadline);
+        for (_, partitioning) in expired {
+            if let Some(batch) = self.batches.remove(&partitioning) {
+                self.send_batch(batch).await;
+            }
+        }
+    }
+
+    async fn send_all(&mut self) {
+        let mut batches = self
+            .batches
+            .drain()
+            .map(|(_, batch)| batch)
+            .collect::<Vec<_>>();
+        batches.sort_by_key(|batch| batch.deadline);
+        for batch in batches {
+            self.send_batch(batch).await;
+        }
+    }
+
+    async fn send_batch(&self, batch: Batch) {
+        let (mut messages, deliveries): (Vec<_>, Vec<_>) = batch
+            .messages
+            .into_iter()
+            .map(|buffered| {
+                let message_id = buffered.message.id;
+                (
+                    buffered.message,
+                    (message_id, buffered.delivery, buffered.permit),
+                )
+            })
+            .unzip();
+        trace!(
+            "Sending batch of {} messages with partitioning: {:?}...",
+            messages.len(),
+            batch.partitioning
+        );
+        let messages_count = messages.len() as u64;
+        let result = self.send_messages(&batch.partitioning, &mut messages).await;
+        for (index, (message_id, delivery, _permit)) in deliveries.into_iter().enumerate() {
+            let report = match &result {
+                Ok(appended_messages) => Ok(DeliveryReport {
+                    message_id,
+                    partitioning: batch.partitioning.clone(),
+                    partition_id: appended_messages
+                        .map(|appended_messages| appended_messages.partition_id)
+                        .or_else(|| get_partition_id(&batch.partitioning)),
+                    offset: get_offset(appended_messages, messages_count, index as u64),
+                }),
+                Err(error) => Err(IggyError::from_code_and_details(
+                    error.as_code(),
+                    &error.as_details().unwrap_or_default(),
+                )),
+            };
+            let _ = delivery.send(report);
+        }
+    }
+
+    async fn send_messages(
+        &self,
+        partitioning: &Arc<Partitioning>,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        self.producer.encrypt_messages(messages)?;
+        self.producer
+            .try_send_messages(
+                self.producer.stream(),
+                self.producer.topic(),
+                partitioning,
+                messages,
+                true,
+            )
+            .await
+            .inspect_err(|error| {
+                error!(
+                    "Failed to send batch of {} messages in background to stream: {}, topic: {}. {error}",
+                    messages.len(),
+                    self.producer.stream(),
+                    self.producer.topic()
+                )
+            })
+    }
+}
+
+fn get_buffer_permits(config: &BackgroundConfig) -> u32 {
+    config
+        .max_buffer_size
+        .as_bytes_u64()
+        .clamp(1, Semaphore::MAX_PERMITS.min(u32::MAX as usize) as u64) as u32
+}
+
+/// Returns the offset of the message at the index in the batch, which is known only if all the messages were appended,
+/// as the server might skip some of them, e.g. the duplicated ones or the ones routed to the rejection topic.
+fn get_offset(
+    appended_messages: &Option<AppendedMessages>,
+    messages_count: u64,
+    index: u64,
+) -> Option<u64> {
+    appended_messages
+        .filter(|appended_messages| appended_messages.count() == messages_count)
+        .map(|appended_messages| appended_messages.base_offset + index)
+}
+
+fn get_partition_id(partitioning: &Partitioning) -> Option<u32> {
+    if partitioning.kind != PartitioningKind::PartitionId {
+        return None;
+    }
+
+    partitioning
+        .value
+        .get(..4)
+        .and_then(|value| value.try_into().ok())
+        .map(u32::from_le_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_id_should_be_known_only_for_partition_id_partitioning() {
+        assert_eq!(get_partition_id(&Partitioning::partition_id(3)), Some(3));
+        assert_eq!(get_partition_id(&Partitioning::balanced()), None);
+        assert_eq!(get_partition_id(&Partitioning::messages_key_u32(3)), None);
+    }
+
+    #[test]
+    fn buffer_permits_should_be_capped() {
+        let config = BackgroundConfig {
+            max_buffer_size: IggyByteSize::from(u64::MAX),
+            ..Default::default()
+        };
+        assert!(get_buffer_permits(&config) as usize <= Semaphore::MAX_PERMITS);
+
+        let config = BackgroundConfig {
+            max_buffer_size: IggyByteSize::from(0),
+            ..Default::default()
+        };
+        assert_eq!(get_buffer_permits(&config), 1);
+    }
+}
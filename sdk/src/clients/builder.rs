@@ -26,8 +26,11 @@ use crate::quic::client::QuicClient;
 use crate::quic::config::QuicClientConfigBuilder;
 use crate::tcp::client::TcpClient;
 use crate::tcp::config::TcpClientConfigBuilder;
+use crate::tcp::pool::TcpConnectionPool;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
+use crate::websocket::client::WebSocketClient;
+use crate::websocket::config::WebSocketClientConfigBuilder;
 use std::sync::Arc;
 use tracing::error;
 
@@ -78,6 +81,7 @@ impl IggyClientBuilder {
     pub fn with_tcp(self) -> TcpClientBuilder {
         TcpClientBuilder {
             config: TcpClientConfigBuilder::default(),
+            pool_size: None,
             parent_builder: self,
         }
     }
@@ -102,10 +106,20 @@ impl IggyClientBuilder {
         }
     }
 
+    /// This method provides fluent API for the WebSocket client configuration.
+    /// It returns the `WebSocketClientBuilder` instance, which allows to configure the WebSocket client with custom settings or using defaults.
+    /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    pub fn with_websocket(self) -> WebSocketClientBuilder {
+        WebSocketClientBuilder {
+            config: WebSocketClientConfigBuilder::default(),
+            parent_builder: self,
+        }
+    }
+
     /// Build the `IggyClient` instance.
     /// This method returns an error if the client is not provided.
     /// If the client is provided, it creates the `IggyClient` instance with the provided configuration.
-    /// To provide the client configuration, use the `with_tcp`, `with_quic` or `with_http` methods.
+    /// To provide the client configuration, use the `with_tcp`, `with_quic`, `with_http` or `with_websocket` methods.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let Some(client) = self.client else {
             error!("Client is not provided");
@@ -119,6 +133,7 @@ impl IggyClientBuilder {
 #[derive(Debug, Default)]
 pub struct TcpClientBuilder {
     config: TcpClientConfigBuilder,
+    pool_size: Option<usize>,
     parent_builder: IggyClientBuilder,
 }
 
@@ -129,6 +144,12 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Sets the list of the bootstrap servers for the TCP client, used for failover.
+    pub fn with_server_addresses(mut self, server_addresses: Vec<String>) -> Self {
+        self.config = self.config.with_server_addresses(server_addresses);
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config = self.config.with_auto_sign_in(auto_sign_in);
@@ -175,10 +196,26 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Enables pipelining of the requests over the TCP connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config = self.config.with_pipelining();
+        self
+    }
+
+    /// Uses the pool of the given number of TCP connections, balancing the requests between them.
+    pub fn with_connection_pool(mut self, size: usize) -> Self {
+        self.pool_size = Some(size);
+        self
+    }
+
     /// Builds the parent `IggyClient` with TCP configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
-        let client = TcpClient::create(Arc::new(self.config.build()))?;
-        let client = self.parent_builder.with_client(Box::new(client)).build()?;
+        let config = Arc::new(self.config.build());
+        let client: Box<dyn Client> = match self.pool_size {
+            Some(size) => Box::new(TcpConnectionPool::create(config, size)?),
+            None => Box::new(TcpClient::create(config)?),
+        };
+        let client = self.parent_builder.with_client(client).build()?;
         Ok(client)
     }
 }
@@ -196,6 +233,12 @@ impl QuicClientBuilder {
         self
     }
 
+    /// Sets the list of the bootstrap servers for the QUIC client, used for failover.
+    pub fn with_server_addresses(mut self, server_addresses: Vec<String>) -> Self {
+        self.config = self.config.with_server_addresses(server_addresses);
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config = self.config.with_auto_sign_in(auto_sign_in);
@@ -258,3 +301,58 @@ impl HttpClientBuilder {
         Ok(client)
     }
 }
+
+#[derive(Debug, Default)]
+pub struct WebSocketClientBuilder {
+    config: WebSocketClientConfigBuilder,
+    parent_builder: IggyClientBuilder,
+}
+
+impl WebSocketClientBuilder {
+    /// Sets the server address for the WebSocket client.
+    pub fn with_server_address(mut self, server_address: String) -> Self {
+        self.config = self.config.with_server_address(server_address);
+        self
+    }
+
+    /// Sets the list of the bootstrap servers for the WebSocket client, used for failover.
+    pub fn with_server_addresses(mut self, server_addresses: Vec<String>) -> Self {
+        self.config = self.config.with_server_addresses(server_addresses);
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config = self.config.with_auto_sign_in(auto_sign_in);
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_max_retries(reconnection_retries);
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: IggyDuration) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_interval(reconnection_interval);
+        self
+    }
+
+    /// Sets the maximum size of a single WebSocket message in bytes.
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.config = self.config.with_max_message_size(max_message_size);
+        self
+    }
+
+    /// Builds the parent `IggyClient` with WebSocket configuration.
+    pub fn build(self) -> Result<IggyClient, IggyError> {
+        let client = WebSocketClient::create(Arc::new(self.config.build()))?;
+        let client = self.parent_builder.with_client(Box::new(client)).build()?;
+        Ok(client)
+    }
+}
@@ -18,7 +18,8 @@
 
 use crate::client::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, SegmentClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, SchemaClient, SegmentClient, StreamClient, SystemClient,
+    TopicClient, UserClient,
 };
 use crate::clients::builder::IggyClientBuilder;
 use crate::clients::consumer::IggyConsumerBuilder;
@@ -32,13 +33,18 @@ use crate::locking::IggySharedMut;
 use crate::locking::IggySharedMutFn;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload_info::ConfigReloadInfo;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
@@ -187,6 +193,27 @@ impl IggyClient {
             None,
         ))
     }
+
+    fn encrypt_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        if let Some(encryptor) = &self.encryptor {
+            for message in messages {
+                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
+                message.length = message.payload.len() as u32;
+            }
+        }
+        Ok(())
+    }
+
+    fn decrypt_messages(&self, polled_messages: &mut PolledMessages) -> Result<(), IggyError> {
+        if let Some(encryptor) = &self.encryptor {
+            for message in &mut polled_messages.messages {
+                let payload = encryptor.decrypt(&message.payload)?;
+                message.payload = Bytes::from(payload);
+                message.length = IggyByteSize::from(message.payload.len() as u64);
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -366,6 +393,22 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_clients().await
     }
 
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        self.client.read().await.disconnect_client(client_id).await
+    }
+
+    async fn disconnect_user_clients(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .disconnect_user_clients(user_id)
+            .await
+    }
+
+    async fn reload_config(&self) -> Result<ConfigReloadInfo, IggyError> {
+        self.client.read().await.reload_config().await
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.client.read().await.ping().await
     }
@@ -597,15 +640,41 @@ impl MessageClient for IggyClient {
                 auto_commit,
             )
             .await?;
+        self.decrypt_messages(&mut polled_messages)?;
+        Ok(polled_messages)
+    }
 
-        if let Some(ref encryptor) = self.encryptor {
-            for message in &mut polled_messages.messages {
-                let payload = encryptor.decrypt(&message.payload)?;
-                message.payload = Bytes::from(payload);
-                message.length = IggyByteSize::from(message.payload.len() as u64);
-            }
+    async fn poll_messages_with_wait(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
         }
 
+        let mut polled_messages = self
+            .client
+            .read()
+            .await
+            .poll_messages_with_wait(
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit,
+                max_wait,
+            )
+            .await?;
+        self.decrypt_messages(&mut polled_messages)?;
         Ok(polled_messages)
     }
 
@@ -620,17 +689,30 @@ impl MessageClient for IggyClient {
             return Err(IggyError::InvalidMessagesCount);
         }
 
-        if let Some(encryptor) = &self.encryptor {
-            for message in &mut *messages {
-                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
-                message.length = message.payload.len() as u32;
-            }
+        self.encrypt_messages(messages)?;
+        self.client
+            .read()
+            .await
+            .send_messages(stream_id, topic_id, partitioning, messages)
+            .await
+    }
+
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        if messages.is_empty() {
+            return Err(IggyError::InvalidMessagesCount);
         }
 
+        self.encrypt_messages(messages)?;
         self.client
             .read()
             .await
-            .send_messages(stream_id, topic_id, partitioning, messages)
+            .send_messages_with_offsets(stream_id, topic_id, partitioning, messages)
             .await
     }
 
@@ -776,6 +858,65 @@ impl ConsumerGroupClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        self.client.read().await.get_schema(schema_id).await
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_schemas(stream_id, topic_id)
+            .await
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        self.client
+            .read()
+            .await
+            .register_schema(stream_id, topic_id, format, definition)
+            .await
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_schema_compatibility(stream_id, topic_id, compatibility)
+            .await
+    }
+
+    async fn update_schema_validation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_schema_validation(stream_id, topic_id, validation)
+            .await
+    }
+}
+
 #[async_trait]
 impl AsyncDrop for IggyClient {
     async fn async_drop(&mut self) {
@@ -30,13 +30,14 @@ use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
 use bytes::Bytes;
 use dashmap::DashMap;
+use futures::task::AtomicWaker;
 use futures::Stream;
 use futures_util::{FutureExt, StreamExt};
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 use std::time::Duration;
 use tokio::time;
@@ -67,6 +68,10 @@ pub enum AutoCommit {
     ///
     /// **This will only work with the `IggyConsumerMessageExt` trait when using `consume_messages()`.**
     After(AutoCommitAfter),
+    /// The auto-commit is enabled and the offset is stored on the server once the message, and all the earlier messages
+    /// received from the same partition, are acknowledged via `ReceivedMessage::ack()`.
+    /// The messages can be processed concurrently and acknowledged in any order, without skipping the unprocessed ones.
+    Acknowledged,
 }
 
 /// The auto-commit mode for storing the offset on the server.
@@ -111,6 +116,7 @@ pub struct IggyConsumer {
     partition_id: Option<u32>,
     polling_strategy: PollingStrategy,
     poll_interval_micros: u64,
+    max_wait: Option<IggyDuration>,
     batch_size: u32,
     auto_commit: AutoCommit,
     auto_commit_after_polling: bool,
@@ -132,6 +138,8 @@ pub struct IggyConsumer {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    max_in_flight: Option<u32>,
+    ack_tracker: Option<Arc<AckTracker>>,
 }
 
 impl IggyConsumer {
@@ -144,6 +152,7 @@ impl IggyConsumer {
         topic_id: Identifier,
         partition_id: Option<u32>,
         polling_interval: Option<IggyDuration>,
+        max_wait: Option<IggyDuration>,
         polling_strategy: PollingStrategy,
         batch_size: u32,
         auto_commit: AutoCommit,
@@ -154,6 +163,7 @@ impl IggyConsumer {
         init_retries: Option<u32>,
         init_retry_interval: IggyDuration,
         allow_replay: bool,
+        max_in_flight: Option<u32>,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
         Self {
@@ -169,6 +179,7 @@ impl IggyConsumer {
             partition_id,
             polling_strategy,
             poll_interval_micros: polling_interval.map_or(0, |interval| interval.as_micros()),
+            max_wait,
             last_stored_offsets: Arc::new(DashMap::new()),
             last_consumed_offsets: Arc::new(DashMap::new()),
             current_offsets: Arc::new(DashMap::new()),
@@ -208,6 +219,8 @@ impl IggyConsumer {
             init_retries,
             init_retry_interval,
             allow_replay,
+            max_in_flight,
+            ack_tracker: None,
         }
     }
 
@@ -272,6 +285,55 @@ impl IggyConsumer {
             .await
     }
 
+    /// Returns the future storing the consumer offset, which does not borrow the consumer,
+    /// so that it can be run while the consumer is polling the messages in the background.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn store_offset_future(
+        &self,
+        offset: u64,
+        partition_id: Option<u32>,
+    ) -> impl Future<Output = Result<(), IggyError>> + 'static {
+        let partition_id = partition_id.unwrap_or_else(|| self.current_partition_id.load(ORDERING));
+        let client = self.client.clone();
+        let consumer = self.consumer.clone();
+        let stream_id = self.stream_id.clone();
+        let topic_id = self.topic_id.clone();
+        let last_stored_offsets = self.last_stored_offsets.clone();
+        let allow_replay = self.allow_replay;
+        async move {
+            Self::store_consumer_offset(
+                &client,
+                &consumer,
+                &stream_id,
+                &topic_id,
+                partition_id,
+                offset,
+                &last_stored_offsets,
+                allow_replay,
+            )
+            .await
+        }
+    }
+
+    /// Returns the future deleting the consumer offset, which does not borrow the consumer.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn delete_offset_future(
+        &self,
+        partition_id: Option<u32>,
+    ) -> impl Future<Output = Result<(), IggyError>> + 'static {
+        let client = self.client.clone();
+        let consumer = self.consumer.clone();
+        let stream_id = self.stream_id.clone();
+        let topic_id = self.topic_id.clone();
+        async move {
+            client
+                .read()
+                .await
+                .delete_consumer_offset(&consumer, &stream_id, &topic_id, partition_id)
+                .await
+        }
+    }
+
     /// Initializes the consumer by subscribing to diagnostic events, initializing the consumer group if needed, storing the offsets in the background etc.
     ///
     /// Note: This method must be called before polling messages.
@@ -362,6 +424,12 @@ impl IggyConsumer {
         let topic_id = self.topic_id.clone();
         let last_stored_offsets = self.last_stored_offsets.clone();
         let (store_offset_sender, store_offset_receiver) = flume::unbounded();
+        if self.auto_commit == AutoCommit::Acknowledged {
+            self.ack_tracker = Some(Arc::new(AckTracker::new(
+                store_offset_sender.clone(),
+                self.max_in_flight,
+            )));
+        }
         self.store_offset_sender = store_offset_sender;
 
         tokio::spawn(async move {
@@ -460,6 +528,20 @@ impl IggyConsumer {
         });
     }
 
+    fn received_message(
+        &self,
+        message: PolledMessage,
+        current_offset: u64,
+        partition_id: u32,
+    ) -> ReceivedMessage {
+        let mut received_message = ReceivedMessage::new(message, current_offset, partition_id);
+        if let Some(ack_tracker) = &self.ack_tracker {
+            received_message.ack_handle =
+                Some(ack_tracker.deliver(partition_id, received_message.message.offset));
+        }
+        received_message
+    }
+
     pub(crate) fn send_store_offset(&self, partition_id: u32, offset: u64) {
         if let Err(error) = self.store_offset_sender.send((partition_id, offset)) {
             error!("Failed to send offset to store: {error}, please verify if `init()` on IggyConsumer object has been called.");
@@ -595,8 +677,12 @@ impl IggyConsumer {
         let client = self.client.clone();
         let count = self.batch_size;
         let auto_commit_after_polling = self.auto_commit_after_polling;
-        let auto_commit_enabled = self.auto_commit != AutoCommit::Disabled;
+        let auto_commit_enabled = !matches!(
+            self.auto_commit,
+            AutoCommit::Disabled | AutoCommit::Acknowledged
+        );
         let interval = self.poll_interval_micros;
+        let max_wait = self.max_wait;
         let last_polled_at = self.last_polled_at.clone();
         let can_poll = self.can_poll.clone();
         let retry_interval = self.reconnection_retry_interval;
@@ -616,19 +702,39 @@ impl IggyConsumer {
 
             trace!("Sending poll messages request");
             last_polled_at.store(IggyTimestamp::now().into(), ORDERING);
-            let polled_messages = client
-                .read()
-                .await
-                .poll_messages(
-                    &stream_id,
-                    &topic_id,
-                    partition_id,
-                    &consumer,
-                    &polling_strategy,
-                    count,
-                    auto_commit_after_polling,
-                )
-                .await;
+            let polled_messages = match max_wait {
+                Some(max_wait) => {
+                    client
+                        .read()
+                        .await
+                        .poll_messages_with_wait(
+                            &stream_id,
+                            &topic_id,
+                            partition_id,
+                            &consumer,
+                            &polling_strategy,
+                            count,
+                            auto_commit_after_polling,
+                            max_wait,
+                        )
+                        .await
+                }
+                None => {
+                    client
+                        .read()
+                        .await
+                        .poll_messages(
+                            &stream_id,
+                            &topic_id,
+                            partition_id,
+                            &consumer,
+                            &polling_strategy,
+                            count,
+                            auto_commit_after_polling,
+                        )
+                        .await
+                }
+            };
 
             if let Ok(mut polled_messages) = polled_messages {
                 if polled_messages.messages.is_empty() {
@@ -805,10 +911,13 @@ impl IggyConsumer {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct ReceivedMessage {
     pub message: PolledMessage,
     pub current_offset: u64,
     pub partition_id: u32,
+    /// The handle acknowledging the message, available only for `AutoCommit::Acknowledged`.
+    pub ack_handle: Option<AckHandle>,
 }
 
 impl ReceivedMessage {
@@ -817,6 +926,156 @@ impl ReceivedMessage {
             message,
             current_offset,
             partition_id,
+            ack_handle: None,
+        }
+    }
+
+    /// Acknowledges the processing of the message, if the consumer uses `AutoCommit::Acknowledged`.
+    pub fn ack(&self) {
+        if let Some(ack_handle) = &self.ack_handle {
+            ack_handle.ack();
+        }
+    }
+}
+
+/// The handle acknowledging the processing of a single received message.
+///
+/// The offset of the partition is committed only when all the earlier messages received from that partition
+/// have been acknowledged as well. The message which is never acknowledged holds back the offset commits
+/// for its partition, so it will be delivered again after restarting the consumer.
+#[derive(Debug, Clone)]
+pub struct AckHandle {
+    partition_id: u32,
+    offset: u64,
+    tracker: Arc<AckTracker>,
+}
+
+impl AckHandle {
+    /// Returns the partition ID of the message.
+    pub fn partition_id(&self) -> u32 {
+        self.partition_id
+    }
+
+    /// Returns the offset of the message.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Acknowledges the message, which might commit the offset of the partition.
+    /// Acknowledging the same message more than once has no effect.
+    pub fn ack(&self) {
+        self.tracker.ack(self.partition_id, self.offset);
+    }
+}
+
+#[derive(Debug, Default)]
+struct PartitionAcks {
+    pending_offsets: BTreeSet<u64>,
+    last_delivered_offset: Option<u64>,
+    last_committed_offset: Option<u64>,
+}
+
+impl PartitionAcks {
+    fn committable_offset(&self) -> Option<u64> {
+        match self.pending_offsets.first() {
+            Some(offset) => offset.checked_sub(1),
+            None => self.last_delivered_offset,
+        }
+    }
+}
+
+/// Tracks the delivered and acknowledged offsets of each partition, and commits the highest offset
+/// below which all the delivered messages have been acknowledged.
+/// When `max_in_flight` is set, the consumer stops yielding the messages once that many are pending acknowledgement.
+#[derive(Debug)]
+pub(crate) struct AckTracker {
+    partitions: Mutex<HashMap<u32, PartitionAcks>>,
+    max_in_flight: Option<u32>,
+    waker: AtomicWaker,
+    store_offset_sender: flume::Sender<(u32, u64)>,
+}
+
+impl AckTracker {
+    pub(crate) fn new(
+        store_offset_sender: flume::Sender<(u32, u64)>,
+        max_in_flight: Option<u32>,
+    ) -> Self {
+        Self {
+            partitions: Mutex::new(HashMap::new()),
+            max_in_flight,
+            waker: AtomicWaker::new(),
+            store_offset_sender,
+        }
+    }
+
+    fn in_flight(partitions: &HashMap<u32, PartitionAcks>) -> usize {
+        partitions
+            .values()
+            .map(|partition| partition.pending_offsets.len())
+            .sum()
+    }
+
+    /// Returns true if another message can be delivered, otherwise registers the waker to be notified on the next ack.
+    pub(crate) fn poll_capacity(&self, cx: &mut Context<'_>) -> bool {
+        let Some(max_in_flight) = self.max_in_flight else {
+            return true;
+        };
+
+        self.waker.register(cx.waker());
+        let partitions = self.partitions.lock().unwrap();
+        Self::in_flight(&partitions) < max_in_flight as usize
+    }
+
+    pub(crate) fn deliver(self: &Arc<Self>, partition_id: u32, offset: u64) -> AckHandle {
+        let mut partitions = self.partitions.lock().unwrap();
+        let partition = partitions.entry(partition_id).or_default();
+        partition.pending_offsets.insert(offset);
+        if partition
+            .last_delivered_offset
+            .is_none_or(|last_offset| offset > last_offset)
+        {
+            partition.last_delivered_offset = Some(offset);
+        }
+
+        AckHandle {
+            partition_id,
+            offset,
+            tracker: self.clone(),
+        }
+    }
+
+    fn ack(&self, partition_id: u32, offset: u64) {
+        let mut partitions = self.partitions.lock().unwrap();
+        let Some(partition) = partitions.get_mut(&partition_id) else {
+            warn!("Acknowledged offset: {offset} for unknown partition ID: {partition_id}");
+            return;
+        };
+
+        if !partition.pending_offsets.remove(&offset) {
+            return;
+        }
+
+        self.waker.wake();
+        let Some(committable_offset) = partition.committable_offset() else {
+            return;
+        };
+
+        if partition
+            .last_committed_offset
+            .is_some_and(|last_offset| committable_offset <= last_offset)
+        {
+            return;
+        }
+
+        partition.last_committed_offset = Some(committable_offset);
+        trace!(
+            "Committing acknowledged offset: {committable_offset} for partition ID: {partition_id}"
+        );
+        if let Err(error) = self
+            .store_offset_sender
+            .send((partition_id, committable_offset))
+        {
+            error!("Failed to send offset to store: {error}, please verify if `init()` on IggyConsumer object has been called.");
         }
     }
 }
@@ -825,6 +1084,13 @@ impl Stream for IggyConsumer {
     type Item = Result<ReceivedMessage, IggyError>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(ack_tracker) = &self.ack_tracker {
+            if !ack_tracker.poll_capacity(cx) {
+                trace!("Reached the maximum number of messages in flight, waiting for the acknowledgements...");
+                return Poll::Pending;
+            }
+        }
+
         let partition_id = self.current_partition_id.load(ORDERING);
         if let Some(message) = self.buffered_messages.pop_front() {
             {
@@ -862,7 +1128,7 @@ impl Stream for IggyConsumer {
                 current_offset = 0;
             }
 
-            return Poll::Ready(Some(Ok(ReceivedMessage::new(
+            return Poll::Ready(Some(Ok(self.received_message(
                 message,
                 current_offset,
                 partition_id,
@@ -934,7 +1200,7 @@ impl Stream for IggyConsumer {
                         }
 
                         self.poll_future = None;
-                        return Poll::Ready(Some(Ok(ReceivedMessage::new(
+                        return Poll::Ready(Some(Ok(self.received_message(
                             message,
                             polled_messages.current_offset,
                             polled_messages.partition_id,
@@ -963,6 +1229,7 @@ pub struct IggyConsumerBuilder {
     partition: Option<u32>,
     polling_strategy: PollingStrategy,
     polling_interval: Option<IggyDuration>,
+    max_wait: Option<IggyDuration>,
     batch_size: u32,
     auto_commit: AutoCommit,
     auto_join_consumer_group: bool,
@@ -972,6 +1239,7 @@ pub struct IggyConsumerBuilder {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    max_in_flight: Option<u32>,
 }
 
 impl IggyConsumerBuilder {
@@ -1003,10 +1271,12 @@ impl IggyConsumerBuilder {
             create_consumer_group_if_not_exists: true,
             encryptor,
             polling_interval,
+            max_wait: None,
             polling_retry_interval: IggyDuration::ONE_SECOND,
             init_retries: None,
             init_retry_interval: IggyDuration::ONE_SECOND,
             allow_replay: false,
+            max_in_flight: None,
         }
     }
 
@@ -1101,6 +1371,24 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Enables the long polling, in which the server holds the poll request until the new messages are appended
+    /// or `max_wait` elapses (capped by the server configuration), so the polling interval is cleared.
+    pub fn long_polling(self, max_wait: IggyDuration) -> Self {
+        Self {
+            polling_interval: None,
+            max_wait: Some(max_wait),
+            ..self
+        }
+    }
+
+    /// Disables the long polling, the server responds right away even if there are no messages to poll.
+    pub fn without_long_polling(self) -> Self {
+        Self {
+            max_wait: None,
+            ..self
+        }
+    }
+
     /// Sets the encryptor for decrypting the messages' payloads.
     pub fn encryptor(self, encryptor: Arc<EncryptorKind>) -> Self {
         Self {
@@ -1144,6 +1432,16 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the maximum number of the received messages which are not acknowledged yet.
+    /// Once reached, the consumer waits for the acknowledgements before returning the next message.
+    /// Applies only to `AutoCommit::Acknowledged`, by default there's no limit.
+    pub fn max_in_flight(self, max_in_flight: u32) -> Self {
+        Self {
+            max_in_flight: Some(max_in_flight.max(1)),
+            ..self
+        }
+    }
+
     /// Builds the consumer.
     ///
     /// Note: After building the consumer, `init()` must be invoked before producing messages.
@@ -1156,6 +1454,7 @@ impl IggyConsumerBuilder {
             self.topic,
             self.partition,
             self.polling_interval,
+            self.max_wait,
             self.polling_strategy,
             self.batch_size,
             self.auto_commit,
@@ -1166,6 +1465,61 @@ impl IggyConsumerBuilder {
             self.init_retries,
             self.init_retry_interval,
             self.allow_replay,
+            self.max_in_flight,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain_offsets(receiver: &flume::Receiver<(u32, u64)>) -> Vec<(u32, u64)> {
+        receiver.try_iter().collect()
+    }
+
+    #[test]
+    fn offset_should_be_committed_only_when_all_earlier_messages_are_acknowledged() {
+        let (sender, receiver) = flume::unbounded();
+        let tracker = Arc::new(AckTracker::new(sender, None));
+        let handles = (0..4)
+            .map(|offset| tracker.deliver(1, offset))
+            .collect::<Vec<_>>();
+        let other_partition_handle = tracker.deliver(2, 10);
+
+        handles[2].ack();
+        handles[1].ack();
+        assert!(drain_offsets(&receiver).is_empty());
+
+        handles[0].ack();
+        assert_eq!(drain_offsets(&receiver), vec![(1, 2)]);
+
+        other_partition_handle.ack();
+        handles[3].ack();
+        assert_eq!(drain_offsets(&receiver), vec![(2, 10), (1, 3)]);
+    }
+
+    #[test]
+    fn acknowledging_message_again_should_not_commit_offset() {
+        let (sender, receiver) = flume::unbounded();
+        let tracker = Arc::new(AckTracker::new(sender, None));
+        let handle = tracker.deliver(1, 5);
+        handle.ack();
+        handle.clone().ack();
+        assert_eq!(drain_offsets(&receiver), vec![(1, 5)]);
+    }
+
+    #[test]
+    fn capacity_should_be_limited_by_max_in_flight_messages() {
+        let (sender, _receiver) = flume::unbounded();
+        let tracker = Arc::new(AckTracker::new(sender, Some(2)));
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        let first = tracker.deliver(1, 0);
+        assert!(tracker.poll_capacity(&mut cx));
+        let _second = tracker.deliver(2, 0);
+        assert!(!tracker.poll_capacity(&mut cx));
+
+        first.ack();
+        assert!(tracker.poll_capacity(&mut cx));
+    }
+}
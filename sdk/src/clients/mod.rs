@@ -16,7 +16,10 @@
  * under the License.
  */
 
+pub mod background_producer;
 pub mod builder;
 pub mod client;
 pub mod consumer;
 pub mod producer;
+pub mod typed_consumer;
+pub mod typed_producer;
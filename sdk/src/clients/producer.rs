@@ -23,6 +23,7 @@ use crate::error::IggyError;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
 use crate::partitioner::Partitioner;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
@@ -330,7 +331,7 @@ impl IggyProducer {
             );
             self.last_sent_at
                 .store(IggyTimestamp::now().into(), ORDERING);
-            self.try_send_messages(&self.stream_id, &self.topic_id, &partitioning, batch)
+            self.try_send_messages(&self.stream_id, &self.topic_id, &partitioning, batch, false)
                 .await?;
             trace!("Sent {messages_count} messages ({current_batch}/{batches_count} batch(es)).");
             current_batch += 1;
@@ -352,7 +353,7 @@ impl IggyProducer {
         if messages.len() <= batch_size {
             self.last_sent_at
                 .store(IggyTimestamp::now().into(), ORDERING);
-            self.try_send_messages(stream, topic, &partitioning, &mut messages)
+            self.try_send_messages(stream, topic, &partitioning, &mut messages, false)
                 .await?;
             return Ok(());
         }
@@ -360,7 +361,7 @@ impl IggyProducer {
         for batch in messages.chunks_mut(batch_size) {
             self.last_sent_at
                 .store(IggyTimestamp::now().into(), ORDERING);
-            self.try_send_messages(stream, topic, &partitioning, batch)
+            self.try_send_messages(stream, topic, &partitioning, batch, false)
                 .await?;
         }
         Ok(())
@@ -383,7 +384,7 @@ impl IggyProducer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
-    fn encrypt_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+    pub(crate) fn encrypt_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
         if let Some(encryptor) = &self.encryptor {
             for message in messages {
                 message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
@@ -393,24 +394,38 @@ impl IggyProducer {
         Ok(())
     }
 
-    async fn try_send_messages(
+    /// Sends the messages using the retry policy, returning the offsets of the appended messages if requested.
+    pub(crate) async fn try_send_messages(
         &self,
         stream: &Identifier,
         topic: &Identifier,
         partitioning: &Arc<Partitioning>,
         messages: &mut [Message],
-    ) -> Result<(), IggyError> {
+        report_offsets: bool,
+    ) -> Result<Option<AppendedMessages>, IggyError> {
         let client = self.client.read().await;
         let Some(max_retries) = self.send_retries_count else {
-            return client
-                .send_messages(stream, topic, partitioning, messages)
-                .await;
+            return Self::send_messages(
+                client.as_ref(),
+                stream,
+                topic,
+                partitioning,
+                messages,
+                report_offsets,
+            )
+            .await;
         };
 
         if max_retries == 0 {
-            return client
-                .send_messages(stream, topic, partitioning, messages)
-                .await;
+            return Self::send_messages(
+                client.as_ref(),
+                stream,
+                topic,
+                partitioning,
+                messages,
+                report_offsets,
+            )
+            .await;
         }
 
         let mut timer = if let Some(interval) = self.send_retries_interval {
@@ -429,11 +444,32 @@ impl IggyProducer {
             topic,
             partitioning,
             messages,
+            report_offsets,
             &mut timer,
         )
         .await
     }
 
+    async fn send_messages(
+        client: &dyn Client,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        report_offsets: bool,
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        if report_offsets {
+            return client
+                .send_messages_with_offsets(stream, topic, partitioning, messages)
+                .await;
+        }
+
+        client
+            .send_messages(stream, topic, partitioning, messages)
+            .await?;
+        Ok(None)
+    }
+
     async fn wait_until_connected(
         &self,
         max_retries: u32,
@@ -468,6 +504,7 @@ impl IggyProducer {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn send_with_retries(
         &self,
         max_retries: u32,
@@ -475,16 +512,23 @@ impl IggyProducer {
         topic: &Identifier,
         partitioning: &Arc<Partitioning>,
         messages: &mut [Message],
+        report_offsets: bool,
         timer: &mut Option<Interval>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<Option<AppendedMessages>, IggyError> {
         let client = self.client.read().await;
         let mut retries = 0;
         loop {
-            match client
-                .send_messages(stream, topic, partitioning, messages)
-                .await
+            match Self::send_messages(
+                client.as_ref(),
+                stream,
+                topic,
+                partitioning,
+                messages,
+                report_offsets,
+            )
+            .await
             {
-                Ok(_) => return Ok(()),
+                Ok(appended_messages) => return Ok(appended_messages),
                 Err(error) => {
                     retries += 1;
                     if retries > max_retries {
@@ -512,7 +556,7 @@ impl IggyProducer {
         }
     }
 
-    fn get_partitioning(
+    pub(crate) fn get_partitioning(
         &self,
         stream: &Identifier,
         topic: &Identifier,
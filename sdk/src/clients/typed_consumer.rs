@@ -0,0 +1,205 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::clients::consumer::{AckHandle, IggyConsumer, ReceivedMessage};
+use crate::codecs::{content_type, schema_id, Codec};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::messages::PolledMessage;
+use futures::Stream;
+use futures_util::ready;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tracing::warn;
+
+/// The policy describing how the typed consumer handles the messages which cannot be decoded.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub enum DecodeErrorPolicy {
+    /// The error is returned from the stream, which then continues with the next message.
+    /// With `AutoCommit::Acknowledged`, the message is acknowledged.
+    #[default]
+    Return,
+    /// The message is skipped (and logged), and the stream continues with the next message.
+    /// With `AutoCommit::Acknowledged`, the message is acknowledged.
+    Skip,
+    /// The error is returned from the stream, which then ends.
+    Stop,
+}
+
+/// The message received by the typed consumer, with the payload decoded into the value of type `T`.
+#[derive(Debug)]
+pub struct TypedReceivedMessage<T> {
+    pub value: T,
+    pub message: PolledMessage,
+    pub current_offset: u64,
+    pub partition_id: u32,
+    pub ack_handle: Option<AckHandle>,
+}
+
+impl<T> TypedReceivedMessage<T> {
+    /// Acknowledges the processing of the message, if the consumer uses `AutoCommit::Acknowledged`.
+    pub fn ack(&self) {
+        if let Some(ack_handle) = &self.ack_handle {
+            ack_handle.ack();
+        }
+    }
+}
+
+/// The consumer receiving the values of type `T`, decoded from the message payloads by the provided codec.
+/// If the message has the content type header, it must match the content type of the codec.
+pub struct IggyTypedConsumer<T, C: Codec<T>> {
+    consumer: IggyConsumer,
+    codec: C,
+    decode_error_policy: DecodeErrorPolicy,
+    stopped: bool,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl IggyConsumer {
+    /// Wraps the consumer into the typed one, using the provided codec to decode the values.
+    pub fn typed<T, C: Codec<T>>(self, codec: C) -> IggyTypedConsumer<T, C> {
+        IggyTypedConsumer::new(self, codec)
+    }
+}
+
+impl<T, C: Codec<T>> IggyTypedConsumer<T, C> {
+    pub fn new(consumer: IggyConsumer, codec: C) -> Self {
+        Self {
+            consumer,
+            codec,
+            decode_error_policy: DecodeErrorPolicy::default(),
+            stopped: false,
+            _value: PhantomData,
+        }
+    }
+
+    /// Sets the policy for handling the messages which cannot be decoded.
+    pub fn decode_error_policy(self, decode_error_policy: DecodeErrorPolicy) -> Self {
+        Self {
+            decode_error_policy,
+            ..self
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.consumer.name()
+    }
+
+    pub fn stream(&self) -> &Identifier {
+        self.consumer.stream()
+    }
+
+    pub fn topic(&self) -> &Identifier {
+        self.consumer.topic()
+    }
+
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Returns the underlying consumer, e.g. to store or delete the offset.
+    pub fn consumer(&self) -> &IggyConsumer {
+        &self.consumer
+    }
+
+    pub fn into_inner(self) -> IggyConsumer {
+        self.consumer
+    }
+
+    /// Initializes the underlying consumer.
+    pub async fn init(&mut self) -> Result<(), IggyError> {
+        self.consumer.init().await
+    }
+
+    /// Decodes the value from the message payload, validating the content type header if present.
+    /// If the message has the schema ID header, the codec decides whether it can read the payload written with that schema.
+    pub fn decode(&self, message: &PolledMessage) -> Result<T, IggyError> {
+        if let Some(content_type) = content_type(&message.headers) {
+            if content_type != self.codec.content_type() {
+                return Err(IggyError::InvalidMessageContentType(
+                    content_type.to_string(),
+                    self.codec.content_type().to_string(),
+                ));
+            }
+        }
+
+        match schema_id(&message.headers) {
+            Some(schema_id) => self.codec.decode_with_schema(schema_id, &message.payload),
+            None => self.codec.decode(&message.payload),
+        }
+    }
+}
+
+impl<T, C: Codec<T> + Unpin> Stream for IggyTypedConsumer<T, C> {
+    type Item = Result<TypedReceivedMessage<T>, IggyError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.stopped {
+                return Poll::Ready(None);
+            }
+
+            let received = match ready!(Pin::new(&mut this.consumer).poll_next(cx)) {
+                Some(Ok(received)) => received,
+                Some(Err(error)) => return Poll::Ready(Some(Err(error))),
+                None => return Poll::Ready(None),
+            };
+
+            let ReceivedMessage {
+                message,
+                current_offset,
+                partition_id,
+                ack_handle,
+            } = received;
+            match this.decode(&message) {
+                Ok(value) => {
+                    return Poll::Ready(Some(Ok(TypedReceivedMessage {
+                        value,
+                        message,
+                        current_offset,
+                        partition_id,
+                        ack_handle,
+                    })))
+                }
+                Err(error) => match this.decode_error_policy {
+                    DecodeErrorPolicy::Return => {
+                        if let Some(ack_handle) = ack_handle {
+                            ack_handle.ack();
+                        }
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                    DecodeErrorPolicy::Stop => {
+                        this.stopped = true;
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                    DecodeErrorPolicy::Skip => {
+                        if let Some(ack_handle) = ack_handle {
+                            ack_handle.ack();
+                        }
+                        warn!(
+                            "Skipping the message with offset: {} in partition: {partition_id}, which cannot be decoded: {error}",
+                            message.offset
+                        );
+                    }
+                },
+            }
+        }
+    }
+}
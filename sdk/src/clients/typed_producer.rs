@@ -0,0 +1,128 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::clients::producer::IggyProducer;
+use crate::codecs::{content_type_header, schema_id_header, Codec};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// The producer sending the values of type `T`, encoded into the message payloads by the provided codec.
+/// Each message gets the content type header set automatically, based on the codec,
+/// and the schema ID header if the codec is bound to the schema registry.
+pub struct IggyTypedProducer<T, C: Codec<T>> {
+    producer: IggyProducer,
+    codec: C,
+    content_type: (HeaderKey, HeaderValue),
+    schema_id: Option<(HeaderKey, HeaderValue)>,
+    _value: PhantomData<fn(&T)>,
+}
+
+impl IggyProducer {
+    /// Wraps the producer into the typed one, using the provided codec to encode the values.
+    pub fn typed<T, C: Codec<T>>(self, codec: C) -> Result<IggyTypedProducer<T, C>, IggyError> {
+        IggyTypedProducer::new(self, codec)
+    }
+}
+
+impl<T, C: Codec<T>> IggyTypedProducer<T, C> {
+    pub fn new(producer: IggyProducer, codec: C) -> Result<Self, IggyError> {
+        let content_type = content_type_header(&codec)?;
+        let schema_id = schema_id_header(&codec)?;
+        Ok(Self {
+            producer,
+            codec,
+            content_type,
+            schema_id,
+            _value: PhantomData,
+        })
+    }
+
+    pub fn stream(&self) -> &Identifier {
+        self.producer.stream()
+    }
+
+    pub fn topic(&self) -> &Identifier {
+        self.producer.topic()
+    }
+
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Returns the underlying producer, which can be used to send the raw messages.
+    pub fn producer(&self) -> &IggyProducer {
+        &self.producer
+    }
+
+    pub fn into_inner(self) -> IggyProducer {
+        self.producer
+    }
+
+    /// Initializes the underlying producer.
+    pub async fn init(&mut self) -> Result<(), IggyError> {
+        self.producer.init().await
+    }
+
+    /// Encodes the value into the message with the optional ID and headers, and the content type and schema ID headers.
+    pub fn encode(
+        &self,
+        id: Option<u128>,
+        value: &T,
+        headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Message, IggyError> {
+        let payload = self.codec.encode(value)?;
+        let mut headers = headers.unwrap_or_default();
+        headers.insert(self.content_type.0.clone(), self.content_type.1.clone());
+        if let Some((key, value)) = &self.schema_id {
+            headers.insert(key.clone(), value.clone());
+        }
+        Ok(Message::new(id, payload, Some(headers)))
+    }
+
+    pub async fn send(&self, values: &[T]) -> Result<(), IggyError> {
+        let messages = self.encode_all(values)?;
+        self.producer.send(messages).await
+    }
+
+    pub async fn send_one(&self, value: &T) -> Result<(), IggyError> {
+        self.send(std::slice::from_ref(value)).await
+    }
+
+    pub async fn send_with_partitioning(
+        &self,
+        values: &[T],
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        let messages = self.encode_all(values)?;
+        self.producer
+            .send_with_partitioning(messages, partitioning)
+            .await
+    }
+
+    fn encode_all(&self, values: &[T]) -> Result<Vec<Message>, IggyError> {
+        values
+            .iter()
+            .map(|value| self.encode(None, value, None))
+            .collect()
+    }
+}
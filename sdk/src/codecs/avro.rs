@@ -0,0 +1,209 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::SchemaClient;
+use crate::codecs::registry::RegisteredCodec;
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaFormat;
+use apache_avro::Schema;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// The codec serializing the values as Avro datums (without the embedded schema) using the provided schema,
+/// for any type implementing `Serialize` and `DeserializeOwned` that matches the schema.
+/// The payloads written with the other (writer) schemas can be decoded by resolving them against the codec schema,
+/// as long as the writer schemas are provided by their registered IDs.
+#[derive(Debug, Clone)]
+pub struct AvroCodec {
+    schema: Schema,
+    writer_schemas: HashMap<u32, Schema>,
+}
+
+impl AvroCodec {
+    /// Creates a new codec for the provided schema.
+    pub fn new(schema: Schema) -> Self {
+        Self {
+            schema,
+            writer_schemas: HashMap::new(),
+        }
+    }
+
+    /// Creates a new codec bound to the latest version of the Avro schema registered for the topic.
+    /// The previous versions are used as the writer schemas, if the topic compatibility rule allows reading them.
+    pub async fn from_registry(
+        client: &impl SchemaClient,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<RegisteredCodec<AvroCodec>, IggyError> {
+        let schemas = client.get_schemas(stream_id, topic_id).await?;
+        let Some(latest) = schemas.latest() else {
+            return Err(IggyError::TopicSchemaNotFound(
+                schemas.topic_id,
+                schemas.stream_id,
+            ));
+        };
+
+        let mut codec = AvroCodec::parse(&latest.definition)?;
+        for schema in &schemas.schemas {
+            if schema.id == latest.id || schema.format != SchemaFormat::Avro {
+                continue;
+            }
+
+            codec =
+                codec.with_writer_schema(schema.id, AvroCodec::parse(&schema.definition)?.schema);
+        }
+        RegisteredCodec::from_schemas(&schemas, codec)
+    }
+
+    /// Adds the writer schema with the provided registered ID, used to decode the payloads written with it.
+    pub fn with_writer_schema(mut self, schema_id: u32, schema: Schema) -> Self {
+        self.writer_schemas.insert(schema_id, schema);
+        self
+    }
+
+    /// Creates a new codec for the schema provided in the JSON format.
+    pub fn parse(schema: &str) -> Result<Self, IggyError> {
+        Schema::parse_str(schema).map(Self::new).map_err(|error| {
+            tracing::error!("Invalid Avro schema: {error}");
+            IggyError::InvalidConfiguration
+        })
+    }
+
+    /// Returns the schema used by the codec.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for AvroCodec {
+    fn content_type(&self) -> &str {
+        "avro/binary"
+    }
+
+    fn schema_format(&self) -> Option<SchemaFormat> {
+        Some(SchemaFormat::Avro)
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        let value = apache_avro::to_value(value)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))?
+            .resolve(&self.schema)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))?;
+        apache_avro::to_avro_datum(&self.schema, value)
+            .map(Bytes::from)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))
+    }
+
+    fn decode(&self, mut payload: &[u8]) -> Result<T, IggyError> {
+        let value = apache_avro::from_avro_datum(&self.schema, &mut payload, None)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))?;
+        apache_avro::from_value(&value)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+
+    fn decode_with_schema(&self, schema_id: u32, mut payload: &[u8]) -> Result<T, IggyError> {
+        let Some(writer_schema) = self.writer_schemas.get(&schema_id) else {
+            return self.decode(payload);
+        };
+
+        let value = apache_avro::from_avro_datum(writer_schema, &mut payload, Some(&self.schema))
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))?;
+        apache_avro::from_value(&value)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    const SCHEMA: &str = r#"
+    {
+        "type": "record",
+        "name": "Order",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "product", "type": "string"}
+        ]
+    }
+    "#;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: i64,
+        product: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let codec = AvroCodec::parse(SCHEMA).unwrap();
+        let order = Order {
+            id: 1,
+            product: "book".to_string(),
+        };
+        let payload = codec.encode(&order).unwrap();
+        let decoded: Order = codec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+
+    #[test]
+    fn value_written_with_writer_schema_should_be_decoded() {
+        let writer = AvroCodec::parse(
+            r#"{"type": "record", "name": "Order", "fields": [{"name": "id", "type": "long"}]}"#,
+        )
+        .unwrap();
+        let payload = writer.encode(&serde_json::json!({"id": 1})).unwrap();
+        let codec = AvroCodec::parse(
+            r#"
+            {
+                "type": "record",
+                "name": "Order",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "product", "type": "string", "default": "unknown"}
+                ]
+            }
+            "#,
+        )
+        .unwrap()
+        .with_writer_schema(1, writer.schema().clone());
+        let decoded: Order = codec.decode_with_schema(1, &payload).unwrap();
+        assert_eq!(
+            decoded,
+            Order {
+                id: 1,
+                product: "unknown".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn value_not_matching_schema_should_not_be_encoded() {
+        let codec = AvroCodec::parse(SCHEMA).unwrap();
+        let result = Codec::<String>::encode(&codec, &"book".to_string());
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotEncodeMessagePayload(_))
+        ));
+    }
+}
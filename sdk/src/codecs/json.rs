@@ -0,0 +1,82 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use crate::models::schema::SchemaFormat;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The codec serializing the values as JSON, for any type implementing `Serialize` and `DeserializeOwned`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonCodec;
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for JsonCodec {
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn schema_format(&self) -> Option<SchemaFormat> {
+        Some(SchemaFormat::JsonSchema)
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        serde_json::to_vec(value)
+            .map(Bytes::from)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        serde_json::from_slice(payload)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u64,
+        product: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            product: "book".to_string(),
+        };
+        let payload = JsonCodec.encode(&order).unwrap();
+        assert_eq!(payload, r#"{"id":1,"product":"book"}"#);
+        let decoded: Order = JsonCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+
+    #[test]
+    fn invalid_payload_should_not_be_decoded() {
+        let result: Result<Order, IggyError> = JsonCodec.decode(b"{\"id\":1}");
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotDecodeMessagePayload(_))
+        ));
+    }
+}
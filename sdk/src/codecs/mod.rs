@@ -0,0 +1,113 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+#[cfg(feature = "avro")]
+pub mod avro;
+pub mod json;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+pub mod registry;
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::schema::SchemaFormat;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// The name of the message header holding the content type of the payload, set automatically by the typed producer.
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+
+/// The name of the message header holding the ID of the registered schema the payload was encoded with,
+/// set automatically by the typed producer when the codec is bound to the schema registry.
+pub const SCHEMA_ID_HEADER: &str = "schema-id";
+
+/// The trait represents the logic responsible for converting the values of type `T` into the message payloads and back.
+/// It's used by the `IggyTypedProducer` and `IggyTypedConsumer` to work with the typed messages instead of the raw bytes.
+pub trait Codec<T>: Send + Sync + Debug {
+    /// The content type of the encoded payload, e.g. `application/json`.
+    fn content_type(&self) -> &str;
+
+    /// Encodes the value into the message payload.
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError>;
+
+    /// Decodes the value from the message payload.
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError>;
+
+    /// The format of the schema which can be registered for the encoded payloads, if any.
+    fn schema_format(&self) -> Option<SchemaFormat> {
+        None
+    }
+
+    /// The ID of the registered schema used to encode the values, if the codec is bound to the schema registry.
+    fn schema_id(&self) -> Option<u32> {
+        None
+    }
+
+    /// Decodes the value from the message payload encoded with the registered schema with the provided ID.
+    /// By default, the payload is decoded only if the codec is not bound to any schema or uses the same one.
+    fn decode_with_schema(&self, schema_id: u32, payload: &[u8]) -> Result<T, IggyError> {
+        match self.schema_id() {
+            Some(id) if id != schema_id => Err(IggyError::IncompatibleMessageSchema(schema_id, id)),
+            _ => self.decode(payload),
+        }
+    }
+}
+
+/// Returns the content type header key and value for the provided codec.
+pub fn content_type_header<T>(codec: &dyn Codec<T>) -> Result<(HeaderKey, HeaderValue), IggyError> {
+    Ok((
+        HeaderKey::new(CONTENT_TYPE_HEADER)?,
+        HeaderValue::from_str(codec.content_type())?,
+    ))
+}
+
+/// Returns the content type of the message payload, if the header is present.
+pub fn content_type(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<&str> {
+    headers
+        .as_ref()?
+        .iter()
+        .find(|(key, _)| key.as_str() == CONTENT_TYPE_HEADER)
+        .and_then(|(_, value)| value.as_str().ok())
+}
+
+/// Returns the schema ID header key and value for the provided codec, if it's bound to the schema registry.
+pub fn schema_id_header<T>(
+    codec: &dyn Codec<T>,
+) -> Result<Option<(HeaderKey, HeaderValue)>, IggyError> {
+    let Some(schema_id) = codec.schema_id() else {
+        return Ok(None);
+    };
+
+    Ok(Some((
+        HeaderKey::new(SCHEMA_ID_HEADER)?,
+        HeaderValue::from_uint32(schema_id)?,
+    )))
+}
+
+/// Returns the ID of the registered schema the message payload was encoded with, if the header is present.
+pub fn schema_id(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<u32> {
+    headers
+        .as_ref()?
+        .iter()
+        .find(|(key, _)| key.as_str() == SCHEMA_ID_HEADER)
+        .and_then(|(_, value)| value.as_uint32().ok())
+}
@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The codec serializing the values as MessagePack maps (with the field names), for any type implementing `Serialize` and `DeserializeOwned`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MessagePackCodec;
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for MessagePackCodec {
+    fn content_type(&self) -> &str {
+        "application/msgpack"
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        rmp_serde::to_vec_named(value)
+            .map(Bytes::from)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        rmp_serde::from_slice(payload)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u64,
+        product: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            product: "book".to_string(),
+        };
+        let payload = MessagePackCodec.encode(&order).unwrap();
+        let decoded: Order = MessagePackCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+}
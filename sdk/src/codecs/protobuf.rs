@@ -0,0 +1,68 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use crate::models::schema::SchemaFormat;
+use bytes::Bytes;
+
+/// The codec serializing the values as Protocol Buffers, for any type generated by `prost`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProtobufCodec;
+
+impl<T: prost::Message + Default> Codec<T> for ProtobufCodec {
+    fn content_type(&self) -> &str {
+        "application/x-protobuf"
+    }
+
+    fn schema_format(&self) -> Option<SchemaFormat> {
+        Some(SchemaFormat::Protobuf)
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        Ok(Bytes::from(value.encode_to_vec()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        T::decode(payload).map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Order {
+        #[prost(uint64, tag = "1")]
+        id: u64,
+        #[prost(string, tag = "2")]
+        product: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            product: "book".to_string(),
+        };
+        let payload = ProtobufCodec.encode(&order).unwrap();
+        let decoded: Order = ProtobufCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+}
@@ -0,0 +1,213 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::SchemaClient;
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::{Schema, SchemaFormat, TopicSchemas};
+use bytes::Bytes;
+
+/// The codec bound to the version of the schema registered for the topic.
+/// The encoded messages get the schema ID header, and the decoded ones are validated against the registry:
+/// only the payloads written with the schema versions readable by the bound one, according to the topic compatibility rule, are accepted.
+#[derive(Debug, Clone)]
+pub struct RegisteredCodec<C> {
+    codec: C,
+    schema: Schema,
+    readable_schema_ids: Vec<u32>,
+}
+
+impl<C> RegisteredCodec<C> {
+    /// Creates a new codec bound to the latest version of the schema registered for the topic.
+    pub async fn latest(
+        client: &impl SchemaClient,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        codec: C,
+    ) -> Result<Self, IggyError> {
+        let schemas = client.get_schemas(stream_id, topic_id).await?;
+        Self::from_schemas(&schemas, codec)
+    }
+
+    /// Registers a new version of the schema for the topic (or reuses the latest one if it's the same),
+    /// and creates a new codec bound to it.
+    pub async fn register(
+        client: &impl SchemaClient,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+        codec: C,
+    ) -> Result<Self, IggyError> {
+        client
+            .register_schema(stream_id, topic_id, format, definition)
+            .await?;
+        Self::latest(client, stream_id, topic_id, codec).await
+    }
+
+    /// Creates a new codec bound to the latest version of the provided topic schemas.
+    /// If the compatibility rule is backward (or full), the payloads written with all the previous versions are readable,
+    /// otherwise only the ones written with the latest version.
+    pub fn from_schemas(schemas: &TopicSchemas, codec: C) -> Result<Self, IggyError> {
+        let Some(schema) = schemas.latest() else {
+            return Err(IggyError::TopicSchemaNotFound(
+                schemas.topic_id,
+                schemas.stream_id,
+            ));
+        };
+
+        let readable_schema_ids = if schemas.compatibility.is_backward() {
+            schemas
+                .schemas
+                .iter()
+                .filter(|previous| previous.format == schema.format)
+                .map(|previous| previous.id)
+                .collect()
+        } else {
+            vec![schema.id]
+        };
+
+        Ok(Self {
+            codec,
+            schema: schema.clone(),
+            readable_schema_ids,
+        })
+    }
+
+    /// Returns the registered schema the codec is bound to.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the IDs of the registered schemas, which payloads can be decoded by the codec.
+    pub fn readable_schema_ids(&self) -> &[u32] {
+        &self.readable_schema_ids
+    }
+
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    pub fn into_inner(self) -> C {
+        self.codec
+    }
+}
+
+impl<T, C: Codec<T>> Codec<T> for RegisteredCodec<C> {
+    fn content_type(&self) -> &str {
+        self.codec.content_type()
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        if self.codec.schema_format() != Some(self.schema.format) {
+            return Err(IggyError::CannotEncodeMessagePayload(format!(
+                "codec with content type: {} cannot encode the payloads with the {} schema with ID: {}",
+                self.codec.content_type(),
+                self.schema.format,
+                self.schema.id
+            )));
+        }
+
+        self.codec.encode(value)
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        self.codec.decode(payload)
+    }
+
+    fn schema_format(&self) -> Option<SchemaFormat> {
+        Some(self.schema.format)
+    }
+
+    fn schema_id(&self) -> Option<u32> {
+        Some(self.schema.id)
+    }
+
+    fn decode_with_schema(&self, schema_id: u32, payload: &[u8]) -> Result<T, IggyError> {
+        if !self.readable_schema_ids.contains(&schema_id) {
+            return Err(IggyError::IncompatibleMessageSchema(
+                schema_id,
+                self.schema.id,
+            ));
+        }
+
+        self.codec.decode_with_schema(schema_id, payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::json::JsonCodec;
+    use crate::models::schema::SchemaCompatibility;
+    use crate::utils::timestamp::IggyTimestamp;
+
+    fn schemas(compatibility: SchemaCompatibility) -> TopicSchemas {
+        let schema = |id, version| Schema {
+            id,
+            stream_id: 1,
+            topic_id: 1,
+            version,
+            format: SchemaFormat::JsonSchema,
+            created_at: IggyTimestamp::now(),
+            definition: r#"{"type":"string"}"#.to_string(),
+        };
+        TopicSchemas {
+            stream_id: 1,
+            topic_id: 1,
+            compatibility,
+            validation: Default::default(),
+            schemas: vec![schema(3, 1), schema(7, 2)],
+        }
+    }
+
+    #[test]
+    fn codec_should_be_bound_to_latest_schema() {
+        let codec =
+            RegisteredCodec::from_schemas(&schemas(SchemaCompatibility::Backward), JsonCodec)
+                .unwrap();
+        assert_eq!(Codec::<String>::schema_id(&codec), Some(7));
+        assert_eq!(codec.readable_schema_ids(), &[3, 7]);
+    }
+
+    #[test]
+    fn payload_written_with_unreadable_schema_should_not_be_decoded() {
+        let codec =
+            RegisteredCodec::from_schemas(&schemas(SchemaCompatibility::Forward), JsonCodec)
+                .unwrap();
+        let payload = Codec::<String>::encode(&codec, &"value".to_string()).unwrap();
+        let value: String = codec.decode_with_schema(7, &payload).unwrap();
+        assert_eq!(value, "value");
+        let result: Result<String, _> = codec.decode_with_schema(3, &payload);
+        assert!(matches!(
+            result,
+            Err(IggyError::IncompatibleMessageSchema(3, 7))
+        ));
+    }
+
+    #[test]
+    fn codec_should_not_be_created_without_schemas() {
+        let topic_schemas = TopicSchemas {
+            schemas: vec![],
+            ..schemas(SchemaCompatibility::Backward)
+        };
+        let result = RegisteredCodec::from_schemas(&topic_schemas, JsonCodec);
+        assert!(matches!(result, Err(IggyError::TopicSchemaNotFound(1, 1))));
+    }
+}
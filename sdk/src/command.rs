@@ -25,18 +25,32 @@ pub trait Command: BytesSerializable + Validatable<IggyError> + Send + Sync + Di
     fn code(&self) -> u32;
 }
 
+/// The flag set in the command code of the TCP request, which carries a correlation ID (`u32`) right after the code.
+/// The server echoes the correlation ID after the response header (status and length),
+/// so that multiple requests can be pipelined over a single connection.
+pub const CORRELATION_ID_FLAG: u32 = 1 << 31;
+
+/// The flag set in the command code of the binary request by the clients supporting the error details.
+/// Only then the server includes the details of the error (e.g. the reason of the schema violation) in the payload
+/// of the error response, so the clients not expecting any payload in it keep receiving the bare status.
+pub const ERROR_DETAILS_FLAG: u32 = 1 << 30;
+
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const RELOAD_CONFIG: &str = "config.reload";
+pub const RELOAD_CONFIG_CODE: u32 = 12;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const DISCONNECT_CLIENT: &str = "client.disconnect";
+pub const DISCONNECT_CLIENT_CODE: u32 = 23;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -67,6 +81,14 @@ pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+pub const SEND_MESSAGES_WITH_OFFSETS: &str = "message.send_with_offsets";
+pub const SEND_MESSAGES_WITH_OFFSETS_CODE: u32 = 103;
+pub const SUBSCRIBE_MESSAGES: &str = "message.subscribe";
+pub const SUBSCRIBE_MESSAGES_CODE: u32 = 104;
+pub const UNSUBSCRIBE_MESSAGES: &str = "message.unsubscribe";
+pub const UNSUBSCRIBE_MESSAGES_CODE: u32 = 105;
+pub const GRANT_MESSAGE_CREDITS: &str = "message.grant_credits";
+pub const GRANT_MESSAGE_CREDITS_CODE: u32 = 106;
 pub const FLUSH_UNSAVED_BUFFER: &str = "message.flush_unsaved_buffer";
 pub const FLUSH_UNSAVED_BUFFER_CODE: u32 = 102;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
@@ -117,6 +139,16 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 700;
+pub const GET_SCHEMAS: &str = "schema.list";
+pub const GET_SCHEMAS_CODE: u32 = 701;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 702;
+pub const UPDATE_SCHEMA_COMPATIBILITY: &str = "schema.update_compatibility";
+pub const UPDATE_SCHEMA_COMPATIBILITY_CODE: u32 = 703;
+pub const UPDATE_SCHEMA_VALIDATION: &str = "schema.update_validation";
+pub const UPDATE_SCHEMA_VALIDATION_CODE: u32 = 704;
 
 pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
@@ -125,6 +157,7 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
+        DISCONNECT_CLIENT_CODE => Ok(DISCONNECT_CLIENT),
         GET_USER_CODE => Ok(GET_USER),
         GET_USERS_CODE => Ok(GET_USERS),
         CREATE_USER_CODE => Ok(CREATE_USER),
@@ -139,6 +172,10 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(DELETE_PERSONAL_ACCESS_TOKEN),
         LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(LOGIN_WITH_PERSONAL_ACCESS_TOKEN),
         SEND_MESSAGES_CODE => Ok(SEND_MESSAGES),
+        SEND_MESSAGES_WITH_OFFSETS_CODE => Ok(SEND_MESSAGES_WITH_OFFSETS),
+        SUBSCRIBE_MESSAGES_CODE => Ok(SUBSCRIBE_MESSAGES),
+        UNSUBSCRIBE_MESSAGES_CODE => Ok(UNSUBSCRIBE_MESSAGES),
+        GRANT_MESSAGE_CREDITS_CODE => Ok(GRANT_MESSAGE_CREDITS),
         POLL_MESSAGES_CODE => Ok(POLL_MESSAGES),
         FLUSH_UNSAVED_BUFFER_CODE => Ok(FLUSH_UNSAVED_BUFFER),
         STORE_CONSUMER_OFFSET_CODE => Ok(STORE_CONSUMER_OFFSET),
@@ -163,7 +200,13 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_CONSUMER_GROUP_CODE => Ok(DELETE_CONSUMER_GROUP),
         JOIN_CONSUMER_GROUP_CODE => Ok(JOIN_CONSUMER_GROUP),
         LEAVE_CONSUMER_GROUP_CODE => Ok(LEAVE_CONSUMER_GROUP),
+        GET_SCHEMA_CODE => Ok(GET_SCHEMA),
+        GET_SCHEMAS_CODE => Ok(GET_SCHEMAS),
+        REGISTER_SCHEMA_CODE => Ok(REGISTER_SCHEMA),
+        UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(UPDATE_SCHEMA_COMPATIBILITY),
+        UPDATE_SCHEMA_VALIDATION_CODE => Ok(UPDATE_SCHEMA_VALIDATION),
         GET_SNAPSHOT_FILE_CODE => Ok(GET_SNAPSHOT_FILE),
+        RELOAD_CONFIG_CODE => Ok(RELOAD_CONFIG),
         _ => Err(IggyError::InvalidCommand),
     }
 }
@@ -17,7 +17,7 @@
  */
 
 use crate::clients::consumer::{AutoCommit, AutoCommitAfter, IggyConsumer};
-use crate::consumer_ext::{IggyConsumerMessageExt, MessageConsumer};
+use crate::consumer_ext::{is_client_error, IggyConsumerMessageExt, MessageConsumer};
 use crate::error::IggyError;
 use async_trait::async_trait;
 use futures_util::StreamExt;
@@ -89,12 +89,16 @@ impl IggyConsumerMessageExt for IggyConsumer {
                             let partition_id = received_message.partition_id;
                             let current_offset = received_message.current_offset;
                             let message_offset = received_message.message.offset;
+                            let ack_handle = received_message.ack_handle.clone();
                             if let Err(err) = message_consumer.consume(received_message).await {
                                 error!("Error while handling message at offset: {message_offset}/{current_offset}, partition: {partition_id} for consumer: {name} on topic: {topic} and stream: {stream} due to error: {err}",
                                     name = self.name(), topic = self.topic(), stream = self.stream());
                             } else {
                                 trace!("Message at offset: {message_offset}/{current_offset}, partition: {partition_id} has been handled by consumer: {name} on topic: {topic} and stream: {stream}",
                                     name = self.name(), topic = self.topic(), stream = self.stream());
+                                if let Some(ack_handle) = ack_handle {
+                                    ack_handle.ack();
+                                }
                             }
 
                             if store_offset_after_each_message {
@@ -112,24 +116,15 @@ impl IggyConsumerMessageExt for IggyConsumer {
                             }
                         }
                         Some(Err(err)) => {
-                            match err {
-                                IggyError::Disconnected |
-                                IggyError::CannotEstablishConnection |
-                                IggyError::StaleClient |
-                                IggyError::InvalidServerAddress |
-                                IggyError::InvalidClientAddress |
-                                IggyError::NotConnected |
-                                IggyError::ClientShutdown => {
-                                    error!("Client error: {err} for consumer: {name} on topic: {topic} and stream: {stream}",
-                                        name = self.name(), topic = self.topic(), stream = self.stream());
-                                    return Err(err);
-                                }
-                                _ => {
-                                    error!("Error while handling message: {err} for consumer: {name} on topic: {topic} and stream: {stream}",
-                                        name = self.name(), topic = self.topic(), stream = self.stream());
-                                    continue;
-                                }
+                            if is_client_error(&err) {
+                                error!("Client error: {err} for consumer: {name} on topic: {topic} and stream: {stream}",
+                                    name = self.name(), topic = self.topic(), stream = self.stream());
+                                return Err(err);
                             }
+
+                            error!("Error while handling message: {err} for consumer: {name} on topic: {topic} and stream: {stream}",
+                                name = self.name(), topic = self.topic(), stream = self.stream());
+                            continue;
                         }
                         None => break,
                     }
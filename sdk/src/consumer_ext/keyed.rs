@@ -0,0 +1,229 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::consumer_ext::{is_client_error, WindowedMessage};
+use crate::error::IggyError;
+use futures::stream::FuturesUnordered;
+use futures::Stream;
+use futures_util::StreamExt;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::hash::Hash;
+use tracing::error;
+
+pub(crate) async fn process_keyed<S, T, K, KF, H, F>(
+    mut stream: S,
+    key: KF,
+    max_in_flight: usize,
+    handler: H,
+) -> Result<(), IggyError>
+where
+    S: Stream<Item = Result<T, IggyError>> + Unpin,
+    T: WindowedMessage,
+    K: Hash + Eq + Clone,
+    KF: Fn(&T) -> K,
+    H: Fn(T) -> F,
+    F: Future<Output = Result<(), IggyError>>,
+{
+    let max_in_flight = max_in_flight.max(1);
+    let process = |key: K, message: T| {
+        let received_message = message.received_message();
+        let partition_id = received_message.partition_id;
+        let offset = received_message.message.offset;
+        let ack_handle = received_message.ack_handle.clone();
+        let result = handler(message);
+        async move {
+            let result = result.await;
+            if let Err(error) = &result {
+                error!("Failed to process the message at offset: {offset}, partition: {partition_id}. {error}");
+            } else if let Some(ack_handle) = ack_handle {
+                ack_handle.ack();
+            }
+            (key, result)
+        }
+    };
+
+    let mut keyed_messages: HashMap<K, VecDeque<T>> = HashMap::new();
+    let mut running = FuturesUnordered::new();
+    let mut in_flight = 0;
+    let mut stream_completed = false;
+    let mut failure: Option<IggyError> = None;
+    loop {
+        let can_read = !stream_completed && failure.is_none() && in_flight < max_in_flight;
+        tokio::select! {
+            Some((message_key, result)) = running.next(), if !running.is_empty() => {
+                in_flight -= 1;
+                if let Err(error) = result {
+                    if failure.is_none() {
+                        failure = Some(error);
+                    }
+                    keyed_messages.remove(&message_key);
+                    continue;
+                }
+
+                if failure.is_some() {
+                    continue;
+                }
+
+                let next_message = keyed_messages
+                    .get_mut(&message_key)
+                    .and_then(|messages| messages.pop_front());
+                match next_message {
+                    Some(message) => running.push(process(message_key, message)),
+                    None => {
+                        keyed_messages.remove(&message_key);
+                    }
+                }
+            }
+            message = stream.next(), if can_read => {
+                match message {
+                    Some(Ok(message)) => {
+                        in_flight += 1;
+                        let message_key = key(&message);
+                        if let Some(messages) = keyed_messages.get_mut(&message_key) {
+                            messages.push_back(message);
+                            continue;
+                        }
+
+                        keyed_messages.insert(message_key.clone(), VecDeque::new());
+                        running.push(process(message_key, message));
+                    }
+                    Some(Err(error)) if is_client_error(&error) => {
+                        error!("Client error: {error} while processing the messages.");
+                        failure = Some(error);
+                    }
+                    Some(Err(error)) => {
+                        error!("Error while receiving the message: {error}");
+                    }
+                    None => stream_completed = true,
+                }
+            }
+            else => break,
+        }
+    }
+
+    match failure {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::consumer::{AckTracker, ReceivedMessage};
+    use crate::models::messages::{MessageState, PolledMessage};
+    use crate::utils::timestamp::IggyTimestamp;
+    use bytes::Bytes;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn received_messages(
+        keys: &[&str],
+        tracker: &Arc<AckTracker>,
+    ) -> Vec<Result<ReceivedMessage, IggyError>> {
+        keys.iter()
+            .enumerate()
+            .map(|(offset, key)| {
+                let offset = offset as u64;
+                let message = PolledMessage::create(
+                    offset,
+                    MessageState::Available,
+                    IggyTimestamp::from(offset),
+                    offset as u128,
+                    Bytes::from(key.to_string()),
+                    0,
+                    None,
+                );
+                let mut message = ReceivedMessage::new(message, offset, 1);
+                message.ack_handle = Some(tracker.deliver(1, offset));
+                Ok(message)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn messages_with_same_key_should_be_processed_in_order_and_committed() {
+        let keys = ["a", "b", "a", "c", "b", "a"];
+        let (sender, receiver) = flume::unbounded();
+        let tracker = Arc::new(AckTracker::new(sender, None));
+        let stream = futures::stream::iter(received_messages(&keys, &tracker));
+
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        process_keyed(
+            stream,
+            |message: &ReceivedMessage| message.message.payload.clone(),
+            2,
+            |message| {
+                let processed = processed.clone();
+                let running = running.clone();
+                let max_running = max_running.clone();
+                async move {
+                    let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(current, Ordering::SeqCst);
+                    let delay = 10 - message.message.offset;
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    processed.lock().unwrap().push(message.message.offset);
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        let processed = processed.lock().unwrap();
+        assert_eq!(processed.len(), keys.len());
+        for key in ["a", "b", "c"] {
+            let offsets = processed
+                .iter()
+                .filter(|offset| keys[**offset as usize] == key)
+                .copied()
+                .collect::<Vec<_>>();
+            assert!(offsets.is_sorted());
+        }
+        assert!(max_running.load(Ordering::SeqCst) <= 2);
+        assert_eq!(receiver.try_iter().last(), Some((1, 5)));
+    }
+
+    #[tokio::test]
+    async fn failed_message_should_not_be_committed() {
+        let (sender, receiver) = flume::unbounded();
+        let tracker = Arc::new(AckTracker::new(sender, None));
+        let stream = futures::stream::iter(received_messages(&["key"; 5], &tracker));
+
+        let result = process_keyed(
+            stream,
+            |message: &ReceivedMessage| message.message.payload.clone(),
+            10,
+            |message| async move {
+                if message.message.offset == 3 {
+                    return Err(IggyError::InvalidCommand);
+                }
+                Ok(())
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(IggyError::InvalidCommand)));
+        assert_eq!(receiver.try_iter().last(), Some((1, 2)));
+    }
+}
@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::consumer_ext::keyed::process_keyed;
+use crate::consumer_ext::{SlidingWindows, TumblingWindows, WindowSize, WindowedMessage};
+use crate::error::IggyError;
+use futures::Stream;
+use std::future::Future;
+use std::hash::Hash;
+
+/// The extension of the message streams, such as `IggyConsumer`, with the windowing and processing combinators.
+///
+/// For the at-least-once processing, the consumer should use `AutoCommit::Acknowledged`, so the offset is committed
+/// only once the message and all the earlier messages from its partition are acknowledged: by `process_keyed`
+/// when the handler succeeds, or by `MessageWindow::ack()` for the whole window. With the other auto-commit modes,
+/// the acknowledgements have no effect and the offsets are committed when the messages are polled or consumed.
+pub trait IggyMessageStreamExt<T>: Stream<Item = Result<T, IggyError>> + Unpin + Sized
+where
+    T: WindowedMessage,
+{
+    /// Groups the messages into the non-overlapping windows of the given size.
+    ///
+    /// # Errors
+    ///
+    /// * `IggyError::InvalidWindowSize`: The window size is zero.
+    fn tumbling_windows(self, size: WindowSize) -> Result<TumblingWindows<Self, T>, IggyError> {
+        TumblingWindows::new(self, size)
+    }
+
+    /// Groups the messages into the overlapping windows of the given size, starting every `step`.
+    /// As the message can belong to more than one window, it's cloned into each of them.
+    ///
+    /// # Errors
+    ///
+    /// * `IggyError::InvalidWindowSize`: The size or the step is zero, the step is greater than the size,
+    ///   or they are not of the same kind (count or time).
+    fn sliding_windows(
+        self,
+        size: WindowSize,
+        step: WindowSize,
+    ) -> Result<SlidingWindows<Self, T>, IggyError>
+    where
+        T: Clone,
+    {
+        SlidingWindows::new(self, size, step)
+    }
+
+    /// Processes the messages with the provided handler, keeping up to `max_in_flight` messages in progress.
+    ///
+    /// The messages with the same key (as returned by the `key` function) are processed one by one,
+    /// in the order of their delivery, while the messages with different keys are processed concurrently.
+    /// Each message is acknowledged once the handler succeeds, so with `AutoCommit::Acknowledged`
+    /// the offsets are committed only up to the messages processed successfully.
+    /// If the handler fails, no more messages are read from the stream, and the error is returned
+    /// after the handlers in progress complete.
+    fn process_keyed<K, KF, H, F>(
+        self,
+        key: KF,
+        max_in_flight: usize,
+        handler: H,
+    ) -> impl Future<Output = Result<(), IggyError>>
+    where
+        K: Hash + Eq + Clone,
+        KF: Fn(&T) -> K,
+        H: Fn(T) -> F,
+        F: Future<Output = Result<(), IggyError>>,
+    {
+        process_keyed(self, key, max_in_flight, handler)
+    }
+}
+
+impl<S, T> IggyMessageStreamExt<T> for S
+where
+    S: Stream<Item = Result<T, IggyError>> + Unpin,
+    T: WindowedMessage,
+{
+}
@@ -18,10 +18,15 @@
 
 mod consumer_message_ext;
 mod consumer_message_trait;
+mod keyed;
+mod message_stream_ext;
+mod window;
 
 use crate::clients::consumer::ReceivedMessage;
 use crate::error::IggyError;
 pub use consumer_message_trait::IggyConsumerMessageExt;
+pub use message_stream_ext::IggyMessageStreamExt;
+pub use window::{MessageWindow, SlidingWindows, TumblingWindows, WindowSize, WindowedMessage};
 
 /// Trait for message consumer
 #[allow(dead_code)] // Clippy can't see that the trait is used
@@ -55,3 +60,17 @@ impl<T: MessageConsumer + Send + Sync> MessageConsumer for &T {
         (**self).consume(message).await
     }
 }
+
+/// Returns true if the error is caused by the client (e.g. disconnection), so the messages cannot be consumed anymore.
+pub(crate) fn is_client_error(error: &IggyError) -> bool {
+    matches!(
+        error,
+        IggyError::Disconnected
+            | IggyError::CannotEstablishConnection
+            | IggyError::StaleClient
+            | IggyError::InvalidServerAddress
+            | IggyError::InvalidClientAddress
+            | IggyError::NotConnected
+            | IggyError::ClientShutdown
+    )
+}
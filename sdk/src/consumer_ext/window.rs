@@ -0,0 +1,543 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::clients::consumer::ReceivedMessage;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use futures::Stream;
+use futures_util::{ready, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Range;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The message which can be assigned to the window, based on its position in the stream or its timestamp.
+pub trait WindowedMessage {
+    /// Returns the received message.
+    fn received_message(&self) -> &ReceivedMessage;
+
+    /// Returns the timestamp of the message in microseconds.
+    fn timestamp(&self) -> u64 {
+        self.received_message().message.timestamp
+    }
+}
+
+impl WindowedMessage for ReceivedMessage {
+    fn received_message(&self) -> &ReceivedMessage {
+        self
+    }
+}
+
+/// The size of the window.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum WindowSize {
+    /// The window contains the specified number of messages.
+    Count(usize),
+    /// The window contains the messages with the timestamps within the specified duration.
+    /// The windows are aligned to the multiples of the duration (or the step for the sliding windows),
+    /// and they are closed once the message with a later timestamp arrives or the stream ends.
+    /// The late messages, older than the current window, are assigned to the current window.
+    Time(IggyDuration),
+}
+
+impl WindowSize {
+    fn validate(&self) -> Result<(), IggyError> {
+        let is_empty = match self {
+            WindowSize::Count(count) => *count == 0,
+            WindowSize::Time(duration) => duration.as_micros() == 0,
+        };
+        if is_empty {
+            return Err(IggyError::InvalidWindowSize);
+        }
+
+        Ok(())
+    }
+}
+
+/// The window of messages emitted by the windowing streams.
+#[derive(Debug)]
+pub struct MessageWindow<T> {
+    /// The range of the message timestamps in microseconds covered by the time window, `None` for the count window.
+    pub range: Option<Range<u64>>,
+    pub messages: Vec<T>,
+}
+
+impl<T> MessageWindow<T> {
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Groups the messages of the window by the key returned by the provided function,
+    /// preserving the order of the messages within each group.
+    pub fn group_by_key<K, F>(self, key: F) -> HashMap<K, Vec<T>>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+        for message in self.messages {
+            groups.entry(key(&message)).or_default().push(message);
+        }
+        groups
+    }
+}
+
+impl<T: WindowedMessage> MessageWindow<T> {
+    /// Acknowledges the processing of all the messages of the window, see `ReceivedMessage::ack()`.
+    /// The sliding windows share the messages with the next windows, so acknowledging such a window
+    /// also counts its shared messages as processed, even if the next window is not processed yet.
+    pub fn ack(&self) {
+        for message in &self.messages {
+            message.received_message().ack();
+        }
+    }
+}
+
+/// The stream of the non-overlapping windows, each containing a fixed number of messages
+/// or the messages from a fixed period of time.
+pub struct TumblingWindows<S, T> {
+    stream: S,
+    size: WindowSize,
+    range: Option<Range<u64>>,
+    messages: Vec<T>,
+    completed: bool,
+}
+
+impl<S, T> TumblingWindows<S, T> {
+    pub(crate) fn new(stream: S, size: WindowSize) -> Result<Self, IggyError> {
+        size.validate()?;
+        Ok(Self {
+            stream,
+            size,
+            range: None,
+            messages: Vec::new(),
+            completed: false,
+        })
+    }
+
+    fn take_window(&mut self) -> Option<MessageWindow<T>> {
+        if self.messages.is_empty() {
+            return None;
+        }
+
+        Some(MessageWindow {
+            range: self.range.take(),
+            messages: std::mem::take(&mut self.messages),
+        })
+    }
+}
+
+impl<S, T> Stream for TumblingWindows<S, T>
+where
+    S: Stream<Item = Result<T, IggyError>> + Unpin,
+    T: WindowedMessage + Unpin,
+{
+    type Item = Result<MessageWindow<T>, IggyError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.completed {
+                return Poll::Ready(None);
+            }
+
+            let message = match ready!(this.stream.poll_next_unpin(cx)) {
+                Some(Ok(message)) => message,
+                Some(Err(error)) => return Poll::Ready(Some(Err(error))),
+                None => {
+                    this.completed = true;
+                    return Poll::Ready(this.take_window().map(Ok));
+                }
+            };
+
+            match this.size {
+                WindowSize::Count(count) => {
+                    this.messages.push(message);
+                    if this.messages.len() == count {
+                        return Poll::Ready(this.take_window().map(Ok));
+                    }
+                }
+                WindowSize::Time(duration) => {
+                    let size = duration.as_micros();
+                    let timestamp = message.timestamp();
+                    let window = match this.range.as_ref() {
+                        Some(range) if timestamp >= range.end => {
+                            let window = this.take_window();
+                            this.range = Some(window_range(timestamp, size, size));
+                            window
+                        }
+                        Some(_) => None,
+                        None => {
+                            this.range = Some(window_range(timestamp, size, size));
+                            None
+                        }
+                    };
+                    this.messages.push(message);
+                    if let Some(window) = window {
+                        return Poll::Ready(Some(Ok(window)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The stream of the overlapping windows of the given size, starting every `step` messages
+/// or every `step` period of time. The messages are cloned into each window they belong to.
+pub struct SlidingWindows<S, T> {
+    stream: S,
+    size: WindowSize,
+    step: WindowSize,
+    start: Option<u64>,
+    unwindowed_messages: usize,
+    messages: VecDeque<(u64, T)>,
+    windows: VecDeque<MessageWindow<T>>,
+    completed: bool,
+}
+
+impl<S, T: Clone> SlidingWindows<S, T> {
+    pub(crate) fn new(stream: S, size: WindowSize, step: WindowSize) -> Result<Self, IggyError> {
+        size.validate()?;
+        step.validate()?;
+        let is_valid = match (size, step) {
+            (WindowSize::Count(size), WindowSize::Count(step)) => step <= size,
+            (WindowSize::Time(size), WindowSize::Time(step)) => {
+                step.as_micros() <= size.as_micros()
+            }
+            _ => false,
+        };
+        if !is_valid {
+            return Err(IggyError::InvalidWindowSize);
+        }
+
+        Ok(Self {
+            stream,
+            size,
+            step,
+            start: None,
+            unwindowed_messages: 0,
+            messages: VecDeque::new(),
+            windows: VecDeque::new(),
+            completed: false,
+        })
+    }
+
+    fn push_count(&mut self, message: T, size: usize, step: usize) {
+        self.messages.push_back((0, message));
+        self.unwindowed_messages += 1;
+        if self.messages.len() < size {
+            return;
+        }
+
+        self.unwindowed_messages = 0;
+
+        self.windows.push_back(MessageWindow {
+            range: None,
+            messages: self.cloned_messages(),
+        });
+        self.messages.drain(..step);
+    }
+
+    fn push_time(&mut self, message: T, timestamp: u64, size: u64, step: u64) {
+        let mut start = *self
+            .start
+            .get_or_insert_with(|| window_range(timestamp, size, step).start);
+        let timestamp = timestamp.max(start);
+        while timestamp >= start + size {
+            self.close_time_window(start, size);
+            start += step;
+            self.messages
+                .retain(|(message_timestamp, _)| *message_timestamp >= start);
+            if self.messages.is_empty() {
+                start = start.max(window_range(timestamp, size, step).start);
+            }
+        }
+        self.start = Some(start);
+        self.messages.push_back((timestamp, message));
+    }
+
+    fn close_time_window(&mut self, start: u64, size: u64) {
+        let messages = self
+            .messages
+            .iter()
+            .filter(|(timestamp, _)| *timestamp < start + size)
+            .map(|(_, message)| message.clone())
+            .collect::<Vec<_>>();
+        if messages.is_empty() {
+            return;
+        }
+
+        self.windows.push_back(MessageWindow {
+            range: Some(start..start + size),
+            messages,
+        });
+    }
+
+    fn complete(&mut self) {
+        self.completed = true;
+        match (self.size, self.step) {
+            (WindowSize::Count(_), _) if self.unwindowed_messages > 0 => {
+                self.windows.push_back(MessageWindow {
+                    range: None,
+                    messages: self.cloned_messages(),
+                });
+            }
+            (WindowSize::Time(size), WindowSize::Time(step)) => {
+                let (size, step) = (size.as_micros(), step.as_micros());
+                let mut start = self.start.unwrap_or_default();
+                while !self.messages.is_empty() {
+                    self.close_time_window(start, size);
+                    start += step;
+                    self.messages
+                        .retain(|(message_timestamp, _)| *message_timestamp >= start);
+                }
+            }
+            _ => {}
+        }
+        self.messages.clear();
+    }
+
+    fn cloned_messages(&self) -> Vec<T> {
+        self.messages
+            .iter()
+            .map(|(_, message)| message.clone())
+            .collect()
+    }
+}
+
+impl<S, T> Stream for SlidingWindows<S, T>
+where
+    S: Stream<Item = Result<T, IggyError>> + Unpin,
+    T: WindowedMessage + Clone + Unpin,
+{
+    type Item = Result<MessageWindow<T>, IggyError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(window) = this.windows.pop_front() {
+                return Poll::Ready(Some(Ok(window)));
+            }
+
+            if this.completed {
+                return Poll::Ready(None);
+            }
+
+            let message = match ready!(this.stream.poll_next_unpin(cx)) {
+                Some(Ok(message)) => message,
+                Some(Err(error)) => return Poll::Ready(Some(Err(error))),
+                None => {
+                    this.complete();
+                    continue;
+                }
+            };
+
+            match (this.size, this.step) {
+                (WindowSize::Count(size), WindowSize::Count(step)) => {
+                    this.push_count(message, size, step);
+                }
+                (WindowSize::Time(size), WindowSize::Time(step)) => {
+                    let timestamp = message.timestamp();
+                    this.push_time(message, timestamp, size.as_micros(), step.as_micros());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the range of the earliest window containing the timestamp, for the windows starting every `step`.
+fn window_range(timestamp: u64, size: u64, step: u64) -> Range<u64> {
+    let start = if timestamp < size {
+        0
+    } else {
+        ((timestamp - size) / step + 1) * step
+    };
+    start..start + size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::consumer::AckTracker;
+    use crate::models::messages::{MessageState, PolledMessage};
+    use crate::utils::timestamp::IggyTimestamp;
+    use bytes::Bytes;
+    use futures::stream;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    fn received_message(offset: u64, timestamp: u64) -> ReceivedMessage {
+        let message = PolledMessage::create(
+            offset,
+            MessageState::Available,
+            IggyTimestamp::from(timestamp),
+            offset as u128,
+            Bytes::from(format!("message-{offset}")),
+            0,
+            None,
+        );
+        ReceivedMessage::new(message, offset, 1)
+    }
+
+    fn messages(timestamps: &[u64]) -> Vec<Result<ReceivedMessage, IggyError>> {
+        timestamps
+            .iter()
+            .enumerate()
+            .map(|(offset, timestamp)| Ok(received_message(offset as u64, *timestamp)))
+            .collect()
+    }
+
+    fn duration(value: &str) -> WindowSize {
+        WindowSize::Time(IggyDuration::from_str(value).unwrap())
+    }
+
+    async fn collect_offsets<S>(windows: S) -> Vec<(Option<Range<u64>>, Vec<u64>)>
+    where
+        S: Stream<Item = Result<MessageWindow<ReceivedMessage>, IggyError>>,
+    {
+        windows
+            .map(|window| {
+                let window = window.unwrap();
+                let offsets = window
+                    .messages
+                    .iter()
+                    .map(|message| message.message.offset)
+                    .collect();
+                (window.range, offsets)
+            })
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn tumbling_count_windows_should_contain_fixed_number_of_messages() {
+        let stream = stream::iter(messages(&[1, 2, 3, 4, 5]));
+        let windows = TumblingWindows::new(stream, WindowSize::Count(2)).unwrap();
+        let windows = collect_offsets(windows).await;
+        assert_eq!(
+            windows,
+            vec![(None, vec![0, 1]), (None, vec![2, 3]), (None, vec![4])]
+        );
+    }
+
+    #[tokio::test]
+    async fn tumbling_time_windows_should_be_aligned_to_window_size() {
+        let stream = stream::iter(messages(&[1_500, 1_900, 2_100, 5_000, 4_000]));
+        let windows = TumblingWindows::new(stream, duration("1ms")).unwrap();
+        let windows = collect_offsets(windows).await;
+        assert_eq!(
+            windows,
+            vec![
+                (Some(1_000..2_000), vec![0, 1]),
+                (Some(2_000..3_000), vec![2]),
+                (Some(5_000..6_000), vec![3, 4]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sliding_count_windows_should_overlap() {
+        let stream = stream::iter(messages(&[1, 2, 3, 4, 5]));
+        let windows =
+            SlidingWindows::new(stream, WindowSize::Count(3), WindowSize::Count(2)).unwrap();
+        let windows = collect_offsets(windows).await;
+        assert_eq!(windows, vec![(None, vec![0, 1, 2]), (None, vec![2, 3, 4])]);
+    }
+
+    #[tokio::test]
+    async fn sliding_time_windows_should_contain_messages_from_each_overlapping_period() {
+        let stream = stream::iter(messages(&[1_500, 2_500, 6_200]));
+        let windows = SlidingWindows::new(stream, duration("2ms"), duration("1ms")).unwrap();
+        let windows = collect_offsets(windows).await;
+        assert_eq!(
+            windows,
+            vec![
+                (Some(0..2_000), vec![0]),
+                (Some(1_000..3_000), vec![0, 1]),
+                (Some(2_000..4_000), vec![1]),
+                (Some(5_000..7_000), vec![2]),
+                (Some(6_000..8_000), vec![2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_window_sizes_should_be_rejected() {
+        let stream = stream::iter(messages(&[]));
+        assert!(TumblingWindows::<_, ReceivedMessage>::new(stream, WindowSize::Count(0)).is_err());
+        let stream = stream::iter(messages(&[]));
+        assert!(SlidingWindows::<_, ReceivedMessage>::new(
+            stream,
+            WindowSize::Count(2),
+            WindowSize::Count(3)
+        )
+        .is_err());
+        let stream = stream::iter(messages(&[]));
+        assert!(SlidingWindows::<_, ReceivedMessage>::new(
+            stream,
+            WindowSize::Count(2),
+            duration("1ms")
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn acknowledged_window_should_be_committed() {
+        let (sender, receiver) = flume::unbounded();
+        let tracker = Arc::new(AckTracker::new(sender, None));
+        let messages = messages(&[1, 2, 3, 4, 5])
+            .into_iter()
+            .map(|message| {
+                message.map(|mut message| {
+                    message.ack_handle = Some(tracker.deliver(1, message.message.offset));
+                    message
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut windows =
+            TumblingWindows::new(stream::iter(messages), WindowSize::Count(2)).unwrap();
+
+        let first_window = windows.next().await.unwrap().unwrap();
+        let second_window = windows.next().await.unwrap().unwrap();
+        second_window.ack();
+        assert_eq!(receiver.try_recv().ok(), None);
+        first_window.ack();
+        assert_eq!(receiver.try_iter().last(), Some((1, 3)));
+    }
+
+    #[test]
+    fn window_should_group_messages_by_key() {
+        let window = MessageWindow {
+            range: None,
+            messages: vec![
+                received_message(0, 0),
+                received_message(1, 0),
+                received_message(2, 0),
+            ],
+        };
+        let groups = window.group_by_key(|message| message.message.offset % 2);
+        assert_eq!(groups[&0].len(), 2);
+        assert_eq!(groups[&1].len(), 1);
+    }
+}
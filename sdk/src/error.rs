@@ -18,6 +18,7 @@
 
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::topic_size::MaxTopicSize;
+use bytes::{BufMut, Bytes, BytesMut};
 use strum::{EnumDiscriminants, FromRepr, IntoStaticStr};
 use thiserror::Error;
 
@@ -62,6 +63,8 @@ pub enum IggyError {
     StateFileCorrupted = 15,
     #[error("Invalid state entry checksum: {0}, expected: {1}, for index: {2}")]
     InvalidStateEntryChecksum(u32, u32, u64) = 16,
+    #[error("Cannot reload configuration")]
+    CannotReloadConfiguration = 17,
     #[error("Cannot open database, Path: {0}")]
     CannotOpenDatabase(String) = 19,
     #[error("Resource with key: {0} was not found.")]
@@ -76,6 +79,8 @@ pub enum IggyError {
     InvalidServerAddress = 33,
     #[error("Invalid client address")]
     InvalidClientAddress = 34,
+    #[error("WebSocket error")]
+    WebSocketError = 35,
     #[error("Unauthenticated")]
     Unauthenticated = 40,
     #[error("Unauthorized")]
@@ -152,8 +157,14 @@ pub enum IggyError {
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
     InvalidClientId = 101,
+    #[error("Client with ID: {0} has been disconnected.")]
+    ClientDisconnected(u32) = 102,
     #[error("Connection closed")]
     ConnectionClosed = 206,
+    #[error("Server is shutting down")]
+    ServerShuttingDown = 207,
+    #[error("Cannot flush {0} partition(s) on shutdown")]
+    CannotFlushPartitions(u32) = 208,
     #[error("Cannot parse header kind from {0}")]
     CannotParseHeaderKind(String) = 209,
     #[error("HTTP response error, status: {0}, body: {1}")]
@@ -342,8 +353,34 @@ pub enum IggyError {
     CommandLengthError(String) = 4029,
     #[error("Incorrect Segments Count size: {0}")]
     InvalidSegmentsCount(u32) = 4030,
+    #[error("Cannot encode message payload: {0}")]
+    CannotEncodeMessagePayload(String) = 4031,
+    #[error("Cannot decode message payload: {0}")]
+    CannotDecodeMessagePayload(String) = 4032,
+    #[error("Invalid message content type: {0}, expected: {1}")]
+    InvalidMessageContentType(String, String) = 4033,
+    #[error("Message schema with ID: {0} cannot be read with the schema with ID: {1}")]
+    IncompatibleMessageSchema(u32, u32) = 4034,
+    #[error("Message at index: {0} does not match the schema with ID: {1}: {2}")]
+    MessagePayloadSchemaViolation(u32, u32, String) = 4035,
     #[error("Cannot sed messages due to client disconnection")]
     CannotSendMessagesDueToClientDisconnection = 4050,
+    #[error("Producer buffer is full")]
+    ProducerBufferFull = 4051,
+    #[error("Producer is closed")]
+    ProducerClosed = 4052,
+    #[error("Invalid window size")]
+    InvalidWindowSize = 4060,
+    #[error("Cannot create runtime")]
+    CannotCreateRuntime = 4070,
+    #[error("Message subscription with ID: {0} already exists")]
+    MessageSubscriptionAlreadyExists(u32) = 4080,
+    #[error("Message subscription with ID: {0} was not found")]
+    MessageSubscriptionNotFound(u32) = 4081,
+    #[error("Message subscriptions are not supported by the transport")]
+    MessageSubscriptionsNotSupported = 4082,
+    #[error("Invalid number of message credits")]
+    InvalidMessageCredits = 4083,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
     #[error("Consumer group with ID: {0} for topic with ID: {1} was not found.")]
@@ -364,6 +401,22 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Schema with ID: {0} was not found.")]
+    SchemaNotFound(u32) = 5100,
+    #[error("Invalid schema ID")]
+    InvalidSchemaId = 5101,
+    #[error("Invalid schema format")]
+    InvalidSchemaFormat = 5102,
+    #[error("Invalid schema compatibility")]
+    InvalidSchemaCompatibility = 5103,
+    #[error("Invalid schema definition: {0}")]
+    InvalidSchemaDefinition(String) = 5104,
+    #[error("Schema is not compatible with the latest version: {0}")]
+    IncompatibleSchema(String) = 5105,
+    #[error("No schema is registered for topic with ID: {0} for stream with ID: {1}.")]
+    TopicSchemaNotFound(u32, u32) = 5106,
+    #[error("Invalid schema validation")]
+    InvalidSchemaValidation = 5107,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
@@ -431,6 +484,36 @@ impl IggyError {
         IggyError::from_repr(code).unwrap_or(IggyError::Error)
     }
 
+    /// Returns the details of the error, sent by the server in the payload of the binary error response.
+    /// Most errors are fully described by their code, so there are no details for them.
+    pub fn as_details(&self) -> Option<Bytes> {
+        match self {
+            IggyError::MessagePayloadSchemaViolation(index, schema_id, reason) => {
+                let mut bytes = BytesMut::with_capacity(8 + reason.len());
+                bytes.put_u32_le(*index);
+                bytes.put_u32_le(*schema_id);
+                bytes.put_slice(reason.as_bytes());
+                Some(bytes.freeze())
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates the error from the code and the details sent in the payload of the binary error response.
+    /// If the details are missing or malformed, the error is created from the code only.
+    pub fn from_code_and_details(code: u32, details: &[u8]) -> Self {
+        let error = IggyError::from_code(code);
+        match error {
+            IggyError::MessagePayloadSchemaViolation(..) if details.len() >= 8 => {
+                let index = u32::from_le_bytes(details[..4].try_into().unwrap());
+                let schema_id = u32::from_le_bytes(details[4..8].try_into().unwrap());
+                let reason = String::from_utf8_lossy(&details[8..]).to_string();
+                IggyError::MessagePayloadSchemaViolation(index, schema_id, reason)
+            }
+            _ => error,
+        }
+    }
+
     pub fn from_code_as_string(code: u32) -> &'static str {
         IggyErrorDiscriminants::from_repr(code)
             .map(|discriminant| discriminant.into())
@@ -477,4 +560,25 @@ mod tests {
             IggyError::from_code_as_string(GROUP_NAME_ERROR_CODE)
         )
     }
+
+    #[test]
+    fn error_with_details_should_be_restored_from_code_and_details() {
+        let error = IggyError::MessagePayloadSchemaViolation(3, 7, "invalid type".to_string());
+        let details = error.as_details().unwrap();
+        let restored = IggyError::from_code_and_details(error.as_code(), &details);
+        assert!(matches!(
+            restored,
+            IggyError::MessagePayloadSchemaViolation(3, 7, ref reason) if reason == "invalid type"
+        ));
+    }
+
+    #[test]
+    fn error_without_details_should_be_restored_from_code() {
+        let error = IggyError::InvalidConsumerGroupName;
+        assert!(error.as_details().is_none());
+        assert!(matches!(
+            IggyError::from_code_and_details(error.as_code(), &[]),
+            IggyError::InvalidConsumerGroupName
+        ));
+    }
 }
@@ -25,7 +25,9 @@ use crate::identifier::Identifier;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
 use crate::messages::poll_messages::{PollMessages, PollingStrategy};
 use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::messages::PolledMessages;
+use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -51,6 +53,40 @@ impl MessageClient for HttpClient {
                     strategy: *strategy,
                     count,
                     auto_commit,
+                    max_wait: IggyDuration::default(),
+                },
+            )
+            .await?;
+        let messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(messages)
+    }
+
+    async fn poll_messages_with_wait(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit,
+                    max_wait,
                 },
             )
             .await?;
@@ -75,12 +111,39 @@ impl MessageClient for HttpClient {
                 topic_id: topic_id.clone(),
                 partitioning: partitioning.clone(),
                 messages: messages.to_vec(),
+                report_offsets: false,
             },
         )
         .await?;
         Ok(())
     }
 
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &SendMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitioning: partitioning.clone(),
+                    messages: messages.to_vec(),
+                    report_offsets: true,
+                },
+            )
+            .await?;
+        let appended_messages = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(appended_messages)
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use crate::schemas::update_schema_validation::UpdateSchemaValidation;
+use async_trait::async_trait;
+
+const PATH: &str = "/schemas";
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        let response = self.get(&format!("{PATH}/{schema_id}")).await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let schema = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(schema))
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        let response = self
+            .get(&get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()))
+            .await?;
+        let schemas = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schemas)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &RegisterSchema {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    format,
+                    definition: definition.to_string(),
+                },
+            )
+            .await?;
+        let schema = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schema)
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/compatibility",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateSchemaCompatibility {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                compatibility,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_schema_validation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/validation",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &UpdateSchemaValidation {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                validation,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/schemas")
+}
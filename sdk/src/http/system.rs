@@ -20,18 +20,23 @@ use crate::client::SystemClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
+use crate::identifier::Identifier;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::config_reload_info::ConfigReloadInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
 use crate::system::get_snapshot::GetSnapshot;
+use crate::system::reload_config::ReloadConfig;
 use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
+const USERS: &str = "/users";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
+const CONFIG_RELOAD: &str = "/config/reload";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -74,6 +79,26 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        self.delete(&format!("{CLIENTS}/{client_id}")).await?;
+        Ok(())
+    }
+
+    async fn disconnect_user_clients(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.delete(&format!("{USERS}/{}{CLIENTS}", &user_id.as_cow_str()))
+            .await?;
+        Ok(())
+    }
+
+    async fn reload_config(&self) -> Result<ConfigReloadInfo, IggyError> {
+        let response = self.post(CONFIG_RELOAD, &ReloadConfig {}).await?;
+        let info = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(info)
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.get(PING).await?;
         Ok(())
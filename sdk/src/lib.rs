@@ -18,6 +18,8 @@
 
 pub mod args;
 pub mod binary;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod bytes_serializable;
 #[cfg(feature = "iggy-cli")]
 pub mod cli;
@@ -29,6 +31,7 @@ pub mod client_error;
 pub mod client_provider;
 #[allow(deprecated)]
 pub mod clients;
+pub mod codecs;
 pub mod command;
 pub mod compression;
 pub mod confirmation;
@@ -47,6 +50,7 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod schemas;
 pub mod segments;
 pub mod snapshot;
 pub mod stream_builder;
@@ -57,3 +61,4 @@ pub mod topics;
 pub mod users;
 pub mod utils;
 pub mod validatable;
+pub mod websocket;
@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GRANT_MESSAGE_CREDITS_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::fmt::Display;
+
+/// `GrantMessageCredits` command is used to allow the server to push more batches of messages for the subscription
+/// created with the limited number of credits, each pushed batch consuming a single credit.
+/// It has additional payload:
+/// - `subscription_id` - ID of the subscription created with `SubscribeMessages` on the same connection.
+/// - `credits` - number of the additional batches which can be pushed.
+#[derive(Debug, PartialEq)]
+pub struct GrantMessageCredits {
+    /// ID of the subscription created with `SubscribeMessages` on the same connection.
+    pub subscription_id: u32,
+    /// Number of the additional batches which can be pushed.
+    pub credits: u32,
+}
+
+impl Default for GrantMessageCredits {
+    fn default() -> Self {
+        Self {
+            subscription_id: 1,
+            credits: 1,
+        }
+    }
+}
+
+impl Command for GrantMessageCredits {
+    fn code(&self) -> u32 {
+        GRANT_MESSAGE_CREDITS_CODE
+    }
+}
+
+impl Validatable<IggyError> for GrantMessageCredits {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.credits == 0 {
+            return Err(IggyError::InvalidMessageCredits);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GrantMessageCredits {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u32_le(self.subscription_id);
+        bytes.put_u32_le(self.credits);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let credits = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(GrantMessageCredits {
+            subscription_id,
+            credits,
+        })
+    }
+}
+
+impl Display for GrantMessageCredits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.subscription_id, self.credits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = GrantMessageCredits {
+            subscription_id: 7,
+            credits: 3,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = GrantMessageCredits::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn zero_credits_should_be_invalid() {
+        let command = GrantMessageCredits {
+            subscription_id: 7,
+            credits: 0,
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -17,8 +17,11 @@
  */
 
 pub mod flush_unsaved_buffer;
+pub mod grant_message_credits;
 pub mod poll_messages;
 pub mod send_messages;
+pub mod subscribe_messages;
+pub mod unsubscribe_messages;
 
 const MAX_HEADERS_SIZE: u32 = 100 * 1000;
 pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1000 * 1000;
@@ -21,6 +21,7 @@ use crate::command::{Command, POLL_MESSAGES_CODE};
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use crate::validatable::Validatable;
@@ -39,6 +40,8 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `max_wait` - how long the server may wait for the messages to be appended when there are none to poll (zero means no waiting).
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -62,6 +65,11 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    /// How long the server may wait for the messages to be appended when there are none to poll, zero means no waiting.
+    /// The server caps it with its own `system.polling.max_wait` setting.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub max_wait: IggyDuration,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -86,6 +94,7 @@ pub struct PollingStrategy {
 
 /// `PollingKind` is an enum which specifies from where to start polling messages and is used by `PollingStrategy`.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PollingKind {
     #[default]
@@ -111,6 +120,7 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            max_wait: IggyDuration::default(),
         }
     }
 }
@@ -266,6 +276,7 @@ impl BytesSerializable for PollMessages {
             &self.strategy,
             self.count,
             self.auto_commit,
+            self.max_wait,
         )
     }
 
@@ -313,6 +324,16 @@ impl BytesSerializable for PollMessages {
         );
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        position += 13;
+        // The maximum waiting time is optional, so that the requests of the older clients are still accepted.
+        let max_wait = match bytes.get(position..position + 8) {
+            Some(max_wait) => IggyDuration::from(u64::from_le_bytes(
+                max_wait
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            )),
+            None => IggyDuration::default(),
+        };
         let command = PollMessages {
             consumer,
             stream_id,
@@ -321,12 +342,14 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            max_wait,
         };
         Ok(command)
     }
 }
 
 // This method is used by the new version of `IggyClient` to serialize `PollMessages` without cloning the args.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn as_bytes(
     stream_id: &Identifier,
     topic_id: &Identifier,
@@ -335,13 +358,14 @@ pub(crate) fn as_bytes(
     strategy: &PollingStrategy,
     count: u32,
     auto_commit: bool,
+    max_wait: IggyDuration,
 ) -> Bytes {
     let consumer_bytes = consumer.to_bytes();
     let stream_id_bytes = stream_id.to_bytes();
     let topic_id_bytes = topic_id.to_bytes();
     let strategy_bytes = strategy.to_bytes();
     let mut bytes = BytesMut::with_capacity(
-        9 + consumer_bytes.len()
+        17 + consumer_bytes.len()
             + stream_id_bytes.len()
             + topic_id_bytes.len()
             + strategy_bytes.len(),
@@ -361,6 +385,7 @@ pub(crate) fn as_bytes(
     } else {
         bytes.put_u8(0);
     }
+    bytes.put_u64_le(max_wait.as_micros());
 
     bytes.freeze()
 }
@@ -369,14 +394,15 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            auto_commit_to_string(self.auto_commit),
+            self.max_wait.as_micros()
         )
     }
 }
@@ -433,6 +459,7 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            max_wait: IggyDuration::from(5_000_000),
         };
 
         let bytes = command.to_bytes();
@@ -459,6 +486,7 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let max_wait = u64::from_le_bytes(bytes[position + 13..position + 21].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -468,6 +496,7 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(max_wait, command.max_wait.as_micros());
     }
 
     #[test]
@@ -479,6 +508,7 @@ mod tests {
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
         let auto_commit = 1u8;
+        let max_wait = 5_000_000u64;
 
         let consumer_bytes = consumer.to_bytes();
         let stream_id_bytes = stream_id.to_bytes();
@@ -497,6 +527,7 @@ mod tests {
         bytes.put_slice(&strategy_bytes);
         bytes.put_u32_le(count);
         bytes.put_u8(auto_commit);
+        bytes.put_u64_le(max_wait);
 
         let command = PollMessages::from_bytes(bytes.freeze());
         assert!(command.is_ok());
@@ -511,5 +542,16 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.max_wait.as_micros(), max_wait);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_max_wait() {
+        let command = PollMessages::default();
+        let bytes = command.to_bytes();
+
+        let command = PollMessages::from_bytes(bytes.slice(..bytes.len() - 8)).unwrap();
+        assert_eq!(command.max_wait, IggyDuration::default());
+        assert_eq!(command.count, default_count());
     }
 }
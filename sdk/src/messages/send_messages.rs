@@ -17,7 +17,7 @@
  */
 
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{Command, SEND_MESSAGES_CODE};
+use crate::command::{Command, SEND_MESSAGES_CODE, SEND_MESSAGES_WITH_OFFSETS_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
@@ -44,7 +44,9 @@ const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
 /// - `messages` - collection of messages to be sent.
+/// - `report_offsets` - whether the partition ID and the offsets of the appended messages should be returned.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct SendMessages {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -56,6 +58,9 @@ pub struct SendMessages {
     pub partitioning: Partitioning,
     /// Collection of messages to be sent.
     pub messages: Vec<Message>,
+    /// Whether the partition ID and the offsets of the appended messages should be returned.
+    #[serde(default)]
+    pub report_offsets: bool,
 }
 
 /// `Partitioning` is used to specify to which partition the messages should be sent.
@@ -65,6 +70,7 @@ pub struct SendMessages {
 /// - `MessagesKey` - the partition ID is calculated by the server using the hash of the provided messages key.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Partitioning {
     /// The kind of partitioning.
     pub kind: PartitioningKind,
@@ -72,6 +78,7 @@ pub struct Partitioning {
     /// The length of the value payload.
     pub length: u8,
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     /// The binary value payload.
     pub value: Vec<u8>,
 }
@@ -91,6 +98,7 @@ impl Hash for Partitioning {
 /// - `headers` - optional collection of headers.
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Message {
     /// Unique message ID, if not specified by the client (has value = 0), it will be generated by the server.
     #[serde(default = "default_message_id")]
@@ -99,6 +107,7 @@ pub struct Message {
     /// Length of the payload.
     pub length: u32,
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     /// Binary message payload.
     pub payload: Bytes,
     /// Optional collection of headers.
@@ -107,6 +116,7 @@ pub struct Message {
 
 /// `PartitioningKind` is an enum which specifies the kind of partitioning and is used by `Partitioning`.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PartitioningKind {
     /// The partition ID is calculated by the server using the round-robin algorithm.
@@ -135,6 +145,7 @@ impl Default for SendMessages {
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
             messages: vec![Message::default()],
+            report_offsets: false,
         }
     }
 }
@@ -229,7 +240,11 @@ impl Sizeable for Partitioning {
 
 impl Command for SendMessages {
     fn code(&self) -> u32 {
-        SEND_MESSAGES_CODE
+        if self.report_offsets {
+            SEND_MESSAGES_WITH_OFFSETS_CODE
+        } else {
+            SEND_MESSAGES_CODE
+        }
     }
 }
 
@@ -530,6 +545,7 @@ impl BytesSerializable for SendMessages {
             topic_id,
             partitioning: key,
             messages,
+            report_offsets: false,
         };
         Ok(command)
     }
@@ -594,6 +610,7 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
             messages,
+            report_offsets: false,
         };
 
         let bytes = command.to_bytes();
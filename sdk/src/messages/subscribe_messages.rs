@@ -0,0 +1,210 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SUBSCRIBE_MESSAGES_CODE};
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{self, PollMessages, PollingStrategy};
+use crate::utils::duration::IggyDuration;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::fmt::Display;
+
+/// The status of the frames pushed by the server for the subscriptions, distinguishing them from the command responses,
+/// as it can't collide with any error code. The frame has the same layout as the response to the pipelined request,
+/// that is the status, the payload length, the subscription ID in place of the correlation ID, and the payload being
+/// the polled messages, serialized in the same way as for `PollMessages`. Over QUIC, each frame is sent on its own
+/// unidirectional stream opened by the server.
+pub const PUSHED_MESSAGES_STATUS: u32 = u32::MAX;
+
+/// The length of the payload of the last frame pushed for the subscription ended by the server, as the messages
+/// can't be polled anymore (e.g. the topic has been deleted). The payload is the code of the error which ended it,
+/// thus it can't be mistaken for the polled messages, which take at least 16 bytes.
+pub const SUBSCRIPTION_ENDED_PAYLOAD_LENGTH: usize = 4;
+
+/// `SubscribeMessages` command is used to subscribe to the messages of a topic in a stream,
+/// which are then pushed by the server as they are appended, instead of being polled by the client.
+/// It's available only for the binary transports (TCP, QUIC and WebSocket), as the messages are pushed over the same connection.
+/// It has additional payload:
+/// - `subscription_id` - unique (per connection) subscription ID chosen by the client, included in every pushed batch of messages.
+/// - `credits` - number of the batches which can be pushed before more credits are granted with `GrantMessageCredits`, zero means unlimited.
+/// - `consumer` - consumer which will receive messages. Either regular consumer or consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID from which messages will be pushed. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+/// - `strategy` - polling strategy which specifies from where to start pushing messages.
+/// - `count` - maximum number of messages in a single pushed batch.
+/// - `auto_commit` - whether to commit offset on the server automatically after pushing the messages.
+#[derive(Debug, PartialEq)]
+pub struct SubscribeMessages {
+    /// Unique (per connection) subscription ID chosen by the client.
+    pub subscription_id: u32,
+    /// Number of the batches which can be pushed before more credits are granted with `GrantMessageCredits`, zero means unlimited.
+    pub credits: u32,
+    /// Consumer which will receive messages. Either regular consumer or consumer group.
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    pub topic_id: Identifier,
+    /// Partition ID from which messages will be pushed. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+    pub partition_id: Option<u32>,
+    /// Polling strategy which specifies from where to start pushing messages.
+    pub strategy: PollingStrategy,
+    /// Maximum number of messages in a single pushed batch.
+    pub count: u32,
+    /// Whether to commit offset on the server automatically after pushing the messages.
+    pub auto_commit: bool,
+}
+
+impl Default for SubscribeMessages {
+    fn default() -> Self {
+        let poll_messages = PollMessages::default();
+        Self {
+            subscription_id: 1,
+            credits: 0,
+            consumer: poll_messages.consumer,
+            stream_id: poll_messages.stream_id,
+            topic_id: poll_messages.topic_id,
+            partition_id: poll_messages.partition_id,
+            strategy: poll_messages.strategy,
+            count: poll_messages.count,
+            auto_commit: poll_messages.auto_commit,
+        }
+    }
+}
+
+impl Command for SubscribeMessages {
+    fn code(&self) -> u32 {
+        SUBSCRIBE_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for SubscribeMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SubscribeMessages {
+    fn to_bytes(&self) -> Bytes {
+        let poll_messages_bytes = poll_messages::as_bytes(
+            &self.stream_id,
+            &self.topic_id,
+            self.partition_id,
+            &self.consumer,
+            &self.strategy,
+            self.count,
+            self.auto_commit,
+            IggyDuration::default(),
+        );
+        let mut bytes = BytesMut::with_capacity(8 + poll_messages_bytes.len());
+        bytes.put_u32_le(self.subscription_id);
+        bytes.put_u32_le(self.credits);
+        bytes.put_slice(&poll_messages_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let credits = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let poll_messages = PollMessages::from_bytes(bytes.slice(8..))?;
+        Ok(SubscribeMessages {
+            subscription_id,
+            credits,
+            consumer: poll_messages.consumer,
+            stream_id: poll_messages.stream_id,
+            topic_id: poll_messages.topic_id,
+            partition_id: poll_messages.partition_id,
+            strategy: poll_messages.strategy,
+            count: poll_messages.count,
+            auto_commit: poll_messages.auto_commit,
+        })
+    }
+}
+
+impl Display for SubscribeMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.subscription_id,
+            self.credits,
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id.unwrap_or(0),
+            self.strategy,
+            self.count,
+            self.auto_commit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let command = SubscribeMessages {
+            subscription_id: 7,
+            credits: 5,
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::offset(2),
+            count: 3,
+            auto_commit: true,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = SubscribeMessages::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn zero_count_should_be_invalid() {
+        let command = SubscribeMessages {
+            count: 0,
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,76 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UNSUBSCRIBE_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use std::fmt::Display;
+
+/// `UnsubscribeMessages` command is used to stop the messages being pushed for the subscription.
+/// It has additional payload:
+/// - `subscription_id` - ID of the subscription created with `SubscribeMessages` on the same connection.
+#[derive(Debug, PartialEq)]
+pub struct UnsubscribeMessages {
+    /// ID of the subscription created with `SubscribeMessages` on the same connection.
+    pub subscription_id: u32,
+}
+
+impl Default for UnsubscribeMessages {
+    fn default() -> Self {
+        Self { subscription_id: 1 }
+    }
+}
+
+impl Command for UnsubscribeMessages {
+    fn code(&self) -> u32 {
+        UNSUBSCRIBE_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for UnsubscribeMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UnsubscribeMessages {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(&self.subscription_id.to_le_bytes())
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(UnsubscribeMessages { subscription_id })
+    }
+}
+
+impl Display for UnsubscribeMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subscription_id)
+    }
+}
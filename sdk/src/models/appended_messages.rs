@@ -0,0 +1,48 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// `AppendedMessages` represents the batch of messages appended to the partition by the `SendMessages` command.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition the messages were appended to.
+/// - `base_offset`: the offset of the first appended message.
+/// - `last_offset`: the offset of the last appended message.
+/// - `base_timestamp`: the timestamp of the first appended message.
+/// - `last_timestamp`: the timestamp of the last appended message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AppendedMessages {
+    /// The unique identifier of the partition the messages were appended to.
+    pub partition_id: u32,
+    /// The offset of the first appended message.
+    pub base_offset: u64,
+    /// The offset of the last appended message.
+    pub last_offset: u64,
+    /// The timestamp of the first appended message.
+    pub base_timestamp: u64,
+    /// The timestamp of the last appended message.
+    pub last_timestamp: u64,
+}
+
+impl AppendedMessages {
+    /// Returns the number of the appended messages.
+    pub fn count(&self) -> u64 {
+        self.last_offset - self.base_offset + 1
+    }
+}
@@ -26,6 +26,7 @@ use serde::{Deserialize, Serialize};
 /// - `transport`: the transport protocol used by the client.
 /// - `consumer_groups_count`: the number of consumer groups the client is part of.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ClientInfo {
     /// The unique identifier of the client.
     pub client_id: u32,
@@ -48,6 +49,7 @@ pub struct ClientInfo {
 /// - `consumer_groups_count`: the number of consumer groups the client is part of.
 /// - `consumer_groups`: the collection of consumer groups the client is part of.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ClientInfoDetails {
     /// The unique identifier of the client.
     pub client_id: u32,
@@ -69,6 +71,7 @@ pub struct ClientInfoDetails {
 /// - `topic_id`: the unique identifier (numeric) of the topic.
 /// - `group_id`: the unique identifier (numeric) of the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroupInfo {
     /// The unique identifier (numeric) of the stream.
     pub stream_id: u32,
@@ -24,6 +24,7 @@ use serde::{Deserialize, Serialize};
 /// - `current_offset`: the current offset of the partition.
 /// - `stored_offset`: the stored offset by the consumer in the partition.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerOffsetInfo {
     /// The unique identifier of the partition.
     pub partition_id: u32,
@@ -30,6 +30,7 @@ use std::str::FromStr;
 
 /// Represents a header key with a unique name. The name is case-insensitive and wraps a string.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HeaderKey(String);
 
 impl HeaderKey {
@@ -78,16 +79,19 @@ impl TryFrom<&str> for HeaderKey {
 /// - `value`: the value of the header.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HeaderValue {
     /// The kind of the header value.
     pub kind: HeaderKind,
     /// The binary value of the header payload.
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub value: Bytes,
 }
 
 /// Represents the kind of a header value.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum HeaderKind {
     Raw,
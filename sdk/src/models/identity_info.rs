@@ -24,8 +24,10 @@ use serde::{Deserialize, Serialize};
 /// - `user_id`: the unique identifier (numeric) of the user.
 /// - `access_token`: the optional access token, used only by HTTP transport.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct IdentityInfo {
     /// The unique identifier (numeric) of the user.
+    #[cfg_attr(feature = "openapi", schema(value_type = u32))]
     pub user_id: UserId,
     /// The optional tokens, used only by HTTP transport.
     pub access_token: Option<TokenInfo>,
@@ -36,6 +38,7 @@ pub struct IdentityInfo {
 /// - `token`: the value of token.
 /// - `expiry`: the expiry of token.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TokenInfo {
     /// The value of token.
     pub token: String,
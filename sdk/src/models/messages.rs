@@ -39,6 +39,7 @@ pub const POLLED_MESSAGE_METADATA: u32 = 8 + 1 + 8 + 4;
 /// - `current_offset`: the current offset of the partition.
 /// - `messages`: the collection of messages.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PolledMessages {
     /// The identifier of the partition. If it's '0', then there's no partition assigned to the consumer group member.
     pub partition_id: u32,
@@ -59,7 +60,8 @@ pub struct PolledMessages {
 /// - `length`: the length of the payload.
 /// - `payload`: the binary payload of the message.
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PolledMessage {
     /// The offset of the message.
     pub offset: u64,
@@ -78,11 +80,13 @@ pub struct PolledMessage {
     pub length: IggyByteSize,
     /// The binary payload of the message.
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub payload: Bytes,
 }
 
 /// The state of the message, currently only the `Available` state is used.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum MessageState {
     /// The message is available.
@@ -16,7 +16,9 @@
  * under the License.
  */
 
+pub mod appended_messages;
 pub mod client_info;
+pub mod config_reload_info;
 pub mod consumer_group;
 pub mod consumer_offset_info;
 pub mod header;
@@ -25,6 +27,7 @@ pub mod messages;
 pub mod partition;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod schema;
 pub mod snapshot;
 pub mod stats;
 pub mod stream;
@@ -28,18 +28,25 @@ use std::fmt::Display;
 /// Global permissions are applied to all streams.
 /// Stream permissions are applied to a specific stream.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Permissions {
     /// Global permissions are applied to all streams.
     pub global: GlobalPermissions,
 
     /// Stream permissions are applied to a specific stream.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<HashMap<String, StreamPermissions>>))]
     pub streams: Option<AHashMap<u32, StreamPermissions>>,
 }
 
 /// `GlobalPermissions` are applied to all streams without a need to specify them one by one in the `streams` field.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct GlobalPermissions {
     /// `manage_servers` permission allows to manage the servers and includes all the permissions of `read_servers`.
+    /// Additionally, the following methods can be invoked:
+    /// - disconnect_client
+    /// - disconnect_user_clients
+    /// - reload_config
     pub manage_servers: bool,
 
     /// `read_servers` permission allows to invoke the following methods:
@@ -106,6 +113,7 @@ pub struct GlobalPermissions {
 /// `StreamPermissions` are applied to a specific stream and its all topics. If you want to define granular permissions for each topic, use the `topics` field.
 /// These permissions do not override the global permissions, but extend them, and allow more granular control over the streams and the users that can access them.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StreamPermissions {
     /// `manage_stream` permission allows to manage the stream and includes all the permissions of `read_stream`.
     /// Also, it allows to manage all the topics of a stream, thus it has all the permissions of `manage_topics`.
@@ -140,11 +148,13 @@ pub struct StreamPermissions {
     pub send_messages: bool,
 
     /// The `topics` field allows to define the granular permissions for each topic of a stream.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<HashMap<String, TopicPermissions>>))]
     pub topics: Option<AHashMap<u32, TopicPermissions>>,
 }
 
 /// `TopicPermissions` are applied to a specific topic of a stream. This is the lowest level of permissions.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopicPermissions {
     /// `manage_topic` permission allows to manage the topic and includes all the permissions of `read_topic`.
     pub manage_topic: bool,
@@ -0,0 +1,324 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::timestamp::IggyTimestamp;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `SchemaFormat` represents the format of the schema definition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaFormat {
+    /// The Apache Avro schema in the JSON format.
+    Avro,
+    /// The JSON Schema.
+    JsonSchema,
+    /// The Protocol Buffers definition in the `.proto` format.
+    Protobuf,
+}
+
+impl FromStr for SchemaFormat {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "avro" => Ok(SchemaFormat::Avro),
+            "json_schema" => Ok(SchemaFormat::JsonSchema),
+            "protobuf" => Ok(SchemaFormat::Protobuf),
+            _ => Err(IggyError::InvalidSchemaFormat),
+        }
+    }
+}
+
+impl Display for SchemaFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaFormat::Avro => write!(f, "avro"),
+            SchemaFormat::JsonSchema => write!(f, "json_schema"),
+            SchemaFormat::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl SchemaFormat {
+    /// Returns the code of the schema format.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaFormat::Avro => 1,
+            SchemaFormat::JsonSchema => 2,
+            SchemaFormat::Protobuf => 3,
+        }
+    }
+
+    /// Returns the schema format from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaFormat::Avro),
+            2 => Ok(SchemaFormat::JsonSchema),
+            3 => Ok(SchemaFormat::Protobuf),
+            _ => Err(IggyError::InvalidSchemaFormat),
+        }
+    }
+}
+
+/// `SchemaCompatibility` represents the rule checked when registering a new version of the topic schema.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    /// The new version is not checked against the previous one.
+    None,
+    /// The consumers using the new version can read the data written with the previous one.
+    #[default]
+    Backward,
+    /// The consumers using the previous version can read the data written with the new one.
+    Forward,
+    /// Both backward and forward compatibility.
+    Full,
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility),
+        }
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl SchemaCompatibility {
+    /// Returns the code of the schema compatibility.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 1,
+            SchemaCompatibility::Backward => 2,
+            SchemaCompatibility::Forward => 3,
+            SchemaCompatibility::Full => 4,
+        }
+    }
+
+    /// Returns the schema compatibility from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaCompatibility::None),
+            2 => Ok(SchemaCompatibility::Backward),
+            3 => Ok(SchemaCompatibility::Forward),
+            4 => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility),
+        }
+    }
+
+    /// Returns true if the data written with the previous versions can be read with the new one.
+    pub fn is_backward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Backward | SchemaCompatibility::Full
+        )
+    }
+
+    /// Returns true if the data written with the new version can be read with the previous ones.
+    pub fn is_forward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Forward | SchemaCompatibility::Full
+        )
+    }
+}
+
+/// `SchemaValidation` represents the validation of the message payloads appended to the topic,
+/// against the registered schema referenced by the message schema ID header, or the latest one.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum SchemaValidation {
+    /// The payloads are not validated.
+    #[default]
+    None,
+    /// The whole batch is rejected, if any payload is invalid.
+    Reject,
+    /// The invalid payloads are appended to the rejection topic, and the valid ones to the topic.
+    Route {
+        /// The unique identifier (numeric) of the rejection stream.
+        stream_id: u32,
+        /// The unique identifier (numeric) of the rejection topic.
+        topic_id: u32,
+    },
+}
+
+impl SchemaValidation {
+    /// The size of the serialized schema validation: the mode code, and the rejection stream and topic IDs.
+    pub const SIZE: usize = 9;
+
+    /// Returns the code of the schema validation mode.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaValidation::None => 1,
+            SchemaValidation::Reject => 2,
+            SchemaValidation::Route { .. } => 3,
+        }
+    }
+
+    /// Returns true if the payloads are validated.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, SchemaValidation::None)
+    }
+}
+
+impl Display for SchemaValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaValidation::None => write!(f, "none"),
+            SchemaValidation::Reject => write!(f, "reject"),
+            SchemaValidation::Route {
+                stream_id,
+                topic_id,
+            } => write!(f, "route|{stream_id}|{topic_id}"),
+        }
+    }
+}
+
+impl FromStr for SchemaValidation {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        match parts.as_slice() {
+            ["none"] => Ok(SchemaValidation::None),
+            ["reject"] => Ok(SchemaValidation::Reject),
+            ["route", stream_id, topic_id] => Ok(SchemaValidation::Route {
+                stream_id: stream_id
+                    .parse()
+                    .map_err(|_| IggyError::InvalidSchemaValidation)?,
+                topic_id: topic_id
+                    .parse()
+                    .map_err(|_| IggyError::InvalidSchemaValidation)?,
+            }),
+            _ => Err(IggyError::InvalidSchemaValidation),
+        }
+    }
+}
+
+impl BytesSerializable for SchemaValidation {
+    fn to_bytes(&self) -> Bytes {
+        let (stream_id, topic_id) = match self {
+            SchemaValidation::Route {
+                stream_id,
+                topic_id,
+            } => (*stream_id, *topic_id),
+            _ => (0, 0),
+        };
+        let mut bytes = BytesMut::with_capacity(Self::SIZE);
+        bytes.put_u8(self.as_code());
+        bytes.put_u32_le(stream_id);
+        bytes.put_u32_le(topic_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() != Self::SIZE {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
+        let topic_id = u32::from_le_bytes(bytes[5..9].try_into().unwrap());
+        match bytes[0] {
+            1 => Ok(SchemaValidation::None),
+            2 => Ok(SchemaValidation::Reject),
+            3 => Ok(SchemaValidation::Route {
+                stream_id,
+                topic_id,
+            }),
+            _ => Err(IggyError::InvalidSchemaValidation),
+        }
+    }
+}
+
+/// `Schema` represents the version of the schema registered for the topic.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the schema, across all the topics.
+/// - `stream_id`: the unique identifier (numeric) of the stream.
+/// - `topic_id`: the unique identifier (numeric) of the topic.
+/// - `version`: the version of the schema for the topic, starting from 1.
+/// - `format`: the format of the schema definition.
+/// - `created_at`: the timestamp when the schema was registered.
+/// - `definition`: the schema definition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Schema {
+    /// The unique identifier (numeric) of the schema, across all the topics.
+    pub id: u32,
+    /// The unique identifier (numeric) of the stream.
+    pub stream_id: u32,
+    /// The unique identifier (numeric) of the topic.
+    pub topic_id: u32,
+    /// The version of the schema for the topic, starting from 1.
+    pub version: u32,
+    /// The format of the schema definition.
+    pub format: SchemaFormat,
+    /// The timestamp when the schema was registered.
+    pub created_at: IggyTimestamp,
+    /// The schema definition.
+    pub definition: String,
+}
+
+/// `TopicSchemas` represents all the versions of the schema registered for the topic.
+/// It consists of the following fields:
+/// - `stream_id`: the unique identifier (numeric) of the stream.
+/// - `topic_id`: the unique identifier (numeric) of the topic.
+/// - `compatibility`: the compatibility rule checked when registering a new version.
+/// - `validation`: the validation of the message payloads appended to the topic.
+/// - `schemas`: the collection of schema versions, ordered by the version.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TopicSchemas {
+    /// The unique identifier (numeric) of the stream.
+    pub stream_id: u32,
+    /// The unique identifier (numeric) of the topic.
+    pub topic_id: u32,
+    /// The compatibility rule checked when registering a new version.
+    pub compatibility: SchemaCompatibility,
+    /// The validation of the message payloads appended to the topic.
+    pub validation: SchemaValidation,
+    /// The collection of schema versions, ordered by the version.
+    pub schemas: Vec<Schema>,
+}
+
+impl TopicSchemas {
+    /// Returns the latest version of the schema, if any.
+    pub fn latest(&self) -> Option<&Schema> {
+        self.schemas.last()
+    }
+}
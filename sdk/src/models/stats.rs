@@ -22,6 +22,7 @@ use std::collections::HashMap;
 
 /// `Stats` represents the statistics and details of the server and running process.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Stats {
     /// The unique identifier of the process.
     pub process_id: u32,
@@ -73,6 +74,7 @@ pub struct Stats {
     pub iggy_server_semver: Option<u32>,
     /// Cache metrics per partition
     #[serde(with = "cache_metrics_serializer")]
+    #[cfg_attr(feature = "openapi", schema(value_type = HashMap<String, CacheMetrics>))]
     pub cache_metrics: HashMap<CacheMetricsKey, CacheMetrics>,
 }
 
@@ -95,6 +97,7 @@ impl CacheMetricsKey {
 
 /// Cache metrics for a specific partition
 #[derive(Debug, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CacheMetrics {
     /// Number of cache hits
     pub hits: u64,
@@ -36,6 +36,7 @@ use serde::{Deserialize, Serialize};
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Topic {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
@@ -73,6 +74,7 @@ pub struct Topic {
 /// - `partitions_count`: the total number of partitions in the topic.
 /// - `partitions`: the collection of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopicDetails {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
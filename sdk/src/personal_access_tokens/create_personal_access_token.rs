@@ -32,6 +32,7 @@ use std::str::from_utf8;
 /// - `name` - unique name of the token, must be between 3 and 30 characters long.
 /// - `expiry` - expiry of the token.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreatePersonalAccessToken {
     /// Unique name of the token, must be between 3 and 30 characters long.
     pub name: String,
@@ -30,6 +30,7 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `token` - personal access token
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct LoginWithPersonalAccessToken {
     /// Personal access token
     pub token: String,
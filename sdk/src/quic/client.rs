@@ -17,11 +17,20 @@
  */
 
 use crate::binary::binary_client::BinaryClient;
+use crate::binary::servers::ServerList;
+use crate::binary::session::{rejoin_consumer_groups, ClientSession};
+use crate::binary::subscriptions::{
+    MessageSubscription, MessageSubscriptions, DEFAULT_SUBSCRIPTION_BUFFER_SIZE,
+};
 use crate::binary::{BinaryTransport, ClientState};
 use crate::client::{AutoLogin, Client, Credentials, PersonalAccessTokenClient, UserClient};
-use crate::command::Command;
+use crate::command::{Command, ERROR_DETAILS_FLAG};
+use crate::consumer::Consumer;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::subscribe_messages::PUSHED_MESSAGES_STATUS;
 use crate::quic::config::QuicClientConfig;
 use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
@@ -36,13 +45,15 @@ use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
 use rustls::{DigitallySignedStruct, Error, SignatureScheme};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tracing::{error, info, trace, warn};
 
 const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const PUSHED_MESSAGES_INITIAL_BYTES_LENGTH: usize = 12;
 const NAME: &str = "Iggy";
 
 /// QUIC client for interacting with the Iggy API.
@@ -51,10 +62,14 @@ pub struct QuicClient {
     pub(crate) endpoint: Endpoint,
     pub(crate) connection: Mutex<Option<Connection>>,
     pub(crate) config: Arc<QuicClientConfig>,
-    pub(crate) server_address: SocketAddr,
     pub(crate) state: Mutex<ClientState>,
+    servers: ServerList<SocketAddr>,
+    current_server: Mutex<Option<SocketAddr>>,
+    session: ClientSession,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
+    subscriptions: Arc<MessageSubscriptions>,
+    pushed_messages_reader: Mutex<Option<JoinHandle<()>>>,
 }
 
 unsafe impl Send for QuicClient {}
@@ -110,7 +125,10 @@ impl BinaryTransport for QuicClient {
         let error = result.unwrap_err();
         if !matches!(
             error,
-            IggyError::Disconnected | IggyError::EmptyResponse | IggyError::Unauthenticated
+            IggyError::Disconnected
+                | IggyError::EmptyResponse
+                | IggyError::Unauthenticated
+                | IggyError::ServerShuttingDown
         ) {
             return Err(error);
         }
@@ -119,10 +137,20 @@ impl BinaryTransport for QuicClient {
             return Err(IggyError::Disconnected);
         }
 
+        let server_address = *self.current_server.lock().await;
+        if let Some(server_address) = &server_address {
+            if error != IggyError::Unauthenticated {
+                self.servers.mark_failed(server_address);
+            }
+        }
+
         self.disconnect().await?;
         info!(
             "Reconnecting to the server: {}, by client: {}",
-            self.config.server_address, self.config.client_address
+            server_address
+                .map(|address| address.to_string())
+                .unwrap_or_else(|| self.config.server_address.clone()),
+            self.config.client_address
         );
         self.connect().await?;
         self.send_raw(code, payload).await
@@ -137,6 +165,10 @@ impl BinaryTransport for QuicClient {
     fn get_heartbeat_interval(&self) -> IggyDuration {
         self.config.heartbeat_interval
     }
+
+    fn session(&self) -> &ClientSession {
+        &self.session
+    }
 }
 
 impl BinaryClient for QuicClient {}
@@ -162,14 +194,8 @@ impl QuicClient {
 
     /// Create a new QUIC client for the provided configuration.
     pub fn create(config: Arc<QuicClientConfig>) -> Result<Self, IggyError> {
-        let server_address = config
-            .server_address
-            .parse::<SocketAddr>()
-            .map_err(|error| {
-                error!("Invalid server address: {error}");
-                IggyError::InvalidServerAddress
-            })?;
-        let client_address = if server_address.is_ipv6()
+        let servers = ServerList::<SocketAddr>::parse(&config.server_address)?;
+        let client_address = if servers.primary().is_ipv6()
             && config.client_address == QuicClientConfig::default().client_address
         {
             "[::1]:0"
@@ -195,14 +221,72 @@ impl QuicClient {
         Ok(Self {
             config,
             endpoint,
-            server_address,
+            servers,
+            current_server: Mutex::new(None),
+            session: ClientSession::default(),
             connection: Mutex::new(None),
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
+            subscriptions: Arc::new(MessageSubscriptions::new(DEFAULT_SUBSCRIPTION_BUFFER_SIZE)),
+            pushed_messages_reader: Mutex::new(None),
         })
     }
 
+    /// Subscribes to the messages of the topic, which are then pushed by the server as they are appended.
+    /// The returned stream yields the batches of at most `count` messages, starting from the provided strategy.
+    /// With non-zero `credits`, the server pushes only that many batches until more credits are granted.
+    /// The subscription is bound to the current connection and ends once the connection is lost.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        credits: u32,
+    ) -> Result<MessageSubscription, IggyError> {
+        self.subscriptions
+            .subscribe(
+                self,
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                strategy,
+                count,
+                auto_commit,
+                credits,
+            )
+            .await
+    }
+
+    /// Cancels the subscription, after which its stream ends.
+    pub async fn unsubscribe_messages(&self, subscription_id: u32) -> Result<(), IggyError> {
+        self.subscriptions.unsubscribe(self, subscription_id).await
+    }
+
+    /// Allows the server to push the given number of additional batches for the subscription created with the credits.
+    pub async fn grant_message_credits(
+        &self,
+        subscription_id: u32,
+        credits: u32,
+    ) -> Result<(), IggyError> {
+        self.subscriptions
+            .grant_credits(self, subscription_id, credits)
+            .await
+    }
+
+    async fn stop_pushed_messages_reader(&self) {
+        if let Some(reader) = self.pushed_messages_reader.lock().await.take() {
+            reader.abort();
+        }
+        self.subscriptions.clear();
+    }
+
     async fn handle_response(&self, recv: &mut RecvStream) -> Result<Bytes, IggyError> {
         let buffer = recv
             .read_to_end(self.config.response_buffer_size as usize)
@@ -220,6 +304,11 @@ impl QuicClient {
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
+        let length = u32::from_le_bytes(
+            buffer[4..RESPONSE_INITIAL_BYTES_LENGTH]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
         if status != 0 {
             error!(
                 "Received an invalid response with status: {} ({}).",
@@ -227,14 +316,12 @@ impl QuicClient {
                 IggyError::from_code_as_string(status)
             );
 
-            return Err(IggyError::from_code(status));
+            let details = buffer
+                .get(RESPONSE_INITIAL_BYTES_LENGTH..RESPONSE_INITIAL_BYTES_LENGTH + length as usize)
+                .unwrap_or_default();
+            return Err(IggyError::from_code_and_details(status, details));
         }
 
-        let length = u32::from_le_bytes(
-            buffer[4..RESPONSE_INITIAL_BYTES_LENGTH]
-                .try_into()
-                .map_err(|_| IggyError::InvalidNumberEncoding)?,
-        );
         trace!("Status: OK. Response length: {}", length);
         if length <= 1 {
             return Ok(Bytes::new());
@@ -281,97 +368,119 @@ impl QuicClient {
         let mut retry_count = 0;
         let connection;
         let remote_address;
-        loop {
-            info!(
-                "{NAME} client is connecting to server: {}...",
-                self.config.server_address
-            );
-            let connection_result = self
-                .endpoint
-                .connect(self.server_address, &self.config.server_name)
-                .unwrap()
-                .await;
-
-            if connection_result.is_err() {
-                error!(
-                    "Failed to connect to server: {}",
-                    self.config.server_address
-                );
-                if !self.config.reconnection.enabled {
-                    warn!("Automatic reconnection is disabled.");
-                    return Err(IggyError::CannotEstablishConnection);
-                }
-
-                let unlimited_retries = self.config.reconnection.max_retries.is_none();
-                let max_retries = self.config.reconnection.max_retries.unwrap_or_default();
-                let max_retries_str =
-                    if let Some(max_retries) = self.config.reconnection.max_retries {
-                        max_retries.to_string()
-                    } else {
-                        "unlimited".to_string()
-                    };
-
-                let interval_str = self.config.reconnection.interval.as_human_time_string();
-                if unlimited_retries || retry_count < max_retries {
-                    retry_count += 1;
-                    info!(
-                        "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
-                        self.config.server_address,
-                    );
-                    sleep(self.config.reconnection.interval.get_duration()).await;
-                    continue;
+        'connection: loop {
+            for server_address in self.servers.candidates() {
+                info!("{NAME} client is connecting to server: {server_address}...");
+                let started_at = Instant::now();
+                let connection_result = match self
+                    .endpoint
+                    .connect(server_address, &self.config.server_name)
+                {
+                    Ok(connecting) => connecting.await.map_err(|error| {
+                        error!("Failed to establish QUIC connection: {error}");
+                        IggyError::CannotEstablishConnection
+                    }),
+                    Err(error) => {
+                        error!("Failed to start QUIC connection: {error}");
+                        Err(IggyError::CannotEstablishConnection)
+                    }
+                };
+
+                match connection_result {
+                    Ok(established_connection) => {
+                        self.servers
+                            .mark_connected(&server_address, started_at.elapsed());
+                        self.current_server.lock().await.replace(server_address);
+                        remote_address = established_connection.remote_address();
+                        connection = established_connection;
+                        break 'connection;
+                    }
+                    Err(_) => {
+                        error!("Failed to connect to server: {server_address}");
+                        self.servers.mark_failed(&server_address);
+                    }
                 }
+            }
 
+            if !self.config.reconnection.enabled {
+                warn!("Automatic reconnection is disabled.");
                 self.set_state(ClientState::Disconnected).await;
-                self.publish_event(DiagnosticEvent::Disconnected).await;
                 return Err(IggyError::CannotEstablishConnection);
             }
 
-            connection = connection_result.map_err(|error| {
-                error!("Failed to establish QUIC connection: {error}");
-                IggyError::CannotEstablishConnection
-            })?;
-            remote_address = connection.remote_address();
-            break;
+            let unlimited_retries = self.config.reconnection.max_retries.is_none();
+            let max_retries = self.config.reconnection.max_retries.unwrap_or_default();
+            let max_retries_str = if let Some(max_retries) = self.config.reconnection.max_retries {
+                max_retries.to_string()
+            } else {
+                "unlimited".to_string()
+            };
+
+            let interval_str = self.config.reconnection.interval.as_human_time_string();
+            if unlimited_retries || retry_count < max_retries {
+                retry_count += 1;
+                info!(
+                    "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
+                    self.config.server_address,
+                );
+                sleep(self.config.reconnection.interval.get_duration()).await;
+                continue;
+            }
+
+            self.set_state(ClientState::Disconnected).await;
+            self.publish_event(DiagnosticEvent::Disconnected).await;
+            return Err(IggyError::CannotEstablishConnection);
         }
 
         let now = IggyTimestamp::now();
         info!("{NAME} client has connected to server: {remote_address} at {now}",);
         self.set_state(ClientState::Connected).await;
+        self.stop_pushed_messages_reader().await;
+        self.pushed_messages_reader
+            .lock()
+            .await
+            .replace(tokio::spawn(read_pushed_messages(
+                connection.clone(),
+                self.subscriptions.clone(),
+                self.config.response_buffer_size as usize,
+            )));
         self.connection.lock().await.replace(connection);
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
 
-        match &self.config.auto_login {
-            AutoLogin::Disabled => {
-                info!("Automatic sign-in is disabled.");
-                Ok(())
+        let credentials = match &self.config.auto_login {
+            AutoLogin::Enabled(credentials) => credentials.clone(),
+            AutoLogin::Disabled => match self.session.credentials() {
+                Some(credentials) => credentials,
+                None => {
+                    info!("Automatic sign-in is disabled.");
+                    return Ok(());
+                }
+            },
+        };
+
+        info!(
+            "{NAME} client: {} is signing in...",
+            self.config.client_address
+        );
+        self.set_state(ClientState::Authenticating).await;
+        match &credentials {
+            Credentials::UsernamePassword(username, password) => {
+                self.login_user(username, password).await?;
+                self.publish_event(DiagnosticEvent::SignedIn).await;
+                info!("{NAME} client: {} has signed in with the user credentials, username: {username}", self.config.client_address);
             }
-            AutoLogin::Enabled(credentials) => {
+            Credentials::PersonalAccessToken(token) => {
+                self.login_with_personal_access_token(token).await?;
+                self.publish_event(DiagnosticEvent::SignedIn).await;
                 info!(
-                    "{NAME} client: {} is signing in...",
+                    "{NAME} client: {} has signed in with a personal access token.",
                     self.config.client_address
                 );
-                self.set_state(ClientState::Authenticating).await;
-                match credentials {
-                    Credentials::UsernamePassword(username, password) => {
-                        self.login_user(username, password).await?;
-                        self.publish_event(DiagnosticEvent::SignedIn).await;
-                        info!("{NAME} client: {} has signed in with the user credentials, username: {username}", self.config.client_address);
-                        Ok(())
-                    }
-                    Credentials::PersonalAccessToken(token) => {
-                        self.login_with_personal_access_token(token).await?;
-                        self.publish_event(DiagnosticEvent::SignedIn).await;
-                        info!(
-                            "{NAME} client: {} has signed in with a personal access token.",
-                            self.config.client_address
-                        );
-                        Ok(())
-                    }
-                }
             }
         }
+        rejoin_consumer_groups(self).await;
+        Ok(())
     }
 
     async fn shutdown(&self) -> Result<(), IggyError> {
@@ -380,6 +489,7 @@ impl QuicClient {
         }
 
         info!("Shutting down the {NAME} QUIC client.");
+        self.stop_pushed_messages_reader().await;
         let connection = self.connection.lock().await.take();
         if let Some(connection) = connection {
             connection.close(0u32.into(), b"");
@@ -402,6 +512,7 @@ impl QuicClient {
             self.config.client_address
         );
         self.set_state(ClientState::Disconnected).await;
+        self.stop_pushed_messages_reader().await;
         self.connection.lock().await.take();
         self.endpoint.wait_idle().await;
         self.publish_event(DiagnosticEvent::Disconnected).await;
@@ -438,8 +549,15 @@ impl QuicClient {
 
         let connection = self.connection.lock().await;
         if let Some(connection) = connection.as_ref() {
+            if let Some(error) = get_server_shutdown_error(connection) {
+                return Err(error);
+            }
+
             let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
             let (mut send, mut recv) = connection.open_bi().await.map_err(|error| {
+                if let Some(error) = get_server_shutdown_error(connection) {
+                    return error;
+                }
                 error!("Failed to open a bidirectional stream: {error}");
                 IggyError::QuicError
             })?;
@@ -450,10 +568,12 @@ impl QuicClient {
                     error!("Failed to write payload length: {error}");
                     IggyError::QuicError
                 })?;
-            send.write_all(&code.to_le_bytes()).await.map_err(|error| {
-                error!("Failed to write payload code: {error}");
-                IggyError::QuicError
-            })?;
+            send.write_all(&(code | ERROR_DETAILS_FLAG).to_le_bytes())
+                .await
+                .map_err(|error| {
+                    error!("Failed to write payload code: {error}");
+                    IggyError::QuicError
+                })?;
             send.write_all(&payload).await.map_err(|error| {
                 error!("Failed to write payload: {error}");
                 IggyError::QuicError
@@ -471,6 +591,63 @@ impl QuicClient {
     }
 }
 
+/// Accepts the unidirectional streams opened by the server, each carrying a single frame
+/// with the messages pushed for one of the subscriptions, until the connection is closed.
+async fn read_pushed_messages(
+    connection: Connection,
+    subscriptions: Arc<MessageSubscriptions>,
+    buffer_size: usize,
+) {
+    while let Ok(mut recv) = connection.accept_uni().await {
+        let frame = match recv.read_to_end(buffer_size).await {
+            Ok(frame) => Bytes::from(frame),
+            Err(error) => {
+                error!("Failed to read pushed messages: {error}");
+                continue;
+            }
+        };
+        if frame.len() < PUSHED_MESSAGES_INITIAL_BYTES_LENGTH {
+            error!("Received an invalid pushed messages frame.");
+            continue;
+        }
+
+        let status = u32::from_le_bytes(frame[..4].try_into().unwrap());
+        if status != PUSHED_MESSAGES_STATUS {
+            error!(
+                "Received an unexpected frame with status: {status} on a unidirectional stream."
+            );
+            continue;
+        }
+
+        let subscription_id = u32::from_le_bytes(
+            frame[RESPONSE_INITIAL_BYTES_LENGTH..PUSHED_MESSAGES_INITIAL_BYTES_LENGTH]
+                .try_into()
+                .unwrap(),
+        );
+        subscriptions
+            .route(
+                subscription_id,
+                frame.slice(PUSHED_MESSAGES_INITIAL_BYTES_LENGTH..),
+            )
+            .await;
+    }
+    subscriptions.clear();
+}
+
+/// Returns `IggyError::ServerShuttingDown` if the connection has been closed by the server
+/// during its graceful shutdown, so that the request can be retried once reconnected.
+fn get_server_shutdown_error(connection: &Connection) -> Option<IggyError> {
+    match connection.close_reason() {
+        Some(quinn::ConnectionError::ApplicationClosed(close))
+            if close.error_code == VarInt::from_u32(IggyError::ServerShuttingDown.as_code()) =>
+        {
+            warn!("The server is shutting down, the connection has been closed.");
+            Some(IggyError::ServerShuttingDown)
+        }
+        _ => None,
+    }
+}
+
 fn configure(config: &QuicClientConfig) -> Result<ClientConfig, IggyError> {
     let max_concurrent_bidi_streams = VarInt::try_from(config.max_concurrent_bidi_streams);
     if max_concurrent_bidi_streams.is_err() {
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::binary::servers::SERVER_ADDRESS_SEPARATOR;
 use crate::client::AutoLogin;
 use crate::utils::duration::IggyDuration;
 use std::str::FromStr;
@@ -25,7 +26,8 @@ use std::str::FromStr;
 pub struct QuicClientConfig {
     /// The address to bind the QUIC client to.
     pub client_address: String,
-    /// The address of the QUIC server to connect to.
+    /// The address of the QUIC server to connect to, or the comma-separated list of the bootstrap servers,
+    /// e.g. `127.0.0.1:8080,127.0.0.2:8080`, which are used for failover when the current server is unavailable.
     pub server_address: String,
     /// The server name to use.
     pub server_name: String,
@@ -136,6 +138,13 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Sets the list of the bootstrap servers. The client connects to the healthiest of them
+    /// and fails over to the next ones when the current server becomes unavailable.
+    pub fn with_server_addresses(mut self, server_addresses: Vec<String>) -> Self {
+        self.config.server_address = server_addresses.join(&SERVER_ADDRESS_SEPARATOR.to_string());
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config.auto_login = auto_sign_in;
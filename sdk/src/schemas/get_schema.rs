@@ -0,0 +1,112 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetSchema` command is used to get the schema by unique ID, regardless of the topic it was registered for.
+/// It has additional payload:
+/// - `schema_id` - unique ID (numeric) of the schema.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetSchema {
+    /// Unique ID (numeric) of the schema.
+    pub schema_id: u32,
+}
+
+impl Command for GetSchema {
+    fn code(&self) -> u32 {
+        GET_SCHEMA_CODE
+    }
+}
+
+impl Default for GetSchema {
+    fn default() -> Self {
+        GetSchema { schema_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for GetSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.schema_id == 0 {
+            return Err(IggyError::InvalidSchemaId);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetSchema {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.schema_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSchema, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_id = u32::from_le_bytes(
+            bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = GetSchema { schema_id };
+        Ok(command)
+    }
+}
+
+impl Display for GetSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.schema_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSchema { schema_id: 1 };
+
+        let bytes = command.to_bytes();
+        let schema_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(schema_id, command.schema_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let schema_id = 1u32;
+        let bytes = Bytes::from(schema_id.to_le_bytes().to_vec());
+        let command = GetSchema::from_bytes(bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.schema_id, schema_id);
+    }
+}
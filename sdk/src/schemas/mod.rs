@@ -0,0 +1,34 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod get_schema;
+pub mod get_schemas;
+pub mod register_schema;
+pub mod update_schema_compatibility;
+pub mod update_schema_validation;
+
+pub const MAX_DEFINITION_LENGTH: usize = 1_000_000;
+
+/// The header with the reason why the message routed to the rejection topic didn't match the schema.
+pub const REJECTION_REASON_HEADER: &str = "rejection-reason";
+/// The header with the ID of the schema the message routed to the rejection topic was validated against.
+pub const REJECTION_SCHEMA_ID_HEADER: &str = "rejection-schema-id";
+/// The header with the ID of the stream the message routed to the rejection topic was sent to.
+pub const REJECTION_STREAM_ID_HEADER: &str = "rejection-stream-id";
+/// The header with the ID of the topic the message routed to the rejection topic was sent to.
+pub const REJECTION_TOPIC_ID_HEADER: &str = "rejection-topic-id";
@@ -0,0 +1,218 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, REGISTER_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaFormat;
+use crate::schemas::MAX_DEFINITION_LENGTH;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RegisterSchema` command registers a new version of the schema for the topic.
+/// The new version must be compatible with the latest one, according to the compatibility rule of the topic.
+/// If the latest version has the same format and definition, it's returned instead of registering a new one.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `format` - format of the schema definition.
+/// - `definition` - schema definition, max length is 1 MB.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RegisterSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Format of the schema definition.
+    pub format: SchemaFormat,
+    /// Schema definition, max length is 1 MB.
+    pub definition: String,
+}
+
+impl Command for RegisterSchema {
+    fn code(&self) -> u32 {
+        REGISTER_SCHEMA_CODE
+    }
+}
+
+impl Default for RegisterSchema {
+    fn default() -> Self {
+        RegisterSchema {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            format: SchemaFormat::JsonSchema,
+            definition: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.definition.trim().is_empty() {
+            return Err(IggyError::InvalidSchemaDefinition(
+                "definition is empty".to_string(),
+            ));
+        }
+
+        if self.definition.len() > MAX_DEFINITION_LENGTH {
+            return Err(IggyError::InvalidSchemaDefinition(format!(
+                "definition is longer than {MAX_DEFINITION_LENGTH} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            5 + stream_id_bytes.len() + topic_id_bytes.len() + self.definition.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.format.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(self.definition.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterSchema, IggyError> {
+        if bytes.len() < 11 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let format = SchemaFormat::from_code(bytes[position])?;
+        let definition_length = u32::from_le_bytes(
+            bytes[position + 1..position + 5]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 5;
+        if bytes.len() != position + definition_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let definition = from_utf8(&bytes[position..position + definition_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let command = RegisterSchema {
+            stream_id,
+            topic_id,
+            format,
+            definition,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.format,
+            self.definition.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            format: SchemaFormat::Avro,
+            definition: r#"{"type":"string"}"#.to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let format = SchemaFormat::from_code(bytes[position]).unwrap();
+        let definition_length =
+            u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap()) as usize;
+        let definition = from_utf8(&bytes[position + 5..position + 5 + definition_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(format, command.format);
+        assert_eq!(definition, command.definition);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let format = SchemaFormat::JsonSchema;
+        let definition = r#"{"type":"object"}"#;
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+        bytes.put_u8(format.as_code());
+        bytes.put_u32_le(definition.len() as u32);
+        bytes.put_slice(definition.as_bytes());
+        let command = RegisterSchema::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.format, format);
+        assert_eq!(command.definition, definition);
+    }
+
+    #[test]
+    fn empty_definition_should_not_be_valid() {
+        let command = RegisterSchema {
+            definition: " ".to_string(),
+            ..RegisterSchema::default()
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,148 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_SCHEMA_COMPATIBILITY_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaCompatibility;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateSchemaCompatibility` command updates the compatibility rule checked when registering a new version of the topic schema.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `compatibility` - compatibility rule.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateSchemaCompatibility {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Compatibility rule.
+    pub compatibility: SchemaCompatibility,
+}
+
+impl Command for UpdateSchemaCompatibility {
+    fn code(&self) -> u32 {
+        UPDATE_SCHEMA_COMPATIBILITY_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateSchemaCompatibility {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateSchemaCompatibility {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateSchemaCompatibility, IggyError> {
+        if bytes.len() < 7 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let compatibility = SchemaCompatibility::from_code(bytes[position])?;
+        let command = UpdateSchemaCompatibility {
+            stream_id,
+            topic_id,
+            compatibility,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateSchemaCompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.topic_id, self.compatibility
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateSchemaCompatibility {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            compatibility: SchemaCompatibility::Full,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let compatibility = SchemaCompatibility::from_code(bytes[position]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(compatibility, command.compatibility);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let compatibility = SchemaCompatibility::Forward;
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+        bytes.put_u8(compatibility.as_code());
+        let command = UpdateSchemaCompatibility::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.compatibility, compatibility);
+    }
+}
@@ -0,0 +1,176 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_SCHEMA_VALIDATION_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::SchemaValidation;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateSchemaValidation` command updates the validation of the message payloads appended to the topic against its registered schema.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `validation` - validation mode, either none, reject or route to the rejection topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateSchemaValidation {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Validation mode, either none, reject or route to the rejection topic.
+    pub validation: SchemaValidation,
+}
+
+impl Command for UpdateSchemaValidation {
+    fn code(&self) -> u32 {
+        UPDATE_SCHEMA_VALIDATION_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateSchemaValidation {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let SchemaValidation::Route {
+            stream_id,
+            topic_id,
+        } = self.validation
+        {
+            if stream_id == 0 || topic_id == 0 {
+                return Err(IggyError::InvalidSchemaValidation);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateSchemaValidation {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            SchemaValidation::SIZE + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&self.validation.to_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateSchemaValidation, IggyError> {
+        if bytes.len() < 6 + SchemaValidation::SIZE {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + SchemaValidation::SIZE {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let validation = SchemaValidation::from_bytes(bytes.slice(position..))?;
+        let command = UpdateSchemaValidation {
+            stream_id,
+            topic_id,
+            validation,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdateSchemaValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.topic_id, self.validation
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateSchemaValidation {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            validation: SchemaValidation::Route {
+                stream_id: 3,
+                topic_id: 4,
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let validation = SchemaValidation::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(validation, command.validation);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let validation = SchemaValidation::Reject;
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+        bytes.put(validation.to_bytes());
+        let command = UpdateSchemaValidation::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.validation, validation);
+    }
+
+    #[test]
+    fn route_to_invalid_topic_should_not_be_valid() {
+        let command = UpdateSchemaValidation {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            validation: SchemaValidation::Route {
+                stream_id: 1,
+                topic_id: 0,
+            },
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -24,6 +24,7 @@ use crate::error::IggyError;
 
 /// Enum representing the different types of system snapshots that can be taken.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum SystemSnapshotType {
     /// Overview of the filesystem.
     FilesystemOverview,
@@ -43,6 +44,7 @@ pub enum SystemSnapshotType {
 
 /// Enum representing the various compression methods available for snapshots.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum SnapshotCompression {
     /// Store the file as is
     Stored,
@@ -0,0 +1,197 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DISCONNECT_CLIENT_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DisconnectClient` command is used to forcibly disconnect the client(s) from the server.
+/// Depending on the target, either a single client session is closed, or all the sessions of the given user.
+/// For the TCP and QUIC transports the connection is closed, for HTTP the issued access tokens are invalidated.
+/// It has additional payload:
+/// - `target` - the client (by unique ID) or the user (numeric ID or name) to be disconnected.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct DisconnectClient {
+    /// The client or the user to be disconnected.
+    pub target: DisconnectTarget,
+}
+
+/// `DisconnectTarget` is an enum that represents the target of the `DisconnectClient` command.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum DisconnectTarget {
+    /// Single client session identified by unique ID (numeric).
+    Client(u32),
+    /// All the client sessions of the user identified by unique ID (numeric or name).
+    User(Identifier),
+}
+
+impl DisconnectClient {
+    /// Creates a new `DisconnectClient` command for the client with the given ID.
+    pub fn client(client_id: u32) -> Self {
+        Self {
+            target: DisconnectTarget::Client(client_id),
+        }
+    }
+
+    /// Creates a new `DisconnectClient` command for all the clients of the given user.
+    pub fn user(user_id: Identifier) -> Self {
+        Self {
+            target: DisconnectTarget::User(user_id),
+        }
+    }
+}
+
+impl Command for DisconnectClient {
+    fn code(&self) -> u32 {
+        DISCONNECT_CLIENT_CODE
+    }
+}
+
+impl Default for DisconnectClient {
+    fn default() -> Self {
+        DisconnectClient::client(1)
+    }
+}
+
+impl Validatable<IggyError> for DisconnectClient {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let DisconnectTarget::Client(0) = self.target {
+            return Err(IggyError::InvalidClientId);
+        }
+
+        Ok(())
+    }
+}
+
+impl DisconnectTarget {
+    /// Returns the code of the `DisconnectTarget`.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            DisconnectTarget::Client(_) => 1,
+            DisconnectTarget::User(_) => 2,
+        }
+    }
+}
+
+impl BytesSerializable for DisconnectClient {
+    fn to_bytes(&self) -> Bytes {
+        let payload = match &self.target {
+            DisconnectTarget::Client(client_id) => Bytes::copy_from_slice(&client_id.to_le_bytes()),
+            DisconnectTarget::User(user_id) => user_id.to_bytes(),
+        };
+        let mut bytes = BytesMut::with_capacity(1 + payload.len());
+        bytes.put_u8(self.target.as_code());
+        bytes.put_slice(&payload);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DisconnectClient, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let target = match bytes[0] {
+            1 => {
+                if bytes.len() != 5 {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                let client_id = u32::from_le_bytes(
+                    bytes[1..5]
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                );
+                DisconnectTarget::Client(client_id)
+            }
+            2 => DisconnectTarget::User(Identifier::from_bytes(bytes.slice(1..))?),
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let command = DisconnectClient { target };
+        Ok(command)
+    }
+}
+
+impl Display for DisconnectTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisconnectTarget::Client(client_id) => write!(f, "client|{client_id}"),
+            DisconnectTarget::User(user_id) => write!(f, "user|{user_id}"),
+        }
+    }
+}
+
+impl Display for DisconnectClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_for_client() {
+        let command = DisconnectClient::client(42);
+
+        let bytes = command.to_bytes();
+        let kind = bytes[0];
+        let client_id = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 5);
+        assert_eq!(kind, 1);
+        assert_eq!(client_id, 42);
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes_for_user() {
+        let command = DisconnectClient::user(Identifier::named("user").unwrap());
+
+        let bytes = command.to_bytes();
+        let kind = bytes[0];
+        let user_id = Identifier::from_bytes(bytes.slice(1..)).unwrap();
+
+        assert_eq!(kind, 2);
+        assert_eq!(user_id, Identifier::named("user").unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = DisconnectClient::client(42);
+        let deserialized = DisconnectClient::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+
+        let command = DisconnectClient::user(Identifier::numeric(2).unwrap());
+        let deserialized = DisconnectClient::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_target() {
+        let mut bytes = BytesMut::with_capacity(5);
+        bytes.put_u8(3);
+        bytes.put_u32_le(1);
+        assert!(DisconnectClient::from_bytes(bytes.freeze()).is_err());
+    }
+}
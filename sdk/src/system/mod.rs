@@ -16,9 +16,11 @@
  * under the License.
  */
 
+pub mod disconnect_client;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
 pub mod get_snapshot;
 pub mod get_stats;
 pub mod ping;
+pub mod reload_config;
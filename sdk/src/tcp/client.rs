@@ -17,14 +17,23 @@
  */
 
 use crate::binary::binary_client::BinaryClient;
+use crate::binary::servers::ServerList;
+use crate::binary::session::{rejoin_consumer_groups, ClientSession};
+use crate::binary::subscriptions::{
+    MessageSubscription, MessageSubscriptions, DEFAULT_SUBSCRIPTION_BUFFER_SIZE,
+};
 use crate::binary::{BinaryTransport, ClientState};
 use crate::client::{
     AutoLogin, Client, ConnectionString, Credentials, PersonalAccessTokenClient, UserClient,
 };
-use crate::command::Command;
+use crate::command::{Command, ERROR_DETAILS_FLAG};
+use crate::consumer::Consumer;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
 use crate::tcp::config::TcpClientConfig;
+use crate::tcp::pipeline::PipelinedConnection;
 use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
 use async_broadcast::{broadcast, Receiver, Sender};
@@ -34,7 +43,9 @@ use rustls::pki_types::{pem::PemObject, CertificateDer, ServerName};
 use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::TcpStream;
@@ -48,15 +59,23 @@ const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
 const NAME: &str = "Iggy";
 
 /// TCP client for interacting with the Iggy API.
-/// It requires a valid server address.
+/// It requires a valid server address, or a comma-separated list of the bootstrap servers used for failover.
 #[derive(Debug)]
 pub struct TcpClient {
     pub(crate) stream: Mutex<Option<ConnectionStreamKind>>,
+    pipeline: Mutex<Option<Arc<PipelinedConnection>>>,
     pub(crate) config: Arc<TcpClientConfig>,
     pub(crate) state: Mutex<ClientState>,
+    servers: ServerList<String>,
+    current_server: Mutex<Option<String>>,
+    session: ClientSession,
     client_address: Mutex<Option<SocketAddr>>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
+    generation: AtomicU64,
+    reconnection: Mutex<()>,
+    restoring_session: AtomicBool,
+    subscriptions: Arc<MessageSubscriptions>,
 }
 
 #[async_trait]
@@ -101,6 +120,20 @@ impl ConnectionStreamKind {
             Self::TcpTls(c) => c.shutdown().await,
         }
     }
+
+    /// Splits the stream into the writer and the background reader, so that the requests can be pipelined.
+    /// The reader also routes the messages pushed by the server to the subscriptions.
+    pub fn into_pipelined(self, subscriptions: Arc<MessageSubscriptions>) -> PipelinedConnection {
+        match self {
+            Self::Tcp(c) => {
+                PipelinedConnection::new(c.client_address, c.reader, c.writer, subscriptions)
+            }
+            Self::TcpTls(c) => {
+                let (reader, writer) = tokio::io::split(c.stream);
+                PipelinedConnection::new(c.client_address, reader, writer, subscriptions)
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -264,6 +297,7 @@ impl BinaryTransport for TcpClient {
     }
 
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        let generation = self.generation.load(Ordering::Acquire);
         let result = self.send_raw(code, payload.clone()).await;
         if result.is_ok() {
             return result;
@@ -276,6 +310,7 @@ impl BinaryTransport for TcpClient {
                 | IggyError::EmptyResponse
                 | IggyError::Unauthenticated
                 | IggyError::StaleClient
+                | IggyError::ServerShuttingDown
         ) {
             return Err(error);
         }
@@ -284,17 +319,19 @@ impl BinaryTransport for TcpClient {
             return Err(IggyError::Disconnected);
         }
 
-        self.disconnect().await?;
+        // The session is being restored by the ongoing (re)connection, which handles the failure on its own.
+        if self.restoring_session.load(Ordering::Acquire) {
+            return Err(error);
+        }
 
         {
-            let client_address = self.get_client_address_value().await;
-            info!(
-                "Reconnecting to the server: {} by client: {client_address}...",
-                self.config.server_address
-            );
+            // Concurrent (e.g. pipelined) requests failing at once reconnect only a single time.
+            let _reconnection = self.reconnection.lock().await;
+            if self.generation.load(Ordering::Acquire) == generation {
+                self.reconnect(error).await?;
+            }
         }
 
-        self.connect().await?;
         self.send_raw(code, payload).await
     }
 
@@ -307,6 +344,10 @@ impl BinaryTransport for TcpClient {
     fn get_heartbeat_interval(&self) -> IggyDuration {
         self.config.heartbeat_interval
     }
+
+    fn session(&self) -> &ClientSession {
+        &self.session
+    }
 }
 
 impl BinaryClient for TcpClient {}
@@ -352,15 +393,76 @@ impl TcpClient {
     /// Create a new TCP client based on the provided configuration.
     pub fn create(config: Arc<TcpClientConfig>) -> Result<Self, IggyError> {
         Ok(Self {
+            servers: ServerList::parse(&config.server_address)?,
+            current_server: Mutex::new(None),
+            session: ClientSession::default(),
             config,
             client_address: Mutex::new(None),
             stream: Mutex::new(None),
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
+            pipeline: Mutex::new(None),
+            generation: AtomicU64::new(0),
+            reconnection: Mutex::new(()),
+            restoring_session: AtomicBool::new(false),
+            subscriptions: Arc::new(MessageSubscriptions::new(DEFAULT_SUBSCRIPTION_BUFFER_SIZE)),
         })
     }
 
+    /// Subscribes to the messages of the topic, which are then pushed by the server as they are appended.
+    /// The returned stream yields the batches of at most `count` messages, starting from the provided strategy.
+    /// With non-zero `credits`, the server pushes only that many batches until more credits are granted.
+    /// The subscription is bound to the current connection and ends once the connection is lost.
+    ///
+    /// Requires the pipelining to be enabled, as the pushed messages are interleaved with the responses.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        credits: u32,
+    ) -> Result<MessageSubscription, IggyError> {
+        if !self.config.pipelining {
+            return Err(IggyError::MessageSubscriptionsNotSupported);
+        }
+
+        self.subscriptions
+            .subscribe(
+                self,
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                strategy,
+                count,
+                auto_commit,
+                credits,
+            )
+            .await
+    }
+
+    /// Cancels the subscription, after which its stream ends.
+    pub async fn unsubscribe_messages(&self, subscription_id: u32) -> Result<(), IggyError> {
+        self.subscriptions.unsubscribe(self, subscription_id).await
+    }
+
+    /// Allows the server to push the given number of additional batches for the subscription created with the credits.
+    pub async fn grant_message_credits(
+        &self,
+        subscription_id: u32,
+        credits: u32,
+    ) -> Result<(), IggyError> {
+        self.subscriptions
+            .grant_credits(self, subscription_id, credits)
+            .await
+    }
+
     async fn handle_response(
         &self,
         status: u32,
@@ -383,6 +485,8 @@ impl TcpClient {
                     status,
                     IggyError::from_code_as_string(status)
                 )
+            } else if status == IggyErrorDiscriminants::ServerShuttingDown as u32 {
+                warn!("The server is shutting down, the connection has been closed.");
             } else {
                 error!(
                     "Received an invalid response with status: {} ({}).",
@@ -391,7 +495,13 @@ impl TcpClient {
                 );
             }
 
-            return Err(IggyError::from_code(status));
+            if length == 0 {
+                return Err(IggyError::from_code(status));
+            }
+
+            let mut details = BytesMut::zeroed(length as usize);
+            stream.read(&mut details).await?;
+            return Err(IggyError::from_code_and_details(status, &details));
         }
 
         trace!("Status: OK. Response length: {}", length);
@@ -439,154 +549,217 @@ impl TcpClient {
             }
         }
 
-        let tls_enabled = self.config.tls_enabled;
         let mut retry_count = 0;
         let connection_stream: ConnectionStreamKind;
         let remote_address;
         let client_address;
-        loop {
-            info!(
-                "{NAME} client is connecting to server: {}...",
-                self.config.server_address
-            );
-
-            let connection = TcpStream::connect(&self.config.server_address).await;
-            if connection.is_err() {
-                error!(
-                    "Failed to connect to server: {}",
-                    self.config.server_address
-                );
-                if !self.config.reconnection.enabled {
-                    warn!("Automatic reconnection is disabled.");
-                    return Err(IggyError::CannotEstablishConnection);
-                }
-
-                let unlimited_retries = self.config.reconnection.max_retries.is_none();
-                let max_retries = self.config.reconnection.max_retries.unwrap_or_default();
-                let max_retries_str =
-                    if let Some(max_retries) = self.config.reconnection.max_retries {
-                        max_retries.to_string()
-                    } else {
-                        "unlimited".to_string()
-                    };
-
-                let interval_str = self.config.reconnection.interval.as_human_time_string();
-                if unlimited_retries || retry_count < max_retries {
-                    retry_count += 1;
-                    info!(
-                        "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
-                        self.config.server_address,
-                    );
-                    sleep(self.config.reconnection.interval.get_duration()).await;
-                    continue;
+        'connection: loop {
+            for server_address in self.servers.candidates() {
+                info!("{NAME} client is connecting to server: {server_address}...");
+                let started_at = Instant::now();
+                match self.connect_to_server(&server_address).await {
+                    Ok((stream, local_address, peer_address)) => {
+                        self.servers
+                            .mark_connected(&server_address, started_at.elapsed());
+                        self.current_server.lock().await.replace(server_address);
+                        connection_stream = stream;
+                        client_address = local_address;
+                        remote_address = peer_address;
+                        break 'connection;
+                    }
+                    Err(
+                        error @ (IggyError::InvalidTlsCertificatePath
+                        | IggyError::InvalidTlsCertificate
+                        | IggyError::InvalidTlsDomain),
+                    ) => {
+                        self.set_state(ClientState::Disconnected).await;
+                        return Err(error);
+                    }
+                    Err(_) => {
+                        error!("Failed to connect to server: {server_address}");
+                        self.servers.mark_failed(&server_address);
+                    }
                 }
+            }
 
+            if !self.config.reconnection.enabled {
+                warn!("Automatic reconnection is disabled.");
                 self.set_state(ClientState::Disconnected).await;
-                self.publish_event(DiagnosticEvent::Disconnected).await;
                 return Err(IggyError::CannotEstablishConnection);
             }
 
-            let stream = connection.map_err(|error| {
-                error!("Failed to establish TCP connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            client_address = stream.local_addr().map_err(|error| {
-                error!("Failed to get the local address of the client: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            remote_address = stream.peer_addr().map_err(|error| {
-                error!("Failed to get the remote address of the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            self.client_address.lock().await.replace(client_address);
-
-            if let Err(e) = stream.set_nodelay(self.config.nodelay) {
-                error!("Failed to set the nodelay option on the client: {e}, continuing...",);
-            }
-
-            if !tls_enabled {
-                connection_stream =
-                    ConnectionStreamKind::Tcp(TcpConnectionStream::new(client_address, stream));
-                break;
-            }
-
-            let mut root_cert_store = rustls::RootCertStore::empty();
-            if let Some(certificate_path) = &self.config.tls_ca_file {
-                for cert in CertificateDer::pem_file_iter(certificate_path).map_err(|error| {
-                    error!("Failed to read the CA file: {certificate_path}. {error}",);
-                    IggyError::InvalidTlsCertificatePath
-                })? {
-                    let certificate = cert.map_err(|error| {
-                        error!(
-                            "Failed to read a certificate from the CA file: {certificate_path}. {error}",
-                        );
-                        IggyError::InvalidTlsCertificate
-                    })?;
-                    root_cert_store.add(certificate).map_err(|error| {
-                        error!(
-                            "Failed to add a certificate to the root certificate store. {error}",
-                        );
-                        IggyError::InvalidTlsCertificate
-                    })?;
-                }
+            let unlimited_retries = self.config.reconnection.max_retries.is_none();
+            let max_retries = self.config.reconnection.max_retries.unwrap_or_default();
+            let max_retries_str = if let Some(max_retries) = self.config.reconnection.max_retries {
+                max_retries.to_string()
             } else {
-                root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+                "unlimited".to_string()
+            };
+
+            let interval_str = self.config.reconnection.interval.as_human_time_string();
+            if unlimited_retries || retry_count < max_retries {
+                retry_count += 1;
+                info!(
+                    "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
+                    self.config.server_address,
+                );
+                sleep(self.config.reconnection.interval.get_duration()).await;
+                continue;
             }
 
-            let config = rustls::ClientConfig::builder()
-                .with_root_certificates(root_cert_store)
-                .with_no_client_auth();
-            let connector = TlsConnector::from(Arc::new(config));
-            let stream = TcpStream::connect(client_address).await.map_err(|error| {
-                error!("Failed to establish TCP connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            let tls_domain = self.config.tls_domain.to_owned();
-            let domain = ServerName::try_from(tls_domain).map_err(|error| {
-                error!("Failed to create a server name from the domain. {error}",);
-                IggyError::InvalidTlsDomain
-            })?;
-            let stream = connector.connect(domain, stream).await.map_err(|error| {
-                error!("Failed to establish a TLS connection to the server: {error}",);
-                IggyError::CannotEstablishConnection
-            })?;
-            connection_stream = ConnectionStreamKind::TcpTls(TcpTlsConnectionStream::new(
-                client_address,
-                TlsStream::Client(stream),
-            ));
-            break;
+            self.set_state(ClientState::Disconnected).await;
+            self.publish_event(DiagnosticEvent::Disconnected).await;
+            return Err(IggyError::CannotEstablishConnection);
         }
 
+        self.client_address.lock().await.replace(client_address);
         let now = IggyTimestamp::now();
         info!(
             "{NAME} client: {client_address} has connected to server: {remote_address} at: {now}",
         );
-        self.stream.lock().await.replace(connection_stream);
+        if self.config.pipelining {
+            self.pipeline.lock().await.replace(Arc::new(
+                connection_stream.into_pipelined(self.subscriptions.clone()),
+            ));
+        } else {
+            self.stream.lock().await.replace(connection_stream);
+        }
+        self.generation.fetch_add(1, Ordering::AcqRel);
         self.set_state(ClientState::Connected).await;
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
-        match &self.config.auto_login {
-            AutoLogin::Disabled => {
-                info!("Automatic sign-in is disabled.");
-                Ok(())
-            }
-            AutoLogin::Enabled(credentials) => {
-                info!("{NAME} client: {client_address} is signing in...");
-                self.set_state(ClientState::Authenticating).await;
-                match credentials {
-                    Credentials::UsernamePassword(username, password) => {
-                        self.login_user(username, password).await?;
-                        info!("{NAME} client: {client_address} has signed in with the user credentials, username: {username}",);
-                        Ok(())
-                    }
-                    Credentials::PersonalAccessToken(token) => {
-                        self.login_with_personal_access_token(token).await?;
-                        info!("{NAME} client: {client_address} has signed in with a personal access token.",);
-                        Ok(())
-                    }
+        self.restoring_session.store(true, Ordering::Release);
+        let result = self.restore_session(client_address).await;
+        self.restoring_session.store(false, Ordering::Release);
+        result
+    }
+
+    /// Signs in with the configured credentials (or the ones used by the previous session)
+    /// and rejoins the consumer groups of the previous session.
+    async fn restore_session(&self, client_address: SocketAddr) -> Result<(), IggyError> {
+        let credentials = match &self.config.auto_login {
+            AutoLogin::Enabled(credentials) => credentials.clone(),
+            AutoLogin::Disabled => match self.session.credentials() {
+                Some(credentials) => credentials,
+                None => {
+                    info!("Automatic sign-in is disabled.");
+                    return Ok(());
                 }
+            },
+        };
+
+        info!("{NAME} client: {client_address} is signing in...");
+        self.set_state(ClientState::Authenticating).await;
+        match &credentials {
+            Credentials::UsernamePassword(username, password) => {
+                self.login_user(username, password).await?;
+                info!("{NAME} client: {client_address} has signed in with the user credentials, username: {username}",);
+            }
+            Credentials::PersonalAccessToken(token) => {
+                self.login_with_personal_access_token(token).await?;
+                info!(
+                    "{NAME} client: {client_address} has signed in with a personal access token.",
+                );
             }
         }
+        rejoin_consumer_groups(self).await;
+        Ok(())
+    }
+
+    async fn connect_to_server(
+        &self,
+        server_address: &str,
+    ) -> Result<(ConnectionStreamKind, SocketAddr, SocketAddr), IggyError> {
+        let stream = TcpStream::connect(server_address).await.map_err(|error| {
+            error!("Failed to establish TCP connection to the server: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+        let client_address = stream.local_addr().map_err(|error| {
+            error!("Failed to get the local address of the client: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+        let remote_address = stream.peer_addr().map_err(|error| {
+            error!("Failed to get the remote address of the server: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+
+        if let Err(e) = stream.set_nodelay(self.config.nodelay) {
+            error!("Failed to set the nodelay option on the client: {e}, continuing...",);
+        }
+
+        if !self.config.tls_enabled {
+            return Ok((
+                ConnectionStreamKind::Tcp(TcpConnectionStream::new(client_address, stream)),
+                client_address,
+                remote_address,
+            ));
+        }
+
+        let mut root_cert_store = rustls::RootCertStore::empty();
+        if let Some(certificate_path) = &self.config.tls_ca_file {
+            for cert in CertificateDer::pem_file_iter(certificate_path).map_err(|error| {
+                error!("Failed to read the CA file: {certificate_path}. {error}",);
+                IggyError::InvalidTlsCertificatePath
+            })? {
+                let certificate = cert.map_err(|error| {
+                    error!(
+                        "Failed to read a certificate from the CA file: {certificate_path}. {error}",
+                    );
+                    IggyError::InvalidTlsCertificate
+                })?;
+                root_cert_store.add(certificate).map_err(|error| {
+                    error!("Failed to add a certificate to the root certificate store. {error}",);
+                    IggyError::InvalidTlsCertificate
+                })?;
+            }
+        } else {
+            root_cert_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        }
+
+        let config = rustls::ClientConfig::builder()
+            .with_root_certificates(root_cert_store)
+            .with_no_client_auth();
+        let connector = TlsConnector::from(Arc::new(config));
+        let tls_domain = self.config.tls_domain.to_owned();
+        let domain = ServerName::try_from(tls_domain).map_err(|error| {
+            error!("Failed to create a server name from the domain. {error}",);
+            IggyError::InvalidTlsDomain
+        })?;
+        let stream = connector.connect(domain, stream).await.map_err(|error| {
+            error!("Failed to establish a TLS connection to the server: {error}",);
+            IggyError::CannotEstablishConnection
+        })?;
+        Ok((
+            ConnectionStreamKind::TcpTls(TcpTlsConnectionStream::new(
+                client_address,
+                TlsStream::Client(stream),
+            )),
+            client_address,
+            remote_address,
+        ))
+    }
+
+    async fn reconnect(&self, error: IggyError) -> Result<(), IggyError> {
+        let server_address = self.current_server.lock().await.clone();
+        if let Some(server_address) = &server_address {
+            if error != IggyError::Unauthenticated {
+                self.servers.mark_failed(server_address);
+            }
+        }
+
+        self.disconnect().await?;
+
+        {
+            let client_address = self.get_client_address_value().await;
+            info!(
+                "Reconnecting to the server: {} by client: {client_address}...",
+                server_address
+                    .as_deref()
+                    .unwrap_or(&self.config.server_address)
+            );
+        }
+
+        self.connect().await
     }
 
     async fn disconnect(&self) -> Result<(), IggyError> {
@@ -598,6 +771,8 @@ impl TcpClient {
         info!("{NAME} client: {client_address} is disconnecting from server...");
         self.set_state(ClientState::Disconnected).await;
         self.stream.lock().await.take();
+        self.pipeline.lock().await.take();
+        self.subscriptions.clear();
         self.publish_event(DiagnosticEvent::Disconnected).await;
         let now = IggyTimestamp::now();
         info!("{NAME} client: {client_address} has disconnected from server at: {now}.");
@@ -615,6 +790,11 @@ impl TcpClient {
         if let Some(mut stream) = stream {
             stream.shutdown().await?;
         }
+        let pipeline = self.pipeline.lock().await.take();
+        if let Some(pipeline) = pipeline {
+            pipeline.shutdown().await?;
+        }
+        self.subscriptions.clear();
         self.set_state(ClientState::Shutdown).await;
         self.publish_event(DiagnosticEvent::Shutdown).await;
         info!("{NAME} TCP client: {client_address} has been shutdown.");
@@ -638,12 +818,24 @@ impl TcpClient {
             _ => {}
         }
 
+        if self.config.pipelining {
+            let pipeline = self.pipeline.lock().await.clone();
+            if let Some(pipeline) = pipeline {
+                return pipeline.send(code, payload).await;
+            }
+
+            error!("Cannot send data. Client is not connected.");
+            return Err(IggyError::NotConnected);
+        }
+
         let mut stream = self.stream.lock().await;
         if let Some(stream) = stream.as_mut() {
             let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
             trace!("Sending a TCP request with code: {code}");
             stream.write(&(payload_length as u32).to_le_bytes()).await?;
-            stream.write(&code.to_le_bytes()).await?;
+            stream
+                .write(&(code | ERROR_DETAILS_FLAG).to_le_bytes())
+                .await?;
             stream.write(&payload).await?;
             stream.flush().await?;
             trace!("Sent a TCP request with code: {code}, waiting for a response...");
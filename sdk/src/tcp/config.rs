@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::binary::servers::SERVER_ADDRESS_SEPARATOR;
 use crate::client::AutoLogin;
 use crate::utils::duration::IggyDuration;
 use std::str::FromStr;
@@ -23,7 +24,8 @@ use std::str::FromStr;
 /// Configuration for the TCP client.
 #[derive(Debug, Clone)]
 pub struct TcpClientConfig {
-    /// The address of the Iggy server.
+    /// The address of the Iggy server, or the comma-separated list of the bootstrap servers,
+    /// e.g. `host1:8090,host2:8090`, which are used for failover when the current server is unavailable.
     pub server_address: String,
     /// Whether to use TLS when connecting to the server.
     pub tls_enabled: bool,
@@ -39,6 +41,9 @@ pub struct TcpClientConfig {
     pub heartbeat_interval: IggyDuration,
     /// Disable Nagle algorithm for the TCP socket.
     pub nodelay: bool,
+    /// Whether to pipeline the requests over the connection, tagging them with correlation IDs,
+    /// instead of waiting for the response before sending the next request. Requires a server supporting correlation IDs.
+    pub pipelining: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -60,6 +65,7 @@ impl Default for TcpClientConfig {
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
             nodelay: false,
+            pipelining: false,
         }
     }
 }
@@ -83,6 +89,7 @@ impl Default for TcpClientReconnectionConfig {
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "localhost".
 /// - `tls_ca_file`: Default is None.
+/// - `pipelining`: Default is false.
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -99,6 +106,13 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the list of the bootstrap servers. The client connects to the healthiest of them
+    /// and fails over to the next ones when the current server becomes unavailable.
+    pub fn with_server_addresses(mut self, server_addresses: Vec<String>) -> Self {
+        self.config.server_address = server_addresses.join(&SERVER_ADDRESS_SEPARATOR.to_string());
+        self
+    }
+
     /// Sets the auto sign in during connection.
     pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
         self.config.auto_login = auto_sign_in;
@@ -146,6 +160,12 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Enables pipelining of the requests over the connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config.pipelining = true;
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> TcpClientConfig {
         self.config
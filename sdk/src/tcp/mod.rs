@@ -18,3 +18,5 @@
 
 pub mod client;
 pub mod config;
+pub(crate) mod pipeline;
+pub mod pool;
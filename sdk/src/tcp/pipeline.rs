@@ -0,0 +1,340 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::subscriptions::MessageSubscriptions;
+use crate::command::{CORRELATION_ID_FLAG, ERROR_DETAILS_FLAG};
+use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::messages::subscribe_messages::PUSHED_MESSAGES_STATUS;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
+use tracing::{error, trace, warn};
+
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
+const CORRELATION_ID_LENGTH: usize = 4;
+const RESPONSE_HEADER_LENGTH: usize = 12;
+
+type PendingResponse = oneshot::Sender<Result<Bytes, IggyError>>;
+
+/// The connection which pipelines the requests over a single TCP stream.
+/// Each request is tagged with a correlation ID and written right away (under the writer lock only),
+/// while the background task reads the responses and completes the pending requests by their correlation IDs.
+/// The messages pushed for the subscriptions carry the subscription ID in place of the correlation ID.
+pub(crate) struct PipelinedConnection {
+    client_address: SocketAddr,
+    writer: Mutex<Box<dyn AsyncWrite + Send + Sync + Unpin>>,
+    pending: Arc<PendingRequests>,
+    next_correlation_id: AtomicU32,
+    reader: JoinHandle<()>,
+}
+
+/// The requests awaiting their responses, `None` once the connection has been closed.
+#[derive(Debug, Default)]
+struct PendingRequests {
+    requests: std::sync::Mutex<Option<HashMap<u32, PendingResponse>>>,
+}
+
+impl Debug for PipelinedConnection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PipelinedConnection")
+            .field("client_address", &self.client_address)
+            .field("in_flight", &self.in_flight())
+            .finish()
+    }
+}
+
+impl PipelinedConnection {
+    pub fn new<R, W>(
+        client_address: SocketAddr,
+        reader: R,
+        writer: W,
+        subscriptions: Arc<MessageSubscriptions>,
+    ) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+        W: AsyncWrite + Send + Sync + Unpin + 'static,
+    {
+        let pending = Arc::new(PendingRequests {
+            requests: std::sync::Mutex::new(Some(HashMap::new())),
+        });
+        let reader = tokio::spawn(read_responses(
+            client_address,
+            reader,
+            pending.clone(),
+            subscriptions,
+        ));
+        Self {
+            client_address,
+            writer: Mutex::new(Box::new(writer)),
+            pending,
+            next_correlation_id: AtomicU32::new(1),
+            reader,
+        }
+    }
+
+    /// Sends the request and waits for its response, while other requests may be in flight.
+    pub async fn send(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+        self.pending.register(correlation_id, sender)?;
+
+        let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH + CORRELATION_ID_LENGTH;
+        let mut request = BytesMut::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
+        request.put_u32_le(payload_length as u32);
+        request.put_u32_le(code | CORRELATION_ID_FLAG | ERROR_DETAILS_FLAG);
+        request.put_u32_le(correlation_id);
+        request.put_slice(&payload);
+
+        trace!(
+            "Sending a pipelined TCP request with code: {code}, correlation ID: {correlation_id}"
+        );
+        {
+            let mut writer = self.writer.lock().await;
+            let written = match writer.write_all(&request).await {
+                Ok(()) => writer.flush().await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = written {
+                error!(
+                    "Failed to write data by client: {} to the TCP connection: {error}",
+                    self.client_address
+                );
+                self.pending.take(correlation_id);
+                return Err(IggyError::Disconnected);
+            }
+        }
+
+        receiver.await.unwrap_or(Err(IggyError::Disconnected))
+    }
+
+    /// Returns the number of the requests awaiting their responses.
+    pub fn in_flight(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub async fn shutdown(&self) -> Result<(), IggyError> {
+        self.reader.abort();
+        self.pending.close();
+        self.writer.lock().await.shutdown().await.map_err(|error| {
+            error!(
+                "Failed to shutdown the TCP connection by client: {} to the TCP connection: {error}",
+                self.client_address
+            );
+            IggyError::TcpError
+        })
+    }
+}
+
+impl Drop for PipelinedConnection {
+    fn drop(&mut self) {
+        self.reader.abort();
+        self.pending.close();
+    }
+}
+
+impl PendingRequests {
+    fn register(&self, correlation_id: u32, sender: PendingResponse) -> Result<(), IggyError> {
+        match self.requests.lock().unwrap().as_mut() {
+            Some(requests) => {
+                requests.insert(correlation_id, sender);
+                Ok(())
+            }
+            None => Err(IggyError::Disconnected),
+        }
+    }
+
+    fn take(&self, correlation_id: u32) -> Option<PendingResponse> {
+        self.requests
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|requests| requests.remove(&correlation_id))
+    }
+
+    fn len(&self) -> usize {
+        self.requests
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or(0, |requests| requests.len())
+    }
+
+    /// Fails all the pending requests, so that the callers can reconnect.
+    fn close(&self) {
+        if let Some(requests) = self.requests.lock().unwrap().take() {
+            for (_, sender) in requests {
+                let _ = sender.send(Err(IggyError::Disconnected));
+            }
+        }
+    }
+}
+
+async fn read_responses<R>(
+    client_address: SocketAddr,
+    mut reader: R,
+    pending: Arc<PendingRequests>,
+    subscriptions: Arc<MessageSubscriptions>,
+) where
+    R: AsyncRead + Unpin,
+{
+    let mut header = [0u8; RESPONSE_HEADER_LENGTH];
+    loop {
+        if let Err(error) = reader.read_exact(&mut header).await {
+            error!(
+                "Failed to read response by client: {client_address} from the TCP connection: {error}"
+            );
+            break;
+        }
+
+        let status = u32::from_le_bytes(header[..4].try_into().unwrap());
+        let length = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+        let correlation_id = u32::from_le_bytes(header[8..].try_into().unwrap());
+        let mut payload = BytesMut::zeroed(length);
+        if let Err(error) = reader.read_exact(&mut payload).await {
+            error!(
+                "Failed to read response payload by client: {client_address} from the TCP connection: {error}"
+            );
+            break;
+        }
+
+        if status == PUSHED_MESSAGES_STATUS {
+            subscriptions.route(correlation_id, payload.freeze()).await;
+            continue;
+        }
+
+        let result = if status == 0 {
+            trace!("Status: OK. Response length: {length}, correlation ID: {correlation_id}");
+            if length <= 1 {
+                Ok(Bytes::new())
+            } else {
+                Ok(payload.freeze())
+            }
+        } else {
+            if status == IggyErrorDiscriminants::ServerShuttingDown as u32 {
+                warn!("The server is shutting down, the connection has been closed.");
+            } else {
+                trace!(
+                    "Received a response with status: {status} ({}), correlation ID: {correlation_id}",
+                    IggyError::from_code_as_string(status)
+                );
+            }
+            Err(IggyError::from_code_and_details(status, &payload))
+        };
+
+        match pending.take(correlation_id) {
+            Some(sender) => {
+                let _ = sender.send(result);
+            }
+            None => {
+                if let Err(IggyError::ServerShuttingDown) = result {
+                    break;
+                }
+                warn!("Received a response for an unknown correlation ID: {correlation_id}");
+            }
+        }
+    }
+
+    pending.close();
+    subscriptions.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::duplex;
+
+    #[tokio::test]
+    async fn pipelined_requests_should_be_completed_by_correlation_ids() {
+        let (client, mut server) = duplex(1024);
+        let (client_reader, client_writer) = tokio::io::split(client);
+        let connection = Arc::new(PipelinedConnection::new(
+            "127.0.0.1:1234".parse().unwrap(),
+            client_reader,
+            client_writer,
+            Arc::new(MessageSubscriptions::new(1)),
+        ));
+
+        let first = tokio::spawn({
+            let connection = connection.clone();
+            async move { connection.send(1, Bytes::from_static(b"a")).await }
+        });
+        let second = tokio::spawn({
+            let connection = connection.clone();
+            async move { connection.send(2, Bytes::from_static(b"b")).await }
+        });
+
+        // Read both requests before responding, then respond in the reversed order.
+        let mut requests = Vec::new();
+        for _ in 0..2 {
+            let length = server.read_u32_le().await.unwrap();
+            let code = server.read_u32_le().await.unwrap();
+            let correlation_id = server.read_u32_le().await.unwrap();
+            let mut payload = vec![0; length as usize - 8];
+            server.read_exact(&mut payload).await.unwrap();
+            assert_ne!(code & CORRELATION_ID_FLAG, 0);
+            requests.push((correlation_id, payload));
+        }
+        assert_eq!(connection.in_flight(), 2);
+
+        for (correlation_id, payload) in requests.into_iter().rev() {
+            let mut response = BytesMut::new();
+            response.put_u32_le(0);
+            response.put_u32_le(payload.len() as u32 + 1);
+            response.put_u32_le(correlation_id);
+            response.put_slice(&payload);
+            response.put_u8(b'!');
+            server.write_all(&response).await.unwrap();
+        }
+
+        assert_eq!(first.await.unwrap().unwrap(), Bytes::from_static(b"a!"));
+        assert_eq!(second.await.unwrap().unwrap(), Bytes::from_static(b"b!"));
+        assert_eq!(connection.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn pending_requests_should_fail_when_connection_is_closed() {
+        let (client, mut server) = duplex(1024);
+        let (client_reader, client_writer) = tokio::io::split(client);
+        let connection = Arc::new(PipelinedConnection::new(
+            "127.0.0.1:1234".parse().unwrap(),
+            client_reader,
+            client_writer,
+            Arc::new(MessageSubscriptions::new(1)),
+        ));
+
+        let request = tokio::spawn({
+            let connection = connection.clone();
+            async move { connection.send(1, Bytes::new()).await }
+        });
+        let mut header = [0u8; 12];
+        server.read_exact(&mut header).await.unwrap();
+        drop(server);
+
+        assert_eq!(request.await.unwrap(), Err(IggyError::Disconnected));
+        assert_eq!(
+            connection.send(1, Bytes::new()).await,
+            Err(IggyError::Disconnected)
+        );
+    }
+}
@@ -0,0 +1,283 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::session::ClientSession;
+use crate::binary::{BinaryTransport, ClientState};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::{Client, PersonalAccessTokenClient, UserClient};
+use crate::command::{
+    Command, DELETE_CONSUMER_OFFSET_CODE, GET_CONSUMER_OFFSET_CODE, GET_ME_CODE,
+    JOIN_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP_CODE, LOGIN_USER_CODE,
+    LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, LOGOUT_USER_CODE, PING_CODE, POLL_MESSAGES_CODE,
+    STORE_CONSUMER_OFFSET_CODE,
+};
+use crate::consumer::ConsumerKind;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::tcp::client::TcpClient;
+use crate::tcp::config::TcpClientConfig;
+use crate::users::login_user::LoginUser;
+use crate::utils::duration::IggyDuration;
+use async_broadcast::Receiver;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// The pool of TCP connections to the server, which balances the requests between the connections,
+/// choosing the one with the least requests in flight.
+///
+/// The first connection is the primary one, which keeps the session state visible to the user:
+/// the consumer group memberships and the requests depending on them (polling messages or storing offsets
+/// by the consumer group, and getting the client info) are always sent through it.
+/// Signing in and out is applied to all the connections, and so is the ping, to keep them all alive.
+#[derive(Debug)]
+pub struct TcpConnectionPool {
+    connections: Vec<PooledConnection>,
+    next: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct PooledConnection {
+    client: TcpClient,
+    in_flight: AtomicUsize,
+}
+
+impl TcpConnectionPool {
+    /// Creates a new pool of the given size, with each connection created based on the provided configuration.
+    pub fn create(config: Arc<TcpClientConfig>, size: usize) -> Result<Self, IggyError> {
+        if size == 0 {
+            error!("TCP connection pool size must be greater than 0.");
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        let mut connections = Vec::with_capacity(size);
+        for _ in 0..size {
+            connections.push(PooledConnection {
+                client: TcpClient::create(config.clone())?,
+                in_flight: AtomicUsize::new(0),
+            });
+        }
+
+        Ok(Self {
+            connections,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Returns the number of the connections in the pool.
+    pub fn size(&self) -> usize {
+        self.connections.len()
+    }
+
+    fn primary(&self) -> &PooledConnection {
+        &self.connections[0]
+    }
+
+    fn secondaries(&self) -> &[PooledConnection] {
+        &self.connections[1..]
+    }
+
+    /// Picks the connection with the least requests in flight, starting from the next one in the round-robin order,
+    /// so that the idle connections are used in turns.
+    fn select(&self) -> &PooledConnection {
+        let size = self.connections.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % size;
+        (0..size)
+            .map(|offset| &self.connections[(start + offset) % size])
+            .min_by_key(|connection| connection.in_flight.load(Ordering::Relaxed))
+            .unwrap()
+    }
+
+    async fn send_to(
+        connection: &PooledConnection,
+        code: u32,
+        payload: Bytes,
+    ) -> Result<Bytes, IggyError> {
+        connection.in_flight.fetch_add(1, Ordering::Relaxed);
+        let result = connection
+            .client
+            .send_raw_with_response(code, payload)
+            .await;
+        connection.in_flight.fetch_sub(1, Ordering::Relaxed);
+        result
+    }
+}
+
+/// Whether the request depends on the consumer group membership of the connection it's sent through.
+fn is_bound_to_session(code: u32, payload: &[u8]) -> bool {
+    match code {
+        GET_ME_CODE | JOIN_CONSUMER_GROUP_CODE | LEAVE_CONSUMER_GROUP_CODE => true,
+        POLL_MESSAGES_CODE
+        | STORE_CONSUMER_OFFSET_CODE
+        | GET_CONSUMER_OFFSET_CODE
+        | DELETE_CONSUMER_OFFSET_CODE => {
+            payload.first() == Some(&ConsumerKind::ConsumerGroup.as_code())
+        }
+        _ => false,
+    }
+}
+
+#[async_trait]
+impl Client for TcpConnectionPool {
+    async fn connect(&self) -> Result<(), IggyError> {
+        for connection in &self.connections {
+            connection.client.connect().await?;
+        }
+        info!(
+            "TCP connection pool with {} connections has been connected.",
+            self.connections.len()
+        );
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        for connection in &self.connections {
+            connection.client.disconnect().await?;
+        }
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        for connection in &self.connections {
+            connection.client.shutdown().await?;
+        }
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.primary().client.subscribe_events().await
+    }
+}
+
+#[async_trait]
+impl BinaryTransport for TcpConnectionPool {
+    async fn get_state(&self) -> ClientState {
+        self.primary().client.get_state().await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        self.primary().client.set_state(state).await;
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        self.primary().client.publish_event(event).await;
+    }
+
+    async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError> {
+        command.validate()?;
+        self.send_raw_with_response(command.code(), command.to_bytes())
+            .await
+    }
+
+    async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        match code {
+            PING_CODE => {
+                for connection in self.secondaries() {
+                    Self::send_to(connection, code, payload.clone()).await?;
+                }
+                Self::send_to(self.primary(), code, payload).await
+            }
+            LOGIN_USER_CODE => {
+                let command = LoginUser::from_bytes(payload.clone())?;
+                let response = Self::send_to(self.primary(), code, payload).await?;
+                for connection in self.secondaries() {
+                    connection
+                        .client
+                        .login_user(&command.username, &command.password)
+                        .await?;
+                }
+                Ok(response)
+            }
+            LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => {
+                let command = LoginWithPersonalAccessToken::from_bytes(payload.clone())?;
+                let response = Self::send_to(self.primary(), code, payload).await?;
+                for connection in self.secondaries() {
+                    connection
+                        .client
+                        .login_with_personal_access_token(&command.token)
+                        .await?;
+                }
+                Ok(response)
+            }
+            LOGOUT_USER_CODE => {
+                let response = Self::send_to(self.primary(), code, payload).await?;
+                for connection in self.secondaries() {
+                    connection.client.logout_user().await?;
+                }
+                Ok(response)
+            }
+            _ if is_bound_to_session(code, &payload) => {
+                Self::send_to(self.primary(), code, payload).await
+            }
+            _ => Self::send_to(self.select(), code, payload).await,
+        }
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        self.primary().client.get_heartbeat_interval()
+    }
+
+    fn session(&self) -> &ClientSession {
+        self.primary().client.session()
+    }
+}
+
+impl BinaryClient for TcpConnectionPool {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_loaded_connection_should_be_selected_in_round_robin_order() {
+        let pool = TcpConnectionPool::create(Arc::new(TcpClientConfig::default()), 3).unwrap();
+        let selected = (0..3)
+            .map(|_| pool.select() as *const PooledConnection)
+            .collect::<Vec<_>>();
+        assert_ne!(selected[0], selected[1]);
+        assert_ne!(selected[1], selected[2]);
+        assert_ne!(selected[0], selected[2]);
+
+        pool.connections[0].in_flight.store(2, Ordering::Relaxed);
+        pool.connections[1].in_flight.store(1, Ordering::Relaxed);
+        pool.connections[2].in_flight.store(3, Ordering::Relaxed);
+        for _ in 0..3 {
+            assert!(std::ptr::eq(pool.select(), &pool.connections[1]));
+        }
+    }
+
+    #[test]
+    fn consumer_group_requests_should_be_bound_to_session() {
+        let group = [ConsumerKind::ConsumerGroup.as_code(), 1];
+        let consumer = [ConsumerKind::Consumer.as_code(), 1];
+        assert!(is_bound_to_session(POLL_MESSAGES_CODE, &group));
+        assert!(!is_bound_to_session(POLL_MESSAGES_CODE, &consumer));
+        assert!(is_bound_to_session(STORE_CONSUMER_OFFSET_CODE, &group));
+        assert!(is_bound_to_session(JOIN_CONSUMER_GROUP_CODE, &[]));
+        assert!(!is_bound_to_session(PING_CODE, &[]));
+    }
+
+    #[test]
+    fn empty_pool_should_not_be_created() {
+        assert!(TcpConnectionPool::create(Arc::new(TcpClientConfig::default()), 0).is_err());
+    }
+}
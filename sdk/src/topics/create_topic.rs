@@ -42,6 +42,7 @@ use std::str::from_utf8;
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -32,6 +32,7 @@ use std::fmt::Display;
 /// - `user_id` - unique user ID (numeric or name).
 /// - `permissions` - new permissions (optional)
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdatePermissions {
     /// Unique user ID (numeric or name).
     #[serde(skip)]
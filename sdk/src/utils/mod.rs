@@ -21,6 +21,8 @@ pub mod checksum;
 pub mod crypto;
 pub mod duration;
 pub mod expiry;
+#[cfg(feature = "openapi")]
+mod openapi;
 pub mod personal_access_token_expiry;
 pub mod sizeable;
 pub mod text;
@@ -0,0 +1,89 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+// The OpenAPI schemas of the types with the custom serialization, which can't be derived.
+
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::utils::topic_size::MaxTopicSize;
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+macro_rules! impl_schema {
+    ($type:ty, $schema:expr) => {
+        impl PartialSchema for $type {
+            fn schema() -> RefOr<Schema> {
+                $schema.into()
+            }
+        }
+
+        impl ToSchema for $type {}
+    };
+}
+
+fn unsigned_integer(description: &str) -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::Integer)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+        .minimum(Some(0))
+        .description(Some(description))
+}
+
+impl_schema!(
+    IggyTimestamp,
+    unsigned_integer("The number of microseconds since the Unix epoch.")
+);
+
+impl_schema!(
+    IggyDuration,
+    unsigned_integer("The duration in microseconds.")
+);
+
+impl_schema!(
+    IggyExpiry,
+    unsigned_integer(
+        "The expiry in microseconds, 0 for the server default, or 18446744073709551615 (u64::MAX) to never expire."
+    )
+);
+
+impl_schema!(
+    MaxTopicSize,
+    unsigned_integer(
+        "The maximum size in bytes, 0 for the server default, or 18446744073709551615 (u64::MAX) for the unlimited size."
+    )
+);
+
+impl_schema!(
+    IggyByteSize,
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .description(Some("The human-readable size, e.g. `1.50 KB`."))
+        .examples(["1.50 KB"])
+);
+
+impl_schema!(
+    CompressionAlgorithm,
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .enum_values(Some(["none", "gzip"]))
+        .description(Some("The compression algorithm of the messages."))
+);
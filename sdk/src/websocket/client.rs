@@ -0,0 +1,560 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::servers::ServerList;
+use crate::binary::session::{rejoin_consumer_groups, ClientSession};
+use crate::binary::subscriptions::{MessageSubscription, MessageSubscriptions};
+use crate::binary::{BinaryTransport, ClientState};
+use crate::client::{AutoLogin, Client, Credentials, PersonalAccessTokenClient, UserClient};
+use crate::command::{Command, ERROR_DETAILS_FLAG};
+use crate::consumer::Consumer;
+use crate::diagnostic::DiagnosticEvent;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::subscribe_messages::PUSHED_MESSAGES_STATUS;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::websocket::config::WebSocketClientConfig;
+use async_broadcast::{broadcast, Receiver, Sender};
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, StreamExt};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::{error, info, trace, warn};
+
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const SUBSCRIPTION_ID_LENGTH: usize = 4;
+const NAME: &str = "Iggy";
+
+/// WebSocket client for interacting with the Iggy API.
+///
+/// It uses the same binary framing as the TCP client, each request and response being a single binary message.
+/// Additionally, it supports the message subscriptions, for which the server pushes the messages as they are appended.
+#[derive(Debug)]
+pub struct WebSocketClient {
+    pub(crate) config: Arc<WebSocketClientConfig>,
+    pub(crate) state: Mutex<ClientState>,
+    connection: Mutex<Option<WebSocketConnection>>,
+    servers: ServerList<String>,
+    current_server: Mutex<Option<String>>,
+    session: ClientSession,
+    events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
+    connected_at: Mutex<Option<IggyTimestamp>>,
+    subscriptions: Arc<MessageSubscriptions>,
+}
+
+#[derive(Debug)]
+struct WebSocketConnection {
+    sink: SplitSink<WebSocketStream<TcpStream>, Message>,
+    responses: mpsc::Receiver<Result<Bytes, IggyError>>,
+    reader: JoinHandle<()>,
+}
+
+impl Drop for WebSocketConnection {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+impl Default for WebSocketClient {
+    fn default() -> Self {
+        WebSocketClient::create(Arc::new(WebSocketClientConfig::default())).unwrap()
+    }
+}
+
+#[async_trait]
+impl Client for WebSocketClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        WebSocketClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        WebSocketClient::disconnect(self).await
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        WebSocketClient::shutdown(self).await
+    }
+
+    async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
+        self.events.1.clone()
+    }
+}
+
+#[async_trait]
+impl BinaryTransport for WebSocketClient {
+    async fn get_state(&self) -> ClientState {
+        *self.state.lock().await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        *self.state.lock().await = state;
+    }
+
+    async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError> {
+        command.validate()?;
+        self.send_raw_with_response(command.code(), command.to_bytes())
+            .await
+    }
+
+    async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        let result = self.send_raw(code, payload.clone()).await;
+        if result.is_ok() {
+            return result;
+        }
+
+        let error = result.unwrap_err();
+        if !matches!(
+            error,
+            IggyError::Disconnected
+                | IggyError::EmptyResponse
+                | IggyError::Unauthenticated
+                | IggyError::ServerShuttingDown
+        ) {
+            return Err(error);
+        }
+
+        if !self.config.reconnection.enabled {
+            return Err(IggyError::Disconnected);
+        }
+
+        let server_address = self.current_server.lock().await.clone();
+        if let Some(server_address) = &server_address {
+            if error != IggyError::Unauthenticated {
+                self.servers.mark_failed(server_address);
+            }
+        }
+
+        self.disconnect().await?;
+        info!(
+            "Reconnecting to the server: {}",
+            server_address.unwrap_or_else(|| self.config.server_address.clone()),
+        );
+        self.connect().await?;
+        self.send_raw(code, payload).await
+    }
+
+    async fn publish_event(&self, event: DiagnosticEvent) {
+        if let Err(error) = self.events.0.broadcast(event).await {
+            error!("Failed to send a WebSocket diagnostic event: {error}");
+        }
+    }
+
+    fn get_heartbeat_interval(&self) -> IggyDuration {
+        self.config.heartbeat_interval
+    }
+
+    fn session(&self) -> &ClientSession {
+        &self.session
+    }
+}
+
+impl BinaryClient for WebSocketClient {}
+
+impl WebSocketClient {
+    /// Creates a new WebSocket client for the provided server address.
+    pub fn new(server_address: &str, auto_sign_in: AutoLogin) -> Result<Self, IggyError> {
+        Self::create(Arc::new(WebSocketClientConfig {
+            server_address: server_address.to_string(),
+            auto_login: auto_sign_in,
+            ..Default::default()
+        }))
+    }
+
+    /// Create a new WebSocket client for the provided configuration.
+    pub fn create(config: Arc<WebSocketClientConfig>) -> Result<Self, IggyError> {
+        let subscriptions = MessageSubscriptions::new(config.subscription_buffer_size);
+        Ok(Self {
+            servers: ServerList::parse(&config.server_address)?,
+            config,
+            state: Mutex::new(ClientState::Disconnected),
+            connection: Mutex::new(None),
+            current_server: Mutex::new(None),
+            session: ClientSession::default(),
+            events: broadcast(1000),
+            connected_at: Mutex::new(None),
+            subscriptions: Arc::new(subscriptions),
+        })
+    }
+
+    /// Subscribes to the messages of the topic, which are then pushed by the server as they are appended.
+    /// The returned stream yields the batches of at most `count` messages, starting from the provided strategy.
+    /// With non-zero `credits`, the server pushes only that many batches until more credits are granted.
+    /// The subscription is bound to the current connection and ends once the connection is lost.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        credits: u32,
+    ) -> Result<MessageSubscription, IggyError> {
+        self.subscriptions
+            .subscribe(
+                self,
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                strategy,
+                count,
+                auto_commit,
+                credits,
+            )
+            .await
+    }
+
+    /// Cancels the subscription, after which its stream ends.
+    pub async fn unsubscribe_messages(&self, subscription_id: u32) -> Result<(), IggyError> {
+        self.subscriptions.unsubscribe(self, subscription_id).await
+    }
+
+    /// Allows the server to push the given number of additional batches for the subscription created with the credits.
+    pub async fn grant_message_credits(
+        &self,
+        subscription_id: u32,
+        credits: u32,
+    ) -> Result<(), IggyError> {
+        self.subscriptions
+            .grant_credits(self, subscription_id, credits)
+            .await
+    }
+
+    async fn connect(&self) -> Result<(), IggyError> {
+        match self.get_state().await {
+            ClientState::Shutdown => {
+                trace!("Cannot connect. Client is shutdown.");
+                return Err(IggyError::ClientShutdown);
+            }
+            ClientState::Connected | ClientState::Authenticating | ClientState::Authenticated => {
+                trace!("Client is already connected.");
+                return Ok(());
+            }
+            ClientState::Connecting => {
+                trace!("Client is already connecting.");
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        self.set_state(ClientState::Connecting).await;
+        if let Some(connected_at) = self.connected_at.lock().await.as_ref() {
+            let now = IggyTimestamp::now();
+            let elapsed = now.as_micros() - connected_at.as_micros();
+            let interval = self.config.reconnection.reestablish_after.as_micros();
+            trace!(
+                "Elapsed time since last connection: {}",
+                IggyDuration::from(elapsed)
+            );
+            if elapsed < interval {
+                let remaining = IggyDuration::from(interval - elapsed);
+                info!("Trying to connect to the server in: {remaining}",);
+                sleep(remaining.get_duration()).await;
+            }
+        }
+
+        let mut retry_count = 0;
+        let connection;
+        let remote_address;
+        'connection: loop {
+            for server_address in self.servers.candidates() {
+                info!("{NAME} client is connecting to server: {server_address}...");
+                let started_at = Instant::now();
+                match self.open_connection(&server_address).await {
+                    Ok(established_connection) => {
+                        self.servers
+                            .mark_connected(&server_address, started_at.elapsed());
+                        self.current_server
+                            .lock()
+                            .await
+                            .replace(server_address.clone());
+                        remote_address = server_address;
+                        connection = established_connection;
+                        break 'connection;
+                    }
+                    Err(_) => {
+                        error!("Failed to connect to server: {server_address}");
+                        self.servers.mark_failed(&server_address);
+                    }
+                }
+            }
+
+            if !self.config.reconnection.enabled {
+                warn!("Automatic reconnection is disabled.");
+                self.set_state(ClientState::Disconnected).await;
+                return Err(IggyError::CannotEstablishConnection);
+            }
+
+            let unlimited_retries = self.config.reconnection.max_retries.is_none();
+            let max_retries = self.config.reconnection.max_retries.unwrap_or_default();
+            let max_retries_str = if let Some(max_retries) = self.config.reconnection.max_retries {
+                max_retries.to_string()
+            } else {
+                "unlimited".to_string()
+            };
+
+            let interval_str = self.config.reconnection.interval.as_human_time_string();
+            if unlimited_retries || retry_count < max_retries {
+                retry_count += 1;
+                info!(
+                    "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
+                    self.config.server_address,
+                );
+                sleep(self.config.reconnection.interval.get_duration()).await;
+                continue;
+            }
+
+            self.set_state(ClientState::Disconnected).await;
+            self.publish_event(DiagnosticEvent::Disconnected).await;
+            return Err(IggyError::CannotEstablishConnection);
+        }
+
+        let now = IggyTimestamp::now();
+        info!("{NAME} client has connected to server: {remote_address} at {now}",);
+        self.set_state(ClientState::Connected).await;
+        self.connection.lock().await.replace(connection);
+        self.connected_at.lock().await.replace(now);
+        self.publish_event(DiagnosticEvent::Connected).await;
+
+        let credentials = match &self.config.auto_login {
+            AutoLogin::Enabled(credentials) => credentials.clone(),
+            AutoLogin::Disabled => match self.session.credentials() {
+                Some(credentials) => credentials,
+                None => {
+                    info!("Automatic sign-in is disabled.");
+                    return Ok(());
+                }
+            },
+        };
+
+        info!("{NAME} WebSocket client is signing in...");
+        self.set_state(ClientState::Authenticating).await;
+        match &credentials {
+            Credentials::UsernamePassword(username, password) => {
+                self.login_user(username, password).await?;
+                self.publish_event(DiagnosticEvent::SignedIn).await;
+                info!("{NAME} WebSocket client has signed in with the user credentials, username: {username}");
+            }
+            Credentials::PersonalAccessToken(token) => {
+                self.login_with_personal_access_token(token).await?;
+                self.publish_event(DiagnosticEvent::SignedIn).await;
+                info!("{NAME} WebSocket client has signed in with a personal access token.");
+            }
+        }
+        rejoin_consumer_groups(self).await;
+        Ok(())
+    }
+
+    async fn open_connection(
+        &self,
+        server_address: &str,
+    ) -> Result<WebSocketConnection, IggyError> {
+        let stream = TcpStream::connect(server_address).await.map_err(|error| {
+            error!("Failed to establish TCP connection to the server: {error}");
+            IggyError::CannotEstablishConnection
+        })?;
+        let websocket_config = WebSocketConfig::default()
+            .max_message_size(Some(self.config.max_message_size))
+            .max_frame_size(Some(self.config.max_message_size));
+        let (stream, _) = tokio_tungstenite::client_async_with_config(
+            format!("ws://{server_address}/"),
+            stream,
+            Some(websocket_config),
+        )
+        .await
+        .map_err(|error| {
+            error!("Failed to perform WebSocket handshake: {error}");
+            IggyError::CannotEstablishConnection
+        })?;
+
+        let (sink, stream) = stream.split();
+        let (responses_sender, responses) = mpsc::channel(1);
+        let reader = tokio::spawn(read_frames(
+            stream,
+            responses_sender,
+            self.subscriptions.clone(),
+        ));
+        Ok(WebSocketConnection {
+            sink,
+            responses,
+            reader,
+        })
+    }
+
+    async fn shutdown(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Shutdown {
+            return Ok(());
+        }
+
+        info!("Shutting down the {NAME} WebSocket client.");
+        let connection = self.connection.lock().await.take();
+        if let Some(mut connection) = connection {
+            let _ = connection.sink.close().await;
+        }
+        self.subscriptions.clear();
+        self.set_state(ClientState::Shutdown).await;
+        self.publish_event(DiagnosticEvent::Shutdown).await;
+        info!("{NAME} WebSocket client has been shutdown.");
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Ok(());
+        }
+
+        info!("{NAME} WebSocket client is disconnecting from server...");
+        self.set_state(ClientState::Disconnected).await;
+        let connection = self.connection.lock().await.take();
+        if let Some(mut connection) = connection {
+            let _ = connection.sink.close().await;
+        }
+        self.subscriptions.clear();
+        self.publish_event(DiagnosticEvent::Disconnected).await;
+        let now = IggyTimestamp::now();
+        info!("{NAME} WebSocket client has disconnected from server at: {now}.");
+        Ok(())
+    }
+
+    async fn send_raw(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        match self.get_state().await {
+            ClientState::Shutdown => {
+                trace!("Cannot send data. Client is shutdown.");
+                return Err(IggyError::ClientShutdown);
+            }
+            ClientState::Disconnected => {
+                trace!("Cannot send data. Client is not connected.");
+                return Err(IggyError::NotConnected);
+            }
+            ClientState::Connecting => {
+                trace!("Cannot send data. Client is still connecting.");
+                return Err(IggyError::NotConnected);
+            }
+            _ => {}
+        }
+
+        let mut connection = self.connection.lock().await;
+        if let Some(connection) = connection.as_mut() {
+            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+            let mut request =
+                BytesMut::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
+            request.put_u32_le(payload_length as u32);
+            request.put_u32_le(code | ERROR_DETAILS_FLAG);
+            request.put_slice(&payload);
+            trace!("Sending a WebSocket request with code: {code}");
+            connection
+                .sink
+                .send(Message::Binary(request.freeze()))
+                .await
+                .map_err(|error| {
+                    error!("Failed to send a WebSocket request: {error}");
+                    IggyError::Disconnected
+                })?;
+            trace!("Sent a WebSocket request with code: {code}, waiting for a response...");
+            return connection
+                .responses
+                .recv()
+                .await
+                .unwrap_or(Err(IggyError::Disconnected));
+        }
+
+        error!("Cannot send data. Client is not connected.");
+        Err(IggyError::NotConnected)
+    }
+}
+
+/// Reads the frames sent by the server, routing the pushed messages to their subscriptions
+/// and the rest of them, being the command responses, to the awaiting request.
+async fn read_frames(
+    mut stream: SplitStream<WebSocketStream<TcpStream>>,
+    responses: mpsc::Sender<Result<Bytes, IggyError>>,
+    subscriptions: Arc<MessageSubscriptions>,
+) {
+    while let Some(message) = stream.next().await {
+        let frame = match message {
+            Ok(Message::Binary(frame)) => frame,
+            Ok(Message::Close(_)) => break,
+            Ok(_) => continue,
+            Err(error) => {
+                error!("Failed to read WebSocket message: {error}");
+                break;
+            }
+        };
+
+        if frame.len() < RESPONSE_INITIAL_BYTES_LENGTH {
+            error!(
+                "Received an invalid WebSocket frame of {} bytes.",
+                frame.len()
+            );
+            continue;
+        }
+
+        let status = u32::from_le_bytes(frame[..4].try_into().unwrap());
+        let length = u32::from_le_bytes(frame[4..8].try_into().unwrap()) as usize;
+        let payload = frame.slice(RESPONSE_INITIAL_BYTES_LENGTH..);
+        if status == PUSHED_MESSAGES_STATUS {
+            if payload.len() < SUBSCRIPTION_ID_LENGTH {
+                error!("Received an invalid pushed messages frame.");
+                continue;
+            }
+
+            let subscription_id =
+                u32::from_le_bytes(payload[..SUBSCRIPTION_ID_LENGTH].try_into().unwrap());
+            subscriptions
+                .route(subscription_id, payload.slice(SUBSCRIPTION_ID_LENGTH..))
+                .await;
+            continue;
+        }
+
+        let response = if status != 0 {
+            error!(
+                "Received an invalid response with status: {} ({}).",
+                status,
+                IggyError::from_code_as_string(status)
+            );
+            let details = payload.get(..length).unwrap_or_default();
+            Err(IggyError::from_code_and_details(status, details))
+        } else if length <= 1 {
+            Ok(Bytes::new())
+        } else {
+            Ok(payload.slice(..length.min(payload.len())))
+        };
+
+        if responses.send(response).await.is_err() {
+            break;
+        }
+    }
+
+    subscriptions.clear();
+}
@@ -0,0 +1,153 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::servers::SERVER_ADDRESS_SEPARATOR;
+use crate::binary::subscriptions::DEFAULT_SUBSCRIPTION_BUFFER_SIZE;
+use crate::client::AutoLogin;
+use crate::utils::duration::IggyDuration;
+use std::str::FromStr;
+
+/// Configuration for the WebSocket client.
+#[derive(Debug, Clone)]
+pub struct WebSocketClientConfig {
+    /// The address of the WebSocket server to connect to, or the comma-separated list of the bootstrap servers,
+    /// e.g. `127.0.0.1:8092,127.0.0.2:8092`, which are used for failover when the current server is unavailable.
+    pub server_address: String,
+    /// Whether to automatically login user after establishing connection.
+    pub auto_login: AutoLogin,
+    // Whether to automatically reconnect when disconnected.
+    pub reconnection: WebSocketClientReconnectionConfig,
+    /// Interval of heartbeats sent by the client
+    pub heartbeat_interval: IggyDuration,
+    /// The maximum size of a single WebSocket message in bytes.
+    pub max_message_size: usize,
+    /// The number of polled batches buffered per subscription before the connection is backpressured.
+    pub subscription_buffer_size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebSocketClientReconnectionConfig {
+    pub enabled: bool,
+    pub max_retries: Option<u32>,
+    pub interval: IggyDuration,
+    pub reestablish_after: IggyDuration,
+}
+
+impl Default for WebSocketClientReconnectionConfig {
+    fn default() -> WebSocketClientReconnectionConfig {
+        WebSocketClientReconnectionConfig {
+            enabled: true,
+            max_retries: None,
+            interval: IggyDuration::from_str("1s").unwrap(),
+            reestablish_after: IggyDuration::from_str("5s").unwrap(),
+        }
+    }
+}
+
+impl Default for WebSocketClientConfig {
+    fn default() -> WebSocketClientConfig {
+        WebSocketClientConfig {
+            server_address: "127.0.0.1:8092".to_string(),
+            auto_login: AutoLogin::Disabled,
+            reconnection: WebSocketClientReconnectionConfig::default(),
+            heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            max_message_size: 1000 * 1000 * 10,
+            subscription_buffer_size: DEFAULT_SUBSCRIPTION_BUFFER_SIZE,
+        }
+    }
+}
+
+/// Builder for the WebSocket client configuration.
+///
+/// Allows configuring the WebSocket client with custom settings or using defaults:
+/// - `server_address`: Default is "127.0.0.1:8092".
+/// - `auto_login`: Default is AutoLogin::Disabled.
+/// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
+/// - `heartbeat_interval`: Default is 5 seconds.
+/// - `max_message_size`: Default is 10MB (10,000,000 bytes).
+/// - `subscription_buffer_size`: Default is 100 batches.
+#[derive(Debug, Default)]
+pub struct WebSocketClientConfigBuilder {
+    config: WebSocketClientConfig,
+}
+
+impl WebSocketClientConfigBuilder {
+    /// Creates a new builder instance with default configuration values.
+    pub fn new() -> Self {
+        WebSocketClientConfigBuilder::default()
+    }
+
+    /// Sets the server address. Defaults to "127.0.0.1:8092".
+    pub fn with_server_address(mut self, server_address: String) -> Self {
+        self.config.server_address = server_address;
+        self
+    }
+
+    /// Sets the list of the bootstrap servers. The client connects to the healthiest of them
+    /// and fails over to the next ones when the current server becomes unavailable.
+    pub fn with_server_addresses(mut self, server_addresses: Vec<String>) -> Self {
+        self.config.server_address = server_addresses.join(&SERVER_ADDRESS_SEPARATOR.to_string());
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config.auto_login = auto_sign_in;
+        self
+    }
+
+    pub fn with_enabled_reconnection(mut self) -> Self {
+        self.config.reconnection.enabled = true;
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.config.reconnection.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, interval: IggyDuration) -> Self {
+        self.config.reconnection.interval = interval;
+        self
+    }
+
+    /// Sets the heartbeat interval. Defaults to 5000ms.
+    pub fn with_heartbeat_interval(mut self, interval: IggyDuration) -> Self {
+        self.config.heartbeat_interval = interval;
+        self
+    }
+
+    /// Sets the maximum size of a single WebSocket message in bytes. Defaults to 10MB (10,000,000 bytes).
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.config.max_message_size = max_message_size;
+        self
+    }
+
+    /// Sets the number of polled batches buffered per subscription. Defaults to 100.
+    pub fn with_subscription_buffer_size(mut self, subscription_buffer_size: usize) -> Self {
+        self.config.subscription_buffer_size = subscription_buffer_size;
+        self
+    }
+
+    /// Finalizes the builder and returns the `WebSocketClientConfig`.
+    pub fn build(self) -> WebSocketClientConfig {
+        self.config
+    }
+}
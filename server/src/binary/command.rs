@@ -27,6 +27,10 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::{
+    get_schema_handler, get_schemas_handler, register_schema_handler,
+    update_schema_compatibility_handler, update_schema_validation_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
@@ -104,6 +108,9 @@ async fn try_handle(
         ServerCommand::GetClients(command) => {
             get_clients_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::DisconnectClient(command) => {
+            disconnect_client_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetUser(command) => {
             get_user_handler::handle(command, sender, session, system).await
         }
@@ -149,6 +156,15 @@ async fn try_handle(
         ServerCommand::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::SubscribeMessages(command) => {
+            subscribe_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UnsubscribeMessages(command) => {
+            unsubscribe_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GrantMessageCredits(command) => {
+            grant_message_credits_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, session, system).await
         }
@@ -218,11 +234,29 @@ async fn try_handle(
         ServerCommand::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetSchema(command) => {
+            get_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetSchemas(command) => {
+            get_schemas_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::RegisterSchema(command) => {
+            register_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateSchemaCompatibility(command) => {
+            update_schema_compatibility_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::UpdateSchemaValidation(command) => {
+            update_schema_validation_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::FlushUnsavedBuffer(command) => {
             flush_unsaved_buffer_handler::handle(command, sender, session, system).await
         }
         ServerCommand::GetSnapshotFile(command) => {
             get_snapshot::handle(command, sender, session, system).await
         }
+        ServerCommand::ReloadConfig(command) => {
+            reload_config_handler::handle(command, sender, session, system).await
+        }
     }
 }
@@ -17,7 +17,10 @@
  */
 
 pub mod flush_unsaved_buffer_handler;
+pub mod grant_message_credits_handler;
 pub mod poll_messages_handler;
 pub mod send_messages_handler;
+pub mod subscribe_messages_handler;
+pub mod unsubscribe_messages_handler;
 
 pub const COMPONENT: &str = "MESSAGE_HANDLER";
@@ -35,17 +35,34 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let system = system.read().await;
-    let messages = system
-        .poll_messages(
-            session,
-            &command.consumer,
-            &command.stream_id,
-            &command.topic_id,
-            command.partition_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
-        )
-        .await
+    let args = PollingArgs::new(command.strategy, command.count, command.auto_commit);
+    let messages = if command.max_wait.as_micros() > 0 {
+        system
+            .poll_messages_with_wait(
+                session,
+                &command.consumer,
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                args,
+                command.max_wait,
+            )
+            .await
+    } else {
+        system
+            .read()
+            .await
+            .poll_messages(
+                session,
+                &command.consumer,
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                args,
+            )
+            .await
+    };
+    let messages = messages
         .with_error_context(|error| format!(
             "{COMPONENT} (error: {error}) - failed to poll messages for consumer: {}, stream ID: {}, topic ID: {}, partition_id: {:?}, session: {}.",
             command.consumer, command.stream_id, command.topic_id, command.partition_id, session
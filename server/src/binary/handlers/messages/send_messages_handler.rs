@@ -17,6 +17,7 @@
  */
 
 use crate::binary::handlers::messages::COMPONENT;
+use crate::binary::mapper;
 use crate::binary::sender::SenderKind;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
@@ -38,6 +39,7 @@ pub async fn handle(
     let stream_id = command.stream_id.clone();
     let topic_id = command.topic_id.clone();
     let partitioning = command.partitioning.clone();
+    let report_offsets = command.report_offsets;
     let mut messages = command.messages;
     messages.iter_mut().for_each(|msg| {
         if msg.id == 0 {
@@ -45,7 +47,7 @@ pub async fn handle(
         }
     });
     // TODO(haze): Add confirmation level after testing is complete
-    system
+    let appended_messages = system
         .append_messages(session, stream_id, topic_id, partitioning, messages, None)
         .await
         .with_error_context(|error| {
@@ -54,6 +56,12 @@ pub async fn handle(
                 command.stream_id, command.topic_id, command.partitioning, session
             )
         })?;
-    sender.send_empty_ok_response().await?;
+    match appended_messages {
+        Some(appended_messages) if report_offsets => {
+            let appended_messages = mapper::map_appended_messages(&appended_messages);
+            sender.send_ok_response(&appended_messages).await?;
+        }
+        _ => sender.send_empty_ok_response().await?,
+    }
     Ok(())
 }
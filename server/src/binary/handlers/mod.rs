@@ -21,6 +21,7 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod segments;
 pub mod streams;
 pub mod system;
@@ -16,11 +16,13 @@
  * under the License.
  */
 
+pub mod disconnect_client_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
 pub mod get_me_handler;
 pub mod get_snapshot;
 pub mod get_stats_handler;
 pub mod ping_handler;
+pub mod reload_config_handler;
 
 pub const COMPONENT: &str = "SYSTEM_HANDLER";
@@ -26,14 +26,32 @@ use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::appended_messages::AppendedMessages;
+use iggy::models::config_reload_info::ConfigReloadInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use iggy::models::messages::PolledMessages;
+use iggy::models::schema::{Schema, TopicSchemas};
 use iggy::models::stats::Stats;
 use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
 use tokio::sync::RwLock;
 
+pub fn map_config_reload_info(info: &ConfigReloadInfo) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_config_keys(&info.applied, &mut bytes);
+    extend_config_keys(&info.requires_restart, &mut bytes);
+    bytes.freeze()
+}
+
+fn extend_config_keys(keys: &[String], bytes: &mut BytesMut) {
+    bytes.put_u32_le(keys.len() as u32);
+    for key in keys {
+        bytes.put_u32_le(key.len() as u32);
+        bytes.put_slice(key.as_bytes());
+    }
+}
+
 pub fn map_stats(stats: &Stats) -> Bytes {
     let mut bytes = BytesMut::with_capacity(104);
     bytes.put_u32_le(stats.process_id);
@@ -82,6 +100,16 @@ pub fn map_stats(stats: &Stats) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_appended_messages(appended_messages: &AppendedMessages) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(36);
+    bytes.put_u32_le(appended_messages.partition_id);
+    bytes.put_u64_le(appended_messages.base_offset);
+    bytes.put_u64_le(appended_messages.last_offset);
+    bytes.put_u64_le(appended_messages.base_timestamp);
+    bytes.put_u64_le(appended_messages.last_timestamp);
+    bytes.freeze()
+}
+
 pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     let mut bytes = BytesMut::with_capacity(20);
     bytes.put_u32_le(offset.partition_id);
@@ -233,6 +261,24 @@ pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) ->
     bytes.freeze()
 }
 
+pub fn map_schema(schema: &Schema) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_schema(schema, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_topic_schemas(schemas: &TopicSchemas) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(schemas.stream_id);
+    bytes.put_u32_le(schemas.topic_id);
+    bytes.put_u8(schemas.compatibility.as_code());
+    bytes.put_slice(&schemas.validation.to_bytes());
+    for schema in &schemas.schemas {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes.freeze()
+}
+
 fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
     bytes.put_u32_le(stream.stream_id);
     bytes.put_u64_le(stream.created_at.into());
@@ -280,6 +326,10 @@ fn extend_client(client: &Client, bytes: &mut BytesMut) {
     let transport: u8 = match client.transport {
         Transport::Tcp => 1,
         Transport::Quic => 2,
+        Transport::WebSocket => 3,
+        Transport::Kafka => 4,
+        Transport::Mqtt => 5,
+        Transport::Http => 6,
     };
     bytes.put_u8(transport);
     let address = client.session.ip_address.to_string();
@@ -308,3 +358,14 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut)
         }
     }
 }
+
+fn extend_schema(schema: &Schema, bytes: &mut BytesMut) {
+    bytes.put_u32_le(schema.id);
+    bytes.put_u32_le(schema.stream_id);
+    bytes.put_u32_le(schema.topic_id);
+    bytes.put_u32_le(schema.version);
+    bytes.put_u8(schema.format.as_code());
+    bytes.put_u64_le(schema.created_at.into());
+    bytes.put_u32_le(schema.definition.len() as u32);
+    bytes.put_slice(schema.definition.as_bytes());
+}
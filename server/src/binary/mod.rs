@@ -18,7 +18,8 @@
 
 pub mod command;
 mod handlers;
-mod mapper;
+pub(crate) mod mapper;
 pub mod sender;
+pub mod subscriptions;
 
 pub const COMPONENT: &str = "BINARY";
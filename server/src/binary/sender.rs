@@ -17,14 +17,24 @@
  */
 
 use std::future::Future;
+use std::sync::Arc;
 
+use crate::binary::subscriptions::{MessageSubscriptions, PushSink};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::tcp_sender::TcpSender;
 use crate::tcp::tcp_tls_sender::TcpTlsSender;
+use crate::websocket::websocket_sender::{WebSocketSender, WebSocketSink};
 use crate::{quic::quic_sender::QuicSender, server_error::ServerError};
+use bytes::Bytes;
+use futures::stream::SplitStream;
+use iggy::command::ERROR_DETAILS_FLAG;
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
 use tokio::net::TcpStream;
+use tokio::sync::Mutex;
 use tokio_native_tls::TlsStream;
+use tokio_tungstenite::WebSocketStream;
 
 macro_rules! forward_async_methods {
     (
@@ -40,12 +50,29 @@ macro_rules! forward_async_methods {
                     Self::Tcp(d) => d.$method_name($( $arg ),*).await,
                     Self::TcpTls(s) => s.$method_name($( $arg ),*).await,
                     Self::Quic(s) => s.$method_name($( $arg ),*).await,
+                    Self::WebSocket(s) => s.$method_name($( $arg ),*).await,
                 }
             }
         )*
     }
 }
 
+/// Strips the `ERROR_DETAILS_FLAG` from the command code (the first 4 bytes of the command),
+/// returning whether the client has requested the error details.
+pub(crate) fn take_error_details_flag(command: &mut [u8]) -> bool {
+    let Some(code) = command.get(..4) else {
+        return false;
+    };
+
+    let code = u32::from_le_bytes(code.try_into().unwrap());
+    if code & ERROR_DETAILS_FLAG == 0 {
+        return false;
+    }
+
+    command[..4].copy_from_slice(&(code & !ERROR_DETAILS_FLAG).to_le_bytes());
+    true
+}
+
 pub trait Sender {
     fn read(&mut self, buffer: &mut [u8]) -> impl Future<Output = Result<usize, IggyError>> + Send;
     fn send_empty_ok_response(&mut self) -> impl Future<Output = Result<(), IggyError>> + Send;
@@ -57,6 +84,10 @@ pub trait Sender {
         &mut self,
         error: IggyError,
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    /// Sets the correlation ID of the request being handled, which is included in its response.
+    fn set_correlation_id(&mut self, correlation_id: Option<u32>);
+    /// Sets whether the client handles the error details in the payload of the error response.
+    fn set_error_details(&mut self, error_details: bool);
     fn shutdown(&mut self) -> impl Future<Output = Result<(), ServerError>> + Send;
 }
 
@@ -64,24 +95,94 @@ pub enum SenderKind {
     Tcp(TcpSender),
     TcpTls(TcpTlsSender),
     Quic(QuicSender),
+    WebSocket(WebSocketSender),
 }
 
 impl SenderKind {
-    pub fn get_tcp_sender(stream: TcpStream) -> Self {
-        Self::Tcp(TcpSender { stream })
+    pub fn get_tcp_sender(stream: TcpStream, session: Arc<Session>, system: SharedSystem) -> Self {
+        let (reader, writer) = stream.into_split();
+        let writer = Arc::new(Mutex::new(writer));
+        Self::Tcp(TcpSender {
+            reader,
+            writer: writer.clone(),
+            correlation_id: None,
+            error_details: false,
+            subscriptions: MessageSubscriptions::new(session, system, PushSink::Tcp(writer)),
+        })
     }
 
-    pub fn get_tcp_tls_sender(stream: TlsStream<TcpStream>) -> Self {
-        Self::TcpTls(TcpTlsSender { stream })
+    pub fn get_tcp_tls_sender(
+        stream: TlsStream<TcpStream>,
+        session: Arc<Session>,
+        system: SharedSystem,
+    ) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
+        let writer = Arc::new(Mutex::new(writer));
+        Self::TcpTls(TcpTlsSender {
+            reader,
+            writer: writer.clone(),
+            correlation_id: None,
+            error_details: false,
+            subscriptions: MessageSubscriptions::new(session, system, PushSink::TcpTls(writer)),
+        })
     }
 
-    pub fn get_quic_sender(send_stream: SendStream, recv_stream: RecvStream) -> Self {
+    pub fn get_quic_sender(
+        send_stream: SendStream,
+        recv_stream: RecvStream,
+        subscriptions: MessageSubscriptions,
+    ) -> Self {
         Self::Quic(QuicSender {
             send: send_stream,
             recv: recv_stream,
+            error_details: false,
+            subscriptions,
+        })
+    }
+
+    pub fn get_websocket_sender(
+        stream: SplitStream<WebSocketStream<TcpStream>>,
+        sink: WebSocketSink,
+        subscriptions: MessageSubscriptions,
+    ) -> Self {
+        Self::WebSocket(WebSocketSender {
+            stream,
+            sink,
+            read_buffer: Bytes::new(),
+            correlation_id: None,
+            error_details: false,
+            subscriptions,
         })
     }
 
+    /// Returns the message subscriptions of the connection.
+    pub fn subscriptions(&self) -> &MessageSubscriptions {
+        match self {
+            Self::Tcp(s) => &s.subscriptions,
+            Self::TcpTls(s) => &s.subscriptions,
+            Self::Quic(s) => &s.subscriptions,
+            Self::WebSocket(s) => &s.subscriptions,
+        }
+    }
+
+    pub fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        match self {
+            Self::Tcp(s) => s.set_correlation_id(correlation_id),
+            Self::TcpTls(s) => s.set_correlation_id(correlation_id),
+            Self::Quic(s) => s.set_correlation_id(correlation_id),
+            Self::WebSocket(s) => s.set_correlation_id(correlation_id),
+        }
+    }
+
+    pub fn set_error_details(&mut self, error_details: bool) {
+        match self {
+            Self::Tcp(s) => s.set_error_details(error_details),
+            Self::TcpTls(s) => s.set_error_details(error_details),
+            Self::Quic(s) => s.set_error_details(error_details),
+            Self::WebSocket(s) => s.set_error_details(error_details),
+        }
+    }
+
     forward_async_methods! {
         async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
         async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
@@ -90,3 +191,28 @@ impl SenderKind {
         async fn shutdown(&mut self) -> Result<(), ServerError>;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_details_flag_should_be_taken_from_command_code() {
+        let mut command = [101u32 | ERROR_DETAILS_FLAG, 7]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>();
+
+        assert!(take_error_details_flag(&mut command));
+        assert_eq!(&command[..4], &101u32.to_le_bytes());
+        assert_eq!(&command[4..], &7u32.to_le_bytes());
+    }
+
+    #[test]
+    fn command_without_error_details_flag_should_be_left_intact() {
+        let mut command = 101u32.to_le_bytes().to_vec();
+
+        assert!(!take_error_details_flag(&mut command));
+        assert_eq!(command, 101u32.to_le_bytes());
+    }
+}
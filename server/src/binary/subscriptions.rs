@@ -0,0 +1,337 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::mapper;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::tcp_sender::TcpWriter;
+use crate::tcp::tcp_tls_sender::TcpTlsWriter;
+use crate::websocket::websocket_sender::WebSocketSink;
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::SinkExt;
+use iggy::error::IggyError;
+use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
+use iggy::messages::subscribe_messages::{
+    SubscribeMessages, PUSHED_MESSAGES_STATUS, SUBSCRIPTION_ENDED_PAYLOAD_LENGTH,
+};
+use iggy::models::messages::PolledMessages;
+use iggy::utils::duration::IggyDuration;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, Weak};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, info};
+
+/// The sending half of the connection, shared by the subscriptions pushing the messages with the command responses.
+#[derive(Clone)]
+pub enum PushSink {
+    Tcp(TcpWriter),
+    TcpTls(TcpTlsWriter),
+    /// Every pushed frame is sent over its own unidirectional stream.
+    Quic(quinn::Connection),
+    WebSocket(WebSocketSink),
+}
+
+impl PushSink {
+    async fn push(&self, frame: Bytes) -> Result<(), IggyError> {
+        match self {
+            Self::Tcp(writer) => writer
+                .lock()
+                .await
+                .write_all(&frame)
+                .await
+                .map_err(|_| IggyError::TcpError),
+            Self::TcpTls(writer) => writer
+                .lock()
+                .await
+                .write_all(&frame)
+                .await
+                .map_err(|_| IggyError::TcpError),
+            Self::Quic(connection) => {
+                let mut stream = connection
+                    .open_uni()
+                    .await
+                    .map_err(|_| IggyError::QuicError)?;
+                stream
+                    .write_all(&frame)
+                    .await
+                    .map_err(|_| IggyError::QuicError)?;
+                stream.finish().map_err(|_| IggyError::QuicError)
+            }
+            Self::WebSocket(sink) => sink
+                .lock()
+                .await
+                .send(Message::Binary(frame))
+                .await
+                .map_err(|_| IggyError::WebSocketError),
+        }
+    }
+}
+
+/// The active message subscriptions of a single connection. Each of them is a task polling the partition
+/// on behalf of the session and pushing every non-empty batch of messages to the client, until it's unsubscribed,
+/// the messages can't be polled anymore or the connection is closed. It can be cloned to be shared by the request streams of the same QUIC connection.
+#[derive(Clone)]
+pub struct MessageSubscriptions {
+    inner: Arc<Subscriptions>,
+}
+
+struct Subscriptions {
+    session: Arc<Session>,
+    system: SharedSystem,
+    sink: PushSink,
+    active: Mutex<HashMap<u32, ActiveSubscription>>,
+}
+
+struct ActiveSubscription {
+    task: JoinHandle<()>,
+    credits: Option<Arc<Semaphore>>,
+}
+
+impl MessageSubscriptions {
+    pub fn new(session: Arc<Session>, system: SharedSystem, sink: PushSink) -> Self {
+        Self {
+            inner: Arc::new(Subscriptions {
+                session,
+                system,
+                sink,
+                active: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// Starts pushing the messages for the subscription. The first batch is polled right away,
+    /// so that the invalid subscription (e.g. missing topic or permissions) is rejected with an error response.
+    pub async fn subscribe(&self, command: SubscribeMessages) -> Result<(), IggyError> {
+        let subscription_id = command.subscription_id;
+        let is_active = |active: &mut HashMap<u32, ActiveSubscription>| {
+            active.retain(|_, subscription| !subscription.task.is_finished());
+            active.contains_key(&subscription_id)
+        };
+        if is_active(&mut self.inner.active.lock().unwrap()) {
+            return Err(IggyError::MessageSubscriptionAlreadyExists(subscription_id));
+        }
+
+        let credits = match command.credits {
+            0 => None,
+            credits => Some(Arc::new(Semaphore::new(credits as usize))),
+        };
+        let max_wait = self.inner.system.read().await.config.polling.max_wait;
+        let subscription = Subscription {
+            command,
+            session: self.inner.session.clone(),
+            system: self.inner.system.clone(),
+            sink: self.inner.sink.clone(),
+            subscriptions: Arc::downgrade(&self.inner),
+            credits: credits.clone(),
+            max_wait,
+        };
+        let messages = subscription
+            .poll_messages(subscription.command.strategy, IggyDuration::default())
+            .await?;
+
+        let mut active = self.inner.active.lock().unwrap();
+        if is_active(&mut active) {
+            return Err(IggyError::MessageSubscriptionAlreadyExists(subscription_id));
+        }
+
+        info!(
+            "Created message subscription with ID: {subscription_id} for session: {}",
+            self.inner.session
+        );
+        active.insert(
+            subscription_id,
+            ActiveSubscription {
+                task: tokio::spawn(subscription.push_messages(messages)),
+                credits,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn unsubscribe(&self, subscription_id: u32) -> Result<(), IggyError> {
+        let Some(subscription) = self.inner.active.lock().unwrap().remove(&subscription_id) else {
+            return Err(IggyError::MessageSubscriptionNotFound(subscription_id));
+        };
+
+        subscription.task.abort();
+        info!(
+            "Deleted message subscription with ID: {subscription_id} for session: {}",
+            self.inner.session
+        );
+        Ok(())
+    }
+
+    /// Allows pushing the given number of additional batches, has no effect for the subscription without the credits limit.
+    pub fn grant_credits(&self, subscription_id: u32, credits: u32) -> Result<(), IggyError> {
+        let active = self.inner.active.lock().unwrap();
+        let Some(subscription) = active.get(&subscription_id) else {
+            return Err(IggyError::MessageSubscriptionNotFound(subscription_id));
+        };
+
+        if let Some(available) = &subscription.credits {
+            let credits =
+                (credits as usize).min(Semaphore::MAX_PERMITS - available.available_permits());
+            available.add_permits(credits);
+        }
+        Ok(())
+    }
+
+    pub fn unsubscribe_all(&self) {
+        self.inner.unsubscribe_all();
+    }
+}
+
+impl Subscriptions {
+    fn unsubscribe_all(&self) {
+        for (_, subscription) in self.active.lock().unwrap().drain() {
+            subscription.task.abort();
+        }
+    }
+}
+
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        self.unsubscribe_all();
+    }
+}
+
+impl Debug for MessageSubscriptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let active = self.inner.active.lock().unwrap();
+        f.debug_struct("MessageSubscriptions")
+            .field("session", &self.inner.session)
+            .field("subscriptions", &active.keys())
+            .finish()
+    }
+}
+
+struct Subscription {
+    command: SubscribeMessages,
+    session: Arc<Session>,
+    system: SharedSystem,
+    sink: PushSink,
+    subscriptions: Weak<Subscriptions>,
+    credits: Option<Arc<Semaphore>>,
+    max_wait: IggyDuration,
+}
+
+impl Subscription {
+    async fn poll_messages(
+        &self,
+        strategy: PollingStrategy,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        let command = &self.command;
+        self.system
+            .poll_messages_with_wait(
+                &self.session,
+                &command.consumer,
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                PollingArgs::new(strategy, command.count, command.auto_commit),
+                max_wait,
+            )
+            .await
+    }
+
+    /// Pushes the messages and keeps polling for the next ones, waiting for them to be appended. Except for the `Next`
+    /// strategy relying on the stored consumer offset, the polling continues from the offset following the last pushed message.
+    /// With the limited credits, the next batch is polled only once there's a credit available to push it.
+    async fn push_messages(self, messages: PolledMessages) {
+        let subscription_id = self.command.subscription_id;
+        let mut strategy = self.command.strategy;
+        let mut first_messages = Some(messages);
+        loop {
+            let credit = match &self.credits {
+                Some(credits) => match credits.acquire().await {
+                    Ok(credit) => Some(credit),
+                    Err(_) => return,
+                },
+                None => None,
+            };
+
+            let messages = match first_messages.take() {
+                Some(messages) => messages,
+                None => match self.poll_messages(strategy, self.max_wait).await {
+                    Ok(messages) => messages,
+                    Err(error) => {
+                        error!("Failed to poll messages for subscription with ID: {subscription_id}, session: {}. {error}", self.session);
+                        self.end(error).await;
+                        return;
+                    }
+                },
+            };
+
+            let Some(last_message) = messages.messages.last() else {
+                continue;
+            };
+
+            if strategy.kind != PollingKind::Next {
+                strategy = PollingStrategy::offset(last_message.offset + 1);
+            }
+            if let Err(error) = self.push(&messages).await {
+                debug!("Failed to push messages for subscription with ID: {subscription_id}, session: {}. {error}", self.session);
+                return;
+            }
+            if let Some(credit) = credit {
+                credit.forget();
+            }
+        }
+    }
+
+    /// Removes the subscription which can't be continued and pushes the last frame with the error code to the client.
+    async fn end(&self, error: IggyError) {
+        let subscription_id = self.command.subscription_id;
+        if let Some(subscriptions) = self.subscriptions.upgrade() {
+            subscriptions
+                .active
+                .lock()
+                .unwrap()
+                .remove(&subscription_id);
+        }
+
+        let mut frame = BytesMut::with_capacity(12 + SUBSCRIPTION_ENDED_PAYLOAD_LENGTH);
+        frame.put_u32_le(PUSHED_MESSAGES_STATUS);
+        frame.put_u32_le(SUBSCRIPTION_ENDED_PAYLOAD_LENGTH as u32);
+        frame.put_u32_le(subscription_id);
+        frame.put_u32_le(error.as_code());
+        if let Err(error) = self.sink.push(frame.freeze()).await {
+            debug!("Failed to push the end of subscription with ID: {subscription_id}, session: {}. {error}", self.session);
+        }
+        info!(
+            "Ended message subscription with ID: {subscription_id} for session: {}",
+            self.session
+        );
+    }
+
+    async fn push(&self, messages: &PolledMessages) -> Result<(), IggyError> {
+        let payload = mapper::map_polled_messages(messages);
+        let mut frame = BytesMut::with_capacity(12 + payload.len());
+        frame.put_u32_le(PUSHED_MESSAGES_STATUS);
+        frame.put_u32_le(payload.len() as u32);
+        frame.put_u32_le(self.command.subscription_id);
+        frame.put_slice(&payload);
+        self.sink.push(frame.freeze()).await
+    }
+}
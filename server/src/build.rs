@@ -21,6 +21,9 @@ use std::path::PathBuf;
 use vergen_git2::{BuildBuilder, CargoBuilder, Emitter, Git2Builder, RustcBuilder, SysinfoBuilder};
 
 fn main() -> Result<(), Box<dyn error::Error>> {
+    #[cfg(feature = "grpc")]
+    compile_protos()?;
+
     if option_env!("IGGY_CI_BUILD") == Some("true") {
         Emitter::default()
             .add_instructions(&BuildBuilder::all_build()?)?
@@ -46,3 +49,13 @@ fn main() -> Result<(), Box<dyn error::Error>> {
 
     Ok(())
 }
+
+/// Generates the gRPC gateway service and messages, using the vendored `protoc` so that it doesn't have to be installed.
+#[cfg(feature = "grpc")]
+fn compile_protos() -> Result<(), Box<dyn error::Error>> {
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    tonic_build::configure()
+        .bytes(["."])
+        .compile_protos(&["proto/iggy.proto"], &["proto"])?;
+    Ok(())
+}
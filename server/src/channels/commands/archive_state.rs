@@ -16,19 +16,21 @@
  * under the License.
  */
 
+use crate::channels::interval::ReloadableInterval;
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::StateMaintenanceConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
-use tokio::time;
 use tracing::{error, info, instrument, warn};
 
 pub struct StateArchiver {
     enabled: bool,
     overwrite: bool,
     interval: IggyDuration,
+    config_watcher: ConfigWatcher,
     sender: Sender<ArchiveStateCommand>,
 }
 
@@ -41,11 +43,16 @@ pub struct ArchiveStateCommand {
 pub struct ArchiveStateExecutor;
 
 impl StateArchiver {
-    pub fn new(config: &StateMaintenanceConfig, sender: Sender<ArchiveStateCommand>) -> Self {
+    pub fn new(
+        config: &StateMaintenanceConfig,
+        config_watcher: ConfigWatcher,
+        sender: Sender<ArchiveStateCommand>,
+    ) -> Self {
         Self {
             enabled: config.archiver_enabled,
             overwrite: config.overwrite,
             interval: config.interval,
+            config_watcher,
             sender,
         }
     }
@@ -59,9 +66,13 @@ impl StateArchiver {
         let overwrite = self.overwrite;
         let interval = self.interval;
         let sender = self.sender.clone();
+        let config_watcher = self.config_watcher.clone();
         info!("State archiver is enabled, state will be archived every: {interval}.");
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer =
+                ReloadableInterval::new("State archiver", interval, config_watcher, |config| {
+                    config.data_maintenance.state.interval
+                });
             loop {
                 interval_timer.tick().await;
                 sender
@@ -104,6 +115,7 @@ impl ServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
         &mut self,
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<ArchiveStateCommand>,
     ) {
         if !config.data_maintenance.archiver.enabled
@@ -112,7 +124,8 @@ impl ServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
             return;
         }
 
-        let state_archiver = StateArchiver::new(&config.data_maintenance.state, sender);
+        let state_archiver =
+            StateArchiver::new(&config.data_maintenance.state, config_watcher, sender);
         state_archiver.start();
     }
 
@@ -16,18 +16,20 @@
  * under the License.
  */
 
+use crate::channels::interval::ReloadableInterval;
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::PersonalAccessTokenCleanerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
-use tokio::time;
 use tracing::{debug, error, info, instrument};
 
 pub struct PersonalAccessTokenCleaner {
     enabled: bool,
     interval: IggyDuration,
+    config_watcher: ConfigWatcher,
     sender: Sender<CleanPersonalAccessTokensCommand>,
 }
 
@@ -40,11 +42,13 @@ pub struct CleanPersonalAccessTokensExecutor;
 impl PersonalAccessTokenCleaner {
     pub fn new(
         config: &PersonalAccessTokenCleanerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<CleanPersonalAccessTokensCommand>,
     ) -> Self {
         Self {
             enabled: config.enabled,
             interval: config.interval,
+            config_watcher,
             sender,
         }
     }
@@ -57,9 +61,15 @@ impl PersonalAccessTokenCleaner {
 
         let interval = self.interval;
         let sender = self.sender.clone();
+        let config_watcher = self.config_watcher.clone();
         info!("Personal access token cleaner is enabled, expired tokens will be deleted every: {interval}.");
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = ReloadableInterval::new(
+                "Personal access token cleaner",
+                interval,
+                config_watcher,
+                |config| config.personal_access_token.cleaner.interval,
+            );
             loop {
                 interval_timer.tick().await;
                 sender
@@ -109,10 +119,14 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
         &mut self,
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<CleanPersonalAccessTokensCommand>,
     ) {
-        let personal_access_token_cleaner =
-            PersonalAccessTokenCleaner::new(&config.personal_access_token.cleaner, sender);
+        let personal_access_token_cleaner = PersonalAccessTokenCleaner::new(
+            &config.personal_access_token.cleaner,
+            config_watcher,
+            sender,
+        );
         personal_access_token_cleaner.start();
     }
 
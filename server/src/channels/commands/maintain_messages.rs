@@ -17,7 +17,9 @@
  */
 
 use crate::archiver::ArchiverKind;
+use crate::channels::interval::ReloadableInterval;
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::MessagesMaintenanceConfig;
 use crate::map_toggle_str;
 use crate::streaming::systems::system::SharedSystem;
@@ -29,13 +31,13 @@ use iggy::locking::IggySharedMutFn;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
-use tokio::time;
 use tracing::{debug, error, info, instrument, trace};
 
 pub struct MessagesMaintainer {
     cleaner_enabled: bool,
     archiver_enabled: bool,
     interval: IggyDuration,
+    config_watcher: ConfigWatcher,
     sender: Sender<MaintainMessagesCommand>,
 }
 
@@ -51,12 +53,14 @@ pub struct MaintainMessagesExecutor;
 impl MessagesMaintainer {
     pub fn new(
         config: &MessagesMaintenanceConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<MaintainMessagesCommand>,
     ) -> Self {
         Self {
             cleaner_enabled: config.cleaner_enabled,
             archiver_enabled: config.archiver_enabled,
             interval: config.interval,
+            config_watcher,
             sender,
         }
     }
@@ -69,6 +73,7 @@ impl MessagesMaintainer {
 
         let interval = self.interval;
         let sender = self.sender.clone();
+        let config_watcher = self.config_watcher.clone();
         info!(
             "Message maintainer, cleaner is {}, archiver is {}, interval: {interval}",
             map_toggle_str(self.cleaner_enabled),
@@ -77,7 +82,12 @@ impl MessagesMaintainer {
         let clean_messages = self.cleaner_enabled;
         let archive_messages = self.archiver_enabled;
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = ReloadableInterval::new(
+                "Messages maintainer",
+                interval,
+                config_watcher,
+                |config| config.data_maintenance.messages.interval,
+            );
             loop {
                 interval_timer.tick().await;
                 sender
@@ -175,6 +185,7 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         &mut self,
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<MaintainMessagesCommand>,
     ) {
         if (!config.data_maintenance.archiver.enabled
@@ -185,7 +196,7 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         }
 
         let messages_maintainer =
-            MessagesMaintainer::new(&config.data_maintenance.messages, sender);
+            MessagesMaintainer::new(&config.data_maintenance.messages, config_watcher, sender);
         messages_maintainer.start();
     }
 
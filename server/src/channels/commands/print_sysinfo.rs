@@ -17,13 +17,13 @@
  */
 
 use crate::{
-    channels::server_command::ServerCommand, configs::server::ServerConfig,
+    channels::interval::ReloadableInterval, channels::server_command::ServerCommand,
+    configs::reload::ConfigWatcher, configs::server::ServerConfig,
     streaming::systems::system::SharedSystem,
 };
 use flume::{Receiver, Sender};
 use human_repr::HumanCount;
 use iggy::utils::duration::IggyDuration;
-use tokio::time::{self};
 use tracing::{error, info, warn};
 
 #[derive(Debug, Default, Clone)]
@@ -31,19 +31,29 @@ pub struct SysInfoPrintCommand;
 
 pub struct SysInfoPrinter {
     interval: IggyDuration,
+    config_watcher: ConfigWatcher,
     sender: Sender<SysInfoPrintCommand>,
 }
 
 pub struct SysInfoPrintExecutor;
 
 impl SysInfoPrinter {
-    pub fn new(interval: IggyDuration, sender: Sender<SysInfoPrintCommand>) -> Self {
-        Self { interval, sender }
+    pub fn new(
+        interval: IggyDuration,
+        config_watcher: ConfigWatcher,
+        sender: Sender<SysInfoPrintCommand>,
+    ) -> Self {
+        Self {
+            interval,
+            config_watcher,
+            sender,
+        }
     }
 
     pub fn start(&self) {
         let interval = self.interval;
         let sender = self.sender.clone();
+        let config_watcher = self.config_watcher.clone();
         if interval.is_zero() {
             info!("SysInfoPrinter is disabled.");
             return;
@@ -51,7 +61,10 @@ impl SysInfoPrinter {
 
         info!("SysInfoPrinter is enabled, system information will be printed every {interval}.");
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer =
+                ReloadableInterval::new("SysInfoPrinter", interval, config_watcher, |config| {
+                    config.system.logging.sysinfo_print_interval
+                });
             loop {
                 interval_timer.tick().await;
                 let command = SysInfoPrintCommand {};
@@ -108,9 +121,14 @@ impl ServerCommand<SysInfoPrintCommand> for SysInfoPrintExecutor {
         &mut self,
         _system: SharedSystem,
         config: &ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<SysInfoPrintCommand>,
     ) {
-        let printer = SysInfoPrinter::new(config.system.logging.sysinfo_print_interval, sender);
+        let printer = SysInfoPrinter::new(
+            config.system.logging.sysinfo_print_interval,
+            config_watcher,
+            sender,
+        );
         printer.start();
     }
 
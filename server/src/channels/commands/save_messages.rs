@@ -16,19 +16,21 @@
  * under the License.
  */
 
+use crate::channels::interval::ReloadableInterval;
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::MessageSaverConfig;
 use crate::configs::server::ServerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
-use tokio::time;
 use tracing::{error, info, instrument, warn};
 
 pub struct MessagesSaver {
     enabled: bool,
     enforce_fsync: bool,
     interval: IggyDuration,
+    config_watcher: ConfigWatcher,
     sender: Sender<SaveMessagesCommand>,
 }
 
@@ -41,11 +43,16 @@ pub struct SaveMessagesCommand {
 pub struct SaveMessagesExecutor;
 
 impl MessagesSaver {
-    pub fn new(config: &MessageSaverConfig, sender: Sender<SaveMessagesCommand>) -> Self {
+    pub fn new(
+        config: &MessageSaverConfig,
+        config_watcher: ConfigWatcher,
+        sender: Sender<SaveMessagesCommand>,
+    ) -> Self {
         Self {
             enabled: config.enabled,
             enforce_fsync: config.enforce_fsync,
             interval: config.interval,
+            config_watcher,
             sender,
         }
     }
@@ -59,11 +66,18 @@ impl MessagesSaver {
         let enforce_fsync = self.enforce_fsync;
         let interval = self.interval;
         let sender = self.sender.clone();
+        let config_watcher = self.config_watcher.clone();
         info!("Message saver is enabled, buffered messages will be automatically saved every: {interval}, enforce fsync: {enforce_fsync}.");
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = ReloadableInterval::new(
+                "Message saver",
+                interval,
+                config_watcher.clone(),
+                |config| config.message_saver.interval,
+            );
             loop {
                 interval_timer.tick().await;
+                let enforce_fsync = config_watcher.borrow().message_saver.enforce_fsync;
                 let command = SaveMessagesCommand { enforce_fsync };
                 sender.send(command).unwrap_or_else(|e| {
                     error!("Failed to send SaveMessagesCommand. Error: {e}",);
@@ -93,9 +107,10 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
         &mut self,
         _system: SharedSystem,
         config: &ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<SaveMessagesCommand>,
     ) {
-        let messages_saver = MessagesSaver::new(&config.message_saver, sender);
+        let messages_saver = MessagesSaver::new(&config.message_saver, config_watcher, sender);
         messages_saver.start();
     }
 
@@ -16,14 +16,15 @@
  * under the License.
  */
 
+use crate::channels::interval::ReloadableInterval;
 use crate::channels::server_command::ServerCommand;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::HeartbeatConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy::locking::IggySharedMutFn;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
-use tokio::time;
 use tracing::{debug, error, info, instrument, warn};
 
 const MAX_THRESHOLD: f64 = 1.2;
@@ -31,6 +32,7 @@ const MAX_THRESHOLD: f64 = 1.2;
 pub struct VerifyHeartbeats {
     enabled: bool,
     interval: IggyDuration,
+    config_watcher: ConfigWatcher,
     sender: Sender<VerifyHeartbeatsCommand>,
 }
 
@@ -43,10 +45,15 @@ pub struct VerifyHeartbeatsCommand {
 pub struct VerifyHeartbeatsExecutor;
 
 impl VerifyHeartbeats {
-    pub fn new(config: &HeartbeatConfig, sender: Sender<VerifyHeartbeatsCommand>) -> Self {
+    pub fn new(
+        config: &HeartbeatConfig,
+        config_watcher: ConfigWatcher,
+        sender: Sender<VerifyHeartbeatsCommand>,
+    ) -> Self {
         Self {
             enabled: config.enabled,
             interval: config.interval,
+            config_watcher,
             sender,
         }
     }
@@ -58,19 +65,25 @@ impl VerifyHeartbeats {
         }
 
         let interval = self.interval;
-        let max_interval = IggyDuration::from((MAX_THRESHOLD * interval.as_micros() as f64) as u64);
         let sender = self.sender.clone();
+        let config_watcher = self.config_watcher.clone();
         info!(
-            "Heartbeats will be verified every: {interval}. Max allowed interval: {max_interval}."
+            "Heartbeats will be verified every: {interval}. Max allowed interval: {}.",
+            max_interval(interval)
         );
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = ReloadableInterval::new(
+                "Heartbeats verifier",
+                interval,
+                config_watcher,
+                |config| config.heartbeat.interval,
+            );
             loop {
                 interval_timer.tick().await;
                 debug!("Verifying heartbeats...");
                 sender
                     .send(VerifyHeartbeatsCommand {
-                        interval: max_interval,
+                        interval: max_interval(interval_timer.period()),
                     })
                     .unwrap_or_else(|error| {
                         error!("Failed to send VerifyHeartbeats. Error: {}", error);
@@ -80,6 +93,10 @@ impl VerifyHeartbeats {
     }
 }
 
+fn max_interval(interval: IggyDuration) -> IggyDuration {
+    IggyDuration::from((MAX_THRESHOLD * interval.as_micros() as f64) as u64)
+}
+
 impl ServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
     #[instrument(skip_all, name = "trace_verify_heartbeats")]
     async fn execute(&mut self, system: &SharedSystem, command: VerifyHeartbeatsCommand) {
@@ -129,9 +146,10 @@ impl ServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
         &mut self,
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<VerifyHeartbeatsCommand>,
     ) {
-        let verify_heartbeats = VerifyHeartbeats::new(&config.heartbeat, sender);
+        let verify_heartbeats = VerifyHeartbeats::new(&config.heartbeat, config_watcher, sender);
         verify_heartbeats.start();
     }
 
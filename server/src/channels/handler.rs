@@ -17,17 +17,27 @@
  */
 
 use super::server_command::ServerCommand;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::ServerConfig;
 use crate::streaming::systems::system::SharedSystem;
 
 pub struct ServerCommandHandler<'a> {
     system: SharedSystem,
     config: &'a ServerConfig,
+    config_watcher: ConfigWatcher,
 }
 
 impl<'a> ServerCommandHandler<'a> {
-    pub fn new(system: SharedSystem, config: &'a ServerConfig) -> Self {
-        Self { system, config }
+    pub fn new(
+        system: SharedSystem,
+        config: &'a ServerConfig,
+        config_watcher: ConfigWatcher,
+    ) -> Self {
+        Self {
+            system,
+            config,
+            config_watcher,
+        }
     }
 
     pub fn install_handler<C, E>(&mut self, mut executor: E) -> Self
@@ -36,11 +46,17 @@ impl<'a> ServerCommandHandler<'a> {
     {
         let (sender, receiver) = flume::unbounded();
         let system = self.system.clone();
-        executor.start_command_sender(system.clone(), self.config, sender);
+        executor.start_command_sender(
+            system.clone(),
+            self.config,
+            self.config_watcher.clone(),
+            sender,
+        );
         executor.start_command_consumer(system.clone(), self.config, receiver);
         Self {
             system,
             config: self.config,
+            config_watcher: self.config_watcher.clone(),
         }
     }
 }
@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::reload::ConfigWatcher;
+use crate::configs::server::ServerConfig;
+use iggy::utils::duration::IggyDuration;
+use tokio::time::{self, Instant, Interval};
+use tracing::{info, warn};
+
+/// Interval timer used by the command senders, restarted whenever the reloaded configuration
+/// changes its period, so that the background tasks pick up the new interval without a restart.
+pub struct ReloadableInterval {
+    name: &'static str,
+    period: IggyDuration,
+    timer: Interval,
+    config_watcher: ConfigWatcher,
+    get_period: fn(&ServerConfig) -> IggyDuration,
+}
+
+impl ReloadableInterval {
+    pub fn new(
+        name: &'static str,
+        period: IggyDuration,
+        config_watcher: ConfigWatcher,
+        get_period: fn(&ServerConfig) -> IggyDuration,
+    ) -> Self {
+        Self {
+            name,
+            period,
+            timer: time::interval(period.get_duration()),
+            config_watcher,
+            get_period,
+        }
+    }
+
+    pub fn period(&self) -> IggyDuration {
+        self.period
+    }
+
+    pub async fn tick(&mut self) {
+        loop {
+            tokio::select! {
+                _ = self.timer.tick() => return,
+                Ok(()) = self.config_watcher.changed() => {
+                    let period = (self.get_period)(&self.config_watcher.borrow_and_update());
+                    if period == self.period {
+                        continue;
+                    }
+
+                    if period.is_zero() {
+                        warn!("{} interval cannot be changed to zero, keeping: {}.", self.name, self.period);
+                        continue;
+                    }
+
+                    self.period = period;
+                    self.timer = time::interval_at(Instant::now() + period.get_duration(), period.get_duration());
+                    info!("{} interval has been changed to: {period}.", self.name);
+                }
+            }
+        }
+    }
+}
@@ -18,4 +18,5 @@
 
 pub mod commands;
 pub mod handler;
+pub mod interval;
 pub mod server_command;
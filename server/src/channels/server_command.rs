@@ -18,6 +18,7 @@
 
 use std::future::Future;
 
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::ServerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::{Receiver, Sender};
@@ -29,6 +30,7 @@ pub trait ServerCommand<C> {
         &mut self,
         system: SharedSystem,
         config: &ServerConfig,
+        config_watcher: ConfigWatcher,
         sender: Sender<C>,
     );
 
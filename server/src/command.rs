@@ -29,26 +29,36 @@ use iggy::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::IggyError;
+use iggy::messages::grant_message_credits::GrantMessageCredits;
 use iggy::messages::poll_messages::PollMessages;
 use iggy::messages::send_messages::SendMessages;
+use iggy::messages::subscribe_messages::SubscribeMessages;
+use iggy::messages::unsubscribe_messages::UnsubscribeMessages;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::schemas::update_schema_validation::UpdateSchemaValidation;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::disconnect_client::DisconnectClient;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_snapshot::GetSnapshot;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::system::reload_config::ReloadConfig;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
@@ -79,6 +89,7 @@ pub enum ServerCommand {
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
+    DisconnectClient(DisconnectClient),
     GetUser(GetUser),
     GetUsers(GetUsers),
     CreateUser(CreateUser),
@@ -94,6 +105,9 @@ pub enum ServerCommand {
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
+    SubscribeMessages(SubscribeMessages),
+    UnsubscribeMessages(UnsubscribeMessages),
+    GrantMessageCredits(GrantMessageCredits),
     FlushUnsavedBuffer(FlushUnsavedBuffer),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
@@ -118,7 +132,13 @@ pub enum ServerCommand {
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    GetSchema(GetSchema),
+    GetSchemas(GetSchemas),
+    RegisterSchema(RegisterSchema),
+    UpdateSchemaCompatibility(UpdateSchemaCompatibility),
+    UpdateSchemaValidation(UpdateSchemaValidation),
     GetSnapshotFile(GetSnapshot),
+    ReloadConfig(ReloadConfig),
 }
 
 impl BytesSerializable for ServerCommand {
@@ -129,6 +149,7 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::GetMe(payload) => as_bytes(payload),
             ServerCommand::GetClient(payload) => as_bytes(payload),
             ServerCommand::GetClients(payload) => as_bytes(payload),
+            ServerCommand::DisconnectClient(payload) => as_bytes(payload),
             ServerCommand::GetUser(payload) => as_bytes(payload),
             ServerCommand::GetUsers(payload) => as_bytes(payload),
             ServerCommand::CreateUser(payload) => as_bytes(payload),
@@ -144,6 +165,9 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::LoginWithPersonalAccessToken(payload) => as_bytes(payload),
             ServerCommand::SendMessages(payload) => as_bytes(payload),
             ServerCommand::PollMessages(payload) => as_bytes(payload),
+            ServerCommand::SubscribeMessages(payload) => as_bytes(payload),
+            ServerCommand::UnsubscribeMessages(payload) => as_bytes(payload),
+            ServerCommand::GrantMessageCredits(payload) => as_bytes(payload),
             ServerCommand::StoreConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::DeleteConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::GetConsumerOffset(payload) => as_bytes(payload),
@@ -167,8 +191,14 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::DeleteConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::JoinConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::LeaveConsumerGroup(payload) => as_bytes(payload),
+            ServerCommand::GetSchema(payload) => as_bytes(payload),
+            ServerCommand::GetSchemas(payload) => as_bytes(payload),
+            ServerCommand::RegisterSchema(payload) => as_bytes(payload),
+            ServerCommand::UpdateSchemaCompatibility(payload) => as_bytes(payload),
+            ServerCommand::UpdateSchemaValidation(payload) => as_bytes(payload),
             ServerCommand::FlushUnsavedBuffer(payload) => as_bytes(payload),
             ServerCommand::GetSnapshotFile(payload) => as_bytes(payload),
+            ServerCommand::ReloadConfig(payload) => as_bytes(payload),
         }
     }
 
@@ -186,6 +216,9 @@ impl BytesSerializable for ServerCommand {
             GET_ME_CODE => Ok(ServerCommand::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(ServerCommand::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(ServerCommand::GetClients(GetClients::from_bytes(payload)?)),
+            DISCONNECT_CLIENT_CODE => Ok(ServerCommand::DisconnectClient(
+                DisconnectClient::from_bytes(payload)?,
+            )),
             GET_USER_CODE => Ok(ServerCommand::GetUser(GetUser::from_bytes(payload)?)),
             GET_USERS_CODE => Ok(ServerCommand::GetUsers(GetUsers::from_bytes(payload)?)),
             CREATE_USER_CODE => Ok(ServerCommand::CreateUser(CreateUser::from_bytes(payload)?)),
@@ -216,9 +249,23 @@ impl BytesSerializable for ServerCommand {
             SEND_MESSAGES_CODE => Ok(ServerCommand::SendMessages(SendMessages::from_bytes(
                 payload,
             )?)),
+            SEND_MESSAGES_WITH_OFFSETS_CODE => {
+                let mut command = SendMessages::from_bytes(payload)?;
+                command.report_offsets = true;
+                Ok(ServerCommand::SendMessages(command))
+            }
             POLL_MESSAGES_CODE => Ok(ServerCommand::PollMessages(PollMessages::from_bytes(
                 payload,
             )?)),
+            SUBSCRIBE_MESSAGES_CODE => Ok(ServerCommand::SubscribeMessages(
+                SubscribeMessages::from_bytes(payload)?,
+            )),
+            UNSUBSCRIBE_MESSAGES_CODE => Ok(ServerCommand::UnsubscribeMessages(
+                UnsubscribeMessages::from_bytes(payload)?,
+            )),
+            GRANT_MESSAGE_CREDITS_CODE => Ok(ServerCommand::GrantMessageCredits(
+                GrantMessageCredits::from_bytes(payload)?,
+            )),
             FLUSH_UNSAVED_BUFFER_CODE => Ok(ServerCommand::FlushUnsavedBuffer(
                 FlushUnsavedBuffer::from_bytes(payload)?,
             )),
@@ -281,9 +328,23 @@ impl BytesSerializable for ServerCommand {
             LEAVE_CONSUMER_GROUP_CODE => Ok(ServerCommand::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            GET_SCHEMA_CODE => Ok(ServerCommand::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_SCHEMAS_CODE => Ok(ServerCommand::GetSchemas(GetSchemas::from_bytes(payload)?)),
+            REGISTER_SCHEMA_CODE => Ok(ServerCommand::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(ServerCommand::UpdateSchemaCompatibility(
+                UpdateSchemaCompatibility::from_bytes(payload)?,
+            )),
+            UPDATE_SCHEMA_VALIDATION_CODE => Ok(ServerCommand::UpdateSchemaValidation(
+                UpdateSchemaValidation::from_bytes(payload)?,
+            )),
             GET_SNAPSHOT_FILE_CODE => Ok(ServerCommand::GetSnapshotFile(GetSnapshot::from_bytes(
                 payload,
             )?)),
+            RELOAD_CONFIG_CODE => Ok(ServerCommand::ReloadConfig(ReloadConfig::from_bytes(
+                payload,
+            )?)),
             _ => {
                 error!("Invalid server command: {code}");
                 Err(IggyError::InvalidCommand)
@@ -308,6 +369,7 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::GetMe(command) => command.validate(),
             ServerCommand::GetClient(command) => command.validate(),
             ServerCommand::GetClients(command) => command.validate(),
+            ServerCommand::DisconnectClient(command) => command.validate(),
             ServerCommand::GetUser(command) => command.validate(),
             ServerCommand::GetUsers(command) => command.validate(),
             ServerCommand::CreateUser(command) => command.validate(),
@@ -323,6 +385,9 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::LoginWithPersonalAccessToken(command) => command.validate(),
             ServerCommand::SendMessages(command) => command.validate(),
             ServerCommand::PollMessages(command) => command.validate(),
+            ServerCommand::SubscribeMessages(command) => command.validate(),
+            ServerCommand::UnsubscribeMessages(command) => command.validate(),
+            ServerCommand::GrantMessageCredits(command) => command.validate(),
             ServerCommand::StoreConsumerOffset(command) => command.validate(),
             ServerCommand::DeleteConsumerOffset(command) => command.validate(),
             ServerCommand::GetConsumerOffset(command) => command.validate(),
@@ -346,8 +411,14 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::DeleteConsumerGroup(command) => command.validate(),
             ServerCommand::JoinConsumerGroup(command) => command.validate(),
             ServerCommand::LeaveConsumerGroup(command) => command.validate(),
+            ServerCommand::GetSchema(command) => command.validate(),
+            ServerCommand::GetSchemas(command) => command.validate(),
+            ServerCommand::RegisterSchema(command) => command.validate(),
+            ServerCommand::UpdateSchemaCompatibility(command) => command.validate(),
+            ServerCommand::UpdateSchemaValidation(command) => command.validate(),
             ServerCommand::FlushUnsavedBuffer(command) => command.validate(),
             ServerCommand::GetSnapshotFile(command) => command.validate(),
+            ServerCommand::ReloadConfig(command) => command.validate(),
         }
     }
 }
@@ -360,6 +431,9 @@ impl Display for ServerCommand {
             ServerCommand::GetMe(_) => write!(formatter, "{GET_ME}"),
             ServerCommand::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             ServerCommand::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
+            ServerCommand::DisconnectClient(payload) => {
+                write!(formatter, "{DISCONNECT_CLIENT}|{payload}")
+            }
             ServerCommand::GetUser(payload) => write!(formatter, "{GET_USER}|{payload}"),
             ServerCommand::GetUsers(_) => write!(formatter, "{GET_USERS}"),
             ServerCommand::CreateUser(payload) => write!(formatter, "{CREATE_USER}|{payload}"),
@@ -404,6 +478,15 @@ impl Display for ServerCommand {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
             ServerCommand::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
+            ServerCommand::SubscribeMessages(payload) => {
+                write!(formatter, "{SUBSCRIBE_MESSAGES}|{payload}")
+            }
+            ServerCommand::UnsubscribeMessages(payload) => {
+                write!(formatter, "{UNSUBSCRIBE_MESSAGES}|{payload}")
+            }
+            ServerCommand::GrantMessageCredits(payload) => {
+                write!(formatter, "{GRANT_MESSAGE_CREDITS}|{payload}")
+            }
             ServerCommand::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
             ServerCommand::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
@@ -432,12 +515,24 @@ impl Display for ServerCommand {
             ServerCommand::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            ServerCommand::GetSchema(payload) => write!(formatter, "{GET_SCHEMA}|{payload}"),
+            ServerCommand::GetSchemas(payload) => write!(formatter, "{GET_SCHEMAS}|{payload}"),
+            ServerCommand::RegisterSchema(payload) => {
+                write!(formatter, "{REGISTER_SCHEMA}|{payload}")
+            }
+            ServerCommand::UpdateSchemaCompatibility(payload) => {
+                write!(formatter, "{UPDATE_SCHEMA_COMPATIBILITY}|{payload}")
+            }
+            ServerCommand::UpdateSchemaValidation(payload) => {
+                write!(formatter, "{UPDATE_SCHEMA_VALIDATION}|{payload}")
+            }
             ServerCommand::FlushUnsavedBuffer(payload) => {
                 write!(formatter, "{FLUSH_UNSAVED_BUFFER}|{payload}")
             }
             ServerCommand::GetSnapshotFile(payload) => {
                 write!(formatter, "{GET_SNAPSHOT_FILE}|{payload}")
             }
+            ServerCommand::ReloadConfig(_) => write!(formatter, "{RELOAD_CONFIG}"),
         }
     }
 }
@@ -473,6 +568,16 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DisconnectClient(DisconnectClient::default()),
+            DISCONNECT_CLIENT_CODE,
+            &DisconnectClient::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ReloadConfig(ReloadConfig::default()),
+            RELOAD_CONFIG_CODE,
+            &ReloadConfig::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -543,11 +648,38 @@ mod tests {
             SEND_MESSAGES_CODE,
             &SendMessages::default(),
         );
+        let send_messages_with_offsets = SendMessages {
+            report_offsets: true,
+            ..Default::default()
+        };
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SendMessages(SendMessages {
+                report_offsets: true,
+                ..Default::default()
+            }),
+            SEND_MESSAGES_WITH_OFFSETS_CODE,
+            &send_messages_with_offsets,
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::PollMessages(PollMessages::default()),
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SubscribeMessages(SubscribeMessages::default()),
+            SUBSCRIBE_MESSAGES_CODE,
+            &SubscribeMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UnsubscribeMessages(UnsubscribeMessages::default()),
+            UNSUBSCRIBE_MESSAGES_CODE,
+            &UnsubscribeMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GrantMessageCredits(GrantMessageCredits::default()),
+            GRANT_MESSAGE_CREDITS_CODE,
+            &GrantMessageCredits::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -658,6 +790,31 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateSchemaCompatibility(UpdateSchemaCompatibility::default()),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE,
+            &UpdateSchemaCompatibility::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateSchemaValidation(UpdateSchemaValidation::default()),
+            UPDATE_SCHEMA_VALIDATION_CODE,
+            &UpdateSchemaValidation::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::FlushUnsavedBuffer(FlushUnsavedBuffer::default()),
             FLUSH_UNSAVED_BUFFER_CODE,
@@ -39,6 +39,7 @@ const SECRET_KEYS: [&str; 6] = [
     "IGGY_SYSTEM_ENCRYPTION_KEY",
 ];
 
+#[derive(Debug)]
 pub enum ConfigProviderKind {
     File(FileConfigProvider),
 }
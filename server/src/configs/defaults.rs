@@ -19,22 +19,27 @@
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 
+use crate::configs::grpc::GrpcConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
+use crate::configs::kafka::{KafkaConfig, KafkaNamingConfig};
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
     ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
     MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
-    ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
+    ServerConfig, ShutdownConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
     TelemetryTracesConfig,
 };
 use crate::configs::system::{
     BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
-    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig,
-    SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, PollingConfig, RecoveryConfig,
+    RuntimeConfig, SegmentConfig, StateConfig, StorageConfig, StreamConfig, SystemConfig,
+    TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::websocket::WebSocketConfig;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -50,12 +55,17 @@ impl Default for ServerConfig {
         ServerConfig {
             data_maintenance: DataMaintenanceConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            shutdown: ShutdownConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
+            websocket: WebSocketConfig::default(),
+            kafka: KafkaConfig::default(),
+            mqtt: MqttConfig::default(),
+            grpc: GrpcConfig::default(),
             telemetry: TelemetryConfig::default(),
         }
     }
@@ -128,6 +138,65 @@ impl Default for QuicConfig {
     }
 }
 
+impl Default for WebSocketConfig {
+    fn default() -> WebSocketConfig {
+        WebSocketConfig {
+            enabled: SERVER_CONFIG.websocket.enabled,
+            address: SERVER_CONFIG.websocket.address.parse().unwrap(),
+            max_message_size: SERVER_CONFIG.websocket.max_message_size.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for KafkaConfig {
+    fn default() -> KafkaConfig {
+        KafkaConfig {
+            enabled: SERVER_CONFIG.kafka.enabled,
+            address: SERVER_CONFIG.kafka.address.parse().unwrap(),
+            advertised_address: SERVER_CONFIG.kafka.advertised_address.parse().unwrap(),
+            max_request_size: SERVER_CONFIG.kafka.max_request_size.parse().unwrap(),
+            naming: KafkaNamingConfig::default(),
+        }
+    }
+}
+
+impl Default for KafkaNamingConfig {
+    fn default() -> KafkaNamingConfig {
+        KafkaNamingConfig {
+            separator: SERVER_CONFIG.kafka.naming.separator.parse().unwrap(),
+            default_stream: SERVER_CONFIG.kafka.naming.default_stream.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for MqttConfig {
+    fn default() -> MqttConfig {
+        MqttConfig {
+            enabled: SERVER_CONFIG.mqtt.enabled,
+            address: SERVER_CONFIG.mqtt.address.parse().unwrap(),
+            max_packet_size: SERVER_CONFIG.mqtt.max_packet_size.parse().unwrap(),
+            connect_timeout: SERVER_CONFIG.mqtt.connect_timeout.parse().unwrap(),
+            max_inflight_messages: SERVER_CONFIG.mqtt.max_inflight_messages as u16,
+            topic_mappings: SERVER_CONFIG
+                .mqtt
+                .topic_mappings
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+        }
+    }
+}
+
+impl Default for GrpcConfig {
+    fn default() -> GrpcConfig {
+        GrpcConfig {
+            enabled: SERVER_CONFIG.grpc.enabled,
+            address: SERVER_CONFIG.grpc.address.parse().unwrap(),
+            max_message_size: SERVER_CONFIG.grpc.max_message_size.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for QuicCertificateConfig {
     fn default() -> QuicCertificateConfig {
         QuicCertificateConfig {
@@ -312,6 +381,7 @@ impl Default for SystemConfig {
         SystemConfig {
             path: SERVER_CONFIG.system.path.parse().unwrap(),
             backup: BackupConfig::default(),
+            storage: StorageConfig::default(),
             runtime: RuntimeConfig::default(),
             logging: LoggingConfig::default(),
             cache: CacheConfig::default(),
@@ -323,6 +393,7 @@ impl Default for SystemConfig {
             state: StateConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
+            polling: PollingConfig::default(),
             recovery: RecoveryConfig::default(),
         }
     }
@@ -360,6 +431,14 @@ impl Default for HeartbeatConfig {
     }
 }
 
+impl Default for ShutdownConfig {
+    fn default() -> ShutdownConfig {
+        ShutdownConfig {
+            timeout: SERVER_CONFIG.shutdown.timeout.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for RuntimeConfig {
     fn default() -> RuntimeConfig {
         RuntimeConfig {
@@ -460,6 +539,7 @@ impl Default for SegmentConfig {
                 .server_confirmation
                 .parse()
                 .unwrap(),
+            durability: SERVER_CONFIG.system.segment.durability.parse().unwrap(),
         }
     }
 }
@@ -475,6 +555,23 @@ impl Default for StateConfig {
     }
 }
 
+impl Default for StorageConfig {
+    fn default() -> StorageConfig {
+        StorageConfig {
+            backend: SERVER_CONFIG.system.storage.backend.parse().unwrap(),
+            queue_depth: SERVER_CONFIG.system.storage.queue_depth as u32,
+            registered_buffers: SERVER_CONFIG.system.storage.registered_buffers as u32,
+            registered_buffer_size: SERVER_CONFIG
+                .system
+                .storage
+                .registered_buffer_size
+                .parse()
+                .unwrap(),
+            direct_io: SERVER_CONFIG.system.storage.direct_io,
+        }
+    }
+}
+
 impl Default for MessageDeduplicationConfig {
     fn default() -> MessageDeduplicationConfig {
         MessageDeduplicationConfig {
@@ -490,6 +587,14 @@ impl Default for MessageDeduplicationConfig {
     }
 }
 
+impl Default for PollingConfig {
+    fn default() -> PollingConfig {
+        PollingConfig {
+            max_wait: SERVER_CONFIG.system.polling.max_wait.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for RecoveryConfig {
     fn default() -> RecoveryConfig {
         RecoveryConfig {
@@ -19,19 +19,24 @@
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
     ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
-    MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
-    TelemetryLogsConfig, TelemetryTracesConfig,
+    MessagesMaintenanceConfig, S3ArchiverConfig, ShutdownConfig, StateMaintenanceConfig,
+    TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::MessageDeduplicationConfig;
+use crate::configs::system::PollingConfig;
 use crate::configs::{
+    grpc::GrpcConfig,
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    kafka::{KafkaConfig, KafkaNamingConfig},
+    mqtt::MqttConfig,
     resource_quota::MemoryResourceQuota,
     server::{MessageSaverConfig, ServerConfig},
     system::{
         CacheConfig, CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig,
-        SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+        SegmentConfig, StateConfig, StorageConfig, StreamConfig, SystemConfig, TopicConfig,
     },
     tcp::{TcpConfig, TcpSocketConfig, TcpTlsConfig},
+    websocket::WebSocketConfig,
 };
 use std::fmt::{Display, Formatter};
 
@@ -104,6 +109,56 @@ impl Display for QuicConfig {
     }
 }
 
+impl Display for WebSocketConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, max_message_size: {} }}",
+            self.enabled, self.address, self.max_message_size
+        )
+    }
+}
+
+impl Display for KafkaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, advertised_address: {}, max_request_size: {}, naming: {} }}",
+            self.enabled, self.address, self.advertised_address, self.max_request_size, self.naming
+        )
+    }
+}
+
+impl Display for KafkaNamingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ separator: {}, default_stream: {} }}",
+            self.separator, self.default_stream
+        )
+    }
+}
+
+impl Display for MqttConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, max_packet_size: {}, connect_timeout: {}, max_inflight_messages: {}, topic_mappings: {:?} }}",
+            self.enabled, self.address, self.max_packet_size, self.connect_timeout, self.max_inflight_messages, self.topic_mappings
+        )
+    }
+}
+
+impl Display for GrpcConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, max_message_size: {} }}",
+            self.enabled, self.address, self.max_message_size
+        )
+    }
+}
+
 impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -204,8 +259,8 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
-            self.data_maintenance, self.message_saver, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry
+            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, shutdown: {}, system: {}, quic: {}, tcp: {}, http: {}, websocket: {}, kafka: {}, mqtt: {}, grpc: {}, telemetry: {} }}",
+            self.data_maintenance, self.message_saver, self.heartbeat, self.shutdown, self.system, self.quic, self.tcp, self.http, self.websocket, self.kafka, self.mqtt, self.grpc, self.telemetry
         )
     }
 }
@@ -236,6 +291,12 @@ impl Display for HeartbeatConfig {
     }
 }
 
+impl Display for ShutdownConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ timeout: {} }}", self.timeout)
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {} }}", self.enabled)
@@ -281,12 +342,18 @@ impl Display for MessageDeduplicationConfig {
     }
 }
 
+impl Display for PollingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ max_wait: {} }}", self.max_wait)
+    }
+}
+
 impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {} }}",
-            self.size, self.cache_indexes, self.message_expiry, self.archive_expired, self.server_confirmation,
+            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {}, durability: {} }}",
+            self.size, self.cache_indexes, self.message_expiry, self.archive_expired, self.server_confirmation, self.durability,
         )
     }
 }
@@ -364,6 +431,20 @@ impl Display for StateConfig {
     }
 }
 
+impl Display for StorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ backend: {}, queue_depth: {}, registered_buffers: {}, registered_buffer_size: {}, direct_io: {} }}",
+            self.backend,
+            self.queue_depth,
+            self.registered_buffers,
+            self.registered_buffer_size,
+            self.direct_io,
+        )
+    }
+}
+
 impl Display for TelemetryTracesConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -378,7 +459,7 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {}, storage: {}, polling: {} }}",
           self.path,
           self.logging,
           self.cache,
@@ -388,6 +469,8 @@ impl Display for SystemConfig {
           self.segment,
           self.encryption,
           self.state,
+          self.storage,
+          self.polling,
       )
     }
 }
@@ -0,0 +1,47 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct KafkaConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub advertised_address: String,
+    pub max_request_size: IggyByteSize,
+    pub naming: KafkaNamingConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct KafkaNamingConfig {
+    pub separator: String,
+    pub default_stream: String,
+}
+
+impl KafkaConfig {
+    /// Returns the host and the port of the advertised address, if it's configured and valid.
+    pub fn advertised_host_and_port(&self) -> Option<(&str, u16)> {
+        let (host, port) = self.advertised_address.rsplit_once(':')?;
+        if host.is_empty() {
+            return None;
+        }
+        let port = port.parse().ok()?;
+        Some((host, port))
+    }
+}
@@ -0,0 +1,357 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::config_provider::ConfigProviderKind;
+use crate::configs::server::ServerConfig;
+use crate::configs::COMPONENT;
+use crate::server_error::ConfigError;
+use crate::tcp::tcp_tls_listener;
+use axum_server::tls_rustls::RustlsConfig;
+use error_set::ErrContext;
+use iggy::models::config_reload_info::ConfigReloadInfo;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::{watch, Mutex};
+use toml::Value as TomlValue;
+use tracing::{error, info, warn};
+
+/// The settings which can be changed while the server is running.
+/// Every other change is reported as requiring a restart.
+const RELOADABLE_KEYS: [&str; 16] = [
+    "system.logging.level",
+    "system.logging.sysinfo_print_interval",
+    "system.cache.size",
+    "system.topic.max_size",
+    "message_saver.enforce_fsync",
+    "message_saver.interval",
+    "heartbeat.interval",
+    "shutdown.timeout",
+    "personal_access_token.cleaner.interval",
+    "data_maintenance.messages.interval",
+    "data_maintenance.state.interval",
+    "tcp.tls.certificate",
+    "tcp.tls.password",
+    "http.tls.cert_file",
+    "http.tls.key_file",
+    "http.cors.allowed_origins",
+];
+
+/// Receives the configuration published after each successful reload.
+pub type ConfigWatcher = watch::Receiver<Arc<ServerConfig>>;
+
+/// Re-reads the configuration from its provider, validates it and publishes the runtime-reloadable
+/// subset to the subscribed components (logging, listeners and background tasks).
+#[derive(Debug)]
+pub struct ConfigReloader {
+    provider: ConfigProviderKind,
+    current: Mutex<LoadedConfig>,
+    sender: watch::Sender<Arc<ServerConfig>>,
+}
+
+#[derive(Debug)]
+struct LoadedConfig {
+    config: ServerConfig,
+    certificates: CertificateFiles,
+}
+
+/// The contents of the TLS certificate files, compared on reload to pick up the certificates renewed in place.
+#[derive(Debug, Default, PartialEq)]
+struct CertificateFiles {
+    tcp_certificate: Option<Vec<u8>>,
+    http_cert_file: Option<Vec<u8>>,
+    http_key_file: Option<Vec<u8>>,
+}
+
+impl ConfigReloader {
+    pub fn new(provider: ConfigProviderKind, config: ServerConfig) -> Self {
+        let (sender, _) = watch::channel(Arc::new(config.clone()));
+        let certificates = CertificateFiles::read(&config);
+        Self {
+            provider,
+            current: Mutex::new(LoadedConfig {
+                config,
+                certificates,
+            }),
+            sender,
+        }
+    }
+
+    pub fn watch(&self) -> ConfigWatcher {
+        self.sender.subscribe()
+    }
+
+    pub async fn reload(&self) -> Result<ConfigReloadInfo, ConfigError> {
+        let mut current = self.current.lock().await;
+        let loaded = ServerConfig::load(&self.provider)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load config for reload")
+            })?;
+
+        // The certificate files are read on every reload, so the ones renewed under the same path are applied too.
+        let reloaded = apply_reloadable(&current.config, &loaded);
+        let certificates = CertificateFiles::read(&reloaded);
+        let mut keys = changed_keys(&current.config, &loaded);
+        keys.extend(current.certificates.changed_keys(&certificates));
+        keys.sort();
+        keys.dedup();
+
+        let (applied, requires_restart): (Vec<_>, Vec<_>) = keys
+            .into_iter()
+            .partition(|key| RELOADABLE_KEYS.contains(&key.as_str()));
+        if !requires_restart.is_empty() {
+            warn!(
+                "Server configuration changes require a restart: {}",
+                requires_restart.join(", ")
+            );
+        }
+
+        if applied.is_empty() {
+            info!("Server configuration reloaded, no changes to apply.");
+            return Ok(ConfigReloadInfo {
+                applied,
+                requires_restart,
+            });
+        }
+
+        validate_certificates(&reloaded).await?;
+        self.sender.send_replace(Arc::new(reloaded.clone()));
+        *current = LoadedConfig {
+            config: reloaded,
+            certificates,
+        };
+        info!(
+            "Server configuration reloaded, applied changes: {}",
+            applied.join(", ")
+        );
+        Ok(ConfigReloadInfo {
+            applied,
+            requires_restart,
+        })
+    }
+}
+
+/// Returns a copy of the `current` config with the reloadable settings taken from `loaded`.
+fn apply_reloadable(current: &ServerConfig, loaded: &ServerConfig) -> ServerConfig {
+    let mut config = current.clone();
+    let mut system = (*current.system).clone();
+    system.logging.level = loaded.system.logging.level.clone();
+    system.logging.sysinfo_print_interval = loaded.system.logging.sysinfo_print_interval;
+    system.cache.size = loaded.system.cache.size.clone();
+    system.topic.max_size = loaded.system.topic.max_size;
+    config.system = Arc::new(system);
+    config.message_saver.enforce_fsync = loaded.message_saver.enforce_fsync;
+    config.message_saver.interval = loaded.message_saver.interval;
+    config.heartbeat.interval = loaded.heartbeat.interval;
+    config.shutdown.timeout = loaded.shutdown.timeout;
+    config.personal_access_token.cleaner.interval = loaded.personal_access_token.cleaner.interval;
+    config.data_maintenance.messages.interval = loaded.data_maintenance.messages.interval;
+    config.data_maintenance.state.interval = loaded.data_maintenance.state.interval;
+    config.tcp.tls.certificate = loaded.tcp.tls.certificate.clone();
+    config.tcp.tls.password = loaded.tcp.tls.password.clone();
+    config.http.tls.cert_file = loaded.http.tls.cert_file.clone();
+    config.http.tls.key_file = loaded.http.tls.key_file.clone();
+    config.http.cors.allowed_origins = loaded.http.cors.allowed_origins.clone();
+    config
+}
+
+/// Makes sure the certificates can be loaded before they're published to the listeners.
+async fn validate_certificates(reloaded: &ServerConfig) -> Result<(), ConfigError> {
+    let tcp_tls = &reloaded.tcp.tls;
+    if reloaded.tcp.enabled && tcp_tls.enabled {
+        if let Err(error) = tcp_tls_listener::build_acceptor(tcp_tls) {
+            error!(
+                "Cannot load TCP TLS certificate: {}. {error}",
+                tcp_tls.certificate
+            );
+            return Err(ConfigError::CannotLoadCertificate);
+        }
+    }
+
+    let http_tls = &reloaded.http.tls;
+    if reloaded.http.enabled && http_tls.enabled {
+        if let Err(error) =
+            RustlsConfig::from_pem_file(&http_tls.cert_file, &http_tls.key_file).await
+        {
+            error!(
+                "Cannot load HTTP TLS certificate: {}. {error}",
+                http_tls.cert_file
+            );
+            return Err(ConfigError::CannotLoadCertificate);
+        }
+    }
+
+    Ok(())
+}
+
+impl CertificateFiles {
+    /// Reads the certificate files of the enabled TLS listeners, a missing file is read as `None`.
+    fn read(config: &ServerConfig) -> Self {
+        let read = |enabled: bool, path: &str| {
+            if enabled {
+                std::fs::read(path).ok()
+            } else {
+                None
+            }
+        };
+        let tcp_tls = config.tcp.enabled && config.tcp.tls.enabled;
+        let http_tls = config.http.enabled && config.http.tls.enabled;
+        CertificateFiles {
+            tcp_certificate: read(tcp_tls, &config.tcp.tls.certificate),
+            http_cert_file: read(http_tls, &config.http.tls.cert_file),
+            http_key_file: read(http_tls, &config.http.tls.key_file),
+        }
+    }
+
+    /// Returns the keys of the certificate settings whose files have different contents.
+    fn changed_keys(&self, other: &CertificateFiles) -> Vec<String> {
+        [
+            (
+                "tcp.tls.certificate",
+                &self.tcp_certificate,
+                &other.tcp_certificate,
+            ),
+            (
+                "http.tls.cert_file",
+                &self.http_cert_file,
+                &other.http_cert_file,
+            ),
+            (
+                "http.tls.key_file",
+                &self.http_key_file,
+                &other.http_key_file,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, current, reloaded)| current != reloaded)
+        .map(|(key, _, _)| key.to_string())
+        .collect()
+    }
+}
+
+/// Returns the sorted, dotted keys (e.g. `heartbeat.interval`) of the settings which differ.
+fn changed_keys(current: &ServerConfig, loaded: &ServerConfig) -> Vec<String> {
+    let mut current_values = BTreeMap::new();
+    let mut loaded_values = BTreeMap::new();
+    flatten("", to_toml(current), &mut current_values);
+    flatten("", to_toml(loaded), &mut loaded_values);
+
+    let mut keys = current_values
+        .iter()
+        .filter(|(key, value)| loaded_values.get(*key) != Some(*value))
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+    keys.extend(
+        loaded_values
+            .keys()
+            .filter(|key| !current_values.contains_key(*key))
+            .cloned(),
+    );
+    keys.sort();
+    keys
+}
+
+fn to_toml(config: &ServerConfig) -> TomlValue {
+    TomlValue::try_from(config).expect("Cannot serialize server config")
+}
+
+fn flatten(prefix: &str, value: TomlValue, values: &mut BTreeMap<String, TomlValue>) {
+    match value {
+        TomlValue::Table(table) => {
+            for (key, value) in table {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&key, value, values);
+            }
+        }
+        value => {
+            values.insert(prefix.to_string(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use iggy::utils::topic_size::MaxTopicSize;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_not_report_changes_for_the_same_config() {
+        let config = ServerConfig::default();
+        assert!(changed_keys(&config, &config.clone()).is_empty());
+    }
+
+    #[test]
+    fn should_report_changed_keys() {
+        let current = ServerConfig::default();
+        let mut loaded = current.clone();
+        loaded.heartbeat.interval = IggyDuration::from_str("1m").unwrap();
+        loaded.tcp.address = "127.0.0.1:9999".to_string();
+
+        let keys = changed_keys(&current, &loaded);
+
+        assert_eq!(keys, vec!["heartbeat.interval", "tcp.address"]);
+    }
+
+    #[test]
+    fn should_apply_only_reloadable_changes() {
+        let current = ServerConfig::default();
+        let mut loaded = current.clone();
+        loaded.heartbeat.interval = IggyDuration::from_str("1m").unwrap();
+        loaded.tcp.address = "127.0.0.1:9999".to_string();
+        let mut system = (*loaded.system).clone();
+        system.topic.max_size = MaxTopicSize::from_str("10 GB").unwrap();
+        loaded.system = Arc::new(system);
+
+        let reloaded = apply_reloadable(&current, &loaded);
+
+        assert_eq!(reloaded.heartbeat.interval, loaded.heartbeat.interval);
+        assert_eq!(reloaded.system.topic.max_size, loaded.system.topic.max_size);
+        assert_eq!(reloaded.tcp.address, current.tcp.address);
+        assert!(changed_keys(&reloaded, &loaded)
+            .iter()
+            .all(|key| !RELOADABLE_KEYS.contains(&key.as_str())));
+    }
+
+    #[test]
+    fn should_report_certificate_renewed_in_place() {
+        let cert_file = tempfile::NamedTempFile::new().unwrap();
+        let key_file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(cert_file.path(), "certificate").unwrap();
+        std::fs::write(key_file.path(), "key").unwrap();
+        let mut config = ServerConfig::default();
+        config.http.enabled = true;
+        config.http.tls.enabled = true;
+        config.http.tls.cert_file = cert_file.path().to_string_lossy().to_string();
+        config.http.tls.key_file = key_file.path().to_string_lossy().to_string();
+        let current = CertificateFiles::read(&config);
+
+        std::fs::write(cert_file.path(), "renewed certificate").unwrap();
+        let reloaded = CertificateFiles::read(&config);
+
+        assert_eq!(current.changed_keys(&reloaded), vec!["http.tls.cert_file"]);
+        assert!(reloaded
+            .changed_keys(&CertificateFiles::read(&config))
+            .is_empty());
+    }
+}
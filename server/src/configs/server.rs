@@ -18,10 +18,14 @@
 
 use crate::archiver::ArchiverKindType;
 use crate::configs::config_provider::ConfigProviderKind;
+use crate::configs::grpc::GrpcConfig;
 use crate::configs::http::HttpConfig;
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
+use crate::configs::websocket::WebSocketConfig;
 use crate::configs::COMPONENT;
 use crate::server_error::ConfigError;
 use derive_more::Display;
@@ -40,10 +44,15 @@ pub struct ServerConfig {
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
     pub heartbeat: HeartbeatConfig,
+    pub shutdown: ShutdownConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
+    pub websocket: WebSocketConfig,
+    pub kafka: KafkaConfig,
+    pub mqtt: MqttConfig,
+    pub grpc: GrpcConfig,
     pub telemetry: TelemetryConfig,
 }
 
@@ -127,6 +136,13 @@ pub struct HeartbeatConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ShutdownConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TelemetryConfig {
     pub enabled: bool,
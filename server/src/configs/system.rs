@@ -17,6 +17,7 @@
  */
 
 use crate::configs::resource_quota::MemoryResourceQuota;
+use derive_more::Display;
 use iggy::confirmation::Confirmation;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -27,12 +28,14 @@ use iggy::{
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::str::FromStr;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SystemConfig {
     pub path: String,
     pub backup: BackupConfig,
     pub state: StateConfig,
+    pub storage: StorageConfig,
     pub runtime: RuntimeConfig,
     pub logging: LoggingConfig,
     pub cache: CacheConfig,
@@ -43,38 +46,71 @@ pub struct SystemConfig {
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
+    pub polling: PollingConfig,
     pub recovery: RecoveryConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct BackupConfig {
     pub path: String,
     pub compatibility: CompatibilityConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CompatibilityConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DatabaseConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    pub queue_depth: u32,
+    pub registered_buffers: u32,
+    pub registered_buffer_size: IggyByteSize,
+    pub direct_io: bool,
+}
+
+/// Determines how the file I/O of the segments and the persisted metadata is performed.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    /// Blocking file operations are offloaded to the tokio threadpool.
+    #[display("tokio")]
+    Tokio,
+    /// File operations are submitted to a dedicated io_uring instance (requires the `io-uring` feature).
+    #[display("io_uring")]
+    IoUring,
+}
+
+impl FromStr for StorageBackend {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tokio" => Ok(StorageBackend::Tokio),
+            "io_uring" => Ok(StorageBackend::IoUring),
+            _ => Err(format!("Invalid storage backend: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RuntimeConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CompressionConfig {
     pub allow_override: bool,
     pub default_algorithm: CompressionAlgorithm,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LoggingConfig {
     pub path: String,
     pub level: String,
@@ -85,25 +121,25 @@ pub struct LoggingConfig {
     pub sysinfo_print_interval: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CacheConfig {
     pub enabled: bool,
     pub size: MemoryResourceQuota,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StreamConfig {
     pub path: String,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TopicConfig {
     pub path: String,
     #[serde_as(as = "DisplayFromStr")]
@@ -111,7 +147,7 @@ pub struct TopicConfig {
     pub delete_oldest_segments: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PartitionConfig {
     pub path: String,
     pub messages_required_to_save: u32,
@@ -120,7 +156,7 @@ pub struct PartitionConfig {
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MessageDeduplicationConfig {
     pub enabled: bool,
     pub max_entries: u64,
@@ -128,13 +164,20 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PollingConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_wait: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RecoveryConfig {
     pub recreate_missing_state: bool,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
     pub cache_indexes: bool,
@@ -143,10 +186,37 @@ pub struct SegmentConfig {
     pub archive_expired: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub server_confirmation: Confirmation,
+    pub durability: Durability,
+}
+
+/// Controls when the appended messages are written to the segment and acknowledged.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Durability {
+    /// Messages are buffered in memory until `messages_required_to_save` is reached
+    /// or the message saver persists them.
+    #[display("buffered")]
+    Buffered,
+    /// Every batch is written to the segment right away and, with the `wait` server confirmation,
+    /// acknowledged only once it's synced to disk. With the `no_wait` one, it's synced in the background.
+    /// Concurrent writers share a single fsync.
+    #[display("group_commit")]
+    GroupCommit,
+}
+
+impl FromStr for Durability {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "buffered" => Ok(Durability::Buffered),
+            "group_commit" => Ok(Durability::GroupCommit),
+            _ => Err(format!("Invalid durability: {s}")),
+        }
+    }
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StateConfig {
     pub enforce_fsync: bool,
     pub max_file_operation_retries: u32,
@@ -24,9 +24,15 @@ use super::server::{
 };
 use super::system::CompressionConfig;
 use crate::archiver::ArchiverKindType;
+use crate::configs::grpc::GrpcConfig;
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::configs::system::{
+    CacheConfig, PollingConfig, SegmentConfig, StorageBackend, StorageConfig,
+};
 use crate::configs::COMPONENT;
+use crate::mqtt::mapping::TopicMappings;
 use crate::server_error::ConfigError;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
@@ -52,9 +58,15 @@ impl Validatable<ConfigError> for ServerConfig {
         self.system.segment.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate segment config")
         })?;
+        self.system.storage.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate storage config")
+        })?;
         self.system.cache.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate cache config")
         })?;
+        self.system.polling.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate polling config")
+        })?;
         self.system
             .compression
             .validate()
@@ -64,6 +76,15 @@ impl Validatable<ConfigError> for ServerConfig {
         self.telemetry.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate telemetry config")
         })?;
+        self.kafka.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate Kafka config")
+        })?;
+        self.mqtt.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate MQTT config")
+        })?;
+        self.grpc.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate gRPC config")
+        })?;
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -178,6 +199,127 @@ impl Validatable<ConfigError> for SegmentConfig {
     }
 }
 
+impl Validatable<ConfigError> for PollingConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        // The idle message subscriptions would otherwise keep polling without any pause.
+        if self.max_wait.as_micros() == 0 {
+            println!("Polling configuration -> max_wait must be greater than zero.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for KafkaConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.naming.separator.is_empty() {
+            println!("Kafka configuration -> naming separator cannot be empty.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.max_request_size.as_bytes_u64() == 0 {
+            println!("Kafka configuration -> max_request_size must be greater than zero.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if !self.advertised_address.is_empty() && self.advertised_host_and_port().is_none() {
+            println!(
+                "Kafka configuration -> advertised_address must be in the host:port format, got: {}.",
+                self.advertised_address
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MqttConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // The remaining length of MQTT packet is encoded on up to 4 bytes.
+        const MAX_PACKET_SIZE: u64 = 268_435_460;
+        let max_packet_size = self.max_packet_size.as_bytes_u64();
+        if max_packet_size == 0 || max_packet_size > MAX_PACKET_SIZE {
+            println!("MQTT configuration -> max_packet_size must be greater than zero and not greater than {MAX_PACKET_SIZE} bytes.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.connect_timeout.is_zero() {
+            println!("MQTT configuration -> connect_timeout must be greater than zero.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.max_inflight_messages == 0 {
+            println!("MQTT configuration -> max_inflight_messages must be greater than zero.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let Err(error) = TopicMappings::parse(&self.topic_mappings) {
+            println!("MQTT configuration -> {error}.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for GrpcConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if !cfg!(feature = "grpc") {
+            println!("gRPC configuration -> gRPC gateway requires the server to be built with the `grpc` feature.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let max_message_size = self.max_message_size.as_bytes_u64();
+        if max_message_size == 0 || max_message_size > u32::MAX as u64 {
+            println!("gRPC configuration -> max_message_size must be greater than zero and not greater than {} bytes.", u32::MAX);
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for StorageConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.backend != StorageBackend::IoUring {
+            return Ok(());
+        }
+
+        if !cfg!(feature = "io-uring") {
+            println!("Storage configuration -> io_uring backend requires the server to be built with the `io-uring` feature.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let buffer_size = self.registered_buffer_size.as_bytes_u64();
+        if self.queue_depth == 0
+            || self.registered_buffers == 0
+            || self.registered_buffers > u16::MAX as u32
+            || buffer_size == 0
+            || !buffer_size.is_multiple_of(4096)
+            || buffer_size > u32::MAX as u64
+        {
+            println!("Storage configuration -> invalid io_uring settings: {self}");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for MessageSaverConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.enabled && self.interval.is_zero() {
@@ -0,0 +1,65 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+use tonic::metadata::MetadataValue;
+use tonic::{Code, Status};
+use tracing::debug;
+
+/// The metadata holding the numeric code of the Iggy error.
+pub const ERROR_CODE_METADATA: &str = "iggy-error-code";
+
+/// Maps the Iggy error to the gRPC status with the closest code, keeping the Iggy error code in the metadata.
+pub fn map_error(error: IggyError) -> Status {
+    debug!("There was an error: {error}");
+    let code = match error {
+        IggyError::StreamIdNotFound(_)
+        | IggyError::TopicIdNotFound(_, _)
+        | IggyError::PartitionNotFound(_, _, _)
+        | IggyError::SegmentNotFound
+        | IggyError::ClientNotFound(_)
+        | IggyError::ConsumerGroupIdNotFound(_, _)
+        | IggyError::ConsumerGroupNameNotFound(_, _)
+        | IggyError::ConsumerGroupMemberNotFound(_, _, _)
+        | IggyError::ConsumerOffsetNotFound(_)
+        | IggyError::ResourceNotFound(_)
+        | IggyError::SchemaNotFound(_)
+        | IggyError::TopicSchemaNotFound(_, _) => Code::NotFound,
+        IggyError::StreamIdAlreadyExists(_)
+        | IggyError::StreamNameAlreadyExists(_)
+        | IggyError::TopicIdAlreadyExists(_, _)
+        | IggyError::TopicNameAlreadyExists(_, _)
+        | IggyError::ConsumerGroupIdAlreadyExists(_, _)
+        | IggyError::ConsumerGroupNameAlreadyExists(_, _)
+        | IggyError::UserAlreadyExists
+        | IggyError::PersonalAccessTokenAlreadyExists(_, _) => Code::AlreadyExists,
+        IggyError::Unauthenticated
+        | IggyError::AccessTokenMissing
+        | IggyError::InvalidAccessToken
+        | IggyError::InvalidCredentials
+        | IggyError::InvalidPersonalAccessToken => Code::Unauthenticated,
+        IggyError::Unauthorized => Code::PermissionDenied,
+        IggyError::ServerShuttingDown => Code::Unavailable,
+        _ => Code::InvalidArgument,
+    };
+    let mut status = Status::new(code, error.to_string());
+    status
+        .metadata_mut()
+        .insert(ERROR_CODE_METADATA, MetadataValue::from(error.as_code()));
+    status
+}
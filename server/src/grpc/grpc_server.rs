@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::grpc::GrpcConfig;
+use crate::grpc::proto::iggy_server::IggyServer;
+use crate::grpc::service::IggyService;
+use crate::http::jwt::jwt_manager::JwtManager;
+use crate::shutdown::Shutdown;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tracing::{error, info};
+
+/// Starts the gRPC gateway and returns the address it is bound to.
+pub async fn start(
+    config: GrpcConfig,
+    system: SharedSystem,
+    jwt_manager: Arc<JwtManager>,
+    shutdown: Shutdown,
+) -> SocketAddr {
+    info!("Initializing Iggy gRPC server...");
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to start gRPC server on address: {}. {error}",
+                config.address
+            )
+        });
+    let addr = listener
+        .local_addr()
+        .expect("Failed to get local address for gRPC server");
+    let incoming = TcpIncoming::from_listener(listener, true, None)
+        .unwrap_or_else(|error| panic!("Failed to accept gRPC connections on {addr}. {error}"));
+
+    let max_message_size = config.max_message_size.as_bytes_usize();
+    let service = IggyService::new(system, jwt_manager, shutdown.clone(), max_message_size);
+    let service = IggyServer::new(service)
+        .max_decoding_message_size(max_message_size)
+        .max_encoding_message_size(max_message_size);
+    let signal = shutdown.clone();
+    shutdown.spawn(async move {
+        if let Err(error) = Server::builder()
+            .add_service(service)
+            .serve_with_incoming_shutdown(incoming, async move { signal.triggered().await })
+            .await
+        {
+            error!("gRPC server has failed: {error}");
+        }
+        info!("gRPC server has stopped.");
+    });
+
+    info!("Iggy gRPC server has started on: {:?}", addr);
+    addr
+}
@@ -0,0 +1,445 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::grpc::proto;
+use crate::http::jwt::json_web_token::GeneratedToken;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingKind;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::appended_messages::AppendedMessages;
+use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use iggy::models::messages::{PolledMessage, PolledMessages};
+use iggy::models::partition::Partition;
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::stream::{Stream, StreamDetails};
+use iggy::models::topic::{Topic, TopicDetails};
+use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::models::user_status::UserStatus;
+use std::collections::HashMap;
+
+/// Returns the identifier, which is required by every request referring to the resource.
+pub fn identifier(identifier: Option<proto::Identifier>) -> Result<Identifier, IggyError> {
+    match identifier.and_then(|identifier| identifier.kind) {
+        Some(proto::identifier::Kind::Numeric(id)) => Identifier::numeric(id),
+        Some(proto::identifier::Kind::Name(name)) => Identifier::named(&name),
+        None => Err(IggyError::InvalidIdentifier),
+    }
+}
+
+/// Returns the consumer, or the default one with ID 1 if unspecified.
+pub fn consumer(consumer_id: Option<proto::Identifier>) -> Result<Consumer, IggyError> {
+    match consumer_id {
+        Some(consumer_id) => Ok(Consumer::new(identifier(Some(consumer_id))?)),
+        None => Ok(Consumer::default()),
+    }
+}
+
+fn code(value: i32) -> Result<u8, IggyError> {
+    u8::try_from(value).map_err(|_| IggyError::InvalidCommand)
+}
+
+/// Returns the compression algorithm, with no compression if unspecified.
+pub fn compression_algorithm(value: i32) -> Result<CompressionAlgorithm, IggyError> {
+    match value {
+        0 => Ok(CompressionAlgorithm::default()),
+        value => CompressionAlgorithm::from_code(code(value)?),
+    }
+}
+
+/// Returns the user status, active if unspecified.
+pub fn user_status(value: i32) -> Result<UserStatus, IggyError> {
+    match value {
+        0 => Ok(UserStatus::default()),
+        value => UserStatus::from_code(code(value)?),
+    }
+}
+
+/// Returns the polling kind, polling by the offset if unspecified.
+pub fn polling_kind(value: i32) -> Result<PollingKind, IggyError> {
+    match value {
+        0 => Ok(PollingKind::default()),
+        value => PollingKind::from_code(code(value)?),
+    }
+}
+
+/// Returns the partitioning, balanced if unspecified.
+pub fn partitioning(partitioning: Option<proto::Partitioning>) -> Result<Partitioning, IggyError> {
+    match partitioning.and_then(|partitioning| partitioning.kind) {
+        None | Some(proto::partitioning::Kind::Balanced(_)) => Ok(Partitioning::balanced()),
+        Some(proto::partitioning::Kind::PartitionId(partition_id)) => {
+            Ok(Partitioning::partition_id(partition_id))
+        }
+        Some(proto::partitioning::Kind::MessagesKey(key)) => Partitioning::messages_key(&key),
+    }
+}
+
+/// Returns the message with the ID decoded from the big-endian bytes, or 0 if empty, so that it's generated by the server.
+pub fn message(message: proto::Message) -> Result<Message, IggyError> {
+    let id = match message.id.len() {
+        0 => 0,
+        16 => u128::from_be_bytes(message.id.as_ref().try_into().unwrap()),
+        _ => return Err(IggyError::InvalidCommand),
+    };
+    let headers = match message.headers.is_empty() {
+        true => None,
+        false => Some(
+            message
+                .headers
+                .into_iter()
+                .map(|(key, value)| {
+                    Ok((
+                        HeaderKey::new(&key)?,
+                        HeaderValue {
+                            kind: HeaderKind::from_code(code(value.kind)?)?,
+                            value: value.value,
+                        },
+                    ))
+                })
+                .collect::<Result<HashMap<_, _>, IggyError>>()?,
+        ),
+    };
+    Ok(Message::new(Some(id), message.payload, headers))
+}
+
+pub fn permissions(permissions: proto::Permissions) -> Permissions {
+    let global = permissions.global.unwrap_or_default();
+    Permissions {
+        global: GlobalPermissions {
+            manage_servers: global.manage_servers,
+            read_servers: global.read_servers,
+            manage_users: global.manage_users,
+            read_users: global.read_users,
+            manage_streams: global.manage_streams,
+            read_streams: global.read_streams,
+            manage_topics: global.manage_topics,
+            read_topics: global.read_topics,
+            poll_messages: global.poll_messages,
+            send_messages: global.send_messages,
+        },
+        streams: (!permissions.streams.is_empty()).then(|| {
+            permissions
+                .streams
+                .into_iter()
+                .map(|(stream_id, stream)| {
+                    let stream = StreamPermissions {
+                        manage_stream: stream.manage_stream,
+                        read_stream: stream.read_stream,
+                        manage_topics: stream.manage_topics,
+                        read_topics: stream.read_topics,
+                        poll_messages: stream.poll_messages,
+                        send_messages: stream.send_messages,
+                        topics: (!stream.topics.is_empty()).then(|| {
+                            stream
+                                .topics
+                                .into_iter()
+                                .map(|(topic_id, topic)| {
+                                    let topic = TopicPermissions {
+                                        manage_topic: topic.manage_topic,
+                                        read_topic: topic.read_topic,
+                                        poll_messages: topic.poll_messages,
+                                        send_messages: topic.send_messages,
+                                    };
+                                    (topic_id, topic)
+                                })
+                                .collect()
+                        }),
+                    };
+                    (stream_id, stream)
+                })
+                .collect()
+        }),
+    }
+}
+
+impl From<Permissions> for proto::Permissions {
+    fn from(permissions: Permissions) -> Self {
+        let global = permissions.global;
+        proto::Permissions {
+            global: Some(proto::GlobalPermissions {
+                manage_servers: global.manage_servers,
+                read_servers: global.read_servers,
+                manage_users: global.manage_users,
+                read_users: global.read_users,
+                manage_streams: global.manage_streams,
+                read_streams: global.read_streams,
+                manage_topics: global.manage_topics,
+                read_topics: global.read_topics,
+                poll_messages: global.poll_messages,
+                send_messages: global.send_messages,
+            }),
+            streams: permissions
+                .streams
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(stream_id, stream)| {
+                    let stream = proto::StreamPermissions {
+                        manage_stream: stream.manage_stream,
+                        read_stream: stream.read_stream,
+                        manage_topics: stream.manage_topics,
+                        read_topics: stream.read_topics,
+                        poll_messages: stream.poll_messages,
+                        send_messages: stream.send_messages,
+                        topics: stream
+                            .topics
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|(topic_id, topic)| {
+                                let topic = proto::TopicPermissions {
+                                    manage_topic: topic.manage_topic,
+                                    read_topic: topic.read_topic,
+                                    poll_messages: topic.poll_messages,
+                                    send_messages: topic.send_messages,
+                                };
+                                (topic_id, topic)
+                            })
+                            .collect(),
+                    };
+                    (stream_id, stream)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<GeneratedToken> for proto::IdentityInfo {
+    fn from(token: GeneratedToken) -> Self {
+        proto::IdentityInfo {
+            user_id: token.user_id,
+            access_token: token.access_token,
+            access_token_expiry: token.access_token_expiry,
+        }
+    }
+}
+
+impl From<UserInfo> for proto::UserInfo {
+    fn from(user: UserInfo) -> Self {
+        proto::UserInfo {
+            id: user.id,
+            created_at: user.created_at.as_micros(),
+            status: user.status.as_code() as i32,
+            username: user.username,
+        }
+    }
+}
+
+impl From<UserInfoDetails> for proto::UserInfoDetails {
+    fn from(user: UserInfoDetails) -> Self {
+        proto::UserInfoDetails {
+            id: user.id,
+            created_at: user.created_at.as_micros(),
+            status: user.status.as_code() as i32,
+            username: user.username,
+            permissions: user.permissions.map(proto::Permissions::from),
+        }
+    }
+}
+
+impl From<PersonalAccessTokenInfo> for proto::PersonalAccessTokenInfo {
+    fn from(token: PersonalAccessTokenInfo) -> Self {
+        proto::PersonalAccessTokenInfo {
+            name: token.name,
+            expiry_at: token.expiry_at.map(|expiry_at| expiry_at.as_micros()),
+        }
+    }
+}
+
+impl From<Stream> for proto::Stream {
+    fn from(stream: Stream) -> Self {
+        proto::Stream {
+            id: stream.id,
+            created_at: stream.created_at.as_micros(),
+            name: stream.name,
+            size_bytes: stream.size.as_bytes_u64(),
+            messages_count: stream.messages_count,
+            topics_count: stream.topics_count,
+        }
+    }
+}
+
+impl From<StreamDetails> for proto::StreamDetails {
+    fn from(stream: StreamDetails) -> Self {
+        proto::StreamDetails {
+            id: stream.id,
+            created_at: stream.created_at.as_micros(),
+            name: stream.name,
+            size_bytes: stream.size.as_bytes_u64(),
+            messages_count: stream.messages_count,
+            topics_count: stream.topics_count,
+            topics: stream.topics.into_iter().map(proto::Topic::from).collect(),
+        }
+    }
+}
+
+impl From<Topic> for proto::Topic {
+    fn from(topic: Topic) -> Self {
+        proto::Topic {
+            id: topic.id,
+            created_at: topic.created_at.as_micros(),
+            name: topic.name,
+            size_bytes: topic.size.as_bytes_u64(),
+            message_expiry: topic.message_expiry.into(),
+            compression_algorithm: topic.compression_algorithm.as_code() as i32,
+            max_topic_size: topic.max_topic_size.into(),
+            replication_factor: topic.replication_factor as u32,
+            messages_count: topic.messages_count,
+            partitions_count: topic.partitions_count,
+        }
+    }
+}
+
+impl From<TopicDetails> for proto::TopicDetails {
+    fn from(topic: TopicDetails) -> Self {
+        proto::TopicDetails {
+            id: topic.id,
+            created_at: topic.created_at.as_micros(),
+            name: topic.name,
+            size_bytes: topic.size.as_bytes_u64(),
+            message_expiry: topic.message_expiry.into(),
+            compression_algorithm: topic.compression_algorithm.as_code() as i32,
+            max_topic_size: topic.max_topic_size.into(),
+            replication_factor: topic.replication_factor as u32,
+            messages_count: topic.messages_count,
+            partitions_count: topic.partitions_count,
+            partitions: topic
+                .partitions
+                .into_iter()
+                .map(proto::Partition::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<Partition> for proto::Partition {
+    fn from(partition: Partition) -> Self {
+        proto::Partition {
+            id: partition.id,
+            created_at: partition.created_at.as_micros(),
+            segments_count: partition.segments_count,
+            current_offset: partition.current_offset,
+            size_bytes: partition.size.as_bytes_u64(),
+            messages_count: partition.messages_count,
+        }
+    }
+}
+
+impl From<AppendedMessages> for proto::AppendedMessages {
+    fn from(messages: AppendedMessages) -> Self {
+        proto::AppendedMessages {
+            partition_id: messages.partition_id,
+            base_offset: messages.base_offset,
+            last_offset: messages.last_offset,
+            base_timestamp: messages.base_timestamp,
+            last_timestamp: messages.last_timestamp,
+        }
+    }
+}
+
+impl From<PolledMessage> for proto::PolledMessage {
+    fn from(message: PolledMessage) -> Self {
+        proto::PolledMessage {
+            offset: message.offset,
+            state: message.state.as_code() as i32,
+            timestamp: message.timestamp,
+            id: Bytes::copy_from_slice(&message.id.to_be_bytes()),
+            checksum: message.checksum,
+            headers: message
+                .headers
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = proto::HeaderValue {
+                        kind: value.kind.as_code() as i32,
+                        value: value.value,
+                    };
+                    (key.as_str().to_owned(), value)
+                })
+                .collect(),
+            payload: message.payload,
+        }
+    }
+}
+
+impl From<PolledMessages> for proto::PolledMessages {
+    fn from(messages: PolledMessages) -> Self {
+        proto::PolledMessages {
+            partition_id: messages.partition_id,
+            current_offset: messages.current_offset,
+            messages: messages
+                .messages
+                .into_iter()
+                .map(proto::PolledMessage::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<ConsumerOffsetInfo> for proto::ConsumerOffsetInfo {
+    fn from(offset: ConsumerOffsetInfo) -> Self {
+        proto::ConsumerOffsetInfo {
+            partition_id: offset.partition_id,
+            current_offset: offset.current_offset,
+            stored_offset: offset.stored_offset,
+        }
+    }
+}
+
+impl From<ConsumerGroup> for proto::ConsumerGroup {
+    fn from(group: ConsumerGroup) -> Self {
+        proto::ConsumerGroup {
+            id: group.id,
+            name: group.name,
+            partitions_count: group.partitions_count,
+            members_count: group.members_count,
+        }
+    }
+}
+
+impl From<ConsumerGroupDetails> for proto::ConsumerGroupDetails {
+    fn from(group: ConsumerGroupDetails) -> Self {
+        proto::ConsumerGroupDetails {
+            id: group.id,
+            name: group.name,
+            partitions_count: group.partitions_count,
+            members_count: group.members_count,
+            members: group
+                .members
+                .into_iter()
+                .map(proto::ConsumerGroupMember::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<ConsumerGroupMember> for proto::ConsumerGroupMember {
+    fn from(member: ConsumerGroupMember) -> Self {
+        proto::ConsumerGroupMember {
+            id: member.id,
+            partitions_count: member.partitions_count,
+            partitions: member.partitions,
+        }
+    }
+}
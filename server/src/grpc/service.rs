@@ -0,0 +1,1228 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::grpc::error::map_error;
+use crate::grpc::mapper;
+use crate::grpc::proto;
+use crate::grpc::proto::iggy_server::Iggy;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::jwt::jwt_manager::JwtManager;
+use crate::http::mapper as models;
+use crate::http::messages::MessagesSubscription;
+use crate::shutdown::Shutdown;
+use crate::state::command::EntryCommand;
+use crate::state::models::{
+    CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
+    CreateTopicWithId, CreateUserWithId,
+};
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::{crypto, random_id};
+use futures::Stream;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::error::IggyError;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::SendMessages;
+use iggy::models::messages::PolledMessages;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::purge_stream::PurgeStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::users::change_password::ChangePassword;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::login_user::LoginUser;
+use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_user::UpdateUser;
+use iggy::utils::duration::IggyDuration;
+use iggy::validatable::Validatable;
+use prost::Message;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tonic::{Request, Response, Status};
+
+const AUTHORIZATION: &str = "authorization";
+const BEARER: &str = "Bearer ";
+const PONG: &str = "pong";
+
+type Result<T> = std::result::Result<Response<T>, Status>;
+type MessagesStream =
+    Pin<Box<dyn Stream<Item = std::result::Result<proto::PolledMessages, Status>> + Send>>;
+
+/// Handles the gRPC calls the same way as the HTTP API, with the stateless session of the user authenticated by the access token.
+pub struct IggyService {
+    system: SharedSystem,
+    jwt_manager: Arc<JwtManager>,
+    shutdown: Shutdown,
+    max_message_size: usize,
+}
+
+impl IggyService {
+    pub fn new(
+        system: SharedSystem,
+        jwt_manager: Arc<JwtManager>,
+        shutdown: Shutdown,
+        max_message_size: usize,
+    ) -> Self {
+        Self {
+            system,
+            jwt_manager,
+            shutdown,
+            max_message_size,
+        }
+    }
+
+    /// Validates the access token sent in the `authorization` metadata, the same way as the HTTP API.
+    async fn authenticate<T>(&self, request: &Request<T>) -> std::result::Result<Identity, Status> {
+        let token = request
+            .metadata()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(BEARER))
+            .ok_or_else(|| map_error(IggyError::AccessTokenMissing))?;
+        let header = jsonwebtoken::decode_header(token)
+            .map_err(|_| map_error(IggyError::InvalidAccessToken))?;
+        let claims = self
+            .jwt_manager
+            .decode(token, header.alg)
+            .map_err(|_| map_error(IggyError::InvalidAccessToken))?
+            .claims;
+        if self.jwt_manager.is_token_revoked(&claims.jti).await
+            || self
+                .system
+                .read()
+                .await
+                .is_user_session_revoked(claims.sub, claims.issued_at())
+                .await
+        {
+            return Err(map_error(IggyError::InvalidAccessToken));
+        }
+
+        Ok(Identity {
+            token_id: claims.jti,
+            token_expiry: claims.exp,
+            user_id: claims.sub,
+            ip_address: request
+                .remote_addr()
+                .unwrap_or_else(|| SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0))),
+        })
+    }
+
+    async fn session<T>(&self, request: &Request<T>) -> std::result::Result<Session, Status> {
+        let identity = self.authenticate(request).await?;
+        Ok(Session::stateless(identity.user_id, identity.ip_address))
+    }
+}
+
+/// Splits the polled messages into the chunks, each encoded within the maximum message size,
+/// keeping at least one chunk, so that the empty result is streamed as well.
+fn into_chunks(messages: PolledMessages, max_message_size: usize) -> Vec<proto::PolledMessages> {
+    let mut chunk = proto::PolledMessages {
+        partition_id: messages.partition_id,
+        current_offset: messages.current_offset,
+        messages: Vec::new(),
+    };
+    let empty_chunk_size = chunk.encoded_len();
+    let mut chunk_size = empty_chunk_size;
+    let mut chunks = Vec::new();
+    for message in messages.messages {
+        let message = proto::PolledMessage::from(message);
+        let message_size = message.encoded_len();
+        // The tag and the length delimiter of the repeated field.
+        let message_size = 1 + prost::length_delimiter_len(message_size) + message_size;
+        if !chunk.messages.is_empty() && chunk_size + message_size > max_message_size {
+            let messages = std::mem::take(&mut chunk.messages);
+            chunks.push(proto::PolledMessages {
+                messages,
+                ..chunk.clone()
+            });
+            chunk_size = empty_chunk_size;
+        }
+        chunk_size += message_size;
+        chunk.messages.push(message);
+    }
+    chunks.push(chunk);
+    chunks
+}
+
+fn poll_messages_command(
+    request: proto::PollMessagesRequest,
+) -> std::result::Result<PollMessages, IggyError> {
+    let defaults = PollMessages::default();
+    let command = PollMessages {
+        consumer: mapper::consumer(request.consumer_id)?,
+        stream_id: mapper::identifier(request.stream_id)?,
+        topic_id: mapper::identifier(request.topic_id)?,
+        partition_id: request.partition_id.or(defaults.partition_id),
+        strategy: PollingStrategy {
+            kind: mapper::polling_kind(request.kind)?,
+            value: request.value,
+        },
+        count: match request.count {
+            0 => defaults.count,
+            count => count,
+        },
+        auto_commit: request.auto_commit,
+        max_wait: IggyDuration::new(Duration::from_millis(request.max_wait_ms)),
+    };
+    command.validate()?;
+    Ok(command)
+}
+
+fn replication_factor(value: u32) -> std::result::Result<Option<u8>, IggyError> {
+    match value {
+        0 => Ok(None),
+        value => u8::try_from(value)
+            .map(Some)
+            .map_err(|_| IggyError::InvalidReplicationFactor),
+    }
+}
+
+#[tonic::async_trait]
+impl Iggy for IggyService {
+    async fn ping(&self, _: Request<proto::PingRequest>) -> Result<proto::PingResponse> {
+        Ok(Response::new(proto::PingResponse {
+            message: PONG.to_owned(),
+        }))
+    }
+
+    async fn login_user(
+        &self,
+        request: Request<proto::LoginUserRequest>,
+    ) -> Result<proto::IdentityInfo> {
+        let request = request.into_inner();
+        let command = LoginUser {
+            username: request.username,
+            password: request.password,
+            version: None,
+            context: None,
+        };
+        command.validate().map_err(map_error)?;
+        let system = self.system.read().await;
+        let user = system
+            .login_user(&command.username, &command.password, None)
+            .await
+            .map_err(map_error)?;
+        let token = self.jwt_manager.generate(user.id).map_err(map_error)?;
+        Ok(Response::new(token.into()))
+    }
+
+    async fn login_with_personal_access_token(
+        &self,
+        request: Request<proto::LoginWithPersonalAccessTokenRequest>,
+    ) -> Result<proto::IdentityInfo> {
+        let command = LoginWithPersonalAccessToken {
+            token: request.into_inner().token,
+        };
+        command.validate().map_err(map_error)?;
+        let system = self.system.read().await;
+        let user = system
+            .login_with_personal_access_token(&command.token, None)
+            .await
+            .map_err(map_error)?;
+        let token = self.jwt_manager.generate(user.id).map_err(map_error)?;
+        Ok(Response::new(token.into()))
+    }
+
+    async fn logout_user(
+        &self,
+        request: Request<proto::LogoutUserRequest>,
+    ) -> Result<proto::Empty> {
+        let identity = self.authenticate(&request).await?;
+        let system = self.system.read().await;
+        system
+            .logout_user(&Session::stateless(identity.user_id, identity.ip_address))
+            .await
+            .map_err(map_error)?;
+        self.jwt_manager
+            .revoke_token(&identity.token_id, identity.token_expiry)
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<proto::GetUserRequest>,
+    ) -> Result<proto::UserInfoDetails> {
+        let session = self.session(&request).await?;
+        let user_id = mapper::identifier(request.into_inner().user_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let user = system
+            .find_user(&session, &user_id)
+            .map_err(map_error)?
+            .ok_or_else(|| map_error(IggyError::ResourceNotFound(user_id.to_string())))?;
+        Ok(Response::new(models::map_user(user).into()))
+    }
+
+    async fn get_users(
+        &self,
+        request: Request<proto::GetUsersRequest>,
+    ) -> Result<proto::GetUsersResponse> {
+        let session = self.session(&request).await?;
+        let system = self.system.read().await;
+        let users = system.get_users(&session).await.map_err(map_error)?;
+        Ok(Response::new(proto::GetUsersResponse {
+            users: models::map_users(&users)
+                .into_iter()
+                .map(proto::UserInfo::from)
+                .collect(),
+        }))
+    }
+
+    async fn create_user(
+        &self,
+        request: Request<proto::CreateUserRequest>,
+    ) -> Result<proto::UserInfoDetails> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = CreateUser {
+            username: request.username,
+            password: request.password,
+            status: mapper::user_status(request.status).map_err(map_error)?,
+            permissions: request.permissions.map(mapper::permissions),
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        let user = system
+            .create_user(
+                &session,
+                &command.username,
+                &command.password,
+                command.status,
+                command.permissions.clone(),
+            )
+            .await
+            .map_err(map_error)?;
+        let user_id = user.id;
+        let response = models::map_user(user).into();
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreateUser(CreateUserWithId {
+                    user_id,
+                    command: CreateUser {
+                        password: crypto::hash_password(&command.password),
+                        ..command
+                    },
+                }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(response))
+    }
+
+    async fn update_user(
+        &self,
+        request: Request<proto::UpdateUserRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = UpdateUser {
+            user_id: mapper::identifier(request.user_id).map_err(map_error)?,
+            username: request.username,
+            status: request
+                .status
+                .map(mapper::user_status)
+                .transpose()
+                .map_err(map_error)?,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .update_user(
+                &session,
+                &command.user_id,
+                command.username.clone(),
+                command.status,
+            )
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(session.get_user_id(), EntryCommand::UpdateUser(command))
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn delete_user(
+        &self,
+        request: Request<proto::DeleteUserRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let user_id = mapper::identifier(request.into_inner().user_id).map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .delete_user(&session, &user_id)
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::DeleteUser(DeleteUser { user_id }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn update_permissions(
+        &self,
+        request: Request<proto::UpdatePermissionsRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = UpdatePermissions {
+            user_id: mapper::identifier(request.user_id).map_err(map_error)?,
+            permissions: request.permissions.map(mapper::permissions),
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .update_permissions(&session, &command.user_id, command.permissions.clone())
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::UpdatePermissions(command),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn change_password(
+        &self,
+        request: Request<proto::ChangePasswordRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = ChangePassword {
+            user_id: mapper::identifier(request.user_id).map_err(map_error)?,
+            current_password: request.current_password,
+            new_password: request.new_password,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .change_password(
+                &session,
+                &command.user_id,
+                &command.current_password,
+                &command.new_password,
+            )
+            .await
+            .map_err(map_error)?;
+        // For the security of the system, we hash the password before storing it in metadata.
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::ChangePassword(ChangePassword {
+                    user_id: command.user_id,
+                    current_password: "".into(),
+                    new_password: crypto::hash_password(&command.new_password),
+                }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn create_personal_access_token(
+        &self,
+        request: Request<proto::CreatePersonalAccessTokenRequest>,
+    ) -> Result<proto::RawPersonalAccessToken> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = CreatePersonalAccessToken {
+            name: request.name,
+            expiry: request.expiry.into(),
+        };
+        command.validate().map_err(map_error)?;
+        let system = self.system.read().await;
+        let token = system
+            .create_personal_access_token(&session, &command.name, command.expiry)
+            .await
+            .map_err(map_error)?;
+        let hash = PersonalAccessToken::hash_token(&token);
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash {
+                    command,
+                    hash,
+                }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::RawPersonalAccessToken { token }))
+    }
+
+    async fn get_personal_access_tokens(
+        &self,
+        request: Request<proto::GetPersonalAccessTokensRequest>,
+    ) -> Result<proto::GetPersonalAccessTokensResponse> {
+        let session = self.session(&request).await?;
+        let system = self.system.read().await;
+        let tokens = system
+            .get_personal_access_tokens(&session)
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::GetPersonalAccessTokensResponse {
+            tokens: models::map_personal_access_tokens(&tokens)
+                .into_iter()
+                .map(proto::PersonalAccessTokenInfo::from)
+                .collect(),
+        }))
+    }
+
+    async fn delete_personal_access_token(
+        &self,
+        request: Request<proto::DeletePersonalAccessTokenRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let name = request.into_inner().name;
+        let mut system = self.system.write().await;
+        system
+            .delete_personal_access_token(&session, &name)
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken { name }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn get_stream(
+        &self,
+        request: Request<proto::GetStreamRequest>,
+    ) -> Result<proto::StreamDetails> {
+        let session = self.session(&request).await?;
+        let stream_id = mapper::identifier(request.into_inner().stream_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let stream = system
+            .try_find_stream(&session, &stream_id)
+            .map_err(map_error)?
+            .ok_or_else(|| map_error(IggyError::ResourceNotFound(stream_id.to_string())))?;
+        Ok(Response::new(models::map_stream(stream).into()))
+    }
+
+    async fn get_streams(
+        &self,
+        request: Request<proto::GetStreamsRequest>,
+    ) -> Result<proto::GetStreamsResponse> {
+        let session = self.session(&request).await?;
+        let system = self.system.read().await;
+        let streams = system.find_streams(&session).map_err(map_error)?;
+        Ok(Response::new(proto::GetStreamsResponse {
+            streams: models::map_streams(&streams)
+                .into_iter()
+                .map(proto::Stream::from)
+                .collect(),
+        }))
+    }
+
+    async fn create_stream(
+        &self,
+        request: Request<proto::CreateStreamRequest>,
+    ) -> Result<proto::StreamDetails> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = CreateStream {
+            stream_id: request.stream_id,
+            name: request.name,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        let stream = system
+            .create_stream(&session, command.stream_id, &command.name)
+            .await
+            .map_err(map_error)?;
+        let stream_id = stream.stream_id;
+        let response = models::map_stream(stream).into();
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreateStream(CreateStreamWithId { stream_id, command }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(response))
+    }
+
+    async fn update_stream(
+        &self,
+        request: Request<proto::UpdateStreamRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = UpdateStream {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            name: request.name,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .update_stream(&session, &command.stream_id, &command.name)
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(session.get_user_id(), EntryCommand::UpdateStream(command))
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn delete_stream(
+        &self,
+        request: Request<proto::DeleteStreamRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let stream_id = mapper::identifier(request.into_inner().stream_id).map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .delete_stream(&session, &stream_id)
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::DeleteStream(DeleteStream { stream_id }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn purge_stream(
+        &self,
+        request: Request<proto::PurgeStreamRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let stream_id = mapper::identifier(request.into_inner().stream_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        system
+            .purge_stream(&session, &stream_id)
+            .await
+            .map_err(map_error)?;
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::PurgeStream(PurgeStream { stream_id }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn get_topic(
+        &self,
+        request: Request<proto::GetTopicRequest>,
+    ) -> Result<proto::TopicDetails> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let topic = system
+            .try_find_topic(&session, &stream_id, &topic_id)
+            .map_err(map_error)?
+            .ok_or_else(|| map_error(IggyError::ResourceNotFound(topic_id.to_string())))?;
+        Ok(Response::new(models::map_topic(topic).await.into()))
+    }
+
+    async fn get_topics(
+        &self,
+        request: Request<proto::GetTopicsRequest>,
+    ) -> Result<proto::GetTopicsResponse> {
+        let session = self.session(&request).await?;
+        let stream_id = mapper::identifier(request.into_inner().stream_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let topics = system
+            .find_topics(&session, &stream_id)
+            .map_err(map_error)?;
+        Ok(Response::new(proto::GetTopicsResponse {
+            topics: models::map_topics(&topics)
+                .into_iter()
+                .map(proto::Topic::from)
+                .collect(),
+        }))
+    }
+
+    async fn create_topic(
+        &self,
+        request: Request<proto::CreateTopicRequest>,
+    ) -> Result<proto::TopicDetails> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let mut command = CreateTopic {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: request.topic_id,
+            partitions_count: request.partitions_count,
+            compression_algorithm: mapper::compression_algorithm(request.compression_algorithm)
+                .map_err(map_error)?,
+            message_expiry: request.message_expiry.into(),
+            max_topic_size: request.max_topic_size.into(),
+            replication_factor: replication_factor(request.replication_factor)
+                .map_err(map_error)?,
+            name: request.name,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        let topic = system
+            .create_topic(
+                &session,
+                &command.stream_id,
+                command.topic_id,
+                &command.name,
+                command.partitions_count,
+                command.message_expiry,
+                command.compression_algorithm,
+                command.max_topic_size,
+                command.replication_factor,
+            )
+            .await
+            .map_err(map_error)?;
+        command.message_expiry = topic.message_expiry;
+        command.max_topic_size = topic.max_topic_size;
+        let topic_id = topic.topic_id;
+        let response = models::map_topic(topic).await.into();
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(response))
+    }
+
+    async fn update_topic(
+        &self,
+        request: Request<proto::UpdateTopicRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let mut command = UpdateTopic {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: mapper::identifier(request.topic_id).map_err(map_error)?,
+            compression_algorithm: mapper::compression_algorithm(request.compression_algorithm)
+                .map_err(map_error)?,
+            message_expiry: request.message_expiry.into(),
+            max_topic_size: request.max_topic_size.into(),
+            replication_factor: replication_factor(request.replication_factor)
+                .map_err(map_error)?,
+            name: request.name,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        let topic = system
+            .update_topic(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                &command.name,
+                command.message_expiry,
+                command.compression_algorithm,
+                command.max_topic_size,
+                command.replication_factor,
+            )
+            .await
+            .map_err(map_error)?;
+        command.message_expiry = topic.message_expiry;
+        command.max_topic_size = topic.max_topic_size;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(session.get_user_id(), EntryCommand::UpdateTopic(command))
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn delete_topic(
+        &self,
+        request: Request<proto::DeleteTopicRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .delete_topic(&session, &stream_id, &topic_id)
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::DeleteTopic(DeleteTopic {
+                    stream_id,
+                    topic_id,
+                }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn purge_topic(
+        &self,
+        request: Request<proto::PurgeTopicRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        system
+            .purge_topic(&session, &stream_id, &topic_id)
+            .await
+            .map_err(map_error)?;
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::PurgeTopic(PurgeTopic {
+                    stream_id,
+                    topic_id,
+                }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn create_partitions(
+        &self,
+        request: Request<proto::CreatePartitionsRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = CreatePartitions {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: mapper::identifier(request.topic_id).map_err(map_error)?,
+            partitions_count: request.partitions_count,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .create_partitions(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                command.partitions_count,
+            )
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreatePartitions(command),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn delete_partitions(
+        &self,
+        request: Request<proto::DeletePartitionsRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = DeletePartitions {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: mapper::identifier(request.topic_id).map_err(map_error)?,
+            partitions_count: request.partitions_count,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .delete_partitions(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                command.partitions_count,
+            )
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::DeletePartitions(command),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn send_messages(
+        &self,
+        request: Request<proto::SendMessagesRequest>,
+    ) -> Result<proto::SendMessagesResponse> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let mut command = SendMessages {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: mapper::identifier(request.topic_id).map_err(map_error)?,
+            partitioning: mapper::partitioning(request.partitioning).map_err(map_error)?,
+            messages: request
+                .messages
+                .into_iter()
+                .map(mapper::message)
+                .collect::<std::result::Result<_, _>>()
+                .map_err(map_error)?,
+            report_offsets: true,
+        };
+        command.messages.iter_mut().for_each(|message| {
+            if message.id == 0 {
+                message.id = random_id::get_uuid();
+            }
+        });
+        command.validate().map_err(map_error)?;
+        let system = self.system.read().await;
+        let appended_messages = system
+            .append_messages(
+                &session,
+                command.stream_id,
+                command.topic_id,
+                command.partitioning,
+                command.messages,
+                None,
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::SendMessagesResponse {
+            appended_messages: appended_messages.map(proto::AppendedMessages::from),
+        }))
+    }
+
+    type PollMessagesStream = MessagesStream;
+
+    async fn poll_messages(
+        &self,
+        request: Request<proto::PollMessagesRequest>,
+    ) -> Result<Self::PollMessagesStream> {
+        let session = self.session(&request).await?;
+        let command = poll_messages_command(request.into_inner()).map_err(map_error)?;
+        let args = PollingArgs::new(command.strategy, command.count, command.auto_commit);
+        let polled_messages = if command.max_wait.as_micros() > 0 {
+            self.system
+                .poll_messages_with_wait(
+                    &session,
+                    &command.consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partition_id,
+                    args,
+                    command.max_wait,
+                )
+                .await
+        } else {
+            self.system
+                .read()
+                .await
+                .poll_messages(
+                    &session,
+                    &command.consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partition_id,
+                    args,
+                )
+                .await
+        }
+        .map_err(map_error)?;
+        let chunks = into_chunks(polled_messages, self.max_message_size)
+            .into_iter()
+            .map(Ok);
+        Ok(Response::new(Box::pin(futures::stream::iter(chunks))))
+    }
+
+    type SubscribeMessagesStream = MessagesStream;
+
+    async fn subscribe_messages(
+        &self,
+        request: Request<proto::PollMessagesRequest>,
+    ) -> Result<Self::SubscribeMessagesStream> {
+        let session = self.session(&request).await?;
+        let command = poll_messages_command(request.into_inner()).map_err(map_error)?;
+        let subscription =
+            MessagesSubscription::new(command, session, self.system.clone(), self.shutdown.clone())
+                .await
+                .map_err(map_error)?;
+        // The first batch is polled right away, so that the invalid request is rejected with the error status.
+        let messages = subscription
+            .poll_messages(IggyDuration::default())
+            .await
+            .map_err(map_error)?;
+        let max_message_size = self.max_message_size;
+        let batches = futures::stream::unfold(
+            (subscription, Some(messages)),
+            move |(mut subscription, messages)| async move {
+                let chunks = match subscription.next_messages(messages).await? {
+                    Ok(messages) => into_chunks(messages, max_message_size)
+                        .into_iter()
+                        .map(Ok)
+                        .collect(),
+                    Err(error) => vec![Err(map_error(error))],
+                };
+                Some((futures::stream::iter(chunks), (subscription, None)))
+            },
+        );
+        Ok(Response::new(Box::pin(futures::StreamExt::flatten(
+            batches,
+        ))))
+    }
+
+    async fn store_consumer_offset(
+        &self,
+        request: Request<proto::StoreConsumerOffsetRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = StoreConsumerOffset {
+            consumer: mapper::consumer(request.consumer_id).map_err(map_error)?,
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: mapper::identifier(request.topic_id).map_err(map_error)?,
+            partition_id: request.partition_id,
+            offset: request.offset,
+        };
+        command.validate().map_err(map_error)?;
+        let system = self.system.read().await;
+        system
+            .store_consumer_offset(
+                &session,
+                command.consumer,
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                command.offset,
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn get_consumer_offset(
+        &self,
+        request: Request<proto::GetConsumerOffsetRequest>,
+    ) -> Result<proto::ConsumerOffsetInfo> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let consumer = mapper::consumer(request.consumer_id).map_err(map_error)?;
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let offset = system
+            .get_consumer_offset(
+                &session,
+                &consumer,
+                &stream_id,
+                &topic_id,
+                request.partition_id,
+            )
+            .await
+            .map_err(map_error)?
+            .ok_or_else(|| map_error(IggyError::ResourceNotFound(consumer.id.to_string())))?;
+        Ok(Response::new(offset.into()))
+    }
+
+    async fn delete_consumer_offset(
+        &self,
+        request: Request<proto::DeleteConsumerOffsetRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let consumer = mapper::consumer(request.consumer_id).map_err(map_error)?;
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        system
+            .delete_consumer_offset(
+                &session,
+                consumer,
+                &stream_id,
+                &topic_id,
+                request.partition_id,
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+
+    async fn get_consumer_group(
+        &self,
+        request: Request<proto::GetConsumerGroupRequest>,
+    ) -> Result<proto::ConsumerGroupDetails> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let group_id = mapper::identifier(request.group_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let consumer_group = system
+            .get_consumer_group(&session, &stream_id, &topic_id, &group_id)
+            .map_err(map_error)?
+            .ok_or_else(|| map_error(IggyError::ResourceNotFound(group_id.to_string())))?;
+        let consumer_group = consumer_group.read().await;
+        Ok(Response::new(
+            models::map_consumer_group(&consumer_group).await.into(),
+        ))
+    }
+
+    async fn get_consumer_groups(
+        &self,
+        request: Request<proto::GetConsumerGroupsRequest>,
+    ) -> Result<proto::GetConsumerGroupsResponse> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let system = self.system.read().await;
+        let consumer_groups = system
+            .get_consumer_groups(&session, &stream_id, &topic_id)
+            .map_err(map_error)?;
+        Ok(Response::new(proto::GetConsumerGroupsResponse {
+            groups: models::map_consumer_groups(&consumer_groups)
+                .await
+                .into_iter()
+                .map(proto::ConsumerGroup::from)
+                .collect(),
+        }))
+    }
+
+    async fn create_consumer_group(
+        &self,
+        request: Request<proto::CreateConsumerGroupRequest>,
+    ) -> Result<proto::ConsumerGroupDetails> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let command = CreateConsumerGroup {
+            stream_id: mapper::identifier(request.stream_id).map_err(map_error)?,
+            topic_id: mapper::identifier(request.topic_id).map_err(map_error)?,
+            group_id: request.group_id,
+            name: request.name,
+        };
+        command.validate().map_err(map_error)?;
+        let mut system = self.system.write().await;
+        let consumer_group = system
+            .create_consumer_group(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                command.group_id,
+                &command.name,
+            )
+            .await
+            .map_err(map_error)?;
+        let consumer_group = consumer_group.read().await;
+        let group_id = consumer_group.group_id;
+        let response = models::map_consumer_group(&consumer_group).await.into();
+        drop(consumer_group);
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreateConsumerGroup(CreateConsumerGroupWithId { group_id, command }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(response))
+    }
+
+    async fn delete_consumer_group(
+        &self,
+        request: Request<proto::DeleteConsumerGroupRequest>,
+    ) -> Result<proto::Empty> {
+        let session = self.session(&request).await?;
+        let request = request.into_inner();
+        let stream_id = mapper::identifier(request.stream_id).map_err(map_error)?;
+        let topic_id = mapper::identifier(request.topic_id).map_err(map_error)?;
+        let group_id = mapper::identifier(request.group_id).map_err(map_error)?;
+        let mut system = self.system.write().await;
+        system
+            .delete_consumer_group(&session, &stream_id, &topic_id, &group_id)
+            .await
+            .map_err(map_error)?;
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::DeleteConsumerGroup(DeleteConsumerGroup {
+                    stream_id,
+                    topic_id,
+                    group_id,
+                }),
+            )
+            .await
+            .map_err(map_error)?;
+        Ok(Response::new(proto::Empty {}))
+    }
+}
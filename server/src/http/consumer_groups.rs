@@ -50,6 +50,19 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("group_id" = String, Path, description = "Unique consumer group ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The consumer group details.", body = ConsumerGroupDetails)
+    )
+)]
 async fn get_consumer_group(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -76,6 +89,18 @@ async fn get_consumer_group(
     Ok(Json(consumer_group))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The consumer groups of the topic.", body = Vec<ConsumerGroup>)
+    )
+)]
 async fn get_consumer_groups(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -93,6 +118,19 @@ async fn get_consumer_groups(
     Ok(Json(consumer_groups))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = CreateConsumerGroup,
+    responses(
+        (status = 201, description = "The created consumer group.", body = ConsumerGroupDetails)
+    )
+)]
 #[instrument(skip_all, name = "trace_create_consumer_group", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_consumer_group(
     State(state): State<Arc<AppState>>,
@@ -131,6 +169,19 @@ async fn create_consumer_group(
     Ok((StatusCode::CREATED, Json(consumer_group_details)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("group_id" = String, Path, description = "Unique consumer group ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The consumer group has been deleted.")
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_consumer_group", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_group_id = group_id))]
 async fn delete_consumer_group(
     State(state): State<Arc<AppState>>,
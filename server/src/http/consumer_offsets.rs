@@ -48,6 +48,20 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    tag = "consumer_offsets",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("id" = Option<String>, Query, description = "Unique consumer ID (numeric or name), 0 by default."),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID, 1 by default.")
+    ),
+    responses(
+        (status = 200, description = "The consumer offset.", body = ConsumerOffsetInfo)
+    )
+)]
 async fn get_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -79,6 +93,19 @@ async fn get_consumer_offset(
     Ok(Json(offset))
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    tag = "consumer_offsets",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = StoreConsumerOffset,
+    responses(
+        (status = 204, description = "The consumer offset has been stored.")
+    )
+)]
 async fn store_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -104,6 +131,20 @@ async fn store_consumer_offset(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/{consumer_id}",
+    tag = "consumer_offsets",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("consumer_id" = String, Path, description = "Unique consumer ID (numeric or name)."),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID, 1 by default.")
+    ),
+    responses(
+        (status = 204, description = "The consumer offset has been deleted.")
+    )
+)]
 async fn delete_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
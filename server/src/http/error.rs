@@ -23,6 +23,7 @@ use iggy::error::IggyError;
 use serde::Serialize;
 use thiserror::Error;
 use tracing::error;
+use utoipa::ToSchema;
 
 #[derive(Debug, Error)]
 pub enum CustomError {
@@ -32,11 +33,15 @@ pub enum CustomError {
     ResourceNotFound,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
+    /// The numeric code of the error.
     pub id: u32,
+    /// The name of the error.
     pub code: String,
+    /// The human-readable description of the error.
     pub reason: String,
+    /// The name of the invalid field, if any.
     pub field: Option<String>,
 }
 
@@ -56,6 +61,8 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerOffsetNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TopicSchemaNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::AccessTokenMissing => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidAccessToken => StatusCode::UNAUTHORIZED,
@@ -107,6 +114,12 @@ impl ErrorResponse {
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidSchemaFormat => Some("format".to_string()),
+                IggyError::InvalidSchemaDefinition(_) => Some("definition".to_string()),
+                IggyError::IncompatibleSchema(_) => Some("definition".to_string()),
+                IggyError::InvalidSchemaCompatibility => Some("compatibility".to_string()),
+                IggyError::InvalidSchemaValidation => Some("validation".to_string()),
+                IggyError::MessagePayloadSchemaViolation(_, _, _) => Some("messages".to_string()),
                 _ => None,
             },
         }
@@ -16,7 +16,8 @@
  * under the License.
  */
 
-use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::configs::http::{HttpConfig, HttpCorsConfig, HttpJwtConfig};
+use crate::configs::reload::ConfigWatcher;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
@@ -24,9 +25,10 @@ use crate::http::jwt::middleware::jwt_auth;
 use crate::http::metrics::metrics;
 use crate::http::shared::AppState;
 use crate::http::*;
+use crate::shutdown::Shutdown;
 use crate::streaming::systems::system::SharedSystem;
 use axum::extract::DefaultBodyLimit;
-use axum::http::Method;
+use axum::http::{HeaderValue, Method};
 use axum::{middleware, Router};
 use axum_server::tls_rustls::RustlsConfig;
 use std::net::SocketAddr;
@@ -37,14 +39,24 @@ use tracing::{error, info};
 
 /// Starts the HTTP API server.
 /// Returns the address the server is listening on.
-pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    config: HttpConfig,
+    system: SharedSystem,
+    jwt_manager: Arc<JwtManager>,
+    config_watcher: ConfigWatcher,
+    shutdown: Shutdown,
+) -> SocketAddr {
     let api_name = if config.tls.enabled {
         "HTTP API (TLS)"
     } else {
         "HTTP API"
     };
 
-    let app_state = build_app_state(&config, system).await;
+    let app_state = Arc::new(AppState {
+        jwt_manager,
+        system,
+        shutdown: shutdown.clone(),
+    });
     let mut app = Router::new()
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
@@ -54,14 +66,16 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
+        .merge(openapi::router(&config.metrics))
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
     if config.cors.enabled {
-        app = app.layer(configure_cors(config.cors));
+        app = app.layer(configure_cors(config.cors, config_watcher.clone()));
     }
 
     if config.metrics.enabled {
@@ -79,11 +93,16 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
             .local_addr()
             .expect("Failed to get local address for HTTP server");
         info!("Started {api_name} on: {address}");
-        tokio::task::spawn(async move {
+        let server_shutdown = shutdown.clone();
+        shutdown.spawn(async move {
             if let Err(error) = axum::serve(
                 listener,
                 app.into_make_service_with_connect_info::<SocketAddr>(),
             )
+            .with_graceful_shutdown(async move {
+                server_shutdown.triggered().await;
+                info!("{api_name} has stopped accepting new connections.");
+            })
             .await
             {
                 error!("Failed to start {api_name} server, error {}", error);
@@ -93,11 +112,12 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         address
     } else {
         let tls_config = RustlsConfig::from_pem_file(
-            PathBuf::from(config.tls.cert_file),
-            PathBuf::from(config.tls.key_file),
+            PathBuf::from(&config.tls.cert_file),
+            PathBuf::from(&config.tls.key_file),
         )
         .await
         .unwrap();
+        start_certificate_reloader(tls_config.clone(), config_watcher);
 
         let listener = std::net::TcpListener::bind(config.address).unwrap();
         let address = listener
@@ -106,8 +126,18 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
         info!("Started {api_name} on: {address}");
 
-        tokio::task::spawn(async move {
+        let handle = axum_server::Handle::new();
+        let server_shutdown = shutdown.clone();
+        let shutdown_handle = handle.clone();
+        shutdown.spawn(async move {
+            server_shutdown.triggered().await;
+            info!("{api_name} has stopped accepting new connections.");
+            shutdown_handle.graceful_shutdown(None);
+        });
+
+        shutdown.spawn(async move {
             if let Err(error) = axum_server::from_tcp_rustls(listener, tls_config)
+                .handle(handle)
                 .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
             {
@@ -119,7 +149,30 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
     }
 }
 
-async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppState> {
+fn start_certificate_reloader(tls_config: RustlsConfig, mut config_watcher: ConfigWatcher) {
+    tokio::spawn(async move {
+        while config_watcher.changed().await.is_ok() {
+            // The files are read again even if their paths are the same, as they could have been renewed in place.
+            let reloaded = config_watcher.borrow_and_update().http.tls.clone();
+            match tls_config
+                .reload_from_pem_file(
+                    PathBuf::from(&reloaded.cert_file),
+                    PathBuf::from(&reloaded.key_file),
+                )
+                .await
+            {
+                Ok(()) => info!("Reloaded HTTP TLS certificate: {}", reloaded.cert_file),
+                Err(error) => error!(
+                    "Unable to reload HTTP TLS certificate: {}. {error}",
+                    reloaded.cert_file
+                ),
+            }
+        }
+    });
+}
+
+/// Creates the JWT manager issuing and validating the access tokens, shared by the HTTP API and the gRPC gateway.
+pub async fn build_jwt_manager(config: &HttpJwtConfig, system: &SharedSystem) -> Arc<JwtManager> {
     let tokens_path;
     let persister;
     {
@@ -128,7 +181,7 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
         persister = system.storage.persister.clone();
     }
 
-    let jwt_manager = JwtManager::from_config(persister, &tokens_path, &config.jwt);
+    let jwt_manager = JwtManager::from_config(persister, &tokens_path, config);
     if let Err(error) = jwt_manager {
         panic!("Failed to initialize JWT manager: {}", error);
     }
@@ -138,18 +191,21 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
         panic!("Failed to load revoked access tokens");
     }
 
-    Arc::new(AppState {
-        jwt_manager,
-        system,
-    })
+    Arc::new(jwt_manager)
 }
 
-fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
-    let allowed_origins = match config.allowed_origins {
-        origins if origins.is_empty() => AllowOrigin::default(),
-        origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
-        origins => AllowOrigin::list(origins.iter().map(|s| s.parse().unwrap())),
-    };
+fn configure_cors(config: HttpCorsConfig, config_watcher: ConfigWatcher) -> CorsLayer {
+    // The origins are checked against the latest configuration, so they can be changed by reloading it.
+    let allowed_origins = AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+        let config = config_watcher.borrow();
+        let allowed_origins = &config.http.cors.allowed_origins;
+        allowed_origins
+            .first()
+            .is_some_and(|allowed| allowed == "*")
+            || allowed_origins
+                .iter()
+                .any(|allowed| allowed.as_bytes() == origin.as_bytes())
+    });
 
     let allowed_headers = config
         .allowed_headers
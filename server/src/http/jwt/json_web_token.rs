@@ -17,6 +17,7 @@
  */
 
 use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 
@@ -37,6 +38,15 @@ pub struct JwtClaims {
     pub iat: u64,
     pub exp: u64,
     pub nbf: u64,
+    /// The issue time with the microseconds precision, used to check if the user sessions have been revoked.
+    #[serde(default)]
+    pub iat_micros: u64,
+}
+
+impl JwtClaims {
+    pub fn issued_at(&self) -> IggyTimestamp {
+        IggyTimestamp::from(self.iat_micros)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
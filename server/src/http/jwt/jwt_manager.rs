@@ -171,8 +171,8 @@ impl JwtManager {
 
     pub fn generate(&self, user_id: UserId) -> Result<GeneratedToken, IggyError> {
         let header = Header::new(self.issuer.algorithm);
-        let now = IggyTimestamp::now().to_secs();
-        let iat = now;
+        let issued_at = IggyTimestamp::now();
+        let iat = issued_at.to_secs();
         let exp = iat
             + (match self.issuer.access_token_expiry {
                 IggyExpiry::NeverExpire => 1_000_000_000,
@@ -188,6 +188,7 @@ impl JwtManager {
             iat,
             exp,
             nbf,
+            iat_micros: issued_at.as_micros(),
         };
 
         let access_token = encode::<JwtClaims>(&header, &claims, &self.issuer.key);
@@ -203,17 +204,20 @@ impl JwtManager {
         })
     }
 
-    // The access token can be refreshed only once and if it is not expired
-    pub async fn refresh_token(&self, token: &str) -> Result<GeneratedToken, IggyError> {
+    pub fn decode_token(&self, token: &str) -> Result<JwtClaims, IggyError> {
         if token.is_empty() {
             return Err(IggyError::InvalidAccessToken);
         }
 
         let token_header =
             jsonwebtoken::decode_header(token).map_err(|_| IggyError::InvalidAccessToken)?;
-        let jwt_claims = self.decode(token, token_header.alg)?;
-        let id = jwt_claims.claims.jti;
-        let expiry = jwt_claims.claims.exp;
+        Ok(self.decode(token, token_header.alg)?.claims)
+    }
+
+    // The access token can be refreshed only once and if it is not expired
+    pub async fn refresh_token(&self, jwt_claims: JwtClaims) -> Result<GeneratedToken, IggyError> {
+        let id = jwt_claims.jti;
+        let expiry = jwt_claims.exp;
         if self
             .revoked_tokens
             .write()
@@ -233,7 +237,7 @@ impl JwtManager {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to save revoked access token: {id}")
             })?;
-        self.generate(jwt_claims.claims.sub)
+        self.generate(jwt_claims.sub)
     }
 
     pub fn decode(
@@ -17,6 +17,7 @@
  */
 
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::openapi::{OPENAPI_PATH, OPENAPI_UI_ASSETS_PATH, OPENAPI_UI_PATH};
 use crate::http::shared::{AppState, RequestDetails};
 use axum::body::Body;
 use axum::{
@@ -31,9 +32,11 @@ use std::sync::Arc;
 const COMPONENT: &str = "JWT_MIDDLEWARE";
 const AUTHORIZATION: &str = "authorization";
 const BEARER: &str = "Bearer ";
+const ACCESS_TOKEN_QUERY_PARAM: &str = "access_token";
+const SUBSCRIBE_PATH_SUFFIX: &str = "/messages/subscribe";
 const UNAUTHORIZED: StatusCode = StatusCode::UNAUTHORIZED;
 
-const PUBLIC_PATHS: &[&str] = &[
+pub(crate) const PUBLIC_PATHS: &[&str] = &[
     "/",
     "/metrics",
     "/ping",
@@ -41,6 +44,8 @@ const PUBLIC_PATHS: &[&str] = &[
     "/users/login",
     "/users/refresh-token",
     "/personal-access-tokens/login",
+    OPENAPI_PATH,
+    OPENAPI_UI_PATH,
 ];
 
 pub async fn jwt_auth(
@@ -48,28 +53,41 @@ pub async fn jwt_auth(
     mut request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if PUBLIC_PATHS.contains(&request.uri().path()) {
+    let path = request.uri().path();
+    if PUBLIC_PATHS.contains(&path) || path.starts_with(OPENAPI_UI_ASSETS_PATH) {
         return Ok(next.run(request).await);
     }
 
-    let bearer = request
-        .headers()
-        .get(AUTHORIZATION)
+    let jwt_token = match request.headers().get(AUTHORIZATION) {
+        Some(header) => {
+            let bearer = header
+                .to_str()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - invalid authorization header format")
+                })
+                .map_err(|_| UNAUTHORIZED)?;
+            if !bearer.starts_with(BEARER) {
+                return Err(StatusCode::UNAUTHORIZED);
+            }
+            bearer[BEARER.len()..].to_owned()
+        }
+        // The browser EventSource can't set the headers, so the subscriptions may pass the token in the query.
+        None if path.ends_with(SUBSCRIBE_PATH_SUFFIX) => get_access_token_from_query(
+            request.uri().query(),
+        )
         .ok_or(UNAUTHORIZED)
         .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - missing or inaccessible Authorization header")
-        })?
-        .to_str()
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - invalid authorization header format")
-        })
-        .map_err(|_| UNAUTHORIZED)?;
-
-    if !bearer.starts_with(BEARER) {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
-
-    let jwt_token = &bearer[BEARER.len()..];
+            format!("{COMPONENT} (error: {error}) - missing Authorization header and access token")
+        })?,
+        None => {
+            return Err(UNAUTHORIZED).with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - missing or inaccessible Authorization header"
+                )
+            });
+        }
+    };
+    let jwt_token = jwt_token.as_str();
     let token_header = jsonwebtoken::decode_header(jwt_token)
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to decode JWT header")
@@ -90,6 +108,16 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
+    if state
+        .system
+        .read()
+        .await
+        .is_user_session_revoked(jwt_claims.claims.sub, jwt_claims.claims.issued_at())
+        .await
+    {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
     let request_details = request.extensions().get::<RequestDetails>().unwrap();
     let identity = Identity {
         token_id: jwt_claims.claims.jti,
@@ -100,3 +128,12 @@ pub async fn jwt_auth(
     request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
 }
+
+fn get_access_token_from_query(query: Option<&str>) -> Option<String> {
+    query?
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| *name == ACCESS_TOKEN_QUERY_PARAM)
+        .map(|(_, token)| token.to_owned())
+        .filter(|token| !token.is_empty())
+}
@@ -20,22 +20,38 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
+use crate::shutdown::Shutdown;
+use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
 use crate::streaming::utils::random_id;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
-use iggy::consumer::Consumer;
+use futures::Stream;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollMessages;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::poll_messages::{PollMessages, PollingKind, PollingStrategy};
 use iggy::messages::send_messages::SendMessages;
+use iggy::models::appended_messages::AppendedMessages;
 use iggy::models::messages::PolledMessages;
+use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
+use serde::Deserialize;
+use std::convert::Infallible;
 use std::sync::Arc;
-use tracing::instrument;
+use tracing::{debug, instrument};
+
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+const MESSAGES_EVENT: &str = "messages";
+const ERROR_EVENT: &str = "error";
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -43,6 +59,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/messages",
             get(poll_messages).post(send_messages),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/subscribe",
+            get(subscribe_messages),
+        )
         .route(
             "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
             get(flush_unsaved_buffer),
@@ -50,6 +70,25 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("id" = Option<String>, Query, description = "Unique consumer ID (numeric or name), 0 by default."),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID, 1 by default."),
+        ("kind" = Option<PollingKind>, Query, description = "Polling strategy kind, offset by default."),
+        ("value" = Option<u64>, Query, description = "Polling strategy value, the offset or the timestamp, 0 by default."),
+        ("count" = Option<u32>, Query, description = "Number of messages to poll, 10 by default."),
+        ("auto_commit" = Option<bool>, Query, description = "Whether to store the consumer offset of the polled messages."),
+        ("max_wait" = Option<String>, Query, description = "How long to wait for the messages to be appended when there are none, e.g. `5s`, capped by the server.")
+    ),
+    responses(
+        (status = 200, description = "The polled messages.", body = PolledMessages)
+    )
+)]
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -61,17 +100,37 @@ async fn poll_messages(
     query.validate()?;
 
     let consumer = Consumer::new(query.0.consumer.id);
-    let system = state.system.read().await;
-    let polled_messages = system
-        .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &consumer,
-            &query.0.stream_id,
-            &query.0.topic_id,
-            query.0.partition_id,
-            PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit),
-        )
-        .await
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let args = PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit);
+    let polled_messages = if query.0.max_wait.as_micros() > 0 {
+        state
+            .system
+            .poll_messages_with_wait(
+                &session,
+                &consumer,
+                &query.0.stream_id,
+                &query.0.topic_id,
+                query.0.partition_id,
+                args,
+                query.0.max_wait,
+            )
+            .await
+    } else {
+        state
+            .system
+            .read()
+            .await
+            .poll_messages(
+                &session,
+                &consumer,
+                &query.0.stream_id,
+                &query.0.topic_id,
+                query.0.partition_id,
+                args,
+            )
+            .await
+    };
+    let polled_messages = polled_messages
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to poll messages, stream ID: {}, topic ID: {}, partition ID: {:?}",
@@ -81,12 +140,279 @@ async fn poll_messages(
     Ok(Json(polled_messages))
 }
 
+/// Streams the messages as the Server-Sent Events, each `messages` event holding the same JSON as the polling response,
+/// with the partition ID and the offset of the last message as the event ID, e.g. `1:42`. The first batch is polled right away,
+/// so that the invalid request is rejected with the error response, and the next ones are polled once appended, waiting up to
+/// the `system.polling.max_wait`. The consumer polls its partition from the offset following the last sent message,
+/// or the `Last-Event-ID` of the same partition sent by the reconnecting client. The consumer group subscription joins
+/// the group for as long as it lasts, and requires the `next` strategy with the auto commit, so that it's resumed
+/// from the offsets stored for each partition. The polling failure is sent as the `error` event, ending the stream.
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/subscribe",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("id" = Option<String>, Query, description = "Unique consumer ID (numeric or name), 0 by default."),
+        ("consumer_kind" = Option<String>, Query, description = "Consumer kind, `consumer` (by default) or `consumer_group`."),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID, 1 by default, ignored for the consumer group."),
+        ("kind" = Option<PollingKind>, Query, description = "Polling strategy kind, offset by default, `next` for the consumer group."),
+        ("value" = Option<u64>, Query, description = "Polling strategy value, the offset or the timestamp, 0 by default."),
+        ("count" = Option<u32>, Query, description = "Number of messages to poll, 10 by default."),
+        ("auto_commit" = Option<bool>, Query, description = "Whether to store the consumer offset of the polled messages, required for the consumer group."),
+        ("max_wait" = Option<String>, Query, description = "How long to wait for the messages to be appended when there are none, e.g. `5s`, capped by the server."),
+        ("Last-Event-ID" = Option<String>, Header, description = "Partition ID and offset of the last received message, e.g. `1:42`, to resume the consumer subscription after it."),
+        ("access_token" = Option<String>, Query, description = "Access token, when it can't be sent in the Authorization header.")
+    ),
+    responses(
+        (status = 200, description = "The `messages` events with the polled messages as JSON and the partition ID with the last offset as ID, or the `error` event ending the stream.", body = PolledMessages, content_type = "text/event-stream")
+    )
+)]
+async fn subscribe_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    mut query: Query<PollMessages>,
+    Query(subscription_query): Query<SubscriptionQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    let mut command = query.0;
+    command.consumer.kind = subscription_query.consumer_kind;
+    if command.consumer.kind == ConsumerKind::Consumer {
+        if let Some((partition_id, offset)) = last_event_id(&headers) {
+            if command.partition_id == Some(partition_id) {
+                command.strategy = PollingStrategy::offset(offset + 1);
+            }
+        }
+    }
+    let subscription = MessagesSubscription::new(
+        command,
+        Session::stateless(identity.user_id, identity.ip_address),
+        state.system.clone(),
+        state.shutdown.clone(),
+    )
+    .await
+    .with_error_context(|error| {
+        format!(
+            "{COMPONENT} (error: {error}) - failed to create messages subscription, stream ID: {}, topic ID: {}",
+            stream_id, topic_id
+        )
+    })?;
+    let messages = subscription
+        .poll_messages(IggyDuration::default())
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to subscribe to messages, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                stream_id, topic_id, subscription.command.partition_id
+            )
+        })?;
+    let events = futures::stream::unfold(
+        (subscription, Some(messages)),
+        |(mut subscription, messages)| async move {
+            let event = match subscription.next_messages(messages).await? {
+                Ok(messages) => messages_event(&messages).unwrap_or_else(|error| {
+                    subscription.completed = true;
+                    Event::default().event(ERROR_EVENT).data(error.to_string())
+                }),
+                Err(error) => Event::default().event(ERROR_EVENT).data(error.to_string()),
+            };
+            Some((Ok(event), (subscription, None)))
+        },
+    );
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// The query parameters of the subscription which aren't a part of `PollMessages`, where `kind` is the polling strategy.
+#[derive(Debug, Deserialize)]
+struct SubscriptionQuery {
+    #[serde(default)]
+    consumer_kind: ConsumerKind,
+}
+
+/// Returns the partition ID and the offset from the `Last-Event-ID` header, formatted as `partition_id:offset`.
+fn last_event_id(headers: &HeaderMap) -> Option<(u32, u64)> {
+    let (partition_id, offset) = headers
+        .get(LAST_EVENT_ID_HEADER)?
+        .to_str()
+        .ok()?
+        .split_once(':')?;
+    Some((partition_id.parse().ok()?, offset.parse().ok()?))
+}
+
+fn messages_event(messages: &PolledMessages) -> Result<Event, axum::Error> {
+    let last_offset = messages.messages.last().map_or(0, |message| message.offset);
+    Event::default()
+        .event(MESSAGES_EVENT)
+        .id(format!("{}:{last_offset}", messages.partition_id))
+        .json_data(messages)
+}
+
+/// Polls the messages continuously on behalf of the subscriber, shared by the Server-Sent Events and the gRPC gateway.
+pub(crate) struct MessagesSubscription {
+    command: PollMessages,
+    session: Session,
+    system: SharedSystem,
+    shutdown: Shutdown,
+    max_wait: IggyDuration,
+    completed: bool,
+    group_client_id: Option<u32>,
+}
+
+impl MessagesSubscription {
+    /// Creates the subscription waiting for the messages up to the `system.polling.max_wait`.
+    /// The consumer group subscription joins the group as a separate client, deleted once the subscription is dropped.
+    /// It requires the `next` strategy with the auto commit, as only the stored offsets span all the partitions of the group.
+    pub(crate) async fn new(
+        command: PollMessages,
+        session: Session,
+        system: SharedSystem,
+        shutdown: Shutdown,
+    ) -> Result<Self, IggyError> {
+        let max_wait = system.read().await.config.polling.max_wait;
+        let mut subscription = Self {
+            session,
+            system,
+            shutdown,
+            command,
+            max_wait,
+            completed: false,
+            group_client_id: None,
+        };
+        if subscription.command.consumer.kind == ConsumerKind::ConsumerGroup {
+            if subscription.command.strategy.kind != PollingKind::Next
+                || !subscription.command.auto_commit
+            {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            subscription.command.partition_id = None;
+            subscription.join_consumer_group().await?;
+        }
+        Ok(subscription)
+    }
+
+    async fn join_consumer_group(&mut self) -> Result<(), IggyError> {
+        let system = self.system.read().await;
+        let user_id = self.session.get_user_id();
+        let ip_address = self.session.ip_address;
+        let client_id = system
+            .add_client(&ip_address, Transport::Http)
+            .await
+            .client_id;
+        self.group_client_id = Some(client_id);
+        self.session = Session::new(client_id, user_id, ip_address);
+        system
+            .client_manager
+            .write()
+            .await
+            .set_user_id(client_id, user_id)
+            .await?;
+        system
+            .join_consumer_group(
+                &self.session,
+                &self.command.stream_id,
+                &self.command.topic_id,
+                &self.command.consumer.id,
+            )
+            .await
+    }
+
+    pub(crate) async fn poll_messages(
+        &self,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        let command = &self.command;
+        self.system
+            .poll_messages_with_wait(
+                &self.session,
+                &command.consumer,
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+                PollingArgs::new(command.strategy, command.count, command.auto_commit),
+                max_wait,
+            )
+            .await
+    }
+
+    /// Returns the next non-empty batch of messages, or the polling failure completing the subscription,
+    /// or `None` once the subscription has completed or the server is shutting down.
+    pub(crate) async fn next_messages(
+        &mut self,
+        mut messages: Option<PolledMessages>,
+    ) -> Option<Result<PolledMessages, IggyError>> {
+        if self.completed {
+            return None;
+        }
+
+        loop {
+            let messages = match messages.take() {
+                Some(messages) => messages,
+                None => {
+                    let polled_messages = tokio::select! {
+                        _ = self.shutdown.triggered() => return None,
+                        polled_messages = self.poll_messages(self.max_wait) => polled_messages,
+                    };
+                    match polled_messages {
+                        Ok(messages) => messages,
+                        Err(error) => {
+                            debug!("Failed to poll messages for the subscription of session: {}. {error}", self.session);
+                            self.completed = true;
+                            return Some(Err(error));
+                        }
+                    }
+                }
+            };
+
+            let Some(last_offset) = messages.messages.last().map(|message| message.offset) else {
+                continue;
+            };
+
+            // The consumer polls the single partition, so it continues after the last sent message.
+            if self.command.consumer.kind == ConsumerKind::Consumer {
+                self.command.strategy = PollingStrategy::offset(last_offset + 1);
+            }
+            return Some(Ok(messages));
+        }
+    }
+}
+
+impl Drop for MessagesSubscription {
+    fn drop(&mut self) {
+        if let Some(client_id) = self.group_client_id.take() {
+            let system = self.system.clone();
+            tokio::spawn(async move {
+                system.read().await.delete_client(client_id).await;
+            });
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = SendMessages,
+    responses(
+        (status = 201, description = "The messages have been appended, with their offsets if `report_offsets` is set.", body = AppendedMessages)
+    )
+)]
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     Json(mut command): Json<SendMessages>,
-) -> Result<StatusCode, CustomError> {
+) -> Result<Response, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.partitioning.length = command.partitioning.value.len() as u8;
@@ -103,7 +429,7 @@ async fn send_messages(
     let partitioning = command.partitioning;
     let system = state.system.read().await;
     // TODO(haze): Add confirmation level after testing is complete
-    system
+    let appended_messages = system
         .append_messages(
             &Session::stateless(identity.user_id, identity.ip_address),
             command_stream_id,
@@ -119,9 +445,27 @@ async fn send_messages(
                 stream_id, topic_id
             )
         })?;
-    Ok(StatusCode::CREATED)
+    if command.report_offsets {
+        return Ok((StatusCode::CREATED, Json(appended_messages)).into_response());
+    }
+
+    Ok(StatusCode::CREATED.into_response())
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("partition_id" = u32, Path, description = "Unique partition ID."),
+        ("fsync" = bool, Path, description = "Whether to fsync the saved messages to the disk.")
+    ),
+    responses(
+        (status = 200, description = "The unsaved messages have been flushed.")
+    )
+)]
 #[instrument(skip_all, name = "trace_flush_unsaved_buffer", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id, iggy_fsync = fsync))]
 async fn flush_unsaved_buffer(
     State(state): State<Arc<AppState>>,
@@ -22,11 +22,13 @@ pub mod diagnostics;
 pub mod error;
 pub mod http_server;
 pub mod jwt;
-mod mapper;
+pub(crate) mod mapper;
 pub mod messages;
 pub mod metrics;
+pub mod openapi;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 mod shared;
 pub mod streams;
 pub mod system;
@@ -0,0 +1,207 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::http::HttpMetricsConfig;
+use crate::http::error::ErrorResponse;
+use crate::http::jwt::middleware::PUBLIC_PATHS;
+use crate::http::{
+    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, schemas,
+    streams, system, topics, users,
+};
+use axum::Router;
+use iggy::messages::poll_messages::PollingKind;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme};
+use utoipa::openapi::{ContentBuilder, Ref, ResponseBuilder};
+use utoipa::{Modify, OpenApi, ToSchema};
+use utoipa_swagger_ui::SwaggerUi;
+
+pub const OPENAPI_PATH: &str = "/openapi.json";
+pub const OPENAPI_UI_PATH: &str = "/docs";
+pub const OPENAPI_UI_ASSETS_PATH: &str = "/docs/";
+const METRICS_PATH: &str = "/metrics";
+const BEARER_AUTH: &str = "bearer_auth";
+const UNAUTHORIZED_RESPONSE: &str = "Unauthorized";
+const ERROR_RESPONSE: &str = "Error";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Iggy HTTP API",
+        description = "The REST API of the Iggy server. Except for the public endpoints, the requests are authenticated with the Bearer access token returned by the login endpoints."
+    ),
+    paths(
+        system::index,
+        system::ping,
+        system::get_metrics,
+        system::get_stats,
+        system::get_client,
+        system::get_clients,
+        system::disconnect_client,
+        system::reload_config,
+        system::get_snapshot,
+        users::get_user,
+        users::get_users,
+        users::create_user,
+        users::update_user,
+        users::update_permissions,
+        users::change_password,
+        users::delete_user,
+        users::disconnect_user_clients,
+        users::login_user,
+        users::logout_user,
+        users::refresh_token,
+        personal_access_tokens::get_personal_access_tokens,
+        personal_access_tokens::create_personal_access_token,
+        personal_access_tokens::delete_personal_access_token,
+        personal_access_tokens::login_with_personal_access_token,
+        streams::get_stream,
+        streams::get_streams,
+        streams::create_stream,
+        streams::update_stream,
+        streams::delete_stream,
+        streams::purge_stream,
+        topics::get_topic,
+        topics::get_topics,
+        topics::create_topic,
+        topics::update_topic,
+        topics::delete_topic,
+        topics::purge_topic,
+        partitions::create_partitions,
+        partitions::delete_partitions,
+        consumer_groups::get_consumer_group,
+        consumer_groups::get_consumer_groups,
+        consumer_groups::create_consumer_group,
+        consumer_groups::delete_consumer_group,
+        consumer_offsets::get_consumer_offset,
+        consumer_offsets::store_consumer_offset,
+        consumer_offsets::delete_consumer_offset,
+        schemas::get_schema,
+        schemas::get_schemas,
+        schemas::register_schema,
+        schemas::update_schema_compatibility,
+        schemas::update_schema_validation,
+        messages::poll_messages,
+        messages::subscribe_messages,
+        messages::send_messages,
+        messages::flush_unsaved_buffer,
+    ),
+    components(schemas(ErrorResponse, PollingKind)),
+    modifiers(&Security),
+    security(("bearer_auth" = [])),
+    tags(
+        (name = "system", description = "Server status, statistics, clients and configuration."),
+        (name = "users", description = "Users, their permissions and the authentication."),
+        (name = "personal_access_tokens", description = "Personal access tokens of the users."),
+        (name = "streams", description = "Streams, the top-level containers of the topics."),
+        (name = "topics", description = "Topics of the streams."),
+        (name = "partitions", description = "Partitions of the topics."),
+        (name = "consumer_groups", description = "Consumer groups of the topics."),
+        (name = "consumer_offsets", description = "Stored offsets of the consumers."),
+        (name = "schemas", description = "Schema registry of the topics."),
+        (name = "messages", description = "Sending, polling and subscribing to the messages."),
+    )
+)]
+struct ApiDoc;
+
+/// Adds the Bearer authentication to all the operations except for the public ones,
+/// and the error responses shared by all the operations.
+struct Security;
+
+impl Modify for Security {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            BEARER_AUTH,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.responses.insert(
+            UNAUTHORIZED_RESPONSE.to_owned(),
+            ResponseBuilder::new()
+                .description("The access token is missing, invalid or expired.")
+                .build()
+                .into(),
+        );
+        components.responses.insert(
+            ERROR_RESPONSE.to_owned(),
+            ResponseBuilder::new()
+                .description("The request has failed, e.g. because it's invalid (400), not permitted (403) or the resource doesn't exist (404).")
+                .content(
+                    "application/json",
+                    ContentBuilder::new()
+                        .schema(Some(Ref::from_schema_name(ErrorResponse::name())))
+                        .build(),
+                )
+                .build()
+                .into(),
+        );
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            let public = PUBLIC_PATHS.contains(&path.as_str());
+            for operation in [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                let responses = &mut operation.responses.responses;
+                if public {
+                    operation.security = Some(vec![SecurityRequirement::default()]);
+                } else {
+                    responses.insert(
+                        "401".to_owned(),
+                        Ref::from_response_name(UNAUTHORIZED_RESPONSE).into(),
+                    );
+                }
+                responses.insert(
+                    "4XX".to_owned(),
+                    Ref::from_response_name(ERROR_RESPONSE).into(),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the OpenAPI document of the routes available with the given configuration.
+pub fn openapi(metrics_config: &HttpMetricsConfig) -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    if let Some(metrics) = openapi.paths.paths.remove(METRICS_PATH) {
+        if metrics_config.enabled {
+            openapi
+                .paths
+                .paths
+                .insert(metrics_config.endpoint.clone(), metrics);
+        }
+    }
+    openapi
+}
+
+/// Serves the OpenAPI document, and the Swagger UI to explore it.
+pub fn router(metrics_config: &HttpMetricsConfig) -> Router {
+    SwaggerUi::new(OPENAPI_UI_PATH)
+        .url(OPENAPI_PATH, openapi(metrics_config))
+        .into()
+}
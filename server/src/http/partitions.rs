@@ -43,6 +43,19 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/partitions",
+    tag = "partitions",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = CreatePartitions,
+    responses(
+        (status = 201, description = "The partitions have been created.")
+    )
+)]
 #[instrument(skip_all, name = "trace_create_partitions", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_partitions(
     State(state): State<Arc<AppState>>,
@@ -84,6 +97,19 @@ async fn create_partitions(
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/partitions",
+    tag = "partitions",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("partitions_count" = u32, Query, description = "Number of the last partitions to delete.")
+    ),
+    responses(
+        (status = 204, description = "The partitions have been deleted.")
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_partitions", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn delete_partitions(
     State(state): State<Arc<AppState>>,
@@ -57,6 +57,14 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/personal-access-tokens",
+    tag = "personal_access_tokens",
+    responses(
+        (status = 200, description = "The personal access tokens of the user.", body = Vec<PersonalAccessTokenInfo>)
+    )
+)]
 async fn get_personal_access_tokens(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -75,6 +83,15 @@ async fn get_personal_access_tokens(
     Ok(Json(personal_access_tokens))
 }
 
+#[utoipa::path(
+    post,
+    path = "/personal-access-tokens",
+    tag = "personal_access_tokens",
+    request_body = CreatePersonalAccessToken,
+    responses(
+        (status = 200, description = "The created personal access token.", body = RawPersonalAccessToken)
+    )
+)]
 #[instrument(skip_all, name = "trace_create_personal_access_token", fields(iggy_user_id = identity.user_id))]
 async fn create_personal_access_token(
     State(state): State<Arc<AppState>>,
@@ -117,6 +134,17 @@ async fn create_personal_access_token(
     Ok(Json(RawPersonalAccessToken { token }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/personal-access-tokens/{name}",
+    tag = "personal_access_tokens",
+    params(
+        ("name" = String, Path, description = "Name of the personal access token.")
+    ),
+    responses(
+        (status = 204, description = "The personal access token has been deleted.")
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_personal_access_token", fields(iggy_user_id = identity.user_id))]
 async fn delete_personal_access_token(
     State(state): State<Arc<AppState>>,
@@ -154,6 +182,15 @@ async fn delete_personal_access_token(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/personal-access-tokens/login",
+    tag = "personal_access_tokens",
+    request_body = LoginWithPersonalAccessToken,
+    responses(
+        (status = 200, description = "The identity with the access token.", body = IdentityInfo)
+    )
+)]
 #[instrument(skip_all, name = "trace_login_with_personal_access_token")]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
@@ -0,0 +1,258 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterSchemaWithId;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::identifier::Identifier;
+use iggy::models::schema::{Schema, TopicSchemas};
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::schemas::update_schema_validation::UpdateSchemaValidation;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/schemas/{schema_id}", get(get_schema))
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas",
+            get(get_schemas).post(register_schema),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas/compatibility",
+            put(update_schema_compatibility),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/schemas/validation",
+            put(update_schema_validation),
+        )
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/schemas/{schema_id}",
+    tag = "schemas",
+    params(
+        ("schema_id" = u32, Path, description = "Unique schema ID.")
+    ),
+    responses(
+        (status = 200, description = "The schema.", body = Schema)
+    )
+)]
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<Json<Schema>, CustomError> {
+    let system = state.system.read().await;
+    let Ok(schema) = system.get_schema(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        schema_id,
+    ) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(schema) = schema else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(schema.clone()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/schemas",
+    tag = "schemas",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The schemas of the topic.", body = TopicSchemas)
+    )
+)]
+async fn get_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<TopicSchemas>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let schemas = system.get_schemas(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &stream_id,
+        &topic_id,
+    )?;
+    Ok(Json(schemas))
+}
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/schemas",
+    tag = "schemas",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = RegisterSchema,
+    responses(
+        (status = 200, description = "The identical schema is already registered.", body = Schema),
+        (status = 201, description = "The registered schema.", body = Schema)
+    )
+)]
+#[instrument(skip_all, name = "trace_register_schema", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<(StatusCode, Json<Schema>), CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let mut system = state.system.write().await;
+    let (schema, registered) = system
+            .register_schema(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                command.format,
+                &command.definition,
+            )
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to register schema, stream ID: {}, topic ID: {}", stream_id, topic_id))?;
+    let schema = schema.clone();
+    if !registered {
+        return Ok((StatusCode::OK, Json(schema)));
+    }
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::RegisterSchema(RegisterSchemaWithId {
+                schema_id: schema.id,
+                command,
+            }),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(schema)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/schemas/compatibility",
+    tag = "schemas",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = UpdateSchemaCompatibility,
+    responses(
+        (status = 204, description = "The schema compatibility has been updated.")
+    )
+)]
+#[instrument(skip_all, name = "trace_update_schema_compatibility", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_schema_compatibility(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateSchemaCompatibility>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let mut system = state.system.write().await;
+    system
+            .update_schema_compatibility(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                command.compatibility,
+            )
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to update schema compatibility, stream ID: {}, topic ID: {}", stream_id, topic_id))?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateSchemaCompatibility(command),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/schemas/validation",
+    tag = "schemas",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = UpdateSchemaValidation,
+    responses(
+        (status = 204, description = "The schema validation has been updated.")
+    )
+)]
+#[instrument(skip_all, name = "trace_update_schema_validation", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn update_schema_validation(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateSchemaValidation>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let mut system = state.system.write().await;
+    system
+            .update_schema_validation(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                &command.stream_id,
+                &command.topic_id,
+                command.validation,
+            )
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to update schema validation, stream ID: {}, topic ID: {}", stream_id, topic_id))?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            EntryCommand::UpdateSchemaValidation(command),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
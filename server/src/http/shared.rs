@@ -17,13 +17,16 @@
  */
 
 use crate::http::jwt::jwt_manager::JwtManager;
+use crate::shutdown::Shutdown;
 use crate::streaming::systems::system::SharedSystem;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use ulid::Ulid;
 
 pub struct AppState {
-    pub jwt_manager: JwtManager,
+    pub jwt_manager: Arc<JwtManager>,
     pub system: SharedSystem,
+    pub shutdown: Shutdown,
 }
 
 #[derive(Debug, Copy, Clone)]
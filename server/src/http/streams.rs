@@ -51,6 +51,17 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The stream details.", body = StreamDetails)
+    )
+)]
 async fn get_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -72,6 +83,14 @@ async fn get_stream(
     Ok(Json(stream))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams",
+    tag = "streams",
+    responses(
+        (status = 200, description = "The streams.", body = Vec<Stream>)
+    )
+)]
 async fn get_streams(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -89,6 +108,15 @@ async fn get_streams(
     Ok(Json(streams))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams",
+    tag = "streams",
+    request_body = CreateStream,
+    responses(
+        (status = 200, description = "The created stream.", body = StreamDetails)
+    )
+)]
 #[instrument(skip_all, name = "trace_create_stream", fields(iggy_user_id = identity.user_id))]
 async fn create_stream(
     State(state): State<Arc<AppState>>,
@@ -130,6 +158,18 @@ async fn create_stream(
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name).")
+    ),
+    request_body = UpdateStream,
+    responses(
+        (status = 204, description = "The stream has been updated.")
+    )
+)]
 #[instrument(skip_all, name = "trace_update_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn update_stream(
     State(state): State<Arc<AppState>>,
@@ -169,6 +209,17 @@ async fn update_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The stream has been deleted.")
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn delete_stream(
     State(state): State<Arc<AppState>>,
@@ -206,6 +257,17 @@ async fn delete_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/purge",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The stream has been purged.")
+    )
+)]
 #[instrument(skip_all, name = "trace_purge_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn purge_stream(
     State(state): State<Arc<AppState>>,
@@ -22,10 +22,11 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
 use axum::body::Body;
 use axum::extract::{Path, State};
-use axum::http::{header, HeaderMap};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
@@ -34,22 +35,30 @@ use chrono::Local;
 use error_set::ErrContext;
 use iggy::locking::IggySharedMutFn;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::config_reload_info::ConfigReloadInfo;
 use iggy::models::stats::Stats;
+use iggy::system::disconnect_client::DisconnectClient;
 use iggy::system::get_snapshot::GetSnapshot;
+use iggy::system::reload_config::ReloadConfig;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
+use tracing::instrument;
 
 const NAME: &str = "Iggy API";
 const PONG: &str = "pong";
 
 pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Router {
     let mut router = Router::new()
-        .route("/", get(|| async { NAME }))
-        .route("/ping", get(|| async { PONG }))
+        .route("/", get(index))
+        .route("/ping", get(ping))
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
-        .route("/clients/{client_id}", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+        .route(
+            "/clients/{client_id}",
+            get(get_client).delete(disconnect_client),
+        )
+        .route("/snapshot", post(get_snapshot))
+        .route("/config/reload", post(reload_config));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -57,11 +66,51 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
     router.with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "system",
+    responses(
+        (status = 200, description = "The name of the API.", content_type = "text/plain")
+    )
+)]
+async fn index() -> &'static str {
+    NAME
+}
+
+#[utoipa::path(
+    get,
+    path = "/ping",
+    tag = "system",
+    responses(
+        (status = 200, description = "The `pong` response.", content_type = "text/plain")
+    )
+)]
+async fn ping() -> &'static str {
+    PONG
+}
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "system",
+    responses(
+        (status = 200, description = "The metrics in the Prometheus text format.", content_type = "text/plain")
+    )
+)]
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
     Ok(system.metrics.get_formatted_output())
 }
 
+#[utoipa::path(
+    get,
+    path = "/stats",
+    tag = "system",
+    responses(
+        (status = 200, description = "The server statistics.", body = Stats)
+    )
+)]
 async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, CustomError> {
     let system = state.system.read().await;
     let stats = system.get_stats().await.with_error_context(|error| {
@@ -70,6 +119,17 @@ async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, Cu
     Ok(Json(stats))
 }
 
+#[utoipa::path(
+    get,
+    path = "/clients/{client_id}",
+    tag = "system",
+    params(
+        ("client_id" = u32, Path, description = "Unique client ID.")
+    ),
+    responses(
+        (status = 200, description = "The client details.", body = ClientInfoDetails)
+    )
+)]
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -100,6 +160,14 @@ async fn get_client(
     Ok(Json(client))
 }
 
+#[utoipa::path(
+    get,
+    path = "/clients",
+    tag = "system",
+    responses(
+        (status = 200, description = "The connected clients.", body = Vec<ClientInfo>)
+    )
+)]
 async fn get_clients(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -118,6 +186,90 @@ async fn get_clients(
     Ok(Json(clients))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/clients/{client_id}",
+    tag = "system",
+    params(
+        ("client_id" = u32, Path, description = "Unique client ID.")
+    ),
+    responses(
+        (status = 204, description = "The client has been disconnected.")
+    )
+)]
+#[instrument(skip_all, name = "trace_disconnect_client", fields(iggy_user_id = identity.user_id, iggy_disconnected_client_id = client_id))]
+async fn disconnect_client(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(client_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let command = DisconnectClient::client(client_id);
+    command.validate()?;
+
+    let system = state.system.read().await;
+    system
+        .disconnect_client(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.target,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to disconnect client with ID: {client_id}"
+            )
+        })?;
+
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::DisconnectClient(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply disconnect client with ID: {client_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/config/reload",
+    tag = "system",
+    request_body = ReloadConfig,
+    responses(
+        (status = 200, description = "The applied changes and the ones requiring the restart.", body = ConfigReloadInfo)
+    )
+)]
+#[instrument(skip_all, name = "trace_reload_config", fields(iggy_user_id = identity.user_id))]
+async fn reload_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<ReloadConfig>,
+) -> Result<Json<ConfigReloadInfo>, CustomError> {
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let info = system
+        .reload_config(&Session::stateless(identity.user_id, identity.ip_address))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to reload config, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(info))
+}
+
+#[utoipa::path(
+    post,
+    path = "/snapshot",
+    tag = "system",
+    request_body = GetSnapshot,
+    responses(
+        (status = 200, description = "The snapshot ZIP archive.", content_type = "application/zip")
+    )
+)]
 async fn get_snapshot(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -56,6 +56,18 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The topic details.", body = TopicDetails)
+    )
+)]
 async fn get_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -79,6 +91,17 @@ async fn get_topic(
     Ok(Json(topic))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The topics of the stream.", body = Vec<Topic>)
+    )
+)]
 async fn get_topics(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -101,6 +124,18 @@ async fn get_topics(
     Ok(Json(topics))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name).")
+    ),
+    request_body = CreateTopic,
+    responses(
+        (status = 200, description = "The created topic.", body = TopicDetails)
+    )
+)]
 #[instrument(skip_all, name = "trace_create_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn create_topic(
     State(state): State<Arc<AppState>>,
@@ -152,6 +187,19 @@ async fn create_topic(
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    request_body = UpdateTopic,
+    responses(
+        (status = 204, description = "The topic has been updated.")
+    )
+)]
 #[instrument(skip_all, name = "trace_update_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn update_topic(
     State(state): State<Arc<AppState>>,
@@ -199,6 +247,18 @@ async fn update_topic(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The topic has been deleted.")
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn delete_topic(
     State(state): State<Arc<AppState>>,
@@ -242,6 +302,18 @@ async fn delete_topic(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/purge",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The topic has been purged.")
+    )
+)]
 #[instrument(skip_all, name = "trace_purge_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn purge_topic(
     State(state): State<Arc<AppState>>,
@@ -31,9 +31,11 @@ use axum::http::StatusCode;
 use axum::routing::{delete, get, post, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::models::identity_info::IdentityInfo;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::system::disconnect_client::DisconnectClient;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::delete_user::DeleteUser;
@@ -44,6 +46,7 @@ use iggy::validatable::Validatable;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa::ToSchema;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -54,12 +57,24 @@ pub fn router(state: Arc<AppState>) -> Router {
         )
         .route("/users/{user_id}/permissions", put(update_permissions))
         .route("/users/{user_id}/password", put(change_password))
+        .route("/users/{user_id}/clients", delete(disconnect_user_clients))
         .route("/users/login", post(login_user))
         .route("/users/logout", delete(logout_user))
         .route("/users/refresh-token", post(refresh_token))
         .with_state(state)
 }
 
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or name).")
+    ),
+    responses(
+        (status = 200, description = "The user details.", body = UserInfoDetails)
+    )
+)]
 async fn get_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -81,6 +96,14 @@ async fn get_user(
     Ok(Json(user))
 }
 
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "users",
+    responses(
+        (status = 200, description = "The users.", body = Vec<UserInfo>)
+    )
+)]
 async fn get_users(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -99,6 +122,15 @@ async fn get_users(
     Ok(Json(users))
 }
 
+#[utoipa::path(
+    post,
+    path = "/users",
+    tag = "users",
+    request_body = CreateUser,
+    responses(
+        (status = 200, description = "The created user.", body = UserInfoDetails)
+    )
+)]
 #[instrument(skip_all, name = "trace_create_user", fields(iggy_user_id = identity.user_id))]
 async fn create_user(
     State(state): State<Arc<AppState>>,
@@ -153,6 +185,18 @@ async fn create_user(
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or name).")
+    ),
+    request_body = UpdateUser,
+    responses(
+        (status = 204, description = "The user has been updated.")
+    )
+)]
 #[instrument(skip_all, name = "trace_update_user", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_user(
     State(state): State<Arc<AppState>>,
@@ -193,6 +237,18 @@ async fn update_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/permissions",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or name).")
+    ),
+    request_body = UpdatePermissions,
+    responses(
+        (status = 204, description = "The permissions have been updated.")
+    )
+)]
 #[instrument(skip_all, name = "trace_update_permissions", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_permissions(
     State(state): State<Arc<AppState>>,
@@ -232,6 +288,18 @@ async fn update_permissions(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/password",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or name).")
+    ),
+    request_body = ChangePassword,
+    responses(
+        (status = 204, description = "The password has been changed.")
+    )
+)]
 #[instrument(skip_all, name = "trace_change_password", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn change_password(
     State(state): State<Arc<AppState>>,
@@ -280,6 +348,17 @@ async fn change_password(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The user has been deleted.")
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_user", fields(iggy_user_id = identity.user_id, iggy_deleted_user_id = user_id))]
 async fn delete_user(
     State(state): State<Arc<AppState>>,
@@ -315,6 +394,58 @@ async fn delete_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/{user_id}/clients",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or name).")
+    ),
+    responses(
+        (status = 204, description = "The clients of the user have been disconnected.")
+    )
+)]
+#[instrument(skip_all, name = "trace_disconnect_user_clients", fields(iggy_user_id = identity.user_id, iggy_disconnected_user_id = user_id))]
+async fn disconnect_user_clients(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let command = DisconnectClient::user(Identifier::from_str_value(&user_id)?);
+    let system = state.system.read().await;
+    system
+        .disconnect_client(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.target,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to disconnect clients of user with ID: {user_id}"
+            )
+        })?;
+
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::DisconnectClient(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply disconnect clients of user with ID: {user_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/login",
+    tag = "users",
+    request_body = LoginUser,
+    responses(
+        (status = 200, description = "The identity with the access token.", body = IdentityInfo)
+    )
+)]
 #[instrument(skip_all, name = "trace_login_user")]
 async fn login_user(
     State(state): State<Arc<AppState>>,
@@ -335,6 +466,14 @@ async fn login_user(
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/logout",
+    tag = "users",
+    responses(
+        (status = 204, description = "The access token has been revoked.")
+    )
+)]
 #[instrument(skip_all, name = "trace_logout_user", fields(iggy_user_id = identity.user_id))]
 async fn logout_user(
     State(state): State<Arc<AppState>>,
@@ -363,13 +502,38 @@ async fn logout_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/refresh-token",
+    tag = "users",
+    request_body = RefreshToken,
+    responses(
+        (status = 200, description = "The identity with the new access token.", body = IdentityInfo)
+    )
+)]
 async fn refresh_token(
     State(state): State<Arc<AppState>>,
     Json(command): Json<RefreshToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
+    let claims = state
+        .jwt_manager
+        .decode_token(&command.token)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to decode token")
+        })?;
+    if state
+        .system
+        .read()
+        .await
+        .is_user_session_revoked(claims.sub, claims.issued_at())
+        .await
+    {
+        return Err(IggyError::InvalidAccessToken.into());
+    }
+
     let token = state
         .jwt_manager
-        .refresh_token(&command.token)
+        .refresh_token(claims)
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to refresh token")
@@ -377,7 +541,7 @@ async fn refresh_token(
     Ok(Json(map_generated_access_token_to_identity_info(token)))
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct RefreshToken {
     token: String,
 }
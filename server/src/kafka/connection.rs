@@ -0,0 +1,314 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::groups::GroupCoordinator;
+use crate::kafka::handlers::{
+    api_versions, fetch, groups, list_offsets, metadata, offsets, produce, sasl,
+};
+use crate::kafka::naming::TopicNaming;
+use crate::server_error::ConnectionError;
+use crate::shutdown::Shutdown;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::handle_error;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::error::IggyError;
+use kafka_protocol::messages::{ApiKey, RequestHeader, ResponseHeader};
+use kafka_protocol::protocol::{Decodable, Encodable, HeaderVersion};
+use std::net::SocketAddr;
+use std::sync::atomic::AtomicI64;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tracing::{debug, info, warn};
+
+const REQUEST_LENGTH_BYTES: usize = 4;
+
+/// The state shared by all the Kafka connections.
+#[derive(Debug)]
+pub(crate) struct KafkaContext {
+    pub system: SharedSystem,
+    pub naming: TopicNaming,
+    pub groups: Arc<GroupCoordinator>,
+    pub max_request_size: usize,
+    pub advertised_address: Option<(String, u16)>,
+    pub last_producer_id: AtomicI64,
+}
+
+/// The single client connection speaking the Kafka wire protocol.
+#[derive(Debug)]
+pub(crate) struct KafkaConnection {
+    pub context: Arc<KafkaContext>,
+    pub session: Arc<Session>,
+    pub broker_host: String,
+    pub broker_port: i32,
+    pub sasl_handshake_completed: bool,
+}
+
+pub(crate) async fn handle_connection(
+    mut stream: TcpStream,
+    address: SocketAddr,
+    context: Arc<KafkaContext>,
+    shutdown: Shutdown,
+) {
+    let (broker_host, broker_port) = match &context.advertised_address {
+        Some((host, port)) => (host.clone(), *port),
+        None => match stream.local_addr() {
+            Ok(local_address) => (local_address.ip().to_string(), local_address.port()),
+            Err(error) => {
+                warn!("Failed to get the local address of Kafka connection: {address}. {error}");
+                return;
+            }
+        },
+    };
+
+    let system = context.system.clone();
+    let session = system
+        .read()
+        .await
+        .add_client(&address, Transport::Kafka)
+        .await;
+    let client_id = session.client_id;
+    info!("Created new session: {session}");
+    let mut connection = KafkaConnection {
+        context,
+        session,
+        broker_host,
+        broker_port: broker_port as i32,
+        sasl_handshake_completed: false,
+    };
+    if let Err(error) = connection.handle_requests(&mut stream, shutdown).await {
+        handle_error(error);
+    }
+    system.read().await.delete_client(client_id).await;
+    if let Err(error) = stream.shutdown().await {
+        debug!(
+            "Failed to shutdown Kafka stream for client: {client_id}, address: {address}. {error}"
+        );
+    } else {
+        info!("Successfully closed Kafka stream for client: {client_id}, address: {address}.");
+    }
+}
+
+impl KafkaConnection {
+    /// Handles the requests one by one, so the responses are always sent in the order of the requests.
+    async fn handle_requests(
+        &mut self,
+        stream: &mut TcpStream,
+        shutdown: Shutdown,
+    ) -> Result<(), ConnectionError> {
+        let mut length_buffer = [0u8; REQUEST_LENGTH_BYTES];
+        loop {
+            let read_result = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    return Err(ConnectionError::from(IggyError::ServerShuttingDown));
+                }
+                _ = self.session.disconnected() => {
+                    return Err(ConnectionError::from(IggyError::ClientDisconnected(
+                        self.session.client_id,
+                    )));
+                }
+                read_result = stream.read_exact(&mut length_buffer) => read_result,
+            };
+            if let Err(error) = read_result {
+                if error.kind() == std::io::ErrorKind::UnexpectedEof {
+                    return Err(ConnectionError::from(IggyError::ConnectionClosed));
+                }
+                return Err(ConnectionError::from(error));
+            }
+
+            let length = i32::from_be_bytes(length_buffer);
+            if length < 0 || length as usize > self.context.max_request_size {
+                return Err(ConnectionError::from(IggyError::CommandLengthError(
+                    format!(
+                        "Invalid Kafka request length: {length}, max allowed: {} bytes.",
+                        self.context.max_request_size
+                    ),
+                )));
+            }
+
+            let mut request = BytesMut::zeroed(length as usize);
+            stream.read_exact(&mut request).await?;
+            if let Some(response) = self.handle_request(request.freeze()).await? {
+                let mut frame = BytesMut::with_capacity(REQUEST_LENGTH_BYTES + response.len());
+                frame.put_i32(response.len() as i32);
+                frame.put_slice(&response);
+                stream.write_all(&frame).await?;
+            }
+        }
+    }
+
+    async fn handle_request(&mut self, mut request: Bytes) -> Result<Option<BytesMut>, IggyError> {
+        if request.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let api_key = i16::from_be_bytes([request[0], request[1]]);
+        let version = i16::from_be_bytes([request[2], request[3]]);
+        let Ok(api_key) = ApiKey::try_from(api_key) else {
+            warn!("Received unknown Kafka API key: {api_key}, closing the connection.");
+            return Err(IggyError::InvalidCommand);
+        };
+
+        let header = RequestHeader::decode(&mut request, api_key.request_header_version(version))
+            .map_err(|error| {
+            warn!("Failed to decode Kafka request header. {error}");
+            IggyError::InvalidCommand
+        })?;
+        if !api_versions::is_supported(api_key, version) {
+            if api_key == ApiKey::ApiVersions {
+                return encode_response(
+                    header.correlation_id,
+                    0,
+                    &api_versions::unsupported_version(),
+                )
+                .map(Some);
+            }
+
+            warn!("Received unsupported Kafka API: {api_key:?}, version: {version}, closing the connection.");
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if !self.session.is_authenticated()
+            && !matches!(
+                api_key,
+                ApiKey::ApiVersions | ApiKey::SaslHandshake | ApiKey::SaslAuthenticate
+            )
+        {
+            warn!("Received Kafka API: {api_key:?} before the authentication, closing the connection.");
+            return Err(IggyError::Unauthenticated);
+        }
+
+        debug!(
+            "Received Kafka API: {api_key:?}, version: {version}, correlation ID: {}",
+            header.correlation_id
+        );
+        let correlation_id = header.correlation_id;
+        let client_id = header
+            .client_id
+            .as_ref()
+            .map(|client_id| client_id.to_string())
+            .unwrap_or_default();
+        let response = match api_key {
+            ApiKey::ApiVersions => {
+                let request = decode_request(&mut request, version)?;
+                encode_response(correlation_id, version, &api_versions::handle(request))?
+            }
+            ApiKey::SaslHandshake => {
+                let request = decode_request(&mut request, version)?;
+                encode_response(correlation_id, version, &sasl::handshake(self, request))?
+            }
+            ApiKey::SaslAuthenticate => {
+                let request = decode_request(&mut request, version)?;
+                let response = sasl::authenticate(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::Metadata => {
+                let request = decode_request(&mut request, version)?;
+                let response = metadata::handle(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::Produce => {
+                let request = decode_request(&mut request, version)?;
+                match produce::handle(self, request).await {
+                    Some(response) => encode_response(correlation_id, version, &response)?,
+                    None => return Ok(None),
+                }
+            }
+            ApiKey::InitProducerId => {
+                let request = decode_request(&mut request, version)?;
+                let response = produce::init_producer_id(self, request);
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::Fetch => {
+                let request = decode_request(&mut request, version)?;
+                let response = fetch::handle(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::ListOffsets => {
+                let request = decode_request(&mut request, version)?;
+                let response = list_offsets::handle(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::OffsetCommit => {
+                let request = decode_request(&mut request, version)?;
+                let response = offsets::commit(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::OffsetFetch => {
+                let request = decode_request(&mut request, version)?;
+                let response = offsets::fetch(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::FindCoordinator => {
+                let request = decode_request(&mut request, version)?;
+                encode_response(
+                    correlation_id,
+                    version,
+                    &groups::find_coordinator(self, request),
+                )?
+            }
+            ApiKey::JoinGroup => {
+                let request = decode_request(&mut request, version)?;
+                let response = groups::join(self, request, &client_id).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::SyncGroup => {
+                let request = decode_request(&mut request, version)?;
+                let response = groups::sync(self, request).await;
+                encode_response(correlation_id, version, &response)?
+            }
+            ApiKey::Heartbeat => {
+                let request = decode_request(&mut request, version)?;
+                encode_response(correlation_id, version, &groups::heartbeat(self, request))?
+            }
+            ApiKey::LeaveGroup => {
+                let request = decode_request(&mut request, version)?;
+                encode_response(correlation_id, version, &groups::leave(self, request))?
+            }
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        Ok(Some(response))
+    }
+}
+
+fn decode_request<R: Decodable>(request: &mut Bytes, version: i16) -> Result<R, IggyError> {
+    R::decode(request, version).map_err(|error| {
+        warn!("Failed to decode Kafka request, version: {version}. {error}");
+        IggyError::InvalidCommand
+    })
+}
+
+fn encode_response<R: Encodable + HeaderVersion>(
+    correlation_id: i32,
+    version: i16,
+    response: &R,
+) -> Result<BytesMut, IggyError> {
+    let mut buffer = BytesMut::new();
+    ResponseHeader::default()
+        .with_correlation_id(correlation_id)
+        .encode(&mut buffer, R::header_version(version))
+        .and_then(|_| response.encode(&mut buffer, version))
+        .map_err(|error| {
+            warn!("Failed to encode Kafka response, version: {version}. {error}");
+            IggyError::InvalidCommand
+        })?;
+    Ok(buffer)
+}
@@ -0,0 +1,600 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use kafka_protocol::indexmap::IndexMap;
+use kafka_protocol::ResponseError;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+use tokio::time::{Duration, Instant};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+pub(crate) type JoinResult = Result<JoinedGroup, ResponseError>;
+pub(crate) type SyncResult = Result<SyncedGroup, ResponseError>;
+
+/// The member joining the group along with the protocols it supports.
+#[derive(Debug)]
+pub(crate) struct JoiningMember {
+    pub member_id: String,
+    pub client_id: String,
+    pub protocol_type: String,
+    pub protocols: Vec<(String, Bytes)>,
+    pub session_timeout: Duration,
+    pub rebalance_timeout: Duration,
+}
+
+/// The outcome of the completed join phase of the rebalance.
+/// Only the leader receives the members along with their protocol metadata.
+#[derive(Debug, Clone)]
+pub(crate) struct JoinedGroup {
+    pub generation_id: i32,
+    pub protocol_type: String,
+    pub protocol_name: String,
+    pub leader_id: String,
+    pub member_id: String,
+    pub members: Vec<(String, Bytes)>,
+}
+
+/// The assignment of the member distributed by the group leader.
+#[derive(Debug, Clone)]
+pub(crate) struct SyncedGroup {
+    pub protocol_type: String,
+    pub protocol_name: String,
+    pub assignment: Bytes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupState {
+    Empty,
+    PreparingRebalance,
+    CompletingRebalance,
+    Stable,
+}
+
+#[derive(Debug)]
+struct Member {
+    protocols: Vec<(String, Bytes)>,
+    session_timeout: Duration,
+    rebalance_timeout: Duration,
+    last_heartbeat: Instant,
+    assignment: Bytes,
+    pending_join: Option<oneshot::Sender<JoinResult>>,
+    pending_sync: Option<oneshot::Sender<SyncResult>>,
+}
+
+#[derive(Debug)]
+struct Group {
+    state: GroupState,
+    generation_id: i32,
+    rebalance_id: u64,
+    protocol_type: Option<String>,
+    protocol_name: Option<String>,
+    leader_id: Option<String>,
+    members: IndexMap<String, Member>,
+}
+
+/// The in-memory coordinator of the Kafka consumer groups.
+///
+/// The coordinator drives the join and sync phases of the rebalance protocol, while the partition
+/// assignment itself is computed by the group leader on the client side, just like in Kafka.
+/// The members which don't send any request within their session timeout are removed lazily,
+/// whenever any other member of the group contacts the coordinator.
+#[derive(Debug, Default)]
+pub(crate) struct GroupCoordinator {
+    groups: Mutex<HashMap<String, Group>>,
+}
+
+impl GroupCoordinator {
+    /// Adds the member to the group (or refreshes its protocols) and starts the rebalance.
+    /// The returned receiver completes once all the known members have rejoined the group,
+    /// or the rebalance timeout has elapsed.
+    pub fn join(
+        self: &Arc<Self>,
+        group_id: &str,
+        joining: JoiningMember,
+    ) -> Result<oneshot::Receiver<JoinResult>, ResponseError> {
+        if joining.protocols.is_empty() || joining.protocol_type.is_empty() {
+            return Err(ResponseError::InconsistentGroupProtocol);
+        }
+
+        let mut groups = self.groups.lock().unwrap();
+        let group = groups.entry(group_id.to_owned()).or_insert_with(Group::new);
+        self.expire_members(group_id, group);
+        if !group.members.is_empty()
+            && group.protocol_type.as_deref() != Some(joining.protocol_type.as_str())
+        {
+            return Err(ResponseError::InconsistentGroupProtocol);
+        }
+
+        let member_id = if joining.member_id.is_empty() {
+            format!("{}-{}", joining.client_id, Uuid::new_v4())
+        } else if group.members.contains_key(&joining.member_id) {
+            joining.member_id
+        } else {
+            return Err(ResponseError::UnknownMemberId);
+        };
+
+        if !group.supports_protocols(&member_id, &joining.protocols) {
+            return Err(ResponseError::InconsistentGroupProtocol);
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let member = group
+            .members
+            .entry(member_id.clone())
+            .or_insert_with(|| Member {
+                protocols: Vec::new(),
+                session_timeout: joining.session_timeout,
+                rebalance_timeout: joining.rebalance_timeout,
+                last_heartbeat: Instant::now(),
+                assignment: Bytes::new(),
+                pending_join: None,
+                pending_sync: None,
+            });
+        member.protocols = joining.protocols;
+        member.session_timeout = joining.session_timeout;
+        member.rebalance_timeout = joining.rebalance_timeout;
+        member.last_heartbeat = Instant::now();
+        member.pending_join = Some(sender);
+        group.protocol_type = Some(joining.protocol_type);
+        debug!("Member: {member_id} is joining Kafka group: {group_id}.");
+
+        if group.state != GroupState::PreparingRebalance {
+            self.prepare_rebalance(group_id, group);
+        }
+        group.try_complete_join();
+        Ok(receiver)
+    }
+
+    /// Completes the sync phase of the rebalance. The leader provides the assignments of all
+    /// the members, and the returned receiver completes once the assignments are known.
+    pub fn sync(
+        self: &Arc<Self>,
+        group_id: &str,
+        generation_id: i32,
+        member_id: &str,
+        assignments: Vec<(String, Bytes)>,
+    ) -> Result<oneshot::Receiver<SyncResult>, ResponseError> {
+        let mut groups = self.groups.lock().unwrap();
+        let group = groups
+            .get_mut(group_id)
+            .ok_or(ResponseError::UnknownMemberId)?;
+        self.expire_members(group_id, group);
+        group.validate_member(generation_id, member_id)?;
+        if group.state == GroupState::PreparingRebalance {
+            return Err(ResponseError::RebalanceInProgress);
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let is_leader = group.leader_id.as_deref() == Some(member_id);
+        if group.state == GroupState::CompletingRebalance && is_leader {
+            for (assigned_member_id, assignment) in assignments {
+                if let Some(member) = group.members.get_mut(&assigned_member_id) {
+                    member.assignment = assignment;
+                }
+            }
+            group.state = GroupState::Stable;
+            info!(
+                "Kafka group: {group_id} is stable, generation: {}, members: {}.",
+                group.generation_id,
+                group.members.len()
+            );
+            for member in group.members.values_mut() {
+                if let Some(pending_sync) = member.pending_sync.take() {
+                    let _ = pending_sync.send(Ok(group_synced(
+                        &group.protocol_type,
+                        &group.protocol_name,
+                        member,
+                    )));
+                }
+            }
+        }
+
+        let member = group.members.get_mut(member_id).unwrap();
+        member.last_heartbeat = Instant::now();
+        if group.state == GroupState::Stable {
+            let _ = sender.send(Ok(group_synced(
+                &group.protocol_type,
+                &group.protocol_name,
+                member,
+            )));
+        } else {
+            member.pending_sync = Some(sender);
+        }
+        Ok(receiver)
+    }
+
+    /// Keeps the member alive, or tells it to rejoin the group if the rebalance is in progress.
+    pub fn heartbeat(
+        self: &Arc<Self>,
+        group_id: &str,
+        generation_id: i32,
+        member_id: &str,
+    ) -> Result<(), ResponseError> {
+        let mut groups = self.groups.lock().unwrap();
+        let group = groups
+            .get_mut(group_id)
+            .ok_or(ResponseError::UnknownMemberId)?;
+        self.expire_members(group_id, group);
+        if !group.members.contains_key(member_id) {
+            return Err(ResponseError::UnknownMemberId);
+        }
+        if group.state == GroupState::PreparingRebalance {
+            return Err(ResponseError::RebalanceInProgress);
+        }
+
+        group.validate_member(generation_id, member_id)?;
+        group.members.get_mut(member_id).unwrap().last_heartbeat = Instant::now();
+        Ok(())
+    }
+
+    /// Removes the members from the group and rebalances the remaining ones.
+    pub fn leave(
+        self: &Arc<Self>,
+        group_id: &str,
+        member_ids: &[String],
+    ) -> Result<Vec<Result<(), ResponseError>>, ResponseError> {
+        let mut groups = self.groups.lock().unwrap();
+        let group = groups
+            .get_mut(group_id)
+            .ok_or(ResponseError::UnknownMemberId)?;
+        let results = member_ids
+            .iter()
+            .map(|member_id| match group.members.shift_remove(member_id) {
+                Some(_) => {
+                    debug!("Member: {member_id} has left Kafka group: {group_id}.");
+                    Ok(())
+                }
+                None => Err(ResponseError::UnknownMemberId),
+            })
+            .collect::<Vec<_>>();
+        if results.iter().any(|result| result.is_ok()) {
+            self.rebalance_after_removal(group_id, group);
+        }
+        Ok(results)
+    }
+
+    /// Validates whether the member is allowed to commit the offsets of the group.
+    /// The commits with the negative generation come from the consumers which don't use
+    /// the group membership and are always accepted.
+    pub fn validate_commit(
+        self: &Arc<Self>,
+        group_id: &str,
+        generation_id: i32,
+        member_id: &str,
+    ) -> Result<(), ResponseError> {
+        if generation_id < 0 && member_id.is_empty() {
+            return Ok(());
+        }
+
+        let mut groups = self.groups.lock().unwrap();
+        let group = groups
+            .get_mut(group_id)
+            .ok_or(ResponseError::IllegalGeneration)?;
+        self.expire_members(group_id, group);
+        group.validate_member(generation_id, member_id)?;
+        group.members.get_mut(member_id).unwrap().last_heartbeat = Instant::now();
+        Ok(())
+    }
+
+    fn expire_members(self: &Arc<Self>, group_id: &str, group: &mut Group) {
+        if group.state == GroupState::PreparingRebalance {
+            return;
+        }
+
+        let now = Instant::now();
+        let members_count = group.members.len();
+        group.members.retain(|member_id, member| {
+            let alive = now.duration_since(member.last_heartbeat) <= member.session_timeout;
+            if !alive {
+                info!("Member: {member_id} of Kafka group: {group_id} has expired.");
+            }
+            alive
+        });
+        if group.members.len() != members_count {
+            self.rebalance_after_removal(group_id, group);
+        }
+    }
+
+    fn rebalance_after_removal(self: &Arc<Self>, group_id: &str, group: &mut Group) {
+        if group.members.is_empty() {
+            group.reset();
+            return;
+        }
+
+        if group.state != GroupState::PreparingRebalance {
+            self.prepare_rebalance(group_id, group);
+        }
+        group.try_complete_join();
+    }
+
+    fn prepare_rebalance(self: &Arc<Self>, group_id: &str, group: &mut Group) {
+        group.state = GroupState::PreparingRebalance;
+        group.rebalance_id += 1;
+        for member in group.members.values_mut() {
+            if let Some(pending_sync) = member.pending_sync.take() {
+                let _ = pending_sync.send(Err(ResponseError::RebalanceInProgress));
+            }
+        }
+
+        let rebalance_timeout = group
+            .members
+            .values()
+            .map(|member| member.rebalance_timeout)
+            .max()
+            .unwrap_or_default();
+        let rebalance_id = group.rebalance_id;
+        let group_id = group_id.to_owned();
+        let coordinator = self.clone();
+        info!("Preparing the rebalance of Kafka group: {group_id}.");
+        tokio::spawn(async move {
+            tokio::time::sleep(rebalance_timeout).await;
+            let mut groups = coordinator.groups.lock().unwrap();
+            if let Some(group) = groups.get_mut(&group_id) {
+                if group.state == GroupState::PreparingRebalance
+                    && group.rebalance_id == rebalance_id
+                {
+                    group.complete_join();
+                }
+            }
+        });
+    }
+}
+
+impl Group {
+    fn new() -> Self {
+        Self {
+            state: GroupState::Empty,
+            generation_id: 0,
+            rebalance_id: 0,
+            protocol_type: None,
+            protocol_name: None,
+            leader_id: None,
+            members: IndexMap::new(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.state = GroupState::Empty;
+        self.protocol_type = None;
+        self.protocol_name = None;
+        self.leader_id = None;
+    }
+
+    fn validate_member(&self, generation_id: i32, member_id: &str) -> Result<(), ResponseError> {
+        if !self.members.contains_key(member_id) {
+            return Err(ResponseError::UnknownMemberId);
+        }
+        if generation_id != self.generation_id {
+            return Err(ResponseError::IllegalGeneration);
+        }
+        Ok(())
+    }
+
+    fn supports_protocols(&self, member_id: &str, protocols: &[(String, Bytes)]) -> bool {
+        protocols.iter().any(|(name, _)| {
+            self.members
+                .iter()
+                .filter(|(id, _)| id.as_str() != member_id)
+                .all(|(_, member)| member.supports_protocol(name))
+        })
+    }
+
+    fn try_complete_join(&mut self) {
+        if self.state == GroupState::PreparingRebalance
+            && self
+                .members
+                .values()
+                .all(|member| member.pending_join.is_some())
+        {
+            self.complete_join();
+        }
+    }
+
+    /// Completes the join phase, the members which haven't rejoined the group are removed.
+    fn complete_join(&mut self) {
+        self.members
+            .retain(|_, member| member.pending_join.is_some());
+        if self.members.is_empty() {
+            self.reset();
+            return;
+        }
+
+        self.generation_id += 1;
+        let protocol_name = self.select_protocol();
+        let leader_id = match self.leader_id.take() {
+            Some(leader_id) if self.members.contains_key(&leader_id) => leader_id,
+            _ => self.members.keys().next().cloned().unwrap(),
+        };
+        let protocol_type = self.protocol_type.clone().unwrap_or_default();
+        let members = self
+            .members
+            .iter()
+            .map(|(member_id, member)| {
+                (
+                    member_id.clone(),
+                    member.protocol_metadata(&protocol_name).unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        for (member_id, member) in self.members.iter_mut() {
+            member.assignment = Bytes::new();
+            member.last_heartbeat = Instant::now();
+            let joined_group = JoinedGroup {
+                generation_id: self.generation_id,
+                protocol_type: protocol_type.clone(),
+                protocol_name: protocol_name.clone(),
+                leader_id: leader_id.clone(),
+                member_id: member_id.clone(),
+                members: if *member_id == leader_id {
+                    members.clone()
+                } else {
+                    Vec::new()
+                },
+            };
+            if let Some(pending_join) = member.pending_join.take() {
+                let _ = pending_join.send(Ok(joined_group));
+            }
+        }
+
+        self.state = GroupState::CompletingRebalance;
+        self.protocol_name = Some(protocol_name);
+        self.leader_id = Some(leader_id);
+    }
+
+    /// Selects the first protocol of the leader candidate which is supported by all the members.
+    fn select_protocol(&self) -> String {
+        let (_, first_member) = self.members.first().unwrap();
+        first_member
+            .protocols
+            .iter()
+            .map(|(name, _)| name)
+            .find(|name| {
+                self.members
+                    .values()
+                    .all(|member| member.supports_protocol(name))
+            })
+            .unwrap_or(&first_member.protocols[0].0)
+            .clone()
+    }
+}
+
+impl Member {
+    fn supports_protocol(&self, name: &str) -> bool {
+        self.protocols
+            .iter()
+            .any(|(protocol_name, _)| protocol_name == name)
+    }
+
+    fn protocol_metadata(&self, name: &str) -> Option<Bytes> {
+        self.protocols
+            .iter()
+            .find(|(protocol_name, _)| protocol_name == name)
+            .map(|(_, metadata)| metadata.clone())
+    }
+}
+
+fn group_synced(
+    protocol_type: &Option<String>,
+    protocol_name: &Option<String>,
+    member: &Member,
+) -> SyncedGroup {
+    SyncedGroup {
+        protocol_type: protocol_type.clone().unwrap_or_default(),
+        protocol_name: protocol_name.clone().unwrap_or_default(),
+        assignment: member.assignment.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn joining_member(member_id: &str, rebalance_timeout: Duration) -> JoiningMember {
+        JoiningMember {
+            member_id: member_id.to_owned(),
+            client_id: "client".to_owned(),
+            protocol_type: "consumer".to_owned(),
+            protocols: vec![("range".to_owned(), Bytes::from_static(b"metadata"))],
+            session_timeout: Duration::from_secs(30),
+            rebalance_timeout,
+        }
+    }
+
+    #[tokio::test]
+    async fn single_member_should_become_the_leader_of_the_first_generation() {
+        let coordinator = Arc::new(GroupCoordinator::default());
+        let joined = coordinator
+            .join("group", joining_member("", Duration::from_secs(10)))
+            .unwrap()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(joined.generation_id, 1);
+        assert_eq!(joined.protocol_name, "range");
+        assert_eq!(joined.leader_id, joined.member_id);
+        assert_eq!(joined.members.len(), 1);
+
+        let synced = coordinator
+            .sync(
+                "group",
+                1,
+                &joined.member_id,
+                vec![(joined.member_id.clone(), Bytes::from_static(b"assignment"))],
+            )
+            .unwrap()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(synced.assignment, Bytes::from_static(b"assignment"));
+        assert!(coordinator.heartbeat("group", 1, &joined.member_id).is_ok());
+        assert_eq!(
+            coordinator.heartbeat("group", 2, &joined.member_id),
+            Err(ResponseError::IllegalGeneration)
+        );
+        assert_eq!(
+            coordinator.heartbeat("group", 1, "unknown"),
+            Err(ResponseError::UnknownMemberId)
+        );
+    }
+
+    #[tokio::test]
+    async fn member_which_does_not_rejoin_should_be_removed_once_rebalance_times_out() {
+        let coordinator = Arc::new(GroupCoordinator::default());
+        let rebalance_timeout = Duration::from_millis(100);
+        let first = coordinator
+            .join("group", joining_member("", rebalance_timeout))
+            .unwrap()
+            .await
+            .unwrap()
+            .unwrap();
+
+        let second = coordinator
+            .join("group", joining_member("", rebalance_timeout))
+            .unwrap()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(second.generation_id, 2);
+        assert_eq!(second.leader_id, second.member_id);
+        assert_eq!(second.members.len(), 1);
+        assert_eq!(
+            coordinator.heartbeat("group", 2, &first.member_id),
+            Err(ResponseError::UnknownMemberId)
+        );
+    }
+
+    #[tokio::test]
+    async fn member_with_unsupported_protocol_should_not_join_the_group() {
+        let coordinator = Arc::new(GroupCoordinator::default());
+        coordinator
+            .join("group", joining_member("", Duration::from_secs(10)))
+            .unwrap();
+        let mut joining = joining_member("", Duration::from_secs(10));
+        joining.protocols = vec![("roundrobin".to_owned(), Bytes::new())];
+
+        assert_eq!(
+            coordinator.join("group", joining).err(),
+            Some(ResponseError::InconsistentGroupProtocol)
+        );
+    }
+}
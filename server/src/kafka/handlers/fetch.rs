@@ -0,0 +1,205 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use crate::kafka::handlers::{
+    get_partition_offsets, map_error, resolve_topic, to_partition_id, PartitionOffsets,
+};
+use crate::kafka::records;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use bytes::Bytes;
+use futures::future::select_all;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::PollingStrategy;
+use kafka_protocol::messages::fetch_request::FetchPartition;
+use kafka_protocol::messages::fetch_response::{FetchableTopicResponse, PartitionData};
+use kafka_protocol::messages::{FetchRequest, FetchResponse};
+use kafka_protocol::ResponseError;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::trace;
+
+/// The maximum number of messages polled from the single partition.
+const MAX_PARTITION_MESSAGES: u32 = 1000;
+
+/// Reads the records from the partitions. If there are less than `min_bytes` available, waits until
+/// the new messages are appended to any of the topics, or `max_wait_ms` (capped by the server
+/// configuration) elapses. The system lock is released while waiting.
+pub(crate) async fn handle(connection: &KafkaConnection, request: FetchRequest) -> FetchResponse {
+    let (deadline, messages_appended) = {
+        let system = connection.context.system.read().await;
+        let max_wait = Duration::from_millis(request.max_wait_ms.max(0) as u64)
+            .min(system.config.polling.max_wait.get_duration());
+        let messages_appended = request
+            .topics
+            .iter()
+            .filter_map(|topic| resolve_topic(connection, topic.topic.as_str()).ok())
+            .filter_map(|(stream_id, topic_id)| {
+                system
+                    .get_messages_appended(&connection.session, &stream_id, &topic_id)
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        (Instant::now() + max_wait, messages_appended)
+    };
+
+    loop {
+        // The notifications are enabled before fetching, so that the messages appended in the meantime aren't missed.
+        let notified = messages_appended
+            .iter()
+            .map(|messages_appended| {
+                let mut notified = Box::pin(messages_appended.notified());
+                notified.as_mut().enable();
+                notified
+            })
+            .collect::<Vec<_>>();
+        let fetched = fetch_topics(connection, &request).await;
+        if fetched.bytes >= request.min_bytes.max(0) as usize
+            || fetched.has_errors
+            || notified.is_empty()
+            || Instant::now() >= deadline
+        {
+            return FetchResponse::default().with_responses(fetched.responses);
+        }
+
+        trace!("Not enough Kafka records to fetch, waiting for the new ones...");
+        let _ = tokio::time::timeout_at(deadline, select_all(notified)).await;
+    }
+}
+
+struct FetchedTopics {
+    responses: Vec<FetchableTopicResponse>,
+    bytes: usize,
+    has_errors: bool,
+}
+
+async fn fetch_topics(connection: &KafkaConnection, request: &FetchRequest) -> FetchedTopics {
+    let system = connection.context.system.read().await;
+    let max_bytes = if request.max_bytes > 0 {
+        request.max_bytes as usize
+    } else {
+        usize::MAX
+    };
+    let mut fetched = FetchedTopics {
+        responses: Vec::with_capacity(request.topics.len()),
+        bytes: 0,
+        has_errors: false,
+    };
+    for topic in &request.topics {
+        let mut partitions = Vec::with_capacity(topic.partitions.len());
+        for partition in &topic.partitions {
+            let response = PartitionData::default().with_partition_index(partition.partition);
+            let max_partition_bytes = (partition.partition_max_bytes.max(0) as usize)
+                .min(max_bytes.saturating_sub(fetched.bytes));
+            let result = fetch_partition(
+                connection,
+                &system,
+                topic.topic.as_str(),
+                partition,
+                max_partition_bytes,
+                fetched.bytes == 0,
+            )
+            .await;
+            partitions.push(match result {
+                Ok((offsets, records)) => {
+                    fetched.bytes += records.len();
+                    response
+                        .with_high_watermark(offsets.high_watermark)
+                        .with_last_stable_offset(offsets.high_watermark)
+                        .with_log_start_offset(offsets.log_start_offset)
+                        .with_records(Some(records))
+                }
+                Err(error) => {
+                    fetched.has_errors = true;
+                    response.with_error_code(error.code())
+                }
+            });
+        }
+        fetched.responses.push(
+            FetchableTopicResponse::default()
+                .with_topic(topic.topic.clone())
+                .with_partitions(partitions),
+        );
+    }
+    fetched
+}
+
+/// Polls the messages starting from the fetch offset, limited by the maximum bytes.
+/// If `include_first` is set, the first message is returned even if it exceeds the limit,
+/// so that the consumer can make progress.
+async fn fetch_partition(
+    connection: &KafkaConnection,
+    system: &System,
+    topic_name: &str,
+    partition: &FetchPartition,
+    max_bytes: usize,
+    include_first: bool,
+) -> Result<(PartitionOffsets, Bytes), ResponseError> {
+    let (stream_id, topic_id) = resolve_topic(connection, topic_name)?;
+    let partition_id = to_partition_id(partition.partition)?;
+    let offsets = get_partition_offsets(
+        system,
+        &connection.session,
+        &stream_id,
+        &topic_id,
+        partition_id,
+    )
+    .await?;
+    if partition.fetch_offset < offsets.log_start_offset
+        || partition.fetch_offset > offsets.high_watermark
+    {
+        return Err(ResponseError::OffsetOutOfRange);
+    }
+    if partition.fetch_offset == offsets.high_watermark || (max_bytes == 0 && !include_first) {
+        return Ok((offsets, Bytes::new()));
+    }
+
+    let polled_messages = system
+        .poll_messages(
+            &connection.session,
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+            PollingArgs::new(
+                PollingStrategy::offset(partition.fetch_offset as u64),
+                MAX_PARTITION_MESSAGES,
+                false,
+            ),
+        )
+        .await
+        .map_err(|error| map_error(&error))?;
+
+    let mut size = 0;
+    let mut count = 0;
+    for message in &polled_messages.messages {
+        let record_size = records::get_record_size(message);
+        if size + record_size > max_bytes && (count > 0 || !include_first) {
+            break;
+        }
+        size += record_size;
+        count += 1;
+    }
+    if count == 0 {
+        return Ok((offsets, Bytes::new()));
+    }
+
+    let records = records::encode_records(&polled_messages.messages[..count])?;
+    Ok((offsets, records))
+}
@@ -0,0 +1,200 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use crate::kafka::groups::JoiningMember;
+use crate::kafka::BROKER_ID;
+use kafka_protocol::messages::join_group_response::JoinGroupResponseMember;
+use kafka_protocol::messages::leave_group_response::MemberResponse;
+use kafka_protocol::messages::{
+    BrokerId, FindCoordinatorRequest, FindCoordinatorResponse, HeartbeatRequest, HeartbeatResponse,
+    JoinGroupRequest, JoinGroupResponse, LeaveGroupRequest, LeaveGroupResponse, SyncGroupRequest,
+    SyncGroupResponse,
+};
+use kafka_protocol::protocol::StrBytes;
+use kafka_protocol::ResponseError;
+use std::time::Duration;
+
+const GROUP_KEY_TYPE: i8 = 0;
+
+/// The listener is the coordinator of all the groups, the transactions aren't supported.
+pub(crate) fn find_coordinator(
+    connection: &KafkaConnection,
+    request: FindCoordinatorRequest,
+) -> FindCoordinatorResponse {
+    if request.key_type != GROUP_KEY_TYPE {
+        return FindCoordinatorResponse::default()
+            .with_error_code(ResponseError::CoordinatorNotAvailable.code())
+            .with_node_id(BrokerId(-1))
+            .with_port(-1);
+    }
+
+    FindCoordinatorResponse::default()
+        .with_node_id(BrokerId(BROKER_ID))
+        .with_host(StrBytes::from_string(connection.broker_host.clone()))
+        .with_port(connection.broker_port)
+}
+
+/// Joins the group and waits until the join phase of the rebalance completes.
+pub(crate) async fn join(
+    connection: &KafkaConnection,
+    request: JoinGroupRequest,
+    client_id: &str,
+) -> JoinGroupResponse {
+    if request.session_timeout_ms <= 0 {
+        return join_failed(&request, ResponseError::InvalidSessionTimeout);
+    }
+
+    // The rebalance timeout isn't sent by the clients using version 0.
+    let rebalance_timeout_ms = if request.rebalance_timeout_ms > 0 {
+        request.rebalance_timeout_ms
+    } else {
+        request.session_timeout_ms
+    };
+    let joining = JoiningMember {
+        member_id: request.member_id.to_string(),
+        client_id: client_id.to_owned(),
+        protocol_type: request.protocol_type.to_string(),
+        protocols: request
+            .protocols
+            .iter()
+            .map(|protocol| (protocol.name.to_string(), protocol.metadata.clone()))
+            .collect(),
+        session_timeout: Duration::from_millis(request.session_timeout_ms as u64),
+        rebalance_timeout: Duration::from_millis(rebalance_timeout_ms as u64),
+    };
+    let joined = match connection
+        .context
+        .groups
+        .join(request.group_id.as_str(), joining)
+    {
+        Ok(receiver) => receiver
+            .await
+            .unwrap_or(Err(ResponseError::UnknownMemberId)),
+        Err(error) => Err(error),
+    };
+
+    match joined {
+        Ok(joined) => JoinGroupResponse::default()
+            .with_generation_id(joined.generation_id)
+            .with_protocol_type(Some(StrBytes::from_string(joined.protocol_type)))
+            .with_protocol_name(Some(StrBytes::from_string(joined.protocol_name)))
+            .with_leader(StrBytes::from_string(joined.leader_id))
+            .with_member_id(StrBytes::from_string(joined.member_id))
+            .with_members(
+                joined
+                    .members
+                    .into_iter()
+                    .map(|(member_id, metadata)| {
+                        JoinGroupResponseMember::default()
+                            .with_member_id(StrBytes::from_string(member_id))
+                            .with_metadata(metadata)
+                    })
+                    .collect(),
+            ),
+        Err(error) => join_failed(&request, error),
+    }
+}
+
+/// Waits for the assignment distributed by the group leader.
+pub(crate) async fn sync(
+    connection: &KafkaConnection,
+    request: SyncGroupRequest,
+) -> SyncGroupResponse {
+    let assignments = request
+        .assignments
+        .into_iter()
+        .map(|assignment| (assignment.member_id.to_string(), assignment.assignment))
+        .collect();
+    let synced = match connection.context.groups.sync(
+        request.group_id.as_str(),
+        request.generation_id,
+        request.member_id.as_str(),
+        assignments,
+    ) {
+        Ok(receiver) => receiver
+            .await
+            .unwrap_or(Err(ResponseError::RebalanceInProgress)),
+        Err(error) => Err(error),
+    };
+
+    match synced {
+        Ok(synced) => SyncGroupResponse::default()
+            .with_protocol_type(Some(StrBytes::from_string(synced.protocol_type)))
+            .with_protocol_name(Some(StrBytes::from_string(synced.protocol_name)))
+            .with_assignment(synced.assignment),
+        Err(error) => SyncGroupResponse::default().with_error_code(error.code()),
+    }
+}
+
+pub(crate) fn heartbeat(
+    connection: &KafkaConnection,
+    request: HeartbeatRequest,
+) -> HeartbeatResponse {
+    let result = connection.context.groups.heartbeat(
+        request.group_id.as_str(),
+        request.generation_id,
+        request.member_id.as_str(),
+    );
+    HeartbeatResponse::default().with_error_code(result.err().map_or(0, |error| error.code()))
+}
+
+/// Removes the members from the group, the clients using the versions below 3 leave one at a time.
+pub(crate) fn leave(
+    connection: &KafkaConnection,
+    request: LeaveGroupRequest,
+) -> LeaveGroupResponse {
+    let single_member = request.members.is_empty();
+    let member_ids = if single_member {
+        vec![request.member_id.to_string()]
+    } else {
+        request
+            .members
+            .iter()
+            .map(|member| member.member_id.to_string())
+            .collect()
+    };
+
+    match connection
+        .context
+        .groups
+        .leave(request.group_id.as_str(), &member_ids)
+    {
+        Ok(results) if single_member => LeaveGroupResponse::default()
+            .with_error_code(results[0].err().map_or(0, |error| error.code())),
+        Ok(results) => LeaveGroupResponse::default().with_members(
+            member_ids
+                .into_iter()
+                .zip(results)
+                .map(|(member_id, result)| {
+                    MemberResponse::default()
+                        .with_member_id(StrBytes::from_string(member_id))
+                        .with_error_code(result.err().map_or(0, |error| error.code()))
+                })
+                .collect(),
+        ),
+        Err(error) => LeaveGroupResponse::default().with_error_code(error.code()),
+    }
+}
+
+fn join_failed(request: &JoinGroupRequest, error: ResponseError) -> JoinGroupResponse {
+    JoinGroupResponse::default()
+        .with_error_code(error.code())
+        .with_generation_id(-1)
+        .with_member_id(request.member_id.clone())
+}
@@ -0,0 +1,108 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use crate::kafka::handlers::{get_partition_offsets, map_error, resolve_topic, to_partition_id};
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::utils::timestamp::IggyTimestamp;
+use kafka_protocol::messages::list_offsets_request::ListOffsetsPartition;
+use kafka_protocol::messages::list_offsets_response::{
+    ListOffsetsPartitionResponse, ListOffsetsTopicResponse,
+};
+use kafka_protocol::messages::{ListOffsetsRequest, ListOffsetsResponse};
+use kafka_protocol::ResponseError;
+
+const LATEST_TIMESTAMP: i64 = -1;
+const EARLIEST_TIMESTAMP: i64 = -2;
+
+/// Returns the earliest offset, the latest offset (high watermark) or the offset of the first
+/// message with the timestamp greater than or equal to the requested one (in milliseconds).
+pub(crate) async fn handle(
+    connection: &KafkaConnection,
+    request: ListOffsetsRequest,
+) -> ListOffsetsResponse {
+    let system = connection.context.system.read().await;
+    let mut topics = Vec::with_capacity(request.topics.len());
+    for topic in request.topics {
+        let mut partitions = Vec::with_capacity(topic.partitions.len());
+        for partition in &topic.partitions {
+            let response = ListOffsetsPartitionResponse::default()
+                .with_partition_index(partition.partition_index);
+            let result = list_offset(connection, &system, topic.name.as_str(), partition).await;
+            partitions.push(match result {
+                Ok((timestamp, offset)) => response.with_timestamp(timestamp).with_offset(offset),
+                Err(error) => response.with_error_code(error.code()),
+            });
+        }
+        topics.push(
+            ListOffsetsTopicResponse::default()
+                .with_name(topic.name)
+                .with_partitions(partitions),
+        );
+    }
+    ListOffsetsResponse::default().with_topics(topics)
+}
+
+async fn list_offset(
+    connection: &KafkaConnection,
+    system: &System,
+    topic_name: &str,
+    partition: &ListOffsetsPartition,
+) -> Result<(i64, i64), ResponseError> {
+    let (stream_id, topic_id) = resolve_topic(connection, topic_name)?;
+    let partition_id = to_partition_id(partition.partition_index)?;
+    let offsets = get_partition_offsets(
+        system,
+        &connection.session,
+        &stream_id,
+        &topic_id,
+        partition_id,
+    )
+    .await?;
+    match partition.timestamp {
+        LATEST_TIMESTAMP => return Ok((-1, offsets.high_watermark)),
+        EARLIEST_TIMESTAMP => return Ok((-1, offsets.log_start_offset)),
+        timestamp if timestamp < 0 => return Err(ResponseError::InvalidRequest),
+        _ => {}
+    }
+
+    let polled_messages = system
+        .poll_messages(
+            &connection.session,
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+            PollingArgs::new(
+                PollingStrategy::timestamp(IggyTimestamp::from(partition.timestamp as u64 * 1000)),
+                1,
+                false,
+            ),
+        )
+        .await
+        .map_err(|error| map_error(&error))?;
+    Ok(polled_messages
+        .messages
+        .first()
+        .map_or((-1, -1), |message| {
+            ((message.timestamp / 1000) as i64, message.offset as i64)
+        }))
+}
@@ -0,0 +1,134 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use crate::kafka::handlers::{map_error, resolve_topic};
+use crate::kafka::{BROKER_ID, CLUSTER_ID};
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use iggy::identifier::Identifier;
+use kafka_protocol::messages::metadata_response::{
+    MetadataResponseBroker, MetadataResponsePartition, MetadataResponseTopic,
+};
+use kafka_protocol::messages::{BrokerId, MetadataRequest, MetadataResponse, TopicName};
+use kafka_protocol::protocol::StrBytes;
+use kafka_protocol::ResponseError;
+
+/// Describes the requested topics, or all the topics visible to the user if none is specified.
+/// The listener acts as the single broker, which leads all the partitions.
+pub(crate) async fn handle(
+    connection: &KafkaConnection,
+    request: MetadataRequest,
+) -> MetadataResponse {
+    let system = connection.context.system.read().await;
+    let topics = match request.topics {
+        Some(topics) => topics
+            .into_iter()
+            .filter_map(|topic| topic.name)
+            .map(|name| describe_named_topic(connection, &system, name))
+            .collect(),
+        None => describe_all_topics(connection, &system),
+    };
+
+    let broker = MetadataResponseBroker::default()
+        .with_node_id(BrokerId(BROKER_ID))
+        .with_host(StrBytes::from_string(connection.broker_host.clone()))
+        .with_port(connection.broker_port);
+    MetadataResponse::default()
+        .with_brokers(vec![broker])
+        .with_cluster_id(Some(StrBytes::from_static_str(CLUSTER_ID)))
+        .with_controller_id(BrokerId(BROKER_ID))
+        .with_topics(topics)
+}
+
+fn describe_named_topic(
+    connection: &KafkaConnection,
+    system: &System,
+    name: TopicName,
+) -> MetadataResponseTopic {
+    let topic = resolve_topic(connection, name.as_str()).and_then(|(stream_id, topic_id)| {
+        system
+            .find_topic(&connection.session, &stream_id, &topic_id)
+            .map_err(|error| map_error(&error))
+    });
+    match topic {
+        Ok(topic) => describe_topic(name, topic),
+        Err(error) => MetadataResponseTopic::default()
+            .with_name(Some(name))
+            .with_error_code(error.code()),
+    }
+}
+
+fn describe_all_topics(
+    connection: &KafkaConnection,
+    system: &System,
+) -> Vec<MetadataResponseTopic> {
+    let Ok(streams) = system.find_streams(&connection.session) else {
+        return Vec::new();
+    };
+
+    let mut topics = Vec::new();
+    for stream in streams {
+        let Ok(stream_id) = Identifier::numeric(stream.stream_id) else {
+            continue;
+        };
+        for topic in stream.get_topics() {
+            let Ok(topic_id) = Identifier::numeric(topic.topic_id) else {
+                continue;
+            };
+            if system
+                .find_topic(&connection.session, &stream_id, &topic_id)
+                .is_err()
+            {
+                continue;
+            }
+
+            let name = connection
+                .context
+                .naming
+                .topic_name(&stream.name, &topic.name);
+            topics.push(describe_topic(
+                TopicName(StrBytes::from_string(name)),
+                topic,
+            ));
+        }
+    }
+    topics.sort_by(|first, second| first.name.cmp(&second.name));
+    topics
+}
+
+fn describe_topic(name: TopicName, topic: &Topic) -> MetadataResponseTopic {
+    let partitions = (0..topic.get_partitions_count() as i32)
+        .map(|partition_index| {
+            MetadataResponsePartition::default()
+                .with_partition_index(partition_index)
+                .with_leader_id(BrokerId(BROKER_ID))
+                .with_leader_epoch(0)
+                .with_replica_nodes(vec![BrokerId(BROKER_ID)])
+                .with_isr_nodes(vec![BrokerId(BROKER_ID)])
+        })
+        .collect();
+    MetadataResponseTopic::default()
+        .with_name(Some(name))
+        .with_error_code(if topic.get_partitions_count() == 0 {
+            ResponseError::UnknownTopicOrPartition.code()
+        } else {
+            0
+        })
+        .with_partitions(partitions)
+}
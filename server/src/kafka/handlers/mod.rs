@@ -0,0 +1,114 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub(crate) mod api_versions;
+pub(crate) mod fetch;
+pub(crate) mod groups;
+pub(crate) mod list_offsets;
+pub(crate) mod metadata;
+pub(crate) mod offsets;
+pub(crate) mod produce;
+pub(crate) mod sasl;
+
+use crate::kafka::connection::KafkaConnection;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use kafka_protocol::ResponseError;
+
+/// The log start offset and the high watermark (the offset of the next message) of the partition.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PartitionOffsets {
+    pub log_start_offset: i64,
+    pub high_watermark: i64,
+}
+
+/// Maps the Iggy error onto the closest Kafka error.
+pub(crate) fn map_error(error: &IggyError) -> ResponseError {
+    match error {
+        IggyError::Unauthenticated | IggyError::Unauthorized => {
+            ResponseError::TopicAuthorizationFailed
+        }
+        IggyError::StreamIdNotFound(_)
+        | IggyError::StreamNameNotFound(_)
+        | IggyError::TopicIdNotFound(..)
+        | IggyError::TopicNameNotFound(..)
+        | IggyError::PartitionNotFound(..)
+        | IggyError::NoPartitions(..) => ResponseError::UnknownTopicOrPartition,
+        IggyError::InvalidOffset(_) => ResponseError::OffsetOutOfRange,
+        IggyError::TooBigMessagePayload
+        | IggyError::TooBigHeadersPayload
+        | IggyError::TooManyMessages => ResponseError::MessageTooLarge,
+        IggyError::MessagePayloadSchemaViolation(..) => ResponseError::InvalidRecord,
+        _ => ResponseError::UnknownServerError,
+    }
+}
+
+/// Returns the identifier of the Iggy partition of the Kafka partition index.
+/// The Kafka partitions are numbered from 0, while the Iggy ones from 1.
+pub(crate) fn to_partition_id(partition_index: i32) -> Result<u32, ResponseError> {
+    if partition_index < 0 {
+        return Err(ResponseError::UnknownTopicOrPartition);
+    }
+
+    Ok(partition_index as u32 + 1)
+}
+
+/// Resolves the Kafka topic name into the stream and topic identifiers.
+pub(crate) fn resolve_topic(
+    connection: &KafkaConnection,
+    name: &str,
+) -> Result<(Identifier, Identifier), ResponseError> {
+    connection
+        .context
+        .naming
+        .resolve(name)
+        .ok_or(ResponseError::UnknownTopicOrPartition)
+}
+
+pub(crate) async fn get_partition_offsets(
+    system: &System,
+    session: &Session,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    partition_id: u32,
+) -> Result<PartitionOffsets, ResponseError> {
+    let topic = system
+        .find_topic(session, stream_id, topic_id)
+        .map_err(|error| map_error(&error))?;
+    let partition = topic
+        .get_partition(partition_id)
+        .map_err(|error| map_error(&error))?;
+    let partition = partition.read().await;
+    let high_watermark = if partition.should_increment_offset {
+        partition.current_offset as i64 + 1
+    } else {
+        0
+    };
+    let log_start_offset = partition
+        .segments
+        .first()
+        .map_or(0, |segment| segment.start_offset as i64)
+        .min(high_watermark);
+    Ok(PartitionOffsets {
+        log_start_offset,
+        high_watermark,
+    })
+}
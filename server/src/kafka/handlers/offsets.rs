@@ -0,0 +1,185 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use crate::kafka::handlers::{map_error, resolve_topic, to_partition_id};
+use crate::streaming::systems::system::System;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use kafka_protocol::messages::offset_commit_response::{
+    OffsetCommitResponsePartition, OffsetCommitResponseTopic,
+};
+use kafka_protocol::messages::offset_fetch_response::{
+    OffsetFetchResponsePartition, OffsetFetchResponseTopic,
+};
+use kafka_protocol::messages::{
+    OffsetCommitRequest, OffsetCommitResponse, OffsetFetchRequest, OffsetFetchResponse,
+};
+use kafka_protocol::ResponseError;
+
+// The committed offsets of the group are stored as the offsets of the Iggy consumer named after the group.
+// Kafka commits the offset of the next message to consume, while Iggy stores the last consumed one,
+// hence the offsets are shifted by one in both directions.
+
+pub(crate) async fn commit(
+    connection: &KafkaConnection,
+    request: OffsetCommitRequest,
+) -> OffsetCommitResponse {
+    let group_id = request.group_id.as_str();
+    let validation = connection.context.groups.validate_commit(
+        group_id,
+        request.generation_id_or_member_epoch,
+        request.member_id.as_str(),
+    );
+    let consumer = group_consumer(group_id);
+    let system = connection.context.system.read().await;
+    let mut topics = Vec::with_capacity(request.topics.len());
+    for topic in request.topics {
+        let mut partitions = Vec::with_capacity(topic.partitions.len());
+        for partition in &topic.partitions {
+            let result = match (&validation, &consumer) {
+                (Err(error), _) | (_, Err(error)) => Err(*error),
+                (Ok(()), Ok(consumer)) => {
+                    commit_offset(
+                        connection,
+                        &system,
+                        consumer,
+                        topic.name.as_str(),
+                        partition.partition_index,
+                        partition.committed_offset,
+                    )
+                    .await
+                }
+            };
+            partitions.push(
+                OffsetCommitResponsePartition::default()
+                    .with_partition_index(partition.partition_index)
+                    .with_error_code(result.err().map_or(0, |error| error.code())),
+            );
+        }
+        topics.push(
+            OffsetCommitResponseTopic::default()
+                .with_name(topic.name)
+                .with_partitions(partitions),
+        );
+    }
+    OffsetCommitResponse::default().with_topics(topics)
+}
+
+/// Returns the committed offsets of the requested partitions, or -1 for the ones without any commit.
+pub(crate) async fn fetch(
+    connection: &KafkaConnection,
+    request: OffsetFetchRequest,
+) -> OffsetFetchResponse {
+    let consumer = match group_consumer(request.group_id.as_str()) {
+        Ok(consumer) => consumer,
+        Err(error) => return OffsetFetchResponse::default().with_error_code(error.code()),
+    };
+    // Fetching the offsets of all the partitions consumed by the group isn't supported.
+    let Some(requested_topics) = request.topics else {
+        return OffsetFetchResponse::default();
+    };
+
+    let system = connection.context.system.read().await;
+    let mut topics = Vec::with_capacity(requested_topics.len());
+    for topic in requested_topics {
+        let mut partitions = Vec::with_capacity(topic.partition_indexes.len());
+        for partition_index in &topic.partition_indexes {
+            let response = OffsetFetchResponsePartition::default()
+                .with_partition_index(*partition_index)
+                .with_committed_offset(-1);
+            let result = fetch_offset(
+                connection,
+                &system,
+                &consumer,
+                topic.name.as_str(),
+                *partition_index,
+            )
+            .await;
+            partitions.push(match result {
+                Ok(offset) => response.with_committed_offset(offset),
+                Err(error) => response.with_error_code(error.code()),
+            });
+        }
+        topics.push(
+            OffsetFetchResponseTopic::default()
+                .with_name(topic.name)
+                .with_partitions(partitions),
+        );
+    }
+    OffsetFetchResponse::default().with_topics(topics)
+}
+
+fn group_consumer(group_id: &str) -> Result<Consumer, ResponseError> {
+    Identifier::named(group_id)
+        .map(Consumer::new)
+        .map_err(|_| ResponseError::InvalidGroupId)
+}
+
+async fn commit_offset(
+    connection: &KafkaConnection,
+    system: &System,
+    consumer: &Consumer,
+    topic_name: &str,
+    partition_index: i32,
+    committed_offset: i64,
+) -> Result<(), ResponseError> {
+    let (stream_id, topic_id) = resolve_topic(connection, topic_name)?;
+    let partition_id = to_partition_id(partition_index)?;
+    if committed_offset < 0 {
+        return Err(ResponseError::OffsetOutOfRange);
+    }
+    // Nothing has been consumed yet, and Iggy can't store the offset preceding the first message.
+    if committed_offset == 0 {
+        return Ok(());
+    }
+
+    system
+        .store_consumer_offset(
+            &connection.session,
+            consumer.clone(),
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+            committed_offset as u64 - 1,
+        )
+        .await
+        .map_err(|error| map_error(&error))
+}
+
+async fn fetch_offset(
+    connection: &KafkaConnection,
+    system: &System,
+    consumer: &Consumer,
+    topic_name: &str,
+    partition_index: i32,
+) -> Result<i64, ResponseError> {
+    let (stream_id, topic_id) = resolve_topic(connection, topic_name)?;
+    let partition_id = to_partition_id(partition_index)?;
+    let offset = system
+        .get_consumer_offset(
+            &connection.session,
+            consumer,
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+        )
+        .await
+        .map_err(|error| map_error(&error))?;
+    Ok(offset.map_or(-1, |offset| offset.stored_offset as i64 + 1))
+}
@@ -0,0 +1,122 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use crate::kafka::handlers::{map_error, resolve_topic, to_partition_id};
+use crate::kafka::records;
+use iggy::messages::send_messages::Partitioning;
+use kafka_protocol::messages::produce_request::PartitionProduceData;
+use kafka_protocol::messages::produce_response::{PartitionProduceResponse, TopicProduceResponse};
+use kafka_protocol::messages::{
+    InitProducerIdRequest, InitProducerIdResponse, ProduceRequest, ProduceResponse, ProducerId,
+};
+use kafka_protocol::ResponseError;
+use std::sync::atomic::Ordering;
+use tracing::debug;
+
+/// Appends the records to the partitions. Nothing is returned for the requests with `acks` set to 0,
+/// as the client doesn't expect any response then.
+pub(crate) async fn handle(
+    connection: &KafkaConnection,
+    request: ProduceRequest,
+) -> Option<ProduceResponse> {
+    let transactional = request.transactional_id.is_some();
+    let mut responses = Vec::with_capacity(request.topic_data.len());
+    for topic_data in request.topic_data {
+        let mut partition_responses = Vec::with_capacity(topic_data.partition_data.len());
+        for partition_data in topic_data.partition_data {
+            let response = PartitionProduceResponse::default().with_index(partition_data.index);
+            let result = if transactional {
+                Err(ResponseError::TransactionalIdAuthorizationFailed)
+            } else {
+                append(connection, topic_data.name.as_str(), partition_data).await
+            };
+            partition_responses.push(match result {
+                Ok(base_offset) => response.with_base_offset(base_offset),
+                Err(error) => response.with_error_code(error.code()),
+            });
+        }
+        responses.push(
+            TopicProduceResponse::default()
+                .with_name(topic_data.name)
+                .with_partition_responses(partition_responses),
+        );
+    }
+
+    if request.acks == 0 {
+        return None;
+    }
+
+    Some(ProduceResponse::default().with_responses(responses))
+}
+
+/// Assigns the producer identifier to the idempotent producer. The sequence numbers are not
+/// tracked, so the retried batches might be appended more than once, and the transactions aren't supported.
+pub(crate) fn init_producer_id(
+    connection: &KafkaConnection,
+    request: InitProducerIdRequest,
+) -> InitProducerIdResponse {
+    if request.transactional_id.is_some() {
+        return InitProducerIdResponse::default()
+            .with_error_code(ResponseError::TransactionalIdAuthorizationFailed.code());
+    }
+
+    let producer_id = connection
+        .context
+        .last_producer_id
+        .fetch_add(1, Ordering::Relaxed)
+        + 1;
+    InitProducerIdResponse::default()
+        .with_producer_id(ProducerId(producer_id))
+        .with_producer_epoch(0)
+}
+
+async fn append(
+    connection: &KafkaConnection,
+    topic_name: &str,
+    partition_data: PartitionProduceData,
+) -> Result<i64, ResponseError> {
+    let (stream_id, topic_id) = resolve_topic(connection, topic_name)?;
+    let partition_id = to_partition_id(partition_data.index)?;
+    let messages = match partition_data.records {
+        Some(records) => records::decode_messages(records)?,
+        None => Vec::new(),
+    };
+    if messages.is_empty() {
+        return Err(ResponseError::InvalidRecord);
+    }
+
+    debug!(
+        "Appending {} Kafka records to topic: {topic_name}, partition: {}.",
+        messages.len(),
+        partition_data.index
+    );
+    let system = connection.context.system.read().await;
+    let appended_messages = system
+        .append_messages(
+            &connection.session,
+            stream_id,
+            topic_id,
+            Partitioning::partition_id(partition_id),
+            messages,
+            None,
+        )
+        .await
+        .map_err(|error| map_error(&error))?;
+    Ok(appended_messages.map_or(-1, |appended_messages| appended_messages.base_offset as i64))
+}
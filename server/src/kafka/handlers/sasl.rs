@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::connection::KafkaConnection;
+use kafka_protocol::messages::{
+    SaslAuthenticateRequest, SaslAuthenticateResponse, SaslHandshakeRequest, SaslHandshakeResponse,
+};
+use kafka_protocol::protocol::StrBytes;
+use kafka_protocol::ResponseError;
+use tracing::{info, warn};
+
+/// The only supported mechanism, the credentials are verified as the Iggy users.
+const PLAIN_MECHANISM: &str = "PLAIN";
+
+pub(crate) fn handshake(
+    connection: &mut KafkaConnection,
+    request: SaslHandshakeRequest,
+) -> SaslHandshakeResponse {
+    let response = SaslHandshakeResponse::default()
+        .with_mechanisms(vec![StrBytes::from_static_str(PLAIN_MECHANISM)]);
+    if request.mechanism.as_str() != PLAIN_MECHANISM {
+        return response.with_error_code(ResponseError::UnsupportedSaslMechanism.code());
+    }
+
+    connection.sasl_handshake_completed = true;
+    response
+}
+
+/// Authenticates the connection with the SASL/PLAIN credentials (`[authzid] NUL username NUL password`).
+/// The failed authentication is reported to the client, which is expected to close the connection.
+pub(crate) async fn authenticate(
+    connection: &mut KafkaConnection,
+    request: SaslAuthenticateRequest,
+) -> SaslAuthenticateResponse {
+    if !connection.sasl_handshake_completed || connection.session.is_authenticated() {
+        return SaslAuthenticateResponse::default()
+            .with_error_code(ResponseError::IllegalSaslState.code());
+    }
+
+    let mut parts = request.auth_bytes.split(|byte| *byte == 0).skip(1);
+    let (Some(username), Some(password), None) = (parts.next(), parts.next(), parts.next()) else {
+        return authentication_failed("Invalid SASL/PLAIN credentials format.");
+    };
+    let (Ok(username), Ok(password)) =
+        (std::str::from_utf8(username), std::str::from_utf8(password))
+    else {
+        return authentication_failed("Invalid SASL/PLAIN credentials format.");
+    };
+
+    let system = connection.context.system.read().await;
+    match system
+        .login_user(username, password, Some(&connection.session))
+        .await
+    {
+        Ok(user) => {
+            info!(
+                "Kafka client: {} has been authenticated as user with ID: {}.",
+                connection.session.client_id, user.id
+            );
+            SaslAuthenticateResponse::default()
+        }
+        Err(error) => {
+            warn!(
+                "Failed to authenticate Kafka client: {}. {error}",
+                connection.session.client_id
+            );
+            authentication_failed("Invalid username or password.")
+        }
+    }
+}
+
+fn authentication_failed(message: &'static str) -> SaslAuthenticateResponse {
+    SaslAuthenticateResponse::default()
+        .with_error_code(ResponseError::SaslAuthenticationFailed.code())
+        .with_error_message(Some(StrBytes::from_static_str(message)))
+}
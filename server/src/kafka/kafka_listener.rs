@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::kafka::KafkaConfig;
+use crate::kafka::connection::{handle_connection, KafkaContext};
+use crate::kafka::groups::GroupCoordinator;
+use crate::kafka::naming::TopicNaming;
+use crate::shutdown::Shutdown;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use std::sync::atomic::AtomicI64;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tracing::{error, info};
+
+pub async fn start(config: KafkaConfig, system: SharedSystem, shutdown: Shutdown) -> SocketAddr {
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(&config.address)
+            .await
+            .unwrap_or_else(|error| {
+                panic!(
+                    "Unable to start Kafka server on address: {}. {error}",
+                    config.address
+                )
+            });
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for Kafka listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for Kafka listener",
+                local_addr
+            )
+        });
+
+        let context = Arc::new(KafkaContext {
+            system,
+            naming: TopicNaming::new(&config.naming),
+            groups: Arc::new(GroupCoordinator::default()),
+            max_request_size: config.max_request_size.as_bytes_usize(),
+            advertised_address: config
+                .advertised_host_and_port()
+                .map(|(host, port)| (host.to_owned(), port)),
+            last_producer_id: AtomicI64::new(0),
+        });
+        loop {
+            let accepted = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    info!("Kafka server has stopped accepting new connections.");
+                    break;
+                }
+                accepted = listener.accept() => accepted,
+            };
+            match accepted {
+                Ok((stream, address)) => {
+                    info!("Accepted new Kafka connection: {address}");
+                    let context = context.clone();
+                    let connection_shutdown = shutdown.clone();
+                    shutdown.spawn(async move {
+                        handle_connection(stream, address, context, connection_shutdown).await
+                    });
+                }
+                Err(error) => error!("Unable to accept Kafka socket. {error}"),
+            }
+        }
+    });
+    match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for Kafka listener."),
+    }
+}
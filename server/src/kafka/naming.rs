@@ -0,0 +1,109 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::kafka::KafkaNamingConfig;
+use iggy::identifier::Identifier;
+
+/// Maps the Kafka topic names onto the Iggy streams and topics.
+///
+/// The Kafka topic name is split at the first separator into the stream and the topic name,
+/// e.g. `orders.created` refers to the topic `created` in the stream `orders`.
+/// The names without the separator refer to the topics of the default stream, if it's configured.
+#[derive(Debug, Clone)]
+pub(crate) struct TopicNaming {
+    separator: String,
+    default_stream: Option<String>,
+}
+
+impl TopicNaming {
+    pub fn new(config: &KafkaNamingConfig) -> Self {
+        Self {
+            separator: config.separator.clone(),
+            default_stream: if config.default_stream.is_empty() {
+                None
+            } else {
+                Some(config.default_stream.clone())
+            },
+        }
+    }
+
+    /// Resolves the Kafka topic name into the stream and topic identifiers.
+    pub fn resolve(&self, name: &str) -> Option<(Identifier, Identifier)> {
+        let (stream, topic) = match name.split_once(self.separator.as_str()) {
+            Some((stream, topic)) => (stream, topic),
+            None => (self.default_stream.as_deref()?, name),
+        };
+        let stream_id = Identifier::named(stream).ok()?;
+        let topic_id = Identifier::named(topic).ok()?;
+        Some((stream_id, topic_id))
+    }
+
+    /// Returns the Kafka topic name of the topic in the stream.
+    pub fn topic_name(&self, stream: &str, topic: &str) -> String {
+        if self.default_stream.as_deref() == Some(stream) && !topic.contains(&self.separator) {
+            return topic.to_owned();
+        }
+
+        format!("{stream}{}{topic}", self.separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naming(default_stream: &str) -> TopicNaming {
+        TopicNaming::new(&KafkaNamingConfig {
+            separator: ".".to_owned(),
+            default_stream: default_stream.to_owned(),
+        })
+    }
+
+    #[test]
+    fn should_split_name_at_first_separator() {
+        let (stream_id, topic_id) = naming("").resolve("orders.created.v1").unwrap();
+        assert_eq!(stream_id, Identifier::named("orders").unwrap());
+        assert_eq!(topic_id, Identifier::named("created.v1").unwrap());
+    }
+
+    #[test]
+    fn should_resolve_name_without_separator_to_default_stream() {
+        let (stream_id, topic_id) = naming("events").resolve("created").unwrap();
+        assert_eq!(stream_id, Identifier::named("events").unwrap());
+        assert_eq!(topic_id, Identifier::named("created").unwrap());
+    }
+
+    #[test]
+    fn should_not_resolve_name_without_separator_when_default_stream_is_not_set() {
+        assert!(naming("").resolve("created").is_none());
+        assert!(naming("").resolve("orders.").is_none());
+    }
+
+    #[test]
+    fn topic_name_should_be_resolved_back_to_the_same_topic() {
+        let naming = naming("events");
+        for (stream, topic) in [("events", "created"), ("events", "a.b"), ("orders", "x")] {
+            let name = naming.topic_name(stream, topic);
+            let (stream_id, topic_id) = naming.resolve(&name).unwrap();
+            assert_eq!(stream_id, Identifier::named(stream).unwrap());
+            assert_eq!(topic_id, Identifier::named(topic).unwrap());
+        }
+        assert_eq!(naming.topic_name("events", "created"), "created");
+        assert_eq!(naming.topic_name("orders", "x"), "orders.x");
+    }
+}
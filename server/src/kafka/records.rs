@@ -0,0 +1,196 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Bytes, BytesMut};
+use iggy::messages::send_messages::Message;
+use iggy::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use iggy::models::messages::PolledMessage;
+use kafka_protocol::indexmap::IndexMap;
+use kafka_protocol::protocol::StrBytes;
+use kafka_protocol::records::{
+    Compression, Record, RecordBatchDecoder, RecordBatchEncoder, RecordEncodeOptions, TimestampType,
+};
+use kafka_protocol::ResponseError;
+use std::collections::HashMap;
+
+/// The header holding the key of the Kafka record.
+pub(crate) const KEY_HEADER: &str = "kafka-key";
+
+/// Decodes the Kafka record batches into the Iggy messages.
+///
+/// The value of the record becomes the payload, the key is stored in the `kafka-key` header
+/// and the record headers with the non-empty values are stored as the raw headers.
+/// The control records of the transactional producers are skipped.
+pub(crate) fn decode_messages(records: Bytes) -> Result<Vec<Message>, ResponseError> {
+    let mut records = records;
+    let record_sets =
+        RecordBatchDecoder::decode_all(&mut records).map_err(|_| ResponseError::CorruptMessage)?;
+    let mut messages = Vec::new();
+    for record_set in record_sets {
+        for record in record_set.records {
+            if record.control {
+                continue;
+            }
+
+            messages.push(to_message(record)?);
+        }
+    }
+    Ok(messages)
+}
+
+/// Encodes the polled Iggy messages as a single Kafka record batch.
+pub(crate) fn encode_records(messages: &[PolledMessage]) -> Result<Bytes, ResponseError> {
+    let records = messages.iter().map(to_record).collect::<Vec<_>>();
+    let mut buffer = BytesMut::new();
+    RecordBatchEncoder::encode(
+        &mut buffer,
+        &records,
+        &RecordEncodeOptions {
+            version: 2,
+            compression: Compression::None,
+        },
+    )
+    .map_err(|_| ResponseError::UnknownServerError)?;
+    Ok(buffer.freeze())
+}
+
+/// Returns the approximate size of the message once encoded as the Kafka record.
+pub(crate) fn get_record_size(message: &PolledMessage) -> usize {
+    const RECORD_OVERHEAD: usize = 32;
+    let headers_size = message.headers.as_ref().map_or(0, |headers| {
+        headers
+            .iter()
+            .map(|(key, value)| key.as_str().len() + value.value.len() + 2)
+            .sum()
+    });
+    message.payload.len() + headers_size + RECORD_OVERHEAD
+}
+
+fn to_message(record: Record) -> Result<Message, ResponseError> {
+    let mut headers = HashMap::new();
+    if let Some(key) = record.key.filter(|key| !key.is_empty()) {
+        headers.insert(
+            HeaderKey::new(KEY_HEADER).map_err(|_| ResponseError::InvalidRecord)?,
+            HeaderValue::from_raw(&key).map_err(|_| ResponseError::InvalidRecord)?,
+        );
+    }
+    for (key, value) in record.headers {
+        let Some(value) = value.filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        headers.insert(
+            HeaderKey::new(key.as_str()).map_err(|_| ResponseError::InvalidRecord)?,
+            HeaderValue::from_raw(&value).map_err(|_| ResponseError::InvalidRecord)?,
+        );
+    }
+
+    let headers = if headers.is_empty() {
+        None
+    } else {
+        Some(headers)
+    };
+    Ok(Message::new(
+        None,
+        record.value.unwrap_or_default(),
+        headers,
+    ))
+}
+
+fn to_record(message: &PolledMessage) -> Record {
+    let mut key = None;
+    let mut headers = IndexMap::new();
+    if let Some(message_headers) = &message.headers {
+        for (header_key, header_value) in message_headers {
+            let value = match header_value.kind {
+                HeaderKind::Raw | HeaderKind::String => header_value.value.clone(),
+                _ => Bytes::from(header_value.value_only_to_string()),
+            };
+            if header_key.as_str() == KEY_HEADER {
+                key = Some(value);
+                continue;
+            }
+
+            headers.insert(
+                StrBytes::from_string(header_key.as_str().to_owned()),
+                Some(value),
+            );
+        }
+    }
+
+    Record {
+        transactional: false,
+        control: false,
+        partition_leader_epoch: 0,
+        producer_id: -1,
+        producer_epoch: -1,
+        timestamp_type: TimestampType::Creation,
+        offset: message.offset as i64,
+        sequence: -1,
+        timestamp: (message.timestamp / 1000) as i64,
+        key,
+        value: Some(message.payload.clone()),
+        headers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messages::MessageState;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn encoded_records_should_be_decoded_into_the_same_messages() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new(KEY_HEADER).unwrap(),
+            HeaderValue::from_raw(b"key-1").unwrap(),
+        );
+        headers.insert(
+            HeaderKey::new("trace").unwrap(),
+            HeaderValue::from_raw(b"abc").unwrap(),
+        );
+        let polled_messages = (0..3u64)
+            .map(|offset| PolledMessage {
+                offset: 10 + offset,
+                state: MessageState::Available,
+                timestamp: 1_000_000 * offset,
+                id: 0,
+                checksum: 0,
+                headers: Some(headers.clone()),
+                length: IggyByteSize::from(7),
+                payload: Bytes::from(format!("value-{offset}")),
+            })
+            .collect::<Vec<_>>();
+
+        let records = encode_records(&polled_messages).unwrap();
+        let messages = decode_messages(records).unwrap();
+
+        assert_eq!(messages.len(), polled_messages.len());
+        for (message, polled_message) in messages.iter().zip(polled_messages.iter()) {
+            assert_eq!(message.payload, polled_message.payload);
+            assert_eq!(message.headers, polled_message.headers);
+        }
+    }
+
+    #[test]
+    fn corrupted_records_should_be_rejected() {
+        let result = decode_messages(Bytes::from_static(&[0, 1, 2, 3]));
+        assert_eq!(result.err(), Some(ResponseError::CorruptMessage));
+    }
+}
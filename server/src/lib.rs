@@ -33,14 +33,20 @@ pub mod channels;
 mod command;
 pub(crate) mod compat;
 pub mod configs;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod http;
+pub mod kafka;
 pub mod log;
+pub mod mqtt;
 pub mod quic;
 pub mod server_error;
+pub mod shutdown;
 pub mod state;
 pub mod streaming;
 pub mod tcp;
 pub mod versioning;
+pub mod websocket;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const IGGY_ROOT_USERNAME_ENV: &str = "IGGY_ROOT_USERNAME";
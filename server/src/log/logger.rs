@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::{TelemetryConfig, TelemetryTransport};
 use crate::configs::system::LoggingConfig;
 use crate::server_error::LogError;
@@ -34,7 +35,7 @@ use std::io::{self, Write};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
-use tracing::{event, info, trace, Level};
+use tracing::{error, event, info, trace, Level};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::field::{RecordFields, VisitOutput};
@@ -356,6 +357,32 @@ impl Logging {
         Ok(())
     }
 
+    /// Applies the log level from the reloaded configuration.
+    pub fn watch_config(&self, mut config_watcher: ConfigWatcher) {
+        let handles = [
+            self.filtering_stdout_reload_handle.clone(),
+            self.filtering_file_reload_handle.clone(),
+        ];
+        let mut level = config_watcher.borrow().system.logging.level.clone();
+        tokio::spawn(async move {
+            while config_watcher.changed().await.is_ok() {
+                let config = config_watcher.borrow_and_update().system.logging.clone();
+                if config.level == level {
+                    continue;
+                }
+
+                let filtering_level = Self::get_filtering_level(Some(&config));
+                for handle in handles.iter().flatten() {
+                    if let Err(error) = handle.modify(|layer| *layer = filtering_level.boxed()) {
+                        error!("Failed to reload logging filter. {error}");
+                    }
+                }
+                level = config.level;
+                info!("Log level is: {filtering_level}.");
+            }
+        });
+    }
+
     // RUST_LOG always takes precedence over config
     fn get_filtering_level(config: Option<&LoggingConfig>) -> LevelFilter {
         if let Ok(rust_log) = std::env::var("RUST_LOG") {
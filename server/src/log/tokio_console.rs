@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::server::TelemetryConfig;
 use crate::configs::system::LoggingConfig;
 use crate::server_error::ServerError;
@@ -44,6 +45,8 @@ impl Logging {
     ) -> Result<(), ServerError> {
         Ok(())
     }
+
+    pub fn watch_config(&self, _config_watcher: ConfigWatcher) {}
 }
 
 impl Default for Logging {
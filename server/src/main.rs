@@ -29,18 +29,26 @@ use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
 use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
+use server::configs::reload::ConfigReloader;
 use server::configs::server::ServerConfig;
+#[cfg(feature = "grpc")]
+use server::grpc::grpc_server;
 use server::http::http_server;
+use server::kafka::kafka_server;
 #[cfg(not(feature = "tokio-console"))]
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+use server::mqtt::mqtt_server;
 use server::quic::quic_server;
 use server::server_error::ServerError;
+use server::shutdown::Shutdown;
 use server::streaming::systems::system::{SharedSystem, System};
 use server::tcp::tcp_server;
+use server::websocket::websocket_server;
+use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::{info, instrument};
+use tracing::{error, info, instrument, warn};
 
 #[tokio::main]
 #[instrument(skip_all, name = "trace_start_server")]
@@ -87,6 +95,9 @@ async fn main() -> Result<(), ServerError> {
 
     logging.late_init(config.system.get_system_path(), &config.system.logging)?;
 
+    let config_reloader = Arc::new(ConfigReloader::new(config_provider, config.clone()));
+    logging.watch_config(config_reloader.watch());
+
     #[cfg(feature = "disable-mimalloc")]
     tracing::warn!(
         "Using default system allocator because code was build with `disable-mimalloc` feature"
@@ -105,41 +116,99 @@ async fn main() -> Result<(), ServerError> {
     // have the correct statistics when the server starts.
     system.write().await.get_stats().await?;
     system.write().await.init().await?;
+    system
+        .write()
+        .await
+        .set_config_reloader(config_reloader.clone());
+    system.watch_config(config_reloader.watch());
 
-    let _command_handler = ServerCommandHandler::new(system.clone(), &config)
-        .install_handler(SaveMessagesExecutor)
-        .install_handler(MaintainMessagesExecutor)
-        .install_handler(ArchiveStateExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor)
-        .install_handler(SysInfoPrintExecutor)
-        .install_handler(VerifyHeartbeatsExecutor);
+    let _command_handler =
+        ServerCommandHandler::new(system.clone(), &config, config_reloader.watch())
+            .install_handler(SaveMessagesExecutor)
+            .install_handler(MaintainMessagesExecutor)
+            .install_handler(ArchiveStateExecutor)
+            .install_handler(CleanPersonalAccessTokensExecutor)
+            .install_handler(SysInfoPrintExecutor)
+            .install_handler(VerifyHeartbeatsExecutor);
 
     #[cfg(unix)]
-    let (mut ctrl_c, mut sigterm) = {
+    let (mut ctrl_c, mut sigterm, mut sighup) = {
         use tokio::signal::unix::{signal, SignalKind};
         (
             signal(SignalKind::interrupt())?,
             signal(SignalKind::terminate())?,
+            signal(SignalKind::hangup())?,
         )
     };
 
+    #[cfg(unix)]
+    {
+        let config_reloader = config_reloader.clone();
+        tokio::spawn(async move {
+            while sighup.recv().await.is_some() {
+                info!("Received SIGHUP. Reloading server configuration...");
+                if let Err(error) = config_reloader.reload().await {
+                    error!("Failed to reload server configuration. {error}");
+                }
+            }
+        });
+    }
+
+    let shutdown = Shutdown::new();
     let mut current_config = config.clone();
 
+    let jwt_manager = http_server::build_jwt_manager(&config.http.jwt, &system).await;
     if config.http.enabled {
-        let http_addr = http_server::start(config.http, system.clone()).await;
+        let http_addr = http_server::start(
+            config.http,
+            system.clone(),
+            jwt_manager.clone(),
+            config_reloader.watch(),
+            shutdown.clone(),
+        )
+        .await;
         current_config.http.address = http_addr.to_string();
     }
 
     if config.quic.enabled {
-        let quic_addr = quic_server::start(config.quic, system.clone());
+        let quic_addr = quic_server::start(config.quic, system.clone(), shutdown.clone());
         current_config.quic.address = quic_addr.to_string();
     }
 
     if config.tcp.enabled {
-        let tcp_addr = tcp_server::start(config.tcp, system.clone()).await;
+        let tcp_addr = tcp_server::start(
+            config.tcp,
+            system.clone(),
+            config_reloader.watch(),
+            shutdown.clone(),
+        )
+        .await;
         current_config.tcp.address = tcp_addr.to_string();
     }
 
+    if config.websocket.enabled {
+        let websocket_addr =
+            websocket_server::start(config.websocket, system.clone(), shutdown.clone()).await;
+        current_config.websocket.address = websocket_addr.to_string();
+    }
+
+    if config.kafka.enabled {
+        let kafka_addr = kafka_server::start(config.kafka, system.clone(), shutdown.clone()).await;
+        current_config.kafka.address = kafka_addr.to_string();
+    }
+
+    if config.mqtt.enabled {
+        let mqtt_addr = mqtt_server::start(config.mqtt, system.clone(), shutdown.clone()).await;
+        current_config.mqtt.address = mqtt_addr.to_string();
+    }
+
+    #[cfg(feature = "grpc")]
+    if config.grpc.enabled {
+        let grpc_addr =
+            grpc_server::start(config.grpc, system.clone(), jwt_manager, shutdown.clone()).await;
+        current_config.grpc.address = grpc_addr.to_string();
+    }
+
     let runtime_path = current_config.system.get_runtime_path();
     let current_config_path = format!("{}/current_config.toml", runtime_path);
     let current_config_content =
@@ -173,6 +242,16 @@ async fn main() -> Result<(), ServerError> {
     }
 
     let shutdown_timestamp = Instant::now();
+    let shutdown_timeout = config_reloader.watch().borrow().shutdown.timeout;
+    info!("Waiting up to {shutdown_timeout} for the in-flight requests to complete...");
+    shutdown.trigger();
+    if !shutdown.drain(shutdown_timeout).await {
+        warn!(
+            "Shutdown timeout has elapsed, {} connection task(s) are still running.",
+            shutdown.pending_tasks()
+        );
+    }
+
     let mut system = system.write().await;
     system.shutdown().await?;
     let elapsed_time = shutdown_timestamp.elapsed();
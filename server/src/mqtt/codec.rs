@@ -0,0 +1,310 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Bytes, BytesMut};
+use iggy::error::IggyError;
+use rumqttc::mqttbytes::{self as mqttbytes_v4, v4};
+use rumqttc::v5::mqttbytes::v5::{
+    self, ConnectReturnCode, DisconnectReasonCode, Filter, Packet, SubscribeReasonCode,
+};
+use rumqttc::v5::mqttbytes::QoS;
+use tracing::warn;
+
+const CONNECT_PACKET_TYPE: u8 = 1;
+const DISCONNECT_PACKET: [u8; 2] = [0xE0, 0x00];
+const PROTOCOL_NAME: &[u8] = b"MQTT";
+
+/// The MQTT protocol version negotiated by the CONNECT packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProtocolVersion {
+    /// MQTT 3.1.1, the protocol level 4.
+    V4,
+    /// MQTT 5, the protocol level 5.
+    V5,
+}
+
+/// Reads and writes the MQTT packets of the negotiated protocol version.
+/// The packets of both versions are represented as the MQTT 5 packets, which are a superset of the MQTT 3.1.1 ones.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MqttCodec {
+    pub version: ProtocolVersion,
+    max_packet_size: usize,
+}
+
+/// The protocol of the CONNECT packet, detected before it's decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DetectedProtocol {
+    Supported(ProtocolVersion),
+    Unsupported,
+}
+
+impl MqttCodec {
+    pub fn new(version: ProtocolVersion, max_packet_size: usize) -> Self {
+        Self {
+            version,
+            max_packet_size,
+        }
+    }
+
+    /// Detects the protocol version of the CONNECT packet, once the buffer contains the entire packet.
+    pub fn detect_protocol(
+        buffer: &[u8],
+        max_packet_size: usize,
+    ) -> Result<Option<DetectedProtocol>, IggyError> {
+        let Some(fixed_header_length) = check_frame(buffer, max_packet_size)? else {
+            return Ok(None);
+        };
+        if buffer[0] >> 4 != CONNECT_PACKET_TYPE {
+            warn!("Received MQTT packet other than CONNECT as the first packet.");
+            return Err(IggyError::InvalidCommand);
+        }
+
+        // The variable header starts with the protocol name prefixed with its length, followed by the protocol level.
+        let variable_header = &buffer[fixed_header_length..];
+        let name_length = PROTOCOL_NAME.len();
+        if variable_header.len() < 2 + name_length + 1
+            || variable_header[..2] != (name_length as u16).to_be_bytes()
+            || &variable_header[2..2 + name_length] != PROTOCOL_NAME
+        {
+            return Ok(Some(DetectedProtocol::Unsupported));
+        }
+
+        Ok(Some(match variable_header[2 + name_length] {
+            4 => DetectedProtocol::Supported(ProtocolVersion::V4),
+            5 => DetectedProtocol::Supported(ProtocolVersion::V5),
+            _ => DetectedProtocol::Unsupported,
+        }))
+    }
+
+    /// Reads the next packet from the buffer, or returns `None` if the buffer doesn't contain the entire packet yet.
+    pub fn read(&self, buffer: &mut BytesMut) -> Result<Option<Packet>, IggyError> {
+        if check_frame(buffer, self.max_packet_size)?.is_none() {
+            return Ok(None);
+        }
+
+        // The DISCONNECT without the reason code is a valid MQTT 5 packet, meaning the normal disconnection.
+        if buffer.starts_with(&DISCONNECT_PACKET) {
+            let _ = buffer.split_to(DISCONNECT_PACKET.len());
+            return Ok(Some(Packet::Disconnect(v5::Disconnect::new(
+                DisconnectReasonCode::NormalDisconnection,
+            ))));
+        }
+
+        let packet = match self.version {
+            ProtocolVersion::V4 => v4::Packet::read(buffer, self.max_packet_size)
+                .map_err(|error| invalid_packet(&error))
+                .and_then(from_v4)?,
+            ProtocolVersion::V5 => v5::Packet::read(buffer, Some(self.max_packet_size as u32))
+                .map_err(|error| invalid_packet(&error))?,
+        };
+        Ok(Some(packet))
+    }
+
+    /// Writes the packet to the buffer. The packets which don't exist in MQTT 3.1.1,
+    /// such as the DISCONNECT sent by the server, are skipped for such clients.
+    pub fn write(&self, packet: Packet, buffer: &mut BytesMut) -> Result<(), IggyError> {
+        match self.version {
+            ProtocolVersion::V4 => {
+                let Some(packet) = to_v4(packet)? else {
+                    return Ok(());
+                };
+                packet
+                    .write(buffer, usize::MAX)
+                    .map_err(|error| invalid_packet(&error))?;
+            }
+            ProtocolVersion::V5 => {
+                packet
+                    .write(buffer, None)
+                    .map_err(|error| invalid_packet(&error))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the length of the fixed header, if the buffer contains the entire packet.
+fn check_frame(buffer: &[u8], max_packet_size: usize) -> Result<Option<usize>, IggyError> {
+    // The remaining length is encoded on up to 4 bytes, with the highest bit set if more bytes follow.
+    let mut remaining_length = 0;
+    let mut fixed_header_length = 1;
+    loop {
+        let Some(byte) = buffer.get(fixed_header_length) else {
+            return Ok(None);
+        };
+        remaining_length += ((byte & 0x7F) as usize) << (7 * (fixed_header_length - 1));
+        fixed_header_length += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        if fixed_header_length > 4 {
+            warn!("Received MQTT packet with malformed remaining length.");
+            return Err(IggyError::InvalidCommand);
+        }
+    }
+
+    if fixed_header_length + remaining_length > max_packet_size {
+        return Err(IggyError::CommandLengthError(format!(
+            "Invalid MQTT packet length: {}, max allowed: {max_packet_size} bytes.",
+            fixed_header_length + remaining_length
+        )));
+    }
+
+    if buffer.len() < fixed_header_length + remaining_length {
+        return Ok(None);
+    }
+    Ok(Some(fixed_header_length))
+}
+
+fn invalid_packet(error: &dyn std::error::Error) -> IggyError {
+    warn!("Failed to read or write MQTT packet. {error}");
+    IggyError::InvalidCommand
+}
+
+fn from_v4(packet: v4::Packet) -> Result<Packet, IggyError> {
+    let packet = match packet {
+        v4::Packet::Connect(connect) => Packet::Connect(
+            v5::Connect {
+                keep_alive: connect.keep_alive,
+                client_id: connect.client_id,
+                clean_start: connect.clean_session,
+                properties: None,
+            },
+            connect.last_will.map(|will| v5::LastWill {
+                topic: Bytes::from(will.topic),
+                message: will.message,
+                qos: from_v4_qos(will.qos),
+                retain: will.retain,
+                properties: None,
+            }),
+            connect
+                .login
+                .map(|login| v5::Login::new(login.username, login.password)),
+        ),
+        v4::Packet::Publish(publish) => Packet::Publish(v5::Publish {
+            dup: publish.dup,
+            qos: from_v4_qos(publish.qos),
+            retain: publish.retain,
+            topic: Bytes::from(publish.topic),
+            pkid: publish.pkid,
+            payload: publish.payload,
+            properties: None,
+        }),
+        v4::Packet::PubAck(ack) => Packet::PubAck(v5::PubAck::new(ack.pkid, None)),
+        v4::Packet::PubRec(ack) => Packet::PubRec(v5::PubRec::new(ack.pkid, None)),
+        v4::Packet::PubRel(ack) => Packet::PubRel(v5::PubRel::new(ack.pkid, None)),
+        v4::Packet::PubComp(ack) => Packet::PubComp(v5::PubComp::new(ack.pkid, None)),
+        v4::Packet::Subscribe(subscribe) => Packet::Subscribe(v5::Subscribe {
+            pkid: subscribe.pkid,
+            filters: subscribe
+                .filters
+                .into_iter()
+                .map(|filter| Filter::new(filter.path, from_v4_qos(filter.qos)))
+                .collect(),
+            properties: None,
+        }),
+        v4::Packet::Unsubscribe(unsubscribe) => Packet::Unsubscribe(v5::Unsubscribe {
+            pkid: unsubscribe.pkid,
+            filters: unsubscribe.topics,
+            properties: None,
+        }),
+        v4::Packet::PingReq => Packet::PingReq(v5::PingReq),
+        v4::Packet::Disconnect => Packet::Disconnect(v5::Disconnect::new(
+            DisconnectReasonCode::NormalDisconnection,
+        )),
+        packet => {
+            warn!("Received unexpected MQTT packet from the client: {packet:?}");
+            return Err(IggyError::InvalidCommand);
+        }
+    };
+    Ok(packet)
+}
+
+fn to_v4(packet: Packet) -> Result<Option<v4::Packet>, IggyError> {
+    let packet = match packet {
+        Packet::ConnAck(ack) => v4::Packet::ConnAck(v4::ConnAck::new(
+            match ack.code {
+                ConnectReturnCode::Success => v4::ConnectReturnCode::Success,
+                ConnectReturnCode::RefusedProtocolVersion
+                | ConnectReturnCode::UnsupportedProtocolVersion => {
+                    v4::ConnectReturnCode::RefusedProtocolVersion
+                }
+                ConnectReturnCode::BadClientId | ConnectReturnCode::ClientIdentifierNotValid => {
+                    v4::ConnectReturnCode::BadClientId
+                }
+                ConnectReturnCode::ServiceUnavailable
+                | ConnectReturnCode::ServerUnavailable
+                | ConnectReturnCode::ServerBusy => v4::ConnectReturnCode::ServiceUnavailable,
+                ConnectReturnCode::BadUserNamePassword => {
+                    v4::ConnectReturnCode::BadUserNamePassword
+                }
+                _ => v4::ConnectReturnCode::NotAuthorized,
+            },
+            ack.session_present,
+        )),
+        Packet::Publish(publish) => {
+            let topic =
+                String::from_utf8(publish.topic.to_vec()).map_err(|_| IggyError::InvalidCommand)?;
+            let mut packet =
+                v4::Publish::from_bytes(topic, to_v4_qos(publish.qos), publish.payload);
+            packet.dup = publish.dup;
+            packet.retain = publish.retain;
+            packet.pkid = publish.pkid;
+            v4::Packet::Publish(packet)
+        }
+        Packet::PubAck(ack) => v4::Packet::PubAck(v4::PubAck::new(ack.pkid)),
+        Packet::PubRec(ack) => v4::Packet::PubRec(v4::PubRec::new(ack.pkid)),
+        Packet::PubRel(ack) => v4::Packet::PubRel(v4::PubRel::new(ack.pkid)),
+        Packet::PubComp(ack) => v4::Packet::PubComp(v4::PubComp::new(ack.pkid)),
+        Packet::SubAck(ack) => v4::Packet::SubAck(v4::SubAck::new(
+            ack.pkid,
+            ack.return_codes
+                .into_iter()
+                .map(|code| match code {
+                    SubscribeReasonCode::Success(qos) => {
+                        v4::SubscribeReasonCode::Success(to_v4_qos(qos))
+                    }
+                    _ => v4::SubscribeReasonCode::Failure,
+                })
+                .collect(),
+        )),
+        Packet::UnsubAck(ack) => v4::Packet::UnsubAck(v4::UnsubAck::new(ack.pkid)),
+        Packet::PingResp(_) => v4::Packet::PingResp,
+        Packet::Disconnect(_) => return Ok(None),
+        packet => {
+            warn!("MQTT packet: {packet:?} can't be sent to MQTT 3.1.1 client.");
+            return Err(IggyError::InvalidCommand);
+        }
+    };
+    Ok(Some(packet))
+}
+
+fn from_v4_qos(qos: mqttbytes_v4::QoS) -> QoS {
+    match qos {
+        mqttbytes_v4::QoS::AtMostOnce => QoS::AtMostOnce,
+        mqttbytes_v4::QoS::AtLeastOnce => QoS::AtLeastOnce,
+        mqttbytes_v4::QoS::ExactlyOnce => QoS::ExactlyOnce,
+    }
+}
+
+fn to_v4_qos(qos: QoS) -> mqttbytes_v4::QoS {
+    match qos {
+        QoS::AtMostOnce => mqttbytes_v4::QoS::AtMostOnce,
+        QoS::AtLeastOnce => mqttbytes_v4::QoS::AtLeastOnce,
+        QoS::ExactlyOnce => mqttbytes_v4::QoS::ExactlyOnce,
+    }
+}
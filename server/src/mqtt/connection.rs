@@ -0,0 +1,783 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::mqtt::codec::{DetectedProtocol, MqttCodec, ProtocolVersion};
+use crate::mqtt::mapping::TopicMappings;
+use crate::mqtt::messages;
+use crate::mqtt::subscriptions::{
+    DeliveryConfig, GroupSubscription, OutgoingMessage, SubscriptionFilter,
+};
+use crate::server_error::ConnectionError;
+use crate::shutdown::Shutdown;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::handle_error;
+use bytes::BytesMut;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Partitioning;
+use rumqttc::v5::mqttbytes::v5::{
+    ConnAck, ConnAckProperties, ConnectReturnCode, Disconnect, DisconnectReasonCode, Filter,
+    LastWill, Login, Packet, PingResp, PubAck, PubAckReason, PubComp, PubRec, PubRecReason, PubRel,
+    Publish, PublishProperties, SubAck, SubscribeReasonCode, UnsubAck, UnsubAckReason,
+};
+use rumqttc::v5::mqttbytes::{valid_filter, valid_topic, QoS};
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+const INITIAL_BUFFER_CAPACITY: usize = 4096;
+const SHARED_SUBSCRIPTION_PREFIX: &str = "$share/";
+const CLIENT_GROUP_PREFIX: &str = "mqtt-";
+const GENERATED_CLIENT_ID_PREFIX: &str = "iggy-";
+
+/// The state shared by all the MQTT connections.
+#[derive(Debug)]
+pub(crate) struct MqttContext {
+    pub system: SharedSystem,
+    pub mappings: TopicMappings,
+    pub max_packet_size: usize,
+    pub connect_timeout: Duration,
+    pub max_inflight_messages: u16,
+    pub delivery_config: DeliveryConfig,
+    /// The sessions of the connected clients by the MQTT client ID, so that the existing connection
+    /// is closed once the client with the same ID connects again.
+    pub sessions: Mutex<HashMap<String, Arc<Session>>>,
+}
+
+/// The single client connection speaking MQTT 3.1.1 or MQTT 5.
+#[derive(Debug)]
+struct MqttConnection {
+    context: Arc<MqttContext>,
+    session: Arc<Session>,
+    codec: MqttCodec,
+    client_id: String,
+    clean_start: bool,
+    keep_alive_timeout: Option<Duration>,
+    max_client_packet_size: Option<usize>,
+    delivery_config: DeliveryConfig,
+    will: Option<LastWill>,
+    outgoing: mpsc::Sender<OutgoingMessage>,
+    subscriptions: HashMap<(String, String, String), GroupSubscription>,
+    inflight: HashMap<u16, oneshot::Sender<()>>,
+    last_packet_id: u16,
+    received_packet_ids: HashSet<u16>,
+}
+
+pub(crate) async fn handle_connection(
+    mut stream: TcpStream,
+    address: SocketAddr,
+    context: Arc<MqttContext>,
+    shutdown: Shutdown,
+) {
+    let system = context.system.clone();
+    let session = system
+        .read()
+        .await
+        .add_client(&address, Transport::Mqtt)
+        .await;
+    let client_id = session.client_id;
+    info!("Created new session: {session}");
+    let mut buffer = BytesMut::with_capacity(INITIAL_BUFFER_CAPACITY);
+    let (sender, mut receiver) = mpsc::channel(context.max_inflight_messages as usize);
+    let connected = tokio::time::timeout(
+        context.connect_timeout,
+        MqttConnection::connect(&mut stream, &mut buffer, &context, &session, sender),
+    )
+    .await
+    .unwrap_or_else(|_| {
+        warn!("MQTT client: {client_id} hasn't sent the CONNECT packet in time.");
+        Ok(None)
+    });
+    match connected {
+        Ok(Some(mut connection)) => {
+            if let Err(error) = connection
+                .handle_packets(&mut stream, &mut buffer, &mut receiver, shutdown)
+                .await
+            {
+                handle_error(error);
+            }
+            connection.close().await;
+        }
+        Ok(None) => {}
+        Err(error) => handle_error(error),
+    }
+
+    system.read().await.delete_client(client_id).await;
+    if let Err(error) = stream.shutdown().await {
+        debug!(
+            "Failed to shutdown MQTT stream for client: {client_id}, address: {address}. {error}"
+        );
+    } else {
+        info!("Successfully closed MQTT stream for client: {client_id}, address: {address}.");
+    }
+}
+
+impl MqttConnection {
+    /// Reads the CONNECT packet and authenticates the client, returning the established connection,
+    /// or `None` if the connection has been refused.
+    async fn connect(
+        stream: &mut TcpStream,
+        buffer: &mut BytesMut,
+        context: &Arc<MqttContext>,
+        session: &Arc<Session>,
+        outgoing: mpsc::Sender<OutgoingMessage>,
+    ) -> Result<Option<Self>, ConnectionError> {
+        let detected_protocol = loop {
+            if let Some(protocol) = MqttCodec::detect_protocol(buffer, context.max_packet_size)? {
+                break protocol;
+            }
+            if stream.read_buf(buffer).await? == 0 {
+                return Err(ConnectionError::from(IggyError::ConnectionClosed));
+            }
+        };
+        let DetectedProtocol::Supported(version) = detected_protocol else {
+            warn!(
+                "MQTT client: {} uses unsupported protocol version.",
+                session.client_id
+            );
+            let codec = MqttCodec::new(ProtocolVersion::V4, context.max_packet_size);
+            send(
+                stream,
+                &codec,
+                connack(ConnectReturnCode::UnsupportedProtocolVersion, None),
+            )
+            .await?;
+            return Ok(None);
+        };
+
+        let codec = MqttCodec::new(version, context.max_packet_size);
+        let Some(Packet::Connect(connect, will, login)) = codec.read(buffer)? else {
+            return Err(ConnectionError::from(IggyError::InvalidCommand));
+        };
+
+        let mut client_id = connect.client_id;
+        let mut assigned_client_id = None;
+        if client_id.is_empty() {
+            // MQTT 3.1.1 allows the empty client ID only for the clean sessions.
+            if version == ProtocolVersion::V4 && !connect.clean_start {
+                send(
+                    stream,
+                    &codec,
+                    connack(ConnectReturnCode::BadClientId, None),
+                )
+                .await?;
+                return Ok(None);
+            }
+            client_id = format!(
+                "{GENERATED_CLIENT_ID_PREFIX}{}",
+                uuid::Uuid::now_v7().simple()
+            );
+            assigned_client_id = Some(client_id.clone());
+        }
+
+        if let Err(code) = authenticate(context, session, &client_id, login).await {
+            send(stream, &codec, connack(code, None)).await?;
+            return Ok(None);
+        }
+
+        let properties = connect.properties.unwrap_or_default();
+        let max_inflight_messages = properties
+            .receive_maximum
+            .filter(|receive_maximum| *receive_maximum > 0)
+            .map_or(context.max_inflight_messages, |receive_maximum| {
+                receive_maximum.min(context.max_inflight_messages)
+            });
+        let connection = Self {
+            context: context.clone(),
+            session: session.clone(),
+            codec,
+            client_id,
+            clean_start: connect.clean_start,
+            // The client is disconnected if no packet is received within one and a half times the keep alive.
+            keep_alive_timeout: (connect.keep_alive > 0)
+                .then(|| Duration::from_millis(connect.keep_alive as u64 * 1500)),
+            max_client_packet_size: properties.max_packet_size.map(|size| size as usize),
+            delivery_config: DeliveryConfig {
+                batch_size: max_inflight_messages as u32,
+                ..context.delivery_config
+            },
+            will,
+            outgoing,
+            subscriptions: HashMap::new(),
+            inflight: HashMap::new(),
+            last_packet_id: 0,
+            received_packet_ids: HashSet::new(),
+        };
+
+        let previous_session = context
+            .sessions
+            .lock()
+            .unwrap()
+            .insert(connection.client_id.clone(), session.clone());
+        if let Some(previous_session) = previous_session {
+            info!(
+                "MQTT client ID: {} has connected again, closing the previous connection of client: {}.",
+                connection.client_id, previous_session.client_id
+            );
+            previous_session.disconnect();
+        }
+
+        info!(
+            "MQTT client: {} has connected with client ID: {}, protocol: {version:?}.",
+            session.client_id, connection.client_id
+        );
+        send(
+            stream,
+            &codec,
+            connack(
+                ConnectReturnCode::Success,
+                Some(connection.connack_properties(assigned_client_id)),
+            ),
+        )
+        .await?;
+        Ok(Some(connection))
+    }
+
+    async fn handle_packets(
+        &mut self,
+        stream: &mut TcpStream,
+        buffer: &mut BytesMut,
+        outgoing: &mut mpsc::Receiver<OutgoingMessage>,
+        shutdown: Shutdown,
+    ) -> Result<(), ConnectionError> {
+        let mut last_packet_at = Instant::now();
+        loop {
+            while let Some(packet) = self.codec.read(buffer)? {
+                last_packet_at = Instant::now();
+                if !self.handle_packet(packet, stream).await? {
+                    return Ok(());
+                }
+            }
+
+            let keep_alive_timeout = self.keep_alive_timeout;
+            let keep_alive_expired = async move {
+                match keep_alive_timeout {
+                    Some(timeout) => tokio::time::sleep_until(last_packet_at + timeout).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    self.disconnect(stream, DisconnectReasonCode::ServerShuttingDown).await;
+                    return Err(ConnectionError::from(IggyError::ServerShuttingDown));
+                }
+                _ = self.session.disconnected() => {
+                    self.disconnect(stream, DisconnectReasonCode::SessionTakenOver).await;
+                    return Err(ConnectionError::from(IggyError::ClientDisconnected(
+                        self.session.client_id,
+                    )));
+                }
+                _ = keep_alive_expired => {
+                    warn!("MQTT client: {} hasn't sent any packet within the keep alive, closing the connection.", self.client_id);
+                    self.disconnect(stream, DisconnectReasonCode::KeepAliveTimeout).await;
+                    return Err(ConnectionError::from(IggyError::ConnectionClosed));
+                }
+                Some(message) = outgoing.recv() => {
+                    self.send_message(message, stream).await?;
+                }
+                read_result = stream.read_buf(buffer) => {
+                    if read_result? == 0 {
+                        return Err(ConnectionError::from(IggyError::ConnectionClosed));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Handles the packet and returns `false` if the client has disconnected.
+    async fn handle_packet(
+        &mut self,
+        packet: Packet,
+        stream: &mut TcpStream,
+    ) -> Result<bool, ConnectionError> {
+        match packet {
+            Packet::Publish(publish) => self.handle_publish(publish, stream).await?,
+            Packet::PubAck(ack) => self.acknowledge(ack.pkid),
+            Packet::PubRec(ack) => {
+                // QoS 2 isn't used for the delivered messages, but the flow is completed anyway.
+                self.acknowledge(ack.pkid);
+                self.send(Packet::PubRel(PubRel::new(ack.pkid, None)), stream)
+                    .await?;
+            }
+            Packet::PubRel(ack) => {
+                self.received_packet_ids.remove(&ack.pkid);
+                self.send(Packet::PubComp(PubComp::new(ack.pkid, None)), stream)
+                    .await?;
+            }
+            Packet::PubComp(_) => {}
+            Packet::Subscribe(subscribe) => {
+                let mut return_codes = Vec::with_capacity(subscribe.filters.len());
+                for filter in subscribe.filters {
+                    return_codes.push(self.subscribe(filter).await);
+                }
+                self.send(
+                    Packet::SubAck(SubAck {
+                        pkid: subscribe.pkid,
+                        return_codes,
+                        properties: None,
+                    }),
+                    stream,
+                )
+                .await?;
+            }
+            Packet::Unsubscribe(unsubscribe) => {
+                let mut reasons = Vec::with_capacity(unsubscribe.filters.len());
+                for filter in unsubscribe.filters {
+                    reasons.push(self.unsubscribe(&filter).await);
+                }
+                self.send(
+                    Packet::UnsubAck(UnsubAck {
+                        pkid: unsubscribe.pkid,
+                        reasons,
+                        properties: None,
+                    }),
+                    stream,
+                )
+                .await?;
+            }
+            Packet::PingReq(_) => self.send(Packet::PingResp(PingResp), stream).await?,
+            Packet::Disconnect(disconnect) => {
+                if disconnect.reason_code != DisconnectReasonCode::DisconnectWithWillMessage {
+                    self.will = None;
+                }
+                info!(
+                    "MQTT client: {} with client ID: {} has disconnected.",
+                    self.session.client_id, self.client_id
+                );
+                return Ok(false);
+            }
+            packet => {
+                warn!(
+                    "Received unexpected MQTT packet: {packet:?} from client: {}, closing the connection.",
+                    self.client_id
+                );
+                return Err(ConnectionError::from(IggyError::InvalidCommand));
+            }
+        }
+        Ok(true)
+    }
+
+    /// Appends the published message and acknowledges it according to its QoS.
+    /// The failures of MQTT 3.1.1 clients can't be reported, so the connection is closed instead.
+    async fn handle_publish(
+        &mut self,
+        publish: Publish,
+        stream: &mut TcpStream,
+    ) -> Result<(), ConnectionError> {
+        // The message has been already appended, and the client hasn't received the PUBREC.
+        if publish.qos == QoS::ExactlyOnce && self.received_packet_ids.contains(&publish.pkid) {
+            return self
+                .send(Packet::PubRec(PubRec::new(publish.pkid, None)), stream)
+                .await;
+        }
+
+        let result = self.append(&publish).await;
+        if result.is_err() {
+            if publish.qos == QoS::AtMostOnce {
+                return Ok(());
+            }
+            if self.codec.version == ProtocolVersion::V4 {
+                return Err(ConnectionError::from(IggyError::InvalidCommand));
+            }
+        }
+
+        match publish.qos {
+            QoS::AtMostOnce => Ok(()),
+            QoS::AtLeastOnce => {
+                let mut ack = PubAck::new(publish.pkid, None);
+                if let Err(reason) = result {
+                    ack.reason = reason;
+                }
+                self.send(Packet::PubAck(ack), stream).await
+            }
+            QoS::ExactlyOnce => {
+                let mut ack = PubRec::new(publish.pkid, None);
+                match result {
+                    Ok(()) => {
+                        self.received_packet_ids.insert(publish.pkid);
+                    }
+                    Err(reason) => ack.reason = to_pubrec_reason(reason),
+                }
+                self.send(Packet::PubRec(ack), stream).await
+            }
+        }
+    }
+
+    async fn append(&self, publish: &Publish) -> Result<(), PubAckReason> {
+        let topic = std::str::from_utf8(&publish.topic)
+            .ok()
+            .filter(|topic| !topic.is_empty() && valid_topic(topic))
+            .ok_or(PubAckReason::TopicNameInvalid)?;
+        let (stream_id, topic_id) = resolve_names(self.context.mappings.resolve_topic(topic))
+            .ok_or_else(|| {
+                warn!(
+                    "MQTT topic: {topic} published by client: {} isn't mapped onto any Iggy topic.",
+                    self.client_id
+                );
+                PubAckReason::TopicNameInvalid
+            })?;
+
+        let result = async {
+            let message = messages::to_message(topic, publish)?;
+            // The messages published to the same MQTT topic are appended to the same partition, preserving their order.
+            let partitioning = Partitioning::messages_key_str(topic)?;
+            self.context
+                .system
+                .read()
+                .await
+                .append_messages(
+                    &self.session,
+                    stream_id,
+                    topic_id,
+                    partitioning,
+                    vec![message],
+                    None,
+                )
+                .await
+        }
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => {
+                warn!(
+                    "Failed to append message published to MQTT topic: {topic} by client: {}. {error}",
+                    self.client_id
+                );
+                Err(map_error(&error))
+            }
+        }
+    }
+
+    async fn subscribe(&mut self, filter: Filter) -> SubscribeReasonCode {
+        let (group_name, topic_filter) = match filter.path.strip_prefix(SHARED_SUBSCRIPTION_PREFIX)
+        {
+            Some(shared) => match shared.split_once('/') {
+                Some((group, topic_filter)) if !group.is_empty() && !group.contains(['+', '#']) => {
+                    (group.to_owned(), topic_filter)
+                }
+                _ => return SubscribeReasonCode::TopicFilterInvalid,
+            },
+            None => (
+                format!("{CLIENT_GROUP_PREFIX}{}", self.client_id),
+                filter.path.as_str(),
+            ),
+        };
+        if !valid_filter(topic_filter) {
+            return SubscribeReasonCode::TopicFilterInvalid;
+        }
+        let Some(resolved) = self.context.mappings.resolve_filter(topic_filter) else {
+            warn!(
+                "MQTT filter: {topic_filter} of client: {} isn't mapped onto a single Iggy topic.",
+                self.client_id
+            );
+            return SubscribeReasonCode::TopicFilterInvalid;
+        };
+        let Some((stream_id, topic_id)) =
+            resolve_names(Some((resolved.stream.clone(), resolved.topic.clone())))
+        else {
+            return SubscribeReasonCode::TopicFilterInvalid;
+        };
+
+        // QoS 2 isn't supported for the delivered messages, so it's downgraded to QoS 1.
+        let qos = if filter.qos == QoS::AtMostOnce {
+            QoS::AtMostOnce
+        } else {
+            QoS::AtLeastOnce
+        };
+        let subscription_filter = SubscriptionFilter {
+            name: filter.path.clone(),
+            filter: topic_filter.to_owned(),
+            qos,
+            default_topic: resolved.default_topic,
+        };
+        let key = (group_name, resolved.stream, resolved.topic);
+        if let Some(subscription) = self.subscriptions.get(&key) {
+            subscription.add_filter(subscription_filter);
+            return SubscribeReasonCode::Success(qos);
+        }
+
+        let shared = filter.path.starts_with(SHARED_SUBSCRIPTION_PREFIX);
+        match GroupSubscription::start(
+            &self.context.system,
+            &self.session,
+            stream_id,
+            topic_id,
+            &key.0,
+            !shared && self.clean_start,
+            subscription_filter,
+            self.outgoing.clone(),
+            self.delivery_config,
+        )
+        .await
+        {
+            Ok(subscription) => {
+                self.subscriptions.insert(key, subscription);
+                SubscribeReasonCode::Success(qos)
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to subscribe MQTT client: {} to filter: {}. {error}",
+                    self.client_id, filter.path
+                );
+                match map_error(&error) {
+                    PubAckReason::NotAuthorized => SubscribeReasonCode::NotAuthorized,
+                    PubAckReason::TopicNameInvalid => SubscribeReasonCode::TopicFilterInvalid,
+                    _ => SubscribeReasonCode::Unspecified,
+                }
+            }
+        }
+    }
+
+    async fn unsubscribe(&mut self, filter: &str) -> UnsubAckReason {
+        let Some(key) = self
+            .subscriptions
+            .iter()
+            .find(|(_, subscription)| subscription.has_filter(filter))
+            .map(|(key, _)| key.clone())
+        else {
+            return UnsubAckReason::NoSubscriptionExisted;
+        };
+
+        if self.subscriptions[&key].remove_filter(filter) {
+            let subscription = self.subscriptions.remove(&key).unwrap();
+            subscription.stop(&self.context.system, &self.session).await;
+        }
+        UnsubAckReason::Success
+    }
+
+    async fn send_message(
+        &mut self,
+        message: OutgoingMessage,
+        stream: &mut TcpStream,
+    ) -> Result<(), ConnectionError> {
+        let mut publish = message.publish;
+        if let Some(max_packet_size) = self.max_client_packet_size {
+            if publish.size() > max_packet_size {
+                warn!(
+                    "Message exceeds the maximum packet size: {max_packet_size} of MQTT client: {}, it won't be delivered.",
+                    self.client_id
+                );
+                if let Some(acknowledged) = message.acknowledged {
+                    let _ = acknowledged.send(());
+                }
+                return Ok(());
+            }
+        }
+
+        if let Some(acknowledged) = message.acknowledged {
+            publish.pkid = self.next_packet_id();
+            self.inflight.insert(publish.pkid, acknowledged);
+        }
+        self.send(Packet::Publish(publish), stream).await
+    }
+
+    fn acknowledge(&mut self, packet_id: u16) {
+        if let Some(acknowledged) = self.inflight.remove(&packet_id) {
+            let _ = acknowledged.send(());
+        }
+    }
+
+    fn next_packet_id(&mut self) -> u16 {
+        loop {
+            self.last_packet_id = self.last_packet_id.checked_add(1).unwrap_or(1);
+            if !self.inflight.contains_key(&self.last_packet_id) {
+                return self.last_packet_id;
+            }
+        }
+    }
+
+    async fn send(&self, packet: Packet, stream: &mut TcpStream) -> Result<(), ConnectionError> {
+        send(stream, &self.codec, packet).await
+    }
+
+    /// Notifies MQTT 5 client about the reason of closing the connection.
+    async fn disconnect(&self, stream: &mut TcpStream, reason: DisconnectReasonCode) {
+        if let Err(error) = self
+            .send(Packet::Disconnect(Disconnect::new(reason)), stream)
+            .await
+        {
+            debug!(
+                "Failed to send DISCONNECT to MQTT client: {}. {error}",
+                self.client_id
+            );
+        }
+    }
+
+    /// Stops the subscriptions and publishes the will message, unless the client has disconnected gracefully.
+    async fn close(&mut self) {
+        for (_, subscription) in self.subscriptions.drain() {
+            subscription.stop(&self.context.system, &self.session).await;
+        }
+
+        {
+            let mut sessions = self.context.sessions.lock().unwrap();
+            if sessions
+                .get(&self.client_id)
+                .is_some_and(|session| Arc::ptr_eq(session, &self.session))
+            {
+                sessions.remove(&self.client_id);
+            }
+        }
+
+        let Some(will) = self.will.take() else {
+            return;
+        };
+        let publish = Publish {
+            dup: false,
+            qos: QoS::AtMostOnce,
+            retain: will.retain,
+            topic: will.topic,
+            pkid: 0,
+            payload: will.message,
+            properties: will.properties.map(|properties| PublishProperties {
+                payload_format_indicator: properties.payload_format_indicator,
+                message_expiry_interval: properties.message_expiry_interval,
+                content_type: properties.content_type,
+                response_topic: properties.response_topic,
+                correlation_data: properties.correlation_data,
+                user_properties: properties.user_properties,
+                ..Default::default()
+            }),
+        };
+        if self.append(&publish).await.is_ok() {
+            info!("Published will message of MQTT client: {}.", self.client_id);
+        }
+    }
+
+    fn connack_properties(&self, assigned_client_id: Option<String>) -> ConnAckProperties {
+        ConnAckProperties {
+            session_expiry_interval: None,
+            receive_max: None,
+            max_qos: None,
+            retain_available: Some(0),
+            max_packet_size: Some(self.context.max_packet_size as u32),
+            assigned_client_identifier: assigned_client_id,
+            topic_alias_max: None,
+            reason_string: None,
+            user_properties: Vec::new(),
+            wildcard_subscription_available: Some(1),
+            subscription_identifiers_available: Some(0),
+            shared_subscription_available: Some(1),
+            server_keep_alive: None,
+            response_information: None,
+            server_reference: None,
+            authentication_method: None,
+            authentication_data: None,
+        }
+    }
+}
+
+async fn authenticate(
+    context: &MqttContext,
+    session: &Session,
+    client_id: &str,
+    login: Option<Login>,
+) -> Result<(), ConnectReturnCode> {
+    let Some(login) = login else {
+        warn!("MQTT client ID: {client_id} hasn't provided the credentials.");
+        return Err(ConnectReturnCode::NotAuthorized);
+    };
+
+    let system = context.system.read().await;
+    match system
+        .login_user(&login.username, &login.password, Some(session))
+        .await
+    {
+        Ok(user) => {
+            info!(
+                "MQTT client: {} has been authenticated as user with ID: {}.",
+                session.client_id, user.id
+            );
+            Ok(())
+        }
+        Err(error) => {
+            warn!("Failed to authenticate MQTT client ID: {client_id}. {error}");
+            Err(ConnectReturnCode::BadUserNamePassword)
+        }
+    }
+}
+
+async fn send(
+    stream: &mut TcpStream,
+    codec: &MqttCodec,
+    packet: Packet,
+) -> Result<(), ConnectionError> {
+    let mut buffer = BytesMut::new();
+    codec.write(packet, &mut buffer)?;
+    if !buffer.is_empty() {
+        stream.write_all(&buffer).await?;
+    }
+    Ok(())
+}
+
+fn connack(code: ConnectReturnCode, properties: Option<ConnAckProperties>) -> Packet {
+    Packet::ConnAck(ConnAck {
+        session_present: false,
+        code,
+        properties,
+    })
+}
+
+fn resolve_names(names: Option<(String, String)>) -> Option<(Identifier, Identifier)> {
+    let (stream, topic) = names?;
+    Some((
+        Identifier::named(&stream).ok()?,
+        Identifier::named(&topic).ok()?,
+    ))
+}
+
+/// Maps the Iggy error onto the closest MQTT 5 reason code.
+fn map_error(error: &IggyError) -> PubAckReason {
+    match error {
+        IggyError::Unauthenticated | IggyError::Unauthorized => PubAckReason::NotAuthorized,
+        IggyError::StreamIdNotFound(_)
+        | IggyError::StreamNameNotFound(_)
+        | IggyError::TopicIdNotFound(..)
+        | IggyError::TopicNameNotFound(..)
+        | IggyError::NoPartitions(..) => PubAckReason::TopicNameInvalid,
+        IggyError::TooBigMessagePayload
+        | IggyError::TooBigHeadersPayload
+        | IggyError::InvalidHeaderKey
+        | IggyError::InvalidHeaderValue => PubAckReason::QuotaExceeded,
+        IggyError::MessagePayloadSchemaViolation(..) => PubAckReason::PayloadFormatInvalid,
+        _ => PubAckReason::ImplementationSpecificError,
+    }
+}
+
+fn to_pubrec_reason(reason: PubAckReason) -> PubRecReason {
+    match reason {
+        PubAckReason::Success => PubRecReason::Success,
+        PubAckReason::NoMatchingSubscribers => PubRecReason::NoMatchingSubscribers,
+        PubAckReason::UnspecifiedError => PubRecReason::UnspecifiedError,
+        PubAckReason::ImplementationSpecificError => PubRecReason::ImplementationSpecificError,
+        PubAckReason::NotAuthorized => PubRecReason::NotAuthorized,
+        PubAckReason::TopicNameInvalid => PubRecReason::TopicNameInvalid,
+        PubAckReason::PacketIdentifierInUse => PubRecReason::PacketIdentifierInUse,
+        PubAckReason::QuotaExceeded => PubRecReason::QuotaExceeded,
+        PubAckReason::PayloadFormatInvalid => PubRecReason::PayloadFormatInvalid,
+    }
+}
@@ -0,0 +1,486 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashMap;
+
+const TARGET_SEPARATOR: &str = "=>";
+const LEVEL_SEPARATOR: char = '/';
+const SINGLE_LEVEL_WILDCARD: &str = "+";
+const MULTI_LEVEL_WILDCARD: &str = "#";
+const STREAM_CAPTURE: &str = "stream";
+const TOPIC_CAPTURE: &str = "topic";
+
+/// Maps the MQTT topics and the subscription filters onto the Iggy streams and topics.
+///
+/// Each mapping consists of the pattern matched against the MQTT topic level by level,
+/// optionally followed by the `=> <stream>/<topic>` target referring to the captured levels, e.g.
+/// `devices/{device}/{kind} => telemetry/{kind}`. The pattern without the target must capture
+/// both the `{stream}` and the `{topic}` levels. The mappings are checked in order.
+#[derive(Debug, Clone)]
+pub(crate) struct TopicMappings {
+    mappings: Vec<TopicMapping>,
+}
+
+/// The Iggy topic resolved from the subscription filter.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResolvedFilter {
+    pub stream: String,
+    pub topic: String,
+    /// The MQTT topic of the messages without the original MQTT topic, e.g. appended by the Iggy clients,
+    /// available only if it can be rendered from the pattern and the filter.
+    pub default_topic: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct TopicMapping {
+    pattern: Vec<PatternLevel>,
+    stream: Vec<NamePart>,
+    topic: Vec<NamePart>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PatternLevel {
+    Literal(String),
+    Capture(String),
+    SingleLevelWildcard,
+    MultiLevelWildcard,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum NamePart {
+    Literal(String),
+    Capture(String),
+}
+
+impl TopicMappings {
+    pub fn parse(mappings: &[String]) -> Result<Self, String> {
+        if mappings.is_empty() {
+            return Err("at least one topic mapping is required".to_owned());
+        }
+
+        let mappings = mappings
+            .iter()
+            .map(|mapping| {
+                TopicMapping::parse(mapping)
+                    .map_err(|error| format!("invalid topic mapping: '{mapping}', {error}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { mappings })
+    }
+
+    /// Resolves the MQTT topic name into the stream and the topic name, using the first matching mapping.
+    pub fn resolve_topic(&self, topic: &str) -> Option<(String, String)> {
+        let levels = topic.split(LEVEL_SEPARATOR).collect::<Vec<_>>();
+        self.mappings.iter().find_map(|mapping| {
+            let captures = mapping.match_topic(&levels)?;
+            mapping.render_target(&captures)
+        })
+    }
+
+    /// Resolves the subscription filter into the single Iggy topic, using the first matching mapping.
+    /// The levels captured into the stream or the topic name can't be matched by the wildcards of the filter.
+    pub fn resolve_filter(&self, filter: &str) -> Option<ResolvedFilter> {
+        let levels = filter.split(LEVEL_SEPARATOR).collect::<Vec<_>>();
+        self.mappings.iter().find_map(|mapping| {
+            let captures = mapping.match_filter(&levels)?;
+            let (stream, topic) = mapping.render_target(&captures)?;
+            let default_topic = if is_wildcard_filter(filter) {
+                mapping
+                    .render_topic(&captures)
+                    .filter(|topic| matches(filter, topic))
+            } else {
+                Some(filter.to_owned())
+            };
+            Some(ResolvedFilter {
+                stream,
+                topic,
+                default_topic,
+            })
+        })
+    }
+}
+
+impl TopicMapping {
+    fn parse(mapping: &str) -> Result<Self, String> {
+        let (pattern, target) = match mapping.split_once(TARGET_SEPARATOR) {
+            Some((pattern, target)) => (pattern.trim(), Some(target.trim())),
+            None => (mapping.trim(), None),
+        };
+        if pattern.is_empty() {
+            return Err("the pattern cannot be empty".to_owned());
+        }
+
+        let levels = pattern.split(LEVEL_SEPARATOR).collect::<Vec<_>>();
+        let mut pattern = Vec::with_capacity(levels.len());
+        for (index, level) in levels.iter().enumerate() {
+            let level = match *level {
+                SINGLE_LEVEL_WILDCARD => PatternLevel::SingleLevelWildcard,
+                MULTI_LEVEL_WILDCARD if index == levels.len() - 1 => {
+                    PatternLevel::MultiLevelWildcard
+                }
+                MULTI_LEVEL_WILDCARD => {
+                    return Err("the '#' wildcard must be the last level".to_owned())
+                }
+                level => match parse_capture(level)? {
+                    Some(name) => {
+                        if pattern.contains(&PatternLevel::Capture(name.to_owned())) {
+                            return Err(format!(
+                                "the level '{{{name}}}' is captured more than once"
+                            ));
+                        }
+                        PatternLevel::Capture(name.to_owned())
+                    }
+                    None if level.contains(['+', '#']) => {
+                        return Err(format!(
+                            "the wildcard must occupy the entire level: '{level}'"
+                        ))
+                    }
+                    None => PatternLevel::Literal(level.to_owned()),
+                },
+            };
+            pattern.push(level);
+        }
+
+        let (stream, topic) = match target {
+            Some(target) => {
+                let Some((stream, topic)) = target.split_once(LEVEL_SEPARATOR) else {
+                    return Err("the target must be in the <stream>/<topic> format".to_owned());
+                };
+                (parse_name(stream)?, parse_name(topic)?)
+            }
+            None => (
+                vec![NamePart::Capture(STREAM_CAPTURE.to_owned())],
+                vec![NamePart::Capture(TOPIC_CAPTURE.to_owned())],
+            ),
+        };
+
+        for part in stream.iter().chain(topic.iter()) {
+            if let NamePart::Capture(name) = part {
+                if !pattern.contains(&PatternLevel::Capture(name.clone())) {
+                    return Err(format!(
+                        "the level '{{{name}}}' isn't captured by the pattern"
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            pattern,
+            stream,
+            topic,
+        })
+    }
+
+    fn match_topic<'a>(&self, levels: &[&'a str]) -> Option<HashMap<&str, &'a str>> {
+        let mut captures = HashMap::new();
+        for (index, pattern_level) in self.pattern.iter().enumerate() {
+            if *pattern_level == PatternLevel::MultiLevelWildcard {
+                return Some(captures);
+            }
+
+            let level = levels.get(index)?;
+            match pattern_level {
+                PatternLevel::Literal(literal) if literal != level => return None,
+                PatternLevel::Capture(name) => {
+                    captures.insert(name.as_str(), *level);
+                }
+                _ => {}
+            }
+        }
+        (levels.len() == self.pattern.len()).then_some(captures)
+    }
+
+    fn match_filter<'a>(&self, levels: &[&'a str]) -> Option<HashMap<&str, &'a str>> {
+        let mut captures = HashMap::new();
+        for (index, pattern_level) in self.pattern.iter().enumerate() {
+            if *pattern_level == PatternLevel::MultiLevelWildcard {
+                return Some(captures);
+            }
+
+            let Some(level) = levels.get(index) else {
+                // The filter `a/b` matches the topic `a/b`, which is also matched by the pattern `a/b/#`.
+                return (self.pattern[index..] == [PatternLevel::MultiLevelWildcard])
+                    .then_some(captures);
+            };
+            if *level == MULTI_LEVEL_WILDCARD {
+                let captures_target = self.pattern[index..].iter().any(|pattern_level| {
+                    matches!(pattern_level, PatternLevel::Capture(name) if self.is_target(name))
+                });
+                return (!captures_target).then_some(captures);
+            }
+
+            match pattern_level {
+                PatternLevel::Literal(literal)
+                    if *level != SINGLE_LEVEL_WILDCARD && literal != level =>
+                {
+                    return None
+                }
+                PatternLevel::Capture(name)
+                    if *level == SINGLE_LEVEL_WILDCARD && self.is_target(name) =>
+                {
+                    return None
+                }
+                PatternLevel::Capture(_) if *level == SINGLE_LEVEL_WILDCARD => {}
+                PatternLevel::Capture(name) => {
+                    captures.insert(name.as_str(), *level);
+                }
+                _ => {}
+            }
+        }
+        (levels.len() == self.pattern.len()).then_some(captures)
+    }
+
+    fn is_target(&self, name: &str) -> bool {
+        self.stream
+            .iter()
+            .chain(self.topic.iter())
+            .any(|part| matches!(part, NamePart::Capture(capture) if capture == name))
+    }
+
+    fn render_target(&self, captures: &HashMap<&str, &str>) -> Option<(String, String)> {
+        let stream = render_name(&self.stream, captures)?;
+        let topic = render_name(&self.topic, captures)?;
+        Some((stream, topic))
+    }
+
+    /// Renders the MQTT topic matched by the pattern, skipping the trailing `#` wildcard.
+    fn render_topic(&self, captures: &HashMap<&str, &str>) -> Option<String> {
+        let mut levels = Vec::with_capacity(self.pattern.len());
+        for pattern_level in &self.pattern {
+            match pattern_level {
+                PatternLevel::Literal(literal) => levels.push(literal.as_str()),
+                PatternLevel::Capture(name) => levels.push(captures.get(name.as_str())?),
+                PatternLevel::SingleLevelWildcard => return None,
+                PatternLevel::MultiLevelWildcard => break,
+            }
+        }
+        Some(levels.join("/"))
+    }
+}
+
+/// Checks whether the MQTT topic is matched by the subscription filter.
+pub(crate) fn matches(filter: &str, topic: &str) -> bool {
+    // The topics starting with `$` aren't matched by the filters starting with a wildcard.
+    if topic.starts_with('$') && filter.starts_with(['+', '#']) {
+        return false;
+    }
+
+    let mut topic_levels = topic.split(LEVEL_SEPARATOR);
+    for filter_level in filter.split(LEVEL_SEPARATOR) {
+        if filter_level == MULTI_LEVEL_WILDCARD {
+            return true;
+        }
+
+        match topic_levels.next() {
+            Some(topic_level)
+                if filter_level == SINGLE_LEVEL_WILDCARD || filter_level == topic_level => {}
+            _ => return false,
+        }
+    }
+    topic_levels.next().is_none()
+}
+
+pub(crate) fn is_wildcard_filter(filter: &str) -> bool {
+    filter.contains(['+', '#'])
+}
+
+fn parse_capture(level: &str) -> Result<Option<&str>, String> {
+    if !level.contains(['{', '}']) {
+        return Ok(None);
+    }
+
+    match level
+        .strip_prefix('{')
+        .and_then(|level| level.strip_suffix('}'))
+    {
+        Some(name) if !name.is_empty() && !name.contains(['{', '}']) => Ok(Some(name)),
+        _ => Err(format!(
+            "the capture must occupy the entire level: '{level}'"
+        )),
+    }
+}
+
+fn parse_name(name: &str) -> Result<Vec<NamePart>, String> {
+    let mut parts = Vec::new();
+    let mut remaining = name.trim();
+    while !remaining.is_empty() {
+        match remaining.find('{') {
+            Some(0) => {
+                let Some(end) = remaining.find('}') else {
+                    return Err(format!("unclosed capture in the name: '{name}'"));
+                };
+                let capture = &remaining[1..end];
+                if capture.is_empty() || capture.contains('{') {
+                    return Err(format!("invalid capture in the name: '{name}'"));
+                }
+                parts.push(NamePart::Capture(capture.to_owned()));
+                remaining = &remaining[end + 1..];
+            }
+            Some(start) => {
+                parts.push(NamePart::Literal(remaining[..start].to_owned()));
+                remaining = &remaining[start..];
+            }
+            None => {
+                if remaining.contains('}') {
+                    return Err(format!("invalid capture in the name: '{name}'"));
+                }
+                parts.push(NamePart::Literal(remaining.to_owned()));
+                remaining = "";
+            }
+        }
+    }
+
+    if parts.is_empty() {
+        return Err("the stream and the topic name cannot be empty".to_owned());
+    }
+    Ok(parts)
+}
+
+fn render_name(parts: &[NamePart], captures: &HashMap<&str, &str>) -> Option<String> {
+    let mut name = String::new();
+    for part in parts {
+        match part {
+            NamePart::Literal(literal) => name.push_str(literal),
+            NamePart::Capture(capture) => name.push_str(captures.get(capture.as_str())?),
+        }
+    }
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mappings(mappings: &[&str]) -> TopicMappings {
+        let mappings = mappings
+            .iter()
+            .map(|mapping| mapping.to_string())
+            .collect::<Vec<_>>();
+        TopicMappings::parse(&mappings).unwrap()
+    }
+
+    fn resolved(stream: &str, topic: &str) -> Option<(String, String)> {
+        Some((stream.to_owned(), topic.to_owned()))
+    }
+
+    #[test]
+    fn should_resolve_topic_using_default_mapping() {
+        let mappings = mappings(&["{stream}/{topic}/#"]);
+        assert_eq!(
+            mappings.resolve_topic("iot/readings"),
+            resolved("iot", "readings")
+        );
+        assert_eq!(
+            mappings.resolve_topic("iot/readings/sensor-1/temperature"),
+            resolved("iot", "readings")
+        );
+        assert_eq!(mappings.resolve_topic("iot"), None);
+        assert_eq!(mappings.resolve_topic("/readings"), None);
+    }
+
+    #[test]
+    fn should_resolve_topic_using_first_matching_mapping() {
+        let mappings = mappings(&[
+            "devices/{device}/{kind} => telemetry/{kind}",
+            "factory/+/alerts/# => factory/alerts",
+            "{stream}/{topic}",
+        ]);
+        assert_eq!(
+            mappings.resolve_topic("devices/sensor-1/temperature"),
+            resolved("telemetry", "temperature")
+        );
+        assert_eq!(
+            mappings.resolve_topic("factory/line-1/alerts/fire"),
+            resolved("factory", "alerts")
+        );
+        assert_eq!(
+            mappings.resolve_topic("devices/sensor-1"),
+            resolved("devices", "sensor-1")
+        );
+        assert_eq!(mappings.resolve_topic("a/b/c/d"), None);
+    }
+
+    #[test]
+    fn should_render_names_combining_literals_and_captures() {
+        let mappings = mappings(&["sites/{site}/{kind} => site-{site}/{kind}-raw"]);
+        assert_eq!(
+            mappings.resolve_topic("sites/berlin/power"),
+            resolved("site-berlin", "power-raw")
+        );
+    }
+
+    #[test]
+    fn should_resolve_filter_to_single_topic() {
+        let mappings = mappings(&[
+            "devices/{device}/{kind} => telemetry/{kind}",
+            "{stream}/{topic}/#",
+        ]);
+        let filter = mappings.resolve_filter("devices/+/temperature").unwrap();
+        assert_eq!(filter.stream, "telemetry");
+        assert_eq!(filter.topic, "temperature");
+        assert_eq!(filter.default_topic, None);
+
+        let filter = mappings.resolve_filter("iot/readings/#").unwrap();
+        assert_eq!(filter.stream, "iot");
+        assert_eq!(filter.topic, "readings");
+        assert_eq!(filter.default_topic.as_deref(), Some("iot/readings"));
+
+        let filter = mappings.resolve_filter("iot/readings/sensor-1").unwrap();
+        assert_eq!(
+            filter.default_topic.as_deref(),
+            Some("iot/readings/sensor-1")
+        );
+
+        assert!(mappings.resolve_filter("devices/+/+").is_none());
+        assert!(mappings.resolve_filter("iot/+/sensor-1").is_none());
+        assert!(mappings.resolve_filter("#").is_none());
+    }
+
+    #[test]
+    fn should_reject_invalid_mappings() {
+        for mapping in [
+            "",
+            "devices/#/temperature",
+            "devices/{device}",
+            "devices/{device}/{kind} => telemetry",
+            "devices/{device}/{kind} => telemetry/{unknown}",
+            "devices/{device}/{device} => telemetry/{device}",
+            "devices/sensor+/{stream}/{topic}",
+            "devices/{device/{stream}/{topic}",
+        ] {
+            assert!(
+                TopicMappings::parse(&[mapping.to_owned()]).is_err(),
+                "mapping: '{mapping}' should be invalid"
+            );
+        }
+        assert!(TopicMappings::parse(&[]).is_err());
+    }
+
+    #[test]
+    fn should_match_topics_by_filters() {
+        assert!(matches("a/b/c", "a/b/c"));
+        assert!(matches("a/+/c", "a/b/c"));
+        assert!(matches("a/#", "a/b/c"));
+        assert!(matches("a/#", "a"));
+        assert!(matches("#", "a/b"));
+        assert!(!matches("a/+", "a/b/c"));
+        assert!(!matches("a/b/c", "a/b"));
+        assert!(!matches("#", "$SYS/uptime"));
+        assert!(matches("$SYS/#", "$SYS/uptime"));
+    }
+}
@@ -0,0 +1,216 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use iggy::models::messages::PolledMessage;
+use rumqttc::v5::mqttbytes::v5::{Publish, PublishProperties};
+use rumqttc::v5::mqttbytes::QoS;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The header holding the MQTT topic the message has been published to.
+pub(crate) const TOPIC_HEADER: &str = "mqtt-topic";
+/// The header holding the QoS the message has been published with.
+pub(crate) const QOS_HEADER: &str = "mqtt-qos";
+pub(crate) const CONTENT_TYPE_HEADER: &str = "mqtt-content-type";
+pub(crate) const RESPONSE_TOPIC_HEADER: &str = "mqtt-response-topic";
+pub(crate) const CORRELATION_DATA_HEADER: &str = "mqtt-correlation-data";
+pub(crate) const PAYLOAD_FORMAT_INDICATOR_HEADER: &str = "mqtt-payload-format-indicator";
+pub(crate) const MESSAGE_EXPIRY_INTERVAL_HEADER: &str = "mqtt-message-expiry-interval";
+
+const RESERVED_HEADERS: [&str; 7] = [
+    TOPIC_HEADER,
+    QOS_HEADER,
+    CONTENT_TYPE_HEADER,
+    RESPONSE_TOPIC_HEADER,
+    CORRELATION_DATA_HEADER,
+    PAYLOAD_FORMAT_INDICATOR_HEADER,
+    MESSAGE_EXPIRY_INTERVAL_HEADER,
+];
+
+/// Converts the published MQTT message into the Iggy message.
+///
+/// The MQTT topic and the QoS are stored in the `mqtt-topic` and `mqtt-qos` headers,
+/// the MQTT 5 properties in the `mqtt-*` headers, and the user properties as the string headers named after them.
+pub(crate) fn to_message(topic: &str, publish: &Publish) -> Result<Message, IggyError> {
+    let mut headers = HashMap::new();
+    headers.insert(HeaderKey::new(TOPIC_HEADER)?, HeaderValue::from_str(topic)?);
+    headers.insert(
+        HeaderKey::new(QOS_HEADER)?,
+        HeaderValue::from_uint8(publish.qos as u8)?,
+    );
+    if let Some(properties) = &publish.properties {
+        if let Some(content_type) = &properties.content_type {
+            headers.insert(
+                HeaderKey::new(CONTENT_TYPE_HEADER)?,
+                HeaderValue::from_str(content_type)?,
+            );
+        }
+        if let Some(response_topic) = &properties.response_topic {
+            headers.insert(
+                HeaderKey::new(RESPONSE_TOPIC_HEADER)?,
+                HeaderValue::from_str(response_topic)?,
+            );
+        }
+        if let Some(correlation_data) = &properties.correlation_data {
+            headers.insert(
+                HeaderKey::new(CORRELATION_DATA_HEADER)?,
+                HeaderValue::from_raw(correlation_data)?,
+            );
+        }
+        if let Some(payload_format_indicator) = properties.payload_format_indicator {
+            headers.insert(
+                HeaderKey::new(PAYLOAD_FORMAT_INDICATOR_HEADER)?,
+                HeaderValue::from_uint8(payload_format_indicator)?,
+            );
+        }
+        if let Some(message_expiry_interval) = properties.message_expiry_interval {
+            headers.insert(
+                HeaderKey::new(MESSAGE_EXPIRY_INTERVAL_HEADER)?,
+                HeaderValue::from_uint32(message_expiry_interval)?,
+            );
+        }
+        for (key, value) in &properties.user_properties {
+            headers.insert(HeaderKey::new(key)?, HeaderValue::from_str(value)?);
+        }
+    }
+
+    Ok(Message::new(None, publish.payload.clone(), Some(headers)))
+}
+
+/// Returns the MQTT topic and the QoS the message has been published with, if it was published by the MQTT client.
+pub(crate) fn get_topic_and_qos(message: &PolledMessage) -> (Option<&str>, Option<QoS>) {
+    let Some(headers) = &message.headers else {
+        return (None, None);
+    };
+
+    let topic = get_header(headers, TOPIC_HEADER).and_then(|value| value.as_str().ok());
+    let qos = get_header(headers, QOS_HEADER)
+        .and_then(|value| value.as_uint8().ok())
+        .and_then(rumqttc::v5::mqttbytes::qos);
+    (topic, qos)
+}
+
+/// Converts the polled Iggy message into the MQTT message delivered to the subscriber.
+/// The properties are restored from the headers for MQTT 5, while the other string headers become the user properties.
+pub(crate) fn to_publish(topic: &str, qos: QoS, message: &PolledMessage) -> Publish {
+    let mut properties = PublishProperties::default();
+    if let Some(headers) = &message.headers {
+        for (key, value) in headers {
+            match key.as_str() {
+                CONTENT_TYPE_HEADER => {
+                    properties.content_type = value.as_str().ok().map(String::from)
+                }
+                RESPONSE_TOPIC_HEADER => {
+                    properties.response_topic = value.as_str().ok().map(String::from)
+                }
+                CORRELATION_DATA_HEADER => {
+                    properties.correlation_data = Some(Bytes::copy_from_slice(&value.value))
+                }
+                PAYLOAD_FORMAT_INDICATOR_HEADER => {
+                    properties.payload_format_indicator = value.as_uint8().ok()
+                }
+                MESSAGE_EXPIRY_INTERVAL_HEADER => {
+                    properties.message_expiry_interval = value.as_uint32().ok()
+                }
+                key if RESERVED_HEADERS.contains(&key) => {}
+                key => {
+                    if value.kind == HeaderKind::String {
+                        if let Ok(value) = value.as_str() {
+                            properties
+                                .user_properties
+                                .push((key.to_owned(), value.to_owned()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Publish::new(topic, qos, message.payload.clone(), Some(properties))
+}
+
+fn get_header<'a>(
+    headers: &'a HashMap<HeaderKey, HeaderValue>,
+    key: &str,
+) -> Option<&'a HeaderValue> {
+    headers.get(&HeaderKey::new(key).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messages::MessageState;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    fn polled(message: Message) -> PolledMessage {
+        PolledMessage {
+            offset: 0,
+            state: MessageState::Available,
+            timestamp: 0,
+            id: message.id,
+            checksum: 0,
+            length: IggyByteSize::from(message.payload.len() as u64),
+            payload: message.payload,
+            headers: message.headers,
+        }
+    }
+
+    #[test]
+    fn published_message_should_be_delivered_with_the_same_properties() {
+        let properties = PublishProperties {
+            content_type: Some("application/json".to_owned()),
+            response_topic: Some("devices/sensor-1/replies".to_owned()),
+            correlation_data: Some(Bytes::from_static(b"request-1")),
+            payload_format_indicator: Some(1),
+            message_expiry_interval: Some(60),
+            user_properties: vec![("unit".to_owned(), "celsius".to_owned())],
+            ..Default::default()
+        };
+        let publish = Publish::new(
+            "iot/readings/sensor-1",
+            QoS::AtLeastOnce,
+            "21.5",
+            Some(properties.clone()),
+        );
+
+        let message = polled(to_message("iot/readings/sensor-1", &publish).unwrap());
+        assert_eq!(
+            get_topic_and_qos(&message),
+            (Some("iot/readings/sensor-1"), Some(QoS::AtLeastOnce))
+        );
+
+        let delivered = to_publish("iot/readings/sensor-1", QoS::AtMostOnce, &message);
+        assert_eq!(delivered.topic, publish.topic);
+        assert_eq!(delivered.payload, publish.payload);
+        assert_eq!(delivered.qos, QoS::AtMostOnce);
+        assert_eq!(delivered.properties, Some(properties));
+    }
+
+    #[test]
+    fn message_without_headers_should_be_delivered_without_properties() {
+        let message = polled(Message::new(None, Bytes::from_static(b"data"), None));
+        assert_eq!(get_topic_and_qos(&message), (None, None));
+
+        let delivered = to_publish("iot/readings", QoS::AtLeastOnce, &message);
+        assert_eq!(delivered.properties, Some(PublishProperties::default()));
+    }
+}
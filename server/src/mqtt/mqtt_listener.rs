@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::mqtt::MqttConfig;
+use crate::mqtt::connection::{handle_connection, MqttContext};
+use crate::mqtt::mapping::TopicMappings;
+use crate::mqtt::subscriptions::DeliveryConfig;
+use crate::shutdown::Shutdown;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tracing::{error, info};
+
+pub async fn start(config: MqttConfig, system: SharedSystem, shutdown: Shutdown) -> SocketAddr {
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(&config.address)
+            .await
+            .unwrap_or_else(|error| {
+                panic!(
+                    "Unable to start MQTT server on address: {}. {error}",
+                    config.address
+                )
+            });
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for MQTT listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for MQTT listener",
+                local_addr
+            )
+        });
+
+        // The mappings have been already validated along with the rest of the configuration.
+        let mappings = TopicMappings::parse(&config.topic_mappings)
+            .expect("Failed to parse MQTT topic mappings");
+        let max_wait = system.read().await.config.polling.max_wait;
+        let context = Arc::new(MqttContext {
+            system,
+            mappings,
+            max_packet_size: config.max_packet_size.as_bytes_usize(),
+            connect_timeout: config.connect_timeout.get_duration(),
+            max_inflight_messages: config.max_inflight_messages,
+            delivery_config: DeliveryConfig {
+                batch_size: config.max_inflight_messages as u32,
+                max_wait,
+            },
+            sessions: Default::default(),
+        });
+        loop {
+            let accepted = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    info!("MQTT server has stopped accepting new connections.");
+                    break;
+                }
+                accepted = listener.accept() => accepted,
+            };
+            match accepted {
+                Ok((stream, address)) => {
+                    info!("Accepted new MQTT connection: {address}");
+                    let context = context.clone();
+                    let connection_shutdown = shutdown.clone();
+                    shutdown.spawn(async move {
+                        handle_connection(stream, address, context, connection_shutdown).await
+                    });
+                }
+                Err(error) => error!("Unable to accept MQTT socket. {error}"),
+            }
+        }
+    });
+    match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for MQTT listener."),
+    }
+}
@@ -0,0 +1,389 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::mqtt::mapping::matches;
+use crate::mqtt::messages;
+use crate::mqtt::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::state::models::CreateConsumerGroupWithId;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::messages::PolledMessage;
+use iggy::utils::duration::IggyDuration;
+use rumqttc::v5::mqttbytes::v5::Publish;
+use rumqttc::v5::mqttbytes::QoS;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// The message delivered to the subscriber, with the sender notified once it's acknowledged in case of QoS 1.
+#[derive(Debug)]
+pub(crate) struct OutgoingMessage {
+    pub publish: Publish,
+    pub acknowledged: Option<oneshot::Sender<()>>,
+}
+
+/// The subscription filter resolved to the Iggy topic.
+#[derive(Debug, Clone)]
+pub(crate) struct SubscriptionFilter {
+    /// The filter as received from the client, including the shared subscription prefix.
+    pub name: String,
+    pub filter: String,
+    pub qos: QoS,
+    pub default_topic: Option<String>,
+}
+
+/// The consumer group delivering the messages of the single Iggy topic to the subscriber.
+///
+/// All the filters of the client resolved to the same topic and the same group share the single
+/// consumer group member, so that the messages are delivered once and the partitions aren't split between them.
+#[derive(Debug)]
+pub(crate) struct GroupSubscription {
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+    pub group_id: Identifier,
+    filters: Arc<Mutex<Vec<SubscriptionFilter>>>,
+    task: JoinHandle<()>,
+}
+
+/// The settings of the messages delivery to the subscriber.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DeliveryConfig {
+    pub batch_size: u32,
+    pub max_wait: IggyDuration,
+}
+
+impl GroupSubscription {
+    /// Joins the consumer group, creating it if it doesn't exist, and starts delivering the messages.
+    /// If `only_new_messages` is set, or the group has been just created, the messages appended
+    /// before the subscription are skipped.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start(
+        system: &SharedSystem,
+        session: &Arc<Session>,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        group_name: &str,
+        only_new_messages: bool,
+        filter: SubscriptionFilter,
+        outgoing: mpsc::Sender<OutgoingMessage>,
+        config: DeliveryConfig,
+    ) -> Result<Self, IggyError> {
+        let group_id = Identifier::named(group_name)?;
+        let created = join_group(system, session, &stream_id, &topic_id, &group_id, group_name)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to join consumer group: {group_name}, stream ID: {stream_id}, topic ID: {topic_id}")
+            })?;
+        let consumer = Consumer::group(group_id.clone());
+        if only_new_messages || created {
+            skip_existing_messages(system, session, &consumer, &stream_id, &topic_id).await?;
+        }
+
+        info!(
+            "MQTT client: {} has joined consumer group: {group_name}, stream ID: {stream_id}, topic ID: {topic_id}.",
+            session.client_id
+        );
+        let filters = Arc::new(Mutex::new(vec![filter]));
+        let task = tokio::spawn(deliver(
+            system.clone(),
+            session.clone(),
+            consumer,
+            stream_id.clone(),
+            topic_id.clone(),
+            filters.clone(),
+            outgoing,
+            config,
+        ));
+        Ok(Self {
+            stream_id,
+            topic_id,
+            group_id,
+            filters,
+            task,
+        })
+    }
+
+    /// Adds the filter or replaces the existing one with the same name.
+    pub fn add_filter(&self, filter: SubscriptionFilter) {
+        let mut filters = self.filters.lock().unwrap();
+        filters.retain(|existing| existing.name != filter.name);
+        filters.push(filter);
+    }
+
+    pub fn has_filter(&self, name: &str) -> bool {
+        self.filters
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|filter| filter.name == name)
+    }
+
+    /// Removes the filter and returns `true` if there are no filters left.
+    pub fn remove_filter(&self, name: &str) -> bool {
+        let mut filters = self.filters.lock().unwrap();
+        filters.retain(|existing| existing.name != name);
+        filters.is_empty()
+    }
+
+    /// Stops delivering the messages and leaves the consumer group.
+    pub async fn stop(self, system: &SharedSystem, session: &Session) {
+        self.task.abort();
+        if let Err(error) = system
+            .read()
+            .await
+            .leave_consumer_group(session, &self.stream_id, &self.topic_id, &self.group_id)
+            .await
+        {
+            warn!(
+                "Failed to leave consumer group: {}, stream ID: {}, topic ID: {} by MQTT client: {}. {error}",
+                self.group_id, self.stream_id, self.topic_id, session.client_id
+            );
+        }
+    }
+}
+
+impl Drop for GroupSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Joins the consumer group and returns `true` if the group has been created.
+async fn join_group(
+    system: &SharedSystem,
+    session: &Session,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    group_id: &Identifier,
+    group_name: &str,
+) -> Result<bool, IggyError> {
+    match system
+        .read()
+        .await
+        .join_consumer_group(session, stream_id, topic_id, group_id)
+        .await
+    {
+        Err(IggyError::ConsumerGroupNameNotFound(..)) => {}
+        result => return result.map(|_| false),
+    }
+
+    let mut system = system.write().await;
+    let created_group_id = match system
+        .create_consumer_group(session, stream_id, topic_id, None, group_name)
+        .await
+    {
+        Ok(consumer_group) => Some(consumer_group.read().await.group_id),
+        // The group might have been created by another client in the meantime.
+        Err(IggyError::ConsumerGroupNameAlreadyExists(..)) => None,
+        Err(error) => return Err(error),
+    };
+    let system = system.downgrade();
+    if let Some(created_group_id) = created_group_id {
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::CreateConsumerGroup(CreateConsumerGroupWithId {
+                    group_id: created_group_id,
+                    command: CreateConsumerGroup {
+                        stream_id: stream_id.clone(),
+                        topic_id: topic_id.clone(),
+                        group_id: Some(created_group_id),
+                        name: group_name.to_owned(),
+                    },
+                }),
+            )
+            .await?;
+    }
+    system
+        .join_consumer_group(session, stream_id, topic_id, group_id)
+        .await?;
+    Ok(created_group_id.is_some())
+}
+
+/// Stores the offsets of the last messages of all the partitions, so that only the messages appended afterwards are delivered.
+async fn skip_existing_messages(
+    system: &SharedSystem,
+    session: &Session,
+    consumer: &Consumer,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+) -> Result<(), IggyError> {
+    let system = system.read().await;
+    let mut offsets = Vec::new();
+    {
+        let topic = system.find_topic(session, stream_id, topic_id)?;
+        for partition in topic.partitions.values() {
+            let partition = partition.read().await;
+            if partition.should_increment_offset {
+                offsets.push((partition.partition_id, partition.current_offset));
+            }
+        }
+    }
+
+    for (partition_id, offset) in offsets {
+        system
+            .store_consumer_offset(
+                session,
+                consumer.clone(),
+                stream_id,
+                topic_id,
+                Some(partition_id),
+                offset,
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// Polls the messages of the consumer group and delivers the ones matched by the filters.
+/// The offset of the batch is committed once all the QoS 1 messages are acknowledged, so the unacknowledged
+/// messages are delivered again to the next subscriber of the group, e.g. once the client reconnects.
+#[allow(clippy::too_many_arguments)]
+async fn deliver(
+    system: SharedSystem,
+    session: Arc<Session>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    filters: Arc<Mutex<Vec<SubscriptionFilter>>>,
+    outgoing: mpsc::Sender<OutgoingMessage>,
+    config: DeliveryConfig,
+) {
+    let args = PollingArgs::new(PollingStrategy::next(), config.batch_size, false);
+    loop {
+        let polled_messages = match system
+            .poll_messages_with_wait(
+                &session,
+                &consumer,
+                &stream_id,
+                &topic_id,
+                None,
+                args,
+                config.max_wait,
+            )
+            .await
+        {
+            Ok(polled_messages) => polled_messages,
+            Err(error) => {
+                warn!("Failed to poll messages for MQTT client: {}, {consumer}, stream ID: {stream_id}, topic ID: {topic_id}, the subscription has been stopped. {error}", session.client_id);
+                return;
+            }
+        };
+        let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        else {
+            continue;
+        };
+
+        let mut acknowledgements = Vec::new();
+        for message in &polled_messages.messages {
+            let Some((topic, qos)) = route(&filters, message) else {
+                continue;
+            };
+
+            let acknowledged = if qos == QoS::AtLeastOnce {
+                let (sender, receiver) = oneshot::channel();
+                acknowledgements.push(receiver);
+                Some(sender)
+            } else {
+                None
+            };
+            let publish = messages::to_publish(&topic, qos, message);
+            if outgoing
+                .send(OutgoingMessage {
+                    publish,
+                    acknowledged,
+                })
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        for acknowledgement in acknowledgements {
+            // The connection has been closed before the message was acknowledged.
+            if acknowledgement.await.is_err() {
+                return;
+            }
+        }
+
+        debug!("Committing offset: {last_offset} of partition: {} for MQTT client: {}, {consumer}, stream ID: {stream_id}, topic ID: {topic_id}.", polled_messages.partition_id, session.client_id);
+        if let Err(error) = system
+            .read()
+            .await
+            .store_consumer_offset(
+                &session,
+                consumer.clone(),
+                &stream_id,
+                &topic_id,
+                Some(polled_messages.partition_id),
+                last_offset,
+            )
+            .await
+        {
+            warn!("Failed to commit offset: {last_offset} for MQTT client: {}, {consumer}, stream ID: {stream_id}, topic ID: {topic_id}, the subscription has been stopped. {error}", session.client_id);
+            return;
+        }
+    }
+}
+
+/// Returns the MQTT topic of the message and the QoS it should be delivered with, if any of the filters matches it.
+/// The messages appended by the Iggy clients are delivered with the default topic of the filter, if it's available.
+fn route(
+    filters: &Mutex<Vec<SubscriptionFilter>>,
+    message: &PolledMessage,
+) -> Option<(String, QoS)> {
+    let (topic, published_qos) = messages::get_topic_and_qos(message);
+    let filters = filters.lock().unwrap();
+    let mut routed: Option<(String, QoS)> = None;
+    for filter in filters.iter() {
+        let Some(topic) = topic.or(filter.default_topic.as_deref()) else {
+            continue;
+        };
+        if !matches(&filter.filter, topic) {
+            continue;
+        }
+
+        // The message is delivered with the lower of the published and the granted QoS,
+        // and the highest one of all the matching filters.
+        let qos = match published_qos {
+            Some(published_qos) if published_qos < filter.qos => published_qos,
+            _ => filter.qos,
+        };
+        match &mut routed {
+            Some((_, routed_qos)) if *routed_qos < qos => *routed_qos = qos,
+            Some(_) => {}
+            None => routed = Some((topic.to_owned(), qos)),
+        }
+    }
+    routed
+}
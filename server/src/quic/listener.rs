@@ -17,28 +17,53 @@
  */
 
 use crate::binary::command;
-use crate::binary::sender::SenderKind;
+use crate::binary::sender::{take_error_details_flag, SenderKind};
+use crate::binary::subscriptions::{MessageSubscriptions, PushSink};
 use crate::command::ServerCommand;
 use crate::server_error::ConnectionError;
+use crate::shutdown::Shutdown;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::{anyhow, Context};
 use bytes::Bytes;
+use iggy::error::IggyError;
 use iggy::validatable::Validatable;
 use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
-use quinn::{Connection, Endpoint, RecvStream, SendStream};
+use quinn::{Connection, Endpoint, RecvStream, SendStream, VarInt};
+use tokio_util::task::TaskTracker;
 use tracing::{debug, error, info};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(endpoint: Endpoint, system: SharedSystem, shutdown: Shutdown) {
+    {
+        let endpoint = endpoint.clone();
+        let listener_shutdown = shutdown.clone();
+        // Refuses the new connections and waits until the existing ones are gracefully closed.
+        shutdown.spawn(async move {
+            listener_shutdown.triggered().await;
+            endpoint.set_server_config(None);
+            info!("QUIC server has stopped accepting new connections.");
+            endpoint.wait_idle().await;
+        });
+    }
+
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
         let system = system.clone();
+        let shutdown = shutdown.clone();
         tokio::spawn(async move {
-            while let Some(incoming_connection) = endpoint.accept().await {
+            loop {
+                let incoming_connection = tokio::select! {
+                    biased;
+                    _ = shutdown.triggered() => break,
+                    incoming_connection = endpoint.accept() => incoming_connection,
+                };
+                let Some(incoming_connection) = incoming_connection else {
+                    break;
+                };
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
@@ -53,8 +78,11 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
                     continue;
                 }
                 let incoming_connection = incoming_connection.unwrap();
-                tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                let connection_shutdown = shutdown.clone();
+                shutdown.spawn(async move {
+                    if let Err(error) =
+                        handle_connection(incoming_connection, system, connection_shutdown).await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -66,6 +94,7 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
     system: SharedSystem,
+    shutdown: Shutdown,
 ) -> Result<(), ConnectionError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
@@ -77,16 +106,48 @@ async fn handle_connection(
         .await;
 
     let client_id = session.client_id;
-    while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
+    // The messages of the subscriptions are pushed over the unidirectional streams opened by the server.
+    let subscriptions = MessageSubscriptions::new(
+        session.clone(),
+        system.clone(),
+        PushSink::Quic(connection.clone()),
+    );
+    let streams = TaskTracker::new();
+    loop {
+        let stream = tokio::select! {
+            biased;
+            _ = shutdown.triggered() => {
+                // Let the in-flight requests complete before closing the connection.
+                streams.close();
+                streams.wait().await;
+                info!("Closing QUIC connection for client with ID: {client_id}, address: {address}, server is shutting down.");
+                let error = IggyError::ServerShuttingDown;
+                connection.close(VarInt::from_u32(error.as_code()), error.to_string().as_bytes());
+                system.read().await.delete_client(client_id).await;
+                return Ok(());
+            }
+            _ = session.disconnected() => {
+                info!("Closing QUIC connection for client with ID: {client_id}, address: {address}.");
+                let error = IggyError::ClientDisconnected(client_id);
+                connection.close(VarInt::from_u32(error.as_code()), error.to_string().as_bytes());
+                system.read().await.delete_client(client_id).await;
+                return Ok(());
+            }
+            stream = accept_stream(&connection, &system, client_id) => stream?,
+        };
+        let Some(stream) = stream else {
+            break;
+        };
         let system = system.clone();
         let session = session.clone();
+        let subscriptions = subscriptions.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            if let Err(err) = handle_stream(stream, system, session, subscriptions).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
-        let _handle = tokio::spawn(handle_stream_task);
+        streams.spawn(handle_stream_task);
     }
     Ok(())
 }
@@ -117,10 +178,11 @@ async fn handle_stream(
     stream: BiStream,
     system: SharedSystem,
     session: impl AsRef<Session>,
+    subscriptions: MessageSubscriptions,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
     // TODO: read to BytesMut instead of Vec<u8>
-    let request = recv_stream
+    let mut request = recv_stream
         .read_to_end(MAX_PAYLOAD_SIZE as usize)
         .await
         .with_context(|| "Error when reading the QUIC request.")?;
@@ -137,6 +199,7 @@ async fn handle_stream(
         .try_into()
         .map(u32::from_le_bytes)
         .unwrap_or_default();
+    let error_details = take_error_details_flag(&mut request[INITIAL_BYTES_LENGTH..]);
     let command =
         ServerCommand::from_bytes(Bytes::copy_from_slice(&request[INITIAL_BYTES_LENGTH..]))
             .with_context(|| "Error when reading the QUIC request command.")?;
@@ -146,7 +209,8 @@ async fn handle_stream(
 
     debug!("Received a QUIC command: {command}, payload size: {length}");
 
-    let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream);
+    let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream, subscriptions);
+    sender.set_error_details(error_details);
     command::handle(command, &mut sender, session.as_ref(), system.clone())
         .await
         .with_context(|| "Error when handling the QUIC request.")
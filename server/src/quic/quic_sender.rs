@@ -16,8 +16,10 @@
  * under the License.
  */
 
+use crate::binary::subscriptions::MessageSubscriptions;
 use crate::quic::COMPONENT;
 use crate::{binary::sender::Sender, server_error::ServerError};
+use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
@@ -29,6 +31,9 @@ const STATUS_OK: &[u8] = &[0; 4];
 pub struct QuicSender {
     pub(crate) send: SendStream,
     pub(crate) recv: RecvStream,
+    pub(crate) error_details: bool,
+    /// The subscriptions of the whole connection, shared by all of its request streams.
+    pub(crate) subscriptions: MessageSubscriptions,
 }
 
 impl Sender for QuicSender {
@@ -50,10 +55,22 @@ impl Sender for QuicSender {
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        self.send_response(&error.as_code().to_le_bytes(), &[])
+        let details = match self.error_details {
+            true => error.as_details().unwrap_or_default(),
+            false => Bytes::new(),
+        };
+        self.send_response(&error.as_code().to_le_bytes(), &details)
             .await
     }
 
+    fn set_correlation_id(&mut self, _correlation_id: Option<u32>) {
+        // Every QUIC request is sent over its own bidirectional stream, so no correlation is needed.
+    }
+
+    fn set_error_details(&mut self, error_details: bool) {
+        self.error_details = error_details;
+    }
+
     async fn shutdown(&mut self) -> Result<(), ServerError> {
         Ok(())
     }
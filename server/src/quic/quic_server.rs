@@ -31,11 +31,12 @@ use crate::configs::quic::QuicConfig;
 use crate::quic::listener;
 use crate::quic::COMPONENT;
 use crate::server_error::QuicError;
+use crate::shutdown::Shutdown;
 use crate::streaming::systems::system::SharedSystem;
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
-pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
+pub fn start(config: QuicConfig, system: SharedSystem, shutdown: Shutdown) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let address = config.address.parse().unwrap();
     let quic_config = configure_quic(config);
@@ -45,7 +46,7 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), address).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    listener::start(endpoint, system, shutdown);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
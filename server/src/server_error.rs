@@ -47,6 +47,9 @@ error_set!(
 
         #[display("Cache config validation failure")]
         CacheConfigValidationFailure,
+
+        #[display("Cannot load TLS certificate")]
+        CannotLoadCertificate,
     };
 
     ArchiverError = {
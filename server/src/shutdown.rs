@@ -0,0 +1,109 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::duration::IggyDuration;
+use std::future::Future;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+/// Coordinates the graceful shutdown of the listeners and the client connections.
+///
+/// Once triggered, the listeners stop accepting new connections and each connection
+/// completes its in-flight request before closing with `IggyError::ServerShuttingDown`.
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    token: CancellationToken,
+    tracker: TaskTracker,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns the task which should be completed before the buffered data is flushed.
+    pub fn spawn<F>(&self, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.tracker.spawn(task)
+    }
+
+    pub fn trigger(&self) {
+        self.token.cancel();
+        self.tracker.close();
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Completes once the shutdown has been triggered (immediately, if it already was).
+    pub async fn triggered(&self) {
+        self.token.cancelled().await
+    }
+
+    /// Waits for the tracked tasks to complete, returns `false` if the timeout has elapsed first.
+    pub async fn drain(&self, timeout: IggyDuration) -> bool {
+        tokio::time::timeout(timeout.get_duration(), self.tracker.wait())
+            .await
+            .is_ok()
+    }
+
+    pub fn pending_tasks(&self) -> usize {
+        self.tracker.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn drain_should_wait_for_tracked_tasks_to_complete() {
+        let shutdown = Shutdown::new();
+        let listener = shutdown.clone();
+        shutdown.spawn(async move {
+            listener.triggered().await;
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        });
+
+        shutdown.trigger();
+        assert!(shutdown.is_triggered());
+        assert!(shutdown.drain(IggyDuration::from_str("1s").unwrap()).await);
+        assert_eq!(shutdown.pending_tasks(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_should_time_out_when_tasks_do_not_complete() {
+        let shutdown = Shutdown::new();
+        shutdown.spawn(std::future::pending::<()>());
+
+        shutdown.trigger();
+        assert!(
+            !shutdown
+                .drain(IggyDuration::from_str("10ms").unwrap())
+                .await
+        );
+        assert_eq!(shutdown.pending_tasks(), 1);
+    }
+}
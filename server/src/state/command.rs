@@ -18,7 +18,7 @@
 
 use crate::state::models::{
     CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
-    CreateTopicWithId, CreateUserWithId,
+    CreateTopicWithId, CreateUserWithId, RegisterSchemaWithId,
 };
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
@@ -26,18 +26,23 @@ use iggy::command::{
     Command, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
     CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
     DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
-    UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, DISCONNECT_CLIENT_CODE,
+    PURGE_STREAM_CODE, PURGE_TOPIC_CODE, REGISTER_SCHEMA_CODE, UPDATE_PERMISSIONS_CODE,
+    UPDATE_SCHEMA_COMPATIBILITY_CODE, UPDATE_SCHEMA_VALIDATION_CODE, UPDATE_STREAM_CODE,
+    UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
 };
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::error::IggyError;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::schemas::update_schema_validation::UpdateSchemaValidation;
 use iggy::segments::delete_segments::DeleteSegments;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::disconnect_client::DisconnectClient;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::purge_topic::PurgeTopic;
 use iggy::topics::update_topic::UpdateTopic;
@@ -62,6 +67,9 @@ pub enum EntryCommand {
     DeleteSegments(DeleteSegments),
     CreateConsumerGroup(CreateConsumerGroupWithId),
     DeleteConsumerGroup(DeleteConsumerGroup),
+    RegisterSchema(RegisterSchemaWithId),
+    UpdateSchemaCompatibility(UpdateSchemaCompatibility),
+    UpdateSchemaValidation(UpdateSchemaValidation),
     CreateUser(CreateUserWithId),
     UpdateUser(UpdateUser),
     DeleteUser(DeleteUser),
@@ -69,6 +77,7 @@ pub enum EntryCommand {
     UpdatePermissions(UpdatePermissions),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    DisconnectClient(DisconnectClient),
 }
 
 impl BytesSerializable for EntryCommand {
@@ -87,6 +96,11 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::DeleteSegments(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateConsumerGroup(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteConsumerGroup(command) => (command.code(), command.to_bytes()),
+            EntryCommand::RegisterSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateSchemaCompatibility(command) => {
+                (command.code(), command.to_bytes())
+            }
+            EntryCommand::UpdateSchemaValidation(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteUser(command) => (command.code(), command.to_bytes()),
@@ -98,6 +112,7 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
+            EntryCommand::DisconnectClient(command) => (command.code(), command.to_bytes()),
         };
 
         let mut bytes = BytesMut::with_capacity(4 + 4 + command.len());
@@ -143,6 +158,15 @@ impl BytesSerializable for EntryCommand {
             DELETE_CONSUMER_GROUP_CODE => Ok(EntryCommand::DeleteConsumerGroup(
                 DeleteConsumerGroup::from_bytes(payload)?,
             )),
+            REGISTER_SCHEMA_CODE => Ok(EntryCommand::RegisterSchema(
+                RegisterSchemaWithId::from_bytes(payload)?,
+            )),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(EntryCommand::UpdateSchemaCompatibility(
+                UpdateSchemaCompatibility::from_bytes(payload)?,
+            )),
+            UPDATE_SCHEMA_VALIDATION_CODE => Ok(EntryCommand::UpdateSchemaValidation(
+                UpdateSchemaValidation::from_bytes(payload)?,
+            )),
             CREATE_USER_CODE => Ok(EntryCommand::CreateUser(CreateUserWithId::from_bytes(
                 payload,
             )?)),
@@ -160,6 +184,9 @@ impl BytesSerializable for EntryCommand {
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            DISCONNECT_CLIENT_CODE => Ok(EntryCommand::DisconnectClient(
+                DisconnectClient::from_bytes(payload)?,
+            )),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -185,6 +212,13 @@ impl Display for EntryCommand {
             EntryCommand::DeleteConsumerGroup(command) => {
                 write!(f, "DeleteConsumerGroup({})", command)
             }
+            EntryCommand::RegisterSchema(command) => write!(f, "RegisterSchema({})", command),
+            EntryCommand::UpdateSchemaCompatibility(command) => {
+                write!(f, "UpdateSchemaCompatibility({})", command)
+            }
+            EntryCommand::UpdateSchemaValidation(command) => {
+                write!(f, "UpdateSchemaValidation({})", command)
+            }
             EntryCommand::CreateUser(command) => write!(f, "CreateUser({})", command),
             EntryCommand::UpdateUser(command) => write!(f, "UpdateUser({})", command),
             EntryCommand::DeleteUser(command) => write!(f, "DeleteUser({})", command),
@@ -196,6 +230,7 @@ impl Display for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 write!(f, "DeletePersonalAccessToken({})", command)
             }
+            EntryCommand::DisconnectClient(command) => write!(f, "DisconnectClient({})", command),
         }
     }
 }
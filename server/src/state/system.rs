@@ -24,7 +24,9 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
+use iggy::models::schema::{SchemaCompatibility, SchemaFormat, SchemaValidation};
 use iggy::models::user_status::UserStatus;
+use iggy::system::disconnect_client::DisconnectTarget;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -35,6 +37,8 @@ use tracing::{debug, info};
 pub struct SystemState {
     pub streams: AHashMap<u32, StreamState>,
     pub users: AHashMap<u32, UserState>,
+    pub last_schema_id: u32,
+    pub revoked_user_sessions: AHashMap<u32, IggyTimestamp>,
 }
 
 #[derive(Debug)]
@@ -51,6 +55,9 @@ pub struct TopicState {
     pub name: String,
     pub partitions: AHashMap<u32, PartitionState>,
     pub consumer_groups: AHashMap<u32, ConsumerGroupState>,
+    pub schemas: Vec<SchemaState>,
+    pub schema_compatibility: SchemaCompatibility,
+    pub schema_validation: SchemaValidation,
     pub compression_algorithm: CompressionAlgorithm,
     pub message_expiry: IggyExpiry,
     pub max_topic_size: MaxTopicSize,
@@ -64,6 +71,15 @@ pub struct PartitionState {
     pub created_at: IggyTimestamp,
 }
 
+#[derive(Debug)]
+pub struct SchemaState {
+    pub id: u32,
+    pub version: u32,
+    pub format: SchemaFormat,
+    pub definition: String,
+    pub created_at: IggyTimestamp,
+}
+
 #[derive(Debug)]
 pub struct PersonalAccessTokenState {
     pub name: String,
@@ -92,6 +108,8 @@ impl SystemState {
     pub async fn init(entries: Vec<StateEntry>) -> Result<Self, IggyError> {
         let mut streams = AHashMap::new();
         let mut users = AHashMap::new();
+        let mut last_schema_id = 0;
+        let mut revoked_user_sessions = AHashMap::new();
         for entry in entries {
             debug!("Processing state entry: {entry}",);
             match entry.command().with_error_context(|error| {
@@ -138,6 +156,9 @@ impl SystemState {
                         id: topic_id,
                         name: command.name,
                         consumer_groups: AHashMap::new(),
+                        schemas: Vec::new(),
+                        schema_compatibility: SchemaCompatibility::default(),
+                        schema_validation: SchemaValidation::default(),
                         compression_algorithm: command.compression_algorithm,
                         message_expiry: command.message_expiry,
                         max_topic_size: command.max_topic_size,
@@ -305,6 +326,53 @@ impl SystemState {
                         find_consumer_group_id(&topic.consumer_groups, &command.group_id);
                     topic.consumer_groups.remove(&consumer_group_id);
                 }
+                EntryCommand::RegisterSchema(command) => {
+                    let schema_id = command.schema_id;
+                    let command = command.command;
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    let schema = SchemaState {
+                        id: schema_id,
+                        version: topic.schemas.len() as u32 + 1,
+                        format: command.format,
+                        definition: command.definition,
+                        created_at: entry.timestamp,
+                    };
+                    topic.schemas.push(schema);
+                    // Schema IDs are never reused, even if the topic was deleted
+                    last_schema_id = last_schema_id.max(schema_id);
+                }
+                EntryCommand::UpdateSchemaCompatibility(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.schema_compatibility = command.compatibility;
+                }
+                EntryCommand::UpdateSchemaValidation(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.schema_validation = command.validation;
+                }
                 EntryCommand::CreateUser(command) => {
                     let user_id = command.user_id;
                     let command = command.command;
@@ -398,10 +466,23 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.personal_access_tokens.remove(&command.name);
                 }
+                EntryCommand::DisconnectClient(command) => {
+                    // The client sessions are not part of the state, but the revoked sessions of the user
+                    // must stay revoked, as the access tokens issued before remain valid otherwise.
+                    if let DisconnectTarget::User(user_id) = command.target {
+                        let user_id = find_user_id(&users, &user_id);
+                        revoked_user_sessions.insert(user_id, entry.timestamp);
+                    }
+                }
             }
         }
 
-        let state = SystemState { streams, users };
+        let state = SystemState {
+            streams,
+            users,
+            last_schema_id,
+            revoked_user_sessions,
+        };
         debug!("+++ State +++");
         debug!("{state}");
         debug!("+++ State +++");
@@ -541,10 +622,28 @@ impl Display for TopicState {
         for consumer_group in self.consumer_groups.iter() {
             write!(f, "\n  {}", consumer_group.1)?;
         }
+        write!(
+            f,
+            "\nSchemas (compatibility: {}, validation: {}):",
+            self.schema_compatibility, self.schema_validation
+        )?;
+        for schema in self.schemas.iter() {
+            write!(f, "\n  {}", schema)?;
+        }
         Ok(())
     }
 }
 
+impl Display for SchemaState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Schema -> ID: {}, Version: {}, Format: {}",
+            self.id, self.version, self.format
+        )
+    }
+}
+
 impl Display for PartitionState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
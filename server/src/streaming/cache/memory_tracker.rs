@@ -31,7 +31,7 @@ static INSTANCE: OnceLock<Option<Arc<CacheMemoryTracker>>> = OnceLock::new();
 #[derive(Debug)]
 pub struct CacheMemoryTracker {
     used_memory_bytes: AtomicU64,
-    limit_bytes: IggyByteSize,
+    limit_bytes: AtomicU64,
 }
 
 type MessageSize = u64;
@@ -63,7 +63,7 @@ impl CacheMemoryTracker {
         let free_memory_percentage =
             free_memory.as_bytes_u64() as f64 / total_memory_bytes.as_bytes_u64() as f64 * 100.0;
         let used_memory_bytes = AtomicU64::new(0);
-        let limit_bytes = limit.into();
+        let limit_bytes: IggyByteSize = limit.into();
 
         info!(
             "Cache memory tracker started, cache: {}, total memory: {}, free memory: {}, free memory percentage: {:.2}%",
@@ -72,10 +72,21 @@ impl CacheMemoryTracker {
 
         CacheMemoryTracker {
             used_memory_bytes,
-            limit_bytes,
+            limit_bytes: AtomicU64::new(limit_bytes.as_bytes_u64()),
         }
     }
 
+    /// Changes the cache size, the messages above the new limit are evicted by the next appends.
+    pub fn set_limit(&self, limit: MemoryResourceQuota) {
+        let limit_bytes: IggyByteSize = limit.into();
+        self.limit_bytes
+            .store(limit_bytes.as_bytes_u64(), Ordering::SeqCst);
+        info!(
+            "Cache memory tracker limit set to: {}",
+            limit_bytes.as_human_string()
+        );
+    }
+
     pub fn increment_used_memory(&self, message_size: MessageSize) {
         let mut current_cache_size_bytes = self.used_memory_bytes.load(Ordering::SeqCst);
         loop {
@@ -114,6 +125,6 @@ impl CacheMemoryTracker {
 
     pub fn will_fit_into_cache(&self, requested_size: IggyByteSize) -> bool {
         IggyByteSize::from(self.used_memory_bytes.load(Ordering::SeqCst)) + requested_size
-            <= self.limit_bytes
+            <= IggyByteSize::from(self.limit_bytes.load(Ordering::SeqCst))
     }
 }
@@ -31,6 +31,7 @@ use std::sync::Arc;
 #[derive(Debug, Default)]
 pub struct ClientManager {
     clients: AHashMap<u32, IggySharedMut<Client>>,
+    revoked_user_sessions: AHashMap<UserId, IggyTimestamp>,
 }
 
 #[derive(Debug)]
@@ -53,6 +54,10 @@ pub struct ConsumerGroup {
 pub enum Transport {
     Tcp,
     Quic,
+    WebSocket,
+    Kafka,
+    Mqtt,
+    Http,
 }
 
 impl Display for Transport {
@@ -60,6 +65,10 @@ impl Display for Transport {
         match self {
             Transport::Tcp => write!(f, "TCP"),
             Transport::Quic => write!(f, "QUIC"),
+            Transport::WebSocket => write!(f, "WebSocket"),
+            Transport::Kafka => write!(f, "Kafka"),
+            Transport::Mqtt => write!(f, "MQTT"),
+            Transport::Http => write!(f, "HTTP"),
         }
     }
 }
@@ -109,6 +118,34 @@ impl ClientManager {
         self.clients.values().cloned().collect()
     }
 
+    pub async fn get_clients_for_user(&self, user_id: UserId) -> Vec<IggySharedMut<Client>> {
+        let mut clients = Vec::new();
+        for client in self.clients.values() {
+            if client.read().await.user_id == Some(user_id) {
+                clients.push(client.clone());
+            }
+        }
+        clients
+    }
+
+    // Sessions without the persistent connection (e.g. HTTP with JWT) can't be closed,
+    // thus all the sessions of the user issued before the revocation are considered revoked.
+    pub fn revoke_user_sessions(&mut self, user_id: UserId, revoked_at: IggyTimestamp) {
+        let last_revoked_at = self
+            .revoked_user_sessions
+            .entry(user_id)
+            .or_insert(revoked_at);
+        if last_revoked_at.as_micros() < revoked_at.as_micros() {
+            *last_revoked_at = revoked_at;
+        }
+    }
+
+    pub fn is_user_session_revoked(&self, user_id: UserId, issued_at: IggyTimestamp) -> bool {
+        self.revoked_user_sessions
+            .get(&user_id)
+            .is_some_and(|revoked_at| issued_at.as_micros() < revoked_at.as_micros())
+    }
+
     pub async fn delete_clients_for_user(&mut self, user_id: UserId) -> Result<(), IggyError> {
         let mut clients_to_remove = Vec::new();
         for client in self.clients.values() {
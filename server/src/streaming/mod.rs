@@ -27,6 +27,7 @@ pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod storage;
@@ -19,6 +19,7 @@
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::utils::file;
 use dashmap::DashMap;
 use error_set::ErrContext;
 use iggy::consumer::ConsumerKind;
@@ -45,7 +46,7 @@ impl Partition {
                 }
             }
             PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
-                let consumer_offset = self.consumer_offsets.get(&consumer_group_id);
+                let consumer_offset = self.consumer_group_offsets.get(&consumer_group_id);
                 if let Some(consumer_offset) = consumer_offset {
                     return Ok(Some(consumer_offset.offset));
                 }
@@ -171,6 +172,37 @@ impl Partition {
         Ok(())
     }
 
+    /// Saves the current consumer and consumer group offsets and syncs them to disk.
+    pub async fn persist_consumer_offsets(&self) -> Result<usize, IggyError> {
+        let consumer_offsets = self
+            .consumer_offsets
+            .iter()
+            .chain(self.consumer_group_offsets.iter())
+            .map(|consumer_offset| (consumer_offset.offset, consumer_offset.path.clone()))
+            .collect::<Vec<_>>();
+        for (offset, path) in &consumer_offsets {
+            self.storage
+                .partition
+                .save_consumer_offset(*offset, path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to persist consumer offset: {offset}, path: {path}",
+                    )
+                })?;
+            file::open(path)
+                .await
+                .map_err(|_| IggyError::CannotReadFile)?
+                .sync_all()
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to sync consumer offset, path: {path}")
+                })
+                .map_err(|_| IggyError::CannotSyncFile)?;
+        }
+        Ok(consumer_offsets.len())
+    }
+
     fn get_consumer_offsets(&self, kind: ConsumerKind) -> &DashMap<u32, ConsumerOffset> {
         match kind {
             ConsumerKind::Consumer => &self.consumer_offsets,
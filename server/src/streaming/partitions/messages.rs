@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::system::Durability;
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
 use crate::streaming::models::messages::RetainedMessage;
@@ -27,6 +28,7 @@ use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
 use iggy::error::IggyError;
 use iggy::messages::send_messages::Message;
+use iggy::models::appended_messages::AppendedMessages;
 use iggy::models::messages::POLLED_MESSAGE_METADATA;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::{atomic::Ordering, Arc};
@@ -34,6 +36,15 @@ use tracing::{trace, warn};
 
 const EMPTY_MESSAGES: Vec<RetainedMessage> = vec![];
 
+/// The result of appending the messages to the partition.
+#[derive(Default)]
+pub struct AppendResult {
+    /// The offsets and timestamps of the appended messages, if any message was appended.
+    pub appended_messages: Option<AppendedMessages>,
+    /// The pending sync of the appended messages, if the group commit durability is used.
+    pub pending_sync: Option<PendingSync>,
+}
+
 impl Partition {
     /// Retrieves messages by timestamp (up to a specified count).
     pub async fn get_messages_by_timestamp(
@@ -370,7 +381,7 @@ impl Partition {
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<AppendResult, IggyError> {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
             if last_segment.is_closed {
@@ -395,6 +406,8 @@ impl Partition {
         };
 
         let mut messages_count = 0u32;
+        let mut base_timestamp = 0;
+        let mut last_timestamp = 0;
         let mut retained_messages = Vec::with_capacity(messages.len());
         if let Some(message_deduplicator) = &self.message_deduplicator {
             for message in messages {
@@ -406,6 +419,10 @@ impl Partition {
                     continue;
                 }
                 let now = IggyTimestamp::now().as_micros();
+                if messages_count == 0 {
+                    base_timestamp = now;
+                }
+                last_timestamp = now;
                 let message_offset = base_offset + messages_count as u64;
                 let message = Arc::new(RetainedMessage::new(message_offset, now, message));
                 retained_messages.push(message.clone());
@@ -414,6 +431,10 @@ impl Partition {
         } else {
             for message in messages {
                 let now = IggyTimestamp::now().as_micros();
+                if messages_count == 0 {
+                    base_timestamp = now;
+                }
+                last_timestamp = now;
                 let message_offset = base_offset + messages_count as u64;
                 let message = Arc::new(RetainedMessage::new(message_offset, now, message));
                 retained_messages.push(message.clone());
@@ -421,10 +442,17 @@ impl Partition {
             }
         }
         if messages_count == 0 {
-            return Ok(());
+            return Ok(AppendResult::default());
         }
 
         let last_offset = base_offset + (messages_count - 1) as u64;
+        let appended_messages = AppendedMessages {
+            partition_id: self.partition_id,
+            base_offset,
+            last_offset,
+            base_timestamp,
+            last_timestamp,
+        };
         if self.should_increment_offset {
             self.current_offset = last_offset;
         } else {
@@ -448,9 +476,28 @@ impl Partition {
             cache.extend(retained_messages);
         }
 
+        // Wake up the long-polling requests and the subscriptions waiting for the new messages.
+        self.messages_appended.notify_waiters();
         self.unsaved_messages_count += messages_count;
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+            if self.config.segment.durability == Durability::GroupCommit {
+                let pending_sync = last_segment
+                    .persist_messages_with_group_commit(confirmation)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to persist messages, partition: {}",
+                            self.partition_id
+                        )
+                    })?;
+                self.unsaved_messages_count = 0;
+                return Ok(AppendResult {
+                    appended_messages: Some(appended_messages),
+                    pending_sync,
+                });
+            }
+
             if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
                 || last_segment.is_full().await
             {
@@ -465,7 +512,10 @@ impl Partition {
             }
         }
 
-        Ok(())
+        Ok(AppendResult {
+            appended_messages: Some(appended_messages),
+            pending_sync: None,
+        })
     }
 
     pub fn get_messages_count(&self) -> u64 {
@@ -493,6 +543,25 @@ impl Partition {
         self.unsaved_messages_count = 0;
         Ok(())
     }
+
+    /// Flushes the buffered messages of the open segments with fsync, no messages can be appended afterwards.
+    pub async fn flush_and_shutdown_writing(&mut self) -> Result<usize, IggyError> {
+        let mut saved_messages_number = 0;
+        for segment in self
+            .segments
+            .iter_mut()
+            .filter(|segment| !segment.is_closed)
+        {
+            saved_messages_number += segment.flush_and_shutdown_writing().await.with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to flush segment with start offset: {}, partition ID: {}",
+                    segment.start_offset, self.partition_id
+                )
+            })?;
+        }
+        self.unsaved_messages_count = 0;
+        Ok(saved_messages_number)
+    }
 }
 
 #[cfg(test)]
@@ -502,9 +571,10 @@ mod tests {
     use iggy::utils::sizeable::Sizeable;
     use std::sync::atomic::{AtomicU32, AtomicU64};
     use tempfile::TempDir;
+    use tokio::sync::Notify;
 
     use super::*;
-    use crate::configs::system::{MessageDeduplicationConfig, SystemConfig};
+    use crate::configs::system::{MessageDeduplicationConfig, SegmentConfig, SystemConfig};
     use crate::streaming::partitions::create_messages;
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
     use crate::streaming::storage::SystemStorage;
@@ -521,16 +591,29 @@ mod tests {
                 .sum::<IggyByteSize>(),
             partition_id: partition.partition_id,
         };
-        partition
+        let appended_messages = partition
             .append_messages(appendable_batch_info, messages, None)
             .await
-            .unwrap();
+            .unwrap()
+            .appended_messages
+            .expect("Missing appended messages");
 
         let loaded_messages = partition
             .get_messages_by_offset(0, messages_count)
             .await
             .unwrap();
         assert_eq!(loaded_messages.len(), messages_count as usize);
+        assert_eq!(appended_messages.partition_id, partition.partition_id);
+        assert_eq!(appended_messages.base_offset, 0);
+        assert_eq!(appended_messages.last_offset, messages_count as u64 - 1);
+        assert_eq!(
+            appended_messages.base_timestamp,
+            loaded_messages.first().unwrap().timestamp
+        );
+        assert_eq!(
+            appended_messages.last_timestamp,
+            loaded_messages.last().unwrap().timestamp
+        );
     }
 
     #[tokio::test]
@@ -558,7 +641,80 @@ mod tests {
         assert_eq!(loaded_messages.len(), unique_messages_count);
     }
 
+    #[tokio::test]
+    async fn flush_and_shutdown_writing_should_persist_all_buffered_messages() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        partition.persist().await.unwrap();
+        let messages = create_messages();
+        let messages_count = messages.len();
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages, None)
+            .await
+            .unwrap();
+
+        let saved_messages_number = partition.flush_and_shutdown_writing().await.unwrap();
+
+        assert_eq!(saved_messages_number, messages_count);
+        assert_eq!(partition.unsaved_messages_count, 0);
+        let segment = partition.segments.last().unwrap();
+        assert!(segment.unsaved_messages.is_none());
+        let log_size = tokio::fs::metadata(&segment.log_path).await.unwrap().len();
+        assert!(log_size > 0);
+    }
+
+    #[tokio::test]
+    async fn given_group_commit_durability_appended_messages_should_be_persisted_and_synced() {
+        let (mut partition, _tempdir) = create_partition_with_config(SystemConfig {
+            segment: SegmentConfig {
+                durability: Durability::GroupCommit,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+        partition.persist().await.unwrap();
+        let messages = create_messages();
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+
+        let pending_sync = partition
+            .append_messages(appendable_batch_info, messages, None)
+            .await
+            .unwrap()
+            .pending_sync
+            .expect("Appended messages should wait for the group commit");
+        pending_sync.wait().await.unwrap();
+
+        assert_eq!(partition.unsaved_messages_count, 0);
+        let segment = partition.segments.last().unwrap();
+        let log_size = tokio::fs::metadata(&segment.log_path).await.unwrap().len();
+        assert!(log_size > 0);
+    }
+
     async fn create_partition(deduplication_enabled: bool) -> (Partition, TempDir) {
+        create_partition_with_config(SystemConfig {
+            message_deduplication: MessageDeduplicationConfig {
+                enabled: deduplication_enabled,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn create_partition_with_config(config: SystemConfig) -> (Partition, TempDir) {
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
@@ -566,11 +722,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let config = Arc::new(SystemConfig {
             path: temp_dir.path().to_path_buf().to_str().unwrap().to_string(),
-            message_deduplication: MessageDeduplicationConfig {
-                enabled: deduplication_enabled,
-                ..Default::default()
-            },
-            ..Default::default()
+            ..config
         });
         let storage = Arc::new(SystemStorage::new(
             config.clone(),
@@ -591,6 +743,7 @@ mod tests {
                 Arc::new(AtomicU64::new(0)),
                 Arc::new(AtomicU64::new(0)),
                 Arc::new(AtomicU32::new(0)),
+                Arc::new(Notify::new()),
                 IggyTimestamp::now(),
             )
             .await,
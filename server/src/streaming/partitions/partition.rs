@@ -34,6 +34,7 @@ use iggy::utils::timestamp::IggyTimestamp;
 use std::fmt;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
+use tokio::sync::Notify;
 
 #[derive(Debug)]
 pub struct Partition {
@@ -59,6 +60,8 @@ pub struct Partition {
     pub size_of_parent_topic: Arc<AtomicU64>,
     pub size_bytes: Arc<AtomicU64>,
     pub segments_count_of_parent_stream: Arc<AtomicU32>,
+    /// Notified whenever the messages are appended, shared by all the partitions of the parent topic.
+    pub(crate) messages_appended: Arc<Notify>,
     pub(crate) message_expiry: IggyExpiry,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
@@ -101,6 +104,7 @@ impl Partition {
         size_of_parent_stream: Arc<AtomicU64>,
         size_of_parent_topic: Arc<AtomicU64>,
         segments_count_of_parent_stream: Arc<AtomicU32>,
+        messages_appended: Arc<Notify>,
         created_at: IggyTimestamp,
     ) -> Partition {
         let partition_path = config.get_partition_path(stream_id, topic_id, partition_id);
@@ -162,6 +166,7 @@ impl Partition {
             messages_count_of_parent_topic,
             messages_count: Arc::new(AtomicU64::new(0)),
             segments_count_of_parent_stream,
+            messages_appended,
         };
 
         if with_segment {
@@ -237,6 +242,7 @@ mod tests {
     use iggy::utils::timestamp::IggyTimestamp;
     use std::sync::atomic::{AtomicU32, AtomicU64};
     use std::sync::Arc;
+    use tokio::sync::Notify;
 
     #[tokio::test]
     async fn should_be_created_with_a_single_segment_given_valid_parameters() {
@@ -269,6 +275,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -319,6 +326,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -351,6 +359,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(Notify::new()),
             IggyTimestamp::now(),
         )
         .await;
@@ -18,5 +18,7 @@
 
 pub mod persister;
 pub mod task;
+#[cfg(feature = "io-uring")]
+pub mod uring;
 
 pub const COMPONENT: &str = "STREAMING_PERSISTENCE";
@@ -25,6 +25,11 @@ use std::future::Future;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
+#[cfg(feature = "io-uring")]
+use crate::streaming::persistence::uring::UringDriver;
+#[cfg(feature = "io-uring")]
+use std::sync::Arc;
+
 #[cfg(test)]
 use mockall::automock;
 
@@ -32,6 +37,8 @@ use mockall::automock;
 pub enum PersisterKind {
     File(FilePersister),
     FileWithSync(FileWithSyncPersister),
+    #[cfg(feature = "io-uring")]
+    IoUring(UringPersister),
     #[cfg(test)]
     Mock(MockPersister),
 }
@@ -41,6 +48,8 @@ impl PersisterKind {
         match self {
             PersisterKind::File(p) => p.append(path, bytes).await,
             PersisterKind::FileWithSync(p) => p.append(path, bytes).await,
+            #[cfg(feature = "io-uring")]
+            PersisterKind::IoUring(p) => p.append(path, bytes).await,
             #[cfg(test)]
             PersisterKind::Mock(p) => p.append(path, bytes).await,
         }
@@ -50,6 +59,8 @@ impl PersisterKind {
         match self {
             PersisterKind::File(p) => p.overwrite(path, bytes).await,
             PersisterKind::FileWithSync(p) => p.overwrite(path, bytes).await,
+            #[cfg(feature = "io-uring")]
+            PersisterKind::IoUring(p) => p.overwrite(path, bytes).await,
             #[cfg(test)]
             PersisterKind::Mock(p) => p.overwrite(path, bytes).await,
         }
@@ -59,6 +70,8 @@ impl PersisterKind {
         match self {
             PersisterKind::File(p) => p.delete(path).await,
             PersisterKind::FileWithSync(p) => p.delete(path).await,
+            #[cfg(feature = "io-uring")]
+            PersisterKind::IoUring(p) => p.delete(path).await,
             #[cfg(test)]
             PersisterKind::Mock(p) => p.delete(path).await,
         }
@@ -86,6 +99,14 @@ pub struct FilePersister;
 #[derive(Debug)]
 pub struct FileWithSyncPersister;
 
+/// Persister submitting the writes to the io_uring storage backend.
+#[cfg(feature = "io-uring")]
+#[derive(Debug)]
+pub struct UringPersister {
+    driver: &'static UringDriver,
+    fsync: bool,
+}
+
 impl Persister for FilePersister {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
         let mut file = file::append(path)
@@ -189,3 +210,71 @@ impl Persister for FileWithSyncPersister {
         Ok(())
     }
 }
+
+#[cfg(feature = "io-uring")]
+impl UringPersister {
+    pub fn new(driver: &'static UringDriver, fsync: bool) -> Self {
+        Self { driver, fsync }
+    }
+
+    async fn write(
+        &self,
+        file: fs::File,
+        path: &str,
+        bytes: &[u8],
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let file = Arc::new(file.into_std().await);
+        self.driver
+            .write_all(&file, bytes.to_vec(), offset)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write data to file: {path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        if self.fsync {
+            self.driver
+                .fsync(&file)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to sync file: {path}")
+                })
+                .map_err(|_| IggyError::CannotSyncFile)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "io-uring")]
+impl Persister for UringPersister {
+    async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+        let file = file::append(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to append to file: {path}")
+            })
+            .map_err(|_| IggyError::CannotAppendToFile)?;
+        // The file is opened in the append mode, so the data is written at its end.
+        self.write(file, path, bytes, u64::MAX).await
+    }
+
+    async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+        let file = file::overwrite(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file: {path}")
+            })
+            .map_err(|_| IggyError::CannotOverwriteFile)?;
+        self.write(file, path, bytes, 0).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), IggyError> {
+        fs::remove_file(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to delete file: {path}")
+            })
+            .map_err(|_| IggyError::CannotDeleteFile)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,639 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! io_uring based file I/O, used by the `io_uring` storage backend.
+//!
+//! A single worker thread owns the ring and a pool of registered, block aligned buffers.
+//! Operations are sent to it over a channel and their results are returned via oneshot channels,
+//! so the tokio runtime threads never block on the file I/O.
+
+use crate::configs::system::StorageConfig;
+use flume::{Receiver, Sender};
+use io_uring::{opcode, types, IoUring};
+use std::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::io;
+use std::os::fd::AsRawFd;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::{oneshot, Semaphore};
+use tracing::{error, info};
+
+/// Alignment and granularity of the direct I/O writes.
+pub const BLOCK_SIZE: usize = 4096;
+
+static DRIVER: OnceLock<UringDriver> = OnceLock::new();
+static DRIVER_INIT: Mutex<()> = Mutex::new(());
+
+/// Returns the driver shared by all the files, starting it with the given configuration on the first use.
+pub fn driver(config: &StorageConfig) -> io::Result<&'static UringDriver> {
+    if let Some(driver) = DRIVER.get() {
+        return Ok(driver);
+    }
+
+    let _guard = DRIVER_INIT.lock().unwrap();
+    if let Some(driver) = DRIVER.get() {
+        return Ok(driver);
+    }
+
+    let driver = UringDriver::start(
+        config.queue_depth,
+        config.registered_buffers as u16,
+        config.registered_buffer_size.as_bytes_u64() as usize,
+    )?;
+    info!(
+        "Started io_uring storage backend, queue depth: {}, registered buffers: {} x {}.",
+        config.queue_depth, config.registered_buffers, config.registered_buffer_size
+    );
+    Ok(DRIVER.get_or_init(|| driver))
+}
+
+/// Handle to the io_uring worker thread.
+#[derive(Debug)]
+pub struct UringDriver {
+    sender: Sender<Request>,
+    buffers: Arc<BufferPool>,
+}
+
+#[derive(Debug)]
+enum Op {
+    WriteFixed {
+        file: Arc<File>,
+        buffer: FixedBuffer,
+        len: usize,
+        offset: u64,
+    },
+    Write {
+        file: Arc<File>,
+        bytes: Vec<u8>,
+        offset: u64,
+    },
+    Readv {
+        file: Arc<File>,
+        buffers: Vec<Vec<u8>>,
+        offset: u64,
+    },
+    Fsync {
+        file: Arc<File>,
+    },
+    Ftruncate {
+        file: Arc<File>,
+        len: u64,
+    },
+}
+
+#[derive(Debug)]
+struct Request {
+    op: Op,
+    sender: oneshot::Sender<Completion>,
+}
+
+/// The result of the operation along with the operation itself, which returns the ownership of its buffers.
+#[derive(Debug)]
+struct Completion {
+    result: io::Result<usize>,
+    op: Op,
+}
+
+impl UringDriver {
+    /// Creates the ring, registers the buffers and spawns the worker thread.
+    pub fn start(queue_depth: u32, buffers_count: u16, buffer_size: usize) -> io::Result<Self> {
+        let ring = IoUring::new(queue_depth)?;
+        let buffers = Arc::new(BufferPool::new(buffers_count, buffer_size));
+        // SAFETY: the memory of the buffers is owned by the pool, which is kept alive by the worker
+        // until the ring is dropped.
+        unsafe {
+            ring.submitter().register_buffers(&buffers.iovecs())?;
+        }
+
+        let (sender, receiver) = flume::unbounded();
+        let worker_buffers = buffers.clone();
+        std::thread::Builder::new()
+            .name("iggy-io-uring".to_string())
+            .spawn(move || Worker::new(ring, receiver, worker_buffers).run())?;
+        Ok(Self { sender, buffers })
+    }
+
+    /// Size of a single registered buffer.
+    pub fn buffer_size(&self) -> usize {
+        self.buffers.buffer_size
+    }
+
+    /// Waits until one of the registered buffers is available.
+    pub async fn acquire_buffer(&self) -> FixedBuffer {
+        self.buffers.clone().acquire().await
+    }
+
+    /// Writes the first `len` bytes of the registered buffer at the given offset.
+    pub async fn write_fixed(
+        &self,
+        file: &Arc<File>,
+        buffer: FixedBuffer,
+        len: usize,
+        offset: u64,
+    ) -> io::Result<usize> {
+        self.submit(Op::WriteFixed {
+            file: file.clone(),
+            buffer,
+            len,
+            offset,
+        })
+        .await?
+        .result
+    }
+
+    /// Writes all the bytes at the given offset, `u64::MAX` means the current file position.
+    pub async fn write_all(&self, file: &Arc<File>, bytes: Vec<u8>, offset: u64) -> io::Result<()> {
+        let mut bytes = bytes;
+        let mut offset = offset;
+        while !bytes.is_empty() {
+            let completion = self
+                .submit(Op::Write {
+                    file: file.clone(),
+                    bytes,
+                    offset,
+                })
+                .await?;
+            let written = completion.result?;
+            if written == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            let Op::Write {
+                bytes: mut buffer, ..
+            } = completion.op
+            else {
+                unreachable!("io_uring completion doesn't match the write operation");
+            };
+            buffer.drain(..written);
+            bytes = buffer;
+            if offset != u64::MAX {
+                offset += written as u64;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fills all the buffers with the consecutive bytes read from the given offset, using a single vectored read
+    /// unless the kernel returns less data than requested.
+    pub async fn read_exact_vectored_at(
+        &self,
+        file: &Arc<File>,
+        buffers: Vec<Vec<u8>>,
+        offset: u64,
+    ) -> io::Result<Vec<Vec<u8>>> {
+        let total: usize = buffers.iter().map(|buffer| buffer.len()).sum();
+        let completion = self
+            .submit(Op::Readv {
+                file: file.clone(),
+                buffers,
+                offset,
+            })
+            .await?;
+        let mut read = completion.result?;
+        let Op::Readv { mut buffers, .. } = completion.op else {
+            unreachable!("io_uring completion doesn't match the read operation");
+        };
+
+        while read < total {
+            let remaining = vec![vec![0; total - read]];
+            let completion = self
+                .submit(Op::Readv {
+                    file: file.clone(),
+                    buffers: remaining,
+                    offset: offset + read as u64,
+                })
+                .await?;
+            let bytes_read = completion.result?;
+            if bytes_read == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            let Op::Readv {
+                buffers: remaining, ..
+            } = completion.op
+            else {
+                unreachable!("io_uring completion doesn't match the read operation");
+            };
+            copy_into(&mut buffers, read, &remaining[0][..bytes_read]);
+            read += bytes_read;
+        }
+        Ok(buffers)
+    }
+
+    /// Reads exactly `len` bytes from the given offset.
+    pub async fn read_exact_at(
+        &self,
+        file: &Arc<File>,
+        offset: u64,
+        len: usize,
+    ) -> io::Result<Vec<u8>> {
+        let mut buffers = self
+            .read_exact_vectored_at(file, vec![vec![0; len]], offset)
+            .await?;
+        Ok(buffers.remove(0))
+    }
+
+    /// Flushes the data and the metadata of the file to disk.
+    pub async fn fsync(&self, file: &Arc<File>) -> io::Result<()> {
+        self.submit(Op::Fsync { file: file.clone() })
+            .await?
+            .result
+            .map(|_| ())
+    }
+
+    /// Truncates or extends the file to the given length.
+    pub async fn ftruncate(&self, file: &Arc<File>, len: u64) -> io::Result<()> {
+        self.submit(Op::Ftruncate {
+            file: file.clone(),
+            len,
+        })
+        .await?
+        .result
+        .map(|_| ())
+    }
+
+    async fn submit(&self, op: Op) -> io::Result<Completion> {
+        let (sender, receiver) = oneshot::channel();
+        if self.sender.send(Request { op, sender }).is_err() {
+            return Err(io::Error::other("io_uring worker is not running"));
+        }
+        receiver
+            .await
+            .map_err(|_| io::Error::other("io_uring worker has stopped"))
+    }
+}
+
+/// Copies the bytes into the consecutive buffers, starting at the given position of their concatenation.
+fn copy_into(buffers: &mut [Vec<u8>], position: usize, mut bytes: &[u8]) {
+    let mut skip = position;
+    for buffer in buffers.iter_mut() {
+        if bytes.is_empty() {
+            return;
+        }
+        if skip >= buffer.len() {
+            skip -= buffer.len();
+            continue;
+        }
+        let len = (buffer.len() - skip).min(bytes.len());
+        buffer[skip..skip + len].copy_from_slice(&bytes[..len]);
+        bytes = &bytes[len..];
+        skip = 0;
+    }
+}
+
+struct InFlight {
+    op: Op,
+    /// The vectors passed to the kernel for the vectored reads, must live until the completion.
+    iovecs: Vec<libc::iovec>,
+    sender: oneshot::Sender<Completion>,
+}
+
+struct Worker {
+    // The ring is dropped first, so the kernel no longer uses the in-flight buffers when they're released.
+    ring: IoUring,
+    receiver: Receiver<Request>,
+    in_flight: Vec<Option<InFlight>>,
+    free_slots: Vec<usize>,
+    completed: Vec<(u64, i32)>,
+    _buffers: Arc<BufferPool>,
+}
+
+impl Worker {
+    fn new(ring: IoUring, receiver: Receiver<Request>, buffers: Arc<BufferPool>) -> Self {
+        let capacity = ring.params().sq_entries() as usize;
+        Self {
+            ring,
+            receiver,
+            in_flight: Vec::with_capacity(capacity),
+            free_slots: Vec::with_capacity(capacity),
+            completed: Vec::with_capacity(capacity),
+            _buffers: buffers,
+        }
+    }
+
+    fn run(mut self) {
+        let capacity = self.ring.params().sq_entries() as usize;
+        let mut pending = 0;
+        loop {
+            if pending == 0 {
+                let Ok(request) = self.receiver.recv() else {
+                    return;
+                };
+                self.push(request);
+                pending += 1;
+            }
+
+            while pending < capacity {
+                let Ok(request) = self.receiver.try_recv() else {
+                    break;
+                };
+                self.push(request);
+                pending += 1;
+            }
+
+            if let Err(error) = self.ring.submit_and_wait(1) {
+                if error.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                error!("Failed to submit io_uring operations: {error}, stopping the worker.");
+                return;
+            }
+
+            self.completed.extend(
+                self.ring
+                    .completion()
+                    .map(|entry| (entry.user_data(), entry.result())),
+            );
+            let mut completed = std::mem::take(&mut self.completed);
+            for (user_data, result) in completed.drain(..) {
+                self.complete(user_data as usize, result);
+                pending -= 1;
+            }
+            self.completed = completed;
+        }
+    }
+
+    fn push(&mut self, request: Request) {
+        let slot = self.free_slots.pop().unwrap_or_else(|| {
+            self.in_flight.push(None);
+            self.in_flight.len() - 1
+        });
+        let mut in_flight = InFlight {
+            op: request.op,
+            iovecs: Vec::new(),
+            sender: request.sender,
+        };
+        let entry = match &mut in_flight.op {
+            Op::WriteFixed {
+                file,
+                buffer,
+                len,
+                offset,
+            } => opcode::WriteFixed::new(
+                types::Fd(file.as_raw_fd()),
+                buffer.ptr,
+                *len as u32,
+                buffer.index,
+            )
+            .offset(*offset)
+            .build(),
+            Op::Write {
+                file,
+                bytes,
+                offset,
+            } => opcode::Write::new(
+                types::Fd(file.as_raw_fd()),
+                bytes.as_ptr(),
+                bytes.len().min(u32::MAX as usize) as u32,
+            )
+            .offset(*offset)
+            .build(),
+            Op::Readv {
+                file,
+                buffers,
+                offset,
+            } => {
+                in_flight.iovecs = buffers
+                    .iter_mut()
+                    .map(|buffer| libc::iovec {
+                        iov_base: buffer.as_mut_ptr().cast(),
+                        iov_len: buffer.len(),
+                    })
+                    .collect();
+                opcode::Readv::new(
+                    types::Fd(file.as_raw_fd()),
+                    in_flight.iovecs.as_ptr(),
+                    in_flight.iovecs.len() as u32,
+                )
+                .offset(*offset)
+                .build()
+            }
+            Op::Fsync { file } => opcode::Fsync::new(types::Fd(file.as_raw_fd())).build(),
+            Op::Ftruncate { file, len } => {
+                opcode::Ftruncate::new(types::Fd(file.as_raw_fd()), *len).build()
+            }
+        }
+        .user_data(slot as u64);
+        self.in_flight[slot] = Some(in_flight);
+
+        // SAFETY: the file descriptor and the buffers referenced by the entry are owned by the in-flight slot,
+        // which is released only when the operation completes.
+        while unsafe { self.ring.submission().push(&entry) }.is_err() {
+            if let Err(error) = self.ring.submit() {
+                error!("Failed to submit io_uring operations: {error}");
+            }
+        }
+    }
+
+    fn complete(&mut self, slot: usize, result: i32) {
+        let Some(in_flight) = self.in_flight.get_mut(slot).and_then(Option::take) else {
+            error!("Received io_uring completion for an unknown operation: {slot}");
+            return;
+        };
+        self.free_slots.push(slot);
+
+        let result = match (&in_flight.op, result) {
+            (_, result) if result >= 0 => Ok(result as usize),
+            // Ftruncate is supported since Linux 6.9, older kernels reject the unknown opcode.
+            (Op::Ftruncate { file, len }, result) if result == -libc::EINVAL => {
+                file.set_len(*len).map(|_| 0)
+            }
+            (_, result) => Err(io::Error::from_raw_os_error(-result)),
+        };
+        let _ = in_flight.sender.send(Completion {
+            result,
+            op: in_flight.op,
+        });
+    }
+}
+
+/// A contiguous, block aligned memory region split into the buffers registered with the ring.
+struct BufferPool {
+    memory: *mut u8,
+    layout: Layout,
+    buffer_size: usize,
+    free: Mutex<Vec<u16>>,
+    available: Semaphore,
+}
+
+// SAFETY: the memory is only accessed through the `FixedBuffer` handles, each of them owning a distinct buffer.
+unsafe impl Send for BufferPool {}
+unsafe impl Sync for BufferPool {}
+
+impl BufferPool {
+    fn new(count: u16, buffer_size: usize) -> Self {
+        let layout = Layout::from_size_align(count as usize * buffer_size, BLOCK_SIZE)
+            .expect("Invalid layout of the io_uring buffers");
+        // SAFETY: the size of the layout is validated by the storage config to be non-zero.
+        let memory = unsafe { alloc_zeroed(layout) };
+        if memory.is_null() {
+            handle_alloc_error(layout);
+        }
+        Self {
+            memory,
+            layout,
+            buffer_size,
+            free: Mutex::new((0..count).rev().collect()),
+            available: Semaphore::new(count as usize),
+        }
+    }
+
+    fn iovecs(&self) -> Vec<libc::iovec> {
+        (0..self.layout.size() / self.buffer_size)
+            .map(|index| libc::iovec {
+                // SAFETY: the offset is within the allocated memory.
+                iov_base: unsafe { self.memory.add(index * self.buffer_size) }.cast(),
+                iov_len: self.buffer_size,
+            })
+            .collect()
+    }
+
+    async fn acquire(self: Arc<Self>) -> FixedBuffer {
+        self.available
+            .acquire()
+            .await
+            .expect("io_uring buffer pool is closed")
+            .forget();
+        let index = self
+            .free
+            .lock()
+            .unwrap()
+            .pop()
+            .expect("io_uring buffer pool is empty");
+        FixedBuffer {
+            index,
+            // SAFETY: the index is lower than the number of the buffers.
+            ptr: unsafe { self.memory.add(index as usize * self.buffer_size) },
+            len: self.buffer_size,
+            pool: self,
+        }
+    }
+
+    fn release(&self, index: u16) {
+        self.free.lock().unwrap().push(index);
+        self.available.add_permits(1);
+    }
+}
+
+impl Debug for BufferPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BufferPool")
+            .field("size", &self.layout.size())
+            .field("buffer_size", &self.buffer_size)
+            .finish()
+    }
+}
+
+impl Drop for BufferPool {
+    fn drop(&mut self) {
+        // SAFETY: the memory was allocated with the same layout.
+        unsafe { dealloc(self.memory, self.layout) };
+    }
+}
+
+/// Exclusive handle to one of the registered buffers, returned to the pool on drop.
+pub struct FixedBuffer {
+    index: u16,
+    ptr: *mut u8,
+    len: usize,
+    pool: Arc<BufferPool>,
+}
+
+// SAFETY: the handle has exclusive access to its buffer.
+unsafe impl Send for FixedBuffer {}
+
+impl FixedBuffer {
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: the buffer is owned exclusively by the handle.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: the buffer is owned exclusively by the handle.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl Debug for FixedBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FixedBuffer")
+            .field("index", &self.index)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl Drop for FixedBuffer {
+    fn drop(&mut self) {
+        self.pool.release(self.index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::FileExt;
+
+    fn create_file() -> (tempfile::TempDir, Arc<File>) {
+        let dir = tempfile::tempdir().unwrap();
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(dir.path().join("file"))
+            .unwrap();
+        (dir, Arc::new(file))
+    }
+
+    #[tokio::test]
+    async fn should_write_registered_buffer_and_read_it_back_vectored() {
+        let driver = UringDriver::start(8, 2, BLOCK_SIZE).unwrap();
+        let (_dir, file) = create_file();
+
+        let mut buffer = driver.acquire_buffer().await;
+        buffer.as_mut_slice()[..5].copy_from_slice(b"hello");
+        buffer.as_mut_slice()[5..10].copy_from_slice(b"world");
+        let written = driver.write_fixed(&file, buffer, 10, 0).await.unwrap();
+        driver.fsync(&file).await.unwrap();
+
+        let buffers = driver
+            .read_exact_vectored_at(&file, vec![vec![0; 5], vec![0; 5]], 0)
+            .await
+            .unwrap();
+
+        assert_eq!(written, 10);
+        assert_eq!(buffers, vec![b"hello".to_vec(), b"world".to_vec()]);
+    }
+
+    #[tokio::test]
+    async fn should_truncate_file_and_fail_reading_past_its_end() {
+        let driver = UringDriver::start(8, 1, BLOCK_SIZE).unwrap();
+        let (_dir, file) = create_file();
+
+        driver.write_all(&file, vec![1; 100], 0).await.unwrap();
+        driver.ftruncate(&file, 40).await.unwrap();
+
+        let mut bytes = vec![0; 40];
+        file.read_exact_at(&mut bytes, 0).unwrap();
+        let error = driver.read_exact_at(&file, 30, 20).await.unwrap_err();
+
+        assert_eq!(file.metadata().unwrap().len(), 40);
+        assert_eq!(bytes, vec![1; 40]);
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
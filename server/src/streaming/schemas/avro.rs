@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use apache_avro::schema_compatibility::SchemaCompatibility;
+use apache_avro::Schema;
+
+pub fn validate(definition: &str) -> Result<(), String> {
+    parse(definition).map(|_| ())
+}
+
+/// Checks if the data written with the writer schema can be read with the reader one, following the Avro schema resolution rules.
+pub fn can_read(writer: &str, reader: &str) -> Result<(), String> {
+    let writer = parse(writer)?;
+    let reader = parse(reader)?;
+    SchemaCompatibility::can_read(&writer, &reader).map_err(|error| error.to_string())
+}
+
+pub fn compile(definition: &str) -> Result<Schema, String> {
+    parse(definition)
+}
+
+/// Validates the Avro binary encoded payload (a single datum, without the container header) against the schema.
+pub fn validate_payload(schema: &Schema, mut payload: &[u8]) -> Result<(), String> {
+    apache_avro::from_avro_datum(schema, &mut payload, None).map_err(|error| error.to_string())?;
+    if !payload.is_empty() {
+        return Err(format!(
+            "{} trailing bytes after the decoded datum",
+            payload.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn parse(definition: &str) -> Result<Schema, String> {
+    Schema::parse_str(definition).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str = r#"{"type":"record","name":"Order","fields":[{"name":"id","type":"long"}]}"#;
+    const V2: &str = r#"{"type":"record","name":"Order","fields":[{"name":"id","type":"long"},{"name":"product","type":"string","default":""}]}"#;
+    const V3: &str = r#"{"type":"record","name":"Order","fields":[{"name":"id","type":"long"},{"name":"product","type":"string"}]}"#;
+
+    #[test]
+    fn invalid_definition_should_not_be_valid() {
+        assert!(validate(r#"{"type":"record"}"#).is_err());
+        assert!(validate(V1).is_ok());
+    }
+
+    #[test]
+    fn field_with_default_value_should_be_readable_from_data_without_it() {
+        assert!(can_read(V1, V2).is_ok());
+        assert!(can_read(V2, V1).is_ok());
+    }
+
+    #[test]
+    fn field_without_default_value_should_not_be_readable_from_data_without_it() {
+        assert!(can_read(V1, V3).is_err());
+    }
+
+    #[test]
+    fn payload_should_be_validated_against_schema() {
+        let schema = compile(V1).unwrap();
+        let record = apache_avro::types::Value::Record(vec![(
+            "id".to_string(),
+            apache_avro::types::Value::Long(7),
+        )]);
+        let payload = apache_avro::to_avro_datum(&schema, record).unwrap();
+        assert!(validate_payload(&schema, &payload).is_ok());
+        assert!(validate_payload(&schema, &[]).is_err());
+        let mut payload = payload;
+        payload.push(0);
+        assert!(validate_payload(&schema, &payload).is_err());
+    }
+}
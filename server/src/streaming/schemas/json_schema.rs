@@ -0,0 +1,298 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use jsonschema::Validator;
+use serde_json::{Map, Value};
+
+pub fn validate(definition: &str) -> Result<(), String> {
+    compile(definition).map(|_| ())
+}
+
+pub fn compile(definition: &str) -> Result<Validator, String> {
+    let schema = parse(definition)?;
+    jsonschema::validator_for(&schema).map_err(|error| error.to_string())
+}
+
+/// Validates the JSON payload against the compiled schema, returning the first violation with its location.
+pub fn validate_payload(validator: &Validator, payload: &[u8]) -> Result<(), String> {
+    let instance: Value = serde_json::from_slice(payload)
+        .map_err(|error| format!("payload is not a valid JSON: {error}"))?;
+    let violation = validator
+        .iter_errors(&instance)
+        .next()
+        .map(|error| format!("{}: {error}", location(&error.instance_path)));
+    match violation {
+        Some(violation) => Err(violation),
+        None => Ok(()),
+    }
+}
+
+fn location(path: &jsonschema::paths::Location) -> String {
+    let path = path.to_string();
+    if path.is_empty() {
+        "$".to_string()
+    } else {
+        format!("${path}")
+    }
+}
+
+/// Checks if every document valid against the writer schema is also valid against the reader one.
+/// The check is structural and covers the subset of the JSON Schema commonly used to describe the messages:
+/// `type`, `enum`, `properties`, `required`, `additionalProperties` and `items`, other keywords are not compared.
+pub fn can_read(writer: &str, reader: &str) -> Result<(), String> {
+    let writer = parse(writer)?;
+    let reader = parse(reader)?;
+    check(&writer, &reader, "$")
+}
+
+fn parse(definition: &str) -> Result<Value, String> {
+    serde_json::from_str(definition).map_err(|error| error.to_string())
+}
+
+fn check(writer: &Value, reader: &Value, path: &str) -> Result<(), String> {
+    let reader = match reader {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => {
+            return match writer {
+                Value::Bool(false) => Ok(()),
+                _ => Err(format!("{path}: no value is accepted by the reader")),
+            }
+        }
+        Value::Object(reader) if reader.is_empty() => return Ok(()),
+        Value::Object(reader) => reader,
+        _ => return Err(format!("{path}: invalid reader schema")),
+    };
+    let writer = match writer {
+        Value::Bool(false) => return Ok(()),
+        Value::Bool(true) => {
+            return Err(format!(
+                "{path}: any value is accepted by the writer, but the reader is constrained"
+            ))
+        }
+        Value::Object(writer) => writer,
+        _ => return Err(format!("{path}: invalid writer schema")),
+    };
+
+    check_types(writer, reader, path)?;
+    check_enum(writer, reader, path)?;
+    check_properties(writer, reader, path)?;
+    if let (Some(writer_items), Some(reader_items)) = (writer.get("items"), reader.get("items")) {
+        check(writer_items, reader_items, &format!("{path}[]"))?;
+    }
+
+    Ok(())
+}
+
+fn check_types(
+    writer: &Map<String, Value>,
+    reader: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(reader_types) = types(reader) else {
+        return Ok(());
+    };
+
+    let Some(writer_types) = types(writer) else {
+        return Err(format!(
+            "{path}: any type is accepted by the writer, but the reader accepts only: {}",
+            reader_types.join(", ")
+        ));
+    };
+
+    for writer_type in writer_types {
+        let accepted = reader_types.contains(&writer_type)
+            || (writer_type == "integer" && reader_types.contains(&"number"));
+        if !accepted {
+            return Err(format!(
+                "{path}: type '{writer_type}' is not accepted by the reader"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn types(schema: &Map<String, Value>) -> Option<Vec<&str>> {
+    match schema.get("type")? {
+        Value::String(value) => Some(vec![value.as_str()]),
+        Value::Array(values) => Some(values.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+fn check_enum(
+    writer: &Map<String, Value>,
+    reader: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(Value::Array(reader_values)) = reader.get("enum") else {
+        return Ok(());
+    };
+
+    let Some(Value::Array(writer_values)) = writer.get("enum") else {
+        return Err(format!(
+            "{path}: only the enumerated values are accepted by the reader"
+        ));
+    };
+
+    if let Some(value) = writer_values
+        .iter()
+        .find(|value| !reader_values.contains(value))
+    {
+        return Err(format!(
+            "{path}: value {value} is not accepted by the reader"
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_properties(
+    writer: &Map<String, Value>,
+    reader: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let writer_required = required(writer);
+    for property in required(reader) {
+        if !writer_required.contains(&property) {
+            return Err(format!(
+                "{path}: property '{property}' is required by the reader, but optional for the writer"
+            ));
+        }
+    }
+
+    let empty = Map::new();
+    let writer_properties = properties(writer).unwrap_or(&empty);
+    let reader_properties = properties(reader).unwrap_or(&empty);
+    let reader_additional = reader.get("additionalProperties");
+    for (name, writer_property) in writer_properties {
+        let path = format!("{path}.{name}");
+        match (reader_properties.get(name), reader_additional) {
+            (Some(reader_property), _) => check(writer_property, reader_property, &path)?,
+            (None, Some(Value::Bool(false))) => {
+                return Err(format!("{path}: property is not allowed by the reader"))
+            }
+            (None, Some(reader_additional @ Value::Object(_))) => {
+                check(writer_property, reader_additional, &path)?
+            }
+            _ => {}
+        }
+    }
+
+    // The properties defined only by the reader could still be written as the additional properties.
+    let writer_additional = writer.get("additionalProperties");
+    for (name, reader_property) in reader_properties {
+        if writer_properties.contains_key(name) {
+            continue;
+        }
+
+        let path = format!("{path}.{name}");
+        match writer_additional {
+            Some(Value::Bool(false)) => {}
+            Some(writer_additional @ Value::Object(_)) => {
+                check(writer_additional, reader_property, &path)?
+            }
+            _ => check(&Value::Bool(true), reader_property, &path)?,
+        }
+    }
+
+    if writer_additional != Some(&Value::Bool(false))
+        && reader_additional == Some(&Value::Bool(false))
+    {
+        return Err(format!(
+            "{path}: additional properties are allowed by the writer, but not by the reader"
+        ));
+    }
+
+    Ok(())
+}
+
+fn required(schema: &Map<String, Value>) -> Vec<&str> {
+    match schema.get("required") {
+        Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn properties(schema: &Map<String, Value>) -> Option<&Map<String, Value>> {
+    schema.get("properties")?.as_object()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str =
+        r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#;
+
+    #[test]
+    fn payload_should_be_validated_against_schema() {
+        let validator = compile(V1).unwrap();
+        assert!(validate_payload(&validator, br#"{"id":1}"#).is_ok());
+        let error = validate_payload(&validator, br#"{"id":"1"}"#).unwrap_err();
+        assert!(error.starts_with("$/id:"));
+        assert!(validate_payload(&validator, br#"{"name":"order"}"#).is_err());
+        assert!(validate_payload(&validator, b"not json").is_err());
+    }
+
+    #[test]
+    fn invalid_definition_should_not_be_valid() {
+        assert!(validate(r#"{"type":"unknown"}"#).is_err());
+        assert!(validate("{").is_err());
+        assert!(validate(V1).is_ok());
+    }
+
+    #[test]
+    fn widened_type_should_be_readable() {
+        let v2 = r#"{"type":"object","properties":{"id":{"type":"number"}},"required":["id"]}"#;
+        assert!(can_read(V1, v2).is_ok());
+        assert!(can_read(v2, V1).is_err());
+    }
+
+    #[test]
+    fn typed_property_added_to_open_schema_should_be_readable_only_by_old_reader() {
+        let v2 = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id"]}"#;
+        assert!(can_read(V1, v2).is_err());
+        assert!(can_read(v2, V1).is_ok());
+    }
+
+    #[test]
+    fn property_added_to_closed_schema_should_be_readable_in_both_directions() {
+        let v1 = r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"],"additionalProperties":false}"#;
+        let v2 = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id"]}"#;
+        let v3 = r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"],"additionalProperties":{"type":"string"}}"#;
+        assert!(can_read(v1, v2).is_ok());
+        assert!(can_read(v2, V1).is_ok());
+        assert!(can_read(v3, v2).is_ok());
+    }
+
+    #[test]
+    fn property_not_allowed_by_reader_should_not_be_readable() {
+        let v2 = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id"],"additionalProperties":false}"#;
+        let v3 = r#"{"type":"object","properties":{"name":{"type":"string"}},"additionalProperties":false}"#;
+        assert!(can_read(v2, v3).is_err());
+    }
+
+    #[test]
+    fn narrowed_enum_should_not_be_readable() {
+        let v1 = r#"{"type":"string","enum":["a","b"]}"#;
+        let v2 = r#"{"type":"string","enum":["a"]}"#;
+        assert!(can_read(v2, v1).is_ok());
+        assert!(can_read(v1, v2).is_err());
+    }
+}
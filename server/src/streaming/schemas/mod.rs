@@ -0,0 +1,202 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod avro;
+pub mod json_schema;
+pub mod protobuf;
+
+use iggy::error::IggyError;
+use iggy::models::schema::{Schema, SchemaCompatibility, SchemaFormat};
+use tracing::error;
+
+pub const COMPONENT: &str = "STREAMING_SCHEMAS";
+
+/// The compiled schema definition, used to validate the message payloads.
+#[derive(Debug)]
+pub enum PayloadValidator {
+    Avro(apache_avro::Schema),
+    JsonSchema(jsonschema::Validator),
+    Protobuf(protobuf::Definition),
+}
+
+impl PayloadValidator {
+    pub fn new(format: SchemaFormat, definition: &str) -> Result<Self, IggyError> {
+        let validator = match format {
+            SchemaFormat::Avro => avro::compile(definition).map(PayloadValidator::Avro),
+            SchemaFormat::JsonSchema => {
+                json_schema::compile(definition).map(PayloadValidator::JsonSchema)
+            }
+            SchemaFormat::Protobuf => protobuf::compile(definition).map(PayloadValidator::Protobuf),
+        };
+        validator.map_err(|reason| {
+            error!("{COMPONENT} - invalid {format} schema definition: {reason}");
+            IggyError::InvalidSchemaDefinition(reason)
+        })
+    }
+
+    /// Validates the message payload, returning the reason if it doesn't match the schema.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        match self {
+            PayloadValidator::Avro(schema) => avro::validate_payload(schema, payload),
+            PayloadValidator::JsonSchema(validator) => {
+                json_schema::validate_payload(validator, payload)
+            }
+            PayloadValidator::Protobuf(definition) => {
+                protobuf::validate_payload(definition, payload)
+            }
+        }
+    }
+}
+
+/// Validates the schema definition in the provided format.
+pub fn validate(format: SchemaFormat, definition: &str) -> Result<(), IggyError> {
+    PayloadValidator::new(format, definition).map(|_| ())
+}
+
+/// Checks if the new schema definition can be registered after the previous version, according to the compatibility rule.
+/// Backward compatibility means that the data written with the previous version can be read with the new one,
+/// while forward compatibility means that the data written with the new version can be read with the previous one.
+pub fn check_compatibility(
+    compatibility: SchemaCompatibility,
+    previous: &Schema,
+    format: SchemaFormat,
+    definition: &str,
+) -> Result<(), IggyError> {
+    if compatibility == SchemaCompatibility::None {
+        return Ok(());
+    }
+
+    if previous.format != format {
+        return Err(incompatible(
+            previous,
+            format!(
+                "format cannot be changed from {} to {format}",
+                previous.format
+            ),
+        ));
+    }
+
+    if compatibility.is_backward() {
+        can_read(format, &previous.definition, definition).map_err(|reason| {
+            incompatible(
+                previous,
+                format!("data written with the previous version cannot be read: {reason}"),
+            )
+        })?;
+    }
+
+    if compatibility.is_forward() {
+        can_read(format, definition, &previous.definition).map_err(|reason| {
+            incompatible(
+                previous,
+                format!("data written with the new version cannot be read by the previous one: {reason}"),
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+fn can_read(format: SchemaFormat, writer: &str, reader: &str) -> Result<(), String> {
+    match format {
+        SchemaFormat::Avro => avro::can_read(writer, reader),
+        SchemaFormat::JsonSchema => json_schema::can_read(writer, reader),
+        SchemaFormat::Protobuf => protobuf::can_read(writer, reader),
+    }
+}
+
+fn incompatible(previous: &Schema, reason: String) -> IggyError {
+    error!(
+        "{COMPONENT} - schema is incompatible with version: {} (ID: {}): {reason}",
+        previous.version, previous.id
+    );
+    IggyError::IncompatibleSchema(format!(
+        "version: {} (ID: {}), {reason}",
+        previous.version, previous.id
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    fn schema(format: SchemaFormat, definition: &str) -> Schema {
+        Schema {
+            id: 1,
+            stream_id: 1,
+            topic_id: 1,
+            version: 1,
+            format,
+            created_at: IggyTimestamp::now(),
+            definition: definition.to_string(),
+        }
+    }
+
+    #[test]
+    fn format_change_should_not_be_allowed_unless_compatibility_is_none() {
+        let previous = schema(SchemaFormat::JsonSchema, r#"{"type":"string"}"#);
+        let definition = r#""string""#;
+        assert!(check_compatibility(
+            SchemaCompatibility::Backward,
+            &previous,
+            SchemaFormat::Avro,
+            definition
+        )
+        .is_err());
+        assert!(check_compatibility(
+            SchemaCompatibility::None,
+            &previous,
+            SchemaFormat::Avro,
+            definition
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn adding_required_field_should_be_forward_but_not_backward_compatible() {
+        let previous = schema(
+            SchemaFormat::JsonSchema,
+            r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#,
+        );
+        let definition = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id","name"]}"#;
+        assert!(check_compatibility(
+            SchemaCompatibility::Forward,
+            &previous,
+            SchemaFormat::JsonSchema,
+            definition
+        )
+        .is_ok());
+        assert!(matches!(
+            check_compatibility(
+                SchemaCompatibility::Backward,
+                &previous,
+                SchemaFormat::JsonSchema,
+                definition
+            ),
+            Err(IggyError::IncompatibleSchema(_))
+        ));
+        assert!(check_compatibility(
+            SchemaCompatibility::Full,
+            &previous,
+            SchemaFormat::JsonSchema,
+            definition
+        )
+        .is_err());
+    }
+}
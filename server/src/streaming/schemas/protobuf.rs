@@ -0,0 +1,321 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use prost_reflect::{
+    Cardinality, DescriptorPool, DynamicMessage, Kind, MessageDescriptor, ReflectMessage, Value,
+};
+use protox::file::{File, FileResolver, GoogleFileResolver};
+use protox::Compiler;
+
+/// The name under which the definition is compiled, as it's stored without the file name.
+const FILE_NAME: &str = "schema.proto";
+
+/// The groups of the scalar types sharing the same wire encoding, which can be changed without breaking the readers.
+const COMPATIBLE_KINDS: &[&[Kind]] = &[
+    &[
+        Kind::Int32,
+        Kind::Uint32,
+        Kind::Int64,
+        Kind::Uint64,
+        Kind::Bool,
+    ],
+    &[Kind::Sint32, Kind::Sint64],
+    &[Kind::Fixed32, Kind::Sfixed32],
+    &[Kind::Fixed64, Kind::Sfixed64],
+    &[Kind::String, Kind::Bytes],
+];
+
+/// The compiled definition, used to validate the binary encoded payloads of its first top-level (root) message.
+#[derive(Debug)]
+pub struct Definition {
+    root: MessageDescriptor,
+}
+
+/// Resolves the definition as the only source file, which can import the well-known Google types.
+struct DefinitionResolver {
+    definition: String,
+}
+
+impl FileResolver for DefinitionResolver {
+    fn open_file(&self, name: &str) -> Result<File, protox::Error> {
+        if name == FILE_NAME {
+            return File::from_source(name, &self.definition);
+        }
+
+        GoogleFileResolver::new().open_file(name)
+    }
+}
+
+pub fn validate(definition: &str) -> Result<(), String> {
+    compile(definition).map(|_| ())
+}
+
+pub fn compile(definition: &str) -> Result<Definition, String> {
+    let pool = parse(definition)?;
+    let Some(root) = pool
+        .get_file_by_name(FILE_NAME)
+        .and_then(|file| file.messages().next())
+    else {
+        return Err("no message is defined".to_string());
+    };
+
+    Ok(Definition { root })
+}
+
+/// Validates the binary encoded payload of the root message.
+/// The fields must be decodable as their declared types, and the required fields must be present, also in the nested messages.
+/// The unknown fields are skipped, as the readers preserve them.
+pub fn validate_payload(definition: &Definition, payload: &[u8]) -> Result<(), String> {
+    let message = DynamicMessage::decode(definition.root.clone(), payload)
+        .map_err(|error| error.to_string())?;
+    validate_required_fields(&message)
+}
+
+fn validate_required_fields(message: &DynamicMessage) -> Result<(), String> {
+    let descriptor = message.descriptor();
+    if let Some(field) = descriptor
+        .fields()
+        .find(|field| field.cardinality() == Cardinality::Required && !message.has_field(field))
+    {
+        return Err(format!(
+            "{}: required field '{}' with tag {} is missing",
+            descriptor.full_name(),
+            field.name(),
+            field.number()
+        ));
+    }
+
+    for (_, value) in message.fields() {
+        validate_required_values(value)?;
+    }
+
+    Ok(())
+}
+
+fn validate_required_values(value: &Value) -> Result<(), String> {
+    match value {
+        Value::Message(message) => validate_required_fields(message),
+        Value::List(values) => values.iter().try_for_each(validate_required_values),
+        Value::Map(values) => values.values().try_for_each(validate_required_values),
+        _ => Ok(()),
+    }
+}
+
+/// Checks if the data written with the writer definition can be read with the reader one.
+/// The fields of the messages present in both definitions are matched by their tags, and must have the compatible types and labels.
+/// The required fields of the reader must also be present in the writer.
+pub fn can_read(writer: &str, reader: &str) -> Result<(), String> {
+    let writer = parse(writer)?;
+    let reader = parse(reader)?;
+    for reader_message in reader
+        .all_messages()
+        .filter(|message| message.parent_file().name() == FILE_NAME)
+    {
+        let message = reader_message.full_name();
+        let Some(writer_message) = writer.get_message_by_name(message) else {
+            continue;
+        };
+
+        for reader_field in reader_message.fields() {
+            let tag = reader_field.number();
+            let Some(writer_field) = writer_message.get_field(tag) else {
+                if reader_field.cardinality() == Cardinality::Required {
+                    return Err(format!(
+                        "{message}: required field '{}' with tag {tag} is missing in the writer",
+                        reader_field.name()
+                    ));
+                }
+                continue;
+            };
+
+            if (writer_field.cardinality() == Cardinality::Repeated)
+                != (reader_field.cardinality() == Cardinality::Repeated)
+            {
+                return Err(format!(
+                    "{message}: field with tag {tag} cannot be changed between repeated and singular"
+                ));
+            }
+
+            let (writer_kind, reader_kind) = (writer_field.kind(), reader_field.kind());
+            if !compatible_kinds(&writer_kind, &reader_kind) {
+                return Err(format!(
+                    "{message}: type of field with tag {tag} cannot be changed from '{}' to '{}'",
+                    kind_name(&writer_kind),
+                    kind_name(&reader_kind)
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The messages and enums are compared by their names, as the messages are checked on their own.
+fn compatible_kinds(writer: &Kind, reader: &Kind) -> bool {
+    match (writer, reader) {
+        (Kind::Message(writer), Kind::Message(reader)) => writer.name() == reader.name(),
+        (Kind::Enum(writer), Kind::Enum(reader)) => writer.name() == reader.name(),
+        _ => {
+            writer == reader
+                || COMPATIBLE_KINDS
+                    .iter()
+                    .any(|group| group.contains(writer) && group.contains(reader))
+        }
+    }
+}
+
+fn kind_name(kind: &Kind) -> String {
+    match kind {
+        Kind::Message(message) => message.full_name().to_string(),
+        Kind::Enum(value) => value.full_name().to_string(),
+        scalar => format!("{scalar:?}").to_lowercase(),
+    }
+}
+
+fn parse(definition: &str) -> Result<DescriptorPool, String> {
+    let mut compiler = Compiler::with_file_resolver(DefinitionResolver {
+        definition: definition.to_string(),
+    });
+    compiler
+        .open_file(FILE_NAME)
+        .map_err(|error| error.to_string())?;
+    Ok(compiler.descriptor_pool())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str = r#"
+        syntax = "proto3";
+        package orders;
+
+        // The order created event.
+        message OrderCreated {
+            uint64 id = 1;
+            string product = 2 [json_name = "product"];
+            repeated string tags = 3;
+            map<string, string> attributes = 4;
+            oneof payment {
+                string card = 5;
+                string transfer = 6;
+            }
+            message Item {
+                int32 quantity = 1;
+            }
+        }
+    "#;
+
+    #[test]
+    fn definition_should_be_compiled() {
+        let definition = compile(V1).unwrap();
+        assert_eq!(definition.root.full_name(), "orders.OrderCreated");
+        assert_eq!(definition.root.fields().len(), 6);
+        assert!(definition.root.get_field(4).unwrap().is_map());
+        assert!(definition.root.get_field(3).unwrap().is_list());
+        let item = definition
+            .root
+            .child_messages()
+            .find(|message| message.name() == "Item");
+        assert_eq!(item.unwrap().get_field(1).unwrap().name(), "quantity");
+    }
+
+    #[test]
+    fn invalid_definition_should_not_be_valid() {
+        assert!(validate("message Order { string id = ; }").is_err());
+        assert!(validate("syntax = \"proto3\";").is_err());
+        assert!(validate("syntax = \"proto3\"; message Order { Item item = 1; }").is_err());
+        assert!(validate(V1).is_ok());
+    }
+
+    #[test]
+    fn definition_with_well_known_import_should_be_valid() {
+        let definition = r#"
+            syntax = "proto3";
+            import "google/protobuf/timestamp.proto";
+            message Order { google.protobuf.Timestamp created_at = 1; }
+        "#;
+        assert!(validate(definition).is_ok());
+    }
+
+    #[test]
+    fn field_with_compatible_type_should_be_readable() {
+        let v2 = V1.replace("uint64 id = 1", "int64 identifier = 1");
+        assert!(can_read(V1, &v2).is_ok());
+    }
+
+    #[test]
+    fn field_with_incompatible_type_should_not_be_readable() {
+        let v2 = V1.replace("uint64 id = 1", "string id = 1");
+        assert!(can_read(V1, &v2).is_err());
+        let v3 = V1.replace("repeated string tags = 3", "string tags = 3");
+        assert!(can_read(V1, &v3).is_err());
+        let v4 = V1.replace("map<string, string>", "map<string, int32>");
+        assert!(can_read(V1, &v4).is_err());
+    }
+
+    #[test]
+    fn valid_payload_should_match_definition() {
+        let definition = compile(V1).unwrap();
+        // id = 1, product = "abc", tags = ["x"], card = "c"
+        let payload = [
+            0x08, 0x01, 0x12, 0x03, b'a', b'b', b'c', 0x1a, 0x01, b'x', 0x2a, 0x01, b'c',
+        ];
+        assert!(validate_payload(&definition, &payload).is_ok());
+        assert!(validate_payload(&definition, &[]).is_ok());
+    }
+
+    #[test]
+    fn payload_with_invalid_wire_type_should_not_match_definition() {
+        let definition = compile(V1).unwrap();
+        // id as length-delimited value
+        assert!(validate_payload(&definition, &[0x0a, 0x01, 0x00]).is_err());
+        // product as varint
+        assert!(validate_payload(&definition, &[0x10, 0x01]).is_err());
+        // truncated product
+        assert!(validate_payload(&definition, &[0x12, 0x05, b'a']).is_err());
+    }
+
+    #[test]
+    fn payload_with_invalid_nested_message_should_not_match_definition() {
+        let definition = compile(
+            "syntax = \"proto3\"; message Order { Item item = 1; message Item { int32 quantity = 1; } }",
+        )
+        .unwrap();
+        assert!(validate_payload(&definition, &[0x0a, 0x02, 0x08, 0x05]).is_ok());
+        assert!(validate_payload(&definition, &[0x0a, 0x02, 0x0a, 0x00]).is_err());
+    }
+
+    #[test]
+    fn payload_without_required_field_should_not_match_definition() {
+        let definition = compile(
+            "syntax = \"proto2\"; message Order { required int64 id = 1; optional Item item = 2; message Item { required int32 quantity = 1; } }",
+        )
+        .unwrap();
+        assert!(validate_payload(&definition, &[0x08, 0x01]).is_ok());
+        assert!(validate_payload(&definition, &[]).is_err());
+        assert!(validate_payload(&definition, &[0x08, 0x01, 0x12, 0x00]).is_err());
+    }
+
+    #[test]
+    fn missing_required_field_should_not_be_readable() {
+        let v1 = "syntax = \"proto2\"; message Order { required int64 id = 1; }";
+        let v2 = "syntax = \"proto2\"; message Order { required int64 id = 1; required string product = 2; }";
+        assert!(can_read(v2, v1).is_ok());
+        assert!(can_read(v1, v2).is_err());
+    }
+}
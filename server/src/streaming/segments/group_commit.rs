@@ -0,0 +1,193 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::fs::{File, OpenOptions};
+use tokio::sync::Mutex;
+use tracing::{error, trace};
+
+/// Shares a single fsync of the segment files between the concurrent writers (group commit).
+///
+/// Every persisted batch is registered as a write. The first writer waiting for its write to become durable
+/// becomes the leader and syncs the files, which covers all the writes registered so far, so the writers
+/// queued behind the leader usually return without issuing their own fsync.
+#[derive(Debug)]
+pub struct GroupCommit {
+    log_path: String,
+    log_file: File,
+    index_file: File,
+    written: AtomicU64,
+    synced: AtomicU64,
+    leader: Mutex<()>,
+}
+
+/// The write which has to be synced to disk before it's acknowledged.
+#[derive(Debug)]
+pub struct PendingSync {
+    group_commit: Arc<GroupCommit>,
+    sequence: u64,
+}
+
+impl GroupCommit {
+    pub async fn open(log_path: &str, index_path: &str) -> Result<Self, IggyError> {
+        Ok(Self {
+            log_path: log_path.to_owned(),
+            log_file: open_file(log_path).await?,
+            index_file: open_file(index_path).await?,
+            written: AtomicU64::new(0),
+            synced: AtomicU64::new(0),
+            leader: Mutex::new(()),
+        })
+    }
+
+    /// Registers the batch which has just been written to the segment files.
+    pub fn register_write(self: &Arc<Self>) -> PendingSync {
+        let sequence = self.written.fetch_add(1, Ordering::AcqRel) + 1;
+        PendingSync {
+            group_commit: self.clone(),
+            sequence,
+        }
+    }
+
+    /// Registers the batch which has just been written to the segment files without waiting for it to be synced.
+    /// The sync is done in the background, so the write is still covered by the group commit.
+    pub fn enqueue_write(self: &Arc<Self>) {
+        let pending_sync = self.register_write();
+        let log_path = self.log_path.clone();
+        tokio::spawn(async move {
+            if let Err(error) = pending_sync.wait().await {
+                error!("Failed to sync the enqueued write for log file: {log_path}. {error}");
+            }
+        });
+    }
+
+    async fn sync(&self, sequence: u64) -> Result<(), IggyError> {
+        if self.synced.load(Ordering::Acquire) >= sequence {
+            return Ok(());
+        }
+
+        let _leader = self.leader.lock().await;
+        let synced = self.synced.load(Ordering::Acquire);
+        if synced >= sequence {
+            return Ok(());
+        }
+
+        let written = self.written.load(Ordering::Acquire);
+        sync_file(&self.log_file, &self.log_path).await?;
+        sync_file(&self.index_file, &self.log_path).await?;
+        self.synced.store(written, Ordering::Release);
+        trace!(
+            "Synced {} write(s) with a single fsync for log file: {}",
+            written - synced,
+            self.log_path
+        );
+        Ok(())
+    }
+}
+
+impl PendingSync {
+    /// Waits until the write is synced to disk, either by this or by one of the concurrent writers.
+    pub async fn wait(self) -> Result<(), IggyError> {
+        self.group_commit.sync(self.sequence).await
+    }
+}
+
+async fn open_file(path: &str) -> Result<File, IggyError> {
+    OpenOptions::new()
+        .append(true)
+        .open(path)
+        .await
+        .with_error_context(|error| {
+            format!("Failed to open file for group commit: {path}. {error}")
+        })
+        .map_err(|_| IggyError::CannotReadFile)
+}
+
+async fn sync_file(file: &File, log_path: &str) -> Result<(), IggyError> {
+    file.sync_data()
+        .await
+        .with_error_context(|error| {
+            format!("Failed to sync segment with log file: {log_path}. {error}")
+        })
+        .map_err(|_| IggyError::CannotSyncFile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn leader_sync_should_cover_all_registered_writes() {
+        let group_commit = create_group_commit().await;
+        let first = group_commit.register_write();
+        let second = group_commit.register_write();
+
+        second.wait().await.unwrap();
+
+        assert_eq!(group_commit.synced.load(Ordering::Acquire), 2);
+        first.wait().await.unwrap();
+        assert_eq!(group_commit.synced.load(Ordering::Acquire), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_writers_should_complete_their_syncs() {
+        let group_commit = create_group_commit().await;
+        let writers = (0..10)
+            .map(|_| {
+                let pending_sync = group_commit.register_write();
+                tokio::spawn(pending_sync.wait())
+            })
+            .collect::<Vec<_>>();
+
+        for writer in writers {
+            writer.await.unwrap().unwrap();
+        }
+        assert_eq!(group_commit.synced.load(Ordering::Acquire), 10);
+    }
+
+    #[tokio::test]
+    async fn enqueued_write_should_be_synced_in_background() {
+        let group_commit = create_group_commit().await;
+        group_commit.enqueue_write();
+
+        for _ in 0..100 {
+            if group_commit.synced.load(Ordering::Acquire) == 1 {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("Enqueued write has not been synced.");
+    }
+
+    async fn create_group_commit() -> Arc<GroupCommit> {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("segment.log");
+        let index_path = temp_dir.path().join("segment.index");
+        std::fs::File::create(&log_path).unwrap();
+        std::fs::File::create(&index_path).unwrap();
+        let group_commit =
+            GroupCommit::open(log_path.to_str().unwrap(), index_path.to_str().unwrap())
+                .await
+                .unwrap();
+        Arc::new(group_commit)
+    }
+}
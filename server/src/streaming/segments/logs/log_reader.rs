@@ -16,6 +16,11 @@
  * under the License.
  */
 
+#[cfg(feature = "io-uring")]
+use crate::configs::system::StorageBackend;
+use crate::configs::system::StorageConfig;
+#[cfg(feature = "io-uring")]
+use crate::streaming::persistence::uring::{self, UringDriver};
 use crate::streaming::{
     batching::{
         iterator::IntoMessagesIterator,
@@ -46,11 +51,22 @@ pub struct SegmentLogReader {
     file_path: String,
     file: Arc<File>,
     log_size_bytes: Arc<AtomicU64>,
+    /// Set when the io_uring storage backend is used for the reads.
+    #[cfg(feature = "io-uring")]
+    uring: Option<&'static UringDriver>,
+    /// Position and bytes of the batch header read together with the payload of the previous batch.
+    #[cfg(feature = "io-uring")]
+    prefetched_header: std::sync::Mutex<Option<(u64, Vec<u8>)>>,
 }
 
 impl SegmentLogReader {
     /// Opens the log file in read mode.
-    pub async fn new(file_path: &str, log_size_bytes: Arc<AtomicU64>) -> Result<Self, IggyError> {
+    #[cfg_attr(not(feature = "io-uring"), allow(unused_variables))]
+    pub async fn new(
+        file_path: &str,
+        log_size_bytes: Arc<AtomicU64>,
+        storage: &StorageConfig,
+    ) -> Result<Self, IggyError> {
         let file = OpenOptions::new()
             .read(true)
             .open(file_path)
@@ -83,10 +99,26 @@ impl SegmentLogReader {
 
         log_size_bytes.store(actual_log_size, Ordering::Release);
 
+        #[cfg(feature = "io-uring")]
+        let uring = match storage.backend {
+            StorageBackend::IoUring => Some(
+                uring::driver(storage)
+                    .with_error_context(|error| {
+                        format!("Failed to start io_uring for log file: {file_path}. {error}")
+                    })
+                    .map_err(|_| IggyError::CannotReadFile)?,
+            ),
+            StorageBackend::Tokio => None,
+        };
+
         Ok(Self {
             file_path: file_path.to_string(),
             file: Arc::new(file),
             log_size_bytes,
+            #[cfg(feature = "io-uring")]
+            uring,
+            #[cfg(feature = "io-uring")]
+            prefetched_header: std::sync::Mutex::new(None),
         })
     }
 
@@ -244,7 +276,7 @@ impl SegmentLogReader {
             return Ok(None);
         }
 
-        let header_buf = match self.read_at(offset, batch_header_size).await {
+        let header_buf = match self.read_batch_header(offset).await {
             Ok(buf) => buf,
             Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
             Err(error) => {
@@ -318,7 +350,10 @@ impl SegmentLogReader {
             return Ok(None);
         }
 
-        let payload_buf = match self.read_at(payload_offset, payload_len as u64).await {
+        let payload_buf = match self
+            .read_batch_payload(payload_offset, payload_len as u64)
+            .await
+        {
             Ok(buf) => buf,
             Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
             Err(error) => {
@@ -346,7 +381,52 @@ impl SegmentLogReader {
         self.log_size_bytes.load(Ordering::Acquire)
     }
 
+    async fn read_batch_header(&self, offset: u64) -> Result<Vec<u8>, std::io::Error> {
+        #[cfg(feature = "io-uring")]
+        {
+            let mut prefetched_header = self.prefetched_header.lock().unwrap();
+            if prefetched_header
+                .as_ref()
+                .is_some_and(|(position, _)| *position == offset)
+            {
+                let (_, header) = prefetched_header.take().unwrap();
+                return Ok(header);
+            }
+        }
+
+        self.read_at(offset, RETAINED_BATCH_HEADER_LEN).await
+    }
+
+    /// Reads the batch payload; with io_uring, the header of the next batch is fetched in the same vectored read.
+    async fn read_batch_payload(&self, offset: u64, len: u64) -> Result<Vec<u8>, std::io::Error> {
+        #[cfg(feature = "io-uring")]
+        if let Some(driver) = self.uring {
+            let next_header_position = offset + len;
+            if next_header_position + RETAINED_BATCH_HEADER_LEN <= self.file_size() {
+                let mut buffers = driver
+                    .read_exact_vectored_at(
+                        &self.file,
+                        vec![
+                            vec![0; len as usize],
+                            vec![0; RETAINED_BATCH_HEADER_LEN as usize],
+                        ],
+                        offset,
+                    )
+                    .await?;
+                let next_header = buffers.pop().unwrap();
+                *self.prefetched_header.lock().unwrap() = Some((next_header_position, next_header));
+                return Ok(buffers.pop().unwrap());
+            }
+        }
+        self.read_at(offset, len).await
+    }
+
     async fn read_at(&self, offset: u64, len: u64) -> Result<Vec<u8>, std::io::Error> {
+        #[cfg(feature = "io-uring")]
+        if let Some(driver) = self.uring {
+            return driver.read_exact_at(&self.file, offset, len as usize).await;
+        }
+
         let file = self.file.clone();
         spawn_blocking(move || {
             let mut buf = vec![0u8; len as usize];
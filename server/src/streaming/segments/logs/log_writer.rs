@@ -16,8 +16,12 @@
  * under the License.
  */
 
+#[cfg(feature = "io-uring")]
+use super::uring_log_writer::UringLogWriter;
 use super::PersisterTask;
+use crate::configs::system::{StorageBackend, StorageConfig};
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::segments::group_commit::GroupCommit;
 use error_set::ErrContext;
 use iggy::{
     confirmation::Confirmation,
@@ -45,6 +49,9 @@ pub struct SegmentLogWriter {
     file: Option<File>,
     /// When set, asynchronous writes are handled by this persister task.
     persister_task: Option<PersisterTask>,
+    /// When the io_uring storage backend is used, synchronous writes are handled by this writer instead of `file`.
+    #[cfg(feature = "io-uring")]
+    uring_writer: Option<UringLogWriter>,
     log_size_bytes: Arc<AtomicU64>,
     fsync: bool,
 }
@@ -54,12 +61,14 @@ impl SegmentLogWriter {
     ///
     /// If the server confirmation is set to `NoWait`, the file handle is transferred to the
     /// persister task (and stored in `persister_task`) so that writes are done asynchronously.
-    /// Otherwise, the file is retained in `self.file` for synchronous writes.
+    /// Otherwise, the file is retained in `self.file` for synchronous writes,
+    /// unless the io_uring storage backend is selected, which then handles them.
     pub async fn new(
         file_path: &str,
         log_size_bytes: Arc<AtomicU64>,
         fsync: bool,
         server_confirmation: Confirmation,
+        storage: &StorageConfig,
         max_file_operation_retries: u32,
         retry_delay: IggyDuration,
     ) -> Result<Self, IggyError> {
@@ -97,13 +106,26 @@ impl SegmentLogWriter {
                 );
                 (None, Some(persister))
             }
+            Confirmation::Wait
+                if cfg!(feature = "io-uring") && storage.backend == StorageBackend::IoUring =>
+            {
+                (None, None)
+            }
             Confirmation::Wait => (Some(file), None),
         };
 
+        #[cfg(feature = "io-uring")]
+        let uring_writer = match file.is_none() && persister_task.is_none() {
+            true => Some(UringLogWriter::open(file_path, storage).await?),
+            false => None,
+        };
+
         Ok(Self {
             file_path: file_path.to_string(),
             file,
             persister_task,
+            #[cfg(feature = "io-uring")]
+            uring_writer,
             log_size_bytes,
             fsync,
         })
@@ -144,8 +166,23 @@ impl SegmentLogWriter {
         Ok(batch_size)
     }
 
+    /// Enqueues the group commit of the saved batches without waiting for it.
+    /// The asynchronous writes are registered in the group only once the persister task has written them.
+    pub async fn enqueue_group_commit(&self, group_commit: Arc<GroupCommit>) {
+        match &self.persister_task {
+            Some(task) => task.enqueue_group_commit(group_commit).await,
+            None => group_commit.enqueue_write(),
+        }
+    }
+
     /// Write a batch of bytes to the log file and return the new file position.
     async fn write_batch(&mut self, batch_to_write: RetainedMessageBatch) -> Result<(), IggyError> {
+        #[cfg(feature = "io-uring")]
+        if let Some(ref mut writer) = self.uring_writer {
+            let header = batch_to_write.header_as_bytes();
+            return writer.append(&header, &batch_to_write.bytes).await;
+        }
+
         if let Some(ref mut file) = self.file {
             let header = batch_to_write.header_as_bytes();
             let batch_bytes = batch_to_write.bytes;
@@ -166,6 +203,11 @@ impl SegmentLogWriter {
     }
 
     pub async fn fsync(&self) -> Result<(), IggyError> {
+        #[cfg(feature = "io-uring")]
+        if let Some(writer) = self.uring_writer.as_ref() {
+            return writer.fsync().await;
+        }
+
         if let Some(file) = self.file.as_ref() {
             file.sync_all()
                 .await
@@ -19,6 +19,8 @@
 mod log_reader;
 mod log_writer;
 mod persister_task;
+#[cfg(feature = "io-uring")]
+mod uring_log_writer;
 
 pub use log_reader::SegmentLogReader;
 pub use log_writer::SegmentLogWriter;
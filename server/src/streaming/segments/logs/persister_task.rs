@@ -17,6 +17,7 @@
  */
 
 use crate::streaming::batching::message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN};
+use crate::streaming::segments::group_commit::GroupCommit;
 use flume::{unbounded, Receiver};
 use iggy::{error::IggyError, utils::duration::IggyDuration};
 use std::{
@@ -34,6 +35,7 @@ use tracing::{error, trace, warn};
 /// A command to the persister task.
 enum PersisterTaskCommand {
     WriteRequest(RetainedMessageBatch),
+    GroupCommit(Arc<GroupCommit>),
     Shutdown,
 }
 
@@ -91,6 +93,20 @@ impl PersisterTask {
         }
     }
 
+    /// Enqueues the group commit of the batches sent so far, which is done once they're written (fire-and-forget).
+    pub async fn enqueue_group_commit(&self, group_commit: Arc<GroupCommit>) {
+        if let Err(e) = self
+            .sender
+            .send_async(PersisterTaskCommand::GroupCommit(group_commit))
+            .await
+        {
+            error!(
+                "Failed to send group commit request to LogPersisterTask for file {}: {:?}",
+                self.file_path, e
+            );
+        }
+    }
+
     /// Sends the shutdown command to the persister task and waits for a response.
     pub async fn shutdown(self) {
         let start_time = tokio::time::Instant::now();
@@ -213,6 +229,9 @@ impl PersisterTask {
                         }
                     }
                 }
+                PersisterTaskCommand::GroupCommit(group_commit) => {
+                    group_commit.enqueue_write();
+                }
                 PersisterTaskCommand::Shutdown => {
                     trace!("LogPersisterTask for file {file_path} received shutdown command");
                     if let Err(e) = file.sync_all().await {
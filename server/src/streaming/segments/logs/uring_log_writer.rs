@@ -0,0 +1,274 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::StorageConfig;
+use crate::streaming::persistence::uring::{self, UringDriver, BLOCK_SIZE};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    os::unix::fs::{FileExt, OpenOptionsExt},
+    sync::Arc,
+};
+use tokio::task::spawn_blocking;
+use tracing::{trace, warn};
+
+/// Appends the message batches to the log file via io_uring, using the registered buffers.
+///
+/// With direct I/O, only the complete blocks are written via the O_DIRECT file descriptor. The last partially
+/// filled block is kept in memory, so it can be written again as a whole once it's complete, while its bytes
+/// are written through the page cache via a regular file descriptor. This way the file never contains
+/// the block padding and always ends with the last batch, without truncating it after every write.
+#[derive(Debug)]
+pub struct UringLogWriter {
+    file_path: String,
+    file: Arc<File>,
+    tail_file: Arc<File>,
+    driver: &'static UringDriver,
+    direct_io: bool,
+    position: u64,
+    tail: Vec<u8>,
+}
+
+impl UringLogWriter {
+    /// Opens the log file, falling back to the buffered I/O if the file system doesn't support O_DIRECT.
+    pub async fn open(file_path: &str, storage: &StorageConfig) -> Result<Self, IggyError> {
+        let driver = uring::driver(storage)
+            .with_error_context(|error| {
+                format!("Failed to start io_uring for log file: {file_path}. {error}")
+            })
+            .map_err(|_| IggyError::CannotOpenPartitionLogFile)?;
+        Self::open_with_driver(file_path, storage.direct_io, driver).await
+    }
+
+    /// Opens the log file using the given io_uring driver.
+    pub async fn open_with_driver(
+        file_path: &str,
+        direct_io: bool,
+        driver: &'static UringDriver,
+    ) -> Result<Self, IggyError> {
+        let path = file_path.to_string();
+        let (file, tail_file, direct_io, position, tail) =
+            spawn_blocking(move || Self::open_file(&path, direct_io))
+                .await
+                .map_err(|_| IggyError::CannotOpenPartitionLogFile)?
+                .with_error_context(|error| {
+                    format!("Failed to open log file: {file_path}. {error}")
+                })
+                .map_err(|_| IggyError::CannotOpenPartitionLogFile)?;
+
+        trace!("Opened log file for io_uring writing: {file_path}, size: {position}, direct I/O: {direct_io}");
+        Ok(Self {
+            file_path: file_path.to_string(),
+            file,
+            tail_file,
+            driver,
+            direct_io,
+            position,
+            tail,
+        })
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn open_file(
+        path: &str,
+        direct_io: bool,
+    ) -> io::Result<(Arc<File>, Arc<File>, bool, u64, Vec<u8>)> {
+        let mut options = OpenOptions::new();
+        options.write(true).create(true);
+        let (file, direct_io) = if direct_io {
+            match options.clone().custom_flags(libc::O_DIRECT).open(path) {
+                Ok(file) => (file, true),
+                Err(error) if error.raw_os_error() == Some(libc::EINVAL) => {
+                    warn!("Direct I/O is not supported for log file: {path}, using buffered I/O.");
+                    (options.open(path)?, false)
+                }
+                Err(error) => return Err(error),
+            }
+        } else {
+            (options.open(path)?, false)
+        };
+
+        let position = file.metadata()?.len();
+        let file = Arc::new(file);
+        if !direct_io {
+            return Ok((file.clone(), file, false, position, Vec::new()));
+        }
+
+        // The direct I/O file descriptor requires the aligned access, so the tail is read and written via a regular one.
+        let tail_file = options.read(true).open(path)?;
+        let tail_len = (position % BLOCK_SIZE as u64) as usize;
+        let mut tail = vec![0; tail_len];
+        tail_file.read_exact_at(&mut tail, position - tail_len as u64)?;
+        Ok((file, Arc::new(tail_file), true, position, tail))
+    }
+
+    /// Writes the batch header and payload at the end of the log file.
+    pub async fn append(&mut self, header: &[u8], payload: &[u8]) -> Result<(), IggyError> {
+        let sources = [self.tail.as_slice(), header, payload];
+        let total: usize = sources.iter().map(|source| source.len()).sum();
+        let start = self.position - self.tail.len() as u64;
+        let blocks_len = match self.direct_io {
+            true => total - total % BLOCK_SIZE,
+            false => total,
+        };
+
+        self.write_blocks(&sources, start, blocks_len).await?;
+        // The bytes of the tail which have been written before are already in the file.
+        let unwritten_start = blocks_len.max(self.tail.len());
+        let mut unwritten = vec![0; total - unwritten_start];
+        fill(&sources, unwritten_start, &mut unwritten);
+        if blocks_len > 0 {
+            self.tail.clear();
+        }
+        if !unwritten.is_empty() {
+            self.driver
+                .write_all(
+                    &self.tail_file,
+                    unwritten.clone(),
+                    start + unwritten_start as u64,
+                )
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to log to file: {}. {error}", self.file_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            self.tail.extend_from_slice(&unwritten);
+        }
+
+        self.position = start + total as u64;
+        Ok(())
+    }
+
+    /// Writes the first `len` bytes of the concatenated sources at the given position, using the registered buffers.
+    async fn write_blocks(
+        &self,
+        sources: &[&[u8]],
+        start: u64,
+        len: usize,
+    ) -> Result<(), IggyError> {
+        let buffer_size = self.driver.buffer_size();
+        let mut written = 0;
+        while written < len {
+            let mut buffer = self.driver.acquire_buffer().await;
+            // With direct I/O, the chunks are multiples of the block size, as the buffer size is.
+            let chunk_len = (len - written).min(buffer_size);
+            fill(sources, written, &mut buffer.as_mut_slice()[..chunk_len]);
+            let bytes_written = self
+                .driver
+                .write_fixed(&self.file, buffer, chunk_len, start + written as u64)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to log to file: {}. {error}", self.file_path)
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            if bytes_written != chunk_len {
+                warn!(
+                    "Short write to log file: {}, written {bytes_written} of {chunk_len} bytes.",
+                    self.file_path
+                );
+                return Err(IggyError::CannotWriteToFile);
+            }
+            written += chunk_len;
+        }
+        Ok(())
+    }
+
+    pub async fn fsync(&self) -> Result<(), IggyError> {
+        self.driver
+            .fsync(&self.file)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to fsync log file: {}. {error}", self.file_path)
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+}
+
+/// Copies the bytes of the concatenated sources, starting at the given position, into the destination.
+fn fill(sources: &[&[u8]], position: usize, destination: &mut [u8]) {
+    let mut skip = position;
+    let mut filled = 0;
+    for source in sources {
+        if filled == destination.len() {
+            return;
+        }
+        if skip >= source.len() {
+            skip -= source.len();
+            continue;
+        }
+        let len = (source.len() - skip).min(destination.len() - filled);
+        destination[filled..filled + len].copy_from_slice(&source[skip..skip + len]);
+        filled += len;
+        skip = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::OnceLock;
+
+    /// The driver with the buffers of a single block, so the batches span multiple buffers.
+    fn driver() -> &'static UringDriver {
+        static DRIVER: OnceLock<UringDriver> = OnceLock::new();
+        DRIVER.get_or_init(|| UringDriver::start(8, 2, BLOCK_SIZE).unwrap())
+    }
+
+    #[tokio::test]
+    async fn should_append_unaligned_batches_larger_than_buffer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("00000000000000000000.log");
+        let path = path.to_str().unwrap();
+        let mut expected = Vec::new();
+
+        let mut writer = UringLogWriter::open_with_driver(path, true, driver())
+            .await
+            .unwrap();
+        for (index, len) in [10_usize, 5000, 3, 9000, 2351, 3].into_iter().enumerate() {
+            let header = vec![index as u8; 4];
+            let payload = vec![index as u8 + 100; len];
+            writer.append(&header, &payload).await.unwrap();
+            expected.extend_from_slice(&header);
+            expected.extend_from_slice(&payload);
+            // The file never contains the padding of the last block.
+            assert_eq!(std::fs::read(path).unwrap(), expected);
+        }
+        writer.fsync().await.unwrap();
+        drop(writer);
+
+        let mut writer = UringLogWriter::open_with_driver(path, true, driver())
+            .await
+            .unwrap();
+        writer.append(b"last", b"batch").await.unwrap();
+        expected.extend_from_slice(b"lastbatch");
+
+        assert_eq!(std::fs::read(path).unwrap(), expected);
+    }
+
+    #[test]
+    fn should_fill_destination_from_concatenated_sources() {
+        let sources: [&[u8]; 3] = [b"ab", b"", b"cdef"];
+        let mut destination = [0; 3];
+
+        fill(&sources, 1, &mut destination);
+
+        assert_eq!(&destination, b"bcd");
+    }
+}
@@ -16,12 +16,14 @@
  * under the License.
  */
 
+mod group_commit;
 mod indexes;
 mod logs;
 mod reading_messages;
 mod segment;
 mod writing_messages;
 
+pub use group_commit::PendingSync;
 pub use indexes::Index;
 pub use segment::Segment;
 
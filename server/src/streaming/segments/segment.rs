@@ -16,9 +16,10 @@
  * under the License.
  */
 
+use super::group_commit::GroupCommit;
 use super::indexes::*;
 use super::logs::*;
-use crate::configs::system::SystemConfig;
+use crate::configs::system::{Durability, SystemConfig};
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
@@ -57,6 +58,7 @@ pub struct Segment {
     pub(super) log_reader: Option<SegmentLogReader>,
     pub(super) index_writer: Option<SegmentIndexWriter>,
     pub(super) index_reader: Option<SegmentIndexReader>,
+    pub(super) group_commit: Option<Arc<GroupCommit>>,
     pub message_expiry: IggyExpiry,
     pub unsaved_messages: Option<BatchAccumulator>,
     pub config: Arc<SystemConfig>,
@@ -115,6 +117,7 @@ impl Segment {
             log_reader: None,
             index_writer: None,
             index_reader: None,
+            group_commit: None,
             size_of_parent_stream,
             size_of_parent_partition,
             size_of_parent_topic,
@@ -220,11 +223,13 @@ impl Segment {
     }
 
     pub async fn initialize_writing(&mut self) -> Result<(), IggyError> {
+        let server_confirmation = self.config.segment.server_confirmation;
+        // With the group commit, the files are synced once for all the concurrent writers instead of on every write.
+        let group_commit = self.config.segment.durability == Durability::GroupCommit;
         // TODO(hubcio): consider splitting enforce_fsync for index/log to separate entries in config
-        let log_fsync = self.config.partition.enforce_fsync;
-        let index_fsync = self.config.partition.enforce_fsync;
+        let log_fsync = self.config.partition.enforce_fsync && !group_commit;
+        let index_fsync = self.config.partition.enforce_fsync && !group_commit;
 
-        let server_confirmation = self.config.segment.server_confirmation;
         let max_file_operation_retries = self.config.state.max_file_operation_retries;
         let retry_delay = self.config.state.retry_delay;
 
@@ -233,6 +238,7 @@ impl Segment {
             self.log_size_bytes.clone(),
             log_fsync,
             server_confirmation,
+            &self.config.storage,
             max_file_operation_retries,
             retry_delay,
         )
@@ -244,11 +250,20 @@ impl Segment {
 
         self.log_writer = Some(log_writer);
         self.index_writer = Some(index_writer);
+        if group_commit {
+            let group_commit = GroupCommit::open(&self.log_path, &self.index_path).await?;
+            self.group_commit = Some(Arc::new(group_commit));
+        }
         Ok(())
     }
 
     pub async fn initialize_reading(&mut self) -> Result<(), IggyError> {
-        let log_reader = SegmentLogReader::new(&self.log_path, self.log_size_bytes.clone()).await?;
+        let log_reader = SegmentLogReader::new(
+            &self.log_path,
+            self.log_size_bytes.clone(),
+            &self.config.storage,
+        )
+        .await?;
         // TODO(hubcio): there is no need to store open fd for reader if we have index cache enabled
         let index_reader =
             SegmentIndexReader::new(&self.index_path, self.index_size_bytes.clone()).await?;
@@ -302,6 +317,7 @@ impl Segment {
     }
 
     pub async fn shutdown_writing(&mut self) {
+        self.group_commit = None;
         if let Some(log_writer) = self.log_writer.take() {
             tokio::spawn(async move {
                 let _ = log_writer.fsync().await;
@@ -324,6 +340,25 @@ impl Segment {
         }
     }
 
+    /// Persists all the buffered messages and waits until the log and index files are synced to disk.
+    /// Unlike `shutdown_writing`, it doesn't return before the data is durable, so it's used on server shutdown.
+    pub async fn flush_and_shutdown_writing(&mut self) -> Result<usize, IggyError> {
+        let mut saved_messages_number = 0;
+        while self.unsaved_messages.is_some() {
+            saved_messages_number += self.persist_messages(None).await?;
+        }
+
+        if let Some(log_writer) = self.log_writer.take() {
+            log_writer.fsync().await?;
+            log_writer.shutdown_persister_task().await;
+        }
+        if let Some(index_writer) = self.index_writer.take() {
+            index_writer.fsync().await?;
+        }
+        self.group_commit = None;
+        Ok(saved_messages_number)
+    }
+
     pub async fn delete(&mut self) -> Result<(), IggyError> {
         let segment_size = self.size_bytes;
         let segment_count_of_messages = self.get_messages_count();
@@ -17,6 +17,7 @@
  */
 
 use super::indexes::*;
+use super::PendingSync;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
 use crate::streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN;
 use crate::streaming::models::messages::RetainedMessage;
@@ -98,6 +99,36 @@ impl Segment {
         index
     }
 
+    /// Persists the buffered messages and, if the group commit is enabled, returns the pending fsync
+    /// which has to complete before the messages are acknowledged. With the `NoWait` confirmation,
+    /// the fsync is enqueued in the group commit instead of being awaited.
+    pub async fn persist_messages_with_group_commit(
+        &mut self,
+        confirmation: Option<Confirmation>,
+    ) -> Result<Option<PendingSync>, IggyError> {
+        // The segment might get closed while persisting, so the group commit is kept to sync the last write.
+        let group_commit = self.group_commit.clone();
+        let saved_messages_number = self.persist_messages(confirmation).await?;
+        let Some(group_commit) = group_commit else {
+            return Ok(None);
+        };
+        if saved_messages_number == 0 {
+            return Ok(None);
+        }
+
+        let confirmation = confirmation.unwrap_or(self.config.segment.server_confirmation);
+        if confirmation == Confirmation::NoWait {
+            // The write still joins the group commit, but it's acknowledged without waiting for the sync.
+            // If the segment has been closed in the meantime, its files have already been synced on shutdown.
+            if let Some(log_writer) = &self.log_writer {
+                log_writer.enqueue_group_commit(group_commit).await;
+            }
+            return Ok(None);
+        }
+
+        Ok(Some(group_commit.register_write()))
+    }
+
     pub async fn persist_messages(
         &mut self,
         confirmation: Option<Confirmation>,
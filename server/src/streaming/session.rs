@@ -20,12 +20,14 @@ use iggy::models::user_info::{AtomicUserId, UserId};
 use std::fmt::Display;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Notify;
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
     active: AtomicBool,
+    disconnected: Notify,
     pub client_id: u32,
     pub ip_address: SocketAddr,
 }
@@ -35,6 +37,7 @@ impl Session {
         Self {
             client_id,
             active: AtomicBool::new(true),
+            disconnected: Notify::new(),
             user_id: AtomicUserId::new(user_id),
             ip_address,
         }
@@ -60,6 +63,16 @@ impl Session {
         self.active.store(false, Ordering::Release)
     }
 
+    // Marks the session as stale and wakes up the connection handler, so that it can close the connection.
+    pub fn disconnect(&self) {
+        self.set_stale();
+        self.disconnected.notify_one();
+    }
+
+    pub async fn disconnected(&self) {
+        self.disconnected.notified().await
+    }
+
     pub fn clear_user_id(&self) {
         self.set_user_id(0)
     }
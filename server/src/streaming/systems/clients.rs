@@ -25,6 +25,9 @@ use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::user_info::UserId;
+use iggy::system::disconnect_client::DisconnectTarget;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::{error, info};
@@ -112,4 +115,64 @@ impl System {
         let client_manager = self.client_manager.read().await;
         Ok(client_manager.get_clients())
     }
+
+    pub async fn disconnect_client(
+        &self,
+        session: &Session,
+        target: &DisconnectTarget,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .disconnect_client(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to disconnect {target} by user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let clients = match target {
+            DisconnectTarget::Client(client_id) => {
+                let client_manager = self.client_manager.read().await;
+                // ClientNotFound is reserved for the session of the caller, as it results in closing its connection.
+                let Some(client) = client_manager.try_get_client(*client_id) else {
+                    return Err(IggyError::ResourceNotFound(client_id.to_string()));
+                };
+                vec![client]
+            }
+            DisconnectTarget::User(user_id) => {
+                let user_id = self
+                    .get_user(user_id)
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get user with ID: {user_id}"
+                        )
+                    })?
+                    .id;
+                let mut client_manager = self.client_manager.write().await;
+                client_manager.revoke_user_sessions(user_id, IggyTimestamp::now());
+                client_manager.get_clients_for_user(user_id).await
+            }
+        };
+
+        // The connection handlers are responsible for closing the connections and deleting the clients.
+        for client in clients {
+            let client = client.read().await;
+            client.session.disconnect();
+            info!(
+                "Disconnected {} client with ID: {} for IP address: {} by user ID: {}",
+                client.transport,
+                client.session.client_id,
+                client.session.ip_address,
+                session.get_user_id()
+            );
+        }
+
+        Ok(())
+    }
+
+    pub async fn is_user_session_revoked(&self, user_id: UserId, issued_at: IggyTimestamp) -> bool {
+        let client_manager = self.client_manager.read().await;
+        client_manager.is_user_session_revoked(user_id, issued_at)
+    }
 }
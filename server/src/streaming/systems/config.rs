@@ -0,0 +1,88 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::reload::{ConfigReloader, ConfigWatcher};
+use crate::configs::system::SystemConfig;
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::config_reload_info::ConfigReloadInfo;
+use std::sync::Arc;
+use tracing::{error, info};
+
+impl System {
+    pub fn set_config_reloader(&mut self, config_reloader: Arc<ConfigReloader>) {
+        self.config_reloader = Some(config_reloader);
+    }
+
+    /// Applies the reloaded quotas: the cache size and the maximum size of the topics created from now on.
+    pub fn apply_config(&mut self, config: Arc<SystemConfig>) {
+        if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+            memory_tracker.set_limit(config.cache.size.clone());
+        }
+        for stream in self.streams.values_mut() {
+            stream.config = config.clone();
+        }
+        info!("Default maximum topic size is: {}.", config.topic.max_size);
+        self.config = config;
+    }
+
+    pub async fn reload_config(&self, session: &Session) -> Result<ConfigReloadInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .reload_config(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to reload config for user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let Some(config_reloader) = &self.config_reloader else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        config_reloader.reload().await.map_err(|error| {
+            error!("{COMPONENT} (error: {error}) - failed to reload config, session: {session}");
+            IggyError::CannotReloadConfiguration
+        })
+    }
+}
+
+impl SharedSystem {
+    /// Applies the quotas from the reloaded configuration.
+    pub fn watch_config(&self, mut config_watcher: ConfigWatcher) {
+        let system = self.clone();
+        tokio::spawn(async move {
+            while config_watcher.changed().await.is_ok() {
+                let config = config_watcher.borrow_and_update().system.clone();
+                let mut system = system.write().await;
+                if config.cache.size == system.config.cache.size
+                    && config.topic.max_size == system.config.topic.max_size
+                {
+                    continue;
+                }
+
+                system.apply_config(config);
+            }
+        });
+    }
+}
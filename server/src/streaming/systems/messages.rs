@@ -18,19 +18,34 @@
 
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::session::Session;
-use crate::streaming::systems::system::System;
+use crate::streaming::systems::system::{SharedSystem, System};
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::schemas::RejectedMessage;
+use crate::streaming::topics::topic::Topic;
 use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::confirmation::Confirmation;
-use iggy::consumer::Consumer;
+use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Message;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::appended_messages::AppendedMessages;
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::{PolledMessage, PolledMessages};
+use iggy::models::schema::SchemaValidation;
+use iggy::schemas::{
+    REJECTION_REASON_HEADER, REJECTION_SCHEMA_ID_HEADER, REJECTION_STREAM_ID_HEADER,
+    REJECTION_TOPIC_ID_HEADER,
+};
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::sizeable::Sizeable;
 use iggy::{error::IggyError, identifier::Identifier};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tokio::time::Instant;
 use tracing::{error, trace};
 
 impl System {
@@ -122,6 +137,8 @@ impl System {
         Ok(polled_messages)
     }
 
+    /// Appends the messages to the topic, returning the offsets of the appended messages,
+    /// or `None` if no message was appended, e.g. all of them were routed to the rejection topic.
     pub async fn append_messages(
         &self,
         session: &Session,
@@ -130,7 +147,7 @@ impl System {
         partitioning: Partitioning,
         messages: Vec<Message>,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<Option<AppendedMessages>, IggyError> {
         self.ensure_authenticated(session)?;
         let topic = self.find_topic(session, &stream_id, &topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner.append_messages(
@@ -144,6 +161,115 @@ impl System {
             topic.topic_id
         ))?;
 
+        let validated_messages = topic.validate_messages(messages)?;
+        if !validated_messages.rejected.is_empty() {
+            self.route_rejected_messages(topic, validated_messages.rejected, confirmation)
+                .await?;
+        }
+
+        if validated_messages.accepted.is_empty() {
+            return Ok(None);
+        }
+
+        self.append_topic_messages(
+            topic,
+            partitioning,
+            validated_messages.accepted,
+            confirmation,
+        )
+        .await
+    }
+
+    /// Appends the messages which payloads don't match the topic schema to the rejection topic,
+    /// together with the headers describing the rejection reason and the origin of the message.
+    async fn route_rejected_messages(
+        &self,
+        topic: &Topic,
+        rejected_messages: Vec<RejectedMessage>,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        let SchemaValidation::Route {
+            stream_id: rejection_stream_id,
+            topic_id: rejection_topic_id,
+        } = topic.schema_validation
+        else {
+            return Ok(());
+        };
+
+        let rejection_topic = self
+            .get_stream(&Identifier::numeric(rejection_stream_id)?)
+            .and_then(|stream| stream.get_topic(&Identifier::numeric(rejection_topic_id)?));
+        let Ok(rejection_topic) = rejection_topic else {
+            error!(
+                "Rejection topic with ID: {rejection_topic_id} in stream with ID: {rejection_stream_id} was not found for topic with ID: {} in stream with ID: {}.",
+                topic.topic_id, topic.stream_id
+            );
+            let rejected_message = rejected_messages.into_iter().next().unwrap();
+            return Err(IggyError::MessagePayloadSchemaViolation(
+                rejected_message.index,
+                rejected_message.schema_id,
+                rejected_message.reason,
+            ));
+        };
+
+        let mut messages = Vec::with_capacity(rejected_messages.len());
+        for rejected_message in rejected_messages {
+            let mut message = rejected_message.message;
+            let headers = message.headers.get_or_insert_with(HashMap::new);
+            headers.insert(
+                HeaderKey::new(REJECTION_REASON_HEADER)?,
+                HeaderValue::from_str(&Self::rejection_reason(&rejected_message.reason))?,
+            );
+            headers.insert(
+                HeaderKey::new(REJECTION_SCHEMA_ID_HEADER)?,
+                HeaderValue::from_uint32(rejected_message.schema_id)?,
+            );
+            headers.insert(
+                HeaderKey::new(REJECTION_STREAM_ID_HEADER)?,
+                HeaderValue::from_uint32(topic.stream_id)?,
+            );
+            headers.insert(
+                HeaderKey::new(REJECTION_TOPIC_ID_HEADER)?,
+                HeaderValue::from_uint32(topic.topic_id)?,
+            );
+            messages.push(message);
+        }
+
+        self.append_topic_messages(
+            rejection_topic,
+            Partitioning::balanced(),
+            messages,
+            confirmation,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Truncates the rejection reason to fit into the header value, at the characters boundary.
+    fn rejection_reason(reason: &str) -> String {
+        const MAX_LENGTH: usize = 255;
+        if reason.is_empty() {
+            return "invalid payload".to_string();
+        }
+
+        if reason.len() <= MAX_LENGTH {
+            return reason.to_string();
+        }
+
+        let mut length = MAX_LENGTH;
+        while !reason.is_char_boundary(length) {
+            length -= 1;
+        }
+        reason[..length].to_string()
+    }
+
+    async fn append_topic_messages(
+        &self,
+        topic: &Topic,
+        partitioning: Partitioning,
+        messages: Vec<Message>,
+        confirmation: Option<Confirmation>,
+    ) -> Result<Option<AppendedMessages>, IggyError> {
         let mut batch_size_bytes = IggyByteSize::default();
         let mut messages = messages;
         if let Some(encryptor) = &self.encryptor {
@@ -174,11 +300,11 @@ impl System {
             }
         }
         let messages_count = messages.len() as u64;
-        topic
+        let appended_messages = topic
             .append_messages(batch_size_bytes, partitioning, messages, confirmation)
             .await?;
         self.metrics.increment_messages(messages_count);
-        Ok(())
+        Ok(appended_messages)
     }
 
     pub async fn flush_unsaved_buffer(
@@ -205,9 +331,80 @@ impl System {
         topic.flush_unsaved_buffer(partition_id, fsync).await?;
         Ok(())
     }
+
+    /// Returns the notification of the messages being appended to any partition of the topic.
+    pub fn get_messages_appended(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Arc<Notify>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        Ok(topic.messages_appended.clone())
+    }
+}
+
+impl SharedSystem {
+    /// Polls the messages and if there are none, waits until the new messages are appended to the topic
+    /// or the `max_wait` (capped by the server configuration) elapses, returning the empty batch in such a case.
+    /// The system lock is released while waiting, so that the messages can be appended in the meantime.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn poll_messages_with_wait(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: PollingArgs,
+        max_wait: IggyDuration,
+    ) -> Result<PolledMessages, IggyError> {
+        let mut deadline = None;
+        loop {
+            let system = self.read().await;
+            let deadline = *deadline.get_or_insert_with(|| {
+                let max_wait = max_wait
+                    .get_duration()
+                    .min(system.config.polling.max_wait.get_duration());
+                Instant::now() + max_wait
+            });
+            let messages_appended = system.get_messages_appended(session, stream_id, topic_id)?;
+            // The notification is enabled before polling, so that the messages appended in the meantime aren't missed.
+            let notified = messages_appended.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            // The consumer group members poll their partitions in turns, so all of them are checked before waiting.
+            let attempts = match consumer.kind {
+                ConsumerKind::ConsumerGroup if partition_id.is_none() => system
+                    .find_topic(session, stream_id, topic_id)?
+                    .get_partitions_count()
+                    .max(1),
+                ConsumerKind::Consumer | ConsumerKind::ConsumerGroup => 1,
+            };
+            let mut polled_messages = system
+                .poll_messages(session, consumer, stream_id, topic_id, partition_id, args)
+                .await?;
+            for _ in 1..attempts {
+                if !polled_messages.messages.is_empty() {
+                    break;
+                }
+                polled_messages = system
+                    .poll_messages(session, consumer, stream_id, topic_id, partition_id, args)
+                    .await?;
+            }
+            drop(system);
+            if !polled_messages.messages.is_empty() || Instant::now() >= deadline {
+                return Ok(polled_messages);
+            }
+
+            trace!("No messages to poll for {consumer}, stream: {stream_id}, topic: {topic_id}, waiting for the new ones...");
+            let _ = tokio::time::timeout_at(deadline, notified).await;
+        }
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
@@ -17,12 +17,14 @@
  */
 
 pub mod clients;
+pub mod config;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod info;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod segments;
 pub mod snapshot;
 pub mod stats;
@@ -0,0 +1,195 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
+
+impl System {
+    pub fn get_schema(
+        &self,
+        session: &Session,
+        schema_id: u32,
+    ) -> Result<Option<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let schema = self
+            .streams
+            .values()
+            .flat_map(|stream| stream.get_topics())
+            .find_map(|topic| topic.get_schema(schema_id));
+        let Some(schema) = schema else {
+            return Ok(None);
+        };
+
+        self.permissioner
+            .get_schema(session.get_user_id(), schema.stream_id, schema.topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schema with ID: {schema_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+        Ok(Some(schema))
+    }
+
+    pub fn get_schemas(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicSchemas, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+
+        self.permissioner
+            .get_schemas(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schemas in topic with ID: {topic_id} and stream with ID: {stream_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        Ok(topic.get_schemas())
+    }
+
+    /// Registers a new version of the schema for the topic, returning it together with the flag indicating whether it was registered,
+    /// as the latest version is returned instead, if it has the same format and definition.
+    pub fn register_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<(&Schema, bool), IggyError> {
+        self.ensure_authenticated(session)?;
+        let is_latest;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+
+            self.permissioner.register_schema(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to register schema for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+            is_latest = topic
+                .get_latest_schema()
+                .is_some_and(|latest| latest.format == format && latest.definition == definition);
+        }
+
+        if !is_latest {
+            let schema_id = self.last_schema_id + 1;
+            let topic = self.get_stream_mut(stream_id)?
+                .get_topic_mut(topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+            topic
+                .register_schema(schema_id, format, definition)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to register schema for stream ID: {stream_id}, topic_id: {topic_id}")
+                })?;
+            self.last_schema_id = schema_id;
+        }
+
+        let topic = self.get_stream(stream_id)?.get_topic(topic_id)?;
+        let schema = topic
+            .get_latest_schema()
+            .ok_or(IggyError::TopicSchemaNotFound(
+                topic.topic_id,
+                topic.stream_id,
+            ))?;
+        Ok((schema, !is_latest))
+    }
+
+    pub fn update_schema_compatibility(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+
+            self.permissioner.update_schema_compatibility(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to update schema compatibility for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        topic.update_schema_compatibility(compatibility);
+        Ok(())
+    }
+
+    /// Updates the schema validation mode of the topic, the rejection topic used by the route mode must exist,
+    /// and it can't be the same topic, as the rejected messages would have to be validated again.
+    pub fn update_schema_validation(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        validation: SchemaValidation,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+
+            self.permissioner.update_schema_validation(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to update schema validation for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+            if let SchemaValidation::Route {
+                stream_id: rejection_stream_id,
+                topic_id: rejection_topic_id,
+            } = validation
+            {
+                if rejection_stream_id == topic.stream_id && rejection_topic_id == topic.topic_id {
+                    return Err(IggyError::InvalidSchemaValidation);
+                }
+
+                self.get_stream(&Identifier::numeric(rejection_stream_id)?)?
+                    .get_topic(&Identifier::numeric(rejection_topic_id)?)
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - rejection topic not found for stream ID: {rejection_stream_id}, topic_id: {rejection_topic_id}"))?;
+            }
+        }
+
+        let topic = self.get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        topic.update_schema_validation(validation);
+        Ok(())
+    }
+}
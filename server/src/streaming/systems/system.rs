@@ -17,8 +17,9 @@
  */
 
 use crate::archiver::{ArchiverKind, ArchiverKindType};
+use crate::configs::reload::ConfigReloader;
 use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
-use crate::configs::system::SystemConfig;
+use crate::configs::system::{StorageBackend, StorageConfig, SystemConfig};
 use crate::map_toggle_str;
 use crate::state::file::FileState;
 use crate::state::system::SystemState;
@@ -92,6 +93,8 @@ pub struct System {
     pub(crate) state: Arc<StateKind>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub(crate) config_reloader: Option<Arc<ConfigReloader>>,
+    pub(crate) last_schema_id: u32,
 }
 
 /// For each cache eviction, we want to remove more than the size we need.
@@ -117,8 +120,10 @@ impl System {
             false => None,
         };
 
-        let state_persister = Self::resolve_persister(config.state.enforce_fsync);
-        let partition_persister = Self::resolve_persister(config.partition.enforce_fsync);
+        info!("Storage backend: {}.", config.storage.backend);
+        let state_persister = Self::resolve_persister(&config.storage, config.state.enforce_fsync);
+        let partition_persister =
+            Self::resolve_persister(&config.storage, config.partition.enforce_fsync);
 
         let state = Arc::new(StateKind::File(FileState::new(
             &config.get_state_log_path(),
@@ -136,7 +141,21 @@ impl System {
         )
     }
 
-    fn resolve_persister(enforce_fsync: bool) -> Arc<PersisterKind> {
+    fn resolve_persister(storage: &StorageConfig, enforce_fsync: bool) -> Arc<PersisterKind> {
+        if storage.backend == StorageBackend::IoUring {
+            #[cfg(feature = "io-uring")]
+            {
+                let driver = crate::streaming::persistence::uring::driver(storage)
+                    .expect("Failed to start the io_uring storage backend");
+                return Arc::new(PersisterKind::IoUring(UringPersister::new(
+                    driver,
+                    enforce_fsync,
+                )));
+            }
+            #[cfg(not(feature = "io-uring"))]
+            tracing::warn!("The io_uring storage backend is not available in this build, falling back to tokio.");
+        }
+
         match enforce_fsync {
             true => Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister)),
             false => Arc::new(PersisterKind::File(FilePersister)),
@@ -189,6 +208,8 @@ impl System {
             state,
             personal_access_token: pat_config,
             archiver,
+            config_reloader: None,
+            last_schema_id: 0,
         }
     }
 
@@ -232,6 +253,7 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to initialize system state")
             })?;
         let now = Instant::now();
+        self.last_schema_id = system_state.last_schema_id;
         self.load_version().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to load version")
         })?;
@@ -240,6 +262,11 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load users")
             })?;
+        let mut client_manager = self.client_manager.write().await;
+        for (user_id, revoked_at) in system_state.revoked_user_sessions {
+            client_manager.revoke_user_sessions(user_id, revoked_at);
+        }
+        drop(client_manager);
         self.load_streams(system_state.streams.into_values().collect())
             .await
             .with_error_context(|error| {
@@ -257,7 +284,43 @@ impl System {
 
     #[instrument(skip_all, name = "trace_shutdown")]
     pub async fn shutdown(&mut self) -> Result<(), IggyError> {
-        self.persist_messages().await?;
+        let mut saved_messages_number = 0;
+        let mut saved_consumer_offsets_number = 0;
+        let mut failed_partitions_number = 0;
+        // The failure of a single partition must not prevent flushing the remaining ones.
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let mut partition = partition.write().await;
+                    let partition_id = partition.partition_id;
+                    let mut failed = false;
+                    match partition.flush_and_shutdown_writing().await {
+                        Ok(messages_number) => saved_messages_number += messages_number,
+                        Err(error) => {
+                            error!("{COMPONENT} (error: {error}) - failed to flush messages, stream ID: {}, topic ID: {}, partition ID: {partition_id}", stream.stream_id, topic.topic_id);
+                            failed = true;
+                        }
+                    }
+                    match partition.persist_consumer_offsets().await {
+                        Ok(offsets_number) => saved_consumer_offsets_number += offsets_number,
+                        Err(error) => {
+                            error!("{COMPONENT} (error: {error}) - failed to persist consumer offsets, stream ID: {}, topic ID: {}, partition ID: {partition_id}", stream.stream_id, topic.topic_id);
+                            failed = true;
+                        }
+                    }
+                    if failed {
+                        failed_partitions_number += 1;
+                    }
+                }
+            }
+        }
+        info!(
+            "Flushed {saved_messages_number} buffered messages and {saved_consumer_offsets_number} consumer offsets on disk."
+        );
+        if failed_partitions_number > 0 {
+            return Err(IggyError::CannotFlushPartitions(failed_partitions_number));
+        }
+
         Ok(())
     }
 
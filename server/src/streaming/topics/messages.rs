@@ -30,6 +30,7 @@ use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use iggy::models::appended_messages::AppendedMessages;
 use iggy::models::messages::PolledMessages;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -91,13 +92,15 @@ impl Topic {
         })
     }
 
+    /// Appends the messages to the partition calculated with the partitioning,
+    /// returning the offsets of the appended messages, if any message was appended.
     pub async fn append_messages(
         &self,
         batch_size: IggyByteSize,
         partitioning: Partitioning,
         messages: Vec<Message>,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<Option<AppendedMessages>, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
@@ -109,7 +112,7 @@ impl Topic {
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
         let partition_id = match partitioning.kind {
@@ -152,9 +155,9 @@ impl Topic {
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<Option<AppendedMessages>, IggyError> {
         let partition = self.partitions.get(&appendable_batch_info.partition_id);
-        partition
+        let append_result = partition
             .ok_or({
                 IggyError::PartitionNotFound(
                     appendable_batch_info.partition_id,
@@ -170,7 +173,14 @@ impl Topic {
                 format!("{COMPONENT} (error: {error}) - failed to append messages")
             })?;
 
-        Ok(())
+        // The partition lock is already released, so that the concurrent writers can share the fsync.
+        if let Some(pending_sync) = append_result.pending_sync {
+            pending_sync.wait().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to sync appended messages")
+            })?;
+        }
+
+        Ok(append_result.appended_messages)
     }
 
     fn get_next_partition_id(&self) -> u32 {
@@ -61,6 +61,7 @@ impl Topic {
                 self.size_of_parent_stream.clone(),
                 self.size_bytes.clone(),
                 self.segments_count_of_parent_stream.clone(),
+                self.messages_appended.clone(),
                 IggyTimestamp::now(),
             )
             .await;
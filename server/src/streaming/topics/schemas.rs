@@ -0,0 +1,179 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::schemas::{self, PayloadValidator};
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use error_set::ErrContext;
+use iggy::codecs;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::models::schema::{
+    Schema, SchemaCompatibility, SchemaFormat, SchemaValidation, TopicSchemas,
+};
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{info, warn};
+
+/// The message which payload doesn't match the schema, to be routed to the rejection topic.
+#[derive(Debug)]
+pub struct RejectedMessage {
+    pub index: u32,
+    pub schema_id: u32,
+    pub reason: String,
+    pub message: Message,
+}
+
+/// The messages split by the schema validation into the valid ones, and the rejected ones.
+#[derive(Debug, Default)]
+pub struct ValidatedMessages {
+    pub accepted: Vec<Message>,
+    pub rejected: Vec<RejectedMessage>,
+}
+
+impl Topic {
+    pub fn get_schemas(&self) -> TopicSchemas {
+        TopicSchemas {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            compatibility: self.schema_compatibility,
+            validation: self.schema_validation,
+            schemas: self.schemas.clone(),
+        }
+    }
+
+    pub fn get_schema(&self, schema_id: u32) -> Option<&Schema> {
+        self.schemas.iter().find(|schema| schema.id == schema_id)
+    }
+
+    pub fn get_latest_schema(&self) -> Option<&Schema> {
+        self.schemas.last()
+    }
+
+    /// Registers a new version of the schema, if it's valid and compatible with the latest one.
+    pub fn register_schema(
+        &mut self,
+        schema_id: u32,
+        format: SchemaFormat,
+        definition: &str,
+    ) -> Result<(), IggyError> {
+        let validator = PayloadValidator::new(format, definition).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - invalid schema definition for topic with ID: {} in stream with ID: {}",
+                self.topic_id, self.stream_id
+            )
+        })?;
+        if let Some(latest) = self.get_latest_schema() {
+            schemas::check_compatibility(self.schema_compatibility, latest, format, definition)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - incompatible schema for topic with ID: {} in stream with ID: {}",
+                        self.topic_id, self.stream_id
+                    )
+                })?;
+        }
+
+        let schema = Schema {
+            id: schema_id,
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            version: self.schemas.len() as u32 + 1,
+            format,
+            created_at: IggyTimestamp::now(),
+            definition: definition.to_string(),
+        };
+        info!(
+            "Registered {format} schema with ID: {schema_id}, version: {} for topic with ID: {} in stream with ID: {}.",
+            schema.version, self.topic_id, self.stream_id
+        );
+        self.schemas.push(schema);
+        self.schema_validators.insert(schema_id, validator);
+        Ok(())
+    }
+
+    pub fn update_schema_compatibility(&mut self, compatibility: SchemaCompatibility) {
+        self.schema_compatibility = compatibility;
+        info!(
+            "Updated schema compatibility to: {compatibility} for topic with ID: {} in stream with ID: {}.",
+            self.topic_id, self.stream_id
+        );
+    }
+
+    pub fn update_schema_validation(&mut self, validation: SchemaValidation) {
+        self.schema_validation = validation;
+        info!(
+            "Updated schema validation to: {validation} for topic with ID: {} in stream with ID: {}.",
+            self.topic_id, self.stream_id
+        );
+    }
+
+    /// Validates the message payloads, if the schema validation is enabled and any schema is registered for the topic.
+    /// Each payload is validated against the schema referenced by the message schema ID header, or the latest one.
+    /// In the reject mode, the first invalid message fails the whole batch, while in the route mode
+    /// the invalid messages are split from the valid ones, to be appended to the rejection topic.
+    pub fn validate_messages(
+        &self,
+        messages: Vec<Message>,
+    ) -> Result<ValidatedMessages, IggyError> {
+        let Some(latest_schema) = self.get_latest_schema() else {
+            return Ok(ValidatedMessages {
+                accepted: messages,
+                rejected: Vec::new(),
+            });
+        };
+
+        if !self.schema_validation.is_enabled() {
+            return Ok(ValidatedMessages {
+                accepted: messages,
+                rejected: Vec::new(),
+            });
+        }
+
+        let mut validated_messages = ValidatedMessages::default();
+        for (index, message) in messages.into_iter().enumerate() {
+            let index = index as u32;
+            let schema_id = codecs::schema_id(&message.headers).unwrap_or(latest_schema.id);
+            let result = match self.schema_validators.get(&schema_id) {
+                Some(validator) => validator.validate(&message.payload),
+                None => Err("schema is not registered for the topic".to_string()),
+            };
+            let Err(reason) = result else {
+                validated_messages.accepted.push(message);
+                continue;
+            };
+
+            warn!(
+                "Message at index: {index} does not match the schema with ID: {schema_id} for topic with ID: {} in stream with ID: {}: {reason}",
+                self.topic_id, self.stream_id
+            );
+            if self.schema_validation == SchemaValidation::Reject {
+                return Err(IggyError::MessagePayloadSchemaViolation(
+                    index, schema_id, reason,
+                ));
+            }
+
+            validated_messages.rejected.push(RejectedMessage {
+                index,
+                schema_id,
+                reason,
+                message,
+            });
+        }
+
+        Ok(validated_messages)
+    }
+}
@@ -18,6 +18,7 @@
 
 use crate::state::system::TopicState;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::schemas::PayloadValidator;
 use crate::streaming::storage::TopicStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -29,6 +30,7 @@ use futures::future::join_all;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::schema::Schema;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::sync::Arc;
@@ -108,6 +110,7 @@ impl TopicStorage for FileTopicStorage {
                 topic.size_of_parent_stream.clone(),
                 topic.size_bytes.clone(),
                 topic.segments_count_of_parent_stream.clone(),
+                topic.messages_appended.clone(),
                 partition_state.created_at,
             )
             .await;
@@ -154,6 +157,7 @@ impl TopicStorage for FileTopicStorage {
                         topic.size_of_parent_stream.clone(),
                         topic.size_bytes.clone(),
                         topic.segments_count_of_parent_stream.clone(),
+                        topic.messages_appended.clone(),
                         partition_state.created_at,
                     )
                     .await;
@@ -203,6 +207,35 @@ impl TopicStorage for FileTopicStorage {
                 .insert(partition.partition_id, IggySharedMut::new(partition));
         }
 
+        topic.schema_compatibility = state.schema_compatibility;
+        topic.schema_validation = state.schema_validation;
+        topic.schemas = state
+            .schemas
+            .into_iter()
+            .map(|schema| Schema {
+                id: schema.id,
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                version: schema.version,
+                format: schema.format,
+                created_at: schema.created_at,
+                definition: schema.definition,
+            })
+            .collect();
+        for schema in topic.schemas.iter() {
+            match PayloadValidator::new(schema.format, &schema.definition) {
+                Ok(validator) => {
+                    topic.schema_validators.insert(schema.id, validator);
+                }
+                Err(error) => {
+                    error!(
+                        "Cannot compile schema with ID: {} for topic with ID: {} for stream with ID: {}. Error: {error}",
+                        schema.id, topic.topic_id, topic.stream_id
+                    );
+                }
+            }
+        }
+
         for consumer_group in state.consumer_groups.into_values() {
             let consumer_group = ConsumerGroup::new(
                 topic.topic_id,
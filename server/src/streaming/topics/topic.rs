@@ -19,6 +19,7 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::schemas::PayloadValidator;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use ahash::AHashMap;
@@ -27,6 +28,7 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
+use iggy::models::schema::{Schema, SchemaCompatibility, SchemaValidation};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -34,7 +36,7 @@ use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 use tracing::info;
 
 const ALMOST_FULL_THRESHOLD: f64 = 0.9;
@@ -51,6 +53,7 @@ pub struct Topic {
     pub(crate) messages_count_of_parent_stream: Arc<AtomicU64>,
     pub(crate) messages_count: Arc<AtomicU64>,
     pub(crate) segments_count_of_parent_stream: Arc<AtomicU32>,
+    pub(crate) messages_appended: Arc<Notify>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) partitions: AHashMap<u32, IggySharedMut<Partition>>,
     pub(crate) storage: Arc<SystemStorage>,
@@ -58,6 +61,10 @@ pub struct Topic {
     pub(crate) consumer_groups_ids: AHashMap<String, u32>,
     pub(crate) current_consumer_group_id: AtomicU32,
     pub(crate) current_partition_id: AtomicU32,
+    pub(crate) schemas: Vec<Schema>,
+    pub(crate) schema_validators: AHashMap<u32, PayloadValidator>,
+    pub schema_compatibility: SchemaCompatibility,
+    pub schema_validation: SchemaValidation,
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
@@ -127,10 +134,15 @@ impl Topic {
             messages_count_of_parent_stream,
             messages_count: Arc::new(AtomicU64::new(0)),
             segments_count_of_parent_stream,
+            messages_appended: Arc::new(Notify::new()),
             consumer_groups: AHashMap::new(),
             consumer_groups_ids: AHashMap::new(),
             current_consumer_group_id: AtomicU32::new(1),
             current_partition_id: AtomicU32::new(1),
+            schemas: Vec::new(),
+            schema_validators: AHashMap::new(),
+            schema_compatibility: SchemaCompatibility::default(),
+            schema_validation: SchemaValidation::default(),
             message_expiry: Topic::get_message_expiry(message_expiry, &config),
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
             compression_algorithm,
@@ -20,6 +20,7 @@ mod consumer_groups;
 pub mod consumer_offsets;
 mod messages;
 mod partitions;
+mod schemas;
 mod segments;
 mod streams;
 mod system;
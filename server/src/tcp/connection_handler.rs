@@ -16,13 +16,16 @@
  * under the License.
  */
 
-use crate::binary::{command, sender::SenderKind};
+use crate::binary::command;
+use crate::binary::sender::{take_error_details_flag, SenderKind};
 use crate::command::ServerCommand;
 use crate::server_error::ConnectionError;
+use crate::shutdown::Shutdown;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
-use bytes::{BufMut, BytesMut};
+use bytes::{Buf, BufMut, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::command::CORRELATION_ID_FLAG;
 use iggy::error::IggyError;
 use iggy::validatable::Validatable;
 use std::io::ErrorKind;
@@ -30,15 +33,31 @@ use std::sync::Arc;
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
+const CORRELATED_COMMAND_HEADER_LENGTH: usize = 8;
 
 pub(crate) async fn handle_connection(
     session: Arc<Session>,
     sender: &mut SenderKind,
     system: SharedSystem,
+    shutdown: Shutdown,
 ) -> Result<(), ConnectionError> {
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
-        let read_length = match sender.read(&mut initial_buffer).await {
+        // The shutdown is checked only between the requests, so the in-flight one is always completed.
+        let read_result = tokio::select! {
+            biased;
+            _ = shutdown.triggered() => {
+                sender.send_error_response(IggyError::ServerShuttingDown).await?;
+                return Err(ConnectionError::from(IggyError::ServerShuttingDown));
+            }
+            _ = session.disconnected() => {
+                return Err(ConnectionError::from(IggyError::ClientDisconnected(
+                    session.client_id,
+                )));
+            }
+            read_result = sender.read(&mut initial_buffer) => read_result,
+        };
+        let read_length = match read_result {
             Ok(read_length) => read_length,
             Err(error) => {
                 if error.as_code() == IggyError::ConnectionClosed.as_code() {
@@ -62,6 +81,8 @@ pub(crate) async fn handle_connection(
         let mut command_buffer = BytesMut::with_capacity(length as usize);
         command_buffer.put_bytes(0, length as usize);
         sender.read(&mut command_buffer).await?;
+        sender.set_correlation_id(take_correlation_id(&mut command_buffer));
+        sender.set_error_details(take_error_details_flag(&mut command_buffer));
         let command = ServerCommand::from_bytes(command_buffer.freeze());
         if command.is_err() {
             sender
@@ -81,6 +102,24 @@ pub(crate) async fn handle_connection(
     }
 }
 
+/// Strips the optional correlation ID following the command code (marked with `CORRELATION_ID_FLAG`),
+/// leaving the buffer with the plain command code and payload.
+fn take_correlation_id(command_buffer: &mut BytesMut) -> Option<u32> {
+    if command_buffer.len() < CORRELATED_COMMAND_HEADER_LENGTH {
+        return None;
+    }
+
+    let code = u32::from_le_bytes(command_buffer[..4].try_into().unwrap());
+    if code & CORRELATION_ID_FLAG == 0 {
+        return None;
+    }
+
+    let correlation_id = u32::from_le_bytes(command_buffer[4..8].try_into().unwrap());
+    command_buffer[4..8].copy_from_slice(&(code & !CORRELATION_ID_FLAG).to_le_bytes());
+    command_buffer.advance(4);
+    Some(correlation_id)
+}
+
 pub(crate) fn handle_error(error: ConnectionError) {
     match error {
         ConnectionError::IoError(error) => match error.kind() {
@@ -104,6 +143,12 @@ pub(crate) fn handle_error(error: ConnectionError) {
             IggyError::ConnectionClosed => {
                 debug!("Client closed connection.");
             }
+            IggyError::ClientDisconnected(client_id) => {
+                info!("Connection has been closed by the server for client with ID: {client_id}.");
+            }
+            IggyError::ServerShuttingDown => {
+                info!("Connection has been closed due to the server shutdown.");
+            }
             _ => {
                 error!("Failure in internal SDK call: {sdk_error}");
             }
@@ -113,3 +158,30 @@ pub(crate) fn handle_error(error: ConnectionError) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correlation_id_should_be_taken_from_flagged_command() {
+        let mut buffer = BytesMut::new();
+        buffer.put_u32_le(101 | CORRELATION_ID_FLAG);
+        buffer.put_u32_le(7);
+        buffer.put_slice(b"payload");
+
+        assert_eq!(take_correlation_id(&mut buffer), Some(7));
+        assert_eq!(&buffer[..4], &101u32.to_le_bytes());
+        assert_eq!(&buffer[4..], b"payload");
+    }
+
+    #[test]
+    fn command_without_flag_should_be_left_intact() {
+        let mut buffer = BytesMut::new();
+        buffer.put_u32_le(101);
+        buffer.put_slice(b"payload");
+
+        assert_eq!(take_correlation_id(&mut buffer), None);
+        assert_eq!(buffer.len(), 11);
+    }
+}
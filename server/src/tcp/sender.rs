@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use bytes::Bytes;
 use iggy::error::IggyError;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::debug;
@@ -24,7 +25,7 @@ const STATUS_OK: &[u8] = &[0; 4];
 
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncRead + Unpin,
 {
     match stream.read_exact(buffer).await {
         Ok(0) => Err(IggyError::ConnectionClosed),
@@ -39,44 +40,116 @@ where
     }
 }
 
-pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
+pub(crate) async fn send_empty_ok_response<T>(
+    stream: &mut T,
+    correlation_id: Option<u32>,
+) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_ok_response(stream, &[]).await
+    send_ok_response(stream, correlation_id, &[]).await
 }
 
-pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
+pub(crate) async fn send_ok_response<T>(
+    stream: &mut T,
+    correlation_id: Option<u32>,
+    payload: &[u8],
+) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_response(stream, STATUS_OK, payload).await
+    send_response(stream, STATUS_OK, correlation_id, payload).await
 }
 
+/// The details of the error are included in the payload only if the client has requested them.
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
+    correlation_id: Option<u32>,
+    error_details: bool,
     error: IggyError,
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    let details = match error_details {
+        true => error.as_details().unwrap_or_default(),
+        false => Bytes::new(),
+    };
+    send_response(
+        stream,
+        &error.as_code().to_le_bytes(),
+        correlation_id,
+        &details,
+    )
+    .await
 }
 
+/// Writes the response header (status and payload length) followed by the payload.
+/// When the request was sent with a correlation ID, it's echoed back right after the header,
+/// so the client can match the response with one of its pipelined requests.
 pub(crate) async fn send_response<T>(
     stream: &mut T,
     status: &[u8],
+    correlation_id: Option<u32>,
     payload: &[u8],
 ) -> Result<(), IggyError>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
     debug!("Sending response with status: {:?}...", status);
     let length = (payload.len() as u32).to_le_bytes();
+    let response = match correlation_id {
+        Some(correlation_id) => [status, &length, &correlation_id.to_le_bytes(), payload]
+            .as_slice()
+            .concat(),
+        None => [status, &length, payload].as_slice().concat(),
+    };
     stream
-        .write_all(&[status, &length, payload].as_slice().concat())
+        .write_all(&response)
         .await
         .map_err(|_| IggyError::TcpError)?;
     debug!("Sent response with status: {:?}", status);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn schema_violation() -> IggyError {
+        IggyError::MessagePayloadSchemaViolation(1, 2, "invalid type".to_string())
+    }
+
+    #[tokio::test]
+    async fn error_response_without_requested_details_should_have_empty_payload() {
+        let mut response = Cursor::new(Vec::new());
+
+        send_error_response(&mut response, None, false, schema_violation())
+            .await
+            .unwrap();
+
+        let response = response.into_inner();
+
+        let mut expected = schema_violation().as_code().to_le_bytes().to_vec();
+        expected.extend_from_slice(&0u32.to_le_bytes());
+        assert_eq!(response, expected);
+    }
+
+    #[tokio::test]
+    async fn error_response_with_requested_details_should_carry_them_in_payload() {
+        let mut response = Cursor::new(Vec::new());
+
+        send_error_response(&mut response, Some(7), true, schema_violation())
+            .await
+            .unwrap();
+
+        let response = response.into_inner();
+
+        let details = schema_violation().as_details().unwrap();
+        assert_eq!(&response[..4], &schema_violation().as_code().to_le_bytes());
+        assert_eq!(&response[4..8], &(details.len() as u32).to_le_bytes());
+        assert_eq!(&response[8..12], &7u32.to_le_bytes());
+        assert_eq!(&response[12..], &details[..]);
+    }
+}
@@ -17,6 +17,7 @@
  */
 
 use crate::binary::sender::SenderKind;
+use crate::shutdown::Shutdown;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
@@ -25,7 +26,12 @@ use tokio::net::TcpSocket;
 use tokio::sync::oneshot;
 use tracing::{error, info};
 
-pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    address: &str,
+    socket: TcpSocket,
+    system: SharedSystem,
+    shutdown: Shutdown,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -52,7 +58,15 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
         });
 
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    info!("TCP server has stopped accepting new connections.");
+                    break;
+                }
+                accepted = listener.accept() => accepted,
+            };
+            match accepted {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP connection: {address}");
                     let session = system
@@ -64,10 +78,12 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
                     let client_id = session.client_id;
                     info!("Created new session: {session}");
                     let system = system.clone();
-                    let mut sender = SenderKind::get_tcp_sender(stream);
-                    tokio::spawn(async move {
+                    let mut sender =
+                        SenderKind::get_tcp_sender(stream, session.clone(), system.clone());
+                    let connection_shutdown = shutdown.clone();
+                    shutdown.spawn(async move {
                         if let Err(error) =
-                            handle_connection(session, &mut sender, system.clone()).await
+                            handle_connection(session, &mut sender, system.clone(), connection_shutdown).await
                         {
                             handle_error(error);
                             system.read().await.delete_client(client_id).await;
@@ -17,36 +17,62 @@
  */
 
 use crate::binary::sender::Sender;
+use crate::binary::subscriptions::MessageSubscriptions;
 use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
 use error_set::ErrContext;
 use iggy::error::IggyError;
-use tokio::{io::AsyncWriteExt, net::TcpStream};
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::sync::Mutex;
+
+/// The writing half of the TCP stream, shared with the subscriptions pushing the messages.
+pub type TcpWriter = Arc<Mutex<OwnedWriteHalf>>;
 
 #[derive(Debug)]
 pub struct TcpSender {
-    pub(crate) stream: TcpStream,
+    pub(crate) reader: OwnedReadHalf,
+    pub(crate) writer: TcpWriter,
+    pub(crate) correlation_id: Option<u32>,
+    pub(crate) error_details: bool,
+    pub(crate) subscriptions: MessageSubscriptions,
 }
 
 impl Sender for TcpSender {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
-        sender::read(&mut self.stream, buffer).await
+        sender::read(&mut self.reader, buffer).await
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        let mut writer = self.writer.lock().await;
+        sender::send_empty_ok_response(&mut *writer, self.correlation_id).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        let mut writer = self.writer.lock().await;
+        sender::send_ok_response(&mut *writer, self.correlation_id, payload).await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        let mut writer = self.writer.lock().await;
+        sender::send_error_response(&mut *writer, self.correlation_id, self.error_details, error)
+            .await
+    }
+
+    fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        self.correlation_id = correlation_id;
+    }
+
+    fn set_error_details(&mut self, error_details: bool) {
+        self.error_details = error_details;
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
-        self.stream
+        self.subscriptions.unsubscribe_all();
+        self.writer
+            .lock()
+            .await
             .shutdown()
             .await
             .with_error_context(|error| {
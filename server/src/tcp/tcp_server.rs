@@ -16,7 +16,9 @@
  * under the License.
  */
 
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::tcp::TcpConfig;
+use crate::shutdown::Shutdown;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::{tcp_listener, tcp_socket, tcp_tls_listener};
 use std::net::SocketAddr;
@@ -24,7 +26,12 @@ use tracing::info;
 
 /// Starts the TCP server.
 /// Returns the address the server is listening on.
-pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    config: TcpConfig,
+    system: SharedSystem,
+    config_watcher: ConfigWatcher,
+    shutdown: Shutdown,
+) -> SocketAddr {
     let server_name = if config.tls.enabled {
         "Iggy TCP TLS"
     } else {
@@ -33,8 +40,18 @@ pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing {server_name} server...");
     let socket = tcp_socket::build(config.ipv6, config.socket);
     let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, socket, system).await,
-        false => tcp_listener::start(&config.address, socket, system).await,
+        true => {
+            tcp_tls_listener::start(
+                &config.address,
+                config.tls,
+                socket,
+                system,
+                shutdown,
+                config_watcher,
+            )
+            .await
+        }
+        false => tcp_listener::start(&config.address, socket, system, shutdown).await,
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
@@ -17,7 +17,9 @@
  */
 
 use crate::binary::sender::SenderKind;
+use crate::configs::reload::ConfigWatcher;
 use crate::configs::tcp::TcpTlsConfig;
+use crate::shutdown::Shutdown;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
@@ -26,6 +28,7 @@ use tokio::net::TcpSocket;
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
+use tokio_native_tls::TlsAcceptor;
 use tracing::{error, info};
 
 pub(crate) async fn start(
@@ -33,25 +36,14 @@ pub(crate) async fn start(
     config: TcpTlsConfig,
     socket: TcpSocket,
     system: SharedSystem,
+    shutdown: Shutdown,
+    mut config_watcher: ConfigWatcher,
 ) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let certificate = std::fs::read(config.certificate.clone());
-        if certificate.is_err() {
-            panic!("Unable to read certificate file.");
-        }
-
-        let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
-        if identity.is_err() {
-            panic!("Unable to create identity from certificate.");
-        }
-
-        let acceptor = tokio_native_tls::TlsAcceptor::from(
-            native_tls::TlsAcceptor::builder(identity.unwrap())
-                .build()
-                .unwrap(),
-        );
+        let mut acceptor = build_acceptor(&config)
+            .unwrap_or_else(|error| panic!("Unable to create TLS acceptor. {error}"));
 
         let addr = address.parse();
         if addr.is_err() {
@@ -78,7 +70,30 @@ pub(crate) async fn start(
         });
 
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    info!("TCP TLS server has stopped accepting new connections.");
+                    break;
+                }
+                accepted = listener.accept() => accepted,
+                Ok(()) = config_watcher.changed() => {
+                    // The certificate is read again even if its path is the same, as it could have been renewed in place.
+                    let reloaded = config_watcher.borrow_and_update().tcp.tls.clone();
+                    match build_acceptor(&reloaded) {
+                        Ok(reloaded_acceptor) => {
+                            acceptor = reloaded_acceptor;
+                            info!("Reloaded TCP TLS certificate: {}", reloaded.certificate);
+                        }
+                        Err(error) => error!(
+                            "Unable to reload TCP TLS certificate: {}. {error}",
+                            reloaded.certificate
+                        ),
+                    }
+                    continue;
+                }
+            };
+            match accepted {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP TLS connection: {}", address);
                     let session = system
@@ -91,10 +106,12 @@ pub(crate) async fn start(
                     let acceptor = acceptor.clone();
                     let stream = acceptor.accept(stream).await.unwrap();
                     let system = system.clone();
-                    let mut sender = SenderKind::get_tcp_tls_sender(stream);
-                    tokio::spawn(async move {
+                    let mut sender =
+                        SenderKind::get_tcp_tls_sender(stream, session.clone(), system.clone());
+                    let connection_shutdown = shutdown.clone();
+                    shutdown.spawn(async move {
                         if let Err(error) =
-                            handle_connection(session, &mut sender, system.clone()).await
+                            handle_connection(session, &mut sender, system.clone(), connection_shutdown).await
                         {
                             handle_error(error);
                             system.read().await.delete_client(client_id).await;
@@ -115,3 +132,12 @@ pub(crate) async fn start(
         Err(_) => panic!("Failed to get the local address for TCP TLS listener."),
     }
 }
+
+pub(crate) fn build_acceptor(
+    config: &TcpTlsConfig,
+) -> Result<TlsAcceptor, Box<dyn std::error::Error + Send + Sync>> {
+    let certificate = std::fs::read(&config.certificate)?;
+    let identity = Identity::from_pkcs12(&certificate, &config.password)?;
+    let acceptor = native_tls::TlsAcceptor::builder(identity).build()?;
+    Ok(TlsAcceptor::from(acceptor))
+}
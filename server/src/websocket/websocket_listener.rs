@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::SenderKind;
+use crate::binary::subscriptions::{MessageSubscriptions, PushSink};
+use crate::configs::websocket::WebSocketConfig;
+use crate::shutdown::Shutdown;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use futures::StreamExt;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{oneshot, Mutex};
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig as TungsteniteConfig;
+use tracing::{error, info};
+
+pub async fn start(
+    config: WebSocketConfig,
+    system: SharedSystem,
+    shutdown: Shutdown,
+) -> SocketAddr {
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(&config.address)
+            .await
+            .unwrap_or_else(|error| {
+                panic!(
+                    "Unable to start WebSocket server on address: {}. {error}",
+                    config.address
+                )
+            });
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for WebSocket listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for WebSocket listener",
+                local_addr
+            )
+        });
+
+        let max_message_size = config.max_message_size.as_bytes_usize();
+        let websocket_config = TungsteniteConfig::default()
+            .max_message_size(Some(max_message_size))
+            .max_frame_size(Some(max_message_size));
+        loop {
+            let accepted = tokio::select! {
+                biased;
+                _ = shutdown.triggered() => {
+                    info!("WebSocket server has stopped accepting new connections.");
+                    break;
+                }
+                accepted = listener.accept() => accepted,
+            };
+            match accepted {
+                Ok((stream, address)) => {
+                    info!("Accepted new WebSocket connection: {address}");
+                    let system = system.clone();
+                    let connection_shutdown = shutdown.clone();
+                    shutdown.spawn(async move {
+                        handle_websocket_connection(
+                            stream,
+                            address,
+                            websocket_config,
+                            system,
+                            connection_shutdown,
+                        )
+                        .await
+                    });
+                }
+                Err(error) => error!("Unable to accept WebSocket socket. {error}"),
+            }
+        }
+    });
+    match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for WebSocket listener."),
+    }
+}
+
+async fn handle_websocket_connection(
+    stream: TcpStream,
+    address: SocketAddr,
+    websocket_config: TungsteniteConfig,
+    system: SharedSystem,
+    shutdown: Shutdown,
+) {
+    let websocket_stream =
+        match tokio_tungstenite::accept_async_with_config(stream, Some(websocket_config)).await {
+            Ok(websocket_stream) => websocket_stream,
+            Err(error) => {
+                error!("Failed to complete the WebSocket handshake with: {address}. {error}");
+                return;
+            }
+        };
+
+    let session = system
+        .read()
+        .await
+        .add_client(&address, Transport::WebSocket)
+        .await;
+    let client_id = session.client_id;
+    info!("Created new session: {session}");
+    let (sink, stream) = websocket_stream.split();
+    let sink = Arc::new(Mutex::new(sink));
+    let subscriptions = MessageSubscriptions::new(
+        session.clone(),
+        system.clone(),
+        PushSink::WebSocket(sink.clone()),
+    );
+    let mut sender = SenderKind::get_websocket_sender(stream, sink, subscriptions);
+    if let Err(error) = handle_connection(session, &mut sender, system.clone(), shutdown).await {
+        handle_error(error);
+        system.read().await.delete_client(client_id).await;
+        if let Err(error) = sender.shutdown().await {
+            error!("Failed to shutdown WebSocket stream for client: {client_id}, address: {address}. {error}");
+        } else {
+            info!(
+                "Successfully closed WebSocket stream for client: {client_id}, address: {address}."
+            );
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::Sender;
+use crate::binary::subscriptions::MessageSubscriptions;
+use crate::server_error::ServerError;
+use crate::websocket::COMPONENT;
+use bytes::{Buf, Bytes};
+use error_set::ErrContext;
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, StreamExt};
+use iggy::error::IggyError;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+use tokio_tungstenite::WebSocketStream;
+use tracing::debug;
+
+const STATUS_OK: &[u8] = &[0; 4];
+
+/// The sending half of the WebSocket connection, shared with the subscriptions pushing the messages.
+pub type WebSocketSink = Arc<Mutex<SplitSink<WebSocketStream<TcpStream>, Message>>>;
+
+/// Reads the requests from the binary WebSocket messages, each carrying a command with the same framing as TCP,
+/// and writes every response as a single binary message.
+#[derive(Debug)]
+pub struct WebSocketSender {
+    pub(crate) stream: SplitStream<WebSocketStream<TcpStream>>,
+    pub(crate) sink: WebSocketSink,
+    pub(crate) read_buffer: Bytes,
+    pub(crate) correlation_id: Option<u32>,
+    pub(crate) error_details: bool,
+    pub(crate) subscriptions: MessageSubscriptions,
+}
+
+impl WebSocketSender {
+    async fn read_message(&mut self) -> Result<(), IggyError> {
+        while self.read_buffer.is_empty() {
+            match self.stream.next().await {
+                Some(Ok(Message::Binary(payload))) => self.read_buffer = payload,
+                Some(Ok(Message::Close(_))) | None => return Err(IggyError::ConnectionClosed),
+                // Control frames are handled by the protocol itself.
+                Some(Ok(_)) => continue,
+                Some(Err(WsError::ConnectionClosed | WsError::AlreadyClosed)) => {
+                    return Err(IggyError::ConnectionClosed)
+                }
+                Some(Err(error)) => {
+                    debug!("Failed to read WebSocket message: {error}");
+                    return Err(IggyError::WebSocketError);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn send_response(&mut self, status: &[u8], payload: &[u8]) -> Result<(), IggyError> {
+        debug!("Sending response with status: {:?}...", status);
+        let length = (payload.len() as u32).to_le_bytes();
+        let response = match self.correlation_id {
+            Some(correlation_id) => [status, &length, &correlation_id.to_le_bytes(), payload]
+                .as_slice()
+                .concat(),
+            None => [status, &length, payload].as_slice().concat(),
+        };
+        self.sink
+            .lock()
+            .await
+            .send(Message::Binary(response.into()))
+            .await
+            .map_err(|_| IggyError::WebSocketError)?;
+        debug!("Sent response with status: {:?}", status);
+        Ok(())
+    }
+}
+
+impl Sender for WebSocketSender {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        let mut read_bytes = 0;
+        while read_bytes < buffer.len() {
+            self.read_message().await?;
+            let length = (buffer.len() - read_bytes).min(self.read_buffer.len());
+            buffer[read_bytes..read_bytes + length].copy_from_slice(&self.read_buffer[..length]);
+            self.read_buffer.advance(length);
+            read_bytes += length;
+        }
+        Ok(read_bytes)
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.send_response(STATUS_OK, &[]).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.send_response(STATUS_OK, payload).await
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        let details = match self.error_details {
+            true => error.as_details().unwrap_or_default(),
+            false => Bytes::new(),
+        };
+        self.send_response(&error.as_code().to_le_bytes(), &details)
+            .await
+    }
+
+    fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        self.correlation_id = correlation_id;
+    }
+
+    fn set_error_details(&mut self, error_details: bool) {
+        self.error_details = error_details;
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ServerError> {
+        self.subscriptions.unsubscribe_all();
+        self.sink
+            .lock()
+            .await
+            .close()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to close WebSocket stream")
+            })
+            .map_err(|error| ServerError::IoError(std::io::Error::other(error)))
+    }
+}
@@ -0,0 +1,346 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::changelog::{self, SourcePartition};
+use crate::config::StreamsConfig;
+use crate::context::ProcessorContext;
+use crate::error::StreamsError;
+use crate::record::{Record, KEY_HEADER};
+use crate::store::StateStores;
+use crate::topology::{Node, StreamsBuilder, Topology};
+use bytes::Bytes;
+use iggy::client::{ConsumerGroupClient, ConsumerOffsetClient, MessageClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::Partitioning;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::PolledMessage;
+use iggy::stream_builder::IggyStream;
+use std::collections::HashMap;
+use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// The stream processing application, running the topology built by the `StreamsBuilder`.
+///
+/// The source topics are polled by the consumer group named after the application ID, and each polled batch
+/// is checkpointed once processed: the produced messages are sent to the sink topics first, then the changes
+/// of the state stores are appended to the changelog topic along with the offset of the last processed message,
+/// and finally the offset is stored for the consumer group. On startup, the state stores are restored
+/// from the changelog topic, and the messages already reflected in them are not processed again,
+/// so that the state is updated exactly once, while the produced messages might be duplicated after a failure.
+///
+/// Each instance keeps the whole state of the application, so the stateful topologies should be run
+/// by a single instance at a time, while the stateless ones can be scaled out by running more instances.
+pub struct StreamsApp {
+    client: IggyClient,
+    config: StreamsConfig,
+    topology: Topology,
+}
+
+impl StreamsApp {
+    /// Creates the application running the topology with the connected client.
+    ///
+    /// # Errors
+    ///
+    /// If the topology is invalid, e.g. the stream isn't processed by any terminal operation.
+    pub fn new(
+        client: IggyClient,
+        config: StreamsConfig,
+        builder: StreamsBuilder,
+    ) -> Result<Self, StreamsError> {
+        Ok(Self {
+            client,
+            config,
+            topology: builder.build()?,
+        })
+    }
+
+    /// Creates the application running the topology with the client connected by the connection string.
+    pub async fn from_connection_string(
+        connection_string: &str,
+        config: StreamsConfig,
+        builder: StreamsBuilder,
+    ) -> Result<Self, StreamsError> {
+        let client = IggyStream::build_iggy_client(connection_string).await?;
+        Self::new(client, config, builder)
+    }
+
+    /// Restores the state stores and processes the messages of the source topics until the shutdown is requested.
+    ///
+    /// # Errors
+    ///
+    /// If the messages can't be polled, processed or checkpointed. The messages which haven't been checkpointed
+    /// are processed again once the application is restarted.
+    pub async fn run(self, shutdown: CancellationToken) -> Result<(), StreamsError> {
+        let Self {
+            client,
+            config,
+            mut topology,
+        } = self;
+        let application_id = config.application_id();
+        let mut context = ProcessorContext::new(
+            StateStores::new(&topology.stores),
+            topology.observed_tables(),
+        );
+        let changelog_stream = config.changelog_stream();
+        let changelog_topic = config.changelog_topic();
+        let mut checkpoints = HashMap::new();
+        if !topology.stores.is_empty() {
+            changelog::create_if_not_exists(&client, changelog_stream, &changelog_topic).await?;
+            checkpoints = changelog::restore(
+                &client,
+                changelog_stream,
+                &changelog_topic,
+                &Consumer::new(Identifier::named(application_id)?),
+                config.batch_size(),
+                context.stores(),
+            )
+            .await?;
+        }
+
+        let group_id = Identifier::named(application_id)?;
+        let mut sources = Vec::new();
+        for source in &topology.sources {
+            let stream_id = Identifier::from_str_value(&source.stream)?;
+            let topic_id = Identifier::from_str_value(&source.topic)?;
+            join_group(&client, &stream_id, &topic_id, &group_id, application_id).await?;
+            sources.push((stream_id, topic_id));
+        }
+        info!(
+            "Streams application: {application_id} has joined the consumer groups of {} source topic(s).",
+            sources.len()
+        );
+
+        let mut processor = Processor {
+            client: &client,
+            config: &config,
+            consumer: Consumer::group(group_id.clone()),
+            changelog_stream_id: Identifier::named(changelog_stream)?,
+            changelog_topic_id: Identifier::named(&changelog_topic)?,
+            checkpoints,
+        };
+        let result = processor
+            .run(&mut topology, &mut context, &sources, &shutdown)
+            .await;
+        for (stream_id, topic_id) in &sources {
+            if let Err(error) = client
+                .leave_consumer_group(stream_id, topic_id, &group_id)
+                .await
+            {
+                warn!("Failed to leave consumer group: {application_id} of stream: {stream_id}, topic: {topic_id}. {error}");
+            }
+        }
+        info!("Streams application: {application_id} has stopped.");
+        result
+    }
+}
+
+struct Processor<'a> {
+    client: &'a IggyClient,
+    config: &'a StreamsConfig,
+    consumer: Consumer,
+    changelog_stream_id: Identifier,
+    changelog_topic_id: Identifier,
+    checkpoints: HashMap<SourcePartition, u64>,
+}
+
+impl Processor<'_> {
+    async fn run(
+        &mut self,
+        topology: &mut Topology,
+        context: &mut ProcessorContext,
+        sources: &[(Identifier, Identifier)],
+        shutdown: &CancellationToken,
+    ) -> Result<(), StreamsError> {
+        while !shutdown.is_cancelled() {
+            let mut caught_up = true;
+            for (index, (stream_id, topic_id)) in sources.iter().enumerate() {
+                let polled_messages = self
+                    .client
+                    .poll_messages(
+                        stream_id,
+                        topic_id,
+                        None,
+                        &self.consumer,
+                        &PollingStrategy::next(),
+                        self.config.batch_size(),
+                        false,
+                    )
+                    .await?;
+                let Some(last_offset) = polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.offset)
+                else {
+                    continue;
+                };
+
+                caught_up = false;
+                let source = &topology.sources[index];
+                let partition = (
+                    source.stream.clone(),
+                    source.topic.clone(),
+                    polled_messages.partition_id,
+                );
+                let checkpoint = self.checkpoints.get(&partition).copied();
+                for message in polled_messages.messages {
+                    // The state stores restored from the changelog already reflect the checkpointed messages.
+                    if checkpoint.is_some_and(|checkpoint| message.offset <= checkpoint) {
+                        continue;
+                    }
+                    process(topology, context, Node::Topic(index), to_record(message)?)?;
+                }
+                self.checkpoint(context, stream_id, topic_id, &partition, last_offset)
+                    .await?;
+            }
+
+            if caught_up {
+                tokio::select! {
+                    _ = shutdown.cancelled() => {}
+                    _ = sleep(self.config.poll_interval().get_duration()) => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn checkpoint(
+        &self,
+        context: &mut ProcessorContext,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition: &SourcePartition,
+        offset: u64,
+    ) -> Result<(), StreamsError> {
+        // The consecutive messages sent to the same topic with the same key are sent at once, preserving their order.
+        let mut outputs = context.take_outputs().into_iter().peekable();
+        while let Some(output) = outputs.next() {
+            let mut messages = vec![output.message];
+            while let Some(next) = outputs.next_if(|next| {
+                next.stream_id == output.stream_id
+                    && next.topic_id == output.topic_id
+                    && next.key == output.key
+            }) {
+                messages.push(next.message);
+            }
+            let partitioning = match &output.key {
+                Some(key) => Partitioning::messages_key_str(key)?,
+                None => Partitioning::balanced(),
+            };
+            self.client
+                .send_messages(
+                    &output.stream_id,
+                    &output.topic_id,
+                    &partitioning,
+                    &mut messages,
+                )
+                .await?;
+        }
+
+        let mut changes = changelog::to_messages(context.stores(), partition, offset)?;
+        if !changes.is_empty() {
+            self.client
+                .send_messages(
+                    &self.changelog_stream_id,
+                    &self.changelog_topic_id,
+                    &Partitioning::partition_id(1),
+                    &mut changes,
+                )
+                .await?;
+        }
+
+        let partition_id = partition.2;
+        debug!("Committing offset: {offset} of partition: {partition_id}, stream: {stream_id}, topic: {topic_id}.");
+        self.client
+            .store_consumer_offset(
+                &self.consumer,
+                stream_id,
+                topic_id,
+                Some(partition_id),
+                offset,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Processes the record by the processors of the node, followed by the processors of the updated tables.
+fn process(
+    topology: &mut Topology,
+    context: &mut ProcessorContext,
+    node: Node,
+    record: Record<Bytes>,
+) -> Result<(), StreamsError> {
+    let mut next = Some((node, record));
+    while let Some((node, record)) = next {
+        if let Some(processors) = topology.processors.get_mut(&node) {
+            for processor in processors {
+                processor(record.clone(), context)?;
+            }
+        }
+        next = context
+            .next_table_update()
+            .map(|(store, record)| (Node::Table(store), record));
+    }
+    Ok(())
+}
+
+fn to_record(message: PolledMessage) -> Result<Record<Bytes>, StreamsError> {
+    let mut headers = message.headers.unwrap_or_default();
+    let key = headers
+        .remove(&HeaderKey::new(KEY_HEADER)?)
+        .map(|key| key.as_str().map(str::to_owned))
+        .transpose()?;
+    Ok(Record {
+        key,
+        value: message.payload,
+        timestamp: message.timestamp,
+        headers,
+    })
+}
+
+async fn join_group(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    group_id: &Identifier,
+    group_name: &str,
+) -> Result<(), StreamsError> {
+    match client
+        .join_consumer_group(stream_id, topic_id, group_id)
+        .await
+    {
+        Err(IggyError::ConsumerGroupNameNotFound(_, _)) => {
+            match client
+                .create_consumer_group(stream_id, topic_id, group_name, None)
+                .await
+            {
+                Ok(_) | Err(IggyError::ConsumerGroupNameAlreadyExists(_, _)) => {}
+                Err(error) => return Err(error.into()),
+            }
+            client
+                .join_consumer_group(stream_id, topic_id, group_id)
+                .await?;
+            Ok(())
+        }
+        result => Ok(result?),
+    }
+}
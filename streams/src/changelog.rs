@@ -0,0 +1,287 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::StreamsError;
+use crate::record::{header, KEY_HEADER};
+use crate::store::StateStores;
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::Message;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::PolledMessage;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::info;
+
+const STORE_HEADER: &str = "iggy-streams-store";
+const TOMBSTONE_HEADER: &str = "iggy-streams-tombstone";
+const CHECKPOINT_STREAM_HEADER: &str = "iggy-streams-checkpoint-stream";
+const CHECKPOINT_TOPIC_HEADER: &str = "iggy-streams-checkpoint-topic";
+const CHECKPOINT_PARTITION_ID_HEADER: &str = "iggy-streams-checkpoint-partition-id";
+const CHECKPOINT_OFFSET_HEADER: &str = "iggy-streams-checkpoint-offset";
+// The messages can't be empty, so the deletions and the checkpoints have the placeholder payload.
+const EMPTY_PAYLOAD: &[u8] = &[0];
+
+/// The partition of the source topic, identified by the names, as configured in the topology.
+pub(crate) type SourcePartition = (String, String, u32);
+
+/// Returns the changes of the state stores, followed by the checkpoint of the offset of the last processed
+/// message in the source partition, or nothing if the state stores haven't changed. The changelog messages
+/// are appended at once, so that the checkpoint is restored only along with the changes it covers.
+pub(crate) fn to_messages(
+    stores: &mut StateStores,
+    source: &SourcePartition,
+    offset: u64,
+) -> Result<Vec<Message>, StreamsError> {
+    let mut messages = Vec::new();
+    for (name, store) in stores.iter_mut() {
+        for (key, value) in store.take_changes() {
+            let mut headers = HashMap::from([
+                (HeaderKey::new(STORE_HEADER)?, HeaderValue::from_str(name)?),
+                (HeaderKey::new(KEY_HEADER)?, HeaderValue::from_str(&key)?),
+            ]);
+            let payload = match value {
+                Some(value) => value,
+                None => {
+                    headers.insert(
+                        HeaderKey::new(TOMBSTONE_HEADER)?,
+                        HeaderValue::from_bool(true)?,
+                    );
+                    Bytes::from_static(EMPTY_PAYLOAD)
+                }
+            };
+            messages.push(Message::new(None, payload, Some(headers)));
+        }
+    }
+    if messages.is_empty() {
+        return Ok(messages);
+    }
+
+    let (stream, topic, partition_id) = source;
+    let headers = HashMap::from([
+        (
+            HeaderKey::new(CHECKPOINT_STREAM_HEADER)?,
+            HeaderValue::from_str(stream)?,
+        ),
+        (
+            HeaderKey::new(CHECKPOINT_TOPIC_HEADER)?,
+            HeaderValue::from_str(topic)?,
+        ),
+        (
+            HeaderKey::new(CHECKPOINT_PARTITION_ID_HEADER)?,
+            HeaderValue::from_uint32(*partition_id)?,
+        ),
+        (
+            HeaderKey::new(CHECKPOINT_OFFSET_HEADER)?,
+            HeaderValue::from_uint64(offset)?,
+        ),
+    ]);
+    messages.push(Message::new(
+        None,
+        Bytes::from_static(EMPTY_PAYLOAD),
+        Some(headers),
+    ));
+    Ok(messages)
+}
+
+/// Creates the changelog stream and topic with a single partition, if they don't exist.
+pub(crate) async fn create_if_not_exists(
+    client: &IggyClient,
+    stream: &str,
+    topic: &str,
+) -> Result<(), StreamsError> {
+    let stream_id = Identifier::named(stream)?;
+    if client.get_stream(&stream_id).await?.is_none() {
+        match client.create_stream(stream, None).await {
+            Ok(_) | Err(IggyError::StreamNameAlreadyExists(_)) => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    let topic_id = Identifier::named(topic)?;
+    if client.get_topic(&stream_id, &topic_id).await?.is_none() {
+        info!("Creating changelog topic: {topic} in stream: {stream}.");
+        match client
+            .create_topic(
+                &stream_id,
+                topic,
+                1,
+                CompressionAlgorithm::None,
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+        {
+            Ok(_) | Err(IggyError::TopicNameAlreadyExists(_, _)) => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+    Ok(())
+}
+
+/// Restores the state stores from the changelog topic, returning the offsets of the last processed
+/// messages in the source partitions, which changes of the state stores have been checkpointed.
+pub(crate) async fn restore(
+    client: &IggyClient,
+    stream: &str,
+    topic: &str,
+    consumer: &Consumer,
+    batch_size: u32,
+    stores: &mut StateStores,
+) -> Result<HashMap<SourcePartition, u64>, StreamsError> {
+    let stream_id = Identifier::named(stream)?;
+    let topic_id = Identifier::named(topic)?;
+    let mut checkpoints = HashMap::new();
+    let mut offset = 0;
+    let mut restored_messages = 0;
+    loop {
+        let polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(1),
+                consumer,
+                &PollingStrategy::offset(offset),
+                batch_size,
+                false,
+            )
+            .await?;
+        let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        else {
+            break;
+        };
+
+        for message in polled_messages.messages {
+            restored_messages += 1;
+            apply(message, stores, &mut checkpoints)?;
+        }
+        if last_offset >= polled_messages.current_offset {
+            break;
+        }
+        offset = last_offset + 1;
+    }
+
+    info!(
+        "Restored {restored_messages} messages from changelog topic: {topic} in stream: {stream}."
+    );
+    Ok(checkpoints)
+}
+
+fn apply(
+    message: PolledMessage,
+    stores: &mut StateStores,
+    checkpoints: &mut HashMap<SourcePartition, u64>,
+) -> Result<(), StreamsError> {
+    let invalid = |reason: &str| StreamsError::InvalidChangelog(message.offset, reason.to_owned());
+    let headers = message.headers.clone().unwrap_or_default();
+    if let Some(offset) = header(&headers, CHECKPOINT_OFFSET_HEADER) {
+        let (Some(stream), Some(topic), Some(partition_id)) = (
+            header(&headers, CHECKPOINT_STREAM_HEADER),
+            header(&headers, CHECKPOINT_TOPIC_HEADER),
+            header(&headers, CHECKPOINT_PARTITION_ID_HEADER),
+        ) else {
+            return Err(invalid("missing checkpoint header"));
+        };
+
+        checkpoints.insert(
+            (
+                stream.as_str()?.to_owned(),
+                topic.as_str()?.to_owned(),
+                partition_id.as_uint32()?,
+            ),
+            offset.as_uint64()?,
+        );
+        return Ok(());
+    }
+
+    let (Some(store), Some(key)) = (header(&headers, STORE_HEADER), header(&headers, KEY_HEADER))
+    else {
+        return Err(invalid("missing store or key header"));
+    };
+
+    // The changes of the state stores removed from the topology are ignored.
+    let Ok(store) = stores.get_mut(store.as_str()?) else {
+        return Ok(());
+    };
+
+    let value = match header(&headers, TOMBSTONE_HEADER) {
+        Some(_) => None,
+        None => Some(message.payload),
+    };
+    store.restore(key.as_str()?.to_owned(), value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messages::MessageState;
+
+    #[test]
+    fn changes_should_be_restored_along_with_checkpoint() {
+        let stores_names = ["counts".to_owned(), "totals".to_owned()];
+        let mut stores = StateStores::new(&stores_names);
+        let counts = stores.get_mut("counts").unwrap();
+        counts.put("a".to_owned(), Bytes::from("1"));
+        counts.put("b".to_owned(), Bytes::from("2"));
+        counts.delete("b");
+        stores
+            .get_mut("totals")
+            .unwrap()
+            .put("a".to_owned(), Bytes::from("10"));
+        let source = ("orders".to_owned(), "placed".to_owned(), 3);
+
+        let messages = to_messages(&mut stores, &source, 42).unwrap();
+        assert_eq!(messages.len(), 4);
+        assert!(to_messages(&mut stores, &source, 43).unwrap().is_empty());
+
+        let mut restored_stores = StateStores::new(&stores_names);
+        let mut checkpoints = HashMap::new();
+        for (offset, message) in messages.into_iter().enumerate() {
+            let message = PolledMessage::create(
+                offset as u64,
+                MessageState::Available,
+                0.into(),
+                message.id,
+                message.payload,
+                0,
+                message.headers,
+            );
+            apply(message, &mut restored_stores, &mut checkpoints).unwrap();
+        }
+
+        let counts = restored_stores.get("counts").unwrap();
+        assert_eq!(counts.get("a"), Some(&Bytes::from("1")));
+        assert_eq!(counts.get("b"), None);
+        let totals = restored_stores.get("totals").unwrap();
+        assert_eq!(totals.get("a"), Some(&Bytes::from("10")));
+        assert_eq!(checkpoints, HashMap::from([(source, 42)]));
+    }
+}
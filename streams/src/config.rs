@@ -0,0 +1,100 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use bon::Builder;
+use iggy::utils::duration::IggyDuration;
+use std::time::Duration;
+
+/// The configuration of the stream processing application.
+#[derive(Builder, Debug, Clone)]
+#[builder(on(String, into))]
+pub struct StreamsConfig {
+    /// The ID of the application, used as the name of the consumer group polling the source topics
+    /// and the prefix of the changelog topic name. All the instances of the application must use the same ID.
+    application_id: String,
+    /// The name of the stream holding the changelog topic of the state stores, created if it doesn't exist.
+    /// Defaults to the application ID.
+    changelog_stream: Option<String>,
+    /// The max number of messages polled from the source topic at once, processed before the next checkpoint.
+    #[builder(default = 1000)]
+    batch_size: u32,
+    /// The interval between polling the source topics once all of them have been caught up.
+    #[builder(default = IggyDuration::new(Duration::from_millis(100)))]
+    poll_interval: IggyDuration,
+}
+
+impl StreamsConfig {
+    /// Creates the configuration of the application with the given ID and the default settings.
+    pub fn new(application_id: &str) -> Self {
+        Self::builder().application_id(application_id).build()
+    }
+
+    pub fn application_id(&self) -> &str {
+        &self.application_id
+    }
+
+    pub fn changelog_stream(&self) -> &str {
+        self.changelog_stream
+            .as_deref()
+            .unwrap_or(&self.application_id)
+    }
+
+    /// Returns the name of the topic holding the changes of all the state stores of the application.
+    pub fn changelog_topic(&self) -> String {
+        format!("{}-changelog", self.application_id)
+    }
+
+    pub fn batch_size(&self) -> u32 {
+        self.batch_size
+    }
+
+    pub fn poll_interval(&self) -> IggyDuration {
+        self.poll_interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_new() {
+        let config = StreamsConfig::new("word-count");
+        assert_eq!(config.application_id(), "word-count");
+        assert_eq!(config.changelog_stream(), "word-count");
+        assert_eq!(config.changelog_topic(), "word-count-changelog");
+        assert_eq!(config.batch_size(), 1000);
+        assert_eq!(
+            config.poll_interval(),
+            IggyDuration::new(Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn should_be_built() {
+        let config = StreamsConfig::builder()
+            .application_id("word-count")
+            .changelog_stream("state")
+            .batch_size(10)
+            .poll_interval(IggyDuration::ONE_SECOND)
+            .build();
+        assert_eq!(config.changelog_stream(), "state");
+        assert_eq!(config.changelog_topic(), "word-count-changelog");
+        assert_eq!(config.batch_size(), 10);
+        assert_eq!(config.poll_interval(), IggyDuration::ONE_SECOND);
+    }
+}
@@ -0,0 +1,85 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::StreamsError;
+use crate::record::Record;
+use crate::store::{KeyValueStore, StateStores};
+use bytes::Bytes;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Message;
+use std::collections::{HashSet, VecDeque};
+
+/// The message produced by the topology, sent to the sink topic on the next checkpoint.
+#[derive(Debug)]
+pub(crate) struct OutputMessage {
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+    pub key: Option<String>,
+    pub message: Message,
+}
+
+/// The state shared by the processors of the topology: the state stores, the produced messages
+/// and the pending updates of the tables, forwarded to the streams created from them.
+#[derive(Debug)]
+pub(crate) struct ProcessorContext {
+    stores: StateStores,
+    observed_tables: HashSet<String>,
+    outputs: Vec<OutputMessage>,
+    updates: VecDeque<(String, Record<Bytes>)>,
+}
+
+impl ProcessorContext {
+    pub fn new(stores: StateStores, observed_tables: HashSet<String>) -> Self {
+        Self {
+            stores,
+            observed_tables,
+            outputs: Vec::new(),
+            updates: VecDeque::new(),
+        }
+    }
+
+    pub fn stores(&mut self) -> &mut StateStores {
+        &mut self.stores
+    }
+
+    pub fn store(&self, name: &str) -> Result<&KeyValueStore, StreamsError> {
+        self.stores.get(name)
+    }
+
+    pub fn store_mut(&mut self, name: &str) -> Result<&mut KeyValueStore, StreamsError> {
+        self.stores.get_mut(name)
+    }
+
+    pub fn send(&mut self, output: OutputMessage) {
+        self.outputs.push(output);
+    }
+
+    pub fn take_outputs(&mut self) -> Vec<OutputMessage> {
+        std::mem::take(&mut self.outputs)
+    }
+
+    /// Forwards the updated value of the table, if any stream has been created from it.
+    pub fn update_table(&mut self, store: &str, record: Record<Bytes>) {
+        if self.observed_tables.contains(store) {
+            self.updates.push_back((store.to_owned(), record));
+        }
+    }
+
+    pub fn next_table_update(&mut self) -> Option<(String, Record<Bytes>)> {
+        self.updates.pop_front()
+    }
+}
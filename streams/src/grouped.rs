@@ -0,0 +1,245 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::StreamsError;
+use crate::kstream::KStream;
+use crate::record::{Record, WINDOW_END_HEADER, WINDOW_START_HEADER};
+use crate::store::KeyValueStore;
+use crate::table::{KTable, WindowedKTable};
+use crate::topology::lock;
+use crate::window::TimeWindows;
+use iggy::codecs::json::JsonCodec;
+use iggy::codecs::Codec;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+use std::sync::Arc;
+use tracing::debug;
+
+/// The stream of the records grouped by their key, aggregated into the table.
+pub struct KGroupedStream<T> {
+    stream: KStream<T>,
+}
+
+impl<T: Send + 'static> KGroupedStream<T> {
+    pub(crate) fn new(stream: KStream<T>) -> Self {
+        Self { stream }
+    }
+
+    /// Aggregates the values of the records with the same key, starting with the initial value.
+    /// The aggregates are kept in the state store encoded by the codec, and the records without the key are skipped.
+    pub fn aggregate<V, I, A>(
+        self,
+        store: &str,
+        codec: impl Codec<V> + 'static,
+        initializer: I,
+        aggregator: A,
+    ) -> KTable<V>
+    where
+        V: Send + 'static,
+        I: Fn() -> V + Send + 'static,
+        A: Fn(&str, &T, V) -> V + Send + 'static,
+    {
+        self.aggregate_with(store, codec, move |key, value, aggregate| {
+            aggregator(key, &value, aggregate.unwrap_or_else(&initializer))
+        })
+    }
+
+    /// Combines the values of the records with the same key, the first value being the initial aggregate.
+    pub fn reduce<R>(self, store: &str, codec: impl Codec<T> + 'static, reducer: R) -> KTable<T>
+    where
+        R: Fn(T, T) -> T + Send + 'static,
+    {
+        self.aggregate_with(store, codec, move |_, value, aggregate| match aggregate {
+            Some(aggregate) => reducer(aggregate, value),
+            None => value,
+        })
+    }
+
+    /// Counts the records with the same key.
+    pub fn count(self, store: &str) -> KTable<u64> {
+        self.aggregate(store, JsonCodec, || 0, |_, _, count| count + 1)
+    }
+
+    /// Groups the records by the time windows, for the windowed aggregations.
+    pub fn windowed_by(self, windows: TimeWindows) -> TimeWindowedStream<T> {
+        if let Err(error) = windows.validate() {
+            lock(self.stream.topology()).fail(error);
+        }
+        TimeWindowedStream {
+            stream: self.stream,
+            windows,
+        }
+    }
+
+    fn aggregate_with<V, A>(
+        self,
+        store: &str,
+        codec: impl Codec<V> + 'static,
+        aggregator: A,
+    ) -> KTable<V>
+    where
+        V: Send + 'static,
+        A: Fn(&str, T, Option<V>) -> V + Send + 'static,
+    {
+        let topology = self.stream.topology().clone();
+        lock(&topology).add_store(store);
+        let codec: Arc<dyn Codec<V>> = Arc::new(codec);
+        let table_codec = codec.clone();
+        let store_name = store.to_owned();
+        self.stream.terminate(move |record, context| {
+            let Some(key) = record.key else {
+                return Ok(());
+            };
+
+            let store = context.store_mut(&store_name)?;
+            let aggregate = store
+                .get(&key)
+                .map(|aggregate| codec.decode(aggregate))
+                .transpose()?;
+            let aggregate = aggregator(&key, record.value, aggregate);
+            let payload = codec.encode(&aggregate)?;
+            store.put(key.clone(), payload.clone());
+            context.update_table(
+                &store_name,
+                Record::new(Some(key), payload, record.timestamp),
+            );
+            Ok(())
+        });
+        KTable::new(topology, store, table_codec)
+    }
+}
+
+/// The stream of the records grouped by their key and the time windows, aggregated into the windowed table.
+pub struct TimeWindowedStream<T> {
+    stream: KStream<T>,
+    windows: TimeWindows,
+}
+
+impl<T: Send + 'static> TimeWindowedStream<T> {
+    /// Aggregates the values of the records with the same key within each window, starting with the initial value.
+    /// The record is aggregated into every window containing its timestamp, unless the window is already closed.
+    pub fn aggregate<V, I, A>(
+        self,
+        store: &str,
+        codec: impl Codec<V> + 'static,
+        initializer: I,
+        aggregator: A,
+    ) -> WindowedKTable<V>
+    where
+        V: Send + 'static,
+        I: Fn() -> V + Send + 'static,
+        A: Fn(&str, &T, V) -> V + Send + 'static,
+    {
+        let topology = self.stream.topology().clone();
+        lock(&topology).add_store(store);
+        let codec: Arc<dyn Codec<V>> = Arc::new(codec);
+        let table_codec = codec.clone();
+        let store_name = store.to_owned();
+        let windows = self.windows;
+        // The keys of the open windows by their end, built from the restored state store on the first record.
+        let mut open_windows: Option<BTreeMap<u64, Vec<String>>> = None;
+        let mut stream_time = 0;
+        self.stream.terminate(move |record, context| {
+            let Some(key) = record.key else {
+                return Ok(());
+            };
+
+            let store = context.store_mut(&store_name)?;
+            let open_windows =
+                open_windows.get_or_insert_with(|| index_windows(store, windows.size()));
+            stream_time = u64::max(stream_time, record.timestamp);
+            let mut updates = Vec::new();
+            for window in windows.windows_for(record.timestamp) {
+                if windows.is_closed(window.end, stream_time) {
+                    debug!(
+                        "Skipping the late record with key: {key} for the closed window: {window:?} in store: {store_name}."
+                    );
+                    continue;
+                }
+
+                let window_key = format!("{}:{key}", window.start);
+                let aggregate = match store.get(&window_key) {
+                    Some(aggregate) => codec.decode(aggregate)?,
+                    None => {
+                        open_windows
+                            .entry(window.end)
+                            .or_default()
+                            .push(window_key.clone());
+                        initializer()
+                    }
+                };
+                let aggregate = aggregator(&key, &record.value, aggregate);
+                let payload = codec.encode(&aggregate)?;
+                store.put(window_key, payload.clone());
+                let mut update = Record::new(Some(key.clone()), payload, record.timestamp);
+                update.headers = window_headers(&window)?;
+                updates.push(update);
+            }
+
+            while let Some(entry) = open_windows.first_entry() {
+                if !windows.is_closed(*entry.key(), stream_time) {
+                    break;
+                }
+                for window_key in entry.remove() {
+                    store.delete(&window_key);
+                }
+            }
+
+            for update in updates {
+                context.update_table(&store_name, update);
+            }
+            Ok(())
+        });
+        WindowedKTable::new(KTable::new(topology, store, table_codec))
+    }
+
+    /// Counts the records with the same key within each window.
+    pub fn count(self, store: &str) -> WindowedKTable<u64> {
+        self.aggregate(store, JsonCodec, || 0, |_, _, count| count + 1)
+    }
+}
+
+fn index_windows(store: &KeyValueStore, size: u64) -> BTreeMap<u64, Vec<String>> {
+    let mut windows: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+    for window_key in store.keys() {
+        let Some(start) = window_key
+            .split_once(':')
+            .and_then(|(start, _)| start.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        windows
+            .entry(start + size)
+            .or_default()
+            .push(window_key.to_owned());
+    }
+    windows
+}
+
+fn window_headers(window: &Range<u64>) -> Result<HashMap<HeaderKey, HeaderValue>, StreamsError> {
+    Ok(HashMap::from([
+        (
+            HeaderKey::new(WINDOW_START_HEADER)?,
+            HeaderValue::from_uint64(window.start)?,
+        ),
+        (
+            HeaderKey::new(WINDOW_END_HEADER)?,
+            HeaderValue::from_uint64(window.end)?,
+        ),
+    ]))
+}
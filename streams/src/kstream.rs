@@ -0,0 +1,324 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::context::{OutputMessage, ProcessorContext};
+use crate::error::StreamsError;
+use crate::grouped::KGroupedStream;
+use crate::record::{Record, KEY_HEADER};
+use crate::table::KTable;
+use crate::topology::{lock, Node, SharedTopology};
+use bytes::Bytes;
+use iggy::codecs::{Codec, CONTENT_TYPE_HEADER, SCHEMA_ID_HEADER};
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Message;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::str::FromStr;
+use std::sync::Arc;
+
+type Transform<T> = Box<
+    dyn FnMut(Record<Bytes>, &mut ProcessorContext, &mut Vec<Record<T>>) -> Result<(), StreamsError>
+        + Send,
+>;
+
+/// The stream of the records, transformed by the chained operations and processed by the terminal one:
+/// sending the records to the topic, consuming them by the provided function or aggregating them by the key.
+/// Each record read from the source is processed by the whole chain before the next one is read.
+pub struct KStream<T> {
+    topology: SharedTopology,
+    node: Node,
+    transform: Transform<T>,
+}
+
+impl<T: Send + 'static> KStream<T> {
+    pub(crate) fn decoded(
+        topology: SharedTopology,
+        node: Node,
+        codec: Arc<dyn Codec<T>>,
+    ) -> KStream<T> {
+        KStream {
+            topology,
+            node,
+            transform: Box::new(move |record, _, output| {
+                let value = codec.decode(&record.value)?;
+                output.push(record.map(|_| value));
+                Ok(())
+            }),
+        }
+    }
+
+    pub(crate) fn topology(&self) -> &SharedTopology {
+        &self.topology
+    }
+
+    /// Chains the operation invoked for each record produced by the stream.
+    pub(crate) fn pipe<U, F>(self, mut operation: F) -> KStream<U>
+    where
+        F: FnMut(Record<T>, &mut ProcessorContext, &mut Vec<Record<U>>) -> Result<(), StreamsError>
+            + Send
+            + 'static,
+    {
+        let mut transform = self.transform;
+        let mut records = Vec::new();
+        KStream {
+            topology: self.topology,
+            node: self.node,
+            transform: Box::new(move |record, context, output| {
+                records.clear();
+                transform(record, context, &mut records)?;
+                for record in records.drain(..) {
+                    operation(record, context, output)?;
+                }
+                Ok(())
+            }),
+        }
+    }
+
+    /// Ends the stream with the operation invoked for each record, adding it to the topology.
+    pub(crate) fn terminate<F>(self, mut operation: F)
+    where
+        F: FnMut(Record<T>, &mut ProcessorContext) -> Result<(), StreamsError> + Send + 'static,
+    {
+        let mut transform = self.transform;
+        let mut records = Vec::new();
+        lock(&self.topology).add_processor(
+            self.node,
+            Box::new(move |record, context| {
+                records.clear();
+                transform(record, context, &mut records)?;
+                for record in records.drain(..) {
+                    operation(record, context)?;
+                }
+                Ok(())
+            }),
+        );
+    }
+
+    /// Transforms the value of each record, preserving its key.
+    pub fn map<U, F>(self, f: F) -> KStream<U>
+    where
+        U: Send + 'static,
+        F: Fn(T) -> U + Send + 'static,
+    {
+        self.pipe(move |record, _, output| {
+            output.push(record.map(&f));
+            Ok(())
+        })
+    }
+
+    /// Keeps only the records which values match the predicate.
+    pub fn filter<F>(self, predicate: F) -> KStream<T>
+    where
+        F: Fn(&T) -> bool + Send + 'static,
+    {
+        self.pipe(move |record, _, output| {
+            if predicate(&record.value) {
+                output.push(record);
+            }
+            Ok(())
+        })
+    }
+
+    /// Transforms the value of each record into zero or more values, each becoming a record with the same key.
+    pub fn flat_map<U, I, F>(self, f: F) -> KStream<U>
+    where
+        U: Send + 'static,
+        I: IntoIterator<Item = U>,
+        F: Fn(T) -> I + Send + 'static,
+    {
+        self.pipe(move |record, _, output| {
+            let Record {
+                key,
+                value,
+                timestamp,
+                headers,
+            } = record;
+            for value in f(value) {
+                output.push(Record {
+                    key: key.clone(),
+                    value,
+                    timestamp,
+                    headers: headers.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    /// Sets the key of each record to the one derived from its value.
+    pub fn select_key<F>(self, key: F) -> KStream<T>
+    where
+        F: Fn(&T) -> String + Send + 'static,
+    {
+        self.pipe(move |mut record, _, output| {
+            record.key = Some(key(&record.value));
+            output.push(record);
+            Ok(())
+        })
+    }
+
+    /// Invokes the function for each record, e.g. for logging, without changing the stream.
+    pub fn peek<F>(self, f: F) -> KStream<T>
+    where
+        F: Fn(&Record<T>) + Send + 'static,
+    {
+        self.pipe(move |record, _, output| {
+            f(&record);
+            output.push(record);
+            Ok(())
+        })
+    }
+
+    /// Joins each record with the current value of the table under the same key.
+    /// The records without the key or without the matching value in the table are skipped.
+    pub fn join<V, R, F>(self, table: &KTable<V>, joiner: F) -> KStream<R>
+    where
+        V: Send + 'static,
+        R: Send + 'static,
+        F: Fn(T, V) -> R + Send + 'static,
+    {
+        self.join_table(table, move |value, table_value| {
+            table_value.map(|table_value| joiner(value, table_value))
+        })
+    }
+
+    /// Joins each record with the current value of the table under the same key, if any.
+    pub fn left_join<V, R, F>(self, table: &KTable<V>, joiner: F) -> KStream<R>
+    where
+        V: Send + 'static,
+        R: Send + 'static,
+        F: Fn(T, Option<V>) -> R + Send + 'static,
+    {
+        self.join_table(table, move |value, table_value| {
+            Some(joiner(value, table_value))
+        })
+    }
+
+    fn join_table<V, R, F>(self, table: &KTable<V>, joiner: F) -> KStream<R>
+    where
+        V: Send + 'static,
+        R: Send + 'static,
+        F: Fn(T, Option<V>) -> Option<R> + Send + 'static,
+    {
+        if !Arc::ptr_eq(&self.topology, table.topology()) {
+            lock(&self.topology).fail(StreamsError::InvalidTopology(format!(
+                "the joined table: {} belongs to another topology",
+                table.store_name()
+            )));
+        }
+
+        let store = table.store_name().to_owned();
+        let codec = table.codec();
+        self.pipe(move |record, context, output| {
+            let table_value = match record.key.as_deref() {
+                Some(key) => context
+                    .store(&store)?
+                    .get(key)
+                    .map(|value| codec.decode(value))
+                    .transpose()?,
+                None => None,
+            };
+            let Record {
+                key,
+                value,
+                timestamp,
+                headers,
+            } = record;
+            if let Some(value) = joiner(value, table_value) {
+                output.push(Record {
+                    key,
+                    value,
+                    timestamp,
+                    headers,
+                });
+            }
+            Ok(())
+        })
+    }
+
+    /// Groups the records by their current key, for the aggregations.
+    pub fn group_by_key(self) -> KGroupedStream<T> {
+        KGroupedStream::new(self)
+    }
+
+    /// Groups the records by the key derived from their value, for the aggregations.
+    pub fn group_by<F>(self, key: F) -> KGroupedStream<T>
+    where
+        F: Fn(&T) -> String + Send + 'static,
+    {
+        self.select_key(key).group_by_key()
+    }
+
+    /// Invokes the function for each record, ending the stream.
+    pub fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Record<T>) + Send + 'static,
+    {
+        self.terminate(move |record, _| {
+            f(record);
+            Ok(())
+        });
+    }
+
+    /// Sends each record to the topic, with the value encoded by the codec, ending the stream.
+    /// The key is sent in the `iggy-streams-key` header and the messages with the same key are appended
+    /// to the same partition. The headers of the source message are preserved, except for the content type
+    /// and the schema ID, which are replaced by the ones of the codec.
+    pub fn to(self, stream: &str, topic: &str, codec: impl Codec<T> + 'static) {
+        let (stream_id, topic_id) = match (
+            Identifier::from_str_value(stream),
+            Identifier::from_str_value(topic),
+        ) {
+            (Ok(stream_id), Ok(topic_id)) => (stream_id, topic_id),
+            (Err(error), _) | (_, Err(error)) => {
+                lock(&self.topology).fail(error.into());
+                return;
+            }
+        };
+
+        self.terminate(move |record, context| {
+            let payload = codec.encode(&record.value)?;
+            let mut headers = record.headers;
+            headers.retain(|key, _| {
+                !matches!(
+                    key.as_str(),
+                    CONTENT_TYPE_HEADER | SCHEMA_ID_HEADER | KEY_HEADER
+                )
+            });
+            headers.insert(
+                HeaderKey::new(CONTENT_TYPE_HEADER)?,
+                HeaderValue::from_str(codec.content_type())?,
+            );
+            if let Some(schema_id) = codec.schema_id() {
+                headers.insert(
+                    HeaderKey::new(SCHEMA_ID_HEADER)?,
+                    HeaderValue::from_uint32(schema_id)?,
+                );
+            }
+            if let Some(key) = &record.key {
+                headers.insert(HeaderKey::new(KEY_HEADER)?, HeaderValue::from_str(key)?);
+            }
+
+            context.send(OutputMessage {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                key: record.key,
+                message: Message::new(None, payload, Some(headers)),
+            });
+            Ok(())
+        });
+    }
+}
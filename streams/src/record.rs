@@ -0,0 +1,88 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+/// The name of the message header holding the key of the record, used to group the records
+/// and to assign the messages with the same key to the same partition.
+pub const KEY_HEADER: &str = "iggy-streams-key";
+
+/// The name of the message header holding the start of the window in microseconds,
+/// set on the updates of the windowed aggregations.
+pub const WINDOW_START_HEADER: &str = "iggy-streams-window-start";
+
+/// The name of the message header holding the end (exclusive) of the window in microseconds,
+/// set on the updates of the windowed aggregations.
+pub const WINDOW_END_HEADER: &str = "iggy-streams-window-end";
+
+/// The record flowing through the topology, created from the message polled from the source topic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record<T> {
+    /// The key of the record, read from the `iggy-streams-key` header of the message.
+    pub key: Option<String>,
+    pub value: T,
+    /// The timestamp of the source message in microseconds.
+    pub timestamp: u64,
+    /// The headers of the source message, excluding the key.
+    pub headers: HashMap<HeaderKey, HeaderValue>,
+}
+
+impl<T> Record<T> {
+    pub fn new(key: Option<String>, value: T, timestamp: u64) -> Self {
+        Self {
+            key,
+            value,
+            timestamp,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Maps the value of the record, preserving its key, timestamp and headers.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Record<U> {
+        Record {
+            key: self.key,
+            value: f(self.value),
+            timestamp: self.timestamp,
+            headers: self.headers,
+        }
+    }
+
+    pub(crate) fn header(&self, name: &str) -> Option<&HeaderValue> {
+        header(&self.headers, name)
+    }
+}
+
+/// The value of the windowed aggregation, along with the range of the timestamps covered by the window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Windowed<V> {
+    /// The start of the window in microseconds.
+    pub start: u64,
+    /// The end (exclusive) of the window in microseconds.
+    pub end: u64,
+    pub value: V,
+}
+
+pub(crate) fn header<'a>(
+    headers: &'a HashMap<HeaderKey, HeaderValue>,
+    name: &str,
+) -> Option<&'a HeaderValue> {
+    headers
+        .iter()
+        .find(|(key, _)| key.as_str() == name)
+        .map(|(_, value)| value)
+}
@@ -0,0 +1,132 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::StreamsError;
+use bytes::Bytes;
+use std::collections::HashMap;
+
+/// The local key-value state store, holding the encoded values and tracking the changes
+/// to be written to the changelog topic on the next checkpoint.
+#[derive(Debug, Default)]
+pub(crate) struct KeyValueStore {
+    entries: HashMap<String, Bytes>,
+    changes: HashMap<String, Option<Bytes>>,
+}
+
+impl KeyValueStore {
+    pub fn get(&self, key: &str) -> Option<&Bytes> {
+        self.entries.get(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    pub fn put(&mut self, key: String, value: Bytes) {
+        self.changes.insert(key.clone(), Some(value.clone()));
+        self.entries.insert(key, value);
+    }
+
+    pub fn delete(&mut self, key: &str) {
+        if self.entries.remove(key).is_some() {
+            self.changes.insert(key.to_owned(), None);
+        }
+    }
+
+    /// Applies the change read from the changelog topic, without tracking it.
+    pub fn restore(&mut self, key: String, value: Option<Bytes>) {
+        match value {
+            Some(value) => {
+                self.entries.insert(key, value);
+            }
+            None => {
+                self.entries.remove(&key);
+            }
+        }
+    }
+
+    pub fn take_changes(&mut self) -> HashMap<String, Option<Bytes>> {
+        std::mem::take(&mut self.changes)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct StateStores {
+    stores: HashMap<String, KeyValueStore>,
+}
+
+impl StateStores {
+    pub fn new(names: &[String]) -> Self {
+        Self {
+            stores: names
+                .iter()
+                .map(|name| (name.clone(), KeyValueStore::default()))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Result<&KeyValueStore, StreamsError> {
+        self.stores
+            .get(name)
+            .ok_or_else(|| StreamsError::StoreNotFound(name.to_owned()))
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Result<&mut KeyValueStore, StreamsError> {
+        self.stores
+            .get_mut(name)
+            .ok_or_else(|| StreamsError::StoreNotFound(name.to_owned()))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut KeyValueStore)> {
+        self.stores.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn store_should_track_changes_until_taken() {
+        let mut store = KeyValueStore::default();
+        store.put("a".to_owned(), Bytes::from("1"));
+        store.put("b".to_owned(), Bytes::from("2"));
+        store.put("a".to_owned(), Bytes::from("3"));
+        store.delete("b");
+        store.delete("c");
+
+        assert_eq!(store.get("a"), Some(&Bytes::from("3")));
+        assert_eq!(store.get("b"), None);
+        let changes = store.take_changes();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes["a"], Some(Bytes::from("3")));
+        assert_eq!(changes["b"], None);
+        assert!(store.take_changes().is_empty());
+    }
+
+    #[test]
+    fn restored_changes_should_not_be_tracked() {
+        let mut store = KeyValueStore::default();
+        store.restore("a".to_owned(), Some(Bytes::from("1")));
+        store.restore("b".to_owned(), Some(Bytes::from("2")));
+        store.restore("b".to_owned(), None);
+
+        assert_eq!(store.get("a"), Some(&Bytes::from("1")));
+        assert_eq!(store.keys().count(), 1);
+        assert!(store.take_changes().is_empty());
+    }
+}
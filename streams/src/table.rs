@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::StreamsError;
+use crate::kstream::KStream;
+use crate::record::{Windowed, WINDOW_END_HEADER, WINDOW_START_HEADER};
+use crate::topology::{Node, SharedTopology};
+use iggy::codecs::Codec;
+use iggy::error::IggyError;
+use iggy::models::header::HeaderValue;
+use std::sync::Arc;
+
+/// The table holding the latest value for each key in the local state store, which is restored
+/// from the changelog topic on startup. It's materialized from the topic or produced by the aggregation,
+/// and can be joined with the streams or turned into the stream of its updates.
+pub struct KTable<V> {
+    topology: SharedTopology,
+    store: String,
+    codec: Arc<dyn Codec<V>>,
+}
+
+impl<V: Send + 'static> KTable<V> {
+    pub(crate) fn new(topology: SharedTopology, store: &str, codec: Arc<dyn Codec<V>>) -> Self {
+        Self {
+            topology,
+            store: store.to_owned(),
+            codec,
+        }
+    }
+
+    pub(crate) fn topology(&self) -> &SharedTopology {
+        &self.topology
+    }
+
+    pub(crate) fn codec(&self) -> Arc<dyn Codec<V>> {
+        self.codec.clone()
+    }
+
+    /// Returns the name of the state store holding the table.
+    pub fn store_name(&self) -> &str {
+        &self.store
+    }
+
+    /// Creates the stream of the updated values of the table, keyed the same way.
+    pub fn to_stream(&self) -> KStream<V> {
+        KStream::decoded(
+            self.topology.clone(),
+            Node::Table(self.store.clone()),
+            self.codec.clone(),
+        )
+    }
+}
+
+/// The table holding the value of the windowed aggregation for each key and window.
+pub struct WindowedKTable<V> {
+    table: KTable<V>,
+}
+
+impl<V: Send + 'static> WindowedKTable<V> {
+    pub(crate) fn new(table: KTable<V>) -> Self {
+        Self { table }
+    }
+
+    /// Returns the name of the state store holding the table.
+    pub fn store_name(&self) -> &str {
+        self.table.store_name()
+    }
+
+    /// Creates the stream of the updated values of the windows, keyed by the aggregation key.
+    pub fn to_stream(&self) -> KStream<Windowed<V>> {
+        self.table.to_stream().pipe(|record, _, output| {
+            let start = window_bound(record.header(WINDOW_START_HEADER))?;
+            let end = window_bound(record.header(WINDOW_END_HEADER))?;
+            output.push(record.map(|value| Windowed { start, end, value }));
+            Ok(())
+        })
+    }
+}
+
+fn window_bound(value: Option<&HeaderValue>) -> Result<u64, StreamsError> {
+    Ok(value.ok_or(IggyError::InvalidHeaderValue)?.as_uint64()?)
+}
@@ -0,0 +1,203 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::context::ProcessorContext;
+use crate::error::StreamsError;
+use crate::kstream::KStream;
+use crate::record::Record;
+use crate::table::KTable;
+use bytes::Bytes;
+use iggy::codecs::Codec;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub(crate) type Processor =
+    Box<dyn FnMut(Record<Bytes>, &mut ProcessorContext) -> Result<(), StreamsError> + Send>;
+
+pub(crate) type SharedTopology = Arc<Mutex<Topology>>;
+
+/// The node of the topology the records are read from: either the source topic
+/// (by its index in the sources) or the table (by its state store name) forwarding its updates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Node {
+    Topic(usize),
+    Table(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SourceTopic {
+    pub stream: String,
+    pub topic: String,
+}
+
+/// The processors of the records read from the source topics and the tables, along with the state stores they use.
+#[derive(Default)]
+pub(crate) struct Topology {
+    pub sources: Vec<SourceTopic>,
+    pub processors: HashMap<Node, Vec<Processor>>,
+    pub stores: Vec<String>,
+    error: Option<StreamsError>,
+}
+
+impl Debug for Topology {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Topology")
+            .field("sources", &self.sources)
+            .field("processors", &self.processors.keys().collect::<Vec<_>>())
+            .field("stores", &self.stores)
+            .finish()
+    }
+}
+
+impl Topology {
+    pub fn add_source(&mut self, stream: &str, topic: &str) -> Node {
+        let source = SourceTopic {
+            stream: stream.to_owned(),
+            topic: topic.to_owned(),
+        };
+        let index = match self.sources.iter().position(|other| other == &source) {
+            Some(index) => index,
+            None => {
+                self.sources.push(source);
+                self.sources.len() - 1
+            }
+        };
+        Node::Topic(index)
+    }
+
+    pub fn add_processor(&mut self, node: Node, processor: Processor) {
+        self.processors.entry(node).or_default().push(processor);
+    }
+
+    pub fn add_store(&mut self, name: &str) {
+        if name.is_empty() || self.stores.iter().any(|store| store == name) {
+            self.fail(StreamsError::InvalidTopology(format!(
+                "the state store name must be unique and not empty: '{name}'"
+            )));
+            return;
+        }
+
+        self.stores.push(name.to_owned());
+    }
+
+    /// Records the first error of the topology definition, returned once it's built.
+    pub fn fail(&mut self, error: StreamsError) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Returns the names of the tables, which updates are forwarded to the streams.
+    pub fn observed_tables(&self) -> HashSet<String> {
+        self.processors
+            .keys()
+            .filter_map(|node| match node {
+                Node::Table(store) => Some(store.clone()),
+                Node::Topic(_) => None,
+            })
+            .collect()
+    }
+
+    fn validate(&mut self) -> Result<(), StreamsError> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        if self.sources.is_empty() {
+            return Err(StreamsError::InvalidTopology(
+                "at least one source topic is required".to_owned(),
+            ));
+        }
+
+        for (index, source) in self.sources.iter().enumerate() {
+            if !self.processors.contains_key(&Node::Topic(index)) {
+                return Err(StreamsError::InvalidTopology(format!(
+                    "the records of stream: {}, topic: {} are not processed, the stream must end with `to`, `for_each` or an aggregation",
+                    source.stream, source.topic
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn lock(topology: &SharedTopology) -> MutexGuard<'_, Topology> {
+    topology.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// The builder of the topology, creating the streams of the records read from the source topics
+/// and the tables materialized from them.
+#[derive(Debug, Default)]
+pub struct StreamsBuilder {
+    topology: SharedTopology,
+}
+
+impl StreamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the stream of the records read from the topic, with the values decoded by the codec.
+    pub fn stream<T: Send + 'static>(
+        &self,
+        stream: &str,
+        topic: &str,
+        codec: impl Codec<T> + 'static,
+    ) -> KStream<T> {
+        let node = lock(&self.topology).add_source(stream, topic);
+        KStream::decoded(self.topology.clone(), node, Arc::new(codec))
+    }
+
+    /// Creates the table materialized in the state store from the records read from the topic,
+    /// holding the latest value for each key. The records without the key are skipped.
+    pub fn table<V: Send + 'static>(
+        &self,
+        stream: &str,
+        topic: &str,
+        store: &str,
+        codec: impl Codec<V> + 'static,
+    ) -> KTable<V> {
+        let mut topology = lock(&self.topology);
+        let node = topology.add_source(stream, topic);
+        topology.add_store(store);
+        let store_name = store.to_owned();
+        topology.add_processor(
+            node,
+            Box::new(move |record, context| {
+                let Some(key) = record.key.clone() else {
+                    return Ok(());
+                };
+
+                context
+                    .store_mut(&store_name)?
+                    .put(key, record.value.clone());
+                context.update_table(&store_name, record);
+                Ok(())
+            }),
+        );
+        drop(topology);
+        KTable::new(self.topology.clone(), store, Arc::new(codec))
+    }
+
+    pub(crate) fn build(self) -> Result<Topology, StreamsError> {
+        let mut topology = std::mem::take(&mut *lock(&self.topology));
+        topology.validate()?;
+        Ok(topology)
+    }
+}
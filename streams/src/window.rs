@@ -0,0 +1,149 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::StreamsError;
+use iggy::utils::duration::IggyDuration;
+use std::ops::Range;
+
+/// The time windows the records are assigned to by their timestamps, aligned to the multiples of the advance.
+///
+/// The window is closed once the stream time, i.e. the latest timestamp of the aggregated records,
+/// reaches its end plus the grace period. The records arriving later are dropped,
+/// and the closed windows are removed from the state store.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TimeWindows {
+    size: u64,
+    advance: u64,
+    grace: u64,
+}
+
+impl TimeWindows {
+    /// The non-overlapping windows of the given size.
+    pub fn tumbling(size: IggyDuration) -> Self {
+        Self::hopping(size, size)
+    }
+
+    /// The windows of the given size starting every `advance`, overlapping if the advance is smaller than the size.
+    pub fn hopping(size: IggyDuration, advance: IggyDuration) -> Self {
+        Self {
+            size: size.as_micros(),
+            advance: advance.as_micros(),
+            grace: 0,
+        }
+    }
+
+    /// Sets the period of time the window accepts the late records after its end, zero by default.
+    pub fn grace(self, grace: IggyDuration) -> Self {
+        Self {
+            grace: grace.as_micros(),
+            ..self
+        }
+    }
+
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), StreamsError> {
+        if self.size == 0 || self.advance == 0 || self.advance > self.size {
+            return Err(StreamsError::InvalidTopology(
+                "the window size and advance must be greater than zero, and the advance cannot exceed the size"
+                    .to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the windows containing the timestamp, ordered by their start.
+    pub(crate) fn windows_for(&self, timestamp: u64) -> Vec<Range<u64>> {
+        let mut windows = Vec::new();
+        let mut start = timestamp - timestamp % self.advance;
+        loop {
+            windows.push(start..start + self.size);
+            if start < self.advance || start - self.advance + self.size <= timestamp {
+                break;
+            }
+            start -= self.advance;
+        }
+        windows.reverse();
+        windows
+    }
+
+    /// Returns true if the window ending at the given timestamp can't accept the records anymore.
+    pub(crate) fn is_closed(&self, end: u64, stream_time: u64) -> bool {
+        end + self.grace <= stream_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tumbling_windows_should_contain_single_window() {
+        let windows = TimeWindows::tumbling(IggyDuration::new_from_secs(10));
+        assert_eq!(windows.windows_for(0), vec![0..10_000_000]);
+        assert_eq!(
+            windows.windows_for(25_000_000),
+            vec![20_000_000..30_000_000]
+        );
+        assert_eq!(
+            windows.windows_for(29_999_999),
+            vec![20_000_000..30_000_000]
+        );
+    }
+
+    #[test]
+    fn hopping_windows_should_contain_overlapping_windows() {
+        let windows = TimeWindows::hopping(
+            IggyDuration::new_from_secs(10),
+            IggyDuration::new_from_secs(5),
+        );
+        assert_eq!(windows.windows_for(3_000_000), vec![0..10_000_000]);
+        assert_eq!(
+            windows.windows_for(12_000_000),
+            vec![5_000_000..15_000_000, 10_000_000..20_000_000]
+        );
+        assert_eq!(
+            windows.windows_for(15_000_000),
+            vec![10_000_000..20_000_000, 15_000_000..25_000_000]
+        );
+    }
+
+    #[test]
+    fn window_should_be_closed_after_grace_period() {
+        let windows = TimeWindows::tumbling(IggyDuration::new_from_secs(10))
+            .grace(IggyDuration::new_from_secs(2));
+        assert!(!windows.is_closed(10_000_000, 11_999_999));
+        assert!(windows.is_closed(10_000_000, 12_000_000));
+    }
+
+    #[test]
+    fn invalid_windows_should_be_rejected() {
+        let zero = IggyDuration::new_from_secs(0);
+        let second = IggyDuration::new_from_secs(1);
+        assert!(TimeWindows::tumbling(zero).validate().is_err());
+        assert!(TimeWindows::hopping(second, zero).validate().is_err());
+        assert!(TimeWindows::hopping(second, IggyDuration::new_from_secs(2))
+            .validate()
+            .is_err());
+        assert!(TimeWindows::hopping(IggyDuration::new_from_secs(2), second)
+            .validate()
+            .is_ok());
+    }
+}